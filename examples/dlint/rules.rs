@@ -1,6 +1,6 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use crate::color::colorize_markdown;
-use deno_lint::rules::get_all_rules;
+use deno_lint::rules::{get_all_rules, rule_metadata};
 use serde::Serialize;
 
 #[derive(Clone, Copy, Serialize)]
@@ -22,9 +22,13 @@ pub fn get_all_rules_metadata() -> Vec<Rule> {
 }
 
 pub fn get_specific_rule_metadata(rule_name: &str) -> Vec<Rule> {
-  get_all_rules_metadata()
+  rule_metadata(rule_name)
     .into_iter()
-    .filter(|r| r.code == rule_name)
+    .map(|metadata| Rule {
+      code: metadata.code,
+      docs: metadata.docs,
+      tags: metadata.tags,
+    })
     .collect()
 }
 
@@ -0,0 +1,536 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+//! Resolution of the `lint.rules` configuration of `deno.json` files for
+//! projects that nest several configuration files, e.g. monorepos.
+//!
+//! `resolve_config_for_path` walks up from a path to the root of the file
+//! system and merges the `lint.rules` block of every `deno.json` it finds.
+//! The merge goes from the farthest file to the nearest one:
+//!
+//! - `tags` are the union of the tags of all the files;
+//! - `include` and `exclude` decide per rule code whether a rule is enabled,
+//!   and the nearest file that mentions a code wins. Within a single file
+//!   `include` wins over `exclude`, like in `get_filtered_rules`;
+//! - `options` are merged deeply: objects are merged key by key and any other
+//!   value, including arrays, is replaced by the nearer one.
+//!
+//! If none of the files has any `tags`, the `recommended` rules are used.
+//! There are no severities: a rule is either enabled, and all of its
+//! diagnostics are reported, or it's disabled.
+
+use crate::rules::{get_all_rules, get_filtered_rules, LintRule};
+use anyhow::anyhow;
+use serde::Deserialize;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// The name of the configuration files that are looked up.
+pub const CONFIG_FILE_NAME: &str = "deno.json";
+
+/// The file system used to read configuration files.
+pub trait ConfigFs {
+  /// Returns the content of the file at `path`, or `None` if there is no such
+  /// file.
+  fn read_to_string(&self, path: &Path) -> Option<String>;
+}
+
+/// A `ConfigFs` that reads from the real file system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealConfigFs;
+
+impl ConfigFs for RealConfigFs {
+  fn read_to_string(&self, path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok()
+  }
+}
+
+/// The `lint.rules` block of a single `deno.json`.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RulesConfig {
+  pub tags: Vec<String>,
+  pub include: Vec<String>,
+  pub exclude: Vec<String>,
+  pub options: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The configuration that applies to a path once all the `deno.json` files
+/// above it have been merged.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResolvedConfig {
+  /// The union of the tags of all the files, sorted.
+  pub tags: Vec<String>,
+  /// Codes of the rules that are explicitly enabled, sorted.
+  pub include: Vec<String>,
+  /// Codes of the rules that are explicitly disabled, sorted.
+  pub exclude: Vec<String>,
+  /// Options of the rules, keyed by rule code.
+  pub options: serde_json::Map<String, serde_json::Value>,
+  /// The configuration files that have been merged, nearest first.
+  pub config_files: Vec<PathBuf>,
+}
+
+impl ResolvedConfig {
+  /// Returns the rules enabled by this configuration, configured with their
+  /// `options`.
+  ///
+  /// An error is returned if `options` has an entry for an unknown rule or
+  /// options that the rule doesn't accept, even if the rule isn't enabled.
+  pub fn get_rules(&self) -> Result<Vec<Arc<dyn LintRule>>, anyhow::Error> {
+    let all_rules = get_all_rules();
+    let mut configured = HashMap::new();
+    for (code, options) in &self.options {
+      let rule = all_rules
+        .iter()
+        .find(|rule| rule.code() == code)
+        .ok_or_else(|| {
+          anyhow!("Unknown rule in lint.rules.options: {}", code)
+        })?;
+      let rule = rule
+        .with_options_value(options.clone())
+        .map_err(|err| anyhow!("Invalid options for {}: {}", code, err))?;
+      configured.insert(code.as_str(), rule);
+    }
+
+    let tags = if self.tags.is_empty() {
+      vec!["recommended".to_string()]
+    } else {
+      self.tags.clone()
+    };
+    let rules = get_filtered_rules(
+      Some(tags),
+      Some(self.exclude.clone()),
+      Some(self.include.clone()),
+    );
+    Ok(
+      rules
+        .into_iter()
+        .map(|rule| configured.remove(rule.code()).unwrap_or(rule))
+        .collect(),
+    )
+  }
+
+  fn merge(&mut self, config: RulesConfig) {
+    let mut tags = self.tags.drain(..).collect::<BTreeSet<_>>();
+    tags.extend(config.tags);
+    self.tags = tags.into_iter().collect();
+
+    let mut include = self.include.drain(..).collect::<BTreeSet<_>>();
+    let mut exclude = self.exclude.drain(..).collect::<BTreeSet<_>>();
+    for code in config.exclude {
+      include.remove(&code);
+      exclude.insert(code);
+    }
+    for code in config.include {
+      exclude.remove(&code);
+      include.insert(code);
+    }
+    self.include = include.into_iter().collect();
+    self.exclude = exclude.into_iter().collect();
+
+    merge_options(&mut self.options, config.options);
+  }
+}
+
+/// Merges `nearer` into `options`, recursing into objects.
+fn merge_options(
+  options: &mut serde_json::Map<String, serde_json::Value>,
+  nearer: serde_json::Map<String, serde_json::Value>,
+) {
+  for (key, value) in nearer {
+    match (options.get_mut(&key), value) {
+      (
+        Some(serde_json::Value::Object(existing)),
+        serde_json::Value::Object(value),
+      ) => merge_options(existing, value),
+      (_, value) => {
+        options.insert(key, value);
+      }
+    }
+  }
+}
+
+/// Parses the `lint.rules` block of the `deno.json` at `path`, if any.
+fn parse_rules_config(
+  path: &Path,
+  text: &str,
+) -> Result<Option<RulesConfig>, anyhow::Error> {
+  let json: serde_json::Value = serde_json::from_str(text)
+    .map_err(|err| anyhow!("Failed to parse {}: {}", path.display(), err))?;
+  let rules = match json.get("lint").and_then(|lint| lint.get("rules")) {
+    Some(rules) => rules,
+    None => return Ok(None),
+  };
+  RulesConfig::deserialize(rules)
+    .map(Some)
+    .map_err(|err| anyhow!("Invalid lint.rules in {}: {}", path.display(), err))
+}
+
+/// Resolves the configuration for `path` by merging the `lint.rules` blocks of
+/// every `deno.json` in `path` and its ancestors, see the module documentation
+/// for how they are merged.
+///
+/// Files without a `lint.rules` block are skipped. An error is returned if a
+/// configuration file is not valid JSON or its `lint.rules` block is
+/// malformed.
+pub fn resolve_config_for_path(
+  fs: &dyn ConfigFs,
+  path: &Path,
+) -> Result<ResolvedConfig, anyhow::Error> {
+  let mut configs = Vec::new();
+  for dir in path.ancestors() {
+    let config_path = dir.join(CONFIG_FILE_NAME);
+    let text = match fs.read_to_string(&config_path) {
+      Some(text) => text,
+      None => continue,
+    };
+    if let Some(config) = parse_rules_config(&config_path, &text)? {
+      configs.push((config_path, config));
+    }
+  }
+
+  let mut resolved = ResolvedConfig::default();
+  for (config_path, config) in configs.into_iter().rev() {
+    resolved.merge(config);
+    resolved.config_files.insert(0, config_path);
+  }
+  Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[derive(Default)]
+  struct MemoryFs(HashMap<PathBuf, String>);
+
+  impl MemoryFs {
+    fn with(mut self, path: &str, config: serde_json::Value) -> Self {
+      self.0.insert(PathBuf::from(path), config.to_string());
+      self
+    }
+  }
+
+  impl ConfigFs for MemoryFs {
+    fn read_to_string(&self, path: &Path) -> Option<String> {
+      self.0.get(path).cloned()
+    }
+  }
+
+  fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+  }
+
+  fn resolve(fs: &MemoryFs, path: &str) -> ResolvedConfig {
+    resolve_config_for_path(fs, Path::new(path)).unwrap()
+  }
+
+  fn codes_of(fs: &MemoryFs, path: &str) -> Vec<&'static str> {
+    resolve(fs, path)
+      .get_rules()
+      .unwrap()
+      .iter()
+      .map(|rule| rule.code())
+      .collect()
+  }
+
+  #[test]
+  fn no_config_files() {
+    let fs = MemoryFs::default();
+    assert_eq!(resolve(&fs, "/repo/src/mod.ts"), ResolvedConfig::default());
+  }
+
+  #[test]
+  fn single_config_file() {
+    let fs = MemoryFs::default().with(
+      "/repo/deno.json",
+      json!({
+        "lint": {
+          "rules": {
+            "tags": ["recommended"],
+            "include": ["eqeqeq"],
+            "exclude": ["no-explicit-any"],
+            "options": { "max-lines": { "max": 100 } }
+          }
+        }
+      }),
+    );
+    let resolved = resolve(&fs, "/repo/src/mod.ts");
+    assert_eq!(resolved.tags, strings(&["recommended"]));
+    assert_eq!(resolved.include, strings(&["eqeqeq"]));
+    assert_eq!(resolved.exclude, strings(&["no-explicit-any"]));
+    assert_eq!(
+      serde_json::Value::Object(resolved.options),
+      json!({ "max-lines": { "max": 100 } })
+    );
+    assert_eq!(
+      resolved.config_files,
+      vec![PathBuf::from("/repo/deno.json")]
+    );
+  }
+
+  #[test]
+  fn tags_are_unioned() {
+    let fs = MemoryFs::default()
+      .with(
+        "/repo/deno.json",
+        json!({ "lint": { "rules": { "tags": ["recommended"] } } }),
+      )
+      .with(
+        "/repo/packages/web/deno.json",
+        json!({ "lint": { "rules": { "tags": ["react", "recommended"] } } }),
+      );
+    let resolved = resolve(&fs, "/repo/packages/web/app.tsx");
+    assert_eq!(resolved.tags, strings(&["react", "recommended"]));
+  }
+
+  #[test]
+  fn nearest_include_or_exclude_wins() {
+    let fs = MemoryFs::default()
+      .with(
+        "/repo/deno.json",
+        json!({
+          "lint": {
+            "rules": {
+              "include": ["eqeqeq", "no-console"],
+              "exclude": ["no-explicit-any", "no-empty"]
+            }
+          }
+        }),
+      )
+      .with(
+        "/repo/packages/deno.json",
+        json!({
+          "lint": {
+            "rules": {
+              "include": ["no-explicit-any"],
+              "exclude": ["eqeqeq"]
+            }
+          }
+        }),
+      )
+      .with(
+        "/repo/packages/cli/deno.json",
+        json!({
+          "lint": {
+            "rules": {
+              "include": ["no-empty", "eqeqeq"],
+              "exclude": ["eqeqeq"]
+            }
+          }
+        }),
+      );
+
+    let resolved = resolve(&fs, "/repo/packages/cli/main.ts");
+    assert_eq!(
+      resolved.include,
+      strings(&["eqeqeq", "no-console", "no-empty", "no-explicit-any"])
+    );
+    assert!(resolved.exclude.is_empty());
+    assert_eq!(
+      resolved.config_files,
+      vec![
+        PathBuf::from("/repo/packages/cli/deno.json"),
+        PathBuf::from("/repo/packages/deno.json"),
+        PathBuf::from("/repo/deno.json"),
+      ]
+    );
+
+    let resolved = resolve(&fs, "/repo/packages/lib/mod.ts");
+    assert_eq!(
+      resolved.include,
+      strings(&["no-console", "no-explicit-any"])
+    );
+    assert_eq!(resolved.exclude, strings(&["eqeqeq", "no-empty"]));
+
+    let resolved = resolve(&fs, "/repo/mod.ts");
+    assert_eq!(resolved.include, strings(&["eqeqeq", "no-console"]));
+    assert_eq!(resolved.exclude, strings(&["no-empty", "no-explicit-any"]));
+  }
+
+  #[test]
+  fn options_are_deep_merged() {
+    let fs = MemoryFs::default()
+      .with(
+        "/repo/deno.json",
+        json!({
+          "lint": {
+            "rules": {
+              "options": {
+                "max-lines": { "max": 300, "skipComments": true },
+                "no-restricted-jsx-elements": {
+                  "elements": [{ "name": "marquee" }]
+                },
+                "no-shadow": { "hoist": "all" }
+              }
+            }
+          }
+        }),
+      )
+      .with(
+        "/repo/app/deno.json",
+        json!({
+          "lint": {
+            "rules": {
+              "options": {
+                "max-lines": { "max": 500 },
+                "no-restricted-jsx-elements": {
+                  "elements": [{ "name": "blink" }]
+                },
+                "eqeqeq": "smart"
+              }
+            }
+          }
+        }),
+      );
+    let resolved = resolve(&fs, "/repo/app/main.tsx");
+    assert_eq!(
+      serde_json::Value::Object(resolved.options),
+      json!({
+        "max-lines": { "max": 500, "skipComments": true },
+        "no-restricted-jsx-elements": { "elements": [{ "name": "blink" }] },
+        "no-shadow": { "hoist": "all" },
+        "eqeqeq": "smart"
+      })
+    );
+  }
+
+  #[test]
+  fn files_without_lint_rules_are_skipped() {
+    let fs = MemoryFs::default()
+      .with(
+        "/repo/deno.json",
+        json!({ "lint": { "rules": { "exclude": ["no-empty"] } } }),
+      )
+      .with(
+        "/repo/app/deno.json",
+        json!({ "fmt": { "options": { "lineWidth": 80 } } }),
+      );
+    let resolved = resolve(&fs, "/repo/app/main.ts");
+    assert_eq!(resolved.exclude, strings(&["no-empty"]));
+    assert_eq!(
+      resolved.config_files,
+      vec![PathBuf::from("/repo/deno.json")]
+    );
+  }
+
+  #[test]
+  fn invalid_config_files() {
+    let mut fs = MemoryFs::default();
+    fs.0
+      .insert(PathBuf::from("/repo/deno.json"), "{ \"lint\": ".to_string());
+    let err = resolve_config_for_path(&fs, Path::new("/repo/mod.ts"))
+      .unwrap_err()
+      .to_string();
+    assert!(
+      err.starts_with("Failed to parse /repo/deno.json"),
+      "{}",
+      err
+    );
+
+    let fs = MemoryFs::default().with(
+      "/repo/deno.json",
+      json!({ "lint": { "rules": { "tags": "recommended" } } }),
+    );
+    let err = resolve_config_for_path(&fs, Path::new("/repo/mod.ts"))
+      .unwrap_err()
+      .to_string();
+    assert!(
+      err.starts_with("Invalid lint.rules in /repo/deno.json"),
+      "{}",
+      err
+    );
+  }
+
+  #[test]
+  fn resolved_config_rules() {
+    let fs = MemoryFs::default()
+      .with(
+        "/repo/deno.json",
+        json!({
+          "lint": {
+            "rules": { "tags": ["recommended"], "exclude": ["no-empty"] }
+          }
+        }),
+      )
+      .with(
+        "/repo/app/deno.json",
+        json!({ "lint": { "rules": { "include": ["eqeqeq"] } } }),
+      );
+    let codes = codes_of(&fs, "/repo/app/main.ts");
+    assert!(codes.contains(&"eqeqeq"));
+    assert!(codes.contains(&"no-debugger"));
+    assert!(!codes.contains(&"no-empty"));
+
+    // Without any tags, the recommended rules are used
+    let fs = MemoryFs::default().with(
+      "/repo/deno.json",
+      json!({ "lint": { "rules": { "exclude": ["no-empty"] } } }),
+    );
+    let codes = codes_of(&fs, "/repo/main.ts");
+    assert!(codes.contains(&"no-debugger"));
+    assert!(!codes.contains(&"no-empty"));
+    assert!(!codes.contains(&"eqeqeq"));
+  }
+  #[test]
+  fn resolved_config_rule_options() {
+    let fs = MemoryFs::default().with(
+      "/repo/deno.json",
+      json!({
+        "lint": {
+          "rules": {
+            "include": ["max-lines"],
+            "options": {
+              "max-lines": { "max": 1 },
+              "no-shadow": { "hoist": "all" }
+            }
+          }
+        }
+      }),
+    );
+    let rules = resolve(&fs, "/repo/main.ts").get_rules().unwrap();
+    let max_lines = rules
+      .into_iter()
+      .find(|rule| rule.code() == "max-lines")
+      .unwrap();
+    let linter = crate::linter::LinterBuilder::default()
+      .rules(vec![max_lines])
+      .build();
+    let (_, diagnostics) = linter
+      .lint("main.ts".to_string(), "let a;\nlet b;\n".to_string())
+      .unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "max-lines");
+
+    let invalid = [
+      (
+        json!({ "eqeqeq": { "hack": true } }),
+        "Invalid options for eqeqeq",
+      ),
+      (
+        json!({ "max-lines": { "max": 1, "hack": true } }),
+        "Invalid options for max-lines",
+      ),
+      (
+        json!({ "max-lines": { "max": "one" } }),
+        "Invalid options for max-lines",
+      ),
+      (
+        json!({ "no-such-rule": {} }),
+        "Unknown rule in lint.rules.options: no-such-rule",
+      ),
+    ];
+    for (options, expected) in invalid.iter() {
+      let fs = MemoryFs::default().with(
+        "/repo/deno.json",
+        json!({ "lint": { "rules": { "options": options } } }),
+      );
+      let err = resolve(&fs, "/repo/main.ts")
+        .get_rules()
+        .expect_err("options should be rejected")
+        .to_string();
+      assert!(err.starts_with(expected), "{}", err);
+    }
+  }
+}
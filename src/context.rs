@@ -1,16 +1,23 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use crate::control_flow::ControlFlow;
-use crate::diagnostic::{LintDiagnostic, Position, Range};
+use crate::diagnostic::{
+  qualified_code, Applicability, LintDiagnostic, LintFix, LintFixChange,
+  LintRelatedInformation, Position, Range,
+};
+use crate::globals::GlobalsEnv;
 use crate::ignore_directives::{
   CodeStatus, FileIgnoreDirective, LineIgnoreDirective,
 };
 use crate::rules::{get_all_rules, LintRule};
+use crate::scope_tree::ScopeTree;
 use crate::scopes::Scope;
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::{Span, SyntaxContext};
 use deno_ast::view as ast_view;
 use deno_ast::view::{BytePos, RootNode, SourceFile};
+use deno_ast::LineAndColumnIndex;
 use deno_ast::MediaType;
+use once_cell::unsync::OnceCell;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
@@ -44,9 +51,20 @@ pub struct Context<'view> {
   /// The key of the map is line number.
   line_ignore_directives: HashMap<usize, LineIgnoreDirective>,
 
+  /// The map that stores next-line ignore directives
+  /// (`deno-lint-ignore-next-line`). The key of the map is the line number
+  /// the directive applies to, i.e. the line after the directive.
+  next_line_ignore_directives: HashMap<usize, LineIgnoreDirective>,
+
   /// Scope analysis result
   scope: Scope,
 
+  /// Scope tree of the program, built when a rule first asks for it
+  scope_tree: OnceCell<ScopeTree>,
+
+  /// Globals that are known to exist at runtime
+  globals: Arc<GlobalsEnv>,
+
   /// Control-flow analysis result
   control_flow: ControlFlow,
 
@@ -66,7 +84,9 @@ impl<'view> Context<'view> {
     program: ast_view::Program<'view>,
     file_ignore_directive: Option<FileIgnoreDirective>,
     line_ignore_directives: HashMap<usize, LineIgnoreDirective>,
+    next_line_ignore_directives: HashMap<usize, LineIgnoreDirective>,
     scope: Scope,
+    globals: Arc<GlobalsEnv>,
     control_flow: ControlFlow,
     top_level_ctxt: SyntaxContext,
   ) -> Self {
@@ -77,7 +97,10 @@ impl<'view> Context<'view> {
       program,
       file_ignore_directive,
       line_ignore_directives,
+      next_line_ignore_directives,
       scope,
+      scope_tree: OnceCell::new(),
+      globals,
       control_flow,
       top_level_ctxt,
       diagnostics: Vec::new(),
@@ -93,6 +116,13 @@ impl<'view> Context<'view> {
     self.media_type
   }
 
+  /// Checks if the file may contain TypeScript syntax, i.e. it's not a plain
+  /// JavaScript or JSX file. Rules about type annotations can use this to
+  /// skip JavaScript files.
+  pub fn is_typescript(&self) -> bool {
+    !matches!(self.media_type, MediaType::JavaScript | MediaType::Jsx)
+  }
+
   pub fn diagnostics(&self) -> &[LintDiagnostic] {
     &self.diagnostics
   }
@@ -121,10 +151,45 @@ impl<'view> Context<'view> {
     &self.line_ignore_directives
   }
 
+  pub fn next_line_ignore_directives(
+    &self,
+  ) -> &HashMap<usize, LineIgnoreDirective> {
+    &self.next_line_ignore_directives
+  }
+
+  /// Iterates over both the line-level and the next-line ignore directives.
+  fn all_line_ignore_directives(
+    &self,
+  ) -> impl Iterator<Item = &LineIgnoreDirective> {
+    self
+      .line_ignore_directives
+      .values()
+      .chain(self.next_line_ignore_directives.values())
+  }
+
   pub fn scope(&self) -> &Scope {
     &self.scope
   }
 
+  /// Returns the tree of scopes of the program. It's built on the first call
+  /// and shared by all rules linting the file.
+  pub fn scope_tree(&self) -> &ScopeTree {
+    self
+      .scope_tree
+      .get_or_init(|| ScopeTree::analyze(self.program))
+  }
+
+  pub fn globals(&self) -> &GlobalsEnv {
+    &self.globals
+  }
+
+  /// Checks if `name` is a global that is known to exist at runtime. Note
+  /// that this doesn't take into account whether `name` is shadowed by a
+  /// local variable.
+  pub fn is_global(&self, name: &str) -> bool {
+    self.globals.contains(name)
+  }
+
   pub fn control_flow(&self) -> &ControlFlow {
     &self.control_flow
   }
@@ -175,30 +240,94 @@ impl<'view> Context<'view> {
       .trailing_comments(hi)
   }
 
+  /// Returns the comments directly preceding `span`, e.g. the comments
+  /// between a node and the token before it.
+  pub fn leading_comments(
+    &self,
+    span: Span,
+  ) -> impl Iterator<Item = &'view Comment> {
+    self.leading_comments_at(span.lo)
+  }
+
+  /// Returns the comments directly following `span`, e.g. the comments
+  /// between a node and the token after it.
+  pub fn trailing_comments(
+    &self,
+    span: Span,
+  ) -> impl Iterator<Item = &'view Comment> {
+    self.trailing_comments_at(span.hi)
+  }
+
+  /// Returns all the comments that are located inside `span`, in source
+  /// order.
+  pub fn comments_within(
+    &self,
+    span: Span,
+  ) -> impl Iterator<Item = &'view Comment> {
+    let mut comments: Vec<_> = self
+      .all_comments()
+      .filter(|comment| span.contains(comment.span))
+      .collect();
+    comments.sort_by_key(|comment| comment.span.lo);
+    comments.into_iter()
+  }
+
   /// Mark ignore directives as used if that directive actually suppresses some
-  /// diagnostic, and return a list of diagnostics that are not ignored.
-  /// Make sure that this method is called after all lint rules have been
-  /// executed.
-  pub(crate) fn check_ignore_directive_usage(&mut self) -> Vec<LintDiagnostic> {
+  /// of the diagnostics reported since `from`, and return a list of those
+  /// diagnostics that are not ignored.
+  /// Make sure that every diagnostic is checked before running
+  /// `ban-unused-ignore`.
+  pub(crate) fn check_ignore_directive_usage(
+    &mut self,
+    from: usize,
+  ) -> Vec<LintDiagnostic> {
     let mut filtered = Vec::new();
 
-    for diagnostic in self.diagnostics.iter().cloned() {
+    for diagnostic in self.diagnostics[from..].iter().cloned() {
       if let Some(f) = self.file_ignore_directive.as_mut() {
-        if f.check_used(&diagnostic.code) {
+        if f.check_used_by(&diagnostic) {
           continue;
         }
       }
 
+      // A line can't hold both a `deno-lint-ignore` and a
+      // `deno-lint-ignore-next-line` comment, so at most one of them applies
+      // to the line of the diagnostic from the previous line. A trailing
+      // `deno-lint-ignore` on the line of the diagnostic applies as well.
       let diagnostic_line = diagnostic.range.start.line_index;
+      if let Some(l) = self
+        .line_ignore_directives
+        .get_mut(&diagnostic_line)
+        .filter(|l| l.is_trailing())
+      {
+        if l.check_used_by(&diagnostic) {
+          continue;
+        }
+      }
       if diagnostic_line > 0 {
         if let Some(l) =
           self.line_ignore_directives.get_mut(&(diagnostic_line - 1))
         {
-          if l.check_used(&diagnostic.code) {
+          if l.check_used_by(&diagnostic) {
             continue;
           }
         }
       }
+      // Next-line directives with a count apply to several lines. If their
+      // ranges overlap, the one closest to the diagnostic applies.
+      if let Some((_, l)) = self
+        .next_line_ignore_directives
+        .iter_mut()
+        .filter(|(first_line, l)| {
+          (**first_line..**first_line + l.line_count())
+            .contains(&diagnostic_line)
+        })
+        .max_by_key(|(first_line, _)| **first_line)
+      {
+        if l.check_used_by(&diagnostic) {
+          continue;
+        }
+      }
 
       filtered.push(diagnostic);
     }
@@ -217,16 +346,14 @@ impl<'view> Context<'view> {
 
     // If there's a file-level ignore directive containing `ban-unused-ignore`,
     // exit without running this rule.
-    if self
-      .file_ignore_directive
-      .as_ref()
-      .map_or(false, |file_ignore| file_ignore.has_code(CODE))
-    {
+    if matches!(
+      &self.file_ignore_directive,
+      Some(file_ignore) if file_ignore.has_code(CODE)
+    ) {
       return vec![];
     }
 
-    let executed_builtin_codes: HashSet<&'static str> =
-      specified_rules.iter().map(|r| r.code()).collect();
+    let executed_builtin_codes = rule_codes(specified_rules);
     let is_unused_code = |&(code, status): &(&String, &CodeStatus)| {
       let is_unknown = !executed_builtin_codes.contains(code.as_str())
         && !self.plugin_codes.contains(code.as_str());
@@ -249,7 +376,7 @@ impl<'view> Context<'view> {
       }
     }
 
-    for line_ignore in self.line_ignore_directives.values() {
+    for line_ignore in self.all_line_ignore_directives() {
       // We do nothing special even if the line-level ignore directive contains
       // `ban-unused-ignore`. `ban-unused-ignore` can be ignored only via the
       // file-level directive.
@@ -275,8 +402,7 @@ impl<'view> Context<'view> {
   /// currently we collect the rule codes of plugins as they are run and thus
   /// there's no way of knowing what are the "known" rule codes beforehand.
   pub(crate) fn ban_unknown_rule_code(&self) -> Vec<LintDiagnostic> {
-    let builtin_all_rule_codes: HashSet<&'static str> =
-      get_all_rules().iter().map(|r| r.code()).collect();
+    let builtin_all_rule_codes = rule_codes(&get_all_rules());
     let is_unknown_rule = |code: &&String| {
       !builtin_all_rule_codes.contains(code.as_str())
         && !self.plugin_codes.contains(code.as_str())
@@ -298,7 +424,7 @@ impl<'view> Context<'view> {
       }
     }
 
-    for line_ignore in self.line_ignore_directives.values() {
+    for line_ignore in self.all_line_ignore_directives() {
       for unknown_rule_code in
         line_ignore.codes().keys().filter(is_unknown_rule)
       {
@@ -338,6 +464,141 @@ impl<'view> Context<'view> {
     self.diagnostics.push(diagnostic);
   }
 
+  /// Fills in `applicability` for the fixes of the diagnostics added since the
+  /// first `from` ones that don't specify their own.
+  pub(crate) fn resolve_fix_applicability(
+    &mut self,
+    from: usize,
+    applicability: Applicability,
+  ) {
+    for diagnostic in &mut self.diagnostics[from..] {
+      for fix in &mut diagnostic.fixes {
+        fix.applicability.get_or_insert(applicability);
+      }
+    }
+  }
+
+  /// Add a diagnostic along with automatic fixes for it. Fixes can be created
+  /// using `Context::fix_change`.
+  pub fn add_diagnostic_with_fixes(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    maybe_hint: Option<String>,
+    fixes: Vec<LintFix>,
+  ) {
+    let mut diagnostic =
+      self.create_diagnostic(span, code, message, maybe_hint);
+    diagnostic.fixes = fixes;
+    self.diagnostics.push(diagnostic);
+  }
+
+  /// Add a diagnostic that points to additional related locations. Related
+  /// locations can be created using `Context::related_information`.
+  pub fn add_diagnostic_with_related(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    maybe_hint: Option<String>,
+    related: Vec<LintRelatedInformation>,
+  ) {
+    let mut diagnostic =
+      self.create_diagnostic(span, code, message, maybe_hint);
+    diagnostic.related = related;
+    self.diagnostics.push(diagnostic);
+  }
+
+  /// Add a diagnostic carrying structured data for consumers, e.g.
+  /// `json!({ "key": "foo" })`.
+  pub fn add_diagnostic_with_data(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    maybe_hint: Option<String>,
+    data: serde_json::Value,
+  ) {
+    let mut diagnostic =
+      self.create_diagnostic(span, code, message, maybe_hint);
+    diagnostic.data = Some(data);
+    self.diagnostics.push(diagnostic);
+  }
+
+  /// Add a diagnostic with a more specific code within the rule, which must be
+  /// one of its `LintRule::sub_codes`. An ignore directive for `code`
+  /// suppresses the diagnostics of every sub-code, while one for e.g.
+  /// `code/sub_code` only suppresses those of that sub-code.
+  pub fn add_diagnostic_with_sub_code(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    sub_code: impl ToString,
+    message: impl ToString,
+    maybe_hint: Option<String>,
+    fixes: Vec<LintFix>,
+  ) {
+    let mut diagnostic =
+      self.create_diagnostic(span, code, message, maybe_hint);
+    diagnostic.sub_code = Some(sub_code.to_string());
+    diagnostic.fixes = fixes;
+    self.diagnostics.push(diagnostic);
+  }
+
+  /// Create a related location of a diagnostic pointing at `span`.
+  pub fn related_information(
+    &self,
+    span: Span,
+    message: impl ToString,
+  ) -> LintRelatedInformation {
+    LintRelatedInformation {
+      range: self.range_of(span),
+      message: message.to_string(),
+      filename: None,
+    }
+  }
+
+  /// Create a change of a fix that replaces the text at `span` with
+  /// `new_text`.
+  pub fn fix_change(
+    &self,
+    span: Span,
+    new_text: impl ToString,
+  ) -> LintFixChange {
+    LintFixChange {
+      new_text: new_text.to_string(),
+      range: self.range_of(span),
+    }
+  }
+
+  fn range_of(&self, span: Span) -> Range {
+    Range {
+      start: self.position_of(span.lo()),
+      end: self.position_of(span.hi()),
+    }
+  }
+
+  /// Resolves a byte position to its line and column. The column is counted
+  /// in UTF-16 code units, as editors and the LSP do.
+  fn position_of(&self, pos: BytePos) -> Position {
+    let line_index = self.source_file.line_index(pos);
+    let line_start = self.source_file.line_start(line_index);
+    let file_start = self.source_file.span().lo();
+    let text = self.source_file.text();
+    let column_index = text
+      [(line_start - file_start).0 as usize..(pos - file_start).0 as usize]
+      .encode_utf16()
+      .count();
+    Position::new(
+      pos,
+      LineAndColumnIndex {
+        line_index,
+        column_index,
+      },
+    )
+  }
+
   pub(crate) fn create_diagnostic(
     &self,
     span: Span,
@@ -346,21 +607,18 @@ impl<'view> Context<'view> {
     maybe_hint: Option<String>,
   ) -> LintDiagnostic {
     let time_start = Instant::now();
-    let start = Position::new(
-      span.lo(),
-      self.source_file.line_and_column_index(span.lo()),
-    );
-    let end = Position::new(
-      span.hi(),
-      self.source_file.line_and_column_index(span.hi()),
-    );
 
     let diagnostic = LintDiagnostic {
-      range: Range { start, end },
+      range: self.range_of(span),
+      span,
       filename: self.file_name.clone(),
       message: message.to_string(),
       code: code.to_string(),
+      sub_code: None,
       hint: maybe_hint,
+      fixes: Vec::new(),
+      related: Vec::new(),
+      data: None,
     };
 
     let time_end = Instant::now();
@@ -376,6 +634,19 @@ impl<'view> Context<'view> {
   }
 }
 
+/// Returns the codes of the rules, including the codes qualified with their
+/// sub-codes, e.g. `jsx-curly-brace-presence/missing`.
+fn rule_codes(rules: &[Arc<dyn LintRule>]) -> HashSet<String> {
+  let mut codes = HashSet::new();
+  for rule in rules {
+    codes.insert(rule.code().to_string());
+    for sub_code in rule.sub_codes() {
+      codes.insert(qualified_code(rule.code(), sub_code));
+    }
+  }
+  codes
+}
+
 /// A struct containing a boolean value to control whether a node's children
 /// will be traversed or not.
 /// If there's no need to further traverse children nodes, you can call
@@ -405,3 +676,77 @@ impl TraverseFlow {
     stop
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util;
+  use deno_ast::swc::common::Spanned;
+
+  fn test_context(source_code: &str, test: impl Fn(&Context, &[Span])) {
+    let parsed_source = test_util::parse(source_code);
+    let control_flow = ControlFlow::analyze(parsed_source.program_ref().into());
+    parsed_source.with_view(|program| {
+      let stmt_spans: Vec<Span> = match program {
+        ast_view::Program::Module(module) => {
+          module.body.iter().map(|item| item.span()).collect()
+        }
+        ast_view::Program::Script(script) => {
+          script.body.iter().map(|stmt| stmt.span()).collect()
+        }
+      };
+      let context = Context::new(
+        "lint_test.ts".to_string(),
+        MediaType::TypeScript,
+        parsed_source.source(),
+        program,
+        None,
+        HashMap::new(),
+        HashMap::new(),
+        Scope::analyze(program),
+        Arc::new(GlobalsEnv::default()),
+        control_flow,
+        SyntaxContext::empty(),
+      );
+      test(&context, &stmt_spans);
+    });
+  }
+
+  fn texts<'a>(comments: impl Iterator<Item = &'a Comment>) -> Vec<String> {
+    comments.map(|c| c.text.trim().to_string()).collect()
+  }
+
+  #[test]
+  fn leading_and_trailing_comments() {
+    let source_code = r#"
+// a
+foo(); // b
+/* c */ bar(); /* d */
+// e
+"#;
+    test_context(source_code, |ctx, stmts| {
+      assert_eq!(texts(ctx.leading_comments(stmts[0])), vec!["a"]);
+      assert_eq!(texts(ctx.trailing_comments(stmts[0])), vec!["b", "c"]);
+      assert_eq!(texts(ctx.leading_comments(stmts[1])), vec!["b", "c"]);
+      assert_eq!(texts(ctx.trailing_comments(stmts[1])), vec!["d", "e"]);
+    });
+  }
+
+  #[test]
+  fn comments_within() {
+    let source_code = r#"
+// a
+function foo() {
+  // b
+  bar(); /* c */
+  // d
+}
+// e
+switch (x) {}
+"#;
+    test_context(source_code, |ctx, stmts| {
+      assert_eq!(texts(ctx.comments_within(stmts[0])), vec!["b", "c", "d"]);
+      assert!(ctx.comments_within(stmts[1]).next().is_none());
+    });
+  }
+}
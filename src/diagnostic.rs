@@ -1,6 +1,7 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use serde::Serialize;
 use serde::Serializer;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -9,7 +10,7 @@ pub struct Position {
   #[serde(rename(serialize = "line"))]
   #[serde(serialize_with = "to_one_indexed")]
   pub line_index: usize,
-  /// The 0-indexed column index.
+  /// The 0-indexed column index, counted in UTF-16 code units.
   #[serde(rename(serialize = "col"))]
   pub column_index: usize,
   pub byte_pos: usize,
@@ -41,11 +42,131 @@ pub struct Range {
   pub end: Position,
 }
 
+/// A single text edit of a fix; `range` is replaced with `new_text`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintFixChange {
+  pub new_text: String,
+  pub range: Range,
+}
+
+/// How safe it is to apply a fix without reviewing it. Variants are ordered
+/// from the safest to the least safe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Applicability {
+  /// The fix keeps the behavior of the code, so it can be applied without
+  /// asking, e.g. on save.
+  Automatic,
+  /// The fix is likely what's intended, but should be confirmed by the user.
+  Suggestion,
+  /// The fix may change the behavior of the code.
+  Unsafe,
+}
+
+impl Default for Applicability {
+  fn default() -> Self {
+    Applicability::Suggestion
+  }
+}
+
+/// An automatic fix proposed by a rule, consisting of one or more changes that
+/// must be applied together.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LintFix {
+  /// Short description of the fix, e.g. "Convert to a type alias".
+  pub description: String,
+  pub changes: Vec<LintFixChange>,
+  /// How safe the fix is to apply. `None` stands for the default of the rule
+  /// that produced the fix, which the linter fills in.
+  pub applicability: Option<Applicability>,
+}
+
+/// An additional location related to a diagnostic, e.g. the place where a
+/// conflicting declaration was made.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LintRelatedInformation {
+  pub range: Range,
+  pub message: String,
+  /// The file the location is in if it was mapped to a different one than
+  /// the linted file, see `SourceMap::remap_diagnostic`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub filename: Option<String>,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct LintDiagnostic {
+  /// The resolved location of `span`, so consumers don't need the source file.
   pub range: Range,
+  /// The raw span of the diagnostic within the linted source.
+  #[serde(skip)]
+  pub span: deno_ast::swc::common::Span,
   pub filename: String,
   pub message: String,
   pub code: String,
+  /// A more specific code within the rule, e.g. `missing` for the
+  /// `jsx-curly-brace-presence/missing` diagnostics. It's one of the rule's
+  /// `LintRule::sub_codes`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sub_code: Option<String>,
   pub hint: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub fixes: Vec<LintFix>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub related: Vec<LintRelatedInformation>,
+  /// Structured details defined by the rule, e.g. the duplicated key, so
+  /// consumers don't need to parse them out of `message`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub data: Option<serde_json::Value>,
+}
+
+impl LintDiagnostic {
+  /// Returns the code including the sub-code, e.g.
+  /// `jsx-curly-brace-presence/missing`, or `None` if the diagnostic has no
+  /// sub-code.
+  pub fn qualified_code(&self) -> Option<String> {
+    self
+      .sub_code
+      .as_ref()
+      .map(|sub_code| qualified_code(&self.code, sub_code))
+  }
+}
+
+/// Joins a rule code and one of its sub-codes, e.g.
+/// `jsx-curly-brace-presence/missing`.
+pub fn qualified_code(code: &str, sub_code: &str) -> String {
+  format!("{}/{}", code, sub_code)
+}
+
+/// A diagnostic paired with one of its fixes, e.g. to be offered as a code
+/// action by an editor. The edits of `fix` carry both byte offsets and
+/// resolved line and column positions.
+#[derive(Clone, Debug, Serialize)]
+pub struct DiagnosticWithFix {
+  pub diagnostic: LintDiagnostic,
+  pub fix: LintFix,
+}
+
+/// Counts of a set of diagnostics, e.g. to print "15 problems (15 errors, 0
+/// warnings)" followed by a breakdown per rule. Diagnostics don't have a
+/// severity yet, so every one of them counts as an error and `warnings` is
+/// always 0.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintSummary {
+  pub total: usize,
+  pub errors: usize,
+  pub warnings: usize,
+  /// The number of diagnostics per rule code, ordered by code.
+  pub by_code: BTreeMap<String, usize>,
+}
+
+pub fn summarize(diagnostics: &[LintDiagnostic]) -> LintSummary {
+  let mut summary = LintSummary::default();
+  for diagnostic in diagnostics {
+    summary.total += 1;
+    summary.errors += 1;
+    *summary.by_code.entry(diagnostic.code.clone()).or_insert(0) += 1;
+  }
+  summary
 }
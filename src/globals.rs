@@ -1,4 +1,138 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Whether a global variable can be reassigned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GlobalAccess {
+  Readonly,
+  Writable,
+}
+
+impl From<bool> for GlobalAccess {
+  fn from(writable: bool) -> Self {
+    if writable {
+      GlobalAccess::Writable
+    } else {
+      GlobalAccess::Readonly
+    }
+  }
+}
+
+/// A predefined set of globals of a runtime environment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GlobalsPreset {
+  /// Globals of Deno, including the web APIs it implements.
+  Deno,
+  /// Globals of browser main threads.
+  Browser,
+  /// Globals of Node.js, including CommonJS module variables.
+  Node,
+  /// Builtin globals of ECMAScript 2021 only.
+  Es2021,
+  /// Globals of web workers.
+  Worker,
+}
+
+impl GlobalsPreset {
+  fn globals(self) -> Vec<&'static [(&'static str, bool)]> {
+    match self {
+      GlobalsPreset::Deno => vec![GLOBALS],
+      GlobalsPreset::Browser => vec![ES2021_GLOBALS, BROWSER_GLOBALS],
+      GlobalsPreset::Node => vec![ES2021_GLOBALS, NODE_GLOBALS],
+      GlobalsPreset::Es2021 => vec![ES2021_GLOBALS],
+      GlobalsPreset::Worker => vec![ES2021_GLOBALS, WORKER_GLOBALS],
+    }
+  }
+}
+
+/// The set of globals that are known to exist when linting a file, shared by
+/// rules that need to tell globals from undeclared variables.
+///
+/// The default set contains the globals of Deno.
+#[derive(Clone, Debug)]
+pub struct GlobalsEnv {
+  globals: HashMap<String, GlobalAccess>,
+}
+
+impl Default for GlobalsEnv {
+  fn default() -> Self {
+    Self::from_presets(&[GlobalsPreset::Deno])
+  }
+}
+
+impl GlobalsEnv {
+  /// Create an environment without any globals.
+  pub fn empty() -> Self {
+    Self {
+      globals: HashMap::new(),
+    }
+  }
+
+  /// Create an environment containing the globals of all `presets`.
+  pub fn from_presets(presets: &[GlobalsPreset]) -> Self {
+    let mut env = Self::empty();
+    for preset in presets {
+      env.add_preset(*preset);
+    }
+    env
+  }
+
+  /// Add the globals of `preset`. Globals that are already known keep their
+  /// access.
+  pub fn add_preset(&mut self, preset: GlobalsPreset) -> &mut Self {
+    for list in preset.globals() {
+      for (name, writable) in list {
+        self
+          .globals
+          .entry(name.to_string())
+          .or_insert_with(|| (*writable).into());
+      }
+    }
+    self
+  }
+
+  /// Add a global, overriding the access of an already known one.
+  pub fn add_global(
+    &mut self,
+    name: impl Into<String>,
+    access: GlobalAccess,
+  ) -> &mut Self {
+    self.globals.insert(name.into(), access);
+    self
+  }
+
+  /// Add user-provided globals, e.g. from a configuration file, overriding
+  /// the access of already known ones.
+  pub fn merge(
+    &mut self,
+    globals: impl IntoIterator<Item = (String, GlobalAccess)>,
+  ) -> &mut Self {
+    self.globals.extend(globals);
+    self
+  }
+
+  pub fn contains(&self, name: &str) -> bool {
+    self.globals.contains_key(name)
+  }
+
+  pub fn access(&self, name: &str) -> Option<GlobalAccess> {
+    self.globals.get(name).copied()
+  }
+
+  /// Returns all the globals, ordered by name.
+  pub(crate) fn sorted(&self) -> Vec<(&str, GlobalAccess)> {
+    let mut globals: Vec<_> = self
+      .globals
+      .iter()
+      .map(|(name, access)| (name.as_str(), *access))
+      .collect();
+    globals.sort_unstable_by_key(|(name, _)| *name);
+    globals
+  }
+}
 
 /// List of globals available in Deno environment.
 ///
@@ -177,3 +311,299 @@ pub static GLOBALS: &[(&str, bool)] = &[
   ("WorkerNavigator", false),
   ("WritableStream", false),
 ];
+
+/// List of builtin globals of ECMAScript 2021.
+pub static ES2021_GLOBALS: &[(&str, bool)] = &[
+  ("AggregateError", false),
+  ("Array", false),
+  ("ArrayBuffer", false),
+  ("Atomics", false),
+  ("BigInt", false),
+  ("BigInt64Array", false),
+  ("BigUint64Array", false),
+  ("Boolean", false),
+  ("DataView", false),
+  ("Date", false),
+  ("decodeURI", false),
+  ("decodeURIComponent", false),
+  ("encodeURI", false),
+  ("encodeURIComponent", false),
+  ("Error", false),
+  ("escape", false),
+  ("eval", false),
+  ("EvalError", false),
+  ("FinalizationRegistry", false),
+  ("Float32Array", false),
+  ("Float64Array", false),
+  ("Function", false),
+  ("globalThis", false),
+  ("Infinity", false),
+  ("Int16Array", false),
+  ("Int32Array", false),
+  ("Int8Array", false),
+  ("Intl", false),
+  ("isFinite", false),
+  ("isNaN", false),
+  ("JSON", false),
+  ("Map", false),
+  ("Math", false),
+  ("NaN", false),
+  ("Number", false),
+  ("Object", false),
+  ("parseFloat", false),
+  ("parseInt", false),
+  ("Promise", false),
+  ("Proxy", false),
+  ("RangeError", false),
+  ("ReferenceError", false),
+  ("Reflect", false),
+  ("RegExp", false),
+  ("Set", false),
+  ("SharedArrayBuffer", false),
+  ("String", false),
+  ("Symbol", false),
+  ("SyntaxError", false),
+  ("TypeError", false),
+  ("Uint16Array", false),
+  ("Uint32Array", false),
+  ("Uint8Array", false),
+  ("Uint8ClampedArray", false),
+  ("undefined", false),
+  ("unescape", false),
+  ("URIError", false),
+  ("WeakMap", false),
+  ("WeakRef", false),
+  ("WeakSet", false),
+];
+
+/// List of globals available in browsers, in addition to the ECMAScript ones.
+pub static BROWSER_GLOBALS: &[(&str, bool)] = &[
+  ("AbortController", false),
+  ("AbortSignal", false),
+  ("addEventListener", false),
+  ("alert", false),
+  ("atob", false),
+  ("Blob", false),
+  ("btoa", false),
+  ("caches", false),
+  ("cancelAnimationFrame", false),
+  ("clearInterval", false),
+  ("clearTimeout", false),
+  ("close", false),
+  ("closed", false),
+  ("confirm", false),
+  ("console", false),
+  ("crypto", false),
+  ("customElements", false),
+  ("CustomEvent", false),
+  ("document", false),
+  ("Document", false),
+  ("DOMException", false),
+  ("Element", false),
+  ("Event", false),
+  ("EventTarget", false),
+  ("fetch", false),
+  ("File", false),
+  ("FileReader", false),
+  ("FormData", false),
+  ("getComputedStyle", false),
+  ("Headers", false),
+  ("history", false),
+  ("HTMLElement", false),
+  ("Image", false),
+  ("indexedDB", false),
+  ("IntersectionObserver", false),
+  ("localStorage", false),
+  ("location", false),
+  ("matchMedia", false),
+  ("MessageChannel", false),
+  ("MessageEvent", false),
+  ("MutationObserver", false),
+  ("name", true),
+  ("navigator", false),
+  ("Node", false),
+  ("onerror", true),
+  ("onload", true),
+  ("onmessage", true),
+  ("onunload", true),
+  ("open", false),
+  ("performance", false),
+  ("postMessage", false),
+  ("prompt", false),
+  ("queueMicrotask", false),
+  ("ReadableStream", false),
+  ("removeEventListener", false),
+  ("Request", false),
+  ("requestAnimationFrame", false),
+  ("ResizeObserver", false),
+  ("Response", false),
+  ("screen", false),
+  ("self", false),
+  ("sessionStorage", false),
+  ("setInterval", false),
+  ("setTimeout", false),
+  ("status", true),
+  ("structuredClone", false),
+  ("TextDecoder", false),
+  ("TextEncoder", false),
+  ("TransformStream", false),
+  ("URL", false),
+  ("URLSearchParams", false),
+  ("WebSocket", false),
+  ("window", false),
+  ("Window", false),
+  ("Worker", false),
+  ("WritableStream", false),
+  ("XMLHttpRequest", false),
+];
+
+/// List of globals available in Node.js, in addition to the ECMAScript ones.
+pub static NODE_GLOBALS: &[(&str, bool)] = &[
+  ("__dirname", false),
+  ("__filename", false),
+  ("AbortController", false),
+  ("AbortSignal", false),
+  ("atob", false),
+  ("btoa", false),
+  ("Buffer", false),
+  ("clearImmediate", false),
+  ("clearInterval", false),
+  ("clearTimeout", false),
+  ("console", false),
+  ("Event", false),
+  ("EventTarget", false),
+  ("exports", true),
+  ("fetch", false),
+  ("global", false),
+  ("Headers", false),
+  ("MessageChannel", false),
+  ("MessageEvent", false),
+  ("MessagePort", false),
+  ("module", false),
+  ("performance", false),
+  ("process", false),
+  ("queueMicrotask", false),
+  ("Request", false),
+  ("require", false),
+  ("Response", false),
+  ("setImmediate", false),
+  ("setInterval", false),
+  ("setTimeout", false),
+  ("structuredClone", false),
+  ("TextDecoder", false),
+  ("TextEncoder", false),
+  ("URL", false),
+  ("URLSearchParams", false),
+];
+
+/// List of globals available in web workers, in addition to the ECMAScript
+/// ones.
+pub static WORKER_GLOBALS: &[(&str, bool)] = &[
+  ("addEventListener", false),
+  ("atob", false),
+  ("Blob", false),
+  ("btoa", false),
+  ("caches", false),
+  ("clearInterval", false),
+  ("clearTimeout", false),
+  ("close", false),
+  ("console", false),
+  ("crypto", false),
+  ("CustomEvent", false),
+  ("DOMException", false),
+  ("Event", false),
+  ("EventTarget", false),
+  ("fetch", false),
+  ("FileReader", false),
+  ("FormData", false),
+  ("Headers", false),
+  ("importScripts", false),
+  ("indexedDB", false),
+  ("location", false),
+  ("MessageChannel", false),
+  ("MessageEvent", false),
+  ("navigator", false),
+  ("onerror", true),
+  ("onmessage", true),
+  ("onmessageerror", true),
+  ("performance", false),
+  ("postMessage", false),
+  ("queueMicrotask", false),
+  ("ReadableStream", false),
+  ("removeEventListener", false),
+  ("Request", false),
+  ("Response", false),
+  ("self", false),
+  ("setInterval", false),
+  ("setTimeout", false),
+  ("structuredClone", false),
+  ("TextDecoder", false),
+  ("TextEncoder", false),
+  ("TransformStream", false),
+  ("URL", false),
+  ("URLSearchParams", false),
+  ("WebSocket", false),
+  ("WorkerGlobalScope", false),
+  ("WorkerLocation", false),
+  ("WorkerNavigator", false),
+  ("WritableStream", false),
+];
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_env_is_deno() {
+    let env = GlobalsEnv::default();
+    assert!(env.contains("Deno"));
+    assert!(env.contains("Array"));
+    assert!(!env.contains("process"));
+    assert_eq!(env.access("onload"), Some(GlobalAccess::Writable));
+    assert_eq!(env.access("Array"), Some(GlobalAccess::Readonly));
+  }
+
+  #[test]
+  fn presets() {
+    let es = GlobalsEnv::from_presets(&[GlobalsPreset::Es2021]);
+    assert!(es.contains("Promise"));
+    assert!(!es.contains("window"));
+    assert!(!es.contains("Deno"));
+
+    let browser = GlobalsEnv::from_presets(&[GlobalsPreset::Browser]);
+    assert!(browser.contains("Promise"));
+    assert!(browser.contains("document"));
+    assert!(!browser.contains("require"));
+
+    let node = GlobalsEnv::from_presets(&[GlobalsPreset::Node]);
+    assert!(node.contains("require"));
+    assert_eq!(node.access("exports"), Some(GlobalAccess::Writable));
+    assert!(!node.contains("document"));
+
+    let worker = GlobalsEnv::from_presets(&[GlobalsPreset::Worker]);
+    assert!(worker.contains("importScripts"));
+    assert!(!worker.contains("document"));
+
+    let both =
+      GlobalsEnv::from_presets(&[GlobalsPreset::Browser, GlobalsPreset::Node]);
+    assert!(both.contains("document"));
+    assert!(both.contains("require"));
+  }
+
+  #[test]
+  fn user_globals() {
+    let mut env = GlobalsEnv::empty();
+    assert!(!env.contains("Array"));
+
+    env
+      .add_preset(GlobalsPreset::Es2021)
+      .add_global("__DEV__", GlobalAccess::Readonly)
+      .merge(vec![("Array".to_string(), GlobalAccess::Writable)]);
+    assert_eq!(env.access("__DEV__"), Some(GlobalAccess::Readonly));
+    assert_eq!(env.access("Array"), Some(GlobalAccess::Writable));
+
+    // Presets don't override explicitly configured globals
+    env.add_preset(GlobalsPreset::Deno);
+    assert_eq!(env.access("Array"), Some(GlobalAccess::Writable));
+  }
+}
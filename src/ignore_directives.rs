@@ -1,4 +1,5 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::diagnostic::LintDiagnostic;
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::comments::CommentKind;
 use deno_ast::swc::common::Span;
@@ -21,6 +22,11 @@ impl DirectiveKind for File {}
 pub struct IgnoreDirective<T: DirectiveKind> {
   span: Span,
   codes: HashMap<String, CodeStatus>,
+  /// Whether the directive is written after code on the same line.
+  trailing: bool,
+  /// The number of lines the directive applies to, starting at the line it
+  /// is keyed by.
+  line_count: usize,
   _marker: std::marker::PhantomData<T>,
 }
 
@@ -39,6 +45,19 @@ impl<T: DirectiveKind> IgnoreDirective<T> {
     &self.codes
   }
 
+  /// Whether the directive is written after code on the same line, e.g.
+  /// `foo(); // deno-lint-ignore no-explicit-any`.
+  pub fn is_trailing(&self) -> bool {
+    self.trailing
+  }
+
+  /// The number of consecutive lines the directive applies to, which is only
+  /// more than one for next-line directives with a count, e.g.
+  /// `// deno-lint-ignore-next-lines 3 no-explicit-any`.
+  pub fn line_count(&self) -> usize {
+    self.line_count
+  }
+
   pub fn has_code(&self, code: &str) -> bool {
     self.codes.contains_key(code)
   }
@@ -51,6 +70,18 @@ impl<T: DirectiveKind> IgnoreDirective<T> {
       false
     }
   }
+
+  /// Checks if the directive suppresses the diagnostic, either by the rule
+  /// code or by the code including the sub-code, and marks the matching codes
+  /// as used.
+  pub fn check_used_by(&mut self, diagnostic: &LintDiagnostic) -> bool {
+    let by_code = self.check_used(&diagnostic.code);
+    let by_qualified_code = match diagnostic.qualified_code() {
+      Some(qualified_code) => self.check_used(&qualified_code),
+      None => false,
+    };
+    by_code || by_qualified_code
+  }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -64,27 +95,78 @@ impl CodeStatus {
   }
 }
 
+/// Parses line-level ignore directives (`deno-lint-ignore`). The key of the
+/// returned map is the index of the line the directive is written on, and it
+/// applies to diagnostics starting on the following line. A trailing
+/// directive, written after code, also applies to its own line.
 pub fn parse_line_ignore_directives(
   ignore_diagnostic_directive: &str,
   program: ast_view::Program,
 ) -> HashMap<usize, LineIgnoreDirective> {
+  parse_line_comments(program, |comment| {
+    parse_ignore_comment(ignore_diagnostic_directive, comment)
+  })
+}
+
+/// Parses the comments of the program with `parse`, keying the directives by
+/// the index of the line they are written on.
+fn parse_line_comments<F>(
+  program: ast_view::Program,
+  parse: F,
+) -> HashMap<usize, LineIgnoreDirective>
+where
+  F: Fn(&Comment) -> Option<LineIgnoreDirective>,
+{
+  let source_file = program.source_file().unwrap();
   program
     .comments()
     .unwrap()
     .all_comments()
     .filter_map(|comment| {
-      parse_ignore_comment(ignore_diagnostic_directive, comment).map(
-        |directive| {
-          (
-            program.source_file().unwrap().line_index(directive.span.lo),
-            directive,
-          )
-        },
-      )
+      parse(comment).map(|mut directive: LineIgnoreDirective| {
+        let line_index = source_file.line_index(directive.span.lo);
+        let line_start = source_file.line_start(line_index);
+        let file_start = source_file.span().lo;
+        directive.trailing = !source_file.text()[(line_start - file_start).0
+          as usize
+          ..(directive.span.lo - file_start).0 as usize]
+          .trim()
+          .is_empty();
+        (line_index, directive)
+      })
     })
     .collect()
 }
 
+/// Parses next-line ignore directives (`deno-lint-ignore-next-line`). The key
+/// of the returned map is the index of the line following the directive,
+/// which is the first line it applies to, even if that line is blank.
+///
+/// The directive may be followed by a positive number of lines it applies
+/// to, in which case it can also be written in plural, e.g.
+/// `// deno-lint-ignore-next-lines 3 no-explicit-any`. Without a count it
+/// applies to the next line only. A malformed count is warned about and
+/// ignored.
+pub fn parse_next_line_ignore_directives(
+  ignore_next_line_directive: &str,
+  program: ast_view::Program,
+) -> HashMap<usize, LineIgnoreDirective> {
+  parse_line_comments(program, |comment| {
+    parse_next_line_ignore_comment(ignore_next_line_directive, comment)
+  })
+  .into_iter()
+  .map(|(line_index, directive)| {
+    (
+      line_index + 1,
+      IgnoreDirective {
+        trailing: false,
+        ..directive
+      },
+    )
+  })
+  .collect()
+}
+
 pub fn parse_file_ignore_directives(
   ignore_global_directive: &str,
   program: ast_view::Program,
@@ -100,43 +182,104 @@ fn parse_ignore_comment<T: DirectiveKind>(
   ignore_diagnostic_directive: &str,
   comment: &Comment,
 ) -> Option<IgnoreDirective<T>> {
+  let codes = strip_directive(ignore_diagnostic_directive, comment)?;
+  Some(IgnoreDirective::<T> {
+    span: comment.span,
+    codes: parse_codes(codes),
+    trailing: false,
+    line_count: 1,
+    _marker: std::marker::PhantomData,
+  })
+}
+
+fn parse_next_line_ignore_comment(
+  ignore_next_line_directive: &str,
+  comment: &Comment,
+) -> Option<LineIgnoreDirective> {
+  let plural_directive = format!("{}s", ignore_next_line_directive);
+  let (rest, is_plural) =
+    match strip_directive(ignore_next_line_directive, comment) {
+      Some(rest) => (rest, false),
+      None => (strip_directive(&plural_directive, comment)?, true),
+    };
+
+  // Rule codes start with a letter, so a leading word starting with a digit
+  // or a sign is meant as the count.
+  let rest = rest.trim_start();
+  let count = rest
+    .split(|c: char| c.is_whitespace() || c == ',')
+    .next()
+    .filter(|word| {
+      word.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+')
+    });
+  let (line_count, codes) = match count {
+    Some(count) => {
+      let line_count = match count.parse::<usize>() {
+        Ok(line_count) if line_count > 0 => line_count,
+        _ => {
+          warn!(
+            "Ignoring malformed line count \"{}\" of `{}`, expected a positive integer",
+            count, comment.text.trim()
+          );
+          1
+        }
+      };
+      (line_count, &rest[count.len()..])
+    }
+    None => {
+      if is_plural {
+        warn!(
+          "Missing line count of `{}`, expected a positive integer",
+          comment.text.trim()
+        );
+      }
+      (1, rest)
+    }
+  };
+
+  Some(IgnoreDirective {
+    span: comment.span,
+    codes: parse_codes(codes),
+    trailing: false,
+    line_count,
+    _marker: std::marker::PhantomData,
+  })
+}
+
+/// Returns the text following `directive` if the comment is a line comment
+/// starting with it.
+fn strip_directive<'a>(
+  directive: &str,
+  comment: &'a Comment,
+) -> Option<&'a str> {
   if comment.kind != CommentKind::Line {
     return None;
   }
 
   let comment_text = comment.text.trim();
-
-  if let Some(prefix) = comment_text.split_whitespace().next() {
-    if prefix == ignore_diagnostic_directive {
-      let comment_text = comment_text
-        .strip_prefix(ignore_diagnostic_directive)
-        .unwrap();
-
-      static IGNORE_COMMENT_CODE_RE: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r",\s*|\s").unwrap());
-
-      let comment_text = IGNORE_COMMENT_CODE_RE.replace_all(comment_text, ",");
-      let codes = comment_text
-        .split(',')
-        .filter_map(|code| {
-          if code.is_empty() {
-            None
-          } else {
-            let code = code.trim().to_string();
-            Some((code, CodeStatus::default()))
-          }
-        })
-        .collect();
-
-      return Some(IgnoreDirective::<T> {
-        span: comment.span,
-        codes,
-        _marker: std::marker::PhantomData,
-      });
-    }
+  if comment_text.split_whitespace().next()? == directive {
+    comment_text.strip_prefix(directive)
+  } else {
+    None
   }
+}
 
-  None
+fn parse_codes(text: &str) -> HashMap<String, CodeStatus> {
+  static IGNORE_COMMENT_CODE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r",\s*|\s").unwrap());
+
+  let text = IGNORE_COMMENT_CODE_RE.replace_all(text, ",");
+  text
+    .split(',')
+    .filter_map(|code| {
+      if code.is_empty() {
+        None
+      } else {
+        let code = code.trim().to_string();
+        Some((code, CodeStatus::default()))
+      }
+    })
+    .collect()
 }
 
 #[cfg(test)]
@@ -197,6 +340,77 @@ object | undefined {}
       );
       let d = line_directives.get(&16).unwrap();
       assert_eq!(d.codes, code_map(["ban-types"]));
+      assert!(d.is_trailing());
+      assert!(!line_directives.get(&1).unwrap().is_trailing());
+    });
+  }
+
+  #[test]
+  fn test_parse_next_line_ignore_comments() {
+    let source_code = r#"
+// deno-lint-ignore-next-line no-explicit-any no-empty
+function foo(): any {}
+
+// deno-lint-ignore no-explicit-any
+function foo(): any {}
+// deno-lint-ignore-next-line no-debugger
+
+debugger;
+  "#;
+
+    test_util::parse_and_then(source_code, |program| {
+      let next_line_directives = parse_next_line_ignore_directives(
+        "deno-lint-ignore-next-line",
+        program,
+      );
+
+      assert_eq!(next_line_directives.len(), 2);
+      let d = next_line_directives.get(&2).unwrap();
+      assert_eq!(d.codes, code_map(["no-explicit-any", "no-empty"]));
+      let d = next_line_directives.get(&7).unwrap();
+      assert_eq!(d.codes, code_map(["no-debugger"]));
+
+      let line_directives =
+        parse_line_ignore_directives("deno-lint-ignore", program);
+      assert_eq!(line_directives.len(), 1);
+      assert!(line_directives.contains_key(&4));
+    });
+  }
+
+  #[test]
+  fn test_parse_next_line_ignore_comments_with_count() {
+    let source_code = r#"
+// deno-lint-ignore-next-lines 3 no-explicit-any
+// deno-lint-ignore-next-line 2, no-empty
+// deno-lint-ignore-next-line no-debugger
+// deno-lint-ignore-next-lines no-var
+// deno-lint-ignore-next-lines 0 no-var
+// deno-lint-ignore-next-line -2 no-var
+// deno-lint-ignore-next-lines 1.5 no-var
+  "#;
+
+    test_util::parse_and_then(source_code, |program| {
+      let next_line_directives = parse_next_line_ignore_directives(
+        "deno-lint-ignore-next-line",
+        program,
+      );
+
+      assert_eq!(next_line_directives.len(), 7);
+      let d = next_line_directives.get(&2).unwrap();
+      assert_eq!(d.codes, code_map(["no-explicit-any"]));
+      assert_eq!(d.line_count(), 3);
+      let d = next_line_directives.get(&3).unwrap();
+      assert_eq!(d.codes, code_map(["no-empty"]));
+      assert_eq!(d.line_count(), 2);
+      let d = next_line_directives.get(&4).unwrap();
+      assert_eq!(d.codes, code_map(["no-debugger"]));
+      assert_eq!(d.line_count(), 1);
+      // Malformed counts are ignored, not taken as codes.
+      for line_index in 5..=8 {
+        let d = next_line_directives.get(&line_index).unwrap();
+        assert_eq!(d.codes, code_map(["no-var"]));
+        assert_eq!(d.line_count(), 1);
+      }
     });
   }
 
@@ -0,0 +1,238 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::ast_parser::AstParser;
+use crate::linter::LintError;
+use deno_ast::swc::ast::{ModuleDecl, ModuleItem, Program};
+use deno_ast::MediaType;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+/// The static imports between a set of files, for rules that need to look
+/// beyond the file being linted, like `no-cycle`.
+///
+/// Files are identified by their file name with `.` and `..` segments
+/// resolved and a `file://` prefix removed, so the graph must be built with
+/// the same file names that are later passed to `Linter::lint`.
+#[derive(Debug, Default)]
+pub struct ImportGraph {
+  imports: BTreeMap<String, Vec<String>>,
+}
+
+impl ImportGraph {
+  /// Parses `files`, given as file name and source code pairs, and collects
+  /// the files each of them imports.
+  ///
+  /// Only relative and absolute specifiers are resolved; an extensionless
+  /// specifier refers to the file with the same path and any extension.
+  /// Type-only imports and `import()` calls are left out, since they don't
+  /// load the module when the importing one is evaluated.
+  pub fn build(files: &[(&str, &str)]) -> Result<Self, LintError> {
+    let ast_parser = AstParser::new();
+    let paths: HashSet<String> = files
+      .iter()
+      .map(|(file_name, _)| normalize_file_name(file_name))
+      .collect();
+
+    let mut imports = BTreeMap::new();
+    for (file_name, source_code) in files {
+      let media_type = match MediaType::from(Path::new(file_name)) {
+        MediaType::Unknown => MediaType::TypeScript,
+        media_type => media_type,
+      };
+      let parsed_source = ast_parser.parse_program(
+        file_name,
+        deno_ast::get_syntax(media_type),
+        source_code.to_string(),
+      )?;
+      let targets = static_import_specifiers(parsed_source.program_ref())
+        .into_iter()
+        .filter_map(|specifier| resolve(file_name, specifier))
+        .filter_map(|path| find_file(&paths, &path))
+        .collect();
+      imports.insert(normalize_file_name(file_name), targets);
+    }
+    Ok(ImportGraph { imports })
+  }
+
+  /// Returns the files imported by `file_name`.
+  pub fn imports_of(&self, file_name: &str) -> &[String] {
+    self
+      .imports
+      .get(&normalize_file_name(file_name))
+      .map_or(&[], |imports| imports.as_slice())
+  }
+
+  /// Returns the shortest chain of imports leading from `from` to `to`,
+  /// including both ends, among chains of at most `max_depth` imports.
+  pub fn find_path(
+    &self,
+    from: &str,
+    to: &str,
+    max_depth: Option<usize>,
+  ) -> Option<Vec<String>> {
+    let from = normalize_file_name(from);
+    let to = normalize_file_name(to);
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    let mut queue = VecDeque::new();
+    queue.push_back((from.as_str(), 0));
+
+    while let Some((file, depth)) = queue.pop_front() {
+      if file == to {
+        let mut path = vec![file.to_string()];
+        let mut file = file;
+        while let Some(prev) = previous.get(file) {
+          path.push(prev.to_string());
+          file = prev;
+        }
+        path.reverse();
+        return Some(path);
+      }
+      if matches!(max_depth, Some(max_depth) if depth >= max_depth) {
+        continue;
+      }
+      for import in self.imports_of(file) {
+        if import != &from && !previous.contains_key(import.as_str()) {
+          previous.insert(import, file);
+          queue.push_back((import, depth + 1));
+        }
+      }
+    }
+    None
+  }
+}
+
+fn static_import_specifiers(program: &Program) -> Vec<&str> {
+  let module = match program {
+    Program::Module(module) => module,
+    Program::Script(_) => return vec![],
+  };
+  module
+    .body
+    .iter()
+    .filter_map(|item| match item {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import))
+        if !import.type_only =>
+      {
+        Some(&*import.src.value)
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export))
+        if !export.type_only =>
+      {
+        export.src.as_ref().map(|src| &*src.value)
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export)) => {
+        Some(&*export.src.value)
+      }
+      _ => None,
+    })
+    .collect()
+}
+
+/// Returns the file of `paths` that `path` refers to, either exactly or with
+/// an extension added.
+fn find_file(paths: &HashSet<String>, path: &str) -> Option<String> {
+  if paths.contains(path) {
+    return Some(path.to_string());
+  }
+  paths
+    .iter()
+    .find(|file| strip_extension(file) == Some(path))
+    .cloned()
+}
+
+pub(crate) fn strip_extension(path: &str) -> Option<&str> {
+  match path.rfind('.') {
+    Some(index) if !path[index..].contains('/') => Some(&path[..index]),
+    _ => None,
+  }
+}
+
+/// Resolves `.` and `..` segments of a `/`-separated path.
+pub(crate) fn normalize(path: &str) -> String {
+  let mut segments: Vec<&str> = Vec::new();
+  for segment in path.split('/') {
+    match segment {
+      "." => {}
+      ".." if matches!(segments.last(), Some(last) if *last != "..") => {
+        segments.pop();
+      }
+      _ => segments.push(segment),
+    }
+  }
+  segments.join("/")
+}
+
+pub(crate) fn normalize_file_name(file_name: &str) -> String {
+  normalize(file_name.strip_prefix("file://").unwrap_or(file_name))
+}
+
+/// Returns the path `specifier` refers to when imported from `file_name`, or
+/// `None` for bare specifiers like `react`, whose path isn't known.
+pub(crate) fn resolve(file_name: &str, specifier: &str) -> Option<String> {
+  let file_name = file_name.strip_prefix("file://").unwrap_or(file_name);
+  let path = if specifier.starts_with("./") || specifier.starts_with("../") {
+    let dir = match file_name.rfind('/') {
+      Some(index) => &file_name[..index],
+      None => ".",
+    };
+    format!("{}/{}", dir, specifier)
+  } else if let Some(path) = specifier.strip_prefix("file://") {
+    path.to_string()
+  } else if specifier.starts_with('/') {
+    specifier.to_string()
+  } else {
+    return None;
+  };
+  Some(normalize(&path))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn import_graph_resolves_specifiers() {
+    let graph = ImportGraph::build(&[
+      (
+        "file:///src/a.ts",
+        "import './b.ts'; export * from '../lib/c';",
+      ),
+      (
+        "file:///src/b.ts",
+        "import type { A } from './a.ts'; import 'd';",
+      ),
+      ("file:///lib/c.ts", "export { a } from '/src/a.ts';"),
+    ])
+    .unwrap();
+
+    assert_eq!(graph.imports_of("/src/a.ts"), ["/src/b.ts", "/lib/c.ts"]);
+    assert!(graph.imports_of("file:///src/b.ts").is_empty());
+    assert_eq!(graph.imports_of("file:///lib/c.ts"), ["/src/a.ts"]);
+    assert!(graph.imports_of("file:///src/d.ts").is_empty());
+  }
+
+  #[test]
+  fn import_graph_find_path() {
+    let graph = ImportGraph::build(&[
+      ("a.ts", "import './b.ts'; import './c.ts';"),
+      ("b.ts", "import './c.ts';"),
+      ("c.ts", "import './a.ts';"),
+    ])
+    .unwrap();
+
+    assert_eq!(
+      graph.find_path("b.ts", "a.ts", None).unwrap(),
+      ["b.ts", "c.ts", "a.ts"]
+    );
+    assert_eq!(graph.find_path("a.ts", "a.ts", None).unwrap(), ["a.ts"]);
+    assert!(graph.find_path("b.ts", "a.ts", Some(1)).is_none());
+    assert!(graph.find_path("b.ts", "a.ts", Some(2)).is_some());
+  }
+
+  #[test]
+  fn import_graph_parse_error() {
+    assert!(matches!(
+      ImportGraph::build(&[("a.ts", "import from;")]),
+      Err(LintError::Parse(_))
+    ));
+  }
+}
@@ -8,18 +8,22 @@ extern crate log;
 mod test_util;
 
 pub mod ast_parser;
+pub mod config;
 pub mod context;
 // TODO(magurotuna): Making control_flow public is just needed for implementing plugin prototype.
 // It will be likely possible to remove `pub` later.
 pub mod control_flow;
 pub mod diagnostic;
-mod globals;
+pub mod globals;
 mod handler;
 mod ignore_directives;
+pub mod import_graph;
 mod js_regex;
 pub mod linter;
 pub mod rules;
+mod scope_tree;
 mod scopes;
+pub mod source_map;
 pub mod swc_util;
 
 pub use deno_ast::view::Program;
@@ -49,7 +53,7 @@ mod lint_tests {
   ) -> Vec<LintDiagnostic> {
     let linter = LinterBuilder::default().rules(rules).build();
 
-    linter.lint_with_ast(parsed_source)
+    linter.lint_with_ast(parsed_source).expect("Failed to lint")
   }
 
   fn lint_recommended_rules(source: &str) -> Vec<LintDiagnostic> {
@@ -164,6 +168,41 @@ const _foo = 42;
     assert_diagnostic(&diagnostics[1], "ban-unused-ignore", 2, 0, src);
   }
 
+  #[test]
+  fn ignore_directives_with_sub_codes() {
+    use crate::rules::jsx_curly_brace_presence::JsxCurlyBracePresence;
+
+    let src = r#"
+// deno-lint-ignore jsx-curly-brace-presence
+const a = <App>{'a'}</App>;
+// deno-lint-ignore jsx-curly-brace-presence/unnecessary
+const b = <App>{'b'}</App>;
+// deno-lint-ignore jsx-curly-brace-presence/missing
+const c = <App>{'c'}</App>;
+// deno-lint-ignore jsx-curly-brace-presence/unknown
+const d = <App />;
+"#;
+    let linter = LinterBuilder::default()
+      .media_type(deno_ast::MediaType::Tsx)
+      .rules(vec![JsxCurlyBracePresence::new()])
+      .build();
+    let (_, diagnostics) = linter
+      .lint("lint_test.tsx".to_string(), src.to_string())
+      .expect("Failed to lint");
+
+    // A directive for the rule code suppresses every sub-code, one for a
+    // qualified code only suppresses that sub-code.
+    assert_eq!(diagnostics.len(), 3);
+    assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 6, 0, src);
+    assert_diagnostic(&diagnostics[1], "jsx-curly-brace-presence", 7, 15, src);
+    assert_eq!(diagnostics[1].sub_code.as_deref(), Some("unnecessary"));
+    assert_eq!(
+      diagnostics[1].qualified_code().as_deref(),
+      Some("jsx-curly-brace-presence/unnecessary")
+    );
+    assert_diagnostic(&diagnostics[2], "ban-unknown-rule-code", 8, 0, src);
+  }
+
   #[test]
   fn file_directive_with_code() {
     let diagnostics = lint_recommended_rules(
@@ -210,10 +249,955 @@ const _foo = 42;
     assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 4, 1, src);
   }
 
+  #[test]
+  fn linter_is_reusable() {
+    use crate::rules::no_explicit_any::NoExplicitAny;
+    use crate::rules::no_var::NoVar;
+
+    let linter = LinterBuilder::default()
+      .media_type(deno_ast::MediaType::TypeScript)
+      .rules(vec![NoVar::new(), NoExplicitAny::new()])
+      .build();
+
+    let src1 = "var a = 1;\nconst b: any = 2;";
+    let (_, diagnostics) = linter
+      .lint("a.ts".to_string(), src1.to_string())
+      .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-var", 1, 0, src1);
+    assert_diagnostic(&diagnostics[1], "no-explicit-any", 2, 9, src1);
+
+    let src2 = "let c = 3;";
+    let (_, diagnostics) = linter
+      .lint("b.ts".to_string(), src2.to_string())
+      .expect("Failed to lint");
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn linter_builder_custom_directives() {
+    use crate::rules::no_var::NoVar;
+
+    let linter = LinterBuilder::default()
+      .ignore_file_directive("my-lint-ignore-file")
+      .ignore_diagnostic_directive("my-lint-ignore")
+      .rules(vec![NoVar::new()])
+      .build();
+
+    let src = r#"
+// my-lint-ignore no-var
+var a = 1;
+// deno-lint-ignore no-var
+var b = 2;
+"#;
+    let (_, diagnostics) = linter
+      .lint("a.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-var", 5, 0, src);
+
+    let (_, diagnostics) = linter
+      .lint(
+        "b.ts".to_string(),
+        "// my-lint-ignore-file\nvar a = 1;".to_string(),
+      )
+      .expect("Failed to lint");
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn ignore_next_line_directive() {
+    use crate::rules::no_explicit_any::NoExplicitAny;
+    use crate::rules::no_var::NoVar;
+
+    let src = r#"
+// deno-lint-ignore-next-line no-explicit-any
+const a: any = 1;
+const b = 2; // deno-lint-ignore-next-line no-var
+var c = 3;
+// deno-lint-ignore-next-line no-var
+
+var d = 4;
+"#;
+    let diagnostics = lint(src, vec![NoVar::new(), NoExplicitAny::new()]);
+    // A blank line between the directive and the code makes the directive
+    // apply to the blank line only.
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 6, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-var", 8, 0, src);
+  }
+
+  #[test]
+  fn ignore_next_lines_directive() {
+    use crate::rules::no_explicit_any::NoExplicitAny;
+    use crate::rules::no_var::NoVar;
+
+    let src = r#"
+// deno-lint-ignore-next-lines 2 no-var
+var a = 1;
+var b: any = 2;
+var c = 3;
+// deno-lint-ignore-next-line 3 no-var no-explicit-any
+var d = 4;
+
+var e: any = 5;
+"#;
+    let diagnostics = lint(src, vec![NoVar::new(), NoExplicitAny::new()]);
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-explicit-any", 4, 7, src);
+    assert_diagnostic(&diagnostics[1], "no-var", 5, 0, src);
+  }
+
+  #[test]
+  fn ignore_next_lines_directive_at_end_of_file() {
+    use crate::rules::no_var::NoVar;
+
+    // The last line is covered, and a count reaching past the end of the file
+    // is fine.
+    let src = "var a = 1;\n// deno-lint-ignore-next-lines 2 no-var\nvar b = 2;\nvar c = 3;";
+    let diagnostics = lint(src, vec![NoVar::new()]);
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-var", 1, 0, src);
+
+    let src = "// deno-lint-ignore-next-lines 5 no-var\nvar a = 1;";
+    assert!(lint(src, vec![NoVar::new()]).is_empty());
+
+    // A directive on the last line applies to nothing.
+    let src = "var a = 1;\n// deno-lint-ignore-next-lines 2 no-var";
+    let diagnostics = lint(src, vec![NoVar::new()]);
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-var", 1, 0, src);
+    assert_diagnostic(&diagnostics[1], "ban-unused-ignore", 2, 0, src);
+  }
+
+  #[test]
+  fn ignore_next_line_directive_with_line_directive() {
+    use crate::rules::no_explicit_any::NoExplicitAny;
+    use crate::rules::no_var::NoVar;
+
+    // Each directive applies to the line right after it.
+    let src = r#"
+// deno-lint-ignore no-var
+// deno-lint-ignore-next-line no-var
+var a: any = 1;
+"#;
+    let diagnostics = lint(src, vec![NoVar::new(), NoExplicitAny::new()]);
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 2, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-explicit-any", 4, 7, src);
+
+    let src = r#"
+// deno-lint-ignore-next-line no-var
+// deno-lint-ignore no-explicit-any
+var a: any = 1;
+"#;
+    let diagnostics = lint(src, vec![NoVar::new(), NoExplicitAny::new()]);
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 2, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-var", 4, 0, src);
+  }
+
+  #[test]
+  fn ignore_next_line_directive_untagged_and_unknown() {
+    let src = r#"
+// deno-lint-ignore-next-line
+export const a = 1;
+// deno-lint-ignore-next-line some-rule
+export const b = 1;
+"#;
+    let diagnostics = lint_recommended_rules(src);
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "ban-untagged-ignore", 2, 0, src);
+    assert_diagnostic(&diagnostics[1], "ban-unknown-rule-code", 4, 0, src);
+  }
+
+  #[test]
+  fn trailing_ignore_directive() {
+    use crate::rules::no_explicit_any::NoExplicitAny;
+    use crate::rules::no_var::NoVar;
+
+    let src = r#"
+var a: any = 1; // deno-lint-ignore no-explicit-any
+var b: any = 2; // deno-lint-ignore no-var no-explicit-any
+var c = 3;
+const d = 4; // deno-lint-ignore no-var
+
+var e = 5;
+"#;
+    let diagnostics = lint(src, vec![NoVar::new(), NoExplicitAny::new()]);
+    // A trailing directive applies to its own line and to the next line.
+    assert_eq!(diagnostics.len(), 3);
+    assert_diagnostic(&diagnostics[0], "no-var", 2, 0, src);
+    assert_diagnostic(&diagnostics[1], "ban-unused-ignore", 5, 13, src);
+    assert_diagnostic(&diagnostics[2], "no-var", 7, 0, src);
+  }
+
+  #[test]
+  fn trailing_next_line_directive_ignores_next_line_only() {
+    use crate::rules::no_var::NoVar;
+
+    let src = r#"
+var a = 1; // deno-lint-ignore-next-line no-var
+var b = 2;
+"#;
+    let diagnostics = lint(src, vec![NoVar::new()]);
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-var", 2, 0, src);
+  }
+
+  #[test]
+  fn linter_builder_report_unused_ignore() {
+    let src = r#"
+// deno-lint-ignore no-explicit-any
+const _foo = 42;
+"#;
+    let linter = LinterBuilder::default()
+      .report_unused_ignore(false)
+      .rules(get_recommended_rules())
+      .build();
+    let (_, diagnostics) = linter
+      .lint("a.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+    assert!(diagnostics.is_empty());
+
+    let diagnostics = lint_recommended_rules(src);
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 2, 0, src);
+  }
+
+  #[test]
+  fn linter_builder_globals() {
+    use crate::globals::{GlobalAccess, GlobalsEnv, GlobalsPreset};
+    use crate::rules::{no_global_assign::NoGlobalAssign, no_undef::NoUndef};
+
+    let src = "Deno.exit(); require('fs'); __DEV__ = false;";
+    let rules = || -> Vec<Arc<dyn LintRule>> {
+      vec![NoUndef::new(), NoGlobalAssign::new()]
+    };
+
+    let diagnostics = lint(src, rules());
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-undef", 1, 13, src);
+    assert_diagnostic(&diagnostics[1], "no-undef", 1, 28, src);
+
+    let mut globals = GlobalsEnv::from_presets(&[GlobalsPreset::Node]);
+    globals.add_global("__DEV__", GlobalAccess::Readonly);
+    let linter = LinterBuilder::default()
+      .globals(globals)
+      .rules(rules())
+      .build();
+    let (_, mut diagnostics) = linter
+      .lint("a.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+    diagnostics.sort_by_key(|d| d.range.start.column_index);
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-undef", 1, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-global-assign", 1, 28, src);
+  }
+
+  #[test]
+  fn lint_to_diagnostics_infers_media_type() {
+    let src = "const a = <div>{1 as any}</div>;";
+    let diagnostics = lint_to_diagnostics(
+      "component.tsx",
+      src,
+      vec![crate::rules::no_explicit_any::NoExplicitAny::new()],
+    )
+    .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-explicit-any", 1, 21, src);
+    assert_eq!(diagnostics[0].filename, "component.tsx");
+
+    assert!(lint_to_diagnostics("component.ts", src, vec![]).is_err());
+  }
+
+  #[test]
+  fn compute_fixes_returns_edit_ranges() {
+    let src = "let a = 1;\n  var b = 2, c = 3;\nif (a) { var d; }\n";
+    let fixes =
+      compute_fixes("file.ts", src, vec![crate::rules::no_var::NoVar::new()])
+        .expect("Failed to lint");
+    assert_eq!(fixes.len(), 1);
+    assert_eq!(fixes[0].diagnostic.code, "no-var");
+    assert_eq!(fixes[0].diagnostic.range.start.line_index, 1);
+    assert_eq!(fixes[0].fix.description, "Replace `var` with `let`");
+
+    let changes = &fixes[0].fix.changes;
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].new_text, "let");
+    let range = &changes[0].range;
+    assert_eq!((range.start.byte_pos, range.end.byte_pos), (13, 16));
+    assert_eq!((range.start.line_index, range.start.column_index), (1, 2));
+    assert_eq!((range.end.line_index, range.end.column_index), (1, 5));
+    assert_eq!(&src[range.start.byte_pos..range.end.byte_pos], "var");
+  }
+
+  #[test]
+  fn apply_single_fix_applies_only_the_chosen_fix() {
+    use crate::linter::{apply_single_fix, ApplyFixError};
+    use crate::rules::no_unescaped_entities::NoUnescapedEntities;
+    use crate::rules::no_var::NoVar;
+
+    let src = "var a = 1;\nvar b = <p>'</p>;\nvar c = 3;\n";
+    let fixes = compute_fixes(
+      "file.tsx",
+      src,
+      vec![NoVar::new(), NoUnescapedEntities::new()],
+    )
+    .expect("Failed to lint");
+    assert_eq!(fixes.len(), 7);
+    let find = |line_index: usize, description: &str| {
+      fixes
+        .iter()
+        .find(|fix| {
+          fix.diagnostic.range.start.line_index == line_index
+            && fix.fix.description == description
+        })
+        .unwrap()
+    };
+
+    assert_eq!(
+      apply_single_fix(src, find(1, "Replace `var` with `let`")).unwrap(),
+      "var a = 1;\nlet b = <p>'</p>;\nvar c = 3;\n"
+    );
+    assert_eq!(
+      apply_single_fix(src, find(1, "Replace with `&#39;`")).unwrap(),
+      "var a = 1;\nvar b = <p>&#39;</p>;\nvar c = 3;\n"
+    );
+
+    // The buffer changed since the fixes were computed.
+    let last = find(2, "Replace `var` with `let`");
+    let byte_pos = last.fix.changes[0].range.start.byte_pos;
+    let edited = "var a = 10;\nvar b = <p>'</p>;\nvar c = 3;\n";
+    assert_eq!(
+      apply_single_fix(edited, last),
+      Err(ApplyFixError::StalePosition(byte_pos))
+    );
+    assert_eq!(
+      apply_single_fix("var a = 1;\n", last),
+      Err(ApplyFixError::InvalidPosition(byte_pos))
+    );
+
+    let mut overlapping = find(0, "Replace `var` with `let`").clone();
+    let change = overlapping.fix.changes[0].clone();
+    overlapping.fix.changes.push(change);
+    assert_eq!(
+      apply_single_fix(src, &overlapping),
+      Err(ApplyFixError::OverlappingChanges)
+    );
+  }
+
+  #[test]
+  fn fix_source_applies_fixes_until_convergence() {
+    use crate::diagnostic::Applicability;
+    use crate::rules::no_var::NoVar;
+    use crate::rules::one_var::{OneVar, OneVarMode, OneVarOptions};
+
+    let rules = || -> Vec<Arc<dyn LintRule>> {
+      vec![
+        NoVar::new(),
+        OneVar::with_options(OneVarOptions {
+          mode: OneVarMode::Never,
+          ..Default::default()
+        }),
+      ]
+    };
+    let src = "var a = 1, b = 2;\nif (a) { var c = b; }\n";
+    let fix = |max_passes| {
+      fix_source_with_applicability(
+        "file.ts",
+        src,
+        rules(),
+        max_passes,
+        Applicability::Suggestion,
+      )
+      .expect("Failed to lint")
+    };
+
+    // The first pass replaces the first `var` with `let` and splits the
+    // declaration into a new `var` declaration, which the second pass fixes.
+    let (fixed, diagnostics) = fix(1);
+    assert_eq!(fixed, "let a = 1; var b = 2;\nif (a) { var c = b; }\n");
+    assert_eq!(diagnostics.len(), 2);
+
+    let (fixed, diagnostics) = fix(10);
+    assert_eq!(fixed, "let a = 1; let b = 2;\nif (a) { var c = b; }\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-var", 2, 9, &fixed);
+    assert!(diagnostics[0].fixes.is_empty());
+  }
+
+  #[test]
+  fn fix_source_resolves_overlapping_fixes() {
+    use crate::diagnostic::Applicability;
+    use crate::rules::no_var::NoVar;
+    use crate::rules::one_var::OneVar;
+
+    // Combining the declarations removes the second `var`, so replacing it
+    // with `let` is skipped rather than applied to the combined text.
+    let src = "var a; var b;";
+    let (fixed, diagnostics) = fix_source_with_applicability(
+      "file.ts",
+      src,
+      vec![NoVar::new(), OneVar::new()],
+      1,
+      Applicability::Suggestion,
+    )
+    .expect("Failed to lint");
+    assert_eq!(fixed, "let a, b;");
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn fix_source_only_applies_automatic_fixes() {
+    use crate::diagnostic::Applicability;
+    use crate::rules::no_var::NoVar;
+    use crate::rules::one_var::OneVar;
+
+    let src = "var a; var b;";
+    let rules =
+      || -> Vec<Arc<dyn LintRule>> { vec![NoVar::new(), OneVar::new()] };
+
+    let fixes = compute_fixes("file.ts", src, rules()).expect("Failed to lint");
+    let applicability = fixes
+      .iter()
+      .map(|fix| (fix.diagnostic.code.as_str(), fix.fix.applicability))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      applicability,
+      vec![
+        ("no-var", Some(Applicability::Suggestion)),
+        ("no-var", Some(Applicability::Suggestion)),
+        ("one-var", Some(Applicability::Automatic)),
+      ]
+    );
+
+    let (fixed, diagnostics) =
+      fix_source("file.ts", src, rules(), 10).expect("Failed to lint");
+    assert_eq!(fixed, "var a, b;");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "no-var");
+  }
+
+  #[test]
+  fn fix_source_for_codes_only_applies_listed_fixes() {
+    use crate::diagnostic::Applicability;
+    use crate::linter::fix_source_for_codes;
+    use crate::rules::no_var::NoVar;
+    use crate::rules::one_var::OneVar;
+
+    let src = "var a; var b;";
+    let rules =
+      || -> Vec<Arc<dyn LintRule>> { vec![NoVar::new(), OneVar::new()] };
+    let fix = |codes: &[&str]| {
+      fix_source_for_codes(
+        "file.ts",
+        src,
+        rules(),
+        10,
+        Applicability::Suggestion,
+        codes,
+      )
+      .expect("Failed to lint")
+    };
+
+    // The fix of `one-var` overlaps the second `var`, but as it isn't listed
+    // it doesn't keep the second `var` from being fixed.
+    let (fixed, diagnostics) = fix(&["no-var"]);
+    assert_eq!(fixed, "let a; let b;");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "one-var");
+    assert!(!diagnostics[0].fixes.is_empty());
+
+    let (fixed, diagnostics) = fix(&["one-var"]);
+    assert_eq!(fixed, "var a, b;");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "no-var");
+
+    let (fixed, diagnostics) = fix(&[]);
+    assert_eq!(fixed, src);
+    assert_eq!(diagnostics.len(), 3);
+  }
+
+  #[test]
+  fn diagnostic_columns_are_utf16() {
+    let src = "const s = '😀β'; debugger;\n'😀'; debugger;";
+    let diagnostics = lint_to_diagnostics(
+      "file.ts",
+      src,
+      vec![crate::rules::no_debugger::NoDebugger::new()],
+    )
+    .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-debugger", 1, 17, src);
+    assert_eq!(diagnostics[0].range.end.column_index, 26);
+    assert_diagnostic(&diagnostics[1], "no-debugger", 2, 6, src);
+
+    let span = diagnostics[0].span;
+    assert_eq!(span.lo.0 as usize, diagnostics[0].range.start.byte_pos);
+    assert_eq!(span.hi.0 as usize, diagnostics[0].range.end.byte_pos);
+    assert_eq!(&src[span.lo.0 as usize..span.hi.0 as usize], "debugger;");
+  }
+
+  #[test]
+  fn linter_builder_source_map() {
+    use crate::rules::no_var::NoVar;
+    use crate::source_map::SourceMap;
+
+    // Only the first line is mapped, to line 4 column 2 of `original.ts`.
+    let source_map = SourceMap::from_json(
+      r#"{"version": 3, "sources": ["original.ts"], "mappings": "AAIE;"}"#,
+    )
+    .unwrap();
+    let linter = LinterBuilder::default()
+      .rules(vec![NoVar::new()])
+      .source_map(source_map)
+      .build();
+
+    let src = "var a = 1;\n  var b = 2;";
+    let (_, diagnostics) = linter
+      .lint("generated.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 2);
+
+    let range = &diagnostics[0].range;
+    assert_eq!((range.start.line_index, range.start.column_index), (4, 2));
+    assert_eq!((range.end.line_index, range.end.column_index), (4, 12));
+    assert_eq!((range.start.byte_pos, range.end.byte_pos), (0, 10));
+    assert_eq!(diagnostics[0].filename, "original.ts");
+
+    // Positions without a mapping fall back to the generated ones.
+    assert_diagnostic(&diagnostics[1], "no-var", 2, 2, src);
+    assert_eq!(diagnostics[1].range.start.byte_pos, 13);
+    assert_eq!(diagnostics[1].filename, "generated.ts");
+  }
+
+  #[test]
+  fn linter_builder_rule_filter() {
+    use crate::rules::no_explicit_any::NoExplicitAny;
+    use crate::rules::no_var::NoVar;
+    use deno_ast::MediaType;
+
+    let linter = LinterBuilder::default()
+      .media_type(MediaType::JavaScript)
+      .rules(vec![NoVar::new(), NoExplicitAny::new()])
+      .rule_filter(|rule, file_info| {
+        assert_eq!(file_info.file_name, "file:///foo.js");
+        assert_eq!(file_info.media_type, MediaType::JavaScript);
+        rule.code() != "no-var"
+      })
+      .build();
+
+    // The ignore directive isn't reported as unused since `no-var` is skipped.
+    let src = "var a = 1;\n// deno-lint-ignore no-var\nvar b = 2;";
+    let (_, diagnostics) = linter
+      .lint("file:///foo.js".to_string(), src.to_string())
+      .expect("Failed to lint");
+    assert!(diagnostics.is_empty());
+  }
+
   #[test]
   fn empty_file_with_ast() {
     let parsed_source = parse("");
     let diagnostics = lint_recommended_rules_with_ast(&parsed_source);
     assert!(diagnostics.is_empty());
   }
+
+  #[test]
+  fn malformed_source_is_a_parse_error() {
+    let linter = LinterBuilder::default()
+      .rules(get_recommended_rules())
+      .build();
+    let src = "const a = 1;\nconst b = ;\n";
+    match linter.lint("malformed.ts".to_string(), src.to_string()) {
+      Err(LintError::Parse(diagnostic)) => {
+        assert_eq!(diagnostic.filename, "malformed.ts");
+        assert_eq!(diagnostic.line_display, 2);
+        assert_eq!(diagnostic.column_display, 11);
+      }
+      _ => panic!("Expected a parse error"),
+    }
+
+    assert!(matches!(
+      lint_to_diagnostics("malformed.ts", src, get_recommended_rules()),
+      Err(LintError::Parse(_))
+    ));
+  }
+
+  #[test]
+  fn panicking_plugin_is_an_internal_error() {
+    #[derive(Debug)]
+    struct PanickingPlugin;
+
+    impl Plugin for PanickingPlugin {
+      fn run(
+        &self,
+        _context: &mut crate::context::Context,
+        _program: deno_ast::view::ProgramRef,
+      ) -> anyhow::Result<()> {
+        panic!("plugin failed");
+      }
+    }
+
+    let linter = LinterBuilder::default()
+      .plugins(vec![Arc::new(PanickingPlugin)])
+      .build();
+    match linter.lint("a.ts".to_string(), "let a = 1;".to_string()) {
+      Err(LintError::Internal(message)) => assert_eq!(message, "plugin failed"),
+      _ => panic!("Expected an internal error"),
+    }
+  }
+
+  #[test]
+  fn lint_with_callback_passes_diagnostics_after_each_rule() {
+    use crate::rules::no_var::NoVar;
+
+    #[derive(Debug)]
+    struct PanickingPlugin;
+
+    impl Plugin for PanickingPlugin {
+      fn run(
+        &self,
+        _context: &mut crate::context::Context,
+        _program: deno_ast::view::ProgramRef,
+      ) -> anyhow::Result<()> {
+        panic!("plugin failed");
+      }
+    }
+
+    // The plugin runs after the rules, so unless the diagnostics are sorted,
+    // those of `no-var` are passed before it panics
+    let lint_with = |sort: bool| {
+      let linter = LinterBuilder::default()
+        .rules(vec![NoVar::new()])
+        .plugins(vec![Arc::new(PanickingPlugin)])
+        .sort_diagnostics(sort)
+        .build();
+      let mut codes = vec![];
+      let result = linter.lint_with_callback(
+        "a.ts".to_string(),
+        "var a;".to_string(),
+        |d| codes.push(d.code),
+      );
+      assert!(matches!(result, Err(LintError::Internal(_))));
+      codes
+    };
+    assert!(lint_with(true).is_empty());
+    assert_eq!(lint_with(false), ["no-var"]);
+  }
+
+  #[test]
+  fn skip_untriggered_rules() {
+    use deno_ast::swc::common::Spanned;
+
+    #[derive(Debug)]
+    struct FooRule;
+
+    impl LintRule for FooRule {
+      fn new() -> Arc<Self> {
+        Arc::new(FooRule)
+      }
+
+      fn code(&self) -> &'static str {
+        "foo-rule"
+      }
+
+      fn trigger_tokens(&self) -> &'static [&'static str] {
+        &["foo", "bar"]
+      }
+
+      fn lint_program(
+        &self,
+        context: &mut crate::context::Context,
+        program: deno_ast::view::ProgramRef,
+      ) {
+        context.add_diagnostic(program.span(), "foo-rule", "Found foo");
+      }
+
+      #[cfg(feature = "docs")]
+      fn docs(&self) -> &'static str {
+        ""
+      }
+    }
+
+    let lint_with = |src: &str, skip: bool| {
+      let linter = LinterBuilder::default()
+        .rules(vec![FooRule::new()])
+        .skip_untriggered_rules(skip)
+        .build();
+      let (_, diagnostics) = linter
+        .lint("a.ts".to_string(), src.to_string())
+        .expect("Failed to lint");
+      diagnostics.into_iter().map(|d| d.code).collect::<Vec<_>>()
+    };
+
+    assert!(lint_with("let a = 1;", true).is_empty());
+    assert_eq!(lint_with("let a = 1;", false), ["foo-rule"]);
+    // Rules aren't skipped by default
+    let linter = LinterBuilder::default().rules(vec![FooRule::new()]).build();
+    let (_, diagnostics) = linter
+      .lint("a.ts".to_string(), "let a = 1;".to_string())
+      .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 1);
+    // Tokens in strings still make the rule run
+    assert_eq!(lint_with("let a = 'bar';", true), ["foo-rule"]);
+    // Ignore directives for skipped rules are unused
+    let linter = LinterBuilder::default()
+      .rules(vec![crate::rules::eqeqeq::Eqeqeq::new()])
+      .skip_untriggered_rules(true)
+      .build();
+    let (_, diagnostics) = linter
+      .lint(
+        "a.ts".to_string(),
+        "// deno-lint-ignore eqeqeq\nlet a = 1;".to_string(),
+      )
+      .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "ban-unused-ignore");
+  }
+
+  #[test]
+  fn lint_with_callback() {
+    use crate::rules::eqeqeq::Eqeqeq;
+    use crate::rules::no_var::NoVar;
+
+    let src = "var a = 1;\nif (a == 1) {}\nvar b = a;\n// deno-lint-ignore eqeqeq\nvar c = 2;";
+    let lint_with = |sort: bool| {
+      let linter = LinterBuilder::default()
+        .rules(vec![NoVar::new(), Eqeqeq::new()])
+        .sort_diagnostics(sort)
+        .build();
+      let mut diagnostics = vec![];
+      linter
+        .lint_with_callback("a.ts".to_string(), src.to_string(), |d| {
+          diagnostics.push((d.range.start.line_index, d.code))
+        })
+        .expect("Failed to lint");
+      diagnostics
+    };
+
+    let sorted = lint_with(true);
+    assert_eq!(
+      sorted,
+      [
+        (0, "no-var".to_string()),
+        (1, "eqeqeq".to_string()),
+        (2, "no-var".to_string()),
+        (3, "ban-unused-ignore".to_string()),
+        (4, "no-var".to_string()),
+      ]
+    );
+    let (_, diagnostics) = LinterBuilder::default()
+      .rules(vec![NoVar::new(), Eqeqeq::new()])
+      .build()
+      .lint("a.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+    assert_eq!(
+      diagnostics
+        .into_iter()
+        .map(|d| (d.range.start.line_index, d.code))
+        .collect::<Vec<_>>(),
+      sorted
+    );
+    // Without sorting, the diagnostics arrive in rule order
+    assert_eq!(
+      lint_with(false),
+      [
+        (1, "eqeqeq".to_string()),
+        (0, "no-var".to_string()),
+        (2, "no-var".to_string()),
+        (4, "no-var".to_string()),
+        (3, "ban-unused-ignore".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn summarize_diagnostics() {
+    use crate::diagnostic::summarize;
+    use crate::rules::eqeqeq::Eqeqeq;
+    use crate::rules::no_var::NoVar;
+
+    let diagnostics = lint(
+      "var a = 1;\nvar b = a == 1;\nif (a != b) {}",
+      vec![NoVar::new(), Eqeqeq::new()],
+    );
+    let summary = summarize(&diagnostics);
+    assert_eq!(summary.total, 4);
+    assert_eq!(summary.errors, 4);
+    assert_eq!(summary.warnings, 0);
+    assert_eq!(
+      summary.by_code.into_iter().collect::<Vec<_>>(),
+      [("eqeqeq".to_string(), 2), ("no-var".to_string(), 2)]
+    );
+
+    let summary = summarize(&[]);
+    assert_eq!(summary.total, 0);
+    assert_eq!(summary.errors, 0);
+    assert!(summary.by_code.is_empty());
+  }
+
+  #[test]
+  fn lint_batch_groups_results_per_file() {
+    use crate::linter::lint_batch;
+    use crate::rules::eqeqeq::Eqeqeq;
+    use crate::rules::no_var::NoVar;
+
+    let result = lint_batch(
+      vec![
+        (
+          "b.ts".to_string(),
+          "var a = 1;\nvar b = a == 1;".to_string(),
+        ),
+        ("a.ts".to_string(), "var c = 1;\nif (c != 2) {}".to_string()),
+        ("c.ts".to_string(), "const d = ;".to_string()),
+        ("d.ts".to_string(), "let e = 1;".to_string()),
+      ],
+      vec![NoVar::new(), Eqeqeq::new()],
+    );
+
+    let files = result
+      .files
+      .iter()
+      .map(|file| file.file_name.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(files, ["b.ts", "a.ts", "c.ts", "d.ts"]);
+
+    let codes = |index: usize| {
+      let mut codes = result.files[index]
+        .diagnostics
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|diagnostic| {
+          assert_eq!(diagnostic.filename, result.files[index].file_name);
+          diagnostic.code.as_str()
+        })
+        .collect::<Vec<_>>();
+      codes.sort_unstable();
+      codes
+    };
+    assert_eq!(codes(0), ["eqeqeq", "no-var", "no-var"]);
+    assert_eq!(codes(1), ["eqeqeq", "no-var"]);
+    assert!(result.files[2].diagnostics.is_err());
+    assert!(codes(3).is_empty());
+
+    assert_eq!(result.summary.total, 5);
+    assert_eq!(
+      result.summary.by_code.into_iter().collect::<Vec<_>>(),
+      [("eqeqeq".to_string(), 2), ("no-var".to_string(), 3)]
+    );
+  }
+
+  #[test]
+  fn diagnostic_data_is_serialized_when_present() {
+    use crate::rules::no_dupe_keys::NoDupeKeys;
+    use crate::rules::no_var::NoVar;
+
+    let diagnostics = lint(
+      "var a = { b: 1, b: 2 };",
+      vec![NoVar::new(), NoDupeKeys::new()],
+    );
+    let serialized = diagnostics
+      .iter()
+      .map(|d| (d.code.as_str(), serde_json::to_value(d).unwrap()))
+      .collect::<Vec<_>>();
+    assert_eq!(serialized.len(), 2);
+    for (code, value) in serialized {
+      match code {
+        "no-dupe-keys" => {
+          assert_eq!(value["data"], serde_json::json!({ "key": "b" }))
+        }
+        _ => assert!(value.get("data").is_none()),
+      }
+    }
+  }
+
+  #[test]
+  fn fingerprint_changes_with_configuration() {
+    use crate::rules::max_lines::{MaxLines, MaxLinesOptions};
+    use crate::rules::no_var::NoVar;
+
+    let fingerprint = |rules: Vec<Arc<dyn LintRule>>| {
+      LinterBuilder::default().rules(rules).build().fingerprint()
+    };
+    let max_lines = |max: usize| -> Arc<dyn LintRule> {
+      MaxLines::with_options(MaxLinesOptions {
+        max,
+        ..Default::default()
+      })
+    };
+
+    let base = fingerprint(vec![NoVar::new(), max_lines(100)]);
+    assert_eq!(base, fingerprint(vec![max_lines(100), NoVar::new()]));
+    assert_ne!(base, fingerprint(vec![NoVar::new(), max_lines(200)]));
+    assert_ne!(base, fingerprint(vec![max_lines(100)]));
+    assert_ne!(
+      base,
+      LinterBuilder::default()
+        .rules(vec![NoVar::new(), max_lines(100)])
+        .ignore_diagnostic_directive("eslint-disable-line")
+        .build()
+        .fingerprint()
+    );
+    assert_eq!(
+      base,
+      LinterBuilder::default()
+        .rules(vec![NoVar::new(), max_lines(100)])
+        .config()
+        .fingerprint()
+    );
+  }
+
+  #[test]
+  fn fingerprint_ignores_builder_order() {
+    use crate::rules::default_case::{DefaultCase, DefaultCaseOptions};
+
+    let rules = || -> Vec<Arc<dyn LintRule>> {
+      vec![DefaultCase::with_options(DefaultCaseOptions {
+        comment_pattern: Some("^skip default".to_string()),
+      })]
+    };
+    let fingerprint = LinterBuilder::default()
+      .rules(rules())
+      .report_unused_ignore(false)
+      .skip_untriggered_rules(true)
+      .config()
+      .fingerprint();
+    assert_eq!(
+      fingerprint,
+      LinterBuilder::default()
+        .skip_untriggered_rules(true)
+        .report_unused_ignore(false)
+        .rules(rules())
+        .config()
+        .fingerprint()
+    );
+    assert_ne!(
+      fingerprint,
+      LinterBuilder::default()
+        .rules(rules())
+        .report_unused_ignore(true)
+        .skip_untriggered_rules(true)
+        .config()
+        .fingerprint()
+    );
+    assert_ne!(
+      fingerprint,
+      LinterBuilder::default()
+        .rules(rules())
+        .report_unused_ignore(false)
+        .config()
+        .fingerprint()
+    );
+    assert_ne!(
+      fingerprint,
+      LinterBuilder::default()
+        .rules(vec![DefaultCase::new()])
+        .report_unused_ignore(false)
+        .skip_untriggered_rules(true)
+        .config()
+        .fingerprint()
+    );
+  }
 }
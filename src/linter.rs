@@ -3,63 +3,232 @@ use crate::ast_parser::AstParser;
 use crate::ast_parser::SwcDiagnostic;
 use crate::context::Context;
 use crate::control_flow::ControlFlow;
-use crate::diagnostic::LintDiagnostic;
+use crate::diagnostic::{
+  summarize, Applicability, DiagnosticWithFix, LintDiagnostic, LintFix,
+  LintFixChange, LintSummary, Position,
+};
+use crate::globals::GlobalsEnv;
 use crate::ignore_directives::{
   parse_file_ignore_directives, parse_line_ignore_directives,
+  parse_next_line_ignore_directives,
 };
 use crate::rules::LintRule;
 use crate::scopes::Scope;
+use crate::source_map::SourceMap;
 use deno_ast::swc::common::SyntaxContext;
 use deno_ast::view::ProgramRef;
 use deno_ast::MediaType;
 use deno_ast::ParsedSource;
+use rayon::prelude::*;
+use std::error::Error;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Instant;
 
 pub use deno_ast::view::SourceFile;
 
+/// Information about the file being linted, passed to the rule filter set
+/// with `LinterBuilder::rule_filter`.
+#[derive(Debug, Clone, Copy)]
+pub struct FileInfo<'a> {
+  pub file_name: &'a str,
+  pub media_type: MediaType,
+}
+
+/// An error that prevented a file from being linted.
+#[derive(Clone, Debug)]
+pub enum LintError {
+  /// The source code is syntactically invalid. The diagnostic holds the
+  /// position of the first syntax error.
+  Parse(SwcDiagnostic),
+  /// Linting failed for a reason unrelated to the source code, e.g. a rule or
+  /// a plugin panicked.
+  Internal(String),
+}
+
+impl Error for LintError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      LintError::Parse(diagnostic) => Some(diagnostic),
+      LintError::Internal(_) => None,
+    }
+  }
+}
+
+impl fmt::Display for LintError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      LintError::Parse(diagnostic) => {
+        write!(f, "Failed to parse: {}", diagnostic)
+      }
+      LintError::Internal(message) => {
+        write!(f, "Internal error while linting: {}", message)
+      }
+    }
+  }
+}
+
+impl From<SwcDiagnostic> for LintError {
+  fn from(diagnostic: SwcDiagnostic) -> Self {
+    LintError::Parse(diagnostic)
+  }
+}
+
+/// An error that prevented a fix from being applied by [`apply_single_fix`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ApplyFixError {
+  /// A change starts or ends past the end of the source, inside a character,
+  /// or ends before it starts. Holds the byte position of the change.
+  InvalidPosition(usize),
+  /// The line and column of a change don't match its byte position in the
+  /// source, i.e. the source changed since the fix was computed. Holds the
+  /// byte position of the change.
+  StalePosition(usize),
+  /// Two changes of the fix overlap.
+  OverlappingChanges,
+}
+
+impl Error for ApplyFixError {}
+
+impl fmt::Display for ApplyFixError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ApplyFixError::InvalidPosition(byte_pos) => {
+        write!(f, "Invalid position of a fix change: {}", byte_pos)
+      }
+      ApplyFixError::StalePosition(byte_pos) => write!(
+        f,
+        "The source changed since the fix was computed, at position {}",
+        byte_pos
+      ),
+      ApplyFixError::OverlappingChanges => {
+        write!(f, "The changes of the fix overlap")
+      }
+    }
+  }
+}
+
+/// The configuration of a `Linter`, as set with the methods of
+/// `LinterBuilder`. It can be inspected with `LinterBuilder::config` before
+/// the linter is built.
 #[derive(Default)]
-pub struct LinterBuilder {
+pub struct LinterConfig {
   ignore_file_directive: String,
   ignore_diagnostic_directive: String,
+  ignore_next_line_directive: String,
   media_type: MediaType,
+  report_unused_ignore: bool,
+  skip_untriggered_rules: bool,
+  sort_diagnostics: bool,
+  globals: Arc<GlobalsEnv>,
   rules: Vec<Arc<dyn LintRule>>,
   plugins: Vec<Arc<dyn Plugin>>,
 }
 
+impl LinterConfig {
+  /// Returns a fingerprint of the configuration, e.g. to invalidate results
+  /// cached for another configuration. It covers the version of deno_lint,
+  /// every setting of the configuration and, for each rule, its code,
+  /// `LintRule::version` and `LintRule::options`, regardless of the order in
+  /// which they were set. The fingerprint is stable across runs and
+  /// platforms. `rule_filter` and `source_map` aren't covered, since they
+  /// can't be inspected, and neither are severities, since rules don't have
+  /// one yet.
+  pub fn fingerprint(&self) -> u64 {
+    let mut hasher = FingerprintHasher::new();
+    hasher.write(env!("CARGO_PKG_VERSION"));
+    hasher.write(&self.ignore_file_directive);
+    hasher.write(&self.ignore_diagnostic_directive);
+    hasher.write(&self.ignore_next_line_directive);
+    hasher.write(&format!("{:?}", self.media_type));
+    hasher.write(&self.report_unused_ignore.to_string());
+    hasher.write(&self.skip_untriggered_rules.to_string());
+    hasher.write(&self.sort_diagnostics.to_string());
+    for (name, access) in self.globals.sorted() {
+      hasher.write(name);
+      hasher.write(&format!("{:?}", access));
+    }
+
+    let mut rules: Vec<_> = self.rules.iter().collect();
+    rules.sort_by_key(|rule| rule.code());
+    for rule in rules {
+      hasher.write(rule.code());
+      hasher.write(&rule.version().to_string());
+      let options = rule.options().unwrap_or(serde_json::Value::Null);
+      hasher.write(&options.to_string());
+    }
+    for plugin in &self.plugins {
+      hasher.write(&format!("{:?}", plugin));
+    }
+    hasher.finish()
+  }
+}
+
+type RuleFilter = dyn Fn(&dyn LintRule, &FileInfo) -> bool + Send + Sync;
+
+#[derive(Default)]
+pub struct LinterBuilder {
+  config: LinterConfig,
+  source_map: Option<SourceMap>,
+  rule_filter: Option<Arc<RuleFilter>>,
+}
+
 impl LinterBuilder {
   pub fn default() -> Self {
     Self {
-      ignore_file_directive: "deno-lint-ignore-file".to_string(),
-      ignore_diagnostic_directive: "deno-lint-ignore".to_string(),
-      media_type: MediaType::TypeScript,
+      config: LinterConfig {
+        ignore_file_directive: "deno-lint-ignore-file".to_string(),
+        ignore_diagnostic_directive: "deno-lint-ignore".to_string(),
+        ignore_next_line_directive: "deno-lint-ignore-next-line".to_string(),
+        media_type: MediaType::TypeScript,
+        report_unused_ignore: true,
+        skip_untriggered_rules: false,
+        sort_diagnostics: true,
+        ..Default::default()
+      },
       ..Default::default()
     }
   }
 
+  /// Build a `Linter` from the configuration. The linter can be used to lint
+  /// any number of files.
   pub fn build(self) -> Linter {
-    Linter::new(
-      self.ignore_file_directive,
-      self.ignore_diagnostic_directive,
-      self.media_type,
-      self.rules,
-      self.plugins,
-    )
+    Linter::new(self.config, self.source_map, self.rule_filter)
+  }
+
+  /// Returns the configuration set so far.
+  pub fn config(&self) -> &LinterConfig {
+    &self.config
   }
 
   /// Set name for directive that can be used to skip linting file.
   ///
   /// Defaults to "deno-lint-ignore-file".
   pub fn ignore_file_directive(mut self, directive: &str) -> Self {
-    self.ignore_file_directive = directive.to_owned();
+    self.config.ignore_file_directive = directive.to_owned();
     self
   }
 
-  /// Set name for directive that can be used to ignore next line.
+  /// Set name for directive that can be used to ignore next line. When the
+  /// directive is written after code, like eslint's `eslint-disable-line`, it
+  /// also ignores the line it's written on.
   ///
   /// Defaults to "deno-lint-ignore".
   pub fn ignore_diagnostic_directive(mut self, directive: &str) -> Self {
-    self.ignore_diagnostic_directive = directive.to_owned();
+    self.config.ignore_diagnostic_directive = directive.to_owned();
+    self
+  }
+
+  /// Set name for directive that can be used to ignore the next source line,
+  /// like eslint's `eslint-disable-next-line`. It applies to the line right
+  /// after the comment even if that line is blank, and never to the line the
+  /// comment is written on.
+  ///
+  /// Defaults to "deno-lint-ignore-next-line".
+  pub fn ignore_next_line_directive(mut self, directive: &str) -> Self {
+    self.config.ignore_next_line_directive = directive.to_owned();
     self
   }
 
@@ -67,7 +236,49 @@ impl LinterBuilder {
   ///
   /// Defaults to `MediaType::TypeScript`
   pub fn media_type(mut self, media_type: MediaType) -> Self {
-    self.media_type = media_type;
+    self.config.media_type = media_type;
+    self
+  }
+
+  /// Set whether ignore directives that don't suppress any diagnostic are
+  /// reported by `ban-unused-ignore`.
+  ///
+  /// Defaults to `true`.
+  pub fn report_unused_ignore(mut self, report_unused_ignore: bool) -> Self {
+    self.config.report_unused_ignore = report_unused_ignore;
+    self
+  }
+
+  /// Set whether rules are skipped for files that contain none of their
+  /// trigger tokens, see `LintRule::trigger_tokens`. Checking the tokens is a
+  /// cheap substring search, while running a rule walks the whole AST.
+  ///
+  /// Defaults to `false`.
+  pub fn skip_untriggered_rules(
+    mut self,
+    skip_untriggered_rules: bool,
+  ) -> Self {
+    self.config.skip_untriggered_rules = skip_untriggered_rules;
+    self
+  }
+
+  /// Set whether the diagnostics of a file are sorted by line. Otherwise they
+  /// are in the order the rules reported them, followed by those of
+  /// `ban-unused-ignore` and `ban-unknown-rule-code`, and
+  /// `Linter::lint_with_callback` passes them on after each rule.
+  ///
+  /// Defaults to `true`.
+  pub fn sort_diagnostics(mut self, sort_diagnostics: bool) -> Self {
+    self.config.sort_diagnostics = sort_diagnostics;
+    self
+  }
+
+  /// Set the globals that are known to exist at runtime, which are used by
+  /// rules like `no-undef`.
+  ///
+  /// Defaults to the globals of Deno.
+  pub fn globals(mut self, globals: GlobalsEnv) -> Self {
+    self.config.globals = Arc::new(globals);
     self
   }
 
@@ -75,7 +286,7 @@ impl LinterBuilder {
   ///
   /// Defaults to empty list (no rules will be run by default).
   pub fn rules(mut self, rules: Vec<Arc<dyn LintRule>>) -> Self {
-    self.rules = rules;
+    self.config.rules = rules;
     self
   }
 
@@ -83,46 +294,104 @@ impl LinterBuilder {
   ///
   /// Defaults to empty list (no plugins will be loaded).
   pub fn plugins(mut self, plugins: Vec<Arc<dyn Plugin>>) -> Self {
-    self.plugins = plugins;
+    self.config.plugins = plugins;
+    self
+  }
+
+  /// Set the source map of the linted code, if it was generated from another
+  /// source. The lines, columns and file names of diagnostics are then mapped
+  /// back to the original code where the source map has a mapping for them,
+  /// while byte positions still refer to the linted code.
+  ///
+  /// Since a source map belongs to a single generated file, the linter should
+  /// only be used to lint that file.
+  ///
+  /// Defaults to no source map.
+  pub fn source_map(mut self, source_map: SourceMap) -> Self {
+    self.source_map = Some(source_map);
+    self
+  }
+
+  /// Set a predicate that decides for each file which of the rules are run,
+  /// e.g. to skip rules for type annotations on JavaScript files. Rules for
+  /// which it returns `false` are skipped entirely, and ignore directives for
+  /// them are not reported by `ban-unused-ignore`.
+  ///
+  /// Defaults to running all the rules on every file.
+  pub fn rule_filter<F>(mut self, rule_filter: F) -> Self
+  where
+    F: Fn(&dyn LintRule, &FileInfo) -> bool + Send + Sync + 'static,
+  {
+    self.rule_filter = Some(Arc::new(rule_filter));
     self
   }
 }
 
 pub struct Linter {
   ast_parser: AstParser,
-  ignore_file_directive: String,
-  ignore_diagnostic_directive: String,
-  media_type: MediaType,
-  rules: Vec<Arc<dyn LintRule>>,
-  plugins: Vec<Arc<dyn Plugin>>,
+  config: LinterConfig,
+  source_map: Option<SourceMap>,
+  rule_filter: Option<Arc<RuleFilter>>,
 }
 
 impl Linter {
   fn new(
-    ignore_file_directive: String,
-    ignore_diagnostic_directive: String,
-    media_type: MediaType,
-    rules: Vec<Arc<dyn LintRule>>,
-    plugins: Vec<Arc<dyn Plugin>>,
+    mut config: LinterConfig,
+    source_map: Option<SourceMap>,
+    rule_filter: Option<Arc<RuleFilter>>,
   ) -> Self {
+    crate::rules::sort_rules_by_priority(&mut config.rules);
+
     Linter {
       ast_parser: AstParser::new(),
-      ignore_file_directive,
-      ignore_diagnostic_directive,
-      media_type,
-      rules,
-      plugins,
+      config,
+      source_map,
+      rule_filter,
     }
   }
 
+  /// Returns a fingerprint of the configuration the linter was built with,
+  /// see `LinterConfig::fingerprint`.
+  pub fn fingerprint(&self) -> u64 {
+    self.config.fingerprint()
+  }
+
   pub fn lint(
-    mut self,
+    &self,
+    file_name: String,
+    source_code: String,
+  ) -> Result<(ParsedSource, Vec<LintDiagnostic>), LintError> {
+    let mut diagnostics = vec![];
+    let parsed_source =
+      self.lint_with_callback(file_name, source_code, |diagnostic| {
+        diagnostics.push(diagnostic)
+      })?;
+    Ok((parsed_source, diagnostics))
+  }
+
+  /// Like `lint`, but passes each diagnostic to `on_diagnostic` instead of
+  /// collecting them, e.g. to stream them to disk while linting many files.
+  /// Ignored diagnostics are left out.
+  ///
+  /// By default the diagnostics are sorted by line, so they can only be
+  /// passed once all rules have run. With `LinterBuilder::sort_diagnostics`
+  /// disabled, the diagnostics of each rule are passed as soon as the rule
+  /// has run, followed by the ones about ignore directives at the end.
+  /// Nothing is passed if the file fails to parse. If a rule panics, the
+  /// diagnostics already passed are kept and `LintError::Internal` is
+  /// returned.
+  pub fn lint_with_callback<F>(
+    &self,
     file_name: String,
     source_code: String,
-  ) -> Result<(ParsedSource, Vec<LintDiagnostic>), SwcDiagnostic> {
+    mut on_diagnostic: F,
+  ) -> Result<ParsedSource, LintError>
+  where
+    F: FnMut(LintDiagnostic),
+  {
     let start = Instant::now();
 
-    let syntax = deno_ast::get_syntax(self.media_type);
+    let syntax = deno_ast::get_syntax(self.config.media_type);
 
     let parse_result =
       self
@@ -134,45 +403,114 @@ impl Linter {
       end_parse_program - start
     );
     let parsed_source = parse_result?;
-    let diagnostics = self.lint_program(&parsed_source);
+    self.lint_program(&parsed_source, &mut on_diagnostic)?;
 
     let end = Instant::now();
     debug!("Linter::lint took {:#?}", end - start);
-    Ok((parsed_source, diagnostics))
+    Ok(parsed_source)
   }
 
   pub fn lint_with_ast(
-    mut self,
+    &self,
     parsed_source: &ParsedSource,
-  ) -> Vec<LintDiagnostic> {
+  ) -> Result<Vec<LintDiagnostic>, LintError> {
     let start = Instant::now();
-    let diagnostics = self.lint_program(parsed_source);
+    let mut diagnostics = vec![];
+    self.lint_program(parsed_source, &mut |diagnostic| {
+      diagnostics.push(diagnostic)
+    })?;
     let end = Instant::now();
     debug!("Linter::lint_with_ast took {:#?}", end - start);
 
-    diagnostics
+    Ok(diagnostics)
+  }
+
+  /// Passes the diagnostics reported since `from` that aren't ignored to
+  /// `on_diagnostic`, unless they are to be sorted, in which case they are
+  /// kept in `pending` until `finish_diagnostics`.
+  fn filter_diagnostics(
+    &self,
+    context: &mut Context,
+    from: usize,
+    pending: &mut Vec<LintDiagnostic>,
+    on_diagnostic: &mut dyn FnMut(LintDiagnostic),
+  ) {
+    let filtered = context.check_ignore_directive_usage(from);
+    if self.config.sort_diagnostics {
+      pending.extend(filtered);
+    } else {
+      for diagnostic in filtered {
+        self.emit(diagnostic, on_diagnostic);
+      }
+    }
   }
 
-  fn filter_diagnostics(&self, mut context: Context) -> Vec<LintDiagnostic> {
+  /// Reports the diagnostics about ignore directives, which have to run last,
+  /// and passes them on along with the pending diagnostics.
+  fn finish_diagnostics(
+    &self,
+    context: &Context,
+    rules: &[Arc<dyn LintRule>],
+    mut pending: Vec<LintDiagnostic>,
+    on_diagnostic: &mut dyn FnMut(LintDiagnostic),
+  ) {
     let start = Instant::now();
 
-    let mut filtered_diagnostics = context.check_ignore_directive_usage();
     // Run `ban-unused-ignore`
-    filtered_diagnostics.extend(context.ban_unused_ignore(&self.rules));
+    if self.config.report_unused_ignore {
+      pending.extend(context.ban_unused_ignore(rules));
+    }
     // Run `ban-unknown-rule-code`
-    filtered_diagnostics.extend(context.ban_unknown_rule_code());
-    filtered_diagnostics.sort_by_key(|d| d.range.start.line_index);
+    pending.extend(context.ban_unknown_rule_code());
+    if self.config.sort_diagnostics {
+      pending.sort_by_key(|d| d.range.start.line_index);
+    }
+    for diagnostic in pending {
+      self.emit(diagnostic, on_diagnostic);
+    }
 
     let end = Instant::now();
-    debug!("Linter::filter_diagnostics took {:#?}", end - start);
+    debug!("Linter::finish_diagnostics took {:#?}", end - start);
+  }
 
-    filtered_diagnostics
+  fn emit(
+    &self,
+    mut diagnostic: LintDiagnostic,
+    on_diagnostic: &mut dyn FnMut(LintDiagnostic),
+  ) {
+    if let Some(source_map) = &self.source_map {
+      source_map.remap_diagnostic(&mut diagnostic);
+    }
+    on_diagnostic(diagnostic);
   }
 
+  /// Runs the rules and plugins, turning a panic in any of them into
+  /// `LintError::Internal` so that a single file can't bring down the caller.
   fn lint_program(
-    &mut self,
+    &self,
     parsed_source: &ParsedSource,
-  ) -> Vec<LintDiagnostic> {
+    on_diagnostic: &mut dyn FnMut(LintDiagnostic),
+  ) -> Result<(), LintError> {
+    catch_unwind(AssertUnwindSafe(|| {
+      self.lint_program_inner(parsed_source, on_diagnostic)
+    }))
+    .map_err(|payload| {
+      let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+      } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+      } else {
+        "unknown panic".to_string()
+      };
+      LintError::Internal(message)
+    })
+  }
+
+  fn lint_program_inner(
+    &self,
+    parsed_source: &ParsedSource,
+    on_diagnostic: &mut dyn FnMut(LintDiagnostic),
+  ) {
     let start = Instant::now();
 
     let control_flow = ControlFlow::analyze(parsed_source.program_ref().into());
@@ -181,55 +519,487 @@ impl Linter {
         SyntaxContext::empty().apply_mark(self.ast_parser.top_level_mark)
       });
 
-    let diagnostics = parsed_source.with_view(|pg| {
+    parsed_source.with_view(|pg| {
       let file_ignore_directive =
-        parse_file_ignore_directives(&self.ignore_file_directive, pg);
+        parse_file_ignore_directives(&self.config.ignore_file_directive, pg);
 
       // If a global ignore directive that has no codes specified exists, we must skip linting on
       // this file.
       if matches!(file_ignore_directive, Some(ref file_ignore) if file_ignore.ignore_all())
       {
-        return vec![];
+        return;
       }
 
       let line_ignore_directives =
-        parse_line_ignore_directives(&self.ignore_diagnostic_directive, pg);
+        parse_line_ignore_directives(&self.config.ignore_diagnostic_directive, pg);
+      let next_line_ignore_directives = parse_next_line_ignore_directives(
+        &self.config.ignore_next_line_directive,
+        pg,
+      );
 
       let scope = Scope::analyze(pg);
 
       let mut context = Context::new(
         parsed_source.specifier().to_string(),
-        self.media_type,
+        self.config.media_type,
         parsed_source.source(),
         pg,
         file_ignore_directive,
         line_ignore_directives,
+        next_line_ignore_directives,
         scope,
+        Arc::clone(&self.config.globals),
         control_flow,
         top_level_ctxt,
       );
 
-      crate::rules::sort_rules_by_priority(&mut self.rules);
-
-      // Run builtin rules
-      for rule in self.rules.iter() {
+      let file_info = FileInfo {
+        file_name: parsed_source.specifier(),
+        media_type: self.config.media_type,
+      };
+      let rules: Vec<Arc<dyn LintRule>> = match &self.rule_filter {
+        Some(rule_filter) => self
+          .config
+          .rules
+          .iter()
+          .filter(|rule| rule_filter(rule.as_ref(), &file_info))
+          .cloned()
+          .collect(),
+        None => self.config.rules.clone(),
+      };
+
+      // Run builtin rules. Skipped rules can't report anything, so they are
+      // still passed to `filter_diagnostics` and ignore directives for them
+      // are reported as unused.
+      let source = parsed_source.source().text_str();
+      let triggered = |rule: &&Arc<dyn LintRule>| {
+        let tokens = rule.trigger_tokens();
+        !self.config.skip_untriggered_rules
+          || tokens.is_empty()
+          || tokens.iter().any(|token| source.contains(token))
+      };
+      let mut pending = Vec::new();
+      for rule in rules.iter().filter(triggered) {
+        let from = context.diagnostics().len();
         rule.lint_program_with_ast_view(&mut context, pg);
+        context.resolve_fix_applicability(from, rule.fix_applicability());
+        self.filter_diagnostics(&mut context, from, &mut pending, on_diagnostic);
       }
 
       // TODO(bartlomieju): plugins rules should be sorted by priority as well.
       // Run plugin rules
-      for plugin in self.plugins.iter() {
+      let from = context.diagnostics().len();
+      for plugin in self.config.plugins.iter() {
         // Ignore any error
         let _ = plugin.run(&mut context, parsed_source.program_ref().into());
       }
+      context.resolve_fix_applicability(from, Applicability::default());
+      self.filter_diagnostics(&mut context, from, &mut pending, on_diagnostic);
 
-      self.filter_diagnostics(context)
+      self.finish_diagnostics(&context, &rules, pending, on_diagnostic);
     });
 
     let end = Instant::now();
     debug!("Linter::lint_module took {:#?}", end - start);
+  }
+}
+
+/// Lints a single file with the given rules and returns the diagnostics, with
+/// ignore directives already applied. Nothing is printed.
+///
+/// The media type is inferred from the extension of `file_name`, falling back
+/// to TypeScript. Use `LinterBuilder` for more control, e.g. to lint many
+/// files with the same configuration.
+///
+/// ```
+/// use deno_lint::linter::lint_to_diagnostics;
+/// use deno_lint::rules::get_recommended_rules;
+///
+/// let source = "// deno-lint-ignore no-debugger\ndebugger;\ndebugger;\n";
+/// let diagnostics =
+///   lint_to_diagnostics("example.ts", source, get_recommended_rules())
+///     .expect("Failed to parse");
+///
+/// assert_eq!(diagnostics.len(), 1);
+/// assert_eq!(diagnostics[0].code, "no-debugger");
+/// assert_eq!(diagnostics[0].range.start.line_index, 2);
+/// ```
+pub fn lint_to_diagnostics(
+  file_name: &str,
+  source_code: &str,
+  rules: Vec<Arc<dyn LintRule>>,
+) -> Result<Vec<LintDiagnostic>, LintError> {
+  let media_type = match MediaType::from(Path::new(file_name)) {
+    MediaType::Unknown => MediaType::TypeScript,
+    media_type => media_type,
+  };
+  let linter = LinterBuilder::default()
+    .media_type(media_type)
+    .rules(rules)
+    .build();
+  let (_, diagnostics) =
+    linter.lint(file_name.to_string(), source_code.to_string())?;
+  Ok(diagnostics)
+}
+
+/// The result of linting one of the files passed to [`lint_batch`].
+#[derive(Debug)]
+pub struct BatchFileResult {
+  pub file_name: String,
+  pub diagnostics: Result<Vec<LintDiagnostic>, LintError>,
+}
+
+/// The results of [`lint_batch`], in the order the files were passed.
+#[derive(Debug)]
+pub struct BatchResult {
+  pub files: Vec<BatchFileResult>,
+  /// Counts of the diagnostics of all the files. Files that failed to be
+  /// linted don't contribute to it.
+  pub summary: LintSummary,
+}
+
+/// Lints many files in parallel with the given rules, like
+/// [`lint_to_diagnostics`] does for a single file, and summarizes the
+/// diagnostics of all of them, e.g. for the output of a command line tool.
+///
+/// `files` holds the name and source code of each file. The results are in
+/// the same order, and a file that fails to parse doesn't keep the others
+/// from being linted.
+///
+/// ```
+/// use deno_lint::linter::lint_batch;
+/// use deno_lint::rules::get_recommended_rules;
+///
+/// let result = lint_batch(
+///   vec![
+///     ("a.ts".to_string(), "debugger;\n".to_string()),
+///     ("b.ts".to_string(), "export const a = 1;\n".to_string()),
+///   ],
+///   get_recommended_rules(),
+/// );
+///
+/// assert_eq!(result.files[1].file_name, "b.ts");
+/// assert!(result.files[1].diagnostics.as_ref().unwrap().is_empty());
+/// assert_eq!(result.summary.total, 1);
+/// assert_eq!(result.summary.by_code["no-debugger"], 1);
+/// ```
+pub fn lint_batch(
+  files: Vec<(String, String)>,
+  rules: Vec<Arc<dyn LintRule>>,
+) -> BatchResult {
+  let files = files
+    .into_par_iter()
+    .map(|(file_name, source_code)| {
+      let diagnostics =
+        lint_to_diagnostics(&file_name, &source_code, rules.clone());
+      BatchFileResult {
+        file_name,
+        diagnostics,
+      }
+    })
+    .collect::<Vec<_>>();
+  let all_diagnostics = files
+    .iter()
+    .filter_map(|file| file.diagnostics.as_ref().ok())
+    .flatten()
+    .cloned()
+    .collect::<Vec<_>>();
+  BatchResult {
+    summary: summarize(&all_diagnostics),
+    files,
+  }
+}
 
+/// Lints `source_code` and returns the fixes the rules propose, without
+/// applying them.
+///
+/// A diagnostic with several alternative fixes is returned once for each of
+/// them; diagnostics without fixes are left out.
+///
+/// ```
+/// use deno_lint::linter::compute_fixes;
+/// use deno_lint::rules::get_recommended_rules;
+///
+/// let fixes = compute_fixes("example.ts", "var a = 1;\n", get_recommended_rules())
+///   .expect("Failed to parse");
+///
+/// assert_eq!(fixes.len(), 1);
+/// assert_eq!(fixes[0].diagnostic.code, "no-var");
+/// assert_eq!(fixes[0].fix.changes[0].new_text, "let");
+/// ```
+pub fn compute_fixes(
+  file_name: &str,
+  source_code: &str,
+  rules: Vec<Arc<dyn LintRule>>,
+) -> Result<Vec<DiagnosticWithFix>, LintError> {
+  let diagnostics = lint_to_diagnostics(file_name, source_code, rules)?;
+  Ok(
     diagnostics
+      .into_iter()
+      .flat_map(|diagnostic| {
+        diagnostic
+          .fixes
+          .clone()
+          .into_iter()
+          .map(move |fix| DiagnosticWithFix {
+            diagnostic: diagnostic.clone(),
+            fix,
+          })
+      })
+      .collect(),
+  )
+}
+
+/// Applies the changes of a single fix, e.g. the one the user picked from the
+/// code actions of an editor, and returns the fixed source.
+///
+/// The fix must have been computed for `source`: every change is checked
+/// against it, and an error is returned rather than corrupting the text if a
+/// byte position is out of bounds or its line and column no longer match.
+///
+/// ```
+/// use deno_lint::linter::{apply_single_fix, compute_fixes};
+/// use deno_lint::rules::get_recommended_rules;
+///
+/// let source = "var a = 1;\nvar b = 2;\n";
+/// let fixes = compute_fixes("example.ts", source, get_recommended_rules())
+///   .expect("Failed to parse");
+///
+/// let fixed = apply_single_fix(source, &fixes[1]).unwrap();
+/// assert_eq!(fixed, "var a = 1;\nlet b = 2;\n");
+///
+/// // The buffer changed since the fixes were computed.
+/// let edited = "var a = 10;\nvar b = 2;\n";
+/// assert!(apply_single_fix(edited, &fixes[1]).is_err());
+/// ```
+pub fn apply_single_fix(
+  source: &str,
+  fix: &DiagnosticWithFix,
+) -> Result<String, ApplyFixError> {
+  let mut changes = fix.fix.changes.iter().collect::<Vec<_>>();
+  for change in &changes {
+    let (start, end) = (&change.range.start, &change.range.end);
+    validate_position(source, start)?;
+    validate_position(source, end)?;
+    if end.byte_pos < start.byte_pos {
+      return Err(ApplyFixError::InvalidPosition(end.byte_pos));
+    }
+  }
+
+  changes.sort_by_key(|change| change.range.start.byte_pos);
+  let mut fixed = String::new();
+  let mut last_pos = 0;
+  for change in changes {
+    if change.range.start.byte_pos < last_pos {
+      return Err(ApplyFixError::OverlappingChanges);
+    }
+    fixed.push_str(&source[last_pos..change.range.start.byte_pos]);
+    fixed.push_str(&change.new_text);
+    last_pos = change.range.end.byte_pos;
+  }
+  fixed.push_str(&source[last_pos..]);
+  Ok(fixed)
+}
+
+/// Checks that `position` is a valid byte position in `source` and that its
+/// line and column match it.
+fn validate_position(
+  source: &str,
+  position: &Position,
+) -> Result<(), ApplyFixError> {
+  let byte_pos = position.byte_pos;
+  if byte_pos > source.len() || !source.is_char_boundary(byte_pos) {
+    return Err(ApplyFixError::InvalidPosition(byte_pos));
+  }
+  let before = &source[..byte_pos];
+  let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+  let line_index = before.matches('\n').count();
+  let column_index = before[line_start..].encode_utf16().count();
+  if (line_index, column_index) != (position.line_index, position.column_index)
+  {
+    return Err(ApplyFixError::StalePosition(byte_pos));
+  }
+  Ok(())
+}
+
+/// Lints `source_code` and applies the fixes the rules propose, repeating
+/// until no more fixes apply or `max_passes` passes were made, since applying
+/// a fix may enable further fixes.
+///
+/// Returns the fixed source along with the diagnostics that remain in it.
+///
+/// Only fixes that are [`Applicability::Automatic`] are applied; use
+/// [`fix_source_with_applicability`] to apply less safe fixes as well.
+pub fn fix_source(
+  file_name: &str,
+  source_code: &str,
+  rules: Vec<Arc<dyn LintRule>>,
+  max_passes: usize,
+) -> Result<(String, Vec<LintDiagnostic>), LintError> {
+  fix_source_with_applicability(
+    file_name,
+    source_code,
+    rules,
+    max_passes,
+    Applicability::Automatic,
+  )
+}
+
+/// Like [`fix_source`], but applies all fixes that are at most as unsafe as
+/// `applicability`.
+///
+/// Only the first such fix of each diagnostic is applied. When fixes of a
+/// pass overlap, the one whose changes start first wins, with ties broken by
+/// the rule code; the others are retried in the next pass. A pass whose
+/// result fails to parse is discarded.
+pub fn fix_source_with_applicability(
+  file_name: &str,
+  source_code: &str,
+  rules: Vec<Arc<dyn LintRule>>,
+  max_passes: usize,
+  applicability: Applicability,
+) -> Result<(String, Vec<LintDiagnostic>), LintError> {
+  fix_source_inner(file_name, source_code, rules, max_passes, |fix, _| {
+    fix.applicability.unwrap_or_default() <= applicability
+  })
+}
+
+/// Like [`fix_source_with_applicability`], but only applies the fixes of
+/// diagnostics reported for one of `codes`, e.g. to fix all the `no-var`
+/// diagnostics of a file. A code may include a sub-code, like
+/// `jsx-curly-brace-presence/unnecessary`.
+///
+/// All of `rules` still run, so the other diagnostics are returned along
+/// with the remaining ones of `codes`. Fixes that aren't applied never keep
+/// the others from being applied, even if their changes overlap.
+pub fn fix_source_for_codes(
+  file_name: &str,
+  source_code: &str,
+  rules: Vec<Arc<dyn LintRule>>,
+  max_passes: usize,
+  applicability: Applicability,
+  codes: &[&str],
+) -> Result<(String, Vec<LintDiagnostic>), LintError> {
+  fix_source_inner(
+    file_name,
+    source_code,
+    rules,
+    max_passes,
+    |fix, diagnostic| {
+      let is_listed = codes.contains(&diagnostic.code.as_str())
+        || matches!(
+          diagnostic.qualified_code(),
+          Some(code) if codes.contains(&code.as_str())
+        );
+      is_listed && fix.applicability.unwrap_or_default() <= applicability
+    },
+  )
+}
+
+fn fix_source_inner<F>(
+  file_name: &str,
+  source_code: &str,
+  rules: Vec<Arc<dyn LintRule>>,
+  max_passes: usize,
+  is_applicable: F,
+) -> Result<(String, Vec<LintDiagnostic>), LintError>
+where
+  F: Fn(&LintFix, &LintDiagnostic) -> bool,
+{
+  let mut source = source_code.to_string();
+  let mut diagnostics = lint_to_diagnostics(file_name, &source, rules.clone())?;
+  for _ in 0..max_passes {
+    let fixed = match apply_fixes(&source, &diagnostics, &is_applicable) {
+      Some(fixed) => fixed,
+      None => break,
+    };
+    match lint_to_diagnostics(file_name, &fixed, rules.clone()) {
+      Ok(fixed_diagnostics) => {
+        source = fixed;
+        diagnostics = fixed_diagnostics;
+      }
+      Err(_) => break,
+    }
+  }
+  Ok((source, diagnostics))
+}
+
+/// Applies the first applicable fix of each diagnostic, skipping fixes that
+/// overlap with an already applied one. Returns `None` if no fix could be
+/// applied.
+fn apply_fixes<F>(
+  source: &str,
+  diagnostics: &[LintDiagnostic],
+  is_applicable: F,
+) -> Option<String>
+where
+  F: Fn(&LintFix, &LintDiagnostic) -> bool,
+{
+  let mut fixes = diagnostics
+    .iter()
+    .filter_map(|diagnostic| {
+      let fix = diagnostic
+        .fixes
+        .iter()
+        .find(|fix| is_applicable(fix, diagnostic))?;
+      let start = fix.changes.iter().map(|c| c.range.start.byte_pos).min()?;
+      Some((start, diagnostic.code.as_str(), fix))
+    })
+    .collect::<Vec<_>>();
+  fixes.sort_by_key(|(start, code, _)| (*start, *code));
+
+  let mut changes: Vec<&LintFixChange> = Vec::new();
+  for (_, _, fix) in fixes {
+    let overlaps = fix.changes.iter().any(|change| {
+      let (start, end) =
+        (change.range.start.byte_pos, change.range.end.byte_pos);
+      changes.iter().any(|applied| {
+        let applied_start = applied.range.start.byte_pos;
+        let applied_end = applied.range.end.byte_pos;
+        start == applied_start || (start < applied_end && applied_start < end)
+      })
+    });
+    if !overlaps {
+      changes.extend(&fix.changes);
+    }
+  }
+  if changes.is_empty() {
+    return None;
+  }
+
+  changes.sort_by_key(|change| change.range.start.byte_pos);
+  let mut fixed = String::new();
+  let mut last_pos = 0;
+  for change in changes {
+    fixed.push_str(&source[last_pos..change.range.start.byte_pos]);
+    fixed.push_str(&change.new_text);
+    last_pos = change.range.end.byte_pos;
+  }
+  fixed.push_str(&source[last_pos..]);
+  Some(fixed)
+}
+
+/// A 64-bit FNV-1a hasher. Unlike `DefaultHasher`, its output doesn't depend
+/// on the Rust version, so fingerprints can be persisted.
+struct FingerprintHasher(u64);
+
+impl FingerprintHasher {
+  fn new() -> Self {
+    FingerprintHasher(0xcbf2_9ce4_8422_2325)
+  }
+
+  /// Hashes `value`, prefixed with its length so that consecutive values
+  /// can't run into each other.
+  fn write(&mut self, value: &str) {
+    let len = (value.len() as u64).to_le_bytes();
+    for byte in len.iter().chain(value.as_bytes()) {
+      self.0 ^= u64::from(*byte);
+      self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+    }
+  }
+
+  fn finish(&self) -> u64 {
+    self.0
   }
 }
 
@@ -1,7 +1,10 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use crate::context::Context;
+use crate::diagnostic::Applicability;
 use crate::Program;
 use crate::ProgramRef;
+use serde::Serialize;
+use serde_json::json;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -13,89 +16,174 @@ pub mod ban_unknown_rule_code;
 pub mod ban_untagged_ignore;
 pub mod ban_untagged_todo;
 pub mod ban_unused_ignore;
+pub mod block_scoped_var;
 pub mod camelcase;
+pub mod class_methods_use_this;
 pub mod constructor_super;
+pub mod default_case;
+pub mod default_case_last;
 pub mod default_param_last;
 pub mod eqeqeq;
 pub mod explicit_function_return_type;
 pub mod explicit_module_boundary_types;
 pub mod for_direction;
 pub mod getter_return;
+pub mod hook_rules;
+pub mod import_first;
+pub mod init_declarations;
+pub mod jsx_boolean_value;
+pub mod jsx_curly_brace_presence;
+pub mod lines_between_class_members;
+pub mod max_lines;
+pub mod max_lines_per_function;
+pub mod max_statements;
+pub mod new_cap;
+pub mod no_accumulating_spread;
+pub mod no_alert;
+pub mod no_anonymous_default_export;
 pub mod no_array_constructor;
+pub mod no_array_index_key;
+pub mod no_async_foreach;
 pub mod no_async_promise_executor;
+pub mod no_await_expression_member;
 pub mod no_await_in_loop;
+pub mod no_await_in_promise_all_map;
 pub mod no_case_declarations;
 pub mod no_class_assign;
 pub mod no_compare_neg_zero;
 pub mod no_cond_assign;
 pub mod no_const_assign;
 pub mod no_constant_condition;
+pub mod no_constructor_return;
+pub mod no_continue_in_finally;
 pub mod no_control_regex;
+pub mod no_cycle;
 pub mod no_debugger;
 pub mod no_delete_var;
 pub mod no_deprecated_deno_api;
+pub mod no_did_mount_set_state;
+pub mod no_document_cookie;
 pub mod no_dupe_args;
 pub mod no_dupe_class_members;
 pub mod no_dupe_else_if;
 pub mod no_dupe_keys;
 pub mod no_duplicate_case;
+pub mod no_duplicate_imports;
 pub mod no_empty;
 pub mod no_empty_character_class;
 pub mod no_empty_enum;
 pub mod no_empty_interface;
+pub mod no_empty_named_blocks;
 pub mod no_empty_pattern;
+pub mod no_empty_static_block;
 pub mod no_eval;
 pub mod no_ex_assign;
 pub mod no_explicit_any;
+pub mod no_extra_bind;
 pub mod no_extra_boolean_cast;
+pub mod no_extra_label;
 pub mod no_extra_non_null_assertion;
 pub mod no_extra_semi;
 pub mod no_fallthrough;
+pub mod no_floating_promises;
 pub mod no_func_assign;
 pub mod no_global_assign;
+pub mod no_implicit_coercion;
 pub mod no_import_assign;
 pub mod no_inferrable_types;
 pub mod no_inner_declarations;
+pub mod no_invalid_fetch_options;
 pub mod no_invalid_regexp;
+pub mod no_invalid_this;
 pub mod no_invalid_triple_slash_reference;
 pub mod no_irregular_whitespace;
+pub mod no_jsx_bind;
+pub mod no_magic_string_keys;
 pub mod no_misused_new;
+pub mod no_misused_promises;
+pub mod no_mixed_spaces_and_tabs;
+pub mod no_multiple_empty_lines;
+pub mod no_mutable_exports;
 pub mod no_namespace;
+pub mod no_negated_async_condition;
+pub mod no_negated_in_lhs;
+pub mod no_new;
 pub mod no_new_symbol;
 pub mod no_non_null_asserted_optional_chain;
 pub mod no_non_null_assertion;
 pub mod no_obj_calls;
 pub mod no_octal;
+pub mod no_param_reassign;
 pub mod no_prototype_builtins;
 pub mod no_redeclare;
 pub mod no_regex_spaces;
+pub mod no_relative_parent_imports;
+pub mod no_restricted_exports;
+pub mod no_restricted_html_elements_in_head;
+pub mod no_restricted_jsx_elements;
+pub mod no_restricted_modules;
+pub mod no_restricted_property_access;
+pub mod no_return_assign;
+pub mod no_return_in_expression_position;
 pub mod no_self_assign;
+pub mod no_self_import;
 pub mod no_setter_return;
+pub mod no_shadow;
 pub mod no_shadow_restricted_names;
 pub mod no_sparse_arrays;
+pub mod no_sync_in_async_handler;
 pub mod no_this_alias;
 pub mod no_this_before_super;
+pub mod no_throw_in_finally;
 pub mod no_throw_literal;
+pub mod no_top_level_await;
+pub mod no_unassigned_import;
 pub mod no_undef;
+pub mod no_underscore_dangle;
+pub mod no_unescaped_entities;
+pub mod no_unknown_property;
+pub mod no_unnecessary_condition;
 pub mod no_unreachable;
+pub mod no_unsafe_argument_count;
+pub mod no_unsafe_declaration_merging;
 pub mod no_unsafe_finally;
 pub mod no_unsafe_negation;
+pub mod no_unsafe_object_spread_in_jsx;
 pub mod no_unused_labels;
+pub mod no_unused_private_class_members;
 pub mod no_unused_vars;
+pub mod no_use_before_define;
+pub mod no_useless_backreference;
 pub mod no_var;
+pub mod no_var_requires;
 pub mod no_window_prefix;
 pub mod no_with;
+pub mod one_var;
+pub mod padded_blocks;
 pub mod prefer_as_const;
 pub mod prefer_ascii;
+pub mod prefer_at;
 pub mod prefer_const;
+pub mod prefer_const_assertion_over_enum;
+pub mod prefer_early_return;
+pub mod prefer_includes;
+pub mod prefer_literal_enum_member;
+pub mod prefer_named_capture_group;
 pub mod prefer_namespace_keyword;
+pub mod prefer_nullish_coalescing;
+pub mod prefer_optional_chain;
 pub mod prefer_primordials;
+pub mod prefer_string_starts_ends_with;
+pub mod prefer_text_content_over_inner_html;
 pub mod require_await;
+pub mod require_error_cause;
+pub mod require_unicode_regexp;
 pub mod require_yield;
 pub mod single_var_declarator;
 pub mod triple_slash_reference;
 pub mod use_isnan;
 pub mod valid_typeof;
+pub mod vars_on_top;
 
 const DUMMY_NODE: () = ();
 
@@ -131,11 +219,80 @@ pub trait LintRule: std::fmt::Debug + Send + Sync {
   /// Returns the unique code that identifies the rule
   fn code(&self) -> &'static str;
 
+  /// Returns the more specific codes of the diagnostics this rule reports,
+  /// e.g. `missing` for `jsx-curly-brace-presence`. Diagnostics are given a
+  /// sub-code with `Context::add_diagnostic_with_sub_code` and can be ignored
+  /// as `<code>/<sub-code>`, while ignoring `<code>` ignores all of them.
+  fn sub_codes(&self) -> &'static [&'static str] {
+    &[]
+  }
+
   /// Returns the tags this rule belongs to, e.g. `recommended`
   fn tags(&self) -> &'static [&'static str] {
     &[]
   }
 
+  /// Returns strings of which at least one must appear in the source for the
+  /// rule to report anything, e.g. `var` for `no-var`. The linter may skip
+  /// the rule for files containing none of them, see
+  /// `LinterBuilder::skip_untriggered_rules`.
+  ///
+  /// An empty list, the default, means the rule always runs. A token that
+  /// appears only in a string or a comment just makes the rule run, but a
+  /// token must never be missing from a file the rule reports something in,
+  /// e.g. because the syntax can be written in another way.
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &[]
+  }
+
+  /// The version of the rule's implementation, which is part of
+  /// `LinterConfig::fingerprint`. It must be bumped whenever a change to the rule
+  /// can change the diagnostics it reports, so that results cached for the
+  /// previous implementation are invalidated.
+  fn version(&self) -> u32 {
+    1
+  }
+
+  /// Returns a JSON Schema describing the options of this rule, or `None` if
+  /// the rule can't be configured.
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    None
+  }
+
+  /// Returns the options this rule uses when none are given, in the shape
+  /// described by `options_schema`, or `None` if the rule can't be
+  /// configured.
+  fn default_options(&self) -> Option<serde_json::Value> {
+    None
+  }
+
+  /// Returns the options this rule is configured with, in the shape
+  /// described by `options_schema`, or `None` if the rule can't be
+  /// configured. They are part of `LinterConfig::fingerprint`.
+  fn options(&self) -> Option<serde_json::Value> {
+    None
+  }
+
+  /// Returns this rule configured with `options`, in the shape described by
+  /// `options_schema`, e.g. the options of the rule in `deno.json`. Unknown
+  /// options are rejected, and so are any options for a rule that can't be
+  /// configured.
+  fn with_options_value(
+    &self,
+    _options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Err(serde::de::Error::custom(format!(
+      "the rule `{}` has no options",
+      self.code()
+    )))
+  }
+
+  /// Returns the applicability of the fixes this rule produces, unless a fix
+  /// specifies its own.
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Suggestion
+  }
+
   /// Returns the documentation string for this rule, describing what this rule is for with several
   /// examples.
   #[cfg(feature = "docs")]
@@ -217,6 +374,110 @@ pub fn get_filtered_rules(
   rules
 }
 
+/// Returns a JSON Schema for the rules configuration, e.g. the `lint.rules`
+/// block of `deno.json`.
+///
+/// `tags`, `include` and `exclude` are checked against the known tags and rule
+/// codes, and `options` maps rule codes to the options of that rule.
+pub fn config_schema() -> serde_json::Value {
+  let rules = get_all_rules_raw();
+
+  let mut tags = rules
+    .iter()
+    .flat_map(|rule| rule.tags().iter().copied())
+    .collect::<Vec<_>>();
+  tags.sort_unstable();
+  tags.dedup();
+
+  let codes = rules.iter().map(|rule| rule.code()).collect::<Vec<_>>();
+
+  let options = rules
+    .iter()
+    .filter_map(|rule| {
+      rule
+        .options_schema()
+        .map(|schema| (rule.code().to_string(), schema))
+    })
+    .collect::<serde_json::Map<_, _>>();
+
+  let rule_codes = json!({
+    "type": "array",
+    "items": { "type": "string", "enum": codes }
+  });
+
+  json!({
+    "$schema": "http://json-schema.org/draft-07/schema#",
+    "title": "deno_lint rules configuration",
+    "type": "object",
+    "properties": {
+      "tags": {
+        "type": "array",
+        "items": { "type": "string", "enum": tags }
+      },
+      "include": rule_codes,
+      "exclude": rule_codes,
+      "options": {
+        "type": "object",
+        "properties": options,
+        "additionalProperties": false
+      }
+    },
+    "additionalProperties": false
+  })
+}
+
+/// Returns the default options of all the rules that can be configured,
+/// keyed by rule code like the `options` of `config_schema`.
+pub fn default_options() -> serde_json::Map<String, serde_json::Value> {
+  get_all_rules_raw()
+    .into_iter()
+    .filter_map(|rule| {
+      rule
+        .default_options()
+        .map(|options| (rule.code().to_string(), options))
+    })
+    .collect()
+}
+
+/// Information about a rule, e.g. for listing the available rules or
+/// explaining what a rule is for.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleMetadata {
+  pub code: &'static str,
+  pub tags: &'static [&'static str],
+  pub sub_codes: &'static [&'static str],
+  /// The applicability of the fixes the rule produces, unless a fix specifies
+  /// its own.
+  pub fix_applicability: Applicability,
+  /// The documentation of the rule in Markdown.
+  #[cfg(feature = "docs")]
+  pub docs: &'static str,
+}
+
+/// Returns the metadata of the rule with the given code, or `None` if there's
+/// no such rule.
+pub fn rule_metadata(code: &str) -> Option<RuleMetadata> {
+  get_all_rules_raw()
+    .into_iter()
+    .find(|rule| rule.code() == code)
+    .map(|rule| RuleMetadata {
+      code: rule.code(),
+      tags: rule.tags(),
+      sub_codes: rule.sub_codes(),
+      fix_applicability: rule.fix_applicability(),
+      #[cfg(feature = "docs")]
+      docs: rule.docs(),
+    })
+}
+
+/// Returns the documentation of the rule with the given code, or `None` if
+/// there's no such rule.
+#[cfg(feature = "docs")]
+pub fn explain(code: &str) -> Option<&'static str> {
+  rule_metadata(code).map(|metadata| metadata.docs)
+}
+
 /// Sort lint rules by priority and alphabetically.
 pub(crate) fn sort_rules_by_priority(rules: &mut Vec<Arc<dyn LintRule>>) {
   rules.sort_by(|rule1, rule2| {
@@ -239,89 +500,174 @@ fn get_all_rules_raw() -> Vec<Arc<dyn LintRule>> {
     ban_untagged_ignore::BanUntaggedIgnore::new(),
     ban_untagged_todo::BanUntaggedTodo::new(),
     ban_unused_ignore::BanUnusedIgnore::new(),
+    block_scoped_var::BlockScopedVar::new(),
     camelcase::Camelcase::new(),
+    class_methods_use_this::ClassMethodsUseThis::new(),
     constructor_super::ConstructorSuper::new(),
+    default_case::DefaultCase::new(),
+    default_case_last::DefaultCaseLast::new(),
     default_param_last::DefaultParamLast::new(),
     eqeqeq::Eqeqeq::new(),
     explicit_function_return_type::ExplicitFunctionReturnType::new(),
     explicit_module_boundary_types::ExplicitModuleBoundaryTypes::new(),
     for_direction::ForDirection::new(),
     getter_return::GetterReturn::new(),
+    hook_rules::HookRules::new(),
+    import_first::ImportFirst::new(),
+    init_declarations::InitDeclarations::new(),
+    jsx_boolean_value::JsxBooleanValue::new(),
+    jsx_curly_brace_presence::JsxCurlyBracePresence::new(),
+    lines_between_class_members::LinesBetweenClassMembers::new(),
+    max_lines::MaxLines::new(),
+    max_lines_per_function::MaxLinesPerFunction::new(),
+    max_statements::MaxStatements::new(),
+    new_cap::NewCap::new(),
+    no_accumulating_spread::NoAccumulatingSpread::new(),
+    no_alert::NoAlert::new(),
+    no_anonymous_default_export::NoAnonymousDefaultExport::new(),
     no_array_constructor::NoArrayConstructor::new(),
+    no_array_index_key::NoArrayIndexKey::new(),
+    no_async_foreach::NoAsyncForeach::new(),
     no_async_promise_executor::NoAsyncPromiseExecutor::new(),
+    no_await_expression_member::NoAwaitExpressionMember::new(),
     no_await_in_loop::NoAwaitInLoop::new(),
+    no_await_in_promise_all_map::NoAwaitInPromiseAllMap::new(),
     no_case_declarations::NoCaseDeclarations::new(),
     no_class_assign::NoClassAssign::new(),
     no_compare_neg_zero::NoCompareNegZero::new(),
     no_cond_assign::NoCondAssign::new(),
     no_const_assign::NoConstAssign::new(),
     no_constant_condition::NoConstantCondition::new(),
+    no_constructor_return::NoConstructorReturn::new(),
+    no_continue_in_finally::NoContinueInFinally::new(),
     no_control_regex::NoControlRegex::new(),
+    no_cycle::NoCycle::new(),
     no_debugger::NoDebugger::new(),
     no_delete_var::NoDeleteVar::new(),
     no_deprecated_deno_api::NoDeprecatedDenoApi::new(),
+    no_did_mount_set_state::NoDidMountSetState::new(),
+    no_document_cookie::NoDocumentCookie::new(),
     no_dupe_args::NoDupeArgs::new(),
     no_dupe_class_members::NoDupeClassMembers::new(),
     no_dupe_else_if::NoDupeElseIf::new(),
     no_dupe_keys::NoDupeKeys::new(),
     no_duplicate_case::NoDuplicateCase::new(),
+    no_duplicate_imports::NoDuplicateImports::new(),
     no_empty::NoEmpty::new(),
     no_empty_character_class::NoEmptyCharacterClass::new(),
     no_empty_enum::NoEmptyEnum::new(),
     no_empty_interface::NoEmptyInterface::new(),
+    no_empty_named_blocks::NoEmptyNamedBlocks::new(),
     no_empty_pattern::NoEmptyPattern::new(),
+    no_empty_static_block::NoEmptyStaticBlock::new(),
     no_eval::NoEval::new(),
     no_ex_assign::NoExAssign::new(),
     no_explicit_any::NoExplicitAny::new(),
+    no_extra_bind::NoExtraBind::new(),
     no_extra_boolean_cast::NoExtraBooleanCast::new(),
+    no_extra_label::NoExtraLabel::new(),
     no_extra_non_null_assertion::NoExtraNonNullAssertion::new(),
     no_extra_semi::NoExtraSemi::new(),
     no_fallthrough::NoFallthrough::new(),
+    no_floating_promises::NoFloatingPromises::new(),
     no_func_assign::NoFuncAssign::new(),
     no_global_assign::NoGlobalAssign::new(),
+    no_implicit_coercion::NoImplicitCoercion::new(),
     no_import_assign::NoImportAssign::new(),
     no_inferrable_types::NoInferrableTypes::new(),
     no_inner_declarations::NoInnerDeclarations::new(),
+    no_invalid_fetch_options::NoInvalidFetchOptions::new(),
     no_invalid_regexp::NoInvalidRegexp::new(),
+    no_invalid_this::NoInvalidThis::new(),
     no_invalid_triple_slash_reference::NoInvalidTripleSlashReference::new(),
     no_irregular_whitespace::NoIrregularWhitespace::new(),
+    no_jsx_bind::NoJsxBind::new(),
+    no_magic_string_keys::NoMagicStringKeys::new(),
     no_misused_new::NoMisusedNew::new(),
+    no_misused_promises::NoMisusedPromises::new(),
+    no_mixed_spaces_and_tabs::NoMixedSpacesAndTabs::new(),
+    no_multiple_empty_lines::NoMultipleEmptyLines::new(),
+    no_mutable_exports::NoMutableExports::new(),
     no_namespace::NoNamespace::new(),
+    no_negated_async_condition::NoNegatedAsyncCondition::new(),
+    no_negated_in_lhs::NoNegatedInLhs::new(),
+    no_new::NoNew::new(),
     no_new_symbol::NoNewSymbol::new(),
     no_non_null_asserted_optional_chain::NoNonNullAssertedOptionalChain::new(),
     no_non_null_assertion::NoNonNullAssertion::new(),
     no_obj_calls::NoObjCalls::new(),
     no_octal::NoOctal::new(),
+    no_param_reassign::NoParamReassign::new(),
     no_prototype_builtins::NoPrototypeBuiltins::new(),
     no_redeclare::NoRedeclare::new(),
     no_regex_spaces::NoRegexSpaces::new(),
+    no_relative_parent_imports::NoRelativeParentImports::new(),
+    no_restricted_exports::NoRestrictedExports::new(),
+    no_restricted_html_elements_in_head::NoRestrictedHtmlElementsInHead::new(),
+    no_restricted_jsx_elements::NoRestrictedJsxElements::new(),
+    no_restricted_modules::NoRestrictedModules::new(),
+    no_restricted_property_access::NoRestrictedPropertyAccess::new(),
+    no_return_assign::NoReturnAssign::new(),
+    no_return_in_expression_position::NoReturnInExpressionPosition::new(),
     no_self_assign::NoSelfAssign::new(),
+    no_self_import::NoSelfImport::new(),
     no_setter_return::NoSetterReturn::new(),
+    no_shadow::NoShadow::new(),
     no_shadow_restricted_names::NoShadowRestrictedNames::new(),
     no_sparse_arrays::NoSparseArrays::new(),
+    no_sync_in_async_handler::NoSyncInAsyncHandler::new(),
     no_this_alias::NoThisAlias::new(),
     no_this_before_super::NoThisBeforeSuper::new(),
+    no_throw_in_finally::NoThrowInFinally::new(),
     no_throw_literal::NoThrowLiteral::new(),
+    no_top_level_await::NoTopLevelAwait::new(),
+    no_unassigned_import::NoUnassignedImport::new(),
     no_undef::NoUndef::new(),
+    no_underscore_dangle::NoUnderscoreDangle::new(),
+    no_unescaped_entities::NoUnescapedEntities::new(),
+    no_unknown_property::NoUnknownProperty::new(),
+    no_unnecessary_condition::NoUnnecessaryCondition::new(),
     no_unreachable::NoUnreachable::new(),
+    no_unsafe_argument_count::NoUnsafeArgumentCount::new(),
+    no_unsafe_declaration_merging::NoUnsafeDeclarationMerging::new(),
     no_unsafe_finally::NoUnsafeFinally::new(),
     no_unsafe_negation::NoUnsafeNegation::new(),
+    no_unsafe_object_spread_in_jsx::NoUnsafeObjectSpreadInJsx::new(),
     no_unused_labels::NoUnusedLabels::new(),
+    no_unused_private_class_members::NoUnusedPrivateClassMembers::new(),
     no_unused_vars::NoUnusedVars::new(),
+    no_use_before_define::NoUseBeforeDefine::new(),
+    no_useless_backreference::NoUselessBackreference::new(),
     no_var::NoVar::new(),
+    no_var_requires::NoVarRequires::new(),
     no_window_prefix::NoWindowPrefix::new(),
     no_with::NoWith::new(),
+    one_var::OneVar::new(),
+    padded_blocks::PaddedBlocks::new(),
     prefer_as_const::PreferAsConst::new(),
     prefer_ascii::PreferAscii::new(),
+    prefer_at::PreferAt::new(),
     prefer_const::PreferConst::new(),
+    prefer_const_assertion_over_enum::PreferConstAssertionOverEnum::new(),
+    prefer_early_return::PreferEarlyReturn::new(),
+    prefer_includes::PreferIncludes::new(),
+    prefer_literal_enum_member::PreferLiteralEnumMember::new(),
+    prefer_named_capture_group::PreferNamedCaptureGroup::new(),
     prefer_namespace_keyword::PreferNamespaceKeyword::new(),
+    prefer_nullish_coalescing::PreferNullishCoalescing::new(),
+    prefer_optional_chain::PreferOptionalChain::new(),
     prefer_primordials::PreferPrimordials::new(),
+    prefer_string_starts_ends_with::PreferStringStartsEndsWith::new(),
+    prefer_text_content_over_inner_html::PreferTextContentOverInnerHtml::new(),
     require_await::RequireAwait::new(),
+    require_error_cause::RequireErrorCause::new(),
+    require_unicode_regexp::RequireUnicodeRegexp::new(),
     require_yield::RequireYield::new(),
     single_var_declarator::SingleVarDeclarator::new(),
     triple_slash_reference::TripleSlashReference::new(),
     use_isnan::UseIsNaN::new(),
     valid_typeof::ValidTypeof::new(),
+    vars_on_top::VarsOnTop::new(),
   ]
 }
 
@@ -351,6 +697,201 @@ mod tests {
     }
   }
 
+  #[test]
+  fn rule_metadata_by_code() {
+    let metadata = rule_metadata("no-var").unwrap();
+    assert_eq!(metadata.code, "no-var");
+    assert_eq!(metadata.tags, &["recommended"]);
+    assert_eq!(metadata.fix_applicability, Applicability::Suggestion);
+
+    assert!(rule_metadata("no-such-rule").is_none());
+  }
+
+  #[cfg(feature = "docs")]
+  #[test]
+  fn explain_rule() {
+    let docs = explain("no-var").unwrap();
+    assert!(docs.starts_with("Enforces the use of block scoped variables"));
+
+    assert!(explain("no-such-rule").is_none());
+  }
+
+  /// Validates `value` against the subset of JSON Schema used by
+  /// `config_schema`.
+  fn validate(
+    schema: &serde_json::Value,
+    value: &serde_json::Value,
+    path: &str,
+  ) -> Result<(), String> {
+    use serde_json::Value;
+
+    if let Some(ty) = schema.get("type").and_then(Value::as_str) {
+      let matches = match ty {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        _ => panic!("unsupported type `{}` in schema", ty),
+      };
+      if !matches {
+        return Err(format!("{}: expected {}", path, ty));
+      }
+    }
+
+    if let Some(variants) = schema.get("enum").and_then(Value::as_array) {
+      if !variants.contains(value) {
+        return Err(format!("{}: {} is not allowed", path, value));
+      }
+    }
+
+    if let Some(items) = schema.get("items") {
+      for (i, item) in value.as_array().unwrap().iter().enumerate() {
+        validate(items, item, &format!("{}[{}]", path, i))?;
+      }
+    }
+
+    if let Some(properties) = schema.get("properties") {
+      let allow_additional = schema
+        .get("additionalProperties")
+        .and_then(Value::as_bool)
+        .unwrap_or(true);
+      for (key, property) in value.as_object().unwrap() {
+        let property_path = format!("{}.{}", path, key);
+        match properties.get(key) {
+          Some(property_schema) => {
+            validate(property_schema, property, &property_path)?
+          }
+          None if !allow_additional => {
+            return Err(format!("{}: unknown property", property_path))
+          }
+          None => {}
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  #[test]
+  fn config_schema_validates_rules_config() {
+    let schema = config_schema();
+    let config = json!({
+      "tags": ["recommended"],
+      "include": ["ban-untagged-todo", "no-shadow"],
+      "exclude": ["no-explicit-any"],
+      "options": {
+        "no-shadow": {
+          "builtinGlobals": true,
+          "hoist": "all",
+          "allow": ["done"]
+        },
+        "no-unused-vars": {
+          "args": "after-used",
+          "argsIgnorePattern": "^_"
+        },
+        "triple-slash-reference": {
+          "types": "always"
+        }
+      }
+    });
+    assert_eq!(validate(&schema, &config, "rules"), Ok(()));
+
+    let invalid = [
+      (json!({ "tags": ["nonexistent"] }), "rules.tags[0]"),
+      (json!({ "include": ["no-such-rule"] }), "rules.include[0]"),
+      (json!({ "exclude": "no-explicit-any" }), "rules.exclude"),
+      (
+        json!({ "options": { "no-debugger": {} } }),
+        "rules.options.no-debugger",
+      ),
+      (
+        json!({ "options": { "no-shadow": { "hoist": "sometimes" } } }),
+        "rules.options.no-shadow.hoist",
+      ),
+      (
+        json!({ "options": { "new-cap": { "capIsNew": "yes" } } }),
+        "rules.options.new-cap.capIsNew",
+      ),
+      (
+        json!({ "options": { "no-undef": { "unknown": true } } }),
+        "rules.options.no-undef.unknown",
+      ),
+      (json!({ "unknown": [] }), "rules.unknown"),
+    ];
+    for (config, path) in invalid.iter() {
+      let err = validate(&schema, config, "rules").unwrap_err();
+      assert!(err.starts_with(path), "{} should fail at {}", err, path);
+    }
+  }
+
+  #[test]
+  fn options_schema_and_defaults() {
+    use crate::rules::eqeqeq::Eqeqeq;
+    use crate::rules::max_lines::MaxLines;
+
+    assert_eq!(Eqeqeq::new().options_schema(), None);
+    assert_eq!(Eqeqeq::new().default_options(), None);
+
+    let rule = MaxLines::new();
+    let schema = rule.options_schema().unwrap();
+    assert_eq!(schema["properties"]["max"]["type"], "integer");
+    assert_eq!(
+      rule.default_options(),
+      Some(
+        json!({ "max": 300, "skipBlankLines": false, "skipComments": false })
+      )
+    );
+
+    let defaults = default_options();
+    assert!(!defaults.contains_key("eqeqeq"));
+    assert_eq!(defaults.get("max-lines"), rule.default_options().as_ref());
+  }
+
+  #[test]
+  fn with_options_value() {
+    for rule in get_all_rules() {
+      match rule.default_options() {
+        Some(options) => {
+          let configured = rule.with_options_value(options).unwrap();
+          assert_eq!(configured.code(), rule.code());
+          assert!(rule.with_options_value(json!({ "hack": true })).is_err());
+        }
+        None => assert!(rule.with_options_value(json!({})).is_err()),
+      }
+    }
+  }
+
+  #[test]
+  fn default_options_match_schemas() {
+    for rule in get_all_rules_raw() {
+      let schema = rule.options_schema();
+      let defaults = rule.default_options();
+      assert_eq!(schema.is_some(), defaults.is_some(), "{}", rule.code());
+      if let (Some(schema), Some(defaults)) = (schema, defaults) {
+        if let Err(err) = validate(&schema, &defaults, rule.code()) {
+          panic!("Default options don't match the schema: {}", err);
+        }
+        for (key, value) in defaults.as_object().unwrap() {
+          if let Some(default) = schema["properties"][key].get("default") {
+            assert_eq!(default, value, "{}.{}", rule.code(), key);
+          }
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn options_schemas_describe_objects() {
+    for rule in get_all_rules_raw() {
+      if let Some(schema) = rule.options_schema() {
+        assert_eq!(schema["type"], "object", "{}", rule.code());
+        assert!(schema["properties"].is_object(), "{}", rule.code());
+      }
+    }
+  }
+
   #[test]
   fn test_get_filtered_rules() {
     let rules =
@@ -142,7 +142,7 @@ trait ExtractMethod {
 impl<'a> ExtractMethod for ast_view::ExportDecl<'a> {
   fn get_method(&self) -> Option<Method> {
     let method_name = extract_ident_from_decl(&self.decl);
-    method_name.map(Method::Method)
+    method_name.map(Method::Named)
   }
 }
 
@@ -152,7 +152,7 @@ impl<'a> ExtractMethod for ast_view::Stmt<'a> {
       ast_view::Stmt::Decl(ref decl) => extract_ident_from_decl(decl),
       _ => None,
     };
-    method_name.map(Method::Method)
+    method_name.map(Method::Named)
   }
 }
 
@@ -178,12 +178,12 @@ impl<'a> ExtractMethod for ast_view::ClassMember<'a> {
           if inner.is_static {
             Method::Static(k)
           } else {
-            Method::Method(k)
+            Method::Named(k)
           }
         })
       }
       ClassMember::Constructor(_) => {
-        Some(Method::Method("constructor".to_string()))
+        Some(Method::Named("constructor".to_string()))
       }
       _ => None,
     }
@@ -195,10 +195,16 @@ impl<'a> ExtractMethod for ast_view::TsTypeElement<'a> {
     use deno_ast::view::{Expr, Lit, TsMethodSignature, TsTypeElement};
     match self {
       TsTypeElement::TsMethodSignature(TsMethodSignature {
-        ref key, ..
-      }) => match &*key {
-        Expr::Ident(ident) => Some(Method::Method(ident.sym().to_string())),
-        Expr::Lit(Lit::Str(s)) => Some(Method::Method(s.value().to_string())),
+        ref key,
+        inner,
+        ..
+      }) => match key {
+        // `[foo](): void` is keyed by the value of `foo`, not by the name
+        // "foo", so it can't be compared with other signatures
+        Expr::Ident(_) if inner.computed => None,
+        Expr::Ident(ident) => Some(Method::Named(ident.sym().to_string())),
+        Expr::Lit(Lit::Str(s)) => Some(Method::Named(s.value().to_string())),
+        Expr::Lit(Lit::Num(n)) => Some(Method::Named(n.value().to_string())),
         _ => None,
       },
       TsTypeElement::TsCallSignatureDecl(_) => Some(Method::CallSignature),
@@ -213,7 +219,7 @@ impl<'a> ExtractMethod for ast_view::TsTypeElement<'a> {
 #[derive(PartialEq, Eq, Hash, Clone, Display)]
 enum Method {
   #[display(fmt = "{}", _0)]
-  Method(String),
+  Named(String),
   #[display(fmt = "{}", _0)]
   Static(String),
   #[display(fmt = "call")]
@@ -473,6 +479,21 @@ class Test {
 interface Foo {
   [Symbol.toStringTag](): void;
   [Symbol.iterator](): void;
+}
+      "#,
+      r#"
+const foo = "foo";
+interface Foo {
+  foo(s: string): void;
+  bar(): void;
+  [foo](n: number): void;
+}
+      "#,
+      r#"
+interface Foo {
+  0(s: string): void;
+  0(n: number): void;
+  1(): void;
 }
       "#,
     };
@@ -1035,6 +1056,41 @@ type Foo = {
               message: variant!(AdjacentOverloadSignaturesMessage, ShouldBeAdjacent, "baz"),
               hint: AdjacentOverloadSignaturesHint::GroupedTogether,
             }
+          ],
+r#"
+interface Foo {
+  0(s: string): void;
+  1(): void;
+  0(n: number): void;
+}
+      "#: [
+            {
+              line: 5,
+              col: 2,
+              message: variant!(AdjacentOverloadSignaturesMessage, ShouldBeAdjacent, "0"),
+              hint: AdjacentOverloadSignaturesHint::GroupedTogether,
+            }
+          ],
+r#"
+class Foo {
+  foo(s: string): void;
+  static foo(s: string): void;
+  foo(n: number): void;
+  static foo(n: number): void;
+}
+      "#: [
+            {
+              line: 5,
+              col: 2,
+              message: variant!(AdjacentOverloadSignaturesMessage, ShouldBeAdjacent, "foo"),
+              hint: AdjacentOverloadSignaturesHint::GroupedTogether,
+            },
+            {
+              line: 6,
+              col: 2,
+              message: variant!(AdjacentOverloadSignaturesMessage, ShouldBeAdjacent, "foo"),
+              hint: AdjacentOverloadSignaturesHint::GroupedTogether,
+            }
           ]
     };
   }
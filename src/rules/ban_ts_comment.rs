@@ -74,6 +74,10 @@ impl LintRule for BanTsComment {
     CODE
   }
 
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["@ts-"]
+  }
+
   fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
     unreachable!();
   }
@@ -41,6 +41,7 @@ impl LintRule for BanUntaggedIgnore {
       context
         .line_ignore_directives()
         .values()
+        .chain(context.next_line_ignore_directives().values())
         .filter_map(|d| d.ignore_all().then(|| d.span())),
     );
 
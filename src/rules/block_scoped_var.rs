@@ -0,0 +1,218 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::scope_tree::{Binding, ScopeTree};
+use crate::scopes::BindingKind;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::Span;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct BlockScopedVar;
+
+const CODE: &str = "block-scoped-var";
+
+#[derive(Display)]
+enum BlockScopedVarMessage {
+  #[display(fmt = "`{}` is used outside of the block it is declared in", _0)]
+  OutsideOfBlock(String),
+}
+
+#[derive(Display)]
+enum BlockScopedVarHint {
+  #[display(
+    fmt = "Move the declaration to the scope where the variable is used, or use `let` or `const` instead"
+  )]
+  MoveDeclaration,
+}
+
+impl LintRule for BlockScopedVar {
+  fn new() -> Arc<Self> {
+    Arc::new(BlockScopedVar)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program<'_>,
+  ) {
+    let tree = context.scope_tree();
+    let mut diagnostics = Vec::new();
+    for scope in tree.scopes() {
+      let bindings = tree.scope(scope).bindings();
+      for (i, binding) in bindings.iter().enumerate() {
+        let binding = tree.binding(*binding);
+        // Redeclarations are checked along with the first declaration, which
+        // holds all references
+        if bindings[..i]
+          .iter()
+          .any(|b| tree.binding(*b).name() == binding.name())
+        {
+          continue;
+        }
+        let declarations: Vec<&Binding> = bindings[i..]
+          .iter()
+          .map(|b| tree.binding(*b))
+          .filter(|b| b.name() == binding.name())
+          .collect();
+        // Another kind of declaration, like a parameter, makes the variable
+        // belong to the whole function anyway
+        if declarations.iter().any(|b| b.kind() != BindingKind::Var) {
+          continue;
+        }
+
+        let blocks: Vec<Span> = declarations
+          .iter()
+          .map(|b| enclosing_block(tree, b))
+          .collect();
+        for reference in binding.references() {
+          let span = reference.span();
+          if !blocks.iter().any(|block| block.contains(span)) {
+            diagnostics.push((span, binding.name().to_string()));
+          }
+        }
+      }
+    }
+
+    for (span, name) in diagnostics {
+      context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        BlockScopedVarMessage::OutsideOfBlock(name),
+        BlockScopedVarHint::MoveDeclaration,
+      );
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/block_scoped_var.md")
+  }
+}
+
+/// Returns the span of the scope a `var` would belong to if it was block
+/// scoped, e.g. an `if` body or a `for` loop.
+fn enclosing_block(tree: &ScopeTree, binding: &Binding) -> Span {
+  let mut scope = binding.scope();
+  while let Some(child) = tree
+    .scope(scope)
+    .children()
+    .iter()
+    .copied()
+    .find(|child| tree.scope(*child).span().contains(binding.span()))
+  {
+    scope = child;
+  }
+  tree.scope(scope).span()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.32.0/tests/lib/rules/block-scoped-var.js
+  // MIT Licensed.
+
+  #[test]
+  fn block_scoped_var_valid() {
+    assert_lint_ok! {
+      BlockScopedVar,
+      "function f() { } f(); var exports = { f: f };",
+      "var f = () => {}; f(); var exports = { f: f };",
+      "!function f(){ f; }",
+      "function f() { } f(); var exports = { f: f };",
+      "function f() { var a, b; { a = 1; b = 2; } }",
+      "var a; for (a in {}) {}",
+      "function f() { if (true) { var a = 1; a++; } }",
+      "function f() { for (var i = 0; i < 10; i++) { i; } }",
+      "function f(a) { a = 1; }",
+      "var a = 1; { a; }",
+      "function f() { var a; function g() { a = 1; } }",
+      "if (true) { var a = 1; } else { var a = 2; }",
+      "switch (x) { case 1: var a = 1; break; case 2: a = 2; break; }",
+      "function f() { try { var a = 1; a; } catch (e) {} }",
+      "if (true) { let a = 1; } a;",
+      "if (true) { var a = 1; } foo.a; ({ a: 1 });",
+      "declare var a: number; a;",
+      "if (true) { var { a, b: [c] } = obj; a; c; }",
+      "function f(a) { if (c) { var a; } a; }",
+    };
+  }
+
+  #[test]
+  fn block_scoped_var_invalid() {
+    assert_lint_err! {
+      BlockScopedVar,
+      "function f() { if (true) { var a = 1; } a; }": [
+        {
+          col: 40,
+          message: BlockScopedVarMessage::OutsideOfBlock("a".to_string()),
+          hint: BlockScopedVarHint::MoveDeclaration,
+        }
+      ],
+      "function f() { a = 1; { var a; } }": [
+        {
+          col: 15,
+          message: BlockScopedVarMessage::OutsideOfBlock("a".to_string()),
+          hint: BlockScopedVarHint::MoveDeclaration,
+        }
+      ],
+      "for (var i = 0; i < 10; i++) {} i;": [
+        {
+          col: 32,
+          message: BlockScopedVarMessage::OutsideOfBlock("i".to_string()),
+          hint: BlockScopedVarHint::MoveDeclaration,
+        }
+      ],
+      "for (var key in obj) {} console.log(key);": [
+        {
+          col: 36,
+          message: BlockScopedVarMessage::OutsideOfBlock("key".to_string()),
+          hint: BlockScopedVarHint::MoveDeclaration,
+        }
+      ],
+      "if (true) { var { a, b: [c] } = obj; } a; c;": [
+        {
+          col: 39,
+          message: BlockScopedVarMessage::OutsideOfBlock("a".to_string()),
+          hint: BlockScopedVarHint::MoveDeclaration,
+        },
+        {
+          col: 42,
+          message: BlockScopedVarMessage::OutsideOfBlock("c".to_string()),
+          hint: BlockScopedVarHint::MoveDeclaration,
+        }
+      ],
+      "function f() { try { var a = 1; } catch (e) { a; } }": [
+        {
+          col: 46,
+          message: BlockScopedVarMessage::OutsideOfBlock("a".to_string()),
+          hint: BlockScopedVarHint::MoveDeclaration,
+        }
+      ],
+      "function f() { if (c) { var a; } function g() { a = 1; } }": [
+        {
+          col: 48,
+          message: BlockScopedVarMessage::OutsideOfBlock("a".to_string()),
+          hint: BlockScopedVarHint::MoveDeclaration,
+        }
+      ],
+      "if (true) { var a = 1; } ({ a });": [
+        {
+          col: 28,
+          message: BlockScopedVarMessage::OutsideOfBlock("a".to_string()),
+          hint: BlockScopedVarHint::MoveDeclaration,
+        }
+      ],
+    };
+  }
+}
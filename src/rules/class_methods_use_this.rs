@@ -0,0 +1,447 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  Class, ClassMember, Expr, Function, Lit, PropName, Super, ThisExpr,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node as SwcNode;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct ClassMethodsUseThis {
+  options: ClassMethodsUseThisOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct ClassMethodsUseThisOptions {
+  /// Names of methods that are allowed not to use `this`, with a leading `#`
+  /// for private names.
+  pub except_methods: Vec<String>,
+  /// Whether instance fields initialized with a function are checked too.
+  pub enforce_for_class_fields: bool,
+}
+
+impl Default for ClassMethodsUseThisOptions {
+  fn default() -> Self {
+    ClassMethodsUseThisOptions {
+      except_methods: Vec::new(),
+      enforce_for_class_fields: true,
+    }
+  }
+}
+
+impl ClassMethodsUseThis {
+  pub fn with_options(options: ClassMethodsUseThisOptions) -> Arc<Self> {
+    Arc::new(ClassMethodsUseThis { options })
+  }
+}
+
+const CODE: &str = "class-methods-use-this";
+
+#[derive(Display)]
+enum ClassMethodsUseThisMessage {
+  #[display(fmt = "Class method `{}` doesn't use `this`", _0)]
+  Unused(String),
+}
+
+#[derive(Display)]
+enum ClassMethodsUseThisHint {
+  #[display(fmt = "Make the method static, or move it out of the class")]
+  MakeStatic,
+}
+
+impl LintRule for ClassMethodsUseThis {
+  fn new() -> Arc<Self> {
+    Self::with_options(ClassMethodsUseThisOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["class"]
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "exceptMethods": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Names of methods that are allowed not to use `this`."
+        },
+        "enforceForClassFields": {
+          "type": "boolean",
+          "description": "Whether instance fields initialized with a function are checked.",
+          "default": true
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(ClassMethodsUseThisOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    ClassMethodsUseThisHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/class_methods_use_this.md")
+  }
+}
+
+/// Looks for `this` or `super` that refer to the instance the visited code
+/// runs on, skipping nested functions and classes which have their own.
+#[derive(Default)]
+struct ThisFinder {
+  found: bool,
+}
+
+impl ThisFinder {
+  fn uses_this<N: VisitWith<Self>>(node: &N) -> bool {
+    let mut finder = ThisFinder::default();
+    node.visit_with(&DUMMY_NODE, &mut finder);
+    finder.found
+  }
+}
+
+impl Visit for ThisFinder {
+  noop_visit_type!();
+
+  fn visit_this_expr(&mut self, _: &ThisExpr, _: &dyn SwcNode) {
+    self.found = true;
+  }
+
+  fn visit_super(&mut self, _: &Super, _: &dyn SwcNode) {
+    self.found = true;
+  }
+
+  fn visit_function(&mut self, _: &Function, _: &dyn SwcNode) {}
+
+  fn visit_class(&mut self, class: &Class, _: &dyn SwcNode) {
+    class.super_class.visit_with(&DUMMY_NODE, self);
+  }
+}
+
+/// Checks if the body of `function` refers to the instance.
+fn function_uses_this(function: &Function) -> bool {
+  function.params.iter().any(ThisFinder::uses_this)
+    || function.body.iter().any(ThisFinder::uses_this)
+}
+
+fn prop_name(key: &PropName) -> Option<String> {
+  match key {
+    PropName::Ident(ident) => Some(ident.sym.to_string()),
+    PropName::Str(str) => Some(str.value.to_string()),
+    PropName::Num(num) => Some(num.value.to_string()),
+    _ => None,
+  }
+}
+
+struct ClassMethodsUseThisHandler<'a> {
+  options: &'a ClassMethodsUseThisOptions,
+}
+
+impl<'a> ClassMethodsUseThisHandler<'a> {
+  /// Reports the member at `span` unless it's excepted by its `name`.
+  /// Members with computed keys, whose `name` is the key as written, are
+  /// never excepted.
+  fn check(
+    &self,
+    ctx: &mut Context,
+    span: Span,
+    name: String,
+    is_computed: bool,
+  ) {
+    if !is_computed && self.options.except_methods.contains(&name) {
+      return;
+    }
+    ctx.add_diagnostic_with_hint(
+      span,
+      CODE,
+      ClassMethodsUseThisMessage::Unused(name),
+      ClassMethodsUseThisHint::MakeStatic,
+    );
+  }
+
+  /// Checks if a field initializer is a function that doesn't refer to the
+  /// instance. Other initializers aren't checked.
+  fn is_field_without_this(&self, value: &Option<Box<Expr>>) -> bool {
+    if !self.options.enforce_for_class_fields {
+      return false;
+    }
+    match value.as_deref() {
+      Some(Expr::Fn(fn_expr)) => !function_uses_this(&fn_expr.function),
+      Some(Expr::Arrow(arrow)) => !ThisFinder::uses_this(arrow),
+      _ => false,
+    }
+  }
+}
+
+impl<'a> Handler for ClassMethodsUseThisHandler<'a> {
+  fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
+    for member in &class.inner.body {
+      match member {
+        ClassMember::Method(method) => {
+          let function = &method.function;
+          if method.is_static
+            || function.body.is_none()
+            || function_uses_this(function)
+          {
+            continue;
+          }
+          let (name, is_computed) = match prop_name(&method.key) {
+            Some(name) => (name, false),
+            None => (
+              ctx.file_text_substring(&method.key.span()).to_string(),
+              true,
+            ),
+          };
+          self.check(ctx, method.span, name, is_computed);
+        }
+        ClassMember::PrivateMethod(method) => {
+          let function = &method.function;
+          if method.is_static
+            || function.body.is_none()
+            || function_uses_this(function)
+          {
+            continue;
+          }
+          let name = format!("#{}", method.key.id.sym);
+          self.check(ctx, method.span, name, false);
+        }
+        ClassMember::ClassProp(prop) => {
+          if prop.is_static || !self.is_field_without_this(&prop.value) {
+            continue;
+          }
+          let (name, is_computed) = match (&*prop.key, prop.computed) {
+            (Expr::Ident(ident), false) => (ident.sym.to_string(), false),
+            (Expr::Lit(Lit::Str(str)), false) => (str.value.to_string(), false),
+            (key, false) => {
+              (ctx.file_text_substring(&key.span()).to_string(), false)
+            }
+            (key, true) => {
+              let key = ctx.file_text_substring(&key.span());
+              (format!("[{}]", key), true)
+            }
+          };
+          self.check(ctx, prop.span, name, is_computed);
+        }
+        ClassMember::PrivateProp(prop) => {
+          if prop.is_static || !self.is_field_without_this(&prop.value) {
+            continue;
+          }
+          let name = format!("#{}", prop.key.id.sym);
+          self.check(ctx, prop.span, name, false);
+        }
+        _ => {}
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/class-methods-use-this.js
+  // MIT Licensed.
+
+  #[test]
+  fn class_methods_use_this_valid() {
+    assert_lint_ok! {
+      ClassMethodsUseThis,
+      "class A { constructor() {} }",
+      "class A { foo() { this } }",
+      "class A { foo() { this.bar = 'bar'; } }",
+      "class A { foo() { bar(this); } }",
+      "class A extends B { foo() { super.foo(); } }",
+      "class A { foo() { if (true) { return this; } } }",
+      "class A { static foo() {} }",
+      "class A { foo() { return () => this; } }",
+      "class A { foo(a = this.bar) {} }",
+      "class A { get foo() { return this.bar; } }",
+      "class A { set foo(value) { this.bar = value; } }",
+      "class A { #foo() { this.bar(); } }",
+      "class A { foo = () => this.bar; }",
+      "class A { foo = function () { return this.bar; }; }",
+      "class A { static foo = () => {}; }",
+      "class A { foo = 1; }",
+      "class A { foo; }",
+      "abstract class A { abstract foo(): void; }",
+      "class A { foo(): void; foo(a?: number) { this.a = a; } }",
+      "({ a: function () {} });",
+    };
+
+    assert_lint_ok! {
+      ClassMethodsUseThis,
+      options: ClassMethodsUseThisOptions {
+        except_methods: vec![
+          "foo".to_string(),
+          "#bar".to_string(),
+          "baz".to_string(),
+        ],
+        enforce_for_class_fields: true,
+      },
+      "class A { foo() {} }",
+      "class A { #bar() {} }",
+      "class A { 'foo'() {} }",
+      "class A { baz = () => {}; }",
+    };
+
+    assert_lint_ok! {
+      ClassMethodsUseThis,
+      options: ClassMethodsUseThisOptions {
+        except_methods: vec![],
+        enforce_for_class_fields: false,
+      },
+      "class A { foo = () => {}; }",
+      "class A { #foo = function () {}; }",
+    };
+  }
+
+  #[test]
+  fn class_methods_use_this_invalid() {
+    assert_lint_err! {
+      ClassMethodsUseThis,
+      "class A { foo() {} }": [
+        {
+          col: 10,
+          message: ClassMethodsUseThisMessage::Unused("foo".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class A { foo() { function bar() { this.baz(); } } }": [
+        {
+          col: 10,
+          message: ClassMethodsUseThisMessage::Unused("foo".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class A { foo() { class B { bar() { this.baz(); } } return B; } }": [
+        {
+          col: 10,
+          message: ClassMethodsUseThisMessage::Unused("foo".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class A { get foo() { return 1; } set foo(value) {} }": [
+        {
+          col: 10,
+          message: ClassMethodsUseThisMessage::Unused("foo".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        },
+        {
+          col: 34,
+          message: ClassMethodsUseThisMessage::Unused("foo".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class A { #foo() {} }": [
+        {
+          col: 10,
+          message: ClassMethodsUseThisMessage::Unused("#foo".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class A { [foo]() {} }": [
+        {
+          col: 10,
+          message: ClassMethodsUseThisMessage::Unused("[foo]".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class A { foo = () => {}; }": [
+        {
+          col: 10,
+          message: ClassMethodsUseThisMessage::Unused("foo".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class A { #foo = function () {}; }": [
+        {
+          col: 10,
+          message: ClassMethodsUseThisMessage::Unused("#foo".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class A { foo = () => { function bar() { return this; } }; }": [
+        {
+          col: 10,
+          message: ClassMethodsUseThisMessage::Unused("foo".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class A { [foo] = () => {}; }": [
+        {
+          col: 10,
+          message: ClassMethodsUseThisMessage::Unused("[foo]".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ClassMethodsUseThis,
+      options: ClassMethodsUseThisOptions {
+        except_methods: vec!["bar".to_string(), "foo".to_string()],
+        enforce_for_class_fields: true,
+      },
+      "class A { bar() {} baz() {} [foo]() {} #foo() {} }": [
+        {
+          col: 19,
+          message: ClassMethodsUseThisMessage::Unused("baz".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        },
+        {
+          col: 28,
+          message: ClassMethodsUseThisMessage::Unused("[foo]".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        },
+        {
+          col: 39,
+          message: ClassMethodsUseThisMessage::Unused("#foo".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+    };
+  }
+}
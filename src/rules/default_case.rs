@@ -0,0 +1,264 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct DefaultCase {
+  options: DefaultCaseOptions,
+  comment_pattern: Regex,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct DefaultCaseOptions {
+  /// Regular expression matched against the last comment of a `switch`
+  /// statement to allow omitting the `default` clause. Defaults to
+  /// `^no default$` (case-insensitive). Invalid patterns fall back to the
+  /// default.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub comment_pattern: Option<String>,
+}
+
+static DEFAULT_COMMENT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+  RegexBuilder::new(r"^no default$")
+    .case_insensitive(true)
+    .build()
+    .unwrap()
+});
+
+impl DefaultCase {
+  pub fn with_options(options: DefaultCaseOptions) -> Arc<Self> {
+    let comment_pattern = options
+      .comment_pattern
+      .as_deref()
+      .and_then(|p| Regex::new(p).ok())
+      .unwrap_or_else(|| DEFAULT_COMMENT_PATTERN.clone());
+    Arc::new(DefaultCase {
+      options,
+      comment_pattern,
+    })
+  }
+}
+
+const CODE: &str = "default-case";
+
+#[derive(Display)]
+enum DefaultCaseMessage {
+  #[display(fmt = "Expected a `default` case")]
+  MissingDefault,
+}
+
+#[derive(Display)]
+enum DefaultCaseHint {
+  #[display(
+    fmt = "Add a `default` case, or a `// no default` comment at the end of the `switch` if it's intentionally omitted"
+  )]
+  AddDefault,
+}
+
+impl LintRule for DefaultCase {
+  fn new() -> Arc<Self> {
+    Self::with_options(DefaultCaseOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "commentPattern": {
+          "type": "string",
+          "description": "Regular expression matching a comment that allows omitting the `default` clause."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(DefaultCaseOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    DefaultCaseHandler {
+      comment_pattern: &self.comment_pattern,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/default_case.md")
+  }
+}
+
+struct DefaultCaseHandler<'a> {
+  comment_pattern: &'a Regex,
+}
+
+impl<'a> Handler for DefaultCaseHandler<'a> {
+  fn switch_stmt(
+    &mut self,
+    switch_stmt: &ast_view::SwitchStmt,
+    ctx: &mut Context,
+  ) {
+    let last_case = match switch_stmt.cases.last() {
+      Some(case) => case,
+      // An empty `switch` has nothing to default from
+      None => return,
+    };
+
+    if switch_stmt.cases.iter().any(|case| case.test.is_none()) {
+      return;
+    }
+
+    // The escape comment must be the last thing in the `switch` body.
+    let last_case_hi = last_case.span().hi();
+    let switch_hi = switch_stmt.span().hi();
+    let last_comment = ctx
+      .comments_within(Span::new(last_case_hi, switch_hi, Default::default()))
+      .last();
+    if let Some(comment) = last_comment {
+      if self.comment_pattern.is_match(comment.text.trim()) {
+        return;
+      }
+    }
+
+    ctx.add_diagnostic_with_hint(
+      switch_stmt.span(),
+      CODE,
+      DefaultCaseMessage::MissingDefault,
+      DefaultCaseHint::AddDefault,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_case_valid() {
+    assert_lint_ok! {
+      DefaultCase,
+      "switch (a) {}",
+      "switch (a) { case 1: break; default: break; }",
+      "switch (a) { default: break; case 1: break; }",
+      r#"
+switch (a) {
+  case 1:
+    break;
+  // no default
+}
+"#,
+      r#"
+switch (a) {
+  case 1:
+    break;
+  // No Default
+}
+"#,
+      r#"
+switch (a) {
+  case 1:
+    foo();
+    /* no default */
+}
+"#,
+    };
+
+    assert_lint_ok! {
+      DefaultCase,
+      options: DefaultCaseOptions {
+        comment_pattern: Some("^skip default".to_string()),
+      },
+      r#"
+switch (a) {
+  case 1:
+    break;
+  // skip default: handled below
+}
+"#,
+    };
+  }
+
+  #[test]
+  fn default_case_invalid() {
+    assert_lint_err! {
+      DefaultCase,
+      DefaultCaseMessage::MissingDefault,
+      DefaultCaseHint::AddDefault,
+      "switch (a) { case 1: break; }": [{ col: 0 }],
+      r#"
+switch (a) {
+  // no default
+  case 1:
+    break;
+}
+"#: [{ line: 2, col: 0 }],
+      r#"
+switch (a) {
+  case 1:
+    break;
+  // no default
+  // TODO
+}
+"#: [{ line: 2, col: 0 }],
+      r#"
+switch (a) {
+  case 1:
+    break;
+  // nodefault
+}
+"#: [{ line: 2, col: 0 }],
+      "function f() { switch (a) { case 1: return; } }": [{ col: 15 }],
+    };
+
+    assert_lint_err! {
+      DefaultCase,
+      options: DefaultCaseOptions {
+        comment_pattern: Some("^skip default".to_string()),
+      },
+      DefaultCaseMessage::MissingDefault,
+      DefaultCaseHint::AddDefault,
+      r#"
+switch (a) {
+  case 1:
+    break;
+  // no default
+}
+"#: [{ line: 2, col: 0 }],
+    };
+  }
+}
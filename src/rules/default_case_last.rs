@@ -0,0 +1,113 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct DefaultCaseLast;
+
+const CODE: &str = "default-case-last";
+
+#[derive(Display)]
+enum DefaultCaseLastMessage {
+  #[display(fmt = "`default` clause should be the last clause")]
+  NotLast,
+}
+
+#[derive(Display)]
+enum DefaultCaseLastHint {
+  #[display(fmt = "Move the `default` clause to the end of the `switch`")]
+  MoveToEnd,
+}
+
+impl LintRule for DefaultCaseLast {
+  fn new() -> Arc<Self> {
+    Arc::new(DefaultCaseLast)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    DefaultCaseLastHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/default_case_last.md")
+  }
+}
+
+struct DefaultCaseLastHandler;
+
+impl Handler for DefaultCaseLastHandler {
+  fn switch_stmt(
+    &mut self,
+    switch_stmt: &ast_view::SwitchStmt,
+    ctx: &mut Context,
+  ) {
+    let cases = &switch_stmt.cases;
+    if let Some(idx) = cases.iter().position(|case| case.test.is_none()) {
+      if idx + 1 != cases.len() {
+        ctx.add_diagnostic_with_hint(
+          cases[idx].span(),
+          CODE,
+          DefaultCaseLastMessage::NotLast,
+          DefaultCaseLastHint::MoveToEnd,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_case_last_valid() {
+    assert_lint_ok! {
+      DefaultCaseLast,
+      "switch (a) {}",
+      "switch (a) { case 1: break; }",
+      "switch (a) { default: break; }",
+      "switch (a) { case 1: break; default: break; }",
+      "switch (a) { case 1: case 2: default: foo(); }",
+    };
+  }
+
+  #[test]
+  fn default_case_last_invalid() {
+    assert_lint_err! {
+      DefaultCaseLast,
+      DefaultCaseLastMessage::NotLast,
+      DefaultCaseLastHint::MoveToEnd,
+      "switch (a) { default: break; case 1: break; }": [{ col: 13 }],
+      "switch (a) { case 1: break; default: break; case 2: break; }": [{ col: 28 }],
+      "switch (a) { case 1: default: case 2: foo(); }": [{ col: 21 }],
+      r#"
+switch (a) {
+  case 1:
+    break;
+  default:
+    // falls through
+  case 2:
+    break;
+}
+"#: [{ line: 5, col: 2 }],
+    };
+  }
+}
@@ -38,6 +38,10 @@ impl LintRule for Eqeqeq {
     CODE
   }
 
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["==", "!="]
+  }
+
   fn lint_program<'view>(
     &self,
     _context: &mut Context<'view>,
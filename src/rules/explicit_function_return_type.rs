@@ -42,6 +42,10 @@ impl LintRule for ExplicitFunctionReturnType {
     context: &mut Context,
     program: Program,
   ) {
+    // JavaScript files can't have type annotations.
+    if !context.is_typescript() {
+      return;
+    }
     ExplicitFunctionReturnTypeHandler.traverse(program, context);
   }
 
@@ -78,6 +82,19 @@ mod tests {
       "const bar = (a: string) => { }",
       "const barTyped = (a: string): Promise<void> => { }",
     };
+
+    assert_lint_ok! {
+      ExplicitFunctionReturnType,
+      filename: "foo.js",
+      "function foo() { }",
+      "const bar = function() { };",
+    };
+
+    assert_lint_ok! {
+      ExplicitFunctionReturnType,
+      filename: "foo.mjs",
+      "export function foo() { }",
+    };
   }
 
   #[test]
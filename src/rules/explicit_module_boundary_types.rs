@@ -52,6 +52,10 @@ impl LintRule for ExplicitModuleBoundaryTypes {
     context: &mut Context,
     program: ast_view::Program,
   ) {
+    // JavaScript files can't have type annotations.
+    if !context.is_typescript() {
+      return;
+    }
     ExplicitModuleBoundaryTypesHandler.traverse(program, context);
   }
 
@@ -191,6 +195,19 @@ mod tests {
       "export var arrowFn = (arg: unknown): string => `test ${arg}`",
       "class Test { method() { return; } }",
     };
+
+    assert_lint_ok! {
+      ExplicitModuleBoundaryTypes,
+      filename: "foo.js",
+      "export function test() { return; }",
+      "export var arrowFn = (arg) => `test ${arg}`",
+    };
+
+    assert_lint_ok! {
+      ExplicitModuleBoundaryTypes,
+      filename: "foo.jsx",
+      "export function Foo() { return <div />; }",
+    };
   }
 
   #[test]
@@ -234,6 +251,17 @@ mod tests {
         message: ExplicitModuleBoundaryTypesMessage::MissingRetType,
         hint: ExplicitModuleBoundaryTypesHint::AddRetType,
       }],
-    }
+    };
+
+    assert_lint_err! {
+      ExplicitModuleBoundaryTypes,
+      filename: "foo.tsx",
+      "export function Foo() { return <div />; }": [
+      {
+        col: 7,
+        message: ExplicitModuleBoundaryTypesMessage::MissingRetType,
+        hint: ExplicitModuleBoundaryTypesHint::AddRetType,
+      }],
+    };
   }
 }
@@ -48,6 +48,10 @@ impl LintRule for GetterReturn {
     CODE
   }
 
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["get"]
+  }
+
   fn lint_program<'view>(
     &self,
     context: &mut Context<'view>,
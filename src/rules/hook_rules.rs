@@ -0,0 +1,492 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  ArrowExpr, BinExpr, BinaryOp, BlockStmtOrExpr, CallExpr, Class, CondExpr,
+  DoWhileStmt, Expr, ExprOrSuper, ForInStmt, ForOfStmt, ForStmt, Function,
+  IfStmt, Pat, ReturnStmt, SwitchStmt, WhileStmt,
+};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node as SwcNode;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct HookRules;
+
+const CODE: &str = "hook-rules";
+
+#[derive(Display)]
+enum HookRulesMessage {
+  #[display(fmt = "React Hook `{}` is called conditionally", _0)]
+  Conditional(String),
+  #[display(fmt = "React Hook `{}` is called in a loop", _0)]
+  Loop(String),
+  #[display(fmt = "React Hook `{}` is called after an early return", _0)]
+  AfterReturn(String),
+}
+
+#[derive(Display)]
+enum HookRulesHint {
+  #[display(
+    fmt = "Hooks must be called in the same order on every render; call it at the top level of the component or hook"
+  )]
+  TopLevel,
+}
+
+impl LintRule for HookRules {
+  fn new() -> Arc<Self> {
+    Arc::new(HookRules)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    HookRulesHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/hook_rules.md")
+  }
+}
+
+/// Checks if `name` looks like a hook, e.g. `useState`.
+fn is_hook_name(name: &str) -> bool {
+  matches!(
+    name.strip_prefix("use").and_then(|rest| rest.chars().next()),
+    Some(c) if c.is_ascii_uppercase() || c.is_ascii_digit()
+  )
+}
+
+fn is_component_name(name: &str) -> bool {
+  matches!(name.chars().next(), Some(c) if c.is_ascii_uppercase())
+}
+
+/// Checks if the function is a component or a custom hook, based on its name,
+/// e.g. `function Button() {}` or `const useToggle = () => {}`. Callbacks of
+/// `memo` and `forwardRef` are considered components as well.
+fn is_component_or_hook(function: Node) -> bool {
+  let is_named = |name: &str| is_component_name(name) || is_hook_name(name);
+  let parent = match function
+    .ancestors()
+    .find(|ancestor| !matches!(ancestor, Node::ParenExpr(_)))
+  {
+    Some(parent) => parent,
+    None => return false,
+  };
+
+  match parent {
+    Node::FnDecl(fn_decl) => is_named(fn_decl.ident.sym()),
+    Node::FnExpr(fn_expr) => {
+      if let Some(ident) = &fn_expr.inner.ident {
+        if is_named(&ident.sym) {
+          return true;
+        }
+      }
+      is_component_or_hook(fn_expr.as_node())
+    }
+    Node::VarDeclarator(declarator) => matches!(
+      &declarator.inner.name,
+      Pat::Ident(binding) if is_named(&binding.id.sym)
+    ),
+    Node::ExprOrSpread(arg) => match arg.parent() {
+      Node::CallExpr(call_expr) => {
+        let name = match &call_expr.inner.callee {
+          ExprOrSuper::Expr(callee) => match &**callee {
+            Expr::Ident(ident) => &ident.sym,
+            Expr::Member(member_expr) if !member_expr.computed => {
+              match &*member_expr.prop {
+                Expr::Ident(ident) => &ident.sym,
+                _ => return false,
+              }
+            }
+            _ => return false,
+          },
+          ExprOrSuper::Super(_) => return false,
+        };
+        *name == *"memo" || *name == *"forwardRef"
+      }
+      _ => false,
+    },
+    _ => false,
+  }
+}
+
+/// Returns the span of the callee if the call is a hook call, e.g.
+/// `useState()` or `React.useState()`.
+fn hook_callee(call_expr: &CallExpr) -> Option<Span> {
+  let callee = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => &**callee,
+    ExprOrSuper::Super(_) => return None,
+  };
+  match callee {
+    Expr::Ident(ident) if is_hook_name(&ident.sym) => Some(ident.span),
+    Expr::Member(member_expr) if !member_expr.computed => {
+      let is_namespace = match &member_expr.obj {
+        ExprOrSuper::Expr(obj) => matches!(
+          &**obj,
+          Expr::Ident(ident) if is_component_name(&ident.sym)
+        ),
+        ExprOrSuper::Super(_) => false,
+      };
+      match &*member_expr.prop {
+        Expr::Ident(prop) if is_namespace && is_hook_name(&prop.sym) => {
+          Some(member_expr.span)
+        }
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+struct HookRulesHandler;
+
+impl HookRulesHandler {
+  fn check<N: VisitWith<HookCallFinder>>(&self, body: &N, ctx: &mut Context) {
+    let mut finder = HookCallFinder {
+      conditional_depth: 0,
+      loop_depth: 0,
+      after_return: false,
+      calls: Vec::new(),
+    };
+    body.visit_with(&DUMMY_NODE, &mut finder);
+
+    for (span, callee, kind) in finder.calls {
+      let name = ctx.file_text_substring(&callee).to_string();
+      let message = match kind {
+        CallKind::Conditional => HookRulesMessage::Conditional(name),
+        CallKind::Loop => HookRulesMessage::Loop(name),
+        CallKind::AfterReturn => HookRulesMessage::AfterReturn(name),
+      };
+      ctx.add_diagnostic_with_hint(
+        span,
+        CODE,
+        message,
+        HookRulesHint::TopLevel,
+      );
+    }
+  }
+}
+
+impl Handler for HookRulesHandler {
+  fn function(&mut self, function: &ast_view::Function, ctx: &mut Context) {
+    if let Some(body) = &function.inner.body {
+      if is_component_or_hook(function.as_node()) {
+        self.check(body, ctx);
+      }
+    }
+  }
+
+  fn arrow_expr(
+    &mut self,
+    arrow_expr: &ast_view::ArrowExpr,
+    ctx: &mut Context,
+  ) {
+    if is_component_or_hook(arrow_expr.as_node()) {
+      match &arrow_expr.inner.body {
+        BlockStmtOrExpr::BlockStmt(block) => self.check(block, ctx),
+        BlockStmtOrExpr::Expr(expr) => self.check(&**expr, ctx),
+      }
+    }
+  }
+}
+
+enum CallKind {
+  Conditional,
+  Loop,
+  AfterReturn,
+}
+
+/// Finds hook calls that may not run on every render. Nested functions are
+/// skipped, since they are checked separately if they are components or
+/// hooks themselves.
+struct HookCallFinder {
+  conditional_depth: usize,
+  loop_depth: usize,
+  after_return: bool,
+  calls: Vec<(Span, Span, CallKind)>,
+}
+
+impl HookCallFinder {
+  fn conditional(&mut self, op: impl FnOnce(&mut Self)) {
+    self.conditional_depth += 1;
+    op(self);
+    self.conditional_depth -= 1;
+  }
+
+  fn in_loop(&mut self, op: impl FnOnce(&mut Self)) {
+    self.loop_depth += 1;
+    op(self);
+    self.loop_depth -= 1;
+  }
+}
+
+impl Visit for HookCallFinder {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn SwcNode) {}
+
+  fn visit_arrow_expr(
+    &mut self,
+    _arrow_expr: &ArrowExpr,
+    _parent: &dyn SwcNode,
+  ) {
+  }
+
+  fn visit_class(&mut self, _class: &Class, _parent: &dyn SwcNode) {}
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn SwcNode) {
+    if_stmt.test.visit_with(if_stmt, self);
+    self.conditional(|this| {
+      if_stmt.cons.visit_with(if_stmt, this);
+      if_stmt.alt.visit_with(if_stmt, this);
+    });
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _parent: &dyn SwcNode) {
+    cond_expr.test.visit_with(cond_expr, self);
+    self.conditional(|this| {
+      cond_expr.cons.visit_with(cond_expr, this);
+      cond_expr.alt.visit_with(cond_expr, this);
+    });
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn SwcNode) {
+    bin_expr.left.visit_with(bin_expr, self);
+    if matches!(
+      bin_expr.op,
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    ) {
+      self.conditional(|this| bin_expr.right.visit_with(bin_expr, this));
+    } else {
+      bin_expr.right.visit_with(bin_expr, self);
+    }
+  }
+
+  fn visit_switch_stmt(
+    &mut self,
+    switch_stmt: &SwitchStmt,
+    _parent: &dyn SwcNode,
+  ) {
+    switch_stmt.discriminant.visit_with(switch_stmt, self);
+    self.conditional(|this| switch_stmt.cases.visit_with(switch_stmt, this));
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn SwcNode) {
+    for_stmt.init.visit_with(for_stmt, self);
+    self.in_loop(|this| {
+      for_stmt.test.visit_with(for_stmt, this);
+      for_stmt.update.visit_with(for_stmt, this);
+      for_stmt.body.visit_with(for_stmt, this);
+    });
+  }
+
+  fn visit_for_in_stmt(
+    &mut self,
+    for_in_stmt: &ForInStmt,
+    _parent: &dyn SwcNode,
+  ) {
+    for_in_stmt.right.visit_with(for_in_stmt, self);
+    self.in_loop(|this| for_in_stmt.body.visit_with(for_in_stmt, this));
+  }
+
+  fn visit_for_of_stmt(
+    &mut self,
+    for_of_stmt: &ForOfStmt,
+    _parent: &dyn SwcNode,
+  ) {
+    for_of_stmt.right.visit_with(for_of_stmt, self);
+    self.in_loop(|this| for_of_stmt.body.visit_with(for_of_stmt, this));
+  }
+
+  fn visit_while_stmt(
+    &mut self,
+    while_stmt: &WhileStmt,
+    _parent: &dyn SwcNode,
+  ) {
+    self.in_loop(|this| {
+      while_stmt.test.visit_with(while_stmt, this);
+      while_stmt.body.visit_with(while_stmt, this);
+    });
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn SwcNode,
+  ) {
+    self.in_loop(|this| {
+      do_while_stmt.body.visit_with(do_while_stmt, this);
+      do_while_stmt.test.visit_with(do_while_stmt, this);
+    });
+  }
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn SwcNode,
+  ) {
+    return_stmt.visit_children_with(self);
+    self.after_return = true;
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn SwcNode) {
+    call_expr.visit_children_with(self);
+    if let Some(callee) = hook_callee(call_expr) {
+      let kind = if self.loop_depth > 0 {
+        CallKind::Loop
+      } else if self.conditional_depth > 0 {
+        CallKind::Conditional
+      } else if self.after_return {
+        CallKind::AfterReturn
+      } else {
+        return;
+      };
+      self.calls.push((call_expr.span, callee, kind));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/facebook/react/blob/v18.2.0/packages/eslint-plugin-react-hooks/__tests__/ESLintRulesOfHooks-test.js
+  // MIT Licensed.
+
+  #[test]
+  fn hook_rules_valid() {
+    assert_lint_ok! {
+      HookRules,
+      filename: "foo.tsx",
+      "function ComponentWithHook() { useHook(); }",
+      "function createComponentWithHook() { return function ComponentWithHook() { useHook(); }; }",
+      "function useHookWithHook() { useHook(); }",
+      "function createHook() { return function useHookWithHook() { useHook(); } }",
+      "function ComponentWithNormalFunction() { doSomething(); }",
+      "function normalFunctionWithNormalFunction() { doSomething(); }",
+      "function normalFunctionWithConditionalFunction() { if (cond) { doSomething(); } }",
+      "function functionThatStartsWithUseButIsntAHook() { if (cond) { userFetch(); } }",
+      "const FancyButton = React.forwardRef((props, ref) => { useHook(); return <button {...props} ref={ref} /> });",
+      "const MemoizedFunction = React.memo(props => { useHook(); return <button {...props} /> });",
+      "function useHook() { useHook1(); useHook2(); }",
+      "function App() { const [a, setA] = React.useState(0); return a; }",
+      "function useHookWithConditionalHook() { if (cond) { doSomething(); } useHook(); }",
+      "function ComponentWithHook() { const value = useHook() ? 1 : 2; }",
+      "function ComponentWithHook() { useEffect(() => { if (cond) { doSomething(); } }); }",
+      "function Component() { const ref = useRef(); return ref.current ? <div /> : null; }",
+      "function notAComponent() { if (cond) { useHook(); } }",
+      "function Component() { if (cond) { obj.useHook(); } }",
+      "function Component() { if (cond) { use(promise); } }",
+      "function Component() { const handler = () => { if (cond) { useHook(); } }; }",
+      "function useHook() { for (const x of useItems()) { doSomething(x); } }",
+    };
+  }
+
+  #[test]
+  fn hook_rules_invalid() {
+    assert_lint_err! {
+      HookRules,
+      filename: "foo.tsx",
+      "function ComponentWithConditionalHook() { if (cond) { useConditionalHook(); } }": [
+        {
+          col: 54,
+          message: HookRulesMessage::Conditional("useConditionalHook".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+      "function ComponentWithConditionalHook() { if (cond) { React.useState(); } }": [
+        {
+          col: 54,
+          message: HookRulesMessage::Conditional("React.useState".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+      "const Hello = () => { cond && useHook(); };": [
+        {
+          col: 30,
+          message: HookRulesMessage::Conditional("useHook".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+      "const useHook = () => cond ? useHook1() : useHook2();": [
+        {
+          col: 29,
+          message: HookRulesMessage::Conditional("useHook1".to_string()),
+          hint: HookRulesHint::TopLevel,
+        },
+        {
+          col: 42,
+          message: HookRulesMessage::Conditional("useHook2".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+      "function useHook() { switch (a) { case 1: useState(); } }": [
+        {
+          col: 42,
+          message: HookRulesMessage::Conditional("useState".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+      "function ComponentWithHookInsideLoop() { while (cond) { useHookInsideLoop(); } }": [
+        {
+          col: 56,
+          message: HookRulesMessage::Loop("useHookInsideLoop".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+      "function useHook() { for (const item of items) { if (item) { useState(); } } }": [
+        {
+          col: 61,
+          message: HookRulesMessage::Loop("useState".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+      "function useHook() { if (a) return; useState(); }": [
+        {
+          col: 36,
+          message: HookRulesMessage::AfterReturn("useState".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+      "const MemoizedButton = memo(function (props) { if (props.fancy) { useCustomHook(); } return <button />; });": [
+        {
+          col: 66,
+          message: HookRulesMessage::Conditional("useCustomHook".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+      "export default function Component() { if (cond) { useHook(); } }": [
+        {
+          col: 50,
+          message: HookRulesMessage::Conditional("useHook".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+      "function outer() { function useInner() { if (cond) { useHook(); } } }": [
+        {
+          col: 53,
+          message: HookRulesMessage::Conditional("useHook".to_string()),
+          hint: HookRulesHint::TopLevel,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,153 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, Lit, ModuleDecl, ModuleItem, Stmt};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct ImportFirst;
+
+const CODE: &str = "import-first";
+const MESSAGE: &str = "Import statements should come before other statements";
+const HINT: &str = "Move the import to the top of the module";
+
+impl LintRule for ImportFirst {
+  fn new() -> Arc<Self> {
+    Arc::new(ImportFirst)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    ImportFirstHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/import_first.md")
+  }
+}
+
+/// Whether the item is a string literal expression statement, e.g.
+/// `"use strict";`.
+fn is_directive(item: &ModuleItem) -> bool {
+  matches!(
+    item,
+    ModuleItem::Stmt(Stmt::Expr(expr_stmt))
+      if matches!(&*expr_stmt.expr, Expr::Lit(Lit::Str(_)))
+  )
+}
+
+fn is_import(item: &ModuleItem) -> bool {
+  matches!(
+    item,
+    ModuleItem::ModuleDecl(ModuleDecl::Import(_))
+      | ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(_))
+  )
+}
+
+struct ImportFirstHandler;
+
+impl Handler for ImportFirstHandler {
+  fn module(&mut self, module: &ast_view::Module, ctx: &mut Context) {
+    let body = &module.inner.body;
+    // Directives are only recognized at the very beginning of the module.
+    let prologue_len =
+      body.iter().take_while(|item| is_directive(item)).count();
+
+    let mut non_import_seen = false;
+    for item in &body[prologue_len..] {
+      if !is_import(item) {
+        non_import_seen = true;
+      } else if non_import_seen {
+        ctx.add_diagnostic_with_hint(item.span(), CODE, MESSAGE, HINT);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/import-js/eslint-plugin-import/blob/v2.26.0/tests/src/rules/first.js
+  // MIT Licensed.
+
+  #[test]
+  fn import_first_valid() {
+    assert_lint_ok! {
+      ImportFirst,
+      "import { x } from './foo.ts'; import { y } from './bar.ts'; export { x, y };",
+      "import { x } from 'foo'; import { y } from './bar.ts';",
+      "import * as a from 'a'; import * as b from 'b';",
+      "'use strict'; import { x } from 'foo';",
+      "'use directive'; 'use strict'; import { x } from 'foo'; const y = x;",
+      "import foo = require('foo'); import { bar } from 'bar';",
+      "const x = 1;",
+      "export { x } from './foo.ts'; export * from './bar.ts';",
+      "import { x } from './foo.ts'; const y = await import('./bar.ts');",
+    };
+  }
+
+  #[test]
+  fn import_first_invalid() {
+    assert_lint_err! {
+      ImportFirst,
+      "export { x } from './foo.ts'; import { y } from './bar.ts';": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import { x } from './foo.ts'; export { x }; import { y } from './bar.ts';": [
+        {
+          col: 44,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import { x } from './foo.ts'; const y = x + 1; import { z } from './bar.ts'; import * as w from './baz.ts';": [
+        {
+          col: 47,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          col: 77,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import { x } from './foo.ts'; 'use strict'; import { y } from './bar.ts';": [
+        {
+          col: 44,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const x = 1;\nimport foo = require('foo');": [
+        {
+          line: 2,
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,345 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Pat, VarDeclKind};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view::{self as ast_view, NodeTrait};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct InitDeclarations {
+  options: InitDeclarationsOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InitDeclarationsMode {
+  /// Every variable must be initialized on declaration.
+  Always,
+  /// `var` and `let` variables must not be initialized on declaration.
+  Never,
+}
+
+impl Default for InitDeclarationsMode {
+  fn default() -> Self {
+    InitDeclarationsMode::Always
+  }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct InitDeclarationsOptions {
+  pub mode: InitDeclarationsMode,
+  /// In `never` mode, allow initializing variables declared in the head of
+  /// `for`, `for-in` and `for-of` loops.
+  pub ignore_for_loop_init: bool,
+}
+
+impl InitDeclarations {
+  pub fn with_options(options: InitDeclarationsOptions) -> Arc<Self> {
+    Arc::new(InitDeclarations { options })
+  }
+}
+
+const CODE: &str = "init-declarations";
+
+#[derive(Display)]
+enum InitDeclarationsMessage {
+  #[display(fmt = "Variable `{}` should be initialized on declaration", _0)]
+  Always(String),
+  #[display(
+    fmt = "Variable `{}` should not be initialized on declaration",
+    _0
+  )]
+  Never(String),
+}
+
+#[derive(Display)]
+enum InitDeclarationsHint {
+  #[display(fmt = "Assign an initial value to the variable")]
+  AddInitializer,
+  #[display(
+    fmt = "Remove the initializer and assign the value in a separate statement"
+  )]
+  RemoveInitializer,
+}
+
+impl LintRule for InitDeclarations {
+  fn new() -> Arc<Self> {
+    Self::with_options(InitDeclarationsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "mode": {
+          "type": "string",
+          "enum": ["always", "never"],
+          "default": "always"
+        },
+        "ignoreForLoopInit": {
+          "type": "boolean",
+          "description": "In `never` mode, allow initializing variables declared in the head of loops.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(InitDeclarationsOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    InitDeclarationsHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/init_declarations.md")
+  }
+}
+
+struct InitDeclarationsHandler<'a> {
+  options: &'a InitDeclarationsOptions,
+}
+
+impl<'a> Handler for InitDeclarationsHandler<'a> {
+  fn var_decl(&mut self, var_decl: &ast_view::VarDecl, ctx: &mut Context) {
+    if is_ambient(var_decl) {
+      return;
+    }
+
+    let loop_kind = match var_decl.parent() {
+      ast_view::Node::ForStmt(_) => Some(LoopKind::For),
+      ast_view::Node::ForInStmt(_) | ast_view::Node::ForOfStmt(_) => {
+        Some(LoopKind::ForInOf)
+      }
+      _ => None,
+    };
+
+    for decl in &var_decl.decls {
+      // The variable of a `for-in` or `for-of` loop is assigned on each
+      // iteration, which counts as being initialized.
+      let initialized =
+        decl.init.is_some() || loop_kind == Some(LoopKind::ForInOf);
+      let name = binding_name(ctx, &decl.inner.name);
+
+      match self.options.mode {
+        InitDeclarationsMode::Always if !initialized => {
+          ctx.add_diagnostic_with_hint(
+            decl.span(),
+            CODE,
+            InitDeclarationsMessage::Always(name),
+            InitDeclarationsHint::AddInitializer,
+          );
+        }
+        InitDeclarationsMode::Never
+          if initialized
+            && var_decl.inner.kind != VarDeclKind::Const
+            && !(self.options.ignore_for_loop_init && loop_kind.is_some()) =>
+        {
+          ctx.add_diagnostic_with_hint(
+            decl.span(),
+            CODE,
+            InitDeclarationsMessage::Never(name),
+            InitDeclarationsHint::RemoveInitializer,
+          );
+        }
+        _ => {}
+      }
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LoopKind {
+  For,
+  ForInOf,
+}
+
+/// Checks if the declaration is `declare`d, either by itself or by being
+/// inside of a `declare namespace` or `declare module`.
+fn is_ambient(var_decl: &ast_view::VarDecl) -> bool {
+  if var_decl.declare() {
+    return true;
+  }
+  var_decl
+    .as_node()
+    .ancestors()
+    .any(|node| matches!(node, ast_view::Node::TsModuleDecl(m) if m.declare()))
+}
+
+fn binding_name(ctx: &Context, pat: &Pat) -> String {
+  match pat {
+    Pat::Ident(binding) => binding.id.sym.to_string(),
+    _ => ctx.file_text_substring(&pat.span()).to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.32.0/tests/lib/rules/init-declarations.js
+  // MIT Licensed.
+
+  #[test]
+  fn init_declarations_valid() {
+    assert_lint_ok! {
+      InitDeclarations,
+      "var a = null;",
+      "let a = 1, b = 2;",
+      "const a = 1;",
+      "for (var i = 0; i < 1; i++) {}",
+      "for (let x in obj) {}",
+      "for (const x of arr) {}",
+      "declare let a: number;",
+      "declare namespace Foo { let a: number; }",
+      "declare module 'foo' { let a: number; }",
+      "function foo() { var a = 1; }",
+    };
+
+    assert_lint_ok! {
+      InitDeclarations,
+      options: InitDeclarationsOptions {
+        mode: InitDeclarationsMode::Never,
+        ..Default::default()
+      },
+      "var a;",
+      "let a, b;",
+      "const a = 1;",
+      "for (var i; i < 1; i++) {}",
+      "declare let a: number;",
+    };
+
+    assert_lint_ok! {
+      InitDeclarations,
+      options: InitDeclarationsOptions {
+        mode: InitDeclarationsMode::Never,
+        ignore_for_loop_init: true,
+      },
+      "for (var i = 0; i < 1; i++) {}",
+      "for (let x in obj) {}",
+      "for (let x of arr) {}",
+    };
+  }
+
+  #[test]
+  fn init_declarations_invalid() {
+    assert_lint_err! {
+      InitDeclarations,
+      "var a;": [
+        {
+          col: 4,
+          message: InitDeclarationsMessage::Always("a".to_string()),
+          hint: InitDeclarationsHint::AddInitializer,
+        }
+      ],
+      "let a = 1, b;": [
+        {
+          col: 11,
+          message: InitDeclarationsMessage::Always("b".to_string()),
+          hint: InitDeclarationsHint::AddInitializer,
+        }
+      ],
+      "for (var i; i < 1; i++) {}": [
+        {
+          col: 9,
+          message: InitDeclarationsMessage::Always("i".to_string()),
+          hint: InitDeclarationsHint::AddInitializer,
+        }
+      ],
+      "function foo() { let a: number; }": [
+        {
+          col: 21,
+          message: InitDeclarationsMessage::Always("a".to_string()),
+          hint: InitDeclarationsHint::AddInitializer,
+        }
+      ],
+      "namespace Foo { let a: number; }": [
+        {
+          col: 20,
+          message: InitDeclarationsMessage::Always("a".to_string()),
+          hint: InitDeclarationsHint::AddInitializer,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      InitDeclarations,
+      options: InitDeclarationsOptions {
+        mode: InitDeclarationsMode::Never,
+        ..Default::default()
+      },
+      "var a = 1;": [
+        {
+          col: 4,
+          message: InitDeclarationsMessage::Never("a".to_string()),
+          hint: InitDeclarationsHint::RemoveInitializer,
+        }
+      ],
+      "let a, b = 2;": [
+        {
+          col: 7,
+          message: InitDeclarationsMessage::Never("b".to_string()),
+          hint: InitDeclarationsHint::RemoveInitializer,
+        }
+      ],
+      "let { a } = obj;": [
+        {
+          col: 4,
+          message: InitDeclarationsMessage::Never("{ a }".to_string()),
+          hint: InitDeclarationsHint::RemoveInitializer,
+        }
+      ],
+      "for (var i = 0; i < 1; i++) {}": [
+        {
+          col: 9,
+          message: InitDeclarationsMessage::Never("i".to_string()),
+          hint: InitDeclarationsHint::RemoveInitializer,
+        }
+      ],
+      "for (let x of arr) {}": [
+        {
+          col: 9,
+          message: InitDeclarationsMessage::Never("x".to_string()),
+          hint: InitDeclarationsHint::RemoveInitializer,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,293 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::jsx_attr_name;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, JSXAttrValue, JSXExpr, Lit};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct JsxBooleanValue {
+  options: JsxBooleanValueOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsxBooleanValueMode {
+  /// `true` values must be omitted, e.g. `<Foo disabled />`.
+  Never,
+  /// Values must always be written, e.g. `<Foo disabled={true} />`.
+  Always,
+}
+
+impl Default for JsxBooleanValueMode {
+  fn default() -> Self {
+    JsxBooleanValueMode::Never
+  }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsxBooleanValueOptions {
+  pub mode: JsxBooleanValueMode,
+  /// Names of props for which the opposite of `mode` is enforced.
+  pub exceptions: Vec<String>,
+}
+
+impl JsxBooleanValue {
+  pub fn with_options(options: JsxBooleanValueOptions) -> Arc<Self> {
+    Arc::new(JsxBooleanValue { options })
+  }
+}
+
+const CODE: &str = "jsx-boolean-value";
+
+#[derive(Display)]
+enum JsxBooleanValueMessage {
+  #[display(fmt = "Value must be omitted for boolean prop `{}`", _0)]
+  Never(String),
+  #[display(fmt = "Value must be set for boolean prop `{}`", _0)]
+  Always(String),
+}
+
+#[derive(Display)]
+enum JsxBooleanValueFix {
+  #[display(fmt = "Remove `={{true}}`")]
+  Remove,
+  #[display(fmt = "Add `={{true}}`")]
+  Add,
+}
+
+impl LintRule for JsxBooleanValue {
+  fn new() -> Arc<Self> {
+    Self::with_options(JsxBooleanValueOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Automatic
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "mode": {
+          "type": "string",
+          "enum": ["never", "always"],
+          "default": "never"
+        },
+        "exceptions": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Names of props for which the opposite of `mode` is enforced.",
+          "default": []
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(JsxBooleanValueOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    JsxBooleanValueHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/jsx_boolean_value.md")
+  }
+}
+
+/// Whether the value is `{true}`.
+fn is_true(value: &JSXAttrValue) -> bool {
+  match value {
+    JSXAttrValue::JSXExprContainer(container) => matches!(
+      &container.expr,
+      JSXExpr::Expr(expr)
+        if matches!(&**expr, Expr::Lit(Lit::Bool(b)) if b.value)
+    ),
+    _ => false,
+  }
+}
+
+struct JsxBooleanValueHandler<'a> {
+  options: &'a JsxBooleanValueOptions,
+}
+
+impl<'a> Handler for JsxBooleanValueHandler<'a> {
+  fn jsx_attr(&mut self, jsx_attr: &ast_view::JSXAttr, ctx: &mut Context) {
+    let attr = jsx_attr.inner;
+    let name = jsx_attr_name(&attr.name);
+    let is_exception = self.options.exceptions.contains(&name);
+    let mode = match (self.options.mode, is_exception) {
+      (JsxBooleanValueMode::Never, false)
+      | (JsxBooleanValueMode::Always, true) => JsxBooleanValueMode::Never,
+      _ => JsxBooleanValueMode::Always,
+    };
+
+    let name_text = ctx.file_text_substring(&attr.name.span()).to_string();
+    let (message, fix, replacement) = match (mode, &attr.value) {
+      (JsxBooleanValueMode::Never, Some(value)) if is_true(value) => (
+        JsxBooleanValueMessage::Never(name),
+        JsxBooleanValueFix::Remove,
+        name_text,
+      ),
+      (JsxBooleanValueMode::Always, None) => (
+        JsxBooleanValueMessage::Always(name),
+        JsxBooleanValueFix::Add,
+        format!("{}={{true}}", name_text),
+      ),
+      _ => return,
+    };
+
+    let fix = LintFix {
+      description: fix.to_string(),
+      changes: vec![ctx.fix_change(attr.span, &replacement)],
+      applicability: None,
+    };
+    ctx.add_diagnostic_with_fixes(attr.span, CODE, message, None, vec![fix]);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.31.10/tests/lib/rules/jsx-boolean-value.js
+  // MIT Licensed.
+
+  #[test]
+  fn jsx_boolean_value_valid() {
+    assert_lint_ok! {
+      JsxBooleanValue,
+      filename: "foo.tsx",
+      "<App foo />;",
+      "<App foo={false} />;",
+      "<App foo=\"true\" />;",
+      "<App foo={bar} />;",
+      "<App {...{ foo: true }} />;",
+    };
+
+    assert_lint_ok! {
+      JsxBooleanValue,
+      options: JsxBooleanValueOptions {
+        mode: JsxBooleanValueMode::Never,
+        exceptions: vec!["foo".to_string()],
+      },
+      filename: "foo.tsx",
+      "<App foo={true} bar />;",
+    };
+
+    assert_lint_ok! {
+      JsxBooleanValue,
+      options: JsxBooleanValueOptions {
+        mode: JsxBooleanValueMode::Always,
+        exceptions: vec![],
+      },
+      filename: "foo.tsx",
+      "<App foo={true} />;",
+      "<App foo={false} />;",
+    };
+
+    assert_lint_ok! {
+      JsxBooleanValue,
+      options: JsxBooleanValueOptions {
+        mode: JsxBooleanValueMode::Always,
+        exceptions: vec!["foo".to_string()],
+      },
+      filename: "foo.tsx",
+      "<App foo bar={true} />;",
+    };
+  }
+
+  #[test]
+  fn jsx_boolean_value_invalid() {
+    assert_lint_err! {
+      JsxBooleanValue,
+      filename: "foo.tsx",
+      "<App foo={true} />;": [
+        {
+          col: 5,
+          message: JsxBooleanValueMessage::Never("foo".to_string()),
+          fix: (JsxBooleanValueFix::Remove, "<App foo />;"),
+        }
+      ],
+      "<App foo = {true} bar={true} />;": [
+        {
+          col: 5,
+          message: JsxBooleanValueMessage::Never("foo".to_string()),
+          fix: (JsxBooleanValueFix::Remove, "<App foo bar={true} />;"),
+        },
+        {
+          col: 18,
+          message: JsxBooleanValueMessage::Never("bar".to_string()),
+          fix: (JsxBooleanValueFix::Remove, "<App foo = {true} bar />;"),
+        }
+      ],
+      "<svg xlink:show={true} />;": [
+        {
+          col: 5,
+          message: JsxBooleanValueMessage::Never("xlink:show".to_string()),
+          fix: (JsxBooleanValueFix::Remove, "<svg xlink:show />;"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      JsxBooleanValue,
+      options: JsxBooleanValueOptions {
+        mode: JsxBooleanValueMode::Always,
+        exceptions: vec!["bar".to_string()],
+      },
+      filename: "foo.tsx",
+      "<App foo bar={true} />;": [
+        {
+          col: 5,
+          message: JsxBooleanValueMessage::Always("foo".to_string()),
+          fix: (JsxBooleanValueFix::Add, "<App foo={true} bar={true} />;"),
+        },
+        {
+          col: 9,
+          message: JsxBooleanValueMessage::Never("bar".to_string()),
+          fix: (JsxBooleanValueFix::Remove, "<App foo bar />;"),
+        }
+      ],
+    };
+  }
+}
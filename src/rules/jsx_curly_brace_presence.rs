@@ -0,0 +1,458 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, JSXAttrValue, JSXExpr, Lit};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct JsxCurlyBracePresence {
+  options: JsxCurlyBracePresenceOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsxCurlyBracePresenceMode {
+  /// String literals must not be wrapped in curly braces.
+  Never,
+  /// String literals must be wrapped in curly braces.
+  Always,
+  /// Both forms are allowed.
+  Ignore,
+}
+
+impl Default for JsxCurlyBracePresenceMode {
+  fn default() -> Self {
+    JsxCurlyBracePresenceMode::Never
+  }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsxCurlyBracePresenceOptions {
+  /// The style of string literal prop values, e.g. `<Foo bar="baz" />`.
+  pub props: JsxCurlyBracePresenceMode,
+  /// The style of string literal children, e.g. `<Foo>bar</Foo>`.
+  pub children: JsxCurlyBracePresenceMode,
+}
+
+impl JsxCurlyBracePresence {
+  pub fn with_options(options: JsxCurlyBracePresenceOptions) -> Arc<Self> {
+    Arc::new(JsxCurlyBracePresence { options })
+  }
+}
+
+const CODE: &str = "jsx-curly-brace-presence";
+
+#[derive(Display)]
+enum JsxCurlyBracePresenceMessage {
+  #[display(fmt = "Curly braces are unnecessary here")]
+  Unnecessary,
+  #[display(fmt = "String literal should be wrapped in curly braces")]
+  Missing,
+}
+
+impl JsxCurlyBracePresenceMessage {
+  fn sub_code(&self) -> &'static str {
+    match self {
+      JsxCurlyBracePresenceMessage::Unnecessary => "unnecessary",
+      JsxCurlyBracePresenceMessage::Missing => "missing",
+    }
+  }
+}
+
+#[derive(Display)]
+enum JsxCurlyBracePresenceFix {
+  #[display(fmt = "Remove the curly braces")]
+  Remove,
+  #[display(fmt = "Wrap in curly braces")]
+  Add,
+}
+
+impl LintRule for JsxCurlyBracePresence {
+  fn new() -> Arc<Self> {
+    Self::with_options(JsxCurlyBracePresenceOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn sub_codes(&self) -> &'static [&'static str] {
+    &["unnecessary", "missing"]
+  }
+
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Automatic
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let mode = |description: &str| {
+      json!({
+        "type": "string",
+        "enum": ["never", "always", "ignore"],
+        "description": description,
+        "default": "never"
+      })
+    };
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "props": mode("The style of string literal prop values."),
+        "children": mode("The style of string literal children.")
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(JsxCurlyBracePresenceOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    JsxCurlyBracePresenceHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/jsx_curly_brace_presence.md")
+  }
+}
+
+/// Returns the value of a string literal or a template literal without
+/// expressions.
+fn string_value(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+      .quasis
+      .first()
+      .and_then(|quasi| quasi.cooked.as_ref())
+      .map(|cooked| cooked.value.to_string()),
+    _ => None,
+  }
+}
+
+/// Returns the prop value written without curly braces, if it keeps the same
+/// meaning. There are no escape sequences in strings of JSX attributes, and
+/// HTML entities are decoded.
+fn unwrapped_prop(value: &str) -> Option<String> {
+  if value.contains(&['&', '\n', '\r'][..]) {
+    return None;
+  }
+  if !value.contains('"') {
+    Some(format!("\"{}\"", value))
+  } else if !value.contains('\'') {
+    Some(format!("'{}'", value))
+  } else {
+    None
+  }
+}
+
+/// Returns the child written as JSX text, if it keeps the same meaning.
+/// Whitespace around JSX text is trimmed when it contains line breaks, and
+/// HTML entities are decoded.
+fn unwrapped_child(value: &str) -> Option<String> {
+  if value.trim().is_empty()
+    || value.trim() != value
+    || value.contains(&['{', '}', '<', '>', '&', '\n', '\r'][..])
+  {
+    return None;
+  }
+  Some(value.to_string())
+}
+
+/// Returns the string wrapped in curly braces as a string literal.
+fn wrapped(value: &str) -> Option<String> {
+  if value.contains(&['&', '\n', '\r'][..]) {
+    return None;
+  }
+  Some(format!(
+    "{{\"{}\"}}",
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+  ))
+}
+
+struct JsxCurlyBracePresenceHandler<'a> {
+  options: &'a JsxCurlyBracePresenceOptions,
+}
+
+impl<'a> JsxCurlyBracePresenceHandler<'a> {
+  fn report(
+    &self,
+    ctx: &mut Context,
+    span: Span,
+    message: JsxCurlyBracePresenceMessage,
+    fix: JsxCurlyBracePresenceFix,
+    replacement: String,
+  ) {
+    let fix = LintFix {
+      description: fix.to_string(),
+      changes: vec![ctx.fix_change(span, &replacement)],
+      applicability: None,
+    };
+    ctx.add_diagnostic_with_sub_code(
+      span,
+      CODE,
+      message.sub_code(),
+      message,
+      None,
+      vec![fix],
+    );
+  }
+}
+
+impl<'a> Handler for JsxCurlyBracePresenceHandler<'a> {
+  fn jsx_expr_container(
+    &mut self,
+    jsx_expr_container: &ast_view::JSXExprContainer,
+    ctx: &mut Context,
+  ) {
+    let value = match &jsx_expr_container.inner.expr {
+      JSXExpr::Expr(expr) => match string_value(expr) {
+        Some(value) => value,
+        None => return,
+      },
+      JSXExpr::JSXEmptyExpr(_) => return,
+    };
+
+    let replacement = match jsx_expr_container.parent() {
+      ast_view::Node::JSXAttr(_)
+        if self.options.props == JsxCurlyBracePresenceMode::Never =>
+      {
+        unwrapped_prop(&value)
+      }
+      ast_view::Node::JSXElement(_) | ast_view::Node::JSXFragment(_)
+        if self.options.children == JsxCurlyBracePresenceMode::Never =>
+      {
+        unwrapped_child(&value)
+      }
+      _ => return,
+    };
+
+    if let Some(replacement) = replacement {
+      self.report(
+        ctx,
+        jsx_expr_container.span(),
+        JsxCurlyBracePresenceMessage::Unnecessary,
+        JsxCurlyBracePresenceFix::Remove,
+        replacement,
+      );
+    }
+  }
+
+  fn jsx_attr(&mut self, jsx_attr: &ast_view::JSXAttr, ctx: &mut Context) {
+    if self.options.props != JsxCurlyBracePresenceMode::Always {
+      return;
+    }
+    if let Some(JSXAttrValue::Lit(Lit::Str(s))) = &jsx_attr.inner.value {
+      // The source text is used since the parser handles escape sequences in
+      // the value, which JSX doesn't have.
+      let text = ctx.file_text_substring(&s.span);
+      if let Some(replacement) = wrapped(&text[1..text.len() - 1]) {
+        self.report(
+          ctx,
+          s.span,
+          JsxCurlyBracePresenceMessage::Missing,
+          JsxCurlyBracePresenceFix::Add,
+          replacement,
+        );
+      }
+    }
+  }
+
+  fn jsx_text(&mut self, jsx_text: &ast_view::JSXText, ctx: &mut Context) {
+    if self.options.children != JsxCurlyBracePresenceMode::Always
+      || jsx_text.inner.value.trim().is_empty()
+    {
+      return;
+    }
+    let text = ctx.file_text_substring(&jsx_text.span());
+    if let Some(replacement) = wrapped(text) {
+      self.report(
+        ctx,
+        jsx_text.span(),
+        JsxCurlyBracePresenceMessage::Missing,
+        JsxCurlyBracePresenceFix::Add,
+        replacement,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.31.10/tests/lib/rules/jsx-curly-brace-presence.js
+  // MIT Licensed.
+
+  #[test]
+  fn jsx_curly_brace_presence_valid() {
+    assert_lint_ok! {
+      JsxCurlyBracePresence,
+      filename: "foo.tsx",
+      "<App>foo</App>;",
+      "<App foo=\"bar\" />;",
+      "<App foo={bar} />;",
+      "<App>{bar}</App>;",
+      "<App>{`foo ${bar}`}</App>;",
+      "<App foo={`foo ${bar}`} />;",
+      "<App>{\" \"}</App>;",
+      "<App>{\" foo\"}</App>;",
+      "<App>{\"{foo}\"}</App>;",
+      "<App>{\"a & b\"}</App>;",
+      "<App>{\"foo\\nbar\"}</App>;",
+      "<App foo={\"a&b\"} />;",
+      "<App foo={\"'\\\"\"} />;",
+      "<App>{/* comment */}</App>;",
+      "<App>{}</App>;",
+    };
+
+    assert_lint_ok! {
+      JsxCurlyBracePresence,
+      options: JsxCurlyBracePresenceOptions {
+        props: JsxCurlyBracePresenceMode::Always,
+        children: JsxCurlyBracePresenceMode::Always,
+      },
+      filename: "foo.tsx",
+      "<App foo={\"bar\"}>{\"baz\"}</App>;",
+      "<App>\n  {\"foo\"}\n</App>;",
+    };
+
+    assert_lint_ok! {
+      JsxCurlyBracePresence,
+      options: JsxCurlyBracePresenceOptions {
+        props: JsxCurlyBracePresenceMode::Ignore,
+        children: JsxCurlyBracePresenceMode::Ignore,
+      },
+      filename: "foo.tsx",
+      "<App foo={\"bar\"} baz=\"qux\">{\"foo\"}bar</App>;",
+    };
+  }
+
+  #[test]
+  fn jsx_curly_brace_presence_invalid() {
+    assert_lint_err! {
+      JsxCurlyBracePresence,
+      filename: "foo.tsx",
+      "<App>{'foo'}</App>;": [
+        {
+          col: 5,
+          message: JsxCurlyBracePresenceMessage::Unnecessary,
+          sub_code: "unnecessary",
+          fix: (JsxCurlyBracePresenceFix::Remove, "<App>foo</App>;"),
+        }
+      ],
+      "<App>{`foo`}</App>;": [
+        {
+          col: 5,
+          message: JsxCurlyBracePresenceMessage::Unnecessary,
+          fix: (JsxCurlyBracePresenceFix::Remove, "<App>foo</App>;"),
+        }
+      ],
+      "<>{\"foo bar\"}</>;": [
+        {
+          col: 2,
+          message: JsxCurlyBracePresenceMessage::Unnecessary,
+          fix: (JsxCurlyBracePresenceFix::Remove, "<>foo bar</>;"),
+        }
+      ],
+      "<App prop={'foo'} />;": [
+        {
+          col: 10,
+          message: JsxCurlyBracePresenceMessage::Unnecessary,
+          fix: (JsxCurlyBracePresenceFix::Remove, "<App prop=\"foo\" />;"),
+        }
+      ],
+      "<App prop={`foo`} />;": [
+        {
+          col: 10,
+          message: JsxCurlyBracePresenceMessage::Unnecessary,
+          fix: (JsxCurlyBracePresenceFix::Remove, "<App prop=\"foo\" />;"),
+        }
+      ],
+      "<App prop={'say \"hi\"'} />;": [
+        {
+          col: 10,
+          message: JsxCurlyBracePresenceMessage::Unnecessary,
+          fix: (JsxCurlyBracePresenceFix::Remove, "<App prop='say \"hi\"' />;"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      JsxCurlyBracePresence,
+      options: JsxCurlyBracePresenceOptions {
+        props: JsxCurlyBracePresenceMode::Always,
+        children: JsxCurlyBracePresenceMode::Never,
+      },
+      filename: "foo.tsx",
+      "<App prop=\"foo\">{\"bar\"}</App>;": [
+        {
+          col: 10,
+          message: JsxCurlyBracePresenceMessage::Missing,
+          sub_code: "missing",
+          fix: (JsxCurlyBracePresenceFix::Add, "<App prop={\"foo\"}>{\"bar\"}</App>;"),
+        },
+        {
+          col: 16,
+          message: JsxCurlyBracePresenceMessage::Unnecessary,
+          fix: (JsxCurlyBracePresenceFix::Remove, "<App prop=\"foo\">bar</App>;"),
+        }
+      ],
+      "<App prop='C:\\foo' />;": [
+        {
+          col: 10,
+          message: JsxCurlyBracePresenceMessage::Missing,
+          fix: (JsxCurlyBracePresenceFix::Add, "<App prop={\"C:\\\\foo\"} />;"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      JsxCurlyBracePresence,
+      options: JsxCurlyBracePresenceOptions {
+        props: JsxCurlyBracePresenceMode::Ignore,
+        children: JsxCurlyBracePresenceMode::Always,
+      },
+      filename: "foo.tsx",
+      "<App prop=\"foo\"> say \"hi\" </App>;": [
+        {
+          col: 16,
+          message: JsxCurlyBracePresenceMessage::Missing,
+          fix: (JsxCurlyBracePresenceFix::Add, "<App prop=\"foo\">{\" say \\\"hi\\\" \"}</App>;"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,480 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::member_start;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::ClassMember;
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct LinesBetweenClassMembers {
+  options: LinesBetweenClassMembersOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinesBetweenClassMembersMode {
+  /// Class members must be separated by an empty line.
+  Always,
+  /// Class members must not be separated by an empty line.
+  Never,
+}
+
+impl Default for LinesBetweenClassMembersMode {
+  fn default() -> Self {
+    LinesBetweenClassMembersMode::Always
+  }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct LinesBetweenClassMembersOptions {
+  pub mode: LinesBetweenClassMembersMode,
+  /// In `always` mode, allow members written on a single line to be followed
+  /// by another member without an empty line.
+  pub except_after_single_line: bool,
+  /// In `always` mode, allow overload signatures of a method to be followed
+  /// by another member without an empty line.
+  pub except_after_overload: bool,
+}
+
+impl Default for LinesBetweenClassMembersOptions {
+  fn default() -> Self {
+    Self {
+      mode: LinesBetweenClassMembersMode::Always,
+      except_after_single_line: false,
+      except_after_overload: true,
+    }
+  }
+}
+
+impl LinesBetweenClassMembers {
+  pub fn with_options(options: LinesBetweenClassMembersOptions) -> Arc<Self> {
+    Arc::new(LinesBetweenClassMembers { options })
+  }
+}
+
+const CODE: &str = "lines-between-class-members";
+
+#[derive(Display)]
+enum LinesBetweenClassMembersMessage {
+  #[display(fmt = "Expected an empty line between class members")]
+  Always,
+  #[display(fmt = "Unexpected empty line between class members")]
+  Never,
+}
+
+#[derive(Display)]
+enum LinesBetweenClassMembersFix {
+  #[display(fmt = "Add an empty line")]
+  Add,
+  #[display(fmt = "Remove the empty lines")]
+  Remove,
+}
+
+impl LintRule for LinesBetweenClassMembers {
+  fn new() -> Arc<Self> {
+    Self::with_options(LinesBetweenClassMembersOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Automatic
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "mode": {
+          "type": "string",
+          "enum": ["always", "never"],
+          "default": "always"
+        },
+        "exceptAfterSingleLine": {
+          "type": "boolean",
+          "description": "In `always` mode, allow single line members to be followed by another member without an empty line.",
+          "default": false
+        },
+        "exceptAfterOverload": {
+          "type": "boolean",
+          "description": "In `always` mode, allow overload signatures to be followed by another member without an empty line.",
+          "default": true
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(LinesBetweenClassMembersOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    let comments: Vec<Span> =
+      context.all_comments().map(|comment| comment.span).collect();
+    LinesBetweenClassMembersHandler {
+      options: &self.options,
+      comments,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/lines_between_class_members.md")
+  }
+}
+
+/// Whether the member is an overload signature, i.e. a method without a body.
+fn is_overload(member: &ClassMember) -> bool {
+  match member {
+    ClassMember::Method(method) => method.function.body.is_none(),
+    ClassMember::PrivateMethod(method) => method.function.body.is_none(),
+    ClassMember::Constructor(constructor) => constructor.body.is_none(),
+    _ => false,
+  }
+}
+
+struct LinesBetweenClassMembersHandler<'a> {
+  options: &'a LinesBetweenClassMembersOptions,
+  comments: Vec<Span>,
+}
+
+impl<'a> LinesBetweenClassMembersHandler<'a> {
+  fn check(
+    &self,
+    ctx: &mut Context,
+    current: &ClassMember,
+    next: &ClassMember,
+    // Comments and stray semicolons between the members, in source order.
+    between: &[Span],
+  ) {
+    let line_of = |pos: BytePos| ctx.source_file().line_index(pos);
+    let current_end = current.span().hi;
+    let next_start = member_start(next);
+
+    // Comments directly above or below a member without an empty line in
+    // between are considered part of the member.
+    let mut before_padding = current_end;
+    for span in between {
+      if line_of(span.lo) - line_of(before_padding) > 1 {
+        break;
+      }
+      before_padding = span.hi;
+    }
+    let mut after_padding = next_start;
+    for span in between.iter().rev() {
+      if span.hi <= before_padding
+        || line_of(after_padding) - line_of(span.hi) > 1
+      {
+        break;
+      }
+      after_padding = span.lo;
+    }
+    let is_padded = after_padding > before_padding
+      && line_of(after_padding) - line_of(before_padding) > 1;
+
+    let skip = (self.options.except_after_single_line
+      && line_of(member_start(current)) == line_of(current_end))
+      || (self.options.except_after_overload && is_overload(current));
+
+    let (message, fix, change) = match self.options.mode {
+      LinesBetweenClassMembersMode::Always if !is_padded && !skip => {
+        // Insert the empty line after the comments on the same line.
+        let insert_at = between
+          .iter()
+          .take_while(|span| line_of(span.lo) == line_of(current_end))
+          .last()
+          .map_or(current_end, |span| span.hi);
+        let content_after = between
+          .iter()
+          .map(|span| span.lo)
+          .find(|lo| insert_at <= *lo)
+          .unwrap_or(next_start);
+        let change = if line_of(content_after) == line_of(insert_at) {
+          // The next member shares the line, so it's moved to a line of its
+          // own, indented like the current one.
+          let current_start = member_start(current);
+          let line_start = ctx.source_file().line_start(line_of(current_start));
+          let line = ctx.file_text_substring(&Span::new(
+            line_start,
+            current_start,
+            Default::default(),
+          ));
+          let indent = &line[..line.len() - line.trim_start().len()];
+          (
+            Span::new(insert_at, content_after, Default::default()),
+            format!("\n\n{}", indent),
+          )
+        } else {
+          (
+            Span::new(insert_at, insert_at, Default::default()),
+            "\n".to_string(),
+          )
+        };
+        (
+          LinesBetweenClassMembersMessage::Always,
+          LinesBetweenClassMembersFix::Add,
+          Some(change),
+        )
+      }
+      LinesBetweenClassMembersMode::Never if is_padded => {
+        let has_comment_in_padding = between
+          .iter()
+          .any(|span| before_padding <= span.lo && span.hi <= after_padding);
+        let line_start = ctx.source_file().line_start(line_of(after_padding));
+        (
+          LinesBetweenClassMembersMessage::Never,
+          LinesBetweenClassMembersFix::Remove,
+          (!has_comment_in_padding).then(|| {
+            (
+              Span::new(before_padding, line_start, Default::default()),
+              "\n".to_string(),
+            )
+          }),
+        )
+      }
+      _ => return,
+    };
+
+    let fixes = change
+      .map(|(span, text)| {
+        vec![LintFix {
+          description: fix.to_string(),
+          changes: vec![ctx.fix_change(span, text)],
+          applicability: None,
+        }]
+      })
+      .unwrap_or_default();
+    ctx.add_diagnostic_with_fixes(next.span(), CODE, message, None, fixes);
+  }
+}
+
+impl<'a> Handler for LinesBetweenClassMembersHandler<'a> {
+  fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
+    let (members, empty): (Vec<_>, Vec<_>) = class
+      .inner
+      .body
+      .iter()
+      .partition(|member| !matches!(member, ClassMember::Empty(_)));
+    let mut others: Vec<Span> = self
+      .comments
+      .iter()
+      .copied()
+      .chain(empty.iter().map(|member| member.span()))
+      .collect();
+    others.sort_by_key(|span| span.lo);
+
+    for pair in members.windows(2) {
+      let (current, next) = (pair[0], pair[1]);
+      let between: Vec<Span> = others
+        .iter()
+        .copied()
+        .filter(|span| {
+          current.span().hi <= span.lo && span.hi <= member_start(next)
+        })
+        .collect();
+      self.check(ctx, current, next, &between);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/lines-between-class-members.js
+  // MIT Licensed.
+
+  const NEVER: LinesBetweenClassMembersOptions =
+    LinesBetweenClassMembersOptions {
+      mode: LinesBetweenClassMembersMode::Never,
+      except_after_single_line: false,
+      except_after_overload: true,
+    };
+
+  #[test]
+  fn lines_between_class_members_valid() {
+    assert_lint_ok! {
+      LinesBetweenClassMembers,
+      "class foo{}",
+      "class foo{;;}",
+      "class foo{\n\n}",
+      "class foo{constructor(){}\n}",
+      "class foo{\nconstructor(){}}",
+      "class foo{ bar(){}\n\nbaz(){}}",
+      "class foo{ bar(){}\n\n/*comments*/baz(){}}",
+      "class foo{ bar(){}\n\n//comments\nbaz(){}}",
+      "class foo{ bar(){}\n//comments\n\nbaz(){}}",
+      "class A{ foo(){}\n/* a */ /* b */\n\nbar(){}}",
+      "class A{ foo(){}\n\n/* a */ /* b */\nbar(){}}",
+      "class foo{ bar(){};\n\nbaz(){}}",
+      "class foo{ bar(){}\n\n;;baz(){}}",
+      "class foo{ a = 1;\n\n@dec\nb = 2; }",
+      "class foo{ bar(): void;\nbar(x?: number) {}\n\nbaz(){}}",
+    };
+
+    assert_lint_ok! {
+      LinesBetweenClassMembers,
+      options: NEVER,
+      "class foo{ bar(){}\nbaz(){}}",
+      "class foo{ bar(){}\n/*comments*/baz(){}}",
+      "class foo{ bar(){}\n//comments\nbaz(){}}",
+      "class foo{ bar(){}/* comments\n\n*/baz(){}}",
+      "class foo{ bar(){}\n/* \ncomments\n*/\nbaz(){}}",
+    };
+
+    assert_lint_ok! {
+      LinesBetweenClassMembers,
+      options: LinesBetweenClassMembersOptions {
+        except_after_single_line: true,
+        ..Default::default()
+      },
+      "class foo{ a = 1;\nb = 2;\n\nbar(){\n}\n\nbaz(){}}",
+    };
+  }
+
+  #[test]
+  fn lines_between_class_members_invalid() {
+    assert_lint_err! {
+      LinesBetweenClassMembers,
+      "class foo{ bar(){}\nbaz(){}}": [
+        {
+          line: 2,
+          col: 0,
+          message: LinesBetweenClassMembersMessage::Always,
+          fix: (LinesBetweenClassMembersFix::Add, "class foo{ bar(){}\n\nbaz(){}}"),
+        }
+      ],
+      "class foo{ bar(){} // comment\n  baz(){}}": [
+        {
+          line: 2,
+          col: 2,
+          message: LinesBetweenClassMembersMessage::Always,
+          fix: (LinesBetweenClassMembersFix::Add, "class foo{ bar(){} // comment\n\n  baz(){}}"),
+        }
+      ],
+      "class foo{ bar(){}\n/* comment */\nbaz(){}}": [
+        {
+          line: 3,
+          col: 0,
+          message: LinesBetweenClassMembersMessage::Always,
+          fix: (LinesBetweenClassMembersFix::Add, "class foo{ bar(){}\n\n/* comment */\nbaz(){}}"),
+        }
+      ],
+      "class foo{ a = 1;\nb = 2; }": [
+        {
+          line: 2,
+          col: 0,
+          message: LinesBetweenClassMembersMessage::Always,
+          fix: (LinesBetweenClassMembersFix::Add, "class foo{ a = 1;\n\nb = 2; }"),
+        }
+      ],
+      "class A { e = 1; f = 2; }": [
+        {
+          col: 17,
+          message: LinesBetweenClassMembersMessage::Always,
+          fix: (LinesBetweenClassMembersFix::Add, "class A { e = 1;\n\nf = 2; }"),
+        }
+      ],
+      "class A {\n  e = 1; /* comment */ f = 2;\n}": [
+        {
+          line: 2,
+          col: 23,
+          message: LinesBetweenClassMembersMessage::Always,
+          fix: (LinesBetweenClassMembersFix::Add, "class A {\n  e = 1; /* comment */\n\n  f = 2;\n}"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      LinesBetweenClassMembers,
+      options: NEVER,
+      "class foo{\n  bar(){}\n\n  baz(){}}": [
+        {
+          line: 4,
+          col: 2,
+          message: LinesBetweenClassMembersMessage::Never,
+          fix: (LinesBetweenClassMembersFix::Remove, "class foo{\n  bar(){}\n  baz(){}}"),
+        }
+      ],
+      "class foo{ bar(){}\n\n\n/*comments*/baz(){}}": [
+        {
+          line: 4,
+          col: 12,
+          message: LinesBetweenClassMembersMessage::Never,
+          fix: (LinesBetweenClassMembersFix::Remove, "class foo{ bar(){}\n/*comments*/baz(){}}"),
+        }
+      ],
+      "class foo{ bar(){}\n\n/* comment */\n\nbaz(){}}": [
+        {
+          line: 5,
+          col: 0,
+          message: LinesBetweenClassMembersMessage::Never,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      LinesBetweenClassMembers,
+      options: LinesBetweenClassMembersOptions {
+        except_after_single_line: true,
+        ..Default::default()
+      },
+      "class foo{ bar(){\n}\nbaz(){}}": [
+        {
+          line: 3,
+          col: 0,
+          message: LinesBetweenClassMembersMessage::Always,
+          fix: (LinesBetweenClassMembersFix::Add, "class foo{ bar(){\n}\n\nbaz(){}}"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      LinesBetweenClassMembers,
+      options: LinesBetweenClassMembersOptions {
+        except_after_overload: false,
+        ..Default::default()
+      },
+      "class foo{ bar(): void;\nbar(x?: number) {}}": [
+        {
+          line: 2,
+          col: 0,
+          message: LinesBetweenClassMembersMessage::Always,
+          fix: (LinesBetweenClassMembersFix::Add, "class foo{ bar(): void;\n\nbar(x?: number) {}}"),
+        }
+      ],
+    };
+  }
+}
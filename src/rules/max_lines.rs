@@ -0,0 +1,337 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{BytePos, Span};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct MaxLines {
+  options: MaxLinesOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MaxLinesOptions {
+  /// The maximum number of lines in a file.
+  pub max: usize,
+  /// Don't count lines made up of whitespace only.
+  pub skip_blank_lines: bool,
+  /// Don't count lines containing only comments.
+  pub skip_comments: bool,
+}
+
+impl Default for MaxLinesOptions {
+  fn default() -> Self {
+    Self {
+      max: 300,
+      skip_blank_lines: false,
+      skip_comments: false,
+    }
+  }
+}
+
+impl MaxLines {
+  pub fn with_options(options: MaxLinesOptions) -> Arc<Self> {
+    Arc::new(MaxLines { options })
+  }
+}
+
+const CODE: &str = "max-lines";
+const HINT: &str = "Split the file into smaller modules";
+
+#[derive(Display)]
+enum MaxLinesMessage {
+  #[display(
+    fmt = "File has too many lines ({}), the maximum allowed is {}",
+    _0,
+    _1
+  )]
+  TooManyLines(usize, usize),
+}
+
+impl LintRule for MaxLines {
+  fn new() -> Arc<Self> {
+    Self::with_options(MaxLinesOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "max": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "The maximum number of lines in a file.",
+          "default": 300
+        },
+        "skipBlankLines": {
+          "type": "boolean",
+          "description": "Don't count lines made up of whitespace only."
+        },
+        "skipComments": {
+          "type": "boolean",
+          "description": "Don't count lines containing only comments."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(MaxLinesOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program<'_>,
+  ) {
+    let count = SourceLines::new(context).count(
+      context.source_file().span(),
+      self.options.skip_blank_lines,
+      self.options.skip_comments,
+    );
+    if count <= self.options.max {
+      return;
+    }
+
+    let file_start = context.source_file().span().lo();
+    let first_line = context.source_file().text().lines().next().unwrap_or("");
+    let span = Span::new(
+      file_start,
+      file_start + BytePos(first_line.len() as u32),
+      Default::default(),
+    );
+    context.add_diagnostic_with_hint(
+      span,
+      CODE,
+      MaxLinesMessage::TooManyLines(count, self.options.max),
+      HINT,
+    );
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/max_lines.md")
+  }
+}
+
+struct Line {
+  start: usize,
+  blank: bool,
+  comment_only: bool,
+}
+
+/// The lines of a file, for counting the lines spans cover.
+pub(crate) struct SourceLines {
+  file_start: BytePos,
+  lines: Vec<Line>,
+}
+
+impl SourceLines {
+  pub(crate) fn new(context: &Context) -> Self {
+    let file_start = context.source_file().span().lo();
+    let text = context.source_file().text();
+
+    // Marks the bytes of the file that are part of a comment.
+    let mut in_comment = vec![false; text.len()];
+    for comment in context.all_comments() {
+      let lo = (comment.span.lo() - file_start).0 as usize;
+      let hi = (comment.span.hi() - file_start).0 as usize;
+      for byte in &mut in_comment[lo..hi] {
+        *byte = true;
+      }
+    }
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in text.split('\n') {
+      let mut blank = true;
+      let mut comment_only = true;
+      for (index, c) in line.char_indices() {
+        if c.is_whitespace() {
+          continue;
+        }
+        blank = false;
+        if !in_comment[start + index] {
+          comment_only = false;
+          break;
+        }
+      }
+      lines.push(Line {
+        start,
+        blank,
+        comment_only,
+      });
+      start += line.len() + 1;
+    }
+    Self { file_start, lines }
+  }
+
+  /// Counts the lines `span` covers. A line break at the end of the span
+  /// doesn't start another line.
+  pub(crate) fn count(
+    &self,
+    span: Span,
+    skip_blank_lines: bool,
+    skip_comments: bool,
+  ) -> usize {
+    let lo = (span.lo() - self.file_start).0 as usize;
+    let hi = (span.hi() - self.file_start).0 as usize;
+    let line_of =
+      |pos: usize| self.lines.partition_point(|line| line.start <= pos) - 1;
+    let first = line_of(lo);
+    let mut last = line_of(hi);
+    if last > first && self.lines[last].start == hi {
+      last -= 1;
+    }
+    self.lines[first..=last]
+      .iter()
+      .filter(|line| {
+        if line.blank {
+          !skip_blank_lines
+        } else {
+          !(skip_comments && line.comment_only)
+        }
+      })
+      .count()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/max-lines.js
+  // MIT Licensed.
+
+  fn max(max: usize) -> MaxLinesOptions {
+    MaxLinesOptions {
+      max,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn max_lines_valid() {
+    assert_lint_ok! {
+      MaxLines,
+      "var x;",
+      "var xy;\nvar xy;",
+      "",
+    };
+
+    assert_lint_ok! {
+      MaxLines,
+      options: max(2),
+      "var xy;\nvar xy;",
+      "var xy;\nvar xy;\n",
+      "var xy;\r\nvar xy;\r\n",
+    };
+
+    assert_lint_ok! {
+      MaxLines,
+      options: MaxLinesOptions {
+        max: 2,
+        skip_blank_lines: true,
+        ..Default::default()
+      },
+      "var x;\n \t\n\n  \t\nvar y;",
+    };
+
+    assert_lint_ok! {
+      MaxLines,
+      options: MaxLinesOptions {
+        max: 2,
+        skip_comments: true,
+        ..Default::default()
+      },
+      "//a single line comment\nvar xy;\nvar xy;\n /* a multiline\n really really\n long comment*/ ",
+      "var x; /* inline comment\n spanning multiple lines */ var z;",
+    };
+  }
+
+  #[test]
+  fn max_lines_invalid() {
+    assert_lint_err! {
+      MaxLines,
+      options: max(1),
+      "var xyz;\nvar xyz;\nvar xyz;": [
+        {
+          col: 0,
+          message: MaxLinesMessage::TooManyLines(3, 1),
+          hint: HINT,
+        }
+      ],
+      "/* a multiline comment\n that goes to many lines*/\nvar xy;\nvar xy;": [
+        {
+          col: 0,
+          message: MaxLinesMessage::TooManyLines(4, 1),
+          hint: HINT,
+        }
+      ],
+      "\n\nvar x;\n": [
+        {
+          col: 0,
+          message: MaxLinesMessage::TooManyLines(3, 1),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MaxLines,
+      options: MaxLinesOptions {
+        max: 2,
+        skip_blank_lines: true,
+        ..Default::default()
+      },
+      "// a comment\n\nvar x;\n\nvar y;": [
+        {
+          col: 0,
+          message: MaxLinesMessage::TooManyLines(3, 2),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MaxLines,
+      options: MaxLinesOptions {
+        max: 2,
+        skip_comments: true,
+        ..Default::default()
+      },
+      "// a comment\nvar x; // trailing\n/* block */ var y;\n\nvar z;": [
+        {
+          col: 0,
+          message: MaxLinesMessage::TooManyLines(4, 2),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,342 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::max_lines::SourceLines;
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct MaxLinesPerFunction {
+  options: MaxLinesPerFunctionOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MaxLinesPerFunctionOptions {
+  /// The maximum number of lines in a function.
+  pub max: usize,
+  /// Don't count lines made up of whitespace only.
+  pub skip_blank_lines: bool,
+  /// Don't count lines containing only comments.
+  pub skip_comments: bool,
+  /// Check immediately invoked function expressions as well.
+  #[serde(rename = "IIFEs")]
+  pub iifes: bool,
+}
+
+impl Default for MaxLinesPerFunctionOptions {
+  fn default() -> Self {
+    Self {
+      max: 50,
+      skip_blank_lines: false,
+      skip_comments: false,
+      iifes: false,
+    }
+  }
+}
+
+impl MaxLinesPerFunction {
+  pub fn with_options(options: MaxLinesPerFunctionOptions) -> Arc<Self> {
+    Arc::new(MaxLinesPerFunction { options })
+  }
+}
+
+const CODE: &str = "max-lines-per-function";
+const HINT: &str = "Split the function into smaller ones";
+
+#[derive(Display)]
+enum MaxLinesPerFunctionMessage {
+  #[display(
+    fmt = "Function has too many lines ({}), the maximum allowed is {}",
+    _0,
+    _1
+  )]
+  TooManyLines(usize, usize),
+}
+
+impl LintRule for MaxLinesPerFunction {
+  fn new() -> Arc<Self> {
+    Self::with_options(MaxLinesPerFunctionOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "max": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "The maximum number of lines in a function.",
+          "default": 50
+        },
+        "skipBlankLines": {
+          "type": "boolean",
+          "description": "Don't count lines made up of whitespace only."
+        },
+        "skipComments": {
+          "type": "boolean",
+          "description": "Don't count lines containing only comments."
+        },
+        "IIFEs": {
+          "type": "boolean",
+          "description": "Check immediately invoked function expressions as well."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(MaxLinesPerFunctionOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    MaxLinesPerFunctionHandler {
+      options: &self.options,
+      lines: SourceLines::new(context),
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/max_lines_per_function.md")
+  }
+}
+
+/// Checks if the function expression is called right away, e.g.
+/// `(function() {})()` or `(() => {})()`.
+fn is_iife(function: Node) -> bool {
+  matches!(
+    function
+      .ancestors()
+      .find(|ancestor| !matches!(ancestor, Node::ParenExpr(_))),
+    Some(Node::CallExpr(_))
+  )
+}
+
+struct MaxLinesPerFunctionHandler<'a> {
+  options: &'a MaxLinesPerFunctionOptions,
+  lines: SourceLines,
+}
+
+impl<'a> MaxLinesPerFunctionHandler<'a> {
+  fn check(&self, ctx: &mut Context, span: Span, function: Node) {
+    if !self.options.iifes && is_iife(function) {
+      return;
+    }
+    let count = self.lines.count(
+      span,
+      self.options.skip_blank_lines,
+      self.options.skip_comments,
+    );
+    if count > self.options.max {
+      ctx.add_diagnostic_with_hint(
+        span,
+        CODE,
+        MaxLinesPerFunctionMessage::TooManyLines(count, self.options.max),
+        HINT,
+      );
+    }
+  }
+}
+
+impl<'a> Handler for MaxLinesPerFunctionHandler<'a> {
+  fn function(&mut self, function: &ast_view::Function, ctx: &mut Context) {
+    let node = match function.parent() {
+      Node::FnExpr(fn_expr) => fn_expr.as_node(),
+      _ => function.as_node(),
+    };
+    self.check(ctx, function.span(), node);
+  }
+
+  fn arrow_expr(
+    &mut self,
+    arrow_expr: &ast_view::ArrowExpr,
+    ctx: &mut Context,
+  ) {
+    self.check(ctx, arrow_expr.span(), arrow_expr.as_node());
+  }
+
+  fn constructor(
+    &mut self,
+    constructor: &ast_view::Constructor,
+    ctx: &mut Context,
+  ) {
+    self.check(ctx, constructor.span(), constructor.as_node());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/max-lines-per-function.js
+  // MIT Licensed.
+
+  fn max(max: usize) -> MaxLinesPerFunctionOptions {
+    MaxLinesPerFunctionOptions {
+      max,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn max_lines_per_function_valid() {
+    assert_lint_ok! {
+      MaxLinesPerFunction,
+      "function name() {}",
+      "var x = 5;\nvar x = 2;\n",
+    };
+
+    assert_lint_ok! {
+      MaxLinesPerFunction,
+      options: max(1),
+      "function name() {}",
+      "const name = () => {};",
+      "class A { foo() {} constructor() {} }",
+    };
+
+    assert_lint_ok! {
+      MaxLinesPerFunction,
+      options: max(3),
+      "function name() {\n  var x = 5;\n}",
+      "var x = 5;\nfunction name() {\n  var x = 5;\n}\nvar y = 2;",
+      "(function() {\n  var x = 5;\n  var y = 2;\n  var z = 3;\n})();",
+      "(() => {\n  var x = 5;\n  var y = 2;\n  var z = 3;\n})();",
+    };
+
+    assert_lint_ok! {
+      MaxLinesPerFunction,
+      options: MaxLinesPerFunctionOptions {
+        max: 3,
+        skip_blank_lines: true,
+        ..Default::default()
+      },
+      "function name() {\n\n  var x = 5;\n\t\n}",
+    };
+
+    assert_lint_ok! {
+      MaxLinesPerFunction,
+      options: MaxLinesPerFunctionOptions {
+        max: 3,
+        skip_comments: true,
+        ..Default::default()
+      },
+      "function name() {\n  // a comment\n  var x = 5; // trailing\n  /* block\n   * comment */\n}",
+    };
+  }
+
+  #[test]
+  fn max_lines_per_function_invalid() {
+    assert_lint_err! {
+      MaxLinesPerFunction,
+      options: max(2),
+      "function name() {\n  var x = 5;\n}": [
+        {
+          col: 0,
+          message: MaxLinesPerFunctionMessage::TooManyLines(3, 2),
+          hint: HINT,
+        }
+      ],
+      "var name = function() {\n  var x = 5;\n};": [
+        {
+          col: 11,
+          message: MaxLinesPerFunctionMessage::TooManyLines(3, 2),
+          hint: HINT,
+        }
+      ],
+      "const name = () => {\n  var x = 5;\n};": [
+        {
+          col: 13,
+          message: MaxLinesPerFunctionMessage::TooManyLines(3, 2),
+          hint: HINT,
+        }
+      ],
+      "class A {\n  constructor() {\n    this.a = 1;\n  }\n}": [
+        {
+          line: 2,
+          col: 2,
+          message: MaxLinesPerFunctionMessage::TooManyLines(3, 2),
+          hint: HINT,
+        }
+      ],
+      "foo(function() {\n  var x = 5;\n});": [
+        {
+          col: 4,
+          message: MaxLinesPerFunctionMessage::TooManyLines(3, 2),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MaxLinesPerFunction,
+      options: MaxLinesPerFunctionOptions {
+        max: 2,
+        iifes: true,
+        ..Default::default()
+      },
+      "(function() {\n  var x = 5;\n})();": [
+        {
+          col: 1,
+          message: MaxLinesPerFunctionMessage::TooManyLines(3, 2),
+          hint: HINT,
+        }
+      ],
+      "(() => {\n  var x = 5;\n})();": [
+        {
+          col: 1,
+          message: MaxLinesPerFunctionMessage::TooManyLines(3, 2),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MaxLinesPerFunction,
+      options: MaxLinesPerFunctionOptions {
+        max: 2,
+        skip_blank_lines: true,
+        skip_comments: true,
+        ..Default::default()
+      },
+      "function name() {\n\n  // comment\n  var x = 5;\n  var y = 2;\n}": [
+        {
+          col: 0,
+          message: MaxLinesPerFunctionMessage::TooManyLines(4, 2),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,343 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, Constructor, Function,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node as SwcNode;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct MaxStatements {
+  options: MaxStatementsOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MaxStatementsOptions {
+  /// The maximum number of statements in a function.
+  pub max: usize,
+  /// Don't check functions that aren't nested in another function.
+  pub ignore_top_level_functions: bool,
+}
+
+impl Default for MaxStatementsOptions {
+  fn default() -> Self {
+    Self {
+      max: 10,
+      ignore_top_level_functions: false,
+    }
+  }
+}
+
+impl MaxStatements {
+  pub fn with_options(options: MaxStatementsOptions) -> Arc<Self> {
+    Arc::new(MaxStatements { options })
+  }
+}
+
+const CODE: &str = "max-statements";
+const HINT: &str = "Split the function into smaller ones";
+
+#[derive(Display)]
+enum MaxStatementsMessage {
+  #[display(
+    fmt = "Function has too many statements ({}), the maximum allowed is {}",
+    _0,
+    _1
+  )]
+  TooManyStatements(usize, usize),
+}
+
+impl LintRule for MaxStatements {
+  fn new() -> Arc<Self> {
+    Self::with_options(MaxStatementsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "max": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "The maximum number of statements in a function.",
+          "default": 10
+        },
+        "ignoreTopLevelFunctions": {
+          "type": "boolean",
+          "description": "Don't check functions that aren't nested in another function."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(MaxStatementsOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    MaxStatementsHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/max_statements.md")
+  }
+}
+
+/// Counts the statements in the blocks of a function body, without the ones
+/// of nested functions.
+#[derive(Default)]
+struct StatementCounter {
+  count: usize,
+}
+
+impl Visit for StatementCounter {
+  noop_visit_type!();
+
+  fn visit_block_stmt(
+    &mut self,
+    block_stmt: &BlockStmt,
+    _parent: &dyn SwcNode,
+  ) {
+    self.count += block_stmt.stmts.len();
+    block_stmt.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn SwcNode) {}
+
+  fn visit_arrow_expr(
+    &mut self,
+    _arrow_expr: &ArrowExpr,
+    _parent: &dyn SwcNode,
+  ) {
+  }
+
+  fn visit_constructor(
+    &mut self,
+    _constructor: &Constructor,
+    _parent: &dyn SwcNode,
+  ) {
+  }
+}
+
+fn is_top_level(function: Node) -> bool {
+  !function.ancestors().any(|ancestor| {
+    matches!(
+      ancestor,
+      Node::Function(_) | Node::ArrowExpr(_) | Node::Constructor(_)
+    )
+  })
+}
+
+struct MaxStatementsHandler<'a> {
+  options: &'a MaxStatementsOptions,
+}
+
+impl<'a> MaxStatementsHandler<'a> {
+  fn check(
+    &self,
+    ctx: &mut Context,
+    span: Span,
+    body: Option<&BlockStmt>,
+    function: Node,
+  ) {
+    if self.options.ignore_top_level_functions && is_top_level(function) {
+      return;
+    }
+    let mut counter = StatementCounter::default();
+    if let Some(body) = body {
+      body.visit_with(&DUMMY_NODE, &mut counter);
+    }
+    if counter.count > self.options.max {
+      ctx.add_diagnostic_with_hint(
+        span,
+        CODE,
+        MaxStatementsMessage::TooManyStatements(
+          counter.count,
+          self.options.max,
+        ),
+        HINT,
+      );
+    }
+  }
+}
+
+impl<'a> Handler for MaxStatementsHandler<'a> {
+  fn function(&mut self, function: &ast_view::Function, ctx: &mut Context) {
+    self.check(
+      ctx,
+      function.span(),
+      function.inner.body.as_ref(),
+      function.as_node(),
+    );
+  }
+
+  fn arrow_expr(
+    &mut self,
+    arrow_expr: &ast_view::ArrowExpr,
+    ctx: &mut Context,
+  ) {
+    let body = match &arrow_expr.inner.body {
+      BlockStmtOrExpr::BlockStmt(body) => Some(body),
+      BlockStmtOrExpr::Expr(_) => None,
+    };
+    self.check(ctx, arrow_expr.span(), body, arrow_expr.as_node());
+  }
+
+  fn constructor(
+    &mut self,
+    constructor: &ast_view::Constructor,
+    ctx: &mut Context,
+  ) {
+    self.check(
+      ctx,
+      constructor.span(),
+      constructor.inner.body.as_ref(),
+      constructor.as_node(),
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/max-statements.js
+  // MIT Licensed.
+
+  fn max(max: usize) -> MaxStatementsOptions {
+    MaxStatementsOptions {
+      max,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn max_statements_valid() {
+    assert_lint_ok! {
+      MaxStatements,
+      "function foo() { var bar = 1; function qux() { var noCount = 2; } return 3; }",
+      "var foo = 1; var bar = 2; var baz = 3; var qux = 4; var quux = 5; var a = 6; var b = 7; var c = 8; var d = 9; var e = 10; var f = 11;",
+    };
+
+    assert_lint_ok! {
+      MaxStatements,
+      options: max(3),
+      "function foo() { if (true) { bar(); } else { baz(); } }",
+      "function foo() { var x = 5; function bar() { var y = 6; } bar(); }",
+      "function foo() { var a; var b; var c; }",
+      "(function() { var bar = 1; return function () { return 42; }; })()",
+      "function foo() { if (a) bar(); else baz(); }",
+      "let foo = () => { a; b; c; };",
+      "let foo = () => bar;",
+      "class A { foo() { a; b; c; } constructor() { a; b; c; } }",
+    };
+
+    assert_lint_ok! {
+      MaxStatements,
+      options: MaxStatementsOptions {
+        max: 1,
+        ignore_top_level_functions: true,
+      },
+      "function foo() { var a; var b; var c; }",
+      "const foo = () => { var a; var b; var c; };",
+      "function foo() { function bar() { var a; } }",
+    };
+  }
+
+  #[test]
+  fn max_statements_invalid() {
+    assert_lint_err! {
+      MaxStatements,
+      options: max(3),
+      "function foo() { var bar = 1; var baz = 2; var qux = 3; var quxx = 4; }": [
+        {
+          col: 0,
+          message: MaxStatementsMessage::TooManyStatements(4, 3),
+          hint: HINT,
+        }
+      ],
+      "var foo = () => { var bar = 1; var baz = 2; var qux = 3; var quxx = 4; };": [
+        {
+          col: 10,
+          message: MaxStatementsMessage::TooManyStatements(4, 3),
+          hint: HINT,
+        }
+      ],
+      "var foo = function() { var bar = 1; if (true) { while (false) { var qux = null; } return 3; } };": [
+        {
+          col: 10,
+          message: MaxStatementsMessage::TooManyStatements(5, 3),
+          hint: HINT,
+        }
+      ],
+      "class A { constructor() { a; b; c; d; } }": [
+        {
+          col: 10,
+          message: MaxStatementsMessage::TooManyStatements(4, 3),
+          hint: HINT,
+        }
+      ],
+      "function foo() { var a; function bar() { var b; var c; var d; var e; } }": [
+        {
+          col: 24,
+          message: MaxStatementsMessage::TooManyStatements(4, 3),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MaxStatements,
+      options: MaxStatementsOptions {
+        max: 1,
+        ignore_top_level_functions: true,
+      },
+      "function foo() { var a; const bar = () => { var b; var c; }; }": [
+        {
+          col: 36,
+          message: MaxStatementsMessage::TooManyStatements(2, 1),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
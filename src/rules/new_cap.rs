@@ -0,0 +1,417 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Lit};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NewCap {
+  options: NewCapOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NewCapOptions {
+  /// Require `new` to be called with a capitalized name.
+  pub new_is_cap: bool,
+  /// Require capitalized functions to be called with `new`.
+  pub cap_is_new: bool,
+  /// Lowercase names that are allowed to be called with `new`.
+  pub new_is_cap_exceptions: Vec<String>,
+  /// Capitalized names that are allowed to be called without `new`, in
+  /// addition to the built-in ones like `String` or `Symbol`.
+  pub cap_is_new_exceptions: Vec<String>,
+}
+
+impl Default for NewCapOptions {
+  fn default() -> Self {
+    Self {
+      new_is_cap: true,
+      cap_is_new: true,
+      new_is_cap_exceptions: Vec::new(),
+      cap_is_new_exceptions: Vec::new(),
+    }
+  }
+}
+
+impl NewCap {
+  pub fn with_options(options: NewCapOptions) -> Arc<Self> {
+    Arc::new(NewCap { options })
+  }
+}
+
+const CODE: &str = "new-cap";
+
+/// Built-in functions that are commonly called without `new`.
+const CAP_IS_NEW_BUILTINS: &[&str] = &[
+  "Array", "BigInt", "Boolean", "Date", "Error", "Function", "Number",
+  "Object", "RegExp", "String", "Symbol",
+];
+
+#[derive(Display)]
+enum NewCapMessage {
+  #[display(
+    fmt = "A constructor name should not start with a lowercase letter"
+  )]
+  LowercaseNew,
+  #[display(
+    fmt = "A function with a name starting with an uppercase letter should only be used as a constructor"
+  )]
+  UppercaseCall,
+}
+
+#[derive(Display)]
+enum NewCapHint {
+  #[display(fmt = "Capitalize the name of the constructor")]
+  Capitalize,
+  #[display(fmt = "Add `new` to the call, or rename the function")]
+  AddNew,
+}
+
+impl LintRule for NewCap {
+  fn new() -> Arc<Self> {
+    Self::with_options(NewCapOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "newIsCap": {
+          "type": "boolean",
+          "description": "Require `new` to be called with a capitalized name.",
+          "default": true
+        },
+        "capIsNew": {
+          "type": "boolean",
+          "description": "Require capitalized functions to be called with `new`.",
+          "default": true
+        },
+        "newIsCapExceptions": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Lowercase names that are allowed to be called with `new`."
+        },
+        "capIsNewExceptions": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Capitalized names that are allowed to be called without `new`."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NewCapOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NewCapHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/new_cap.md")
+  }
+}
+
+#[derive(Debug, PartialEq)]
+enum Capitalization {
+  Upper,
+  Lower,
+  NonAlpha,
+}
+
+fn capitalization(name: &str) -> Capitalization {
+  match name.chars().next() {
+    Some(c) if c.is_uppercase() => Capitalization::Upper,
+    Some(c) if c.is_lowercase() => Capitalization::Lower,
+    _ => Capitalization::NonAlpha,
+  }
+}
+
+/// Returns the name a callee is called by, e.g. `bar` for `foo.bar()`.
+fn callee_name(callee: &Expr) -> Option<String> {
+  match callee {
+    Expr::Ident(ident) => Some(ident.sym.to_string()),
+    Expr::Member(member) => match &*member.prop {
+      Expr::Ident(prop) if !member.computed => Some(prop.sym.to_string()),
+      Expr::Lit(Lit::Str(s)) if member.computed => Some(s.value.to_string()),
+      _ => None,
+    },
+    Expr::Paren(paren) => callee_name(&paren.expr),
+    _ => None,
+  }
+}
+
+/// Returns the dotted path of a callee, e.g. `foo.bar` for `foo.bar()`.
+fn callee_path(callee: &Expr) -> Option<String> {
+  match callee {
+    Expr::Ident(ident) => Some(ident.sym.to_string()),
+    Expr::Member(member) if !member.computed => {
+      let obj = match &member.obj {
+        ExprOrSuper::Expr(obj) => callee_path(obj)?,
+        ExprOrSuper::Super(_) => "super".to_string(),
+      };
+      match &*member.prop {
+        Expr::Ident(prop) => Some(format!("{}.{}", obj, prop.sym)),
+        _ => None,
+      }
+    }
+    Expr::This(_) => Some("this".to_string()),
+    _ => None,
+  }
+}
+
+fn is_allowed(exceptions: &[String], name: &str, callee: &Expr) -> bool {
+  exceptions.iter().any(|exception| exception == name)
+    || matches!(callee_path(callee), Some(path) if exceptions.contains(&path))
+}
+
+struct NewCapHandler<'a> {
+  options: &'a NewCapOptions,
+}
+
+impl<'a> Handler for NewCapHandler<'a> {
+  fn new_expr(&mut self, new_expr: &ast_view::NewExpr, ctx: &mut Context) {
+    if !self.options.new_is_cap {
+      return;
+    }
+
+    let callee = &*new_expr.inner.callee;
+    let name = match callee_name(callee) {
+      Some(name) => name,
+      None => return,
+    };
+    if capitalization(&name) != Capitalization::Lower
+      || is_allowed(&self.options.new_is_cap_exceptions, &name, callee)
+    {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(
+      new_expr.span(),
+      CODE,
+      NewCapMessage::LowercaseNew,
+      NewCapHint::Capitalize,
+    );
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    if !self.options.cap_is_new {
+      return;
+    }
+
+    let callee = match &call_expr.inner.callee {
+      ExprOrSuper::Expr(callee) => &**callee,
+      ExprOrSuper::Super(_) => return,
+    };
+    let name = match callee_name(callee) {
+      Some(name) => name,
+      None => return,
+    };
+    if capitalization(&name) != Capitalization::Upper
+      || CAP_IS_NEW_BUILTINS.contains(&name.as_str())
+      || is_allowed(&self.options.cap_is_new_exceptions, &name, callee)
+      || matches!(callee_path(callee).as_deref(), Some("Date.UTC"))
+    {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(
+      call_expr.span(),
+      CODE,
+      NewCapMessage::UppercaseCall,
+      NewCapHint::AddNew,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.32.0/tests/lib/rules/new-cap.js
+  // MIT Licensed.
+
+  #[test]
+  fn new_cap_valid() {
+    assert_lint_ok! {
+      NewCap,
+      "var x = new Constructor();",
+      "var x = new a.b.Constructor();",
+      "var x = new a.b['Constructor']();",
+      "var x = new a.b[Constructor]();",
+      "var x = new a.b[constructor]();",
+      "var x = new function(){};",
+      "var x = new _;",
+      "var x = new $();",
+      "var x = new Σ();",
+      "var x = new _x();",
+      "var x = new $x();",
+      "var x = Object(y);",
+      "var x = String(y);",
+      "var x = Symbol('symbol');",
+      "var x = BigInt(10);",
+      "var x = Date.UTC(2000, 0);",
+      "var x = Date.UTC;",
+      "var x = Date();",
+      "var x = new (foo())();",
+      "var x = new Foo.bar.Baz();",
+      "var x = _();",
+      "var x = $();",
+      "var x = foo();",
+      "var x = foo.bar();",
+      "class Foo extends Bar { constructor() { super(); } }",
+    };
+
+    assert_lint_ok! {
+      NewCap,
+      options: NewCapOptions {
+        new_is_cap: false,
+        cap_is_new: false,
+        ..Default::default()
+      },
+      "var x = new foo();",
+      "var x = Foo();",
+    };
+
+    assert_lint_ok! {
+      NewCap,
+      options: NewCapOptions {
+        new_is_cap_exceptions: vec!["foo".to_string(), "events.bar".to_string()],
+        cap_is_new_exceptions: vec!["Foo".to_string(), "Bar.Baz".to_string()],
+        ..Default::default()
+      },
+      "var x = new foo();",
+      "var x = new events.bar();",
+      "var x = Foo();",
+      "var x = a.Foo();",
+      "var x = Bar.Baz();",
+    };
+  }
+
+  #[test]
+  fn new_cap_invalid() {
+    assert_lint_err! {
+      NewCap,
+      "var x = new c();": [
+        {
+          col: 8,
+          message: NewCapMessage::LowercaseNew,
+          hint: NewCapHint::Capitalize,
+        }
+      ],
+      "var x = new a.b.c;": [
+        {
+          col: 8,
+          message: NewCapMessage::LowercaseNew,
+          hint: NewCapHint::Capitalize,
+        }
+      ],
+      "var x = new a.b['c']();": [
+        {
+          col: 8,
+          message: NewCapMessage::LowercaseNew,
+          hint: NewCapHint::Capitalize,
+        }
+      ],
+      "var x = new ç();": [
+        {
+          col: 8,
+          message: NewCapMessage::LowercaseNew,
+          hint: NewCapHint::Capitalize,
+        }
+      ],
+      "var x = Foo(42);": [
+        {
+          col: 8,
+          message: NewCapMessage::UppercaseCall,
+          hint: NewCapHint::AddNew,
+        }
+      ],
+      "var x = a.b.Foo();": [
+        {
+          col: 8,
+          message: NewCapMessage::UppercaseCall,
+          hint: NewCapHint::AddNew,
+        }
+      ],
+      "var x = a['Foo']();": [
+        {
+          col: 8,
+          message: NewCapMessage::UppercaseCall,
+          hint: NewCapHint::AddNew,
+        }
+      ],
+      "var x = Foo.Bar(); var y = new foo.bar();": [
+        {
+          col: 8,
+          message: NewCapMessage::UppercaseCall,
+          hint: NewCapHint::AddNew,
+        },
+        {
+          col: 27,
+          message: NewCapMessage::LowercaseNew,
+          hint: NewCapHint::Capitalize,
+        }
+      ],
+      "var x = Foo.UTC();": [
+        {
+          col: 8,
+          message: NewCapMessage::UppercaseCall,
+          hint: NewCapHint::AddNew,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NewCap,
+      options: NewCapOptions {
+        cap_is_new_exceptions: vec!["Bar.Baz".to_string()],
+        ..Default::default()
+      },
+      "var x = Foo.Baz();": [
+        {
+          col: 8,
+          message: NewCapMessage::UppercaseCall,
+          hint: NewCapHint::AddNew,
+        }
+      ],
+    };
+  }
+}
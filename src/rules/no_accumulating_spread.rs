@@ -0,0 +1,411 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::unwrap_paren;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrayLit, ArrowExpr, AssignExpr, AssignOp, CallExpr, Constructor,
+  DoWhileStmt, Expr, ExprOrSuper, ForInStmt, ForOfStmt, ForStmt, Function,
+  ObjectLit, Pat, PatOrExpr, PropOrSpread, WhileStmt,
+};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node;
+use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::VisitWith;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoAccumulatingSpread;
+
+const CODE: &str = "no-accumulating-spread";
+
+#[derive(Display)]
+enum NoAccumulatingSpreadMessage {
+  #[display(fmt = "Do not spread the accumulator of `{}()`", _0)]
+  Reduce(JsWord),
+  #[display(fmt = "Do not spread `{}` into itself in a loop", _0)]
+  Loop(JsWord),
+}
+
+#[derive(Display)]
+enum NoAccumulatingSpreadHint {
+  #[display(
+    fmt = "Spreading copies every element on each iteration, which takes quadratic time. Mutate the value instead, e.g. with `push()` or by assigning the property"
+  )]
+  Mutate,
+}
+
+impl LintRule for NoAccumulatingSpread {
+  fn new() -> Arc<Self> {
+    Arc::new(NoAccumulatingSpread)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoAccumulatingSpreadVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_accumulating_spread.md")
+  }
+}
+
+/// Returns the name of the method if the call is `reduce()` or
+/// `reduceRight()`.
+fn reduce_method(call_expr: &CallExpr) -> Option<&JsWord> {
+  let member_expr = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => match unwrap_paren(callee) {
+      Expr::Member(member_expr) if !member_expr.computed => member_expr,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  match &*member_expr.prop {
+    Expr::Ident(prop)
+      if prop.sym == *"reduce" || prop.sym == *"reduceRight" =>
+    {
+      Some(&prop.sym)
+    }
+    _ => None,
+  }
+}
+
+fn ident_name(pat: &Pat) -> Option<&JsWord> {
+  match pat {
+    Pat::Ident(ident) => Some(&ident.id.sym),
+    Pat::Expr(expr) => match &**expr {
+      Expr::Ident(ident) => Some(&ident.sym),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+fn is_ident(expr: &Expr, name: &JsWord) -> bool {
+  matches!(unwrap_paren(expr), Expr::Ident(ident) if ident.sym == *name)
+}
+
+/// Returns the spans of the elements spreading the variable with the given
+/// name, e.g. `...acc` in `[...acc, x]`.
+fn array_spreads(array: &ArrayLit, name: &JsWord) -> Vec<Span> {
+  array
+    .elems
+    .iter()
+    .flatten()
+    .filter_map(|elem| match elem.spread {
+      Some(spread) if is_ident(&elem.expr, name) => Some(Span::new(
+        spread.lo(),
+        elem.expr.span().hi(),
+        Default::default(),
+      )),
+      _ => None,
+    })
+    .collect()
+}
+
+/// Returns the spans of the properties spreading the variable with the given
+/// name, e.g. `...acc` in `{ ...acc, [x]: 1 }`.
+fn object_spreads(object: &ObjectLit, name: &JsWord) -> Vec<Span> {
+  object
+    .props
+    .iter()
+    .filter_map(|prop| match prop {
+      PropOrSpread::Spread(spread) if is_ident(&spread.expr, name) => {
+        Some(spread.span())
+      }
+      _ => None,
+    })
+    .collect()
+}
+
+struct NoAccumulatingSpreadVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+  /// The method name and the accumulator parameter of the `reduce()` callback
+  /// being visited.
+  accumulator: Option<(JsWord, JsWord)>,
+  /// Whether a loop body of the current function is being visited.
+  in_loop: bool,
+}
+
+impl<'c, 'view> NoAccumulatingSpreadVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self {
+      context,
+      accumulator: None,
+      in_loop: false,
+    }
+  }
+
+  fn report(&mut self, spans: Vec<Span>, message: NoAccumulatingSpreadMessage) {
+    for span in spans {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        message.to_string(),
+        NoAccumulatingSpreadHint::Mutate,
+      );
+    }
+  }
+
+  /// Visits the body of a function, which is not in a loop body, and is a
+  /// `reduce()` callback only if `accumulator` is given.
+  fn with_function<F>(&mut self, accumulator: Option<(JsWord, JsWord)>, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    let prev_accumulator =
+      std::mem::replace(&mut self.accumulator, accumulator);
+    let prev_in_loop = std::mem::replace(&mut self.in_loop, false);
+    op(self);
+    self.accumulator = prev_accumulator;
+    self.in_loop = prev_in_loop;
+  }
+
+  fn with_loop<F>(&mut self, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    let prev_in_loop = std::mem::replace(&mut self.in_loop, true);
+    op(self);
+    self.in_loop = prev_in_loop;
+  }
+
+  fn visit_reduce_callback(&mut self, method: &JsWord, callback: &Expr) {
+    let accumulator = |pat: Option<&Pat>| {
+      pat
+        .and_then(ident_name)
+        .map(|name| (method.clone(), name.clone()))
+    };
+    match unwrap_paren(callback) {
+      Expr::Arrow(arrow) => {
+        let accumulator = accumulator(arrow.params.first());
+        self.with_function(accumulator, |a| arrow.visit_children_with(a));
+      }
+      Expr::Fn(fn_expr) => {
+        let function = &fn_expr.function;
+        let accumulator =
+          accumulator(function.params.first().map(|param| &param.pat));
+        self.with_function(accumulator, |a| function.visit_children_with(a));
+      }
+      callback => callback.visit_with(&DUMMY_NODE, self),
+    }
+  }
+}
+
+impl<'c, 'view> Visit for NoAccumulatingSpreadVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    let (method, callback) =
+      match (reduce_method(call_expr), call_expr.args.first()) {
+        (Some(method), Some(callback)) if callback.spread.is_none() => {
+          (method.clone(), callback)
+        }
+        _ => {
+          call_expr.visit_children_with(self);
+          return;
+        }
+      };
+
+    call_expr.callee.visit_with(&DUMMY_NODE, self);
+    self.visit_reduce_callback(&method, &callback.expr);
+    for arg in &call_expr.args[1..] {
+      arg.visit_with(&DUMMY_NODE, self);
+    }
+  }
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    self.with_function(None, |a| function.visit_children_with(a));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    self.with_function(None, |a| arrow_expr.visit_children_with(a));
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    self.with_function(None, |a| constructor.visit_children_with(a));
+  }
+
+  fn visit_array_lit(&mut self, array: &ArrayLit, _: &dyn Node) {
+    if let Some((method, accumulator)) = &self.accumulator {
+      let spans = array_spreads(array, accumulator);
+      let message = NoAccumulatingSpreadMessage::Reduce(method.clone());
+      self.report(spans, message);
+    }
+    array.visit_children_with(self);
+  }
+
+  fn visit_object_lit(&mut self, object: &ObjectLit, _: &dyn Node) {
+    if let Some((method, accumulator)) = &self.accumulator {
+      let spans = object_spreads(object, accumulator);
+      let message = NoAccumulatingSpreadMessage::Reduce(method.clone());
+      self.report(spans, message);
+    }
+    object.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _: &dyn Node) {
+    let name = match &assign_expr.left {
+      PatOrExpr::Pat(pat) => ident_name(pat),
+      PatOrExpr::Expr(expr) => match &**expr {
+        Expr::Ident(ident) => Some(&ident.sym),
+        _ => None,
+      },
+    };
+    if let (true, AssignOp::Assign, Some(name)) =
+      (self.in_loop, assign_expr.op, name)
+    {
+      // Spreads of the accumulator of `reduce()` are already reported.
+      if !matches!(&self.accumulator, Some((_, acc)) if acc == name) {
+        let spans = match unwrap_paren(&assign_expr.right) {
+          Expr::Array(array) => array_spreads(array, name),
+          Expr::Object(object) => object_spreads(object, name),
+          _ => Vec::new(),
+        };
+        self.report(spans, NoAccumulatingSpreadMessage::Loop(name.clone()));
+      }
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    for_stmt.init.visit_with(&DUMMY_NODE, self);
+    self.with_loop(|a| {
+      for_stmt.test.visit_with(&DUMMY_NODE, a);
+      for_stmt.update.visit_with(&DUMMY_NODE, a);
+      for_stmt.body.visit_with(&DUMMY_NODE, a);
+    });
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _: &dyn Node) {
+    for_of_stmt.left.visit_with(&DUMMY_NODE, self);
+    for_of_stmt.right.visit_with(&DUMMY_NODE, self);
+    self.with_loop(|a| for_of_stmt.body.visit_with(&DUMMY_NODE, a));
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _: &dyn Node) {
+    for_in_stmt.left.visit_with(&DUMMY_NODE, self);
+    for_in_stmt.right.visit_with(&DUMMY_NODE, self);
+    self.with_loop(|a| for_in_stmt.body.visit_with(&DUMMY_NODE, a));
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _: &dyn Node) {
+    self.with_loop(|a| while_stmt.visit_children_with(a));
+  }
+
+  fn visit_do_while_stmt(&mut self, do_while_stmt: &DoWhileStmt, _: &dyn Node) {
+    self.with_loop(|a| do_while_stmt.visit_children_with(a));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_accumulating_spread_valid() {
+    assert_lint_ok! {
+      NoAccumulatingSpread,
+      "arr.reduce((acc, x) => { acc[x] = 1; return acc; }, {});",
+      "arr.reduce((acc, x) => { acc.push(x); return acc; }, []);",
+      "arr.reduce((acc, x) => acc + x, 0);",
+      "arr.reduce((acc, x) => ({ ...x, acc }), {});",
+      "arr.reduce((acc, x) => [...x, acc], []);",
+      "arr.reduce((acc, x) => Math.max(...acc), 0);",
+      "arr.reduce(reducer, {});",
+      "arr.reduce((acc, x) => { const f = (acc) => [...acc]; return f(x); }, []);",
+      "arr.map((acc, x) => [...acc, x]);",
+      "arr.reduce(({ a }, x) => [...a, x], []);",
+      "let a = []; a = [...a, 1];",
+      "let a = []; for (const x of xs) { const b = [...a, x]; }",
+      "let a = []; for (const x of xs) { a = [...b, x]; }",
+      "let a = []; for (const x of xs) { a += [...a]; }",
+      "let a = []; for (const x of xs) { fns.push(() => { a = [...a, x]; }); }",
+      "let a = []; for (const x of xs) { a.push(x); }",
+    };
+  }
+
+  #[test]
+  fn no_accumulating_spread_invalid() {
+    assert_lint_err! {
+      NoAccumulatingSpread,
+      "arr.reduce((acc, x) => ({ ...acc, [x]: 1 }), {});": [
+        {
+          col: 26,
+          message: NoAccumulatingSpreadMessage::Reduce("reduce".into()),
+          hint: NoAccumulatingSpreadHint::Mutate,
+        }
+      ],
+      "arr.reduce((acc, x) => [...acc, x], []);": [
+        {
+          col: 24,
+          message: NoAccumulatingSpreadMessage::Reduce("reduce".into()),
+          hint: NoAccumulatingSpreadHint::Mutate,
+        }
+      ],
+      "arr.reduceRight(function (acc, x) { return [x, ...acc]; }, []);": [
+        {
+          col: 47,
+          message: NoAccumulatingSpreadMessage::Reduce("reduceRight".into()),
+          hint: NoAccumulatingSpreadHint::Mutate,
+        }
+      ],
+      "arr.reduce((acc, x) => { const next = { ...acc }; next[x] = 1; return next; }, {});": [
+        {
+          col: 40,
+          message: NoAccumulatingSpreadMessage::Reduce("reduce".into()),
+          hint: NoAccumulatingSpreadHint::Mutate,
+        }
+      ],
+      "let a = []; for (const x of xs) { a = [...a, x]; }": [
+        {
+          col: 39,
+          message: NoAccumulatingSpreadMessage::Loop("a".into()),
+          hint: NoAccumulatingSpreadHint::Mutate,
+        }
+      ],
+      "let a = {}; for (const k in obj) { a = { ...a, [k]: 1 }; }": [
+        {
+          col: 41,
+          message: NoAccumulatingSpreadMessage::Loop("a".into()),
+          hint: NoAccumulatingSpreadHint::Mutate,
+        }
+      ],
+      "let a = []; for (let i = 0; i < 10; i++) { if (i % 2) { a = [...a, i]; } }": [
+        {
+          col: 61,
+          message: NoAccumulatingSpreadMessage::Loop("a".into()),
+          hint: NoAccumulatingSpreadHint::Mutate,
+        }
+      ],
+      "let a = []; while (x) { a = [...(a), x]; }": [
+        {
+          col: 29,
+          message: NoAccumulatingSpreadMessage::Loop("a".into()),
+          hint: NoAccumulatingSpreadHint::Mutate,
+        }
+      ],
+      "function f() { let a = []; do { a = [...a, 1]; } while (x); }": [
+        {
+          col: 37,
+          message: NoAccumulatingSpreadMessage::Loop("a".into()),
+          hint: NoAccumulatingSpreadHint::Mutate,
+        }
+      ],
+    };
+  }
+}
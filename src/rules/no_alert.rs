@@ -0,0 +1,235 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view::{CallExpr, Expr, ExprOrSuper, Ident, Lit, MemberExpr};
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoAlert;
+
+const CODE: &str = "no-alert";
+
+#[derive(Display)]
+enum NoAlertMessage {
+  #[display(fmt = "`{}` is not allowed", _0)]
+  Unexpected(String),
+}
+
+#[derive(Display)]
+enum NoAlertHint {
+  #[display(
+    fmt = "Use a non-blocking UI element, like a custom dialog, instead"
+  )]
+  UseCustomDialog,
+}
+
+impl LintRule for NoAlert {
+  fn new() -> Arc<Self> {
+    Arc::new(NoAlert)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoAlertHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_alert.md")
+  }
+}
+
+const DIALOGS: &[&str] = &["alert", "confirm", "prompt"];
+
+/// Objects that refer to the global object.
+const GLOBAL_OBJECTS: &[&str] = &["window", "globalThis", "self"];
+
+fn is_global(ctx: &Context, ident: &Ident, names: &[&str]) -> bool {
+  names.contains(&&*ident.inner.sym)
+    && ctx.scope().is_global(&ident.inner.to_id())
+}
+
+/// Returns the name of the dialog called through the global object, e.g. in
+/// `window.alert()` or `window["confirm"]()`.
+fn global_member_dialog(ctx: &Context, member: &MemberExpr) -> Option<String> {
+  let is_global_object = matches!(
+    &member.obj,
+    ExprOrSuper::Expr(Expr::Ident(obj)) if is_global(ctx, obj, GLOBAL_OBJECTS)
+  );
+  if !is_global_object {
+    return None;
+  }
+  let name = match (&member.prop, member.inner.computed) {
+    (Expr::Ident(prop), false) => prop.inner.sym.to_string(),
+    (Expr::Lit(Lit::Str(prop)), true) => prop.inner.value.to_string(),
+    _ => return None,
+  };
+  if DIALOGS.contains(&name.as_str()) {
+    Some(name)
+  } else {
+    None
+  }
+}
+
+struct NoAlertHandler;
+
+impl Handler for NoAlertHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let name = match &call_expr.callee {
+      ExprOrSuper::Expr(Expr::Ident(callee))
+        if is_global(ctx, callee, DIALOGS) =>
+      {
+        callee.inner.sym.to_string()
+      }
+      ExprOrSuper::Expr(Expr::Member(member)) => {
+        match global_member_dialog(ctx, member) {
+          Some(name) => name,
+          None => return,
+        }
+      }
+      _ => return,
+    };
+    ctx.add_diagnostic_with_hint(
+      call_expr.span(),
+      CODE,
+      NoAlertMessage::Unexpected(name),
+      NoAlertHint::UseCustomDialog,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-alert.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_alert_valid() {
+    assert_lint_ok! {
+      NoAlert,
+      "a[o.k](1)",
+      "foo.alert(foo)",
+      "foo.confirm(foo)",
+      "foo.prompt(foo)",
+      "function alert() {} alert();",
+      "var alert = function() {}; alert();",
+      "function foo() { var alert = bar; alert(); }",
+      "function foo(alert) { alert(); }",
+      "var alert = function() {}; function test() { alert(); }",
+      "function foo() { var prompt = function() {}; prompt(); }",
+      "function foo() { var window = bar; window.alert(); }",
+      "function foo(window) { window.alert(); }",
+      "window[alert]()",
+      "window.alert",
+      "this.alert(foo)",
+      "globalThis.open()",
+    };
+  }
+
+  #[test]
+  fn no_alert_invalid() {
+    assert_lint_err! {
+      NoAlert,
+      "alert(foo)": [
+        {
+          col: 0,
+          message: NoAlertMessage::Unexpected("alert".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+      "window.alert(foo)": [
+        {
+          col: 0,
+          message: NoAlertMessage::Unexpected("alert".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+      "window['alert'](foo)": [
+        {
+          col: 0,
+          message: NoAlertMessage::Unexpected("alert".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+      "confirm(foo)": [
+        {
+          col: 0,
+          message: NoAlertMessage::Unexpected("confirm".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+      "window.confirm(foo)": [
+        {
+          col: 0,
+          message: NoAlertMessage::Unexpected("confirm".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+      "prompt(foo)": [
+        {
+          col: 0,
+          message: NoAlertMessage::Unexpected("prompt".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+      "globalThis.prompt(foo)": [
+        {
+          col: 0,
+          message: NoAlertMessage::Unexpected("prompt".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+      "self['prompt'](foo)": [
+        {
+          col: 0,
+          message: NoAlertMessage::Unexpected("prompt".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+      "function alert() {} window.alert(foo)": [
+        {
+          col: 20,
+          message: NoAlertMessage::Unexpected("alert".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+      "function foo() { alert(); }": [
+        {
+          col: 17,
+          message: NoAlertMessage::Unexpected("alert".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+      "const answer = confirm('Sure?') && prompt('Name?');": [
+        {
+          col: 15,
+          message: NoAlertMessage::Unexpected("confirm".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        },
+        {
+          col: 35,
+          message: NoAlertMessage::Unexpected("prompt".to_string()),
+          hint: NoAlertHint::UseCustomDialog,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,358 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{DefaultDecl, Expr};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoAnonymousDefaultExport {
+  options: NoAnonymousDefaultExportOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoAnonymousDefaultExportOptions {
+  /// Allow `export default [];`.
+  pub allow_array: bool,
+  /// Allow `export default () => {};`.
+  pub allow_arrow_function: bool,
+  /// Allow `export default class {}`.
+  pub allow_anonymous_class: bool,
+  /// Allow `export default function () {}`.
+  pub allow_anonymous_function: bool,
+  /// Allow `export default foo();`.
+  pub allow_call_expression: bool,
+  /// Allow `export default new Foo();`.
+  pub allow_new: bool,
+  /// Allow `export default 1;` and other literals.
+  pub allow_literal: bool,
+  /// Allow `export default {};`.
+  pub allow_object: bool,
+}
+
+impl Default for NoAnonymousDefaultExportOptions {
+  fn default() -> Self {
+    Self {
+      allow_array: false,
+      allow_arrow_function: false,
+      allow_anonymous_class: false,
+      allow_anonymous_function: false,
+      allow_call_expression: true,
+      allow_new: false,
+      allow_literal: false,
+      allow_object: false,
+    }
+  }
+}
+
+impl NoAnonymousDefaultExport {
+  pub fn with_options(options: NoAnonymousDefaultExportOptions) -> Arc<Self> {
+    Arc::new(NoAnonymousDefaultExport { options })
+  }
+}
+
+const CODE: &str = "no-anonymous-default-export";
+
+#[derive(Display)]
+enum NoAnonymousDefaultExportMessage {
+  #[display(
+    fmt = "Assign {} to a variable before exporting as module default",
+    _0
+  )]
+  Anonymous(&'static str),
+}
+
+#[derive(Display)]
+enum NoAnonymousDefaultExportHint {
+  #[display(fmt = "Give the exported value a name")]
+  AddName,
+}
+
+impl LintRule for NoAnonymousDefaultExport {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoAnonymousDefaultExportOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let allow = |description: &str, default: bool| {
+      json!({
+        "type": "boolean",
+        "description": description,
+        "default": default
+      })
+    };
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowArray": allow("Allow exporting an array literal.", false),
+        "allowArrowFunction": allow("Allow exporting an arrow function.", false),
+        "allowAnonymousClass": allow("Allow exporting an anonymous class.", false),
+        "allowAnonymousFunction": allow("Allow exporting an anonymous function.", false),
+        "allowCallExpression": allow("Allow exporting the result of a call.", true),
+        "allowNew": allow("Allow exporting a `new` expression.", false),
+        "allowLiteral": allow("Allow exporting a literal.", false),
+        "allowObject": allow("Allow exporting an object literal.", false)
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoAnonymousDefaultExportOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoAnonymousDefaultExportHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_anonymous_default_export.md")
+  }
+}
+
+struct NoAnonymousDefaultExportHandler<'a> {
+  options: &'a NoAnonymousDefaultExportOptions,
+}
+
+impl<'a> NoAnonymousDefaultExportHandler<'a> {
+  /// Returns the description of the exported value if it's not allowed.
+  fn disallowed_expr(&self, expr: &Expr) -> Option<&'static str> {
+    let options = self.options;
+    let (allowed, description) = match expr {
+      Expr::Array(_) => (options.allow_array, "an array"),
+      Expr::Arrow(_) => (options.allow_arrow_function, "an arrow function"),
+      Expr::Class(class) if class.ident.is_none() => {
+        (options.allow_anonymous_class, "a class")
+      }
+      Expr::Fn(function) if function.ident.is_none() => {
+        (options.allow_anonymous_function, "a function")
+      }
+      Expr::Call(_) => (options.allow_call_expression, "a call result"),
+      Expr::New(_) => (options.allow_new, "an instance"),
+      Expr::Lit(_) | Expr::Tpl(_) => (options.allow_literal, "a literal"),
+      Expr::Object(_) => (options.allow_object, "an object"),
+      Expr::Paren(paren) => return self.disallowed_expr(&paren.expr),
+      _ => return None,
+    };
+    if allowed {
+      None
+    } else {
+      Some(description)
+    }
+  }
+}
+
+impl<'a> Handler for NoAnonymousDefaultExportHandler<'a> {
+  fn export_default_decl(
+    &mut self,
+    export_default_decl: &ast_view::ExportDefaultDecl,
+    ctx: &mut Context,
+  ) {
+    let description = match &export_default_decl.inner.decl {
+      DefaultDecl::Class(class)
+        if class.ident.is_none() && !self.options.allow_anonymous_class =>
+      {
+        "a class"
+      }
+      DefaultDecl::Fn(function)
+        if function.ident.is_none()
+          && !self.options.allow_anonymous_function =>
+      {
+        "a function"
+      }
+      _ => return,
+    };
+
+    ctx.add_diagnostic_with_hint(
+      export_default_decl.span(),
+      CODE,
+      NoAnonymousDefaultExportMessage::Anonymous(description),
+      NoAnonymousDefaultExportHint::AddName,
+    );
+  }
+
+  fn export_default_expr(
+    &mut self,
+    export_default_expr: &ast_view::ExportDefaultExpr,
+    ctx: &mut Context,
+  ) {
+    if let Some(description) =
+      self.disallowed_expr(&export_default_expr.inner.expr)
+    {
+      ctx.add_diagnostic_with_hint(
+        export_default_expr.span(),
+        CODE,
+        NoAnonymousDefaultExportMessage::Anonymous(description),
+        NoAnonymousDefaultExportHint::AddName,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/import-js/eslint-plugin-import/blob/v2.26.0/tests/src/rules/no-anonymous-default-export.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_anonymous_default_export_valid() {
+    assert_lint_ok! {
+      NoAnonymousDefaultExport,
+      "const foo = 123; export default foo;",
+      "export default function foo() {}",
+      "export default class MyClass {}",
+      "export default (function foo() {});",
+      "export default foo(bar);",
+      "export default foo.bar;",
+      "export * from 'foo';",
+      "export const foo = 123;",
+      "export { foo };",
+      "export { foo as default };",
+      "export default interface Foo {}",
+    };
+
+    assert_lint_ok! {
+      NoAnonymousDefaultExport,
+      options: NoAnonymousDefaultExportOptions {
+        allow_array: true,
+        allow_arrow_function: true,
+        allow_anonymous_class: true,
+        allow_anonymous_function: true,
+        allow_new: true,
+        allow_literal: true,
+        allow_object: true,
+        ..Default::default()
+      },
+      "export default [];",
+      "export default () => {};",
+      "export default class {}",
+      "export default function() {}",
+      "export default new Foo();",
+      "export default 123;",
+      "export default 'foo';",
+      "export default `foo`;",
+      "export default {};",
+    };
+  }
+
+  #[test]
+  fn no_anonymous_default_export_invalid() {
+    assert_lint_err! {
+      NoAnonymousDefaultExport,
+      "export default [];": [
+        {
+          col: 0,
+          message: NoAnonymousDefaultExportMessage::Anonymous("an array"),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default () => {};": [
+        {
+          col: 0,
+          message: NoAnonymousDefaultExportMessage::Anonymous("an arrow function"),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default class {}": [
+        {
+          col: 0,
+          message: NoAnonymousDefaultExportMessage::Anonymous("a class"),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default function() {}": [
+        {
+          col: 0,
+          message: NoAnonymousDefaultExportMessage::Anonymous("a function"),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default (function() {});": [
+        {
+          col: 0,
+          message: NoAnonymousDefaultExportMessage::Anonymous("a function"),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default new Foo();": [
+        {
+          col: 0,
+          message: NoAnonymousDefaultExportMessage::Anonymous("an instance"),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default 123;": [
+        {
+          col: 0,
+          message: NoAnonymousDefaultExportMessage::Anonymous("a literal"),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default `foo`;": [
+        {
+          col: 0,
+          message: NoAnonymousDefaultExportMessage::Anonymous("a literal"),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default {};": [
+        {
+          col: 0,
+          message: NoAnonymousDefaultExportMessage::Anonymous("an object"),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoAnonymousDefaultExport,
+      options: NoAnonymousDefaultExportOptions {
+        allow_call_expression: false,
+        ..Default::default()
+      },
+      "export default foo(bar);": [
+        {
+          col: 0,
+          message: NoAnonymousDefaultExportMessage::Anonymous("a call result"),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+    };
+  }
+}
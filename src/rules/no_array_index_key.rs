@@ -0,0 +1,364 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BinaryOp, CallExpr, Expr, ExprOrSuper, JSXAttr, JSXAttrName, JSXAttrValue,
+  JSXExpr, Pat, Prop, PropName, PropOrSpread,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::utils::Id;
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use deno_ast::view as ast_view;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoArrayIndexKey;
+
+const CODE: &str = "no-array-index-key";
+const MESSAGE: &str = "Do not use the array index as a key";
+const HINT: &str =
+  "Use a stable identifier of the item instead, e.g. `key={item.id}`";
+
+/// Array methods whose callback receives the index, along with the position
+/// of the index among the parameters of the callback.
+const ARRAY_METHODS: &[(&str, usize)] = &[
+  ("every", 1),
+  ("filter", 1),
+  ("find", 1),
+  ("findIndex", 1),
+  ("findLast", 1),
+  ("findLastIndex", 1),
+  ("flatMap", 1),
+  ("forEach", 1),
+  ("map", 1),
+  ("reduce", 2),
+  ("reduceRight", 2),
+  ("some", 1),
+];
+
+impl LintRule for NoArrayIndexKey {
+  fn new() -> Arc<Self> {
+    Arc::new(NoArrayIndexKey)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoArrayIndexKeyHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_array_index_key.md")
+  }
+}
+
+fn is_ident(expr: &Expr, name: &str) -> bool {
+  matches!(expr, Expr::Ident(ident) if ident.sym == *name)
+}
+
+/// Checks if `expr` is `Children` or `React.Children`.
+fn is_children(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(ident) => ident.sym == *"Children",
+    Expr::Member(member_expr) if !member_expr.computed => {
+      let is_react = match &member_expr.obj {
+        ExprOrSuper::Expr(obj) => is_ident(obj, "React"),
+        ExprOrSuper::Super(_) => false,
+      };
+      is_react && is_ident(&member_expr.prop, "Children")
+    }
+    _ => false,
+  }
+}
+
+/// Returns the callback of an iteration like `items.map((item, i) => ...)`
+/// or `Children.map(children, (child, i) => ...)`, and the position of the
+/// index among its parameters.
+fn iteration_callback(call_expr: &CallExpr) -> Option<(&Expr, usize)> {
+  let member_expr = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => match &**callee {
+      Expr::Member(member_expr) if !member_expr.computed => member_expr,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  let method = match &*member_expr.prop {
+    Expr::Ident(ident) => ident.sym.as_ref(),
+    _ => return None,
+  };
+
+  let (arg, index) = match &member_expr.obj {
+    ExprOrSuper::Expr(obj)
+      if is_children(obj) && matches!(method, "forEach" | "map") =>
+    {
+      (1, 1)
+    }
+    _ => {
+      let (_, index) =
+        ARRAY_METHODS.iter().find(|(name, _)| *name == method)?;
+      (0, *index)
+    }
+  };
+  let callback = unwrap_paren(&call_expr.args.get(arg)?.expr);
+  Some((callback, index))
+}
+
+/// Returns the name of the index parameter of the callback.
+fn index_param(callback: &Expr, index: usize) -> Option<Id> {
+  let param = match callback {
+    Expr::Arrow(arrow_expr) => arrow_expr.params.get(index)?,
+    Expr::Fn(fn_expr) => &fn_expr.function.params.get(index)?.pat,
+    _ => return None,
+  };
+  match param {
+    Pat::Ident(binding_ident) => Some(binding_ident.id.to_id()),
+    _ => None,
+  }
+}
+
+struct NoArrayIndexKeyHandler;
+
+impl Handler for NoArrayIndexKeyHandler {
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let (callback, index) = match iteration_callback(call_expr.inner) {
+      Some(callback) => callback,
+      None => return,
+    };
+    let index = match index_param(callback, index) {
+      Some(index) => index,
+      None => return,
+    };
+
+    let mut finder = IndexKeyFinder {
+      index,
+      spans: Vec::new(),
+    };
+    callback.visit_with(&DUMMY_NODE, &mut finder);
+    for span in finder.spans {
+      ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+/// Finds `key` props whose value is derived from the index.
+struct IndexKeyFinder {
+  index: Id,
+  spans: Vec<Span>,
+}
+
+impl IndexKeyFinder {
+  /// Checks if `expr` is the index, or a string built from it, e.g.
+  /// `` `item-${index}` ``, `"item-" + index` or `index.toString()`.
+  fn uses_index(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Ident(ident) => ident.to_id() == self.index,
+      Expr::Paren(paren) => self.uses_index(&paren.expr),
+      Expr::Tpl(tpl) => tpl.exprs.iter().any(|expr| self.uses_index(expr)),
+      Expr::Bin(bin_expr) if bin_expr.op == BinaryOp::Add => {
+        self.uses_index(&bin_expr.left) || self.uses_index(&bin_expr.right)
+      }
+      Expr::Call(call_expr) => match &call_expr.callee {
+        ExprOrSuper::Expr(callee) => match &**callee {
+          Expr::Ident(ident) if ident.sym == *"String" => call_expr
+            .args
+            .first()
+            .into_iter()
+            .any(|arg| self.uses_index(&arg.expr)),
+          Expr::Member(member_expr)
+            if !member_expr.computed
+              && is_ident(&member_expr.prop, "toString") =>
+          {
+            match &member_expr.obj {
+              ExprOrSuper::Expr(obj) => self.uses_index(obj),
+              ExprOrSuper::Super(_) => false,
+            }
+          }
+          _ => false,
+        },
+        ExprOrSuper::Super(_) => false,
+      },
+      _ => false,
+    }
+  }
+}
+
+/// Checks if the call is `cloneElement(...)` or `createElement(...)`,
+/// possibly through a namespace like `React.cloneElement(...)`.
+fn is_element_factory(call_expr: &CallExpr) -> bool {
+  let name = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => match &**callee {
+      Expr::Ident(ident) => &ident.sym,
+      Expr::Member(member_expr) if !member_expr.computed => {
+        match &*member_expr.prop {
+          Expr::Ident(ident) => &ident.sym,
+          _ => return false,
+        }
+      }
+      _ => return false,
+    },
+    ExprOrSuper::Super(_) => return false,
+  };
+  *name == *"cloneElement" || *name == *"createElement"
+}
+
+impl Visit for IndexKeyFinder {
+  noop_visit_type!();
+
+  fn visit_jsx_attr(&mut self, jsx_attr: &JSXAttr, _parent: &dyn Node) {
+    if let (
+      JSXAttrName::Ident(name),
+      Some(JSXAttrValue::JSXExprContainer(container)),
+    ) = (&jsx_attr.name, &jsx_attr.value)
+    {
+      if let JSXExpr::Expr(expr) = &container.expr {
+        if name.sym == *"key" && self.uses_index(expr) {
+          self.spans.push(jsx_attr.span);
+        }
+      }
+    }
+    jsx_attr.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if is_element_factory(call_expr) {
+      if let Some(Expr::Object(props)) =
+        call_expr.args.get(1).map(|arg| &*arg.expr)
+      {
+        for prop in &props.props {
+          if let PropOrSpread::Prop(prop) = prop {
+            if let Prop::KeyValue(key_value) = &**prop {
+              let is_key = match &key_value.key {
+                PropName::Ident(ident) => ident.sym == *"key",
+                PropName::Str(s) => s.value == *"key",
+                _ => false,
+              };
+              if is_key && self.uses_index(&key_value.value) {
+                self.spans.push(key_value.span());
+              }
+            }
+          }
+        }
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.31.10/tests/lib/rules/no-array-index-key.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_array_index_key_valid() {
+    assert_lint_ok! {
+      NoArrayIndexKey,
+      filename: "foo.tsx",
+      "<Foo key=\"foo\" />;",
+      "<Foo key={i} />;",
+      "<Foo key />;",
+      "<Foo key={`foo-${i}`} />;",
+      "<Foo key={'foo-' + i} />;",
+      "foo.bar((baz, i) => <Foo key={i} />);",
+      "foo.bar((bar, i) => <Foo key={`foo-${i}`} />);",
+      "foo.map((baz) => <Foo key=\"foo\" />);",
+      "foo.map((baz, i) => <Foo key=\"foo\" />);",
+      "foo.map((baz, i) => <Foo key={baz.id} />);",
+      "foo.map((baz, i) => <Foo key={`foo-${baz.id}`} />);",
+      "foo.map((baz, i) => <Foo key={'foo' + baz.id} />);",
+      "foo.map(function (baz, i) { return <Foo key={baz.id} />; });",
+      "foo.map((item, i) => <Foo key={item.id} index={i} />);",
+      "foo.map(({ id }, i) => <Foo key={id} />);",
+      "foo.reduce((a, b) => a.concat(<Foo key={b.id} />), []);",
+      "foo.reduce((a, b, i) => a.concat(<Foo key={b.id} />), []);",
+      "foo.map((bar, i) => React.cloneElement(bar, { key: bar.id }));",
+      "Children.map(this.props.children, (child, i) => React.cloneElement(child, { key: child.id }));",
+      "foo.map((bar, i) => bar.map((baz, i) => <Foo key={baz.id} index={i} />));",
+    };
+  }
+
+  #[test]
+  fn no_array_index_key_invalid() {
+    assert_lint_err! {
+      NoArrayIndexKey,
+      filename: "foo.tsx",
+      "foo.map((bar, i) => <Foo key={i} />);": [
+        { col: 25, message: MESSAGE, hint: HINT }
+      ],
+      "[{}, {}].map((bar, i) => <Foo key={i} />);": [
+        { col: 30, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map((bar, anything) => <Foo key={anything} />);": [
+        { col: 32, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map((bar, i) => <Foo key={`foo-${i}`} />);": [
+        { col: 25, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map((bar, i) => <Foo key={'foo-' + i} />);": [
+        { col: 25, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map((bar, i) => <Foo key={'foo-' + i + '-bar'} />);": [
+        { col: 25, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map((bar, i) => <Foo key={i.toString()} />);": [
+        { col: 25, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map((bar, i) => <Foo key={String(i)} />);": [
+        { col: 25, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map(function (bar, i) { return <Foo key={i} />; });": [
+        { col: 40, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map((bar, i) => <div><Foo key={i} /></div>);": [
+        { col: 30, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map((bar, i) => React.cloneElement(bar, { key: i }));": [
+        { col: 46, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map((bar, i) => React.createElement('div', { key: i }));": [
+        { col: 49, message: MESSAGE, hint: HINT }
+      ],
+      "Children.map(this.props.children, (child, i) => <Foo key={i} />);": [
+        { col: 53, message: MESSAGE, hint: HINT }
+      ],
+      "React.Children.forEach(this.props.children, (child, i) => { result.push(<Foo key={i} />); });": [
+        { col: 77, message: MESSAGE, hint: HINT }
+      ],
+      "foo.forEach((bar, i) => { baz.push(<Foo key={i} />); });": [
+        { col: 40, message: MESSAGE, hint: HINT }
+      ],
+      "foo.filter((bar, i) => { baz.push(<Foo key={i} />); });": [
+        { col: 39, message: MESSAGE, hint: HINT }
+      ],
+      "foo.reduce((a, b, i) => a.concat(<Foo key={i} />), []);": [
+        { col: 38, message: MESSAGE, hint: HINT }
+      ],
+      "foo.flatMap((a, i) => <Foo key={i} />);": [
+        { col: 27, message: MESSAGE, hint: HINT }
+      ],
+      "foo.map((bar, i) => bar.map((baz, j) => <Foo key={`${i}-${j}`} />));": [
+        { col: 45, message: MESSAGE, hint: HINT },
+        { col: 45, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -0,0 +1,185 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view::{CallExpr, Expr, ExprOrSuper, Lit, MemberExpr};
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoAsyncForeach;
+
+const CODE: &str = "no-async-foreach";
+
+#[derive(Display)]
+enum NoAsyncForeachMessage {
+  #[display(
+    fmt = "Async callbacks passed to `forEach` are not awaited, and their errors are swallowed"
+  )]
+  AsyncCallback,
+}
+
+#[derive(Display)]
+enum NoAsyncForeachHint {
+  #[display(
+    fmt = "Use a `for...of` loop with `await`, or `await Promise.all(array.map(...))` instead"
+  )]
+  UseForOfOrPromiseAll,
+}
+
+impl LintRule for NoAsyncForeach {
+  fn new() -> Arc<Self> {
+    Arc::new(NoAsyncForeach)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoAsyncForeachHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_async_foreach.md")
+  }
+}
+
+fn is_async_function(expr: &Expr) -> bool {
+  match expr {
+    Expr::Fn(fn_expr) => fn_expr.function.is_async(),
+    Expr::Arrow(arrow_expr) => arrow_expr.is_async(),
+    Expr::Paren(paren) => is_async_function(&paren.expr),
+    _ => false,
+  }
+}
+
+/// Returns whether the member expression accesses a property named `forEach`,
+/// e.g. `foo.forEach` or `foo["forEach"]`.
+fn is_for_each(member_expr: &MemberExpr) -> bool {
+  match &member_expr.prop {
+    Expr::Ident(ident) if !member_expr.computed() => {
+      ident.sym().as_ref() == "forEach"
+    }
+    Expr::Lit(Lit::Str(s)) if member_expr.computed() => {
+      s.value().as_ref() == "forEach"
+    }
+    _ => false,
+  }
+}
+
+struct NoAsyncForeachHandler;
+
+impl Handler for NoAsyncForeachHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let member_expr = match &call_expr.callee {
+      ExprOrSuper::Expr(Expr::Member(member_expr)) => member_expr,
+      ExprOrSuper::Expr(Expr::OptChain(opt_chain)) => match &opt_chain.expr {
+        Expr::Member(member_expr) => member_expr,
+        _ => return,
+      },
+      _ => return,
+    };
+    if !is_for_each(member_expr) {
+      return;
+    }
+
+    if let Some(callback) = call_expr.args.first() {
+      if is_async_function(&callback.expr) {
+        ctx.add_diagnostic_with_hint(
+          callback.expr.span(),
+          CODE,
+          NoAsyncForeachMessage::AsyncCallback,
+          NoAsyncForeachHint::UseForOfOrPromiseAll,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_async_foreach_valid() {
+    assert_lint_ok! {
+      NoAsyncForeach,
+      "arr.forEach((x) => console.log(x));",
+      "arr.forEach(function (x) { console.log(x); });",
+      "arr.forEach(callback);",
+      "arr.map(async (x) => await foo(x));",
+      "await Promise.all(arr.map(async (x) => await foo(x)));",
+      "for (const x of arr) { await foo(x); }",
+      "forEach(async (x) => await foo(x));",
+      "arr[forEach](async (x) => await foo(x));",
+      "arr.forEach((x) => { (async () => await foo(x))(); });",
+    };
+  }
+
+  #[test]
+  fn no_async_foreach_invalid() {
+    assert_lint_err! {
+      NoAsyncForeach,
+      "arr.forEach(async (x) => await foo(x));": [
+        {
+          col: 12,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOfOrPromiseAll,
+        }
+      ],
+      "arr.forEach(async function (x) { await foo(x); });": [
+        {
+          col: 12,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOfOrPromiseAll,
+        }
+      ],
+      "arr.forEach((async (x) => await foo(x)));": [
+        {
+          col: 12,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOfOrPromiseAll,
+        }
+      ],
+      "arr['forEach'](async (x) => await foo(x));": [
+        {
+          col: 15,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOfOrPromiseAll,
+        }
+      ],
+      "foo.bar.forEach(async (x) => await foo(x), thisArg);": [
+        {
+          col: 16,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOfOrPromiseAll,
+        }
+      ],
+      "arr?.forEach(async (x) => await foo(x));": [
+        {
+          col: 13,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOfOrPromiseAll,
+        }
+      ],
+      "new Map().forEach(async (value, key) => await foo(key, value));": [
+        {
+          col: 18,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOfOrPromiseAll,
+        }
+      ],
+    };
+  }
+}
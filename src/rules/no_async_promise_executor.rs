@@ -27,6 +27,10 @@ impl LintRule for NoAsyncPromiseExecutor {
     CODE
   }
 
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["async"]
+  }
+
   fn lint_program<'view>(
     &self,
     _context: &mut Context<'view>,
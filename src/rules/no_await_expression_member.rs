@@ -0,0 +1,206 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::as_member;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, MemberExpr};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoAwaitExpressionMember;
+
+const CODE: &str = "no-await-expression-member";
+
+#[derive(Display)]
+enum NoAwaitExpressionMemberMessage {
+  #[display(
+    fmt = "`await` applies to the member of the call's result, not to the call"
+  )]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum NoAwaitExpressionMemberHint {
+  #[display(
+    fmt = "Use `(await {}){}` to access the member of the resolved value, or wrap the member access in parentheses if it's intended",
+    _0,
+    _1
+  )]
+  Parenthesize(String, String),
+}
+
+impl LintRule for NoAwaitExpressionMember {
+  fn new() -> Arc<Self> {
+    Arc::new(NoAwaitExpressionMember)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["await"]
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoAwaitExpressionMemberHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_await_expression_member.md")
+  }
+}
+
+fn is_call(expr: &Expr) -> bool {
+  match expr {
+    Expr::Call(_) => true,
+    Expr::OptChain(opt_chain) => matches!(&*opt_chain.expr, Expr::Call(_)),
+    _ => false,
+  }
+}
+
+/// Returns the call at the root of a member access chain, e.g. `foo()` for
+/// `foo().bar[0]`.
+fn called_object(member: &MemberExpr) -> Option<&Expr> {
+  match &member.obj {
+    ExprOrSuper::Expr(obj) if is_call(obj) => Some(obj),
+    ExprOrSuper::Expr(obj) => as_member(obj).and_then(called_object),
+    ExprOrSuper::Super(_) => None,
+  }
+}
+
+struct NoAwaitExpressionMemberHandler;
+
+impl Handler for NoAwaitExpressionMemberHandler {
+  fn await_expr(
+    &mut self,
+    await_expr: &ast_view::AwaitExpr,
+    ctx: &mut Context,
+  ) {
+    // A parenthesized member access, e.g. `await (foo().bar)`, is clearly
+    // intended.
+    let member = match as_member(&await_expr.inner.arg) {
+      Some(member) => member,
+      None => return,
+    };
+    let call = match called_object(member) {
+      Some(call) => call,
+      None => return,
+    };
+
+    let arg_span = await_expr.inner.arg.span();
+    let call_span = call.span();
+    let call_text = ctx.file_text_substring(&call_span).to_string();
+    let access = ctx
+      .file_text_substring(&arg_span.with_lo(call_span.hi()))
+      .to_string();
+    ctx.add_diagnostic_with_hint(
+      await_expr.inner.span,
+      CODE,
+      NoAwaitExpressionMemberMessage::Unexpected,
+      NoAwaitExpressionMemberHint::Parenthesize(call_text, access),
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_await_expression_member_valid() {
+    assert_lint_ok! {
+      NoAwaitExpressionMember,
+      "async function f() { await foo(); }",
+      "async function f() { (await foo()).bar; }",
+      "async function f() { (await foo())[0]; }",
+      "async function f() { await foo.bar; }",
+      "async function f() { await promises[0]; }",
+      "async function f() { await this.ready; }",
+      "async function f() { await foo().bar(); }",
+      "async function f() { await foo().then(bar); }",
+      "async function f() { await (foo().bar); }",
+      "async function f() { await new Foo().bar; }",
+      "function f() { foo().bar; }",
+    };
+  }
+
+  #[test]
+  fn no_await_expression_member_invalid() {
+    assert_lint_err! {
+      NoAwaitExpressionMember,
+      "async function f() { const n = await fetch(url).status; }": [
+        {
+          col: 31,
+          message: NoAwaitExpressionMemberMessage::Unexpected,
+          hint: NoAwaitExpressionMemberHint::Parenthesize(
+            "fetch(url)".to_string(),
+            ".status".to_string(),
+          ),
+        }
+      ],
+      "async function f() { const first = await getUsers()[0]; }": [
+        {
+          col: 35,
+          message: NoAwaitExpressionMemberMessage::Unexpected,
+          hint: NoAwaitExpressionMemberHint::Parenthesize(
+            "getUsers()".to_string(),
+            "[0]".to_string(),
+          ),
+        }
+      ],
+      "async function f() { await api.load().data.items; }": [
+        {
+          col: 21,
+          message: NoAwaitExpressionMemberMessage::Unexpected,
+          hint: NoAwaitExpressionMemberHint::Parenthesize(
+            "api.load()".to_string(),
+            ".data.items".to_string(),
+          ),
+        }
+      ],
+      "async function f() { await foo()?.bar; }": [
+        {
+          col: 21,
+          message: NoAwaitExpressionMemberMessage::Unexpected,
+          hint: NoAwaitExpressionMemberHint::Parenthesize(
+            "foo()".to_string(),
+            "?.bar".to_string(),
+          ),
+        }
+      ],
+      "async function f() { await foo?.().bar; }": [
+        {
+          col: 21,
+          message: NoAwaitExpressionMemberMessage::Unexpected,
+          hint: NoAwaitExpressionMemberHint::Parenthesize(
+            "foo?.()".to_string(),
+            ".bar".to_string(),
+          ),
+        }
+      ],
+      "export const { data } = { data: await load().data };": [
+        {
+          col: 32,
+          message: NoAwaitExpressionMemberMessage::Unexpected,
+          hint: NoAwaitExpressionMemberHint::Parenthesize(
+            "load()".to_string(),
+            ".data".to_string(),
+          ),
+        }
+      ],
+    };
+  }
+}
@@ -23,6 +23,10 @@ impl LintRule for NoAwaitInLoop {
     CODE
   }
 
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["await"]
+  }
+
   fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
     unreachable!();
   }
@@ -0,0 +1,277 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BlockStmt, BlockStmtOrExpr, CallExpr, Decl, Expr, ExprOrSuper, Ident,
+  MemberExpr, PropName, Stmt, VarDecl,
+};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::utils::find_ids;
+use deno_ast::swc::utils::Id;
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node;
+use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::VisitWith;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoAwaitInPromiseAllMap;
+
+const CODE: &str = "no-await-in-promise-all-map";
+
+#[derive(Display)]
+enum NoAwaitInPromiseAllMapMessage {
+  #[display(
+    fmt = "Independent `await`s in this callback run one after another"
+  )]
+  Sequential,
+}
+
+#[derive(Display)]
+enum NoAwaitInPromiseAllMapHint {
+  #[display(
+    fmt = "Await them concurrently, e.g. `const [a, b] = await Promise.all([f(x), g(x)]);`"
+  )]
+  AwaitConcurrently,
+}
+
+impl LintRule for NoAwaitInPromiseAllMap {
+  fn new() -> Arc<Self> {
+    Arc::new(NoAwaitInPromiseAllMap)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoAwaitInPromiseAllMapHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_await_in_promise_all_map.md")
+  }
+}
+
+/// Returns the object and the property name of a non-computed member
+/// expression callee, e.g. `Promise` and `all` for `Promise.all(...)`.
+fn callee_member(call_expr: &CallExpr) -> Option<(&Expr, &JsWord)> {
+  let member_expr = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => match unwrap_paren(callee) {
+      Expr::Member(member_expr) if !member_expr.computed => member_expr,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  match (&member_expr.obj, &*member_expr.prop) {
+    (ExprOrSuper::Expr(obj), Expr::Ident(prop)) => Some((obj, &prop.sym)),
+    _ => None,
+  }
+}
+
+/// Returns the callback of `Promise.all(xs.map(callback))` or
+/// `Promise.allSettled(xs.map(callback))`.
+fn promise_all_map_callback(call_expr: &CallExpr) -> Option<&Expr> {
+  match callee_member(call_expr)? {
+    (Expr::Ident(obj), prop)
+      if obj.sym == *"Promise"
+        && (*prop == *"all" || *prop == *"allSettled") => {}
+    _ => return None,
+  }
+  let map_call = match unwrap_paren(&call_expr.args.first()?.expr) {
+    Expr::Call(map_call) => map_call,
+    _ => return None,
+  };
+  match callee_member(map_call)? {
+    (_, prop) if *prop == *"map" => {}
+    _ => return None,
+  }
+  let callback = map_call.args.first()?;
+  if callback.spread.is_some() {
+    return None;
+  }
+  Some(&callback.expr)
+}
+
+/// Returns the body of an async function or arrow function with a block body.
+fn async_body(expr: &Expr) -> Option<&BlockStmt> {
+  match unwrap_paren(expr) {
+    Expr::Arrow(arrow) if arrow.is_async => match &arrow.body {
+      BlockStmtOrExpr::BlockStmt(block) => Some(block),
+      BlockStmtOrExpr::Expr(_) => None,
+    },
+    Expr::Fn(fn_expr) if fn_expr.function.is_async => {
+      fn_expr.function.body.as_ref()
+    }
+    _ => None,
+  }
+}
+
+/// Returns the awaited expression of `const x = await expr;`.
+fn awaited_declaration(var_decl: &VarDecl) -> Option<&Expr> {
+  match var_decl.decls.as_slice() {
+    [declarator] => match declarator.init.as_deref().map(unwrap_paren) {
+      Some(Expr::Await(await_expr)) => Some(&await_expr.arg),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Collects the names of the variables declared by the statement.
+fn declared_names(stmt: &Stmt) -> Vec<JsWord> {
+  match stmt {
+    Stmt::Decl(Decl::Var(var_decl)) => var_decl
+      .decls
+      .iter()
+      .flat_map(|decl| find_ids::<_, Id>(&decl.name))
+      .map(|id| id.0)
+      .collect(),
+    Stmt::Decl(Decl::Fn(fn_decl)) => vec![fn_decl.ident.sym.clone()],
+    Stmt::Decl(Decl::Class(class_decl)) => vec![class_decl.ident.sym.clone()],
+    _ => Vec::new(),
+  }
+}
+
+/// Collects the names of the variables referenced in an expression.
+#[derive(Default)]
+struct ReferenceCollector {
+  names: HashSet<JsWord>,
+}
+
+impl Visit for ReferenceCollector {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident, _: &dyn Node) {
+    self.names.insert(ident.sym.clone());
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    member_expr.obj.visit_with(&DUMMY_NODE, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(&DUMMY_NODE, self);
+    }
+  }
+
+  fn visit_prop_name(&mut self, prop_name: &PropName, _: &dyn Node) {
+    if let PropName::Computed(computed) = prop_name {
+      computed.visit_with(&DUMMY_NODE, self);
+    }
+  }
+}
+
+/// Returns whether the body contains consecutive `const x = await expr;`
+/// declarations where the later ones don't use anything declared before them
+/// in the body, so they could be awaited concurrently.
+fn has_independent_awaits(body: &BlockStmt) -> bool {
+  let mut declared = HashSet::new();
+  // Whether the previous statement was an `await` declaration.
+  let mut after_await = false;
+
+  for stmt in &body.stmts {
+    let awaited = match stmt {
+      Stmt::Decl(Decl::Var(var_decl)) => awaited_declaration(var_decl),
+      _ => None,
+    };
+    match awaited {
+      Some(awaited) => {
+        let mut collector = ReferenceCollector::default();
+        awaited.visit_with(&DUMMY_NODE, &mut collector);
+        if after_await && collector.names.is_disjoint(&declared) {
+          return true;
+        }
+        after_await = true;
+      }
+      None => after_await = false,
+    }
+    declared.extend(declared_names(stmt));
+  }
+  false
+}
+
+struct NoAwaitInPromiseAllMapHandler;
+
+impl Handler for NoAwaitInPromiseAllMapHandler {
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let callback = match promise_all_map_callback(call_expr.inner) {
+      Some(callback) => callback,
+      None => return,
+    };
+    if matches!(async_body(callback), Some(body) if has_independent_awaits(body))
+    {
+      ctx.add_diagnostic_with_hint(
+        callback.span(),
+        CODE,
+        NoAwaitInPromiseAllMapMessage::Sequential,
+        NoAwaitInPromiseAllMapHint::AwaitConcurrently,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_await_in_promise_all_map_valid() {
+    assert_lint_ok! {
+      NoAwaitInPromiseAllMap,
+      "await Promise.all(ids.map(async (id) => { const user = await getUser(id); }));",
+      "await Promise.all(ids.map(async (id) => { const user = await getUser(id); const posts = await getPosts(user); }));",
+      "await Promise.all(ids.map(async (id) => { const { name } = await getUser(id); const posts = await getPosts(name); }));",
+      "await Promise.all(ids.map(async (id) => { const user = await getUser(id); log(user); const posts = await getPosts(id); }));",
+      "await Promise.all(ids.map(async (id) => { await save(id); await log(id); }));",
+      "await Promise.all(ids.map(async (id) => { const [user, posts] = await Promise.all([getUser(id), getPosts(id)]); }));",
+      "await Promise.all(ids.map(async (id) => { const a = await f(id); const b = await g(a.b); }));",
+      "await Promise.all(ids.map(async (id) => ({ user: await getUser(id), posts: await getPosts(id) })));",
+      "ids.map(async (id) => { const user = await getUser(id); const posts = await getPosts(id); });",
+      "await Promise.race(ids.map(async (id) => { const user = await getUser(id); const posts = await getPosts(id); }));",
+      "await Promise.all(ids.map((id) => { const user = getUser(id); const posts = getPosts(id); }));",
+      "await Promise.all(ids.map(callback));",
+    };
+  }
+
+  #[test]
+  fn no_await_in_promise_all_map_invalid() {
+    assert_lint_err! {
+      NoAwaitInPromiseAllMap,
+      "await Promise.all(ids.map(async (id) => { const user = await getUser(id); const posts = await getPosts(id); }));": [
+        {
+          col: 26,
+          message: NoAwaitInPromiseAllMapMessage::Sequential,
+          hint: NoAwaitInPromiseAllMapHint::AwaitConcurrently,
+        }
+      ],
+      "await Promise.allSettled(ids.map(async function (id) { const user = await api.users[id].get(); const posts = await api.posts.get({ user: id }); return { user, posts }; }));": [
+        {
+          col: 33,
+          message: NoAwaitInPromiseAllMapMessage::Sequential,
+          hint: NoAwaitInPromiseAllMapHint::AwaitConcurrently,
+        }
+      ],
+      "await Promise.all(ids.map(async (id) => { const user = await getUser(id); const posts = await getPosts(id, { user: 1 }); }));": [
+        {
+          col: 26,
+          message: NoAwaitInPromiseAllMapMessage::Sequential,
+          hint: NoAwaitInPromiseAllMapHint::AwaitConcurrently,
+        }
+      ],
+    };
+  }
+}
@@ -29,6 +29,10 @@ impl LintRule for NoClassAssign {
     CODE
   }
 
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["class"]
+  }
+
   fn lint_program<'view>(
     &self,
     context: &mut Context<'view>,
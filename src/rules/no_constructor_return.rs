@@ -0,0 +1,141 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoConstructorReturn;
+
+const CODE: &str = "no-constructor-return";
+const MESSAGE: &str = "Constructors must not return a value";
+const HINT: &str =
+  "Remove the returned value, or use `return;` to stop early if needed";
+
+impl LintRule for NoConstructorReturn {
+  fn new() -> Arc<Self> {
+    Arc::new(NoConstructorReturn)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoConstructorReturnHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_constructor_return.md")
+  }
+}
+
+struct NoConstructorReturnHandler;
+
+impl Handler for NoConstructorReturnHandler {
+  fn return_stmt(
+    &mut self,
+    return_stmt: &ast_view::ReturnStmt,
+    ctx: &mut Context,
+  ) {
+    if return_stmt.arg.is_none() {
+      return;
+    }
+    // The return belongs to the innermost function, which may be nested in
+    // the constructor.
+    let function = return_stmt.as_node().ancestors().find(|ancestor| {
+      matches!(
+        ancestor,
+        Node::Function(_) | Node::ArrowExpr(_) | Node::Constructor(_)
+      )
+    });
+    if let Some(Node::Constructor(_)) = function {
+      ctx.add_diagnostic_with_hint(return_stmt.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-constructor-return.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_constructor_return_valid() {
+    assert_lint_ok! {
+      NoConstructorReturn,
+      "function fn() { return }",
+      "function fn(kumiko) { if (kumiko) { return kumiko } }",
+      "const fn = function () { return }",
+      "const fn = () => { return 1 }",
+      "class C { }",
+      "class C { constructor() {} }",
+      "class C { constructor() { let v } }",
+      "class C { method() { return '' } }",
+      "class C { get value() { return '' } }",
+      "class C { constructor(a) { if (!a) { return } else { a() } } }",
+      "class C { constructor() { return } }",
+      "class C { constructor() { function fn() { return true } } }",
+      "class C { constructor() { this.fn = function () { return true } } }",
+      "class C { constructor() { this.fn = () => { return true } } }",
+      "class C { constructor() { class D { method() { return 1 } } } }",
+      "const obj = { constructor() { return 1 } }",
+    };
+  }
+
+  #[test]
+  fn no_constructor_return_invalid() {
+    assert_lint_err! {
+      NoConstructorReturn,
+      "class C { constructor() { return '' } }": [
+        {
+          col: 26,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "class C { constructor(a) { if (!a) { return '' } else { a() } } }": [
+        {
+          col: 37,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "class C { constructor() { return this } }": [
+        {
+          col: 26,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "class C { constructor() { try { return {} } finally {} } }": [
+        {
+          col: 32,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "class C { constructor() { class D { constructor() { return 1 } } } }": [
+        {
+          col: 52,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,231 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoContinueInFinally;
+
+const CODE: &str = "no-continue-in-finally";
+
+#[derive(Display)]
+enum NoContinueInFinallyMessage {
+  #[display(fmt = "`{}` jumps out of a `finally` block", _0)]
+  JumpOut(&'static str),
+}
+
+#[derive(Display)]
+enum NoContinueInFinallyHint {
+  #[display(
+    fmt = "Move the `{}` statement out of the `finally` block, as it discards any exception or return of the `try` block",
+    _0
+  )]
+  MoveOut(&'static str),
+}
+
+impl LintRule for NoContinueInFinally {
+  fn new() -> Arc<Self> {
+    Arc::new(NoContinueInFinally)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["finally"]
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoContinueInFinallyHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_continue_in_finally.md")
+  }
+}
+
+fn is_loop(node: Node) -> bool {
+  matches!(
+    node,
+    Node::ForStmt(_)
+      | Node::ForInStmt(_)
+      | Node::ForOfStmt(_)
+      | Node::WhileStmt(_)
+      | Node::DoWhileStmt(_)
+  )
+}
+
+/// Checks if the statement at `span` jumps to a statement outside of the
+/// `finally` block it's in. Without a `label`, it targets the innermost loop,
+/// or `switch` statement if `is_break`.
+fn jumps_out_of_finally(
+  stmt: Node,
+  span: Span,
+  label: Option<&ast_view::Ident>,
+  is_break: bool,
+) -> bool {
+  for ancestor in stmt.ancestors() {
+    let is_target = match (ancestor, label) {
+      (Node::LabeledStmt(labeled), Some(label)) => {
+        labeled.label.sym() == label.sym()
+      }
+      (Node::SwitchStmt(_), None) => is_break,
+      (node, None) => is_loop(node),
+      _ => false,
+    };
+    if is_target || matches!(ancestor, Node::Function(_) | Node::ArrowExpr(_)) {
+      return false;
+    }
+    if let Node::TryStmt(try_stmt) = ancestor {
+      if let Some(finalizer) = &try_stmt.finalizer {
+        if finalizer.span().contains(span) {
+          return true;
+        }
+      }
+    }
+  }
+  false
+}
+
+struct NoContinueInFinallyHandler;
+
+impl NoContinueInFinallyHandler {
+  fn check(
+    &self,
+    ctx: &mut Context,
+    stmt: Node,
+    label: Option<&ast_view::Ident>,
+    keyword: &'static str,
+  ) {
+    let span = stmt.span();
+    if jumps_out_of_finally(stmt, span, label, keyword == "break") {
+      ctx.add_diagnostic_with_hint(
+        span,
+        CODE,
+        NoContinueInFinallyMessage::JumpOut(keyword),
+        NoContinueInFinallyHint::MoveOut(keyword),
+      );
+    }
+  }
+}
+
+impl Handler for NoContinueInFinallyHandler {
+  fn break_stmt(
+    &mut self,
+    break_stmt: &ast_view::BreakStmt,
+    ctx: &mut Context,
+  ) {
+    self.check(ctx, break_stmt.as_node(), break_stmt.label, "break");
+  }
+
+  fn continue_stmt(
+    &mut self,
+    continue_stmt: &ast_view::ContinueStmt,
+    ctx: &mut Context,
+  ) {
+    self.check(
+      ctx,
+      continue_stmt.as_node(),
+      continue_stmt.label,
+      "continue",
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_continue_in_finally_valid() {
+    assert_lint_ok! {
+      NoContinueInFinally,
+      "for (const a of b) { try { continue; } finally {} }",
+      "for (const a of b) { try {} catch { break; } finally {} }",
+      "for (const a of b) { try {} finally { for (const c of d) { continue; } } }",
+      "while (a) { try {} finally { while (b) { break; } } }",
+      "while (a) { try {} finally { switch (b) { case 0: break; } } }",
+      "while (a) { try {} finally { label: { break label; } } }",
+      "while (a) { try {} finally { inner: while (b) { continue inner; } } }",
+      "while (a) { try {} finally { const f = () => { while (b) { break; } }; } }",
+      "while (a) { try {} finally { function f() { while (b) { continue; } } } }",
+      "while (a) { try {} finally { return; } }",
+      "while (a) { try {} finally { throw new Error(); } }",
+    };
+  }
+
+  #[test]
+  fn no_continue_in_finally_invalid() {
+    assert_lint_err! {
+      NoContinueInFinally,
+      "for (const a of b) { try {} finally { continue; } }": [
+        {
+          col: 38,
+          message: NoContinueInFinallyMessage::JumpOut("continue"),
+          hint: NoContinueInFinallyHint::MoveOut("continue"),
+        }
+      ],
+      "while (a) { try {} finally { break; } }": [
+        {
+          col: 29,
+          message: NoContinueInFinallyMessage::JumpOut("break"),
+          hint: NoContinueInFinallyHint::MoveOut("break"),
+        }
+      ],
+      "switch (a) { case 0: try {} finally { break; } }": [
+        {
+          col: 38,
+          message: NoContinueInFinallyMessage::JumpOut("break"),
+          hint: NoContinueInFinallyHint::MoveOut("break"),
+        }
+      ],
+      "outer: while (a) { try {} finally { while (b) { continue outer; } } }": [
+        {
+          col: 48,
+          message: NoContinueInFinallyMessage::JumpOut("continue"),
+          hint: NoContinueInFinallyHint::MoveOut("continue"),
+        }
+      ],
+      "label: try {} finally { break label; }": [
+        {
+          col: 24,
+          message: NoContinueInFinallyMessage::JumpOut("break"),
+          hint: NoContinueInFinallyHint::MoveOut("break"),
+        }
+      ],
+      "while (a) { try {} finally { if (b) { break; } else { continue; } } }": [
+        {
+          col: 38,
+          message: NoContinueInFinallyMessage::JumpOut("break"),
+          hint: NoContinueInFinallyHint::MoveOut("break"),
+        },
+        {
+          col: 54,
+          message: NoContinueInFinallyMessage::JumpOut("continue"),
+          hint: NoContinueInFinallyHint::MoveOut("continue"),
+        }
+      ],
+      "while (a) { try {} finally { try {} finally { break; } } }": [
+        {
+          col: 46,
+          message: NoContinueInFinallyMessage::JumpOut("break"),
+          hint: NoContinueInFinallyHint::MoveOut("break"),
+        }
+      ],
+    };
+  }
+}
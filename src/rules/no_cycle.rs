@@ -0,0 +1,334 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::import_graph::{
+  normalize_file_name, resolve, strip_extension, ImportGraph,
+};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::Str;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoCycle {
+  options: NoCycleOptions,
+  graph: Arc<ImportGraph>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoCycleOptions {
+  /// The maximum number of imports followed from the imported module back to
+  /// the current one, e.g. `1` only reports modules importing each other.
+  /// Unlimited if not set.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_depth: Option<usize>,
+}
+
+impl NoCycle {
+  pub fn with_options(options: NoCycleOptions) -> Arc<Self> {
+    Self::with_graph(options, Arc::new(ImportGraph::default()))
+  }
+
+  /// Creates the rule for the files of `graph`. Without a graph, the rule
+  /// doesn't know the imports of other files and never reports anything.
+  pub fn with_graph(
+    options: NoCycleOptions,
+    graph: Arc<ImportGraph>,
+  ) -> Arc<Self> {
+    Arc::new(NoCycle { options, graph })
+  }
+}
+
+const CODE: &str = "no-cycle";
+
+#[derive(Display)]
+enum NoCycleMessage {
+  #[display(fmt = "Dependency cycle detected: {}", _0)]
+  Cycle(String),
+}
+
+const HINT: &str = "Move the code both modules depend on to a separate module, or merge the modules";
+
+impl LintRule for NoCycle {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoCycleOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "maxDepth": {
+          "type": "integer",
+          "minimum": 1,
+          "description": "The maximum number of imports followed from the imported module back to the current one."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoCycleOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoCycleHandler {
+      options: &self.options,
+      graph: &self.graph,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_cycle.md")
+  }
+}
+
+struct NoCycleHandler<'a> {
+  options: &'a NoCycleOptions,
+  graph: &'a ImportGraph,
+}
+
+impl<'a> NoCycleHandler<'a> {
+  fn check_source(&self, ctx: &mut Context, src: &Str) {
+    let file_name = normalize_file_name(ctx.file_name());
+    let path = match resolve(&file_name, &src.value) {
+      Some(path) => path,
+      None => return,
+    };
+    // The graph only knows the files it was built from, and `no-self-import`
+    // takes care of imports of the file itself.
+    let target = match self.graph.imports_of(&file_name).iter().find(|import| {
+      **import == path || strip_extension(import) == Some(path.as_str())
+    }) {
+      Some(target) if *target != file_name => target,
+      _ => return,
+    };
+    let max_depth = self.options.max_depth;
+    if let Some(cycle) = self.graph.find_path(target, &file_name, max_depth) {
+      let cycle = std::iter::once(file_name.as_str())
+        .chain(cycle.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(" -> ");
+      ctx.add_diagnostic_with_hint(
+        src.span,
+        CODE,
+        NoCycleMessage::Cycle(cycle),
+        HINT,
+      );
+    }
+  }
+}
+
+impl<'a> Handler for NoCycleHandler<'a> {
+  fn import_decl(
+    &mut self,
+    import_decl: &ast_view::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    if !import_decl.inner.type_only {
+      self.check_source(ctx, &import_decl.inner.src);
+    }
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    if named_export.inner.type_only {
+      return;
+    }
+    if let Some(src) = &named_export.inner.src {
+      self.check_source(ctx, src);
+    }
+  }
+
+  fn export_all(
+    &mut self,
+    export_all: &ast_view::ExportAll,
+    ctx: &mut Context,
+  ) {
+    self.check_source(ctx, &export_all.inner.src);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::linter::LinterBuilder;
+
+  // Some tests are derived from
+  // https://github.com/import-js/eslint-plugin-import/blob/v2.26.0/tests/src/rules/no-cycle.js
+  // MIT Licensed.
+
+  fn lint_files(
+    files: &[(&str, &str)],
+    options: NoCycleOptions,
+  ) -> Vec<(String, usize, String)> {
+    let graph = Arc::new(ImportGraph::build(files).unwrap());
+    let linter = LinterBuilder::default()
+      .rules(vec![NoCycle::with_graph(options, graph)])
+      .build();
+    files
+      .iter()
+      .flat_map(|(file_name, source_code)| {
+        let (_, diagnostics) = linter
+          .lint(file_name.to_string(), source_code.to_string())
+          .unwrap();
+        diagnostics.into_iter().map(|diagnostic| {
+          (
+            diagnostic.filename,
+            diagnostic.range.start.column_index,
+            diagnostic.message,
+          )
+        })
+      })
+      .collect()
+  }
+
+  #[test]
+  fn no_cycle_valid() {
+    assert_lint_ok! {
+      NoCycle,
+      "import foo from './foo.ts';",
+      "import { bar } from '../bar';",
+    };
+
+    assert!(lint_files(
+      &[
+        (
+          "a.ts",
+          "import { b } from './b.ts'; import { c } from './c.ts';"
+        ),
+        ("b.ts", "import { c } from './c.ts'; export const b = 1;"),
+        ("c.ts", "import { d } from 'd'; export const c = 1;"),
+      ],
+      NoCycleOptions::default(),
+    )
+    .is_empty());
+
+    assert!(lint_files(
+      &[
+        (
+          "a.ts",
+          "import type { B } from './b.ts'; export const a = 1;"
+        ),
+        (
+          "b.ts",
+          "import { a } from './a.ts'; export type B = number;"
+        ),
+      ],
+      NoCycleOptions::default(),
+    )
+    .is_empty());
+
+    assert!(lint_files(
+      &[
+        ("a.ts", "export const a = () => import('./b.ts');"),
+        ("b.ts", "import { a } from './a.ts';"),
+      ],
+      NoCycleOptions::default(),
+    )
+    .is_empty());
+
+    assert!(lint_files(
+      &[("a.ts", "import { a } from './a.ts'; export const a = 1;")],
+      NoCycleOptions::default(),
+    )
+    .is_empty());
+  }
+
+  #[test]
+  fn no_cycle_invalid() {
+    assert_eq!(
+      lint_files(
+        &[
+          (
+            "file:///src/a.ts",
+            "import { b } from './b';\nexport const a = 1;"
+          ),
+          ("file:///src/b.ts", "export { a } from '../src/a.ts';"),
+        ],
+        NoCycleOptions::default(),
+      ),
+      [
+        (
+          "file:///src/a.ts".to_string(),
+          18,
+          "Dependency cycle detected: /src/a.ts -> /src/b.ts -> /src/a.ts"
+            .to_string()
+        ),
+        (
+          "file:///src/b.ts".to_string(),
+          18,
+          "Dependency cycle detected: /src/b.ts -> /src/a.ts -> /src/b.ts"
+            .to_string()
+        ),
+      ]
+    );
+
+    let files = [
+      ("a.ts", "import './b.ts';"),
+      ("b.ts", "export * from './c.ts';"),
+      ("c.ts", "import './a.ts'; import './d.ts';"),
+      ("d.ts", ""),
+    ];
+    assert_eq!(
+      lint_files(&files, NoCycleOptions::default()),
+      [
+        (
+          "a.ts".to_string(),
+          7,
+          "Dependency cycle detected: a.ts -> b.ts -> c.ts -> a.ts".to_string()
+        ),
+        (
+          "b.ts".to_string(),
+          14,
+          "Dependency cycle detected: b.ts -> c.ts -> a.ts -> b.ts".to_string()
+        ),
+        (
+          "c.ts".to_string(),
+          7,
+          "Dependency cycle detected: c.ts -> a.ts -> b.ts -> c.ts".to_string()
+        ),
+      ]
+    );
+    assert!(
+      lint_files(&files, NoCycleOptions { max_depth: Some(1) }).is_empty()
+    );
+    assert_eq!(
+      lint_files(&files, NoCycleOptions { max_depth: Some(2) }).len(),
+      3
+    );
+  }
+}
@@ -1,8 +1,10 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
 use crate::handler::{Handler, Traverse};
 use crate::{Program, ProgramRef};
-use deno_ast::view::{DebuggerStmt, Spanned};
+use deno_ast::swc::common::{BytePos, Span};
+use deno_ast::view::{DebuggerStmt, Node, Spanned};
 use derive_more::Display;
 use std::sync::Arc;
 
@@ -23,6 +25,8 @@ enum NoDebuggerHint {
   Remove,
 }
 
+const FIX_DESC: &str = "Remove the `debugger` statement";
+
 impl LintRule for NoDebugger {
   fn new() -> Arc<Self> {
     Arc::new(NoDebugger)
@@ -36,6 +40,10 @@ impl LintRule for NoDebugger {
     CODE
   }
 
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["debugger"]
+  }
+
   fn lint_program(&self, _context: &mut Context, _program: ProgramRef) {
     unreachable!();
   }
@@ -56,13 +64,65 @@ impl LintRule for NoDebugger {
 
 struct NoDebuggerHandler;
 
+/// Returns the span to remove for the statement, which includes its whole line
+/// if nothing else is written on it.
+fn removal_span(ctx: &Context, span: Span) -> Span {
+  let file_start = ctx.source_file().span().lo();
+  let text = ctx.source_file().text();
+  let lo = (span.lo() - file_start).0 as usize;
+  let hi = (span.hi() - file_start).0 as usize;
+
+  let line_start = text[..lo].rfind('\n').map_or(0, |index| index + 1);
+  let line_end = text[hi..].find('\n').map_or(text.len(), |index| hi + index);
+  if !text[line_start..lo].trim().is_empty()
+    || !text[hi..line_end].trim().is_empty()
+  {
+    return span;
+  }
+  let line_end = if line_end < text.len() {
+    line_end + 1
+  } else {
+    line_end
+  };
+  Span::new(
+    file_start + BytePos(line_start as u32),
+    file_start + BytePos(line_end as u32),
+    Default::default(),
+  )
+}
+
 impl Handler for NoDebuggerHandler {
   fn debugger_stmt(&mut self, debugger_stmt: &DebuggerStmt, ctx: &mut Context) {
-    ctx.add_diagnostic_with_hint(
+    // Removing the only statement of e.g. an `if` would break the syntax.
+    let in_statement_list = matches!(
+      debugger_stmt.parent(),
+      Node::BlockStmt(_)
+        | Node::Module(_)
+        | Node::Script(_)
+        | Node::SwitchCase(_)
+        | Node::TsModuleBlock(_)
+    );
+    if !in_statement_list {
+      ctx.add_diagnostic_with_hint(
+        debugger_stmt.span(),
+        CODE,
+        NoDebuggerMessage::Unexpected,
+        NoDebuggerHint::Remove,
+      );
+      return;
+    }
+
+    let fix_span = removal_span(ctx, debugger_stmt.span());
+    ctx.add_diagnostic_with_fixes(
       debugger_stmt.span(),
       CODE,
       NoDebuggerMessage::Unexpected,
-      NoDebuggerHint::Remove,
+      Some(NoDebuggerHint::Remove.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.to_string(),
+        changes: vec![ctx.fix_change(fix_span, "")],
+        applicability: None,
+      }],
     );
   }
 }
@@ -71,6 +131,15 @@ impl Handler for NoDebuggerHandler {
 mod tests {
   use super::*;
 
+  #[test]
+  fn no_debugger_valid() {
+    assert_lint_ok! {
+      NoDebugger,
+      "function debugger_free() { return 1; }",
+      "const debugger_ = 'debugger';",
+    };
+  }
+
   #[test]
   fn no_debugger_invalid() {
     assert_lint_err! {
@@ -80,6 +149,51 @@ mod tests {
           col: 47,
           message: NoDebuggerMessage::Unexpected,
           hint: NoDebuggerHint::Remove,
+          fix: (
+            FIX_DESC,
+            r#"function asdf(): number { console.log("asdf");  return 1; }"#,
+          ),
+        }
+      ],
+      "function f() {\n  foo();\n  debugger;\n  bar();\n}": [
+        {
+          line: 3,
+          col: 2,
+          message: NoDebuggerMessage::Unexpected,
+          hint: NoDebuggerHint::Remove,
+          fix: (FIX_DESC, "function f() {\n  foo();\n  bar();\n}"),
+        }
+      ],
+      "foo();\ndebugger": [
+        {
+          line: 2,
+          col: 0,
+          message: NoDebuggerMessage::Unexpected,
+          hint: NoDebuggerHint::Remove,
+          fix: (FIX_DESC, "foo();\n"),
+        }
+      ],
+      "switch (a) {\n  case 1:\n    debugger;\n}": [
+        {
+          line: 3,
+          col: 4,
+          message: NoDebuggerMessage::Unexpected,
+          hint: NoDebuggerHint::Remove,
+          fix: (FIX_DESC, "switch (a) {\n  case 1:\n}"),
+        }
+      ],
+      "if (a) debugger;": [
+        {
+          col: 7,
+          message: NoDebuggerMessage::Unexpected,
+          hint: NoDebuggerHint::Remove,
+        }
+      ],
+      "label: debugger;": [
+        {
+          col: 7,
+          message: NoDebuggerMessage::Unexpected,
+          hint: NoDebuggerHint::Remove,
         }
       ]
     };
@@ -37,6 +37,10 @@ impl LintRule for NoDeleteVar {
     CODE
   }
 
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["delete"]
+  }
+
   fn lint_program<'view>(
     &self,
     _context: &mut Context<'view>,
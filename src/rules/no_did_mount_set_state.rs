@@ -0,0 +1,235 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  ArrowExpr, CallExpr, Class, ClassMember, Expr, ExprOrSuper, Function,
+  PropName,
+};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoDidMountSetState;
+
+const CODE: &str = "no-did-mount-set-state";
+
+#[derive(Display)]
+enum NoDidMountSetStateMessage {
+  #[display(fmt = "Do not call `this.setState` in `{}`", _0)]
+  SetState(&'static str),
+}
+
+#[derive(Display)]
+enum NoDidMountSetStateHint {
+  #[display(
+    fmt = "Updating the state here triggers an extra render; compute the state beforehand, e.g. in the constructor"
+  )]
+  ComputeBeforehand,
+}
+
+const LIFECYCLE_METHODS: &[&str] = &["componentDidMount", "componentDidUpdate"];
+
+impl LintRule for NoDidMountSetState {
+  fn new() -> Arc<Self> {
+    Arc::new(NoDidMountSetState)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoDidMountSetStateHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_did_mount_set_state.md")
+  }
+}
+
+/// Checks if the class extends `Component` or `PureComponent`, possibly
+/// through a namespace like `React.Component`.
+fn is_component(class: &Class) -> bool {
+  let super_class = match &class.super_class {
+    Some(super_class) => &**super_class,
+    None => return false,
+  };
+  let name = match super_class {
+    Expr::Ident(ident) => &ident.sym,
+    Expr::Member(member_expr) if !member_expr.computed => {
+      match &*member_expr.prop {
+        Expr::Ident(ident) => &ident.sym,
+        _ => return false,
+      }
+    }
+    _ => return false,
+  };
+  *name == *"Component" || *name == *"PureComponent"
+}
+
+struct NoDidMountSetStateHandler;
+
+impl Handler for NoDidMountSetStateHandler {
+  fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
+    if !is_component(class.inner) {
+      return;
+    }
+
+    for member in &class.inner.body {
+      let method = match member {
+        ClassMember::Method(method) if !method.is_static => method,
+        _ => continue,
+      };
+      let name = match &method.key {
+        PropName::Ident(ident) => ident.sym.as_ref(),
+        PropName::Str(s) => s.value.as_ref(),
+        _ => continue,
+      };
+      let name = match LIFECYCLE_METHODS.iter().find(|m| **m == name) {
+        Some(name) => *name,
+        None => continue,
+      };
+      let body = match &method.function.body {
+        Some(body) => body,
+        None => continue,
+      };
+
+      let mut finder = SetStateFinder { spans: Vec::new() };
+      body.visit_with(&DUMMY_NODE, &mut finder);
+      for span in finder.spans {
+        ctx.add_diagnostic_with_hint(
+          span,
+          CODE,
+          NoDidMountSetStateMessage::SetState(name),
+          NoDidMountSetStateHint::ComputeBeforehand,
+        );
+      }
+    }
+  }
+}
+
+/// Finds `this.setState(...)` calls, except in nested functions where `this`
+/// is either different or the call is deferred, e.g. in a callback.
+struct SetStateFinder {
+  spans: Vec<Span>,
+}
+
+impl Visit for SetStateFinder {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr, _parent: &dyn Node) {}
+
+  fn visit_class(&mut self, _class: &Class, _parent: &dyn Node) {}
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      if let Expr::Member(member_expr) = &**callee {
+        let is_this = matches!(
+          &member_expr.obj,
+          ExprOrSuper::Expr(obj) if matches!(&**obj, Expr::This(_))
+        );
+        let is_set_state = !member_expr.computed
+          && matches!(
+            &*member_expr.prop,
+            Expr::Ident(ident) if ident.sym == *"setState"
+          );
+        if is_this && is_set_state {
+          self.spans.push(call_expr.span);
+        }
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.31.10/tests/lib/rules/no-did-mount-set-state.js
+  // https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.31.10/tests/lib/rules/no-did-update-set-state.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_did_mount_set_state_valid() {
+    assert_lint_ok! {
+      NoDidMountSetState,
+      "class Hello extends React.Component { render() { return null; } }",
+      "class Hello extends React.Component { componentDidMount() {} }",
+      "class Hello extends React.Component { componentDidMount() { someNonMemberFunction(arg); this.someHandler = this.setState; } }",
+      "class Hello extends React.Component { componentDidMount() { someClass.onSomeEvent(function (data) { this.setState({ data: data }); }); } }",
+      "class Hello extends React.Component { componentDidMount() { someClass.onSomeEvent((data) => this.setState({ data: data })); } }",
+      "class Hello extends React.Component { componentDidUpdate() { fetch(url).then(() => { this.setState({ loaded: true }); }); } }",
+      "class Hello extends React.Component { componentWillMount() { this.setState({ data: data }); } }",
+      "class Hello extends React.Component { handleClick() { this.setState({ data: data }); } }",
+      "class Hello extends Foo { componentDidMount() { this.setState({ data: data }); } }",
+      "class Hello { componentDidMount() { this.setState({ data: data }); } }",
+      "class Hello extends React.Component { componentDidMount() { other.setState({ data: data }); } }",
+    };
+  }
+
+  #[test]
+  fn no_did_mount_set_state_invalid() {
+    assert_lint_err! {
+      NoDidMountSetState,
+      "class Hello extends React.Component { componentDidMount() { this.setState({ data: data }); } }": [
+        {
+          col: 60,
+          message: NoDidMountSetStateMessage::SetState("componentDidMount"),
+          hint: NoDidMountSetStateHint::ComputeBeforehand,
+        }
+      ],
+      "class Hello extends Component { componentDidMount() { if (x) { this.setState({ data: data }); } } }": [
+        {
+          col: 63,
+          message: NoDidMountSetStateMessage::SetState("componentDidMount"),
+          hint: NoDidMountSetStateHint::ComputeBeforehand,
+        }
+      ],
+      "class Hello extends React.PureComponent { componentDidUpdate() { this.setState({ data: data }); } }": [
+        {
+          col: 65,
+          message: NoDidMountSetStateMessage::SetState("componentDidUpdate"),
+          hint: NoDidMountSetStateHint::ComputeBeforehand,
+        }
+      ],
+      "class Hello extends React.Component { async componentDidMount() { const data = await fetchData(); this.setState({ data }); } }": [
+        {
+          col: 98,
+          message: NoDidMountSetStateMessage::SetState("componentDidMount"),
+          hint: NoDidMountSetStateHint::ComputeBeforehand,
+        }
+      ],
+      "const Hello = class extends React.Component { componentDidMount() { this.setState({ a: 1 }); this.setState({ b: 2 }); } };": [
+        {
+          col: 68,
+          message: NoDidMountSetStateMessage::SetState("componentDidMount"),
+          hint: NoDidMountSetStateHint::ComputeBeforehand,
+        },
+        {
+          col: 93,
+          message: NoDidMountSetStateMessage::SetState("componentDidMount"),
+          hint: NoDidMountSetStateHint::ComputeBeforehand,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,172 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view::{Expr, ExprOrSuper, Ident, Lit, MemberExpr};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoDocumentCookie;
+
+const CODE: &str = "no-document-cookie";
+const MESSAGE: &str = "Direct use of `document.cookie` is not allowed";
+const HINT: &str =
+  "Use a cookie wrapper library or the `CookieStore` API instead";
+
+impl LintRule for NoDocumentCookie {
+  fn new() -> Arc<Self> {
+    Arc::new(NoDocumentCookie)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoDocumentCookieHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_document_cookie.md")
+  }
+}
+
+/// Objects that refer to the global object.
+const GLOBAL_OBJECTS: &[&str] = &["window", "globalThis", "self"];
+
+fn is_global(ctx: &Context, ident: &Ident, name: &str) -> bool {
+  *ident.inner.sym == *name && ctx.scope().is_global(&ident.inner.to_id())
+}
+
+/// Returns the name of the accessed property, e.g. `cookie` in
+/// `document.cookie` or `document["cookie"]`.
+fn property_name<'a>(member_expr: &'a MemberExpr) -> Option<&'a str> {
+  match (&member_expr.prop, member_expr.computed()) {
+    (Expr::Ident(ident), false) => Some(ident.sym().as_ref()),
+    (Expr::Lit(Lit::Str(str)), true) => Some(str.value().as_ref()),
+    _ => None,
+  }
+}
+
+/// Returns whether the expression is the global `document`, e.g. `document`
+/// or `window.document`.
+fn is_document(ctx: &Context, expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(ident) => is_global(ctx, ident, "document"),
+    Expr::Member(member_expr) => {
+      property_name(member_expr) == Some("document")
+        && matches!(
+          &member_expr.obj,
+          ExprOrSuper::Expr(Expr::Ident(obj))
+            if GLOBAL_OBJECTS.iter().any(|name| is_global(ctx, obj, name))
+        )
+    }
+    Expr::Paren(paren) => is_document(ctx, &paren.expr),
+    _ => false,
+  }
+}
+
+struct NoDocumentCookieHandler;
+
+impl Handler for NoDocumentCookieHandler {
+  fn member_expr(&mut self, member_expr: &MemberExpr, ctx: &mut Context) {
+    if property_name(member_expr) != Some("cookie") {
+      return;
+    }
+    if let ExprOrSuper::Expr(obj) = &member_expr.obj {
+      if is_document(ctx, obj) {
+        ctx.add_diagnostic_with_hint(member_expr.span(), CODE, MESSAGE, HINT);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_document_cookie_valid() {
+    assert_lint_ok! {
+      NoDocumentCookie,
+      "const session = cookies.get('session');",
+      "document.title = 'Home';",
+      "request.cookie;",
+      "doc.cookie = 'a=b';",
+      "document[cookie];",
+      "window.cookie;",
+      "foo.document.cookie;",
+      "function f(document) { return document.cookie; }",
+      "const document = parse(html); document.cookie;",
+      "await cookieStore.set('session', id);",
+    };
+  }
+
+  #[test]
+  fn no_document_cookie_invalid() {
+    assert_lint_err! {
+      NoDocumentCookie,
+      "const cookies = document.cookie;": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "document.cookie = 'session=abc; path=/';": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "document['cookie'] += '; Secure';": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "window.document.cookie.split('; ');": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "globalThis['document'].cookie;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "document.c\\u006fokie = 'session=abc';": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "function read() {\n  return (document).cookie;\n}": [
+        {
+          line: 2,
+          col: 9,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
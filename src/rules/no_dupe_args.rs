@@ -3,9 +3,12 @@ use super::{Context, LintRule, DUMMY_NODE};
 use crate::ProgramRef;
 use deno_ast::swc::ast::ArrowExpr;
 use deno_ast::swc::ast::Function;
+use deno_ast::swc::ast::Ident;
 use deno_ast::swc::ast::Param;
 use deno_ast::swc::ast::Pat;
+use deno_ast::swc::atoms::JsWord;
 use deno_ast::swc::common::Span;
+use deno_ast::swc::utils::find_ids;
 use deno_ast::swc::visit::noop_visit_type;
 use deno_ast::swc::visit::Node;
 use deno_ast::swc::visit::{VisitAll, VisitAllWith};
@@ -86,30 +89,29 @@ impl<'c, 'view> NoDupeArgsVisitor<'c, 'view> {
     }
   }
 
-  fn check_pats<'a, 'b, I>(&'a mut self, span: Span, pats: I)
+  /// Records every parameter that reuses the name of a previous parameter,
+  /// including names bound by destructuring patterns.
+  fn check_pats<'a, 'b, I>(&'a mut self, pats: I)
   where
     I: Iterator<Item = &'b Pat>,
   {
-    let mut seen: HashSet<&str> = HashSet::new();
+    let mut seen: HashSet<JsWord> = HashSet::new();
 
     for pat in pats {
-      match &pat {
-        Pat::Ident(ident) => {
-          if !seen.insert(ident.id.as_ref()) {
-            self.error_spans.insert(span);
-          }
+      for ident in find_ids::<_, Ident>(pat) {
+        if !seen.insert(ident.sym) {
+          self.error_spans.insert(ident.span);
         }
-        _ => continue,
       }
     }
   }
 
-  fn check_params<'a, 'b, I>(&'a mut self, span: Span, params: I)
+  fn check_params<'a, 'b, I>(&'a mut self, params: I)
   where
     I: Iterator<Item = &'b Param>,
   {
     let pats = params.map(|param| &param.pat);
-    self.check_pats(span, pats);
+    self.check_pats(pats);
   }
 }
 
@@ -117,11 +119,11 @@ impl<'ctx, 'view> VisitAll for NoDupeArgsVisitor<'ctx, 'view> {
   noop_visit_type!();
 
   fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
-    self.check_params(function.span, function.params.iter());
+    self.check_params(function.params.iter());
   }
 
   fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
-    self.check_pats(arrow_expr.span, arrow_expr.params.iter());
+    self.check_pats(arrow_expr.params.iter());
   }
 }
 
@@ -147,6 +149,7 @@ mod tests {
       "function foo([[a, b], [c, d]]) {}",
       "const {a, b, c} = obj;",
       "const {a, b, c, a} = obj;",
+      "function a({ a: b }, c = a) {}",
 
       // nested
       r#"
@@ -163,63 +166,104 @@ function foo(a, b) {
       NoDupeArgs,
       "function dupeArgs1(a, b, a) {}": [
         {
-          col: 0,
+          col: 25,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
       ],
       "function a(a, b, b) {}": [
         {
-          col: 0,
+          col: 17,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
       ],
       "function a(a, a, a) {}": [
         {
-          col: 0,
+          col: 14,
+          message: NoDupeArgsMessage::Unexpected,
+          hint: NoDupeArgsHint::RenameOrRemove,
+        },
+        {
+          col: 17,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
       ],
       "function a(a, b, a) {}": [
         {
-          col: 0,
+          col: 17,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
       ],
       "function a(a, b, a, b)": [
         {
-          col: 0,
+          col: 17,
+          message: NoDupeArgsMessage::Unexpected,
+          hint: NoDupeArgsHint::RenameOrRemove,
+        },
+        {
+          col: 20,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
       ],
       "let a = function (a, b, b) {}": [
         {
-          col: 8,
+          col: 24,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
       ],
       "let a = function (a, a, a) {}": [
         {
-          col: 8,
+          col: 21,
+          message: NoDupeArgsMessage::Unexpected,
+          hint: NoDupeArgsHint::RenameOrRemove,
+        },
+        {
+          col: 24,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
       ],
       "let a = function (a, b, a) {}": [
         {
-          col: 8,
+          col: 24,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
       ],
       "let a = function (a, b, a, b) {}": [
         {
-          col: 8,
+          col: 24,
+          message: NoDupeArgsMessage::Unexpected,
+          hint: NoDupeArgsHint::RenameOrRemove,
+        },
+        {
+          col: 27,
+          message: NoDupeArgsMessage::Unexpected,
+          hint: NoDupeArgsHint::RenameOrRemove,
+        }
+      ],
+      "function a({ a, b }, [c, { d: a }]) {}": [
+        {
+          col: 30,
+          message: NoDupeArgsMessage::Unexpected,
+          hint: NoDupeArgsHint::RenameOrRemove,
+        }
+      ],
+      "function a(a, ...a) {}": [
+        {
+          col: 17,
+          message: NoDupeArgsMessage::Unexpected,
+          hint: NoDupeArgsHint::RenameOrRemove,
+        }
+      ],
+      "function a(a = 1, [b = a, a]) {}": [
+        {
+          col: 26,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
@@ -230,21 +274,21 @@ function foo(a, b) {
       // But we *do* check them.
       "const dupeArgs = (a, b, a) => {}": [
         {
-          col: 17,
+          col: 24,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
       ],
       "const obj = { foo(a, b, a) {} };": [
         {
-          col: 14,
+          col: 24,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
       ],
       "class Foo { method(a, b, a) {} }": [
         {
-          col: 12,
+          col: 25,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
@@ -258,7 +302,7 @@ function foo(a, b) {
       "#: [
         {
           line: 3,
-          col: 2,
+          col: 21,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
@@ -270,7 +314,7 @@ const foo = (a, b) => {
       "#: [
         {
           line: 3,
-          col: 14,
+          col: 21,
           message: NoDupeArgsMessage::Unexpected,
           hint: NoDupeArgsHint::RenameOrRemove,
         }
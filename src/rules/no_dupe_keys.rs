@@ -9,6 +9,7 @@ use deno_ast::swc::ast::{
 use deno_ast::swc::common::Span;
 use deno_ast::swc::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
 use derive_more::Display;
+use serde_json::json;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -71,11 +72,13 @@ impl<'c, 'view> NoDupeKeysVisitor<'c, 'view> {
   }
 
   fn report(&mut self, span: Span, key: impl Into<String>) {
-    self.context.add_diagnostic_with_hint(
+    let key = key.into();
+    self.context.add_diagnostic_with_data(
       span,
       CODE,
-      NoDupeKeysMessage::Duplicate(key.into()),
-      NoDupeKeysHint::RemoveOrRename,
+      NoDupeKeysMessage::Duplicate(key.clone()),
+      Some(NoDupeKeysHint::RemoveOrRename.to_string()),
+      json!({ "key": key }),
     );
   }
 
@@ -251,6 +254,7 @@ let x = {
           col: 10,
           message: variant!(NoDupeKeysMessage, Duplicate, "bar"),
           hint: NoDupeKeysHint::RemoveOrRename,
+          data: json!({ "key": "bar" }),
         }
       ],
       r#"var foo = { bar: "baz", bar: "qux", quux: "boom", quux: "bang" };"#: [
@@ -258,11 +262,13 @@ let x = {
           col: 10,
           message: variant!(NoDupeKeysMessage, Duplicate, "bar"),
           hint: NoDupeKeysHint::RemoveOrRename,
+          data: json!({ "key": "bar" }),
         },
         {
           col: 10,
           message: variant!(NoDupeKeysMessage, Duplicate, "quux"),
           hint: NoDupeKeysHint::RemoveOrRename,
+          data: json!({ "key": "quux" }),
         }
       ],
       r#"var foo = { bar: "baz", "bar": "qux" };"#: [
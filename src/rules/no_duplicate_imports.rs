@@ -0,0 +1,661 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{ExportSpecifier, ImportSpecifier};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoDuplicateImports {
+  options: NoDuplicateImportsOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoDuplicateImportsOptions {
+  /// Also report re-exports from a module that is already imported or
+  /// re-exported, e.g. `export { foo } from "./mod.ts";`.
+  pub include_exports: bool,
+}
+
+impl NoDuplicateImports {
+  pub fn with_options(options: NoDuplicateImportsOptions) -> Arc<Self> {
+    Arc::new(NoDuplicateImports { options })
+  }
+}
+
+const CODE: &str = "no-duplicate-imports";
+const FIX_DESC: &str = "Merge into the previous import";
+
+#[derive(Display)]
+enum NoDuplicateImportsMessage {
+  #[display(fmt = "`{}` import is duplicated", _0)]
+  Import(String),
+  #[display(fmt = "`{}` import is duplicated as export", _0)]
+  ImportAs(String),
+  #[display(fmt = "`{}` export is duplicated", _0)]
+  Export(String),
+  #[display(fmt = "`{}` export is duplicated as import", _0)]
+  ExportAs(String),
+}
+
+#[derive(Display)]
+enum NoDuplicateImportsHint {
+  #[display(fmt = "Merge the declarations into a single one")]
+  Merge,
+}
+
+impl LintRule for NoDuplicateImports {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoDuplicateImportsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "includeExports": {
+          "type": "boolean",
+          "description": "Also report duplicated re-exports from a module.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoDuplicateImportsOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoDuplicateImportsHandler {
+      options: &self.options,
+      declarations: Vec::new(),
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_duplicate_imports.md")
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum DeclarationKind {
+  Import,
+  Export,
+}
+
+/// An `import` or an `export ... from` declaration.
+struct Declaration {
+  kind: DeclarationKind,
+  source: JsWord,
+  type_only: bool,
+  /// Whether it has a namespace specifier, e.g. `* as ns`, or is `export *`.
+  namespace: bool,
+  /// Whether it has named specifiers, e.g. `{ foo }`.
+  named: bool,
+  /// The parts of an `import` declaration, if it can be rewritten by a fix.
+  import: Option<ImportParts>,
+}
+
+/// The parts of an `import` declaration needed to merge it with another one.
+struct ImportParts {
+  span: Span,
+  /// The source as written, including the quotes.
+  source: String,
+  default: Option<String>,
+  namespace: Option<String>,
+  /// The named specifiers as written, e.g. `foo as bar`.
+  named: Vec<String>,
+  semicolon: bool,
+}
+
+impl ImportParts {
+  /// Returns the text of a single declaration importing everything that
+  /// `self` and `other` import, or `None` if it can't be written as one.
+  fn merge(&self, other: &ImportParts, type_only: bool) -> Option<String> {
+    fn merge_binding(
+      a: &Option<String>,
+      b: &Option<String>,
+    ) -> Result<Option<String>, ()> {
+      match (a, b) {
+        (Some(a), Some(b)) if a != b => Err(()),
+        (a, b) => Ok(a.clone().or_else(|| b.clone())),
+      }
+    }
+
+    let default = merge_binding(&self.default, &other.default).ok()?;
+    let namespace = merge_binding(&self.namespace, &other.namespace).ok()?;
+    let mut named = self.named.clone();
+    for specifier in &other.named {
+      if !named.contains(specifier) {
+        named.push(specifier.clone());
+      }
+    }
+    if namespace.is_some() && !named.is_empty() {
+      return None;
+    }
+    // A type-only import can't have both a default and other bindings.
+    if type_only
+      && default.is_some()
+      && (namespace.is_some() || !named.is_empty())
+    {
+      return None;
+    }
+
+    let mut bindings = Vec::new();
+    bindings.extend(default);
+    bindings.extend(namespace.map(|ns| format!("* as {}", ns)));
+    if !named.is_empty() {
+      bindings.push(format!("{{ {} }}", named.join(", ")));
+    }
+    let clause = if bindings.is_empty() {
+      String::new()
+    } else {
+      format!(
+        "{}{} from ",
+        if type_only { "type " } else { "" },
+        bindings.join(", ")
+      )
+    };
+    Some(format!(
+      "import {}{}{}",
+      clause,
+      self.source,
+      if self.semicolon { ";" } else { "" }
+    ))
+  }
+}
+
+/// Checks if a comment is inside the declaration, on the same line, or right
+/// above it. Removing the declaration would separate it from such comments.
+fn has_attached_comments(ctx: &Context, span: Span) -> bool {
+  let between = |lo: BytePos, hi: BytePos| {
+    ctx
+      .file_text_substring(&Span::new(lo, hi, Default::default()))
+      .matches('\n')
+      .count()
+  };
+  ctx.comments_within(span).next().is_some()
+    || ctx
+      .leading_comments(span)
+      .any(|comment| between(comment.span.hi, span.lo) <= 1)
+    || ctx
+      .trailing_comments(span)
+      .any(|comment| between(span.hi, comment.span.lo) == 0)
+}
+
+/// Returns the span to remove to delete the declaration, including the
+/// whitespace before it on the same line, or its whole line if nothing else
+/// is on it.
+pub(crate) fn removal_span(ctx: &Context, span: Span) -> Span {
+  let file_start = ctx.source_file().span().lo();
+  let text = ctx.source_file().text();
+  let bytes = text.as_bytes();
+  let is_blank = |b: u8| b == b' ' || b == b'\t';
+  let mut lo = (span.lo() - file_start).0 as usize;
+  let mut hi = (span.hi() - file_start).0 as usize;
+  while lo > 0 && is_blank(bytes[lo - 1]) {
+    lo -= 1;
+  }
+  if lo == 0 || bytes[lo - 1] == b'\n' {
+    let mut end = hi;
+    while end < bytes.len() && is_blank(bytes[end]) {
+      end += 1;
+    }
+    if text[end..].starts_with("\r\n") {
+      hi = end + 2;
+    } else if text[end..].starts_with('\n') || end == bytes.len() {
+      hi = (end + 1).min(bytes.len());
+    } else {
+      lo = (span.lo() - file_start).0 as usize;
+      hi = end;
+    }
+  }
+  Span::new(
+    file_start + BytePos(lo as u32),
+    file_start + BytePos(hi as u32),
+    Default::default(),
+  )
+}
+
+impl Declaration {
+  /// Whether two declarations could be written as a single one. Namespace and
+  /// named specifiers can't be combined, and neither can type-only and value
+  /// declarations.
+  fn can_merge(&self, other: &Declaration) -> bool {
+    self.type_only == other.type_only
+      && !(self.namespace && other.named)
+      && !(self.named && other.namespace)
+  }
+}
+
+struct NoDuplicateImportsHandler<'a> {
+  options: &'a NoDuplicateImportsOptions,
+  declarations: Vec<Declaration>,
+}
+
+impl<'a> NoDuplicateImportsHandler<'a> {
+  /// Returns a fix merging the import into the first previous import of the
+  /// same module it can be merged with.
+  fn merge_fix(&self, ctx: &Context, decl: &Declaration) -> Option<LintFix> {
+    let import = decl.import.as_ref()?;
+    if has_attached_comments(ctx, import.span) {
+      return None;
+    }
+    self.declarations.iter().find_map(|prev| {
+      let prev_import = prev.import.as_ref()?;
+      if prev.source != decl.source || !prev.can_merge(decl) {
+        return None;
+      }
+      let merged = prev_import.merge(import, decl.type_only)?;
+      Some(LintFix {
+        description: FIX_DESC.to_string(),
+        changes: vec![
+          ctx.fix_change(prev_import.span, merged),
+          ctx.fix_change(removal_span(ctx, import.span), ""),
+        ],
+        applicability: None,
+      })
+    })
+  }
+
+  fn check(&mut self, ctx: &mut Context, span: Span, decl: Declaration) {
+    use DeclarationKind::*;
+
+    let duplicated = |kind| {
+      self.declarations.iter().any(|prev| {
+        prev.kind == kind && prev.source == decl.source && prev.can_merge(&decl)
+      })
+    };
+    let source = decl.source.to_string();
+    let mut messages = Vec::new();
+    let mut merge_fix = None;
+    match decl.kind {
+      Import => {
+        if duplicated(Import) {
+          messages.push(NoDuplicateImportsMessage::Import(source.clone()));
+          merge_fix = self.merge_fix(ctx, &decl);
+        }
+        if self.options.include_exports && duplicated(Export) {
+          messages.push(NoDuplicateImportsMessage::ImportAs(source));
+        }
+      }
+      Export if self.options.include_exports => {
+        if duplicated(Export) {
+          messages.push(NoDuplicateImportsMessage::Export(source.clone()));
+        }
+        if duplicated(Import) {
+          messages.push(NoDuplicateImportsMessage::ExportAs(source));
+        }
+      }
+      Export => {}
+    }
+
+    for message in messages {
+      // The fix only applies to the duplicated import, which is always
+      // reported first.
+      match merge_fix.take() {
+        Some(fix) => ctx.add_diagnostic_with_fixes(
+          span,
+          CODE,
+          message,
+          Some(NoDuplicateImportsHint::Merge.to_string()),
+          vec![fix],
+        ),
+        None => ctx.add_diagnostic_with_hint(
+          span,
+          CODE,
+          message,
+          NoDuplicateImportsHint::Merge,
+        ),
+      }
+    }
+
+    self.declarations.push(decl);
+  }
+}
+
+impl<'a> Handler for NoDuplicateImportsHandler<'a> {
+  fn import_decl(
+    &mut self,
+    import_decl: &ast_view::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    let specifiers = &import_decl.inner.specifiers;
+    let span = import_decl.span();
+    let mut default = None;
+    let mut namespace = None;
+    let mut named = Vec::new();
+    for specifier in specifiers {
+      match specifier {
+        ImportSpecifier::Default(default_specifier) => {
+          default = Some(default_specifier.local.sym.to_string());
+        }
+        ImportSpecifier::Namespace(namespace_specifier) => {
+          namespace = Some(namespace_specifier.local.sym.to_string());
+        }
+        ImportSpecifier::Named(named_specifier) => {
+          named
+            .push(ctx.file_text_substring(&named_specifier.span).to_string());
+        }
+      }
+    }
+    // Import assertions and comments within the declaration would be lost
+    // when rewriting it.
+    let import = if import_decl.inner.asserts.is_none()
+      && ctx.comments_within(span).next().is_none()
+    {
+      Some(ImportParts {
+        span,
+        source: ctx
+          .file_text_substring(&import_decl.inner.src.span)
+          .to_string(),
+        default,
+        namespace,
+        named,
+        semicolon: ctx.file_text_substring(&span).ends_with(';'),
+      })
+    } else {
+      None
+    };
+    let decl = Declaration {
+      kind: DeclarationKind::Import,
+      source: import_decl.inner.src.value.clone(),
+      type_only: import_decl.inner.type_only,
+      namespace: specifiers
+        .iter()
+        .any(|s| matches!(s, ImportSpecifier::Namespace(_))),
+      named: specifiers
+        .iter()
+        .any(|s| matches!(s, ImportSpecifier::Named(_))),
+      import,
+    };
+    self.check(ctx, span, decl);
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    let source = match &named_export.inner.src {
+      Some(src) => src.value.clone(),
+      None => return,
+    };
+    let specifiers = &named_export.inner.specifiers;
+    let decl = Declaration {
+      kind: DeclarationKind::Export,
+      source,
+      type_only: named_export.inner.type_only,
+      namespace: specifiers
+        .iter()
+        .any(|s| matches!(s, ExportSpecifier::Namespace(_))),
+      named: specifiers
+        .iter()
+        .any(|s| !matches!(s, ExportSpecifier::Namespace(_))),
+      import: None,
+    };
+    self.check(ctx, named_export.span(), decl);
+  }
+
+  fn export_all(
+    &mut self,
+    export_all: &ast_view::ExportAll,
+    ctx: &mut Context,
+  ) {
+    let decl = Declaration {
+      kind: DeclarationKind::Export,
+      source: export_all.inner.src.value.clone(),
+      type_only: false,
+      namespace: true,
+      named: false,
+      import: None,
+    };
+    self.check(ctx, export_all.span(), decl);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-duplicate-imports.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_duplicate_imports_valid() {
+    assert_lint_ok! {
+      NoDuplicateImports,
+      r#"import os from "os"; import fs from "fs";"#,
+      r#"import { merge } from "lodash-es";"#,
+      r#"import _, { merge } from "lodash-es";"#,
+      r#"import * as Foobar from "async";"#,
+      r#"import "foo";"#,
+      r#"import os from "os"; export { something } from "os";"#,
+      r#"import * as bar from "os"; import { baz } from "os";"#,
+      r#"import foo, * as bar from "os"; import { bar as baz } from "os";"#,
+      r#"import type { Foo } from "./foo.ts"; import { bar } from "./foo.ts";"#,
+      r#"import type Foo from "./foo.ts"; import type { Bar } from "./bar.ts";"#,
+      r#"export { foo } from "./foo.ts"; export { bar } from "./foo.ts";"#,
+    };
+
+    assert_lint_ok! {
+      NoDuplicateImports,
+      options: NoDuplicateImportsOptions { include_exports: true },
+      r#"import os from "os"; export { hello } from "hello";"#,
+      r#"import os from "os"; export * from "hello";"#,
+      r#"import os from "os"; export { hello as hi } from "hello";"#,
+      r#"import os from "os"; export default function() {};"#,
+      r#"import { merge } from "lodash-es"; export { merge as lodashMerge };"#,
+      r#"export { something } from "os"; export * as os from "os";"#,
+      r#"import { something } from "os"; export * as os from "os";"#,
+      r#"import * as os from "os"; export { something } from "os";"#,
+      r#"export * from "os"; export { something } from "os";"#,
+      r#"import type { Foo } from "./foo.ts"; export { bar } from "./foo.ts";"#,
+    };
+  }
+
+  #[test]
+  fn no_duplicate_imports_invalid() {
+    assert_lint_err! {
+      NoDuplicateImports,
+      r#"import "fs"; import "fs";"#: [
+        {
+          col: 13,
+          message: NoDuplicateImportsMessage::Import("fs".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+          fix: (FIX_DESC, r#"import "fs";"#),
+        }
+      ],
+      r#"import { merge } from "lodash-es"; import { find } from "lodash-es";"#: [
+        {
+          col: 35,
+          message: NoDuplicateImportsMessage::Import("lodash-es".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+          fix: (FIX_DESC, r#"import { merge, find } from "lodash-es";"#),
+        }
+      ],
+      r#"import os from "os"; import { something } from "os"; import * as foobar from "os";"#: [
+        {
+          col: 21,
+          message: NoDuplicateImportsMessage::Import("os".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+          fix: (
+            FIX_DESC,
+            r#"import os, { something } from "os"; import * as foobar from "os";"#,
+          ),
+        },
+        {
+          col: 53,
+          message: NoDuplicateImportsMessage::Import("os".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+          fix: (
+            FIX_DESC,
+            r#"import os, * as foobar from "os"; import { something } from "os";"#,
+          ),
+        }
+      ],
+      r#"import * as modns from "lodash-es"; import { merge } from "lodash-es"; import { baz } from "lodash-es";"#: [
+        {
+          col: 71,
+          message: NoDuplicateImportsMessage::Import("lodash-es".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+          fix: (
+            FIX_DESC,
+            r#"import * as modns from "lodash-es"; import { merge, baz } from "lodash-es";"#,
+          ),
+        }
+      ],
+      r#"import type { Foo } from "./foo.ts"; import type { Bar } from "./foo.ts";"#: [
+        {
+          col: 37,
+          message: NoDuplicateImportsMessage::Import("./foo.ts".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+          fix: (FIX_DESC, r#"import type { Foo, Bar } from "./foo.ts";"#),
+        }
+      ],
+      "import a from './a.ts';\nimport { b as c, d } from './a.ts'\nfoo();\n": [
+        {
+          line: 2,
+          col: 0,
+          message: NoDuplicateImportsMessage::Import("./a.ts".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+          fix: (FIX_DESC, "import a, { b as c, d } from './a.ts';\nfoo();\n"),
+        }
+      ],
+      "import { a, b } from './a.ts';\n  import { b, c } from './a.ts'; foo();\n": [
+        {
+          line: 2,
+          col: 2,
+          message: NoDuplicateImportsMessage::Import("./a.ts".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+          fix: (FIX_DESC, "import { a, b, c } from './a.ts';\n  foo();\n"),
+        }
+      ],
+      "import './a.ts';\r\nimport * as a from './a.ts';\r\n": [
+        {
+          line: 2,
+          col: 0,
+          message: NoDuplicateImportsMessage::Import("./a.ts".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+          fix: (FIX_DESC, "import * as a from './a.ts';\r\n"),
+        }
+      ],
+      "import type A from './a.ts';\nimport type A from './a.ts';": [
+        {
+          line: 2,
+          col: 0,
+          message: NoDuplicateImportsMessage::Import("./a.ts".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+          fix: (FIX_DESC, "import type A from './a.ts';\n"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoDuplicateImports,
+      options: NoDuplicateImportsOptions { include_exports: true },
+      r#"export { os } from "os"; export { something } from "os";"#: [
+        {
+          col: 25,
+          message: NoDuplicateImportsMessage::Export("os".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+        }
+      ],
+      r#"import os from "os"; export { os as foobar } from "os"; export { something } from "os";"#: [
+        {
+          col: 21,
+          message: NoDuplicateImportsMessage::ExportAs("os".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+        },
+        {
+          col: 56,
+          message: NoDuplicateImportsMessage::Export("os".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+        },
+        {
+          col: 56,
+          message: NoDuplicateImportsMessage::ExportAs("os".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+        }
+      ],
+      r#"export { something } from "os"; import os from "os";"#: [
+        {
+          col: 32,
+          message: NoDuplicateImportsMessage::ImportAs("os".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+        }
+      ],
+      r#"export * from "os"; export * from "os";"#: [
+        {
+          col: 20,
+          message: NoDuplicateImportsMessage::Export("os".to_string()),
+          hint: NoDuplicateImportsHint::Merge,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_duplicate_imports_without_merge_fix() {
+    let sources = [
+      // Different default bindings
+      "import a from './a.ts';\nimport b from './a.ts';\n",
+      // A type-only import can't have a default and named bindings
+      "import type A from './a.ts';\nimport type { B } from './a.ts';\n",
+      // Import assertions
+      "import a from './a.json' assert { type: 'json' };\nimport { b } from './a.json';\n",
+      // Comments attached to the duplicate
+      "import { a } from './a.ts';\n// b is needed for c\nimport { b } from './a.ts';\n",
+      "import { a } from './a.ts';\nimport { b } from './a.ts'; // see c\n",
+      "import { a } from './a.ts';\nimport { /* b */ b } from './a.ts';\n",
+      // Comments within the declaration that would be rewritten
+      "import { a /* a */ } from './a.ts';\nimport { b } from './a.ts';\n",
+    ];
+    for source in sources {
+      let diagnostics = crate::linter::lint_to_diagnostics(
+        "foo.ts",
+        source,
+        vec![NoDuplicateImports::new()],
+      )
+      .unwrap();
+      assert_eq!(diagnostics.len(), 1, "{}", source);
+      assert!(diagnostics[0].fixes.is_empty(), "{}", source);
+    }
+  }
+}
@@ -114,9 +114,7 @@ trait ContainsComments {
 
 impl ContainsComments for BlockStmt {
   fn contains_comments(&self, context: &Context) -> bool {
-    context
-      .all_comments()
-      .any(|comment| self.span.contains(comment.span))
+    context.comments_within(self.span).next().is_some()
   }
 }
 
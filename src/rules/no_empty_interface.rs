@@ -1,14 +1,33 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
+use crate::diagnostic::LintFix;
 use crate::ProgramRef;
 use deno_ast::swc::ast::TsInterfaceDecl;
+use deno_ast::swc::common::Spanned;
 use deno_ast::swc::visit::Node;
 use deno_ast::swc::visit::Visit;
 use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::Arc;
 
 #[derive(Debug)]
-pub struct NoEmptyInterface;
+pub struct NoEmptyInterface {
+  options: NoEmptyInterfaceOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoEmptyInterfaceOptions {
+  /// Allow an empty interface that extends a single supertype.
+  pub allow_single_extends: bool,
+}
+
+impl NoEmptyInterface {
+  pub fn with_options(options: NoEmptyInterfaceOptions) -> Arc<Self> {
+    Arc::new(NoEmptyInterface { options })
+  }
+}
 
 const CODE: &str = "no-empty-interface";
 
@@ -32,9 +51,11 @@ enum NoEmptyInterfaceHint {
   UseSuperTypeOrAddMember,
 }
 
+const FIX_DESC: &str = "Convert to a type alias";
+
 impl LintRule for NoEmptyInterface {
   fn new() -> Arc<Self> {
-    Arc::new(NoEmptyInterface)
+    Self::with_options(NoEmptyInterfaceOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -45,12 +66,41 @@ impl LintRule for NoEmptyInterface {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowSingleExtends": {
+          "type": "boolean",
+          "description": "Allow an empty interface that extends a single supertype.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoEmptyInterfaceOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
   fn lint_program<'view>(
     &self,
     context: &mut Context<'view>,
     program: ProgramRef<'view>,
   ) {
-    let mut visitor = NoEmptyInterfaceVisitor::new(context);
+    let mut visitor = NoEmptyInterfaceVisitor::new(context, &self.options);
     match program {
       ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
       ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
@@ -65,11 +115,42 @@ impl LintRule for NoEmptyInterface {
 
 struct NoEmptyInterfaceVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  options: &'c NoEmptyInterfaceOptions,
 }
 
 impl<'c, 'view> NoEmptyInterfaceVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'c NoEmptyInterfaceOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  /// Builds a fix that rewrites `interface Foo<T> extends Bar<T> {}` into
+  /// `type Foo<T> = Bar<T>;`.
+  fn type_alias_fix(&self, interface_decl: &TsInterfaceDecl) -> LintFix {
+    let supertype = self
+      .context
+      .file_text_substring(&interface_decl.extends[0].span);
+    let type_params = interface_decl
+      .type_params
+      .as_ref()
+      .map_or("", |p| self.context.file_text_substring(&p.span));
+    let declare = if interface_decl.declare {
+      "declare "
+    } else {
+      ""
+    };
+    let new_text = format!(
+      "{}type {}{} = {};",
+      declare, interface_decl.id.sym, type_params, supertype
+    );
+
+    LintFix {
+      description: FIX_DESC.to_string(),
+      changes: vec![self.context.fix_change(interface_decl.span(), new_text)],
+      applicability: None,
+    }
   }
 }
 
@@ -79,22 +160,28 @@ impl<'c, 'view> Visit for NoEmptyInterfaceVisitor<'c, 'view> {
     interface_decl: &TsInterfaceDecl,
     _parent: &dyn Node,
   ) {
-    if interface_decl.extends.len() <= 1 && interface_decl.body.body.is_empty()
-    {
-      self.context.add_diagnostic_with_hint(
+    if !interface_decl.body.body.is_empty() {
+      return;
+    }
+
+    match interface_decl.extends.len() {
+      0 => self.context.add_diagnostic_with_hint(
         interface_decl.span,
         CODE,
-        if interface_decl.extends.is_empty() {
-          NoEmptyInterfaceMessage::EmptyObject
-        } else {
-          NoEmptyInterfaceMessage::Supertype
-        },
-        if interface_decl.extends.is_empty() {
-          NoEmptyInterfaceHint::RemoveOrAddMember
-        } else {
-          NoEmptyInterfaceHint::UseSuperTypeOrAddMember
-        },
-      );
+        NoEmptyInterfaceMessage::EmptyObject,
+        NoEmptyInterfaceHint::RemoveOrAddMember,
+      ),
+      1 if !self.options.allow_single_extends => {
+        let fix = self.type_alias_fix(interface_decl);
+        self.context.add_diagnostic_with_fixes(
+          interface_decl.span,
+          CODE,
+          NoEmptyInterfaceMessage::Supertype,
+          Some(NoEmptyInterfaceHint::UseSuperTypeOrAddMember.to_string()),
+          vec![fix],
+        );
+      }
+      _ => {}
     }
   }
 }
@@ -153,6 +240,7 @@ interface Bar extends Foo {}
           col: 0,
           message: NoEmptyInterfaceMessage::Supertype,
           hint: NoEmptyInterfaceHint::UseSuperTypeOrAddMember,
+          fix: (FIX_DESC, "type Foo = Array<number>;"),
         }
       ],
       "interface Foo extends Array<number | {}> {}": [
@@ -192,6 +280,7 @@ interface Foo extends R {}
           col: 0,
           message: NoEmptyInterfaceMessage::Supertype,
           hint: NoEmptyInterfaceHint::UseSuperTypeOrAddMember,
+          fix: (FIX_DESC, "type Foo<T> = Bar<T>;"),
         }
       ],
       r#"
@@ -205,8 +294,48 @@ declare module FooBar {
           col: 9,
           message: NoEmptyInterfaceMessage::Supertype,
           hint: NoEmptyInterfaceHint::UseSuperTypeOrAddMember,
+          fix: (FIX_DESC, r#"
+declare module FooBar {
+  type Baz = typeof baz;
+  export type Bar = Baz;
+}
+"#),
+        }
+      ],
+      "declare interface Foo extends Bar {}": [
+        {
+          col: 0,
+          message: NoEmptyInterfaceMessage::Supertype,
+          hint: NoEmptyInterfaceHint::UseSuperTypeOrAddMember,
+          fix: (FIX_DESC, "declare type Foo = Bar;"),
         }
       ]
     };
   }
+
+  #[test]
+  fn no_empty_interface_allow_single_extends() {
+    let options = NoEmptyInterfaceOptions {
+      allow_single_extends: true,
+    };
+
+    assert_lint_ok! {
+      NoEmptyInterface,
+      options: options.clone(),
+      "interface Foo extends Bar {}",
+      "interface Foo<T> extends Array<T> {}",
+    };
+
+    assert_lint_err! {
+      NoEmptyInterface,
+      options: options.clone(),
+      "interface Foo {}": [
+        {
+          col: 0,
+          message: NoEmptyInterfaceMessage::EmptyObject,
+          hint: NoEmptyInterfaceHint::RemoveOrAddMember,
+        }
+      ],
+    };
+  }
 }
@@ -0,0 +1,265 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::no_duplicate_imports::removal_span;
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::ImportSpecifier;
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoEmptyNamedBlocks;
+
+const CODE: &str = "no-empty-named-blocks";
+const MESSAGE: &str = "Unexpected empty named import block";
+
+#[derive(Display)]
+enum NoEmptyNamedBlocksHint {
+  #[display(fmt = "Use a side-effect import, or remove the import")]
+  SideEffectOrRemove,
+  #[display(fmt = "Remove the empty braces")]
+  RemoveBraces,
+}
+
+#[derive(Display)]
+enum NoEmptyNamedBlocksFix {
+  #[display(fmt = "Convert to a side-effect import")]
+  SideEffect,
+  #[display(fmt = "Remove the import")]
+  RemoveImport,
+  #[display(fmt = "Remove the empty braces")]
+  RemoveBraces,
+}
+
+impl LintRule for NoEmptyNamedBlocks {
+  fn new() -> Arc<Self> {
+    Arc::new(NoEmptyNamedBlocks)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["import"]
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoEmptyNamedBlocksHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_empty_named_blocks.md")
+  }
+}
+
+/// Returns the span of the empty braces between `import` and the module
+/// specifier, if there are any. Braces within comments are skipped.
+fn empty_braces(ctx: &Context, span: Span, src_span: Span) -> Option<Span> {
+  let head = Span::new(span.lo(), src_span.lo(), Default::default());
+  let mut text = ctx.file_text_substring(&head).as_bytes().to_vec();
+  for comment in ctx.comments_within(head) {
+    let lo = (comment.span.lo() - span.lo()).0 as usize;
+    let hi = (comment.span.hi() - span.lo()).0 as usize;
+    text[lo..hi].iter_mut().for_each(|b| *b = b' ');
+  }
+  let open = text.iter().position(|b| *b == b'{')?;
+  let close = open + 1 + text[open + 1..].iter().position(|b| *b == b'}')?;
+  if !text[open + 1..close].iter().all(u8::is_ascii_whitespace) {
+    return None;
+  }
+  Some(Span::new(
+    span.lo() + BytePos(open as u32),
+    span.lo() + BytePos(close as u32 + 1),
+    Default::default(),
+  ))
+}
+
+struct NoEmptyNamedBlocksHandler;
+
+impl Handler for NoEmptyNamedBlocksHandler {
+  fn import_decl(
+    &mut self,
+    import_decl: &ast_view::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    let inner = import_decl.inner;
+    let default = match inner.specifiers.as_slice() {
+      [] => None,
+      [ImportSpecifier::Default(default)] => Some(default),
+      _ => return,
+    };
+    let span = import_decl.span();
+    let braces = match empty_braces(ctx, span, inner.src.span) {
+      Some(braces) => braces,
+      None => return,
+    };
+
+    let hint = if default.is_some() {
+      NoEmptyNamedBlocksHint::RemoveBraces
+    } else {
+      NoEmptyNamedBlocksHint::SideEffectOrRemove
+    };
+    // The comments within the declaration would be lost.
+    if ctx.comments_within(span).next().is_some() {
+      ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, hint);
+      return;
+    }
+
+    let mut fixes = Vec::new();
+    match default {
+      Some(default) => {
+        let removed =
+          Span::new(default.span.hi(), braces.hi(), Default::default());
+        fixes.push(LintFix {
+          description: NoEmptyNamedBlocksFix::RemoveBraces.to_string(),
+          changes: vec![ctx.fix_change(removed, "")],
+          applicability: None,
+        });
+      }
+      None => {
+        // Type-only imports are erased, so they must not become side-effect
+        // imports, which are kept at runtime.
+        if !inner.type_only {
+          let head =
+            Span::new(span.lo(), inner.src.span.lo(), Default::default());
+          fixes.push(LintFix {
+            description: NoEmptyNamedBlocksFix::SideEffect.to_string(),
+            changes: vec![ctx.fix_change(head, "import ")],
+            applicability: None,
+          });
+        }
+        fixes.push(LintFix {
+          description: NoEmptyNamedBlocksFix::RemoveImport.to_string(),
+          changes: vec![ctx.fix_change(removal_span(ctx, span), "")],
+          applicability: None,
+        });
+      }
+    }
+    ctx.add_diagnostic_with_fixes(
+      span,
+      CODE,
+      MESSAGE,
+      Some(hint.to_string()),
+      fixes,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_empty_named_blocks_valid() {
+    assert_lint_ok! {
+      NoEmptyNamedBlocks,
+      "import 'mod';",
+      "import { mod } from 'mod';",
+      "import Default from 'mod';",
+      "import { Named } from 'mod';",
+      "import Default, { Named } from 'mod';",
+      "import * as Namespace from 'mod';",
+      "import type { Named } from 'mod';",
+      "import type Default from 'mod';",
+      "import /* {} */ 'mod';",
+      "import Default /* {} */ from 'mod';",
+      "import { a as b } from 'mod';",
+    };
+  }
+
+  #[test]
+  fn no_empty_named_blocks_invalid() {
+    assert_lint_err! {
+      NoEmptyNamedBlocks,
+      "import {} from 'mod';": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: NoEmptyNamedBlocksHint::SideEffectOrRemove,
+          fix: (NoEmptyNamedBlocksFix::SideEffect, "import 'mod';"),
+        }
+      ],
+      "import { } from \"mod\"": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: NoEmptyNamedBlocksHint::SideEffectOrRemove,
+          fix: (NoEmptyNamedBlocksFix::SideEffect, "import \"mod\""),
+        }
+      ],
+      "import Default, {} from 'mod';": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: NoEmptyNamedBlocksHint::RemoveBraces,
+          fix: (NoEmptyNamedBlocksFix::RemoveBraces, "import Default from 'mod';"),
+        }
+      ],
+      "import Default , {\n} from 'mod';": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: NoEmptyNamedBlocksHint::RemoveBraces,
+          fix: (NoEmptyNamedBlocksFix::RemoveBraces, "import Default from 'mod';"),
+        }
+      ],
+      "import type {} from 'mod';\nfoo();": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: NoEmptyNamedBlocksHint::SideEffectOrRemove,
+          fix: (NoEmptyNamedBlocksFix::RemoveImport, "foo();"),
+        }
+      ],
+      "import {/* empty */} from 'mod';": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: NoEmptyNamedBlocksHint::SideEffectOrRemove,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_empty_named_blocks_removal_fix() {
+    let diagnostics = crate::linter::lint_to_diagnostics(
+      "foo.ts",
+      "import {} from 'mod';\nfoo();",
+      vec![NoEmptyNamedBlocks::new()],
+    )
+    .unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    let descriptions = diagnostics[0]
+      .fixes
+      .iter()
+      .map(|fix| fix.description.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      descriptions,
+      ["Convert to a side-effect import", "Remove the import"]
+    );
+    assert_eq!(diagnostics[0].fixes[1].changes[0].new_text, "");
+
+    let diagnostics = crate::linter::lint_to_diagnostics(
+      "foo.ts",
+      "import {/* empty */} from 'mod';",
+      vec![NoEmptyNamedBlocks::new()],
+    )
+    .unwrap();
+    assert!(diagnostics[0].fixes.is_empty());
+  }
+}
@@ -0,0 +1,145 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoEmptyStaticBlock;
+
+const CODE: &str = "no-empty-static-block";
+const MESSAGE: &str = "Empty static block";
+const HINT: &str =
+  "Add code to the static block, or remove it if it's not needed";
+
+impl LintRule for NoEmptyStaticBlock {
+  fn new() -> Arc<Self> {
+    Arc::new(NoEmptyStaticBlock)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["static"]
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoEmptyStaticBlockHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_empty_static_block.md")
+  }
+}
+
+struct NoEmptyStaticBlockHandler;
+
+impl Handler for NoEmptyStaticBlockHandler {
+  fn static_block(
+    &mut self,
+    static_block: &ast_view::StaticBlock,
+    ctx: &mut Context,
+  ) {
+    let body = &static_block.inner.body;
+    // A block with only comments is considered intentionally empty.
+    if body.stmts.is_empty() && ctx.comments_within(body.span).next().is_none()
+    {
+      ctx.add_diagnostic_with_hint(static_block.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-empty-static-block.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_empty_static_block_valid() {
+    assert_lint_ok! {
+      NoEmptyStaticBlock,
+      "class Foo { static { bar(); } }",
+      "class Foo { static { /* comments */ } }",
+      "class Foo { static {\n// comment\n} }",
+      "class Foo { static { bar(); } static { bar(); } }",
+      "class Foo { static foo() {} }",
+      "class Foo { static foo = {}; }",
+    };
+
+    assert_lint_ok! {
+      NoEmptyStaticBlock,
+      filename: "foo.js",
+      "class Foo { static { bar(); } }",
+    };
+  }
+
+  #[test]
+  fn no_empty_static_block_invalid() {
+    assert_lint_err! {
+      NoEmptyStaticBlock,
+      "class Foo { static {} }": [
+        {
+          col: 12,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "class Foo { static { } }": [
+        {
+          col: 12,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "class Foo { static {\n\n} }": [
+        {
+          col: 12,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "class Foo { static { bar(); } static {} }": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "class Foo { static // comment\n {} }": [
+        {
+          col: 12,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoEmptyStaticBlock,
+      filename: "foo.js",
+      "class Foo { static {} }": [
+        {
+          col: 12,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
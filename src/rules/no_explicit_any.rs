@@ -1,21 +1,42 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
-use super::{Context, LintRule, DUMMY_NODE};
-use crate::ProgramRef;
-use deno_ast::swc::ast::TsKeywordType;
-use deno_ast::swc::visit::Node;
-use deno_ast::swc::visit::Visit;
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{TsEntityName, TsKeywordTypeKind, TsTypeOperatorOp};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::Arc;
 
 #[derive(Debug)]
-pub struct NoExplicitAny;
+pub struct NoExplicitAny {
+  options: NoExplicitAnyOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoExplicitAnyOptions {
+  /// Propose replacing `any` with `unknown` as a fix.
+  pub fix_to_unknown: bool,
+  /// Allow `any` in the type of rest parameters, e.g. `...args: any[]`.
+  pub ignore_rest_args: bool,
+}
+
+impl NoExplicitAny {
+  pub fn with_options(options: NoExplicitAnyOptions) -> Arc<Self> {
+    Arc::new(NoExplicitAny { options })
+  }
+}
 
 const CODE: &str = "no-explicit-any";
 const MESSAGE: &str = "`any` type is not allowed";
 const HINT: &str = "Use a specific type other than `any`";
+const FIX_DESC: &str = "Replace with `unknown`";
 
 impl LintRule for NoExplicitAny {
   fn new() -> Arc<Self> {
-    Arc::new(NoExplicitAny)
+    Self::with_options(NoExplicitAnyOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -26,16 +47,57 @@ impl LintRule for NoExplicitAny {
     CODE
   }
 
-  fn lint_program<'view>(
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["any"]
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "fixToUnknown": {
+          "type": "boolean",
+          "description": "Propose replacing `any` with `unknown` as a fix.",
+          "default": false
+        },
+        "ignoreRestArgs": {
+          "type": "boolean",
+          "description": "Allow `any` in the type of rest parameters.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoExplicitAnyOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
     &self,
-    context: &mut Context<'view>,
-    program: ProgramRef<'view>,
+    context: &mut Context,
+    program: Program<'_>,
   ) {
-    let mut visitor = NoExplicitAnyVisitor::new(context);
-    match program {
-      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
-      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    NoExplicitAnyHandler {
+      options: &self.options,
     }
+    .traverse(program, context);
   }
 
   #[cfg(feature = "docs")]
@@ -44,31 +106,77 @@ impl LintRule for NoExplicitAny {
   }
 }
 
-struct NoExplicitAnyVisitor<'c, 'view> {
-  context: &'c mut Context<'view>,
+/// Checks if the `any` type is the element type of a rest parameter, e.g.
+/// `...args: any[]` or `...args: ReadonlyArray<any>`.
+fn is_rest_args_element(any: Node) -> bool {
+  let mut node = match any.parent() {
+    Some(Node::TsArrayType(array)) => array.as_node(),
+    Some(Node::TsTypeParamInstantiation(instantiation))
+      if instantiation.params.len() == 1 =>
+    {
+      match instantiation.parent() {
+        Node::TsTypeRef(type_ref)
+          if matches!(
+            &type_ref.inner.type_name,
+            TsEntityName::Ident(ident)
+              if matches!(ident.sym.as_ref(), "Array" | "ReadonlyArray")
+          ) =>
+        {
+          type_ref.as_node()
+        }
+        _ => return false,
+      }
+    }
+    _ => return false,
+  };
+  if let Some(Node::TsTypeOperator(operator)) = node.parent() {
+    if operator.inner.op != TsTypeOperatorOp::ReadOnly {
+      return false;
+    }
+    node = operator.as_node();
+  }
+  match node.parent() {
+    Some(Node::TsTypeAnn(type_ann)) => {
+      matches!(type_ann.parent(), Node::RestPat(_))
+    }
+    _ => false,
+  }
 }
 
-impl<'c, 'view> NoExplicitAnyVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
-  }
+struct NoExplicitAnyHandler<'a> {
+  options: &'a NoExplicitAnyOptions,
 }
 
-impl<'c, 'view> Visit for NoExplicitAnyVisitor<'c, 'view> {
-  fn visit_ts_keyword_type(
+impl<'a> Handler for NoExplicitAnyHandler<'a> {
+  fn ts_keyword_type(
     &mut self,
-    ts_keyword_type: &TsKeywordType,
-    _parent: &dyn Node,
+    ts_keyword_type: &ast_view::TsKeywordType,
+    ctx: &mut Context,
   ) {
-    use deno_ast::swc::ast::TsKeywordTypeKind::*;
+    if ts_keyword_type.inner.kind != TsKeywordTypeKind::TsAnyKeyword {
+      return;
+    }
+    if self.options.ignore_rest_args
+      && is_rest_args_element(ts_keyword_type.as_node())
+    {
+      return;
+    }
 
-    if ts_keyword_type.kind == TsAnyKeyword {
-      self.context.add_diagnostic_with_hint(
-        ts_keyword_type.span,
+    let span = ts_keyword_type.inner.span;
+    if self.options.fix_to_unknown {
+      ctx.add_diagnostic_with_fixes(
+        span,
         CODE,
         MESSAGE,
-        HINT,
+        Some(HINT.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.to_string(),
+          changes: vec![ctx.fix_change(span, "unknown")],
+          applicability: None,
+        }],
       );
+    } else {
+      ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
     }
   }
 }
@@ -99,6 +207,21 @@ type RequireWrapper = (
   __dirname: string
 ) => void;"#,
     };
+
+    assert_lint_ok! {
+      NoExplicitAny,
+      options: NoExplicitAnyOptions {
+        ignore_rest_args: true,
+        ..Default::default()
+      },
+      "function foo(...args: any[]) {}",
+      "function foo(a: number, ...args: Array<any>) {}",
+      "const foo = (...args: readonly any[]) => {};",
+      "const foo = (...args: ReadonlyArray<any>) => {};",
+      "class Foo { bar(...args: any[]) {} }",
+      "type Foo = (...args: any[]) => void;",
+      "interface Foo { bar(...args: any[]): void; }",
+    };
   }
 
   #[test]
@@ -122,6 +245,45 @@ type RequireWrapper = (
   __filename: string,
   __dirname: string
 ) => void;"#: [{ line: 3, col: 11, message: MESSAGE, hint: HINT }, { line: 4, col: 11, message: MESSAGE, hint: HINT }],
-    }
+      "function foo(...args: any[]) {}": [{ col: 22, message: MESSAGE, hint: HINT }],
+    };
+
+    assert_lint_err! {
+      NoExplicitAny,
+      options: NoExplicitAnyOptions {
+        ignore_rest_args: true,
+        ..Default::default()
+      },
+      "function foo(...args: any) {}": [{ col: 22, message: MESSAGE, hint: HINT }],
+      "function foo(args: any[]) {}": [{ col: 19, message: MESSAGE, hint: HINT }],
+      "function foo(...args: any[][]) {}": [{ col: 22, message: MESSAGE, hint: HINT }],
+      "function foo(...args: Array<any | string>) {}": [{ col: 28, message: MESSAGE, hint: HINT }],
+      "function foo(...args: Set<any>) {}": [{ col: 26, message: MESSAGE, hint: HINT }],
+      "function foo(...args: any[]): any {}": [{ col: 30, message: MESSAGE, hint: HINT }],
+    };
+
+    assert_lint_err! {
+      NoExplicitAny,
+      options: NoExplicitAnyOptions {
+        fix_to_unknown: true,
+        ..Default::default()
+      },
+      "const a: any = {};": [
+        {
+          col: 9,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const a: unknown = {};"),
+        }
+      ],
+      "function foo(): Promise<any> {}": [
+        {
+          col: 24,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo(): Promise<unknown> {}"),
+        }
+      ],
+    };
   }
 }
@@ -0,0 +1,307 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  ClassProp, Constructor, Expr, ExprOrSuper, Function, Lit, MemberExpr,
+  PrivateProp, ThisExpr,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node;
+use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::VisitWith;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoExtraBind;
+
+const CODE: &str = "no-extra-bind";
+const FIX_DESC: &str = "Remove the `.bind()` call";
+
+#[derive(Display)]
+enum NoExtraBindMessage {
+  #[display(fmt = "The function binding is unnecessary")]
+  Unnecessary,
+}
+
+#[derive(Display)]
+enum NoExtraBindHint {
+  #[display(fmt = "Remove `.bind()` since the function doesn't use `this`")]
+  RemoveBind,
+  #[display(fmt = "Remove `.bind()` since arrow functions can't be bound")]
+  RemoveBindArrow,
+}
+
+impl LintRule for NoExtraBind {
+  fn new() -> Arc<Self> {
+    Arc::new(NoExtraBind)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Automatic
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoExtraBindHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_extra_bind.md")
+  }
+}
+
+/// Returns whether the member expression accesses a property named `bind`,
+/// e.g. `foo.bind` or `foo["bind"]`.
+fn is_bind(member_expr: &MemberExpr) -> bool {
+  match &*member_expr.prop {
+    Expr::Ident(ident) if !member_expr.computed => ident.sym == *"bind",
+    Expr::Lit(Lit::Str(s)) if member_expr.computed => s.value == *"bind",
+    _ => false,
+  }
+}
+
+/// Returns whether evaluating the expression can't have side effects, so the
+/// expression can be removed without changing the behavior.
+fn is_side_effect_free(expr: &Expr) -> bool {
+  matches!(
+    unwrap_paren(expr),
+    Expr::Ident(_)
+      | Expr::This(_)
+      | Expr::Lit(_)
+      | Expr::Fn(_)
+      | Expr::Arrow(_)
+  )
+}
+
+/// Returns whether `this` is used in the function, excluding nested
+/// functions and class members which have their own `this`.
+fn uses_this(function: &Function) -> bool {
+  let mut finder = ThisFinder { found: false };
+  function.params.visit_with(&DUMMY_NODE, &mut finder);
+  function.body.visit_with(&DUMMY_NODE, &mut finder);
+  finder.found
+}
+
+struct ThisFinder {
+  found: bool,
+}
+
+impl Visit for ThisFinder {
+  noop_visit_type!();
+
+  fn visit_this_expr(&mut self, _: &ThisExpr, _: &dyn Node) {
+    self.found = true;
+  }
+
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+  fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {}
+
+  fn visit_class_prop(&mut self, class_prop: &ClassProp, _: &dyn Node) {
+    // Only computed keys are evaluated with the outer `this`.
+    if class_prop.computed {
+      class_prop.key.visit_with(&DUMMY_NODE, self);
+    }
+  }
+
+  fn visit_private_prop(&mut self, _: &PrivateProp, _: &dyn Node) {}
+}
+
+struct NoExtraBindHandler;
+
+impl Handler for NoExtraBindHandler {
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let call_expr = call_expr.inner;
+    let member_expr = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match unwrap_paren(callee) {
+        Expr::Member(member_expr) => member_expr,
+        Expr::OptChain(opt_chain) => match &*opt_chain.expr {
+          Expr::Member(member_expr) => member_expr,
+          _ => return,
+        },
+        _ => return,
+      },
+      ExprOrSuper::Super(_) => return,
+    };
+    if !is_bind(member_expr)
+      || call_expr.args.len() != 1
+      || call_expr.args[0].spread.is_some()
+    {
+      return;
+    }
+    let function = match &member_expr.obj {
+      ExprOrSuper::Expr(obj) => obj,
+      ExprOrSuper::Super(_) => return,
+    };
+
+    let hint = match unwrap_paren(function) {
+      Expr::Arrow(_) => NoExtraBindHint::RemoveBindArrow,
+      Expr::Fn(fn_expr) if !uses_this(&fn_expr.function) => {
+        NoExtraBindHint::RemoveBind
+      }
+      _ => return,
+    };
+
+    let fixes = if is_side_effect_free(&call_expr.args[0].expr) {
+      let bind_span = Span::new(
+        function.span().hi(),
+        call_expr.span.hi(),
+        Default::default(),
+      );
+      vec![LintFix {
+        description: FIX_DESC.to_string(),
+        changes: vec![ctx.fix_change(bind_span, "")],
+        applicability: None,
+      }]
+    } else {
+      Vec::new()
+    };
+
+    ctx.add_diagnostic_with_fixes(
+      call_expr.span,
+      CODE,
+      NoExtraBindMessage::Unnecessary,
+      Some(hint.to_string()),
+      fixes,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-extra-bind.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_extra_bind_valid() {
+    assert_lint_ok! {
+      NoExtraBind,
+      "var a = function(b) { return b }.bind(c, d)",
+      "var a = function(b) { return b }.bind(...c)",
+      "var a = function() { this.b }.bind(c)",
+      "var a = function() { return () => this.b }.bind(c)",
+      "var a = function(b = this.c) { return b }.bind(d)",
+      "var a = f.bind(a)",
+      "var a = function() { return this.b }.bind(c).bind(d)",
+      "var a = function() {}.bind",
+      "var a = function() {}[bind](c)",
+      "var a = function() { class A { [this.b]() {} } }.bind(c)",
+      "var a = function() { class A { [this.b] = 1 } }.bind(c)",
+    };
+  }
+
+  #[test]
+  fn no_extra_bind_invalid() {
+    assert_lint_err! {
+      NoExtraBind,
+      "var a = function() { return 1; }.bind(b)": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBind,
+          fix: (FIX_DESC, "var a = function() { return 1; }"),
+        }
+      ],
+      "var a = (function() { return 1; }).bind(b)": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBind,
+          fix: (FIX_DESC, "var a = (function() { return 1; })"),
+        }
+      ],
+      "var a = function() { return 1; }['bind'](b)": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBind,
+          fix: (FIX_DESC, "var a = function() { return 1; }"),
+        }
+      ],
+      "var a = (() => { return 1; }).bind(b)": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBindArrow,
+          fix: (FIX_DESC, "var a = (() => { return 1; })"),
+        }
+      ],
+      "var a = (() => this).bind(b)": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBindArrow,
+          fix: (FIX_DESC, "var a = (() => this)"),
+        }
+      ],
+      "var a = function() { function g() { this.c } }.bind(b)": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBind,
+          fix: (FIX_DESC, "var a = function() { function g() { this.c } }"),
+        }
+      ],
+      "var a = function() { class A { foo() { this.c } } }.bind(b)": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBind,
+          fix: (FIX_DESC, "var a = function() { class A { foo() { this.c } } }"),
+        }
+      ],
+      "var a = function() { class A { b = this.c; constructor() { this.d } } }.bind(e)": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBind,
+          fix: (FIX_DESC, "var a = function() { class A { b = this.c; constructor() { this.d } } }"),
+        }
+      ],
+      "var a = function() {}.bind(this)": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBind,
+          fix: (FIX_DESC, "var a = function() {}"),
+        }
+      ],
+      "var a = function() {}?.bind(b)": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBind,
+          fix: (FIX_DESC, "var a = function() {}"),
+        }
+      ],
+      "var a = function() {}.bind(b());": [
+        {
+          col: 8,
+          message: NoExtraBindMessage::Unnecessary,
+          hint: NoExtraBindHint::RemoveBind,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,272 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoExtraLabel;
+
+const CODE: &str = "no-extra-label";
+const HINT: &str = "Remove the label";
+const FIX_DESC: &str = HINT;
+
+#[derive(Display)]
+enum NoExtraLabelMessage {
+  #[display(fmt = "The label `{}` is unnecessary", _0)]
+  Unnecessary(String),
+}
+
+impl LintRule for NoExtraLabel {
+  fn new() -> Arc<Self> {
+    Arc::new(NoExtraLabel)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoExtraLabelHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_extra_label.md")
+  }
+}
+
+fn is_loop(node: Node) -> bool {
+  matches!(
+    node,
+    Node::ForStmt(_)
+      | Node::ForInStmt(_)
+      | Node::ForOfStmt(_)
+      | Node::WhileStmt(_)
+      | Node::DoWhileStmt(_)
+  )
+}
+
+/// Checks if `label` is a label of the innermost statement that an unlabeled
+/// `break` (if `is_break`) or `continue` would target, so the label doesn't
+/// change the meaning.
+fn targets_innermost(stmt: Node, label: &str, is_break: bool) -> bool {
+  let target = stmt.ancestors().find(|ancestor| {
+    matches!(ancestor, Node::Function(_) | Node::ArrowExpr(_))
+      || is_loop(*ancestor)
+      || (is_break && matches!(ancestor, Node::SwitchStmt(_)))
+  });
+  let mut node = match target {
+    Some(target)
+      if !matches!(target, Node::Function(_) | Node::ArrowExpr(_)) =>
+    {
+      target
+    }
+    _ => return false,
+  };
+  while let Some(Node::LabeledStmt(labeled)) = node.parent() {
+    if *labeled.label.sym() == *label {
+      return true;
+    }
+    node = labeled.as_node();
+  }
+  false
+}
+
+struct NoExtraLabelHandler;
+
+impl NoExtraLabelHandler {
+  fn check(
+    &self,
+    ctx: &mut Context,
+    stmt: Node,
+    label: Option<&ast_view::Ident>,
+    keyword: &str,
+  ) {
+    let label = match label {
+      Some(label) => label,
+      None => return,
+    };
+    if !targets_innermost(stmt, label.sym(), keyword == "break") {
+      return;
+    }
+
+    let span = stmt.span();
+    let message = NoExtraLabelMessage::Unnecessary(label.sym().to_string());
+    let removed = Span::new(
+      span.lo() + BytePos(keyword.len() as u32),
+      label.span().hi(),
+      Default::default(),
+    );
+    // Comments between the keyword and the label would be removed as well.
+    if ctx.file_text_substring(&removed).trim() != label.sym().as_ref() {
+      ctx.add_diagnostic_with_hint(span, CODE, message, HINT);
+      return;
+    }
+    ctx.add_diagnostic_with_fixes(
+      span,
+      CODE,
+      message,
+      Some(HINT.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.to_string(),
+        changes: vec![ctx.fix_change(removed, "")],
+        applicability: None,
+      }],
+    );
+  }
+}
+
+impl Handler for NoExtraLabelHandler {
+  fn break_stmt(
+    &mut self,
+    break_stmt: &ast_view::BreakStmt,
+    ctx: &mut Context,
+  ) {
+    self.check(ctx, break_stmt.as_node(), break_stmt.label, "break");
+  }
+
+  fn continue_stmt(
+    &mut self,
+    continue_stmt: &ast_view::ContinueStmt,
+    ctx: &mut Context,
+  ) {
+    self.check(
+      ctx,
+      continue_stmt.as_node(),
+      continue_stmt.label,
+      "continue",
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-extra-label.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_extra_label_valid() {
+    assert_lint_ok! {
+      NoExtraLabel,
+      "A: break A;",
+      "A: { if (a) break A; }",
+      "A: { while (b) { break A; } }",
+      "A: { switch (b) { case 0: break A; } }",
+      "A: while (a) { while (b) { break; } break; }",
+      "A: while (a) { while (b) { break A; } }",
+      "A: while (a) { while (b) { continue A; } }",
+      "A: while (a) { switch (b) { case 0: break A; } }",
+      "A: while (a) { switch (b) { case 0: continue; } }",
+      "A: switch (a) { case 0: while (b) { break A; } }",
+      "A: switch (a) { case 0: B: { while (b) { break B; } } }",
+      "A: for (;;) { () => { B: for (;;) { break; } }; }",
+      "while (a) { break; }",
+      "for (;;) { continue; }",
+    };
+  }
+
+  #[test]
+  fn no_extra_label_invalid() {
+    assert_lint_err! {
+      NoExtraLabel,
+      "A: while (a) break A;": [
+        {
+          col: 13,
+          message: NoExtraLabelMessage::Unnecessary("A".to_string()),
+          hint: HINT,
+          fix: (FIX_DESC, "A: while (a) break;"),
+        }
+      ],
+      "A: while (a) { B: { continue A; } }": [
+        {
+          col: 20,
+          message: NoExtraLabelMessage::Unnecessary("A".to_string()),
+          hint: HINT,
+          fix: (FIX_DESC, "A: while (a) { B: { continue; } }"),
+        }
+      ],
+      "X: while (x) { A: while (a) { B: { break A; break B; continue X; } } }": [
+        {
+          col: 35,
+          message: NoExtraLabelMessage::Unnecessary("A".to_string()),
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "X: while (x) { A: while (a) { B: { break; break B; continue X; } } }",
+          ),
+        }
+      ],
+      "A: do { break A; } while (a);": [
+        {
+          col: 8,
+          message: NoExtraLabelMessage::Unnecessary("A".to_string()),
+          hint: HINT,
+          fix: (FIX_DESC, "A: do { break; } while (a);"),
+        }
+      ],
+      "A: for (let i = 0; i < 10; ++i) { break A; }": [
+        {
+          col: 34,
+          message: NoExtraLabelMessage::Unnecessary("A".to_string()),
+          hint: HINT,
+          fix: (FIX_DESC, "A: for (let i = 0; i < 10; ++i) { break; }"),
+        }
+      ],
+      "A: for (const a in obj) { continue A; }": [
+        {
+          col: 26,
+          message: NoExtraLabelMessage::Unnecessary("A".to_string()),
+          hint: HINT,
+          fix: (FIX_DESC, "A: for (const a in obj) { continue; }"),
+        }
+      ],
+      "A: for (const a of ary) { break A; }": [
+        {
+          col: 26,
+          message: NoExtraLabelMessage::Unnecessary("A".to_string()),
+          hint: HINT,
+          fix: (FIX_DESC, "A: for (const a of ary) { break; }"),
+        }
+      ],
+      "A: switch (a) { case 0: break A; }": [
+        {
+          col: 24,
+          message: NoExtraLabelMessage::Unnecessary("A".to_string()),
+          hint: HINT,
+          fix: (FIX_DESC, "A: switch (a) { case 0: break; }"),
+        }
+      ],
+      "A: B: while (a) { break A; }": [
+        {
+          col: 18,
+          message: NoExtraLabelMessage::Unnecessary("A".to_string()),
+          hint: HINT,
+          fix: (FIX_DESC, "A: B: while (a) { break; }"),
+        }
+      ],
+      "A: while (a) { break /* comment */ A; }": [
+        {
+          col: 15,
+          message: NoExtraLabelMessage::Unnecessary("A".to_string()),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
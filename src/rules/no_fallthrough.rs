@@ -1,16 +1,57 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
 use crate::ProgramRef;
-use deno_ast::swc::common::{comments::Comment, Span, Spanned, DUMMY_SP};
+use deno_ast::swc::common::{Span, Spanned, DUMMY_SP};
 use deno_ast::swc::{
   ast::*,
   visit::{noop_visit_type, Node, Visit, VisitWith},
 };
 use derive_more::Display;
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::Arc;
 
 #[derive(Debug)]
-pub struct NoFallthrough;
+pub struct NoFallthrough {
+  options: NoFallthroughOptions,
+  comment_pattern: Regex,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoFallthroughOptions {
+  /// Regular expression matched against the comment before a case to allow
+  /// falling through to it. Defaults to `falls?\s*through`
+  /// (case-insensitive). Invalid patterns fall back to the default.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub comment_pattern: Option<String>,
+  /// Allow falling through from cases without statements, even if they span
+  /// several lines.
+  pub allow_empty_case: bool,
+}
+
+static DEFAULT_COMMENT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+  RegexBuilder::new(r"falls?\s*through")
+    .case_insensitive(true)
+    .build()
+    .unwrap()
+});
+
+impl NoFallthrough {
+  pub fn with_options(options: NoFallthroughOptions) -> Arc<Self> {
+    let comment_pattern = options
+      .comment_pattern
+      .as_deref()
+      .and_then(|p| Regex::new(p).ok())
+      .unwrap_or_else(|| DEFAULT_COMMENT_PATTERN.clone());
+    Arc::new(NoFallthrough {
+      options,
+      comment_pattern,
+    })
+  }
+}
 
 const CODE: &str = "no-fallthrough";
 
@@ -28,9 +69,11 @@ enum NoFallthroughHint {
   BreakOrComment,
 }
 
+const RELATED_MESSAGE: &str = "Falls through to this case";
+
 impl LintRule for NoFallthrough {
   fn new() -> Arc<Self> {
-    Arc::new(NoFallthrough)
+    Self::with_options(NoFallthroughOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -41,12 +84,48 @@ impl LintRule for NoFallthrough {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "commentPattern": {
+          "type": "string",
+          "description": "Regular expression matching a comment that allows falling through to the next case."
+        },
+        "allowEmptyCase": {
+          "type": "boolean",
+          "description": "Allow falling through from cases without statements."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoFallthroughOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
   fn lint_program<'view>(
     &self,
     context: &mut Context<'view>,
     program: ProgramRef<'view>,
   ) {
-    let mut visitor = NoFallthroughVisitor { context };
+    let mut visitor = NoFallthroughVisitor {
+      context,
+      comment_pattern: &self.comment_pattern,
+      allow_empty_case: self.options.allow_empty_case,
+    };
     match program {
       ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
       ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
@@ -61,6 +140,24 @@ impl LintRule for NoFallthrough {
 
 struct NoFallthroughVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  comment_pattern: &'c Regex,
+  allow_empty_case: bool,
+}
+
+impl<'c, 'view> NoFallthroughVisitor<'c, 'view> {
+  /// Checks if the last comment between `prev_case` and `case` allows falling
+  /// through, e.g. `/* falls through */`.
+  fn allows_fallthrough(&self, prev_case: Span, case: Span) -> bool {
+    let last_comment = self
+      .context
+      .trailing_comments(prev_case)
+      .chain(self.context.leading_comments(case))
+      .max_by_key(|comment| comment.span.lo);
+    matches!(
+      last_comment,
+      Some(comment) if self.comment_pattern.is_match(&comment.text)
+    )
+  }
 }
 
 impl<'c, 'view> Visit for NoFallthroughVisitor<'c, 'view> {
@@ -70,41 +167,32 @@ impl<'c, 'view> Visit for NoFallthroughVisitor<'c, 'view> {
     let mut should_emit_err = false;
     let mut prev_span = DUMMY_SP;
 
-    'cases: for (case_idx, case) in cases.iter().enumerate() {
+    for (case_idx, case) in cases.iter().enumerate() {
       case.visit_with(parent, self);
 
-      if should_emit_err {
-        let comments = self.context.leading_comments_at(case.span.lo);
-        if !allow_fall_through(comments) {
-          self.context.add_diagnostic_with_hint(
-            prev_span,
-            CODE,
-            NoFallthroughMessage::Unexpected,
-            NoFallthroughHint::BreakOrComment,
-          );
-        }
+      if should_emit_err && !self.allows_fallthrough(prev_span, case.span) {
+        // Point at the case receiving the fallthrough; `default` has no test
+        let next_span = case.test.as_ref().map_or(case.span, |t| t.span());
+        let related =
+          self.context.related_information(next_span, RELATED_MESSAGE);
+        self.context.add_diagnostic_with_related(
+          prev_span,
+          CODE,
+          NoFallthroughMessage::Unexpected,
+          Some(NoFallthroughHint::BreakOrComment.to_string()),
+          vec![related],
+        );
       }
       should_emit_err = true;
       let mut stops_exec = false;
 
       // Handle return / throw / break / continue
-      for (idx, stmt) in case.cons.iter().enumerate() {
-        let last = idx + 1 == case.cons.len();
+      for stmt in &case.cons {
         let metadata = self.context.control_flow().meta(stmt.span().lo);
         stops_exec |= metadata.map(|v| v.stops_execution()).unwrap_or(false);
         if stops_exec {
           should_emit_err = false;
         }
-
-        if last {
-          let comments = self.context.trailing_comments_at(stmt.span().hi);
-          if allow_fall_through(comments) {
-            should_emit_err = false;
-            // User comment beats everything
-            prev_span = case.span;
-            continue 'cases;
-          }
-        }
       }
 
       let empty = case.cons.is_empty()
@@ -129,7 +217,7 @@ impl<'c, 'view> Visit for NoFallthroughVisitor<'c, 'view> {
         // This means there are no statements detected so we must detect case
         // bodies made up of only new lines by counting the total amount of new lines.
         // If there's more than 2 new lines and `case.cons` is empty this indicates the case body only contains new lines.
-        should_emit_err = span_line_count > 2;
+        should_emit_err = !self.allow_empty_case && span_line_count > 2;
       }
 
       prev_span = case.span;
@@ -137,17 +225,6 @@ impl<'c, 'view> Visit for NoFallthroughVisitor<'c, 'view> {
   }
 }
 
-fn allow_fall_through<'c>(
-  mut comments: impl Iterator<Item = &'c Comment>,
-) -> bool {
-  comments.any(|comment| {
-    let l = comment.text.to_ascii_lowercase();
-    l.contains("fallthrough")
-      || l.contains("falls through")
-      || l.contains("fall through")
-  })
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -221,6 +298,7 @@ switch(someValue) {
           col: 14,
           message: NoFallthroughMessage::Unexpected,
           hint: NoFallthroughHint::BreakOrComment,
+          related: (2, 5, RELATED_MESSAGE),
         }
       ],
       "switch(foo) { case 0: a();\ndefault: b() }": [
@@ -228,6 +306,7 @@ switch(someValue) {
           col: 14,
           message: NoFallthroughMessage::Unexpected,
           hint: NoFallthroughHint::BreakOrComment,
+          related: (2, 0, RELATED_MESSAGE),
         }
       ],
       "switch(foo) { case 0: a(); default: b() }": [
@@ -235,6 +314,21 @@ switch(someValue) {
           col: 14,
           message: NoFallthroughMessage::Unexpected,
           hint: NoFallthroughHint::BreakOrComment,
+          related: (1, 27, RELATED_MESSAGE),
+        }
+      ],
+      "switch(foo) { case 0: a(); case 1: b(); case 2: c(); }": [
+        {
+          col: 14,
+          message: NoFallthroughMessage::Unexpected,
+          hint: NoFallthroughHint::BreakOrComment,
+          related: (1, 32, RELATED_MESSAGE),
+        },
+        {
+          col: 27,
+          message: NoFallthroughMessage::Unexpected,
+          hint: NoFallthroughHint::BreakOrComment,
+          related: (1, 45, RELATED_MESSAGE),
         }
       ],
       "switch(foo) { case 0: if (a) { break; } default: b() }": [
@@ -289,6 +383,87 @@ switch(someValue) {
     };
   }
 
+  #[test]
+  fn no_fallthrough_options() {
+    assert_lint_ok! {
+      NoFallthrough,
+      options: NoFallthroughOptions {
+        comment_pattern: Some("^\\s*intentional fallthrough".to_string()),
+        ..Default::default()
+      },
+      "switch(foo) { case 0: a(); // intentional fallthrough\n case 1: b(); }",
+      "switch(foo) { case 0: a();\n /* intentional fallthrough */\n case 1: b(); }",
+    };
+
+    assert_lint_ok! {
+      NoFallthrough,
+      options: NoFallthroughOptions {
+        allow_empty_case: true,
+        ..Default::default()
+      },
+      "switch(foo) { case 0:\n\n default: b() }",
+      "switch(foo) { case 0:\n // comment\n default: b() }",
+    };
+
+    assert_lint_ok! {
+      NoFallthrough,
+      options: NoFallthroughOptions {
+        comment_pattern: Some("[".to_string()),
+        ..Default::default()
+      },
+      "switch(foo) { case 0: a(); /* falls through */ case 1: b(); }",
+    };
+
+    assert_lint_err! {
+      NoFallthrough,
+      options: NoFallthroughOptions {
+        comment_pattern: Some("^\\s*intentional fallthrough".to_string()),
+        ..Default::default()
+      },
+      "switch(foo) { case 0: a(); /* falls through */ case 1: b(); }": [
+        {
+          col: 14,
+          message: NoFallthroughMessage::Unexpected,
+          hint: NoFallthroughHint::BreakOrComment,
+        }
+      ],
+      "switch(foo) { case 0: a(); // not an intentional fallthrough\n case 1: b(); }": [
+        {
+          col: 14,
+          message: NoFallthroughMessage::Unexpected,
+          hint: NoFallthroughHint::BreakOrComment,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoFallthrough,
+      options: NoFallthroughOptions {
+        allow_empty_case: true,
+        ..Default::default()
+      },
+      "switch(foo) { case 0: a();\n\n default: b() }": [
+        {
+          col: 14,
+          message: NoFallthroughMessage::Unexpected,
+          hint: NoFallthroughHint::BreakOrComment,
+        }
+      ],
+    };
+
+    // Only the comment right before the next case counts
+    assert_lint_err! {
+      NoFallthrough,
+      "switch(foo) { case 0: a(); /* falls through */ /* TODO */ case 1: b(); }": [
+        {
+          col: 14,
+          message: NoFallthroughMessage::Unexpected,
+          hint: NoFallthroughHint::BreakOrComment,
+        }
+      ],
+    };
+  }
+
   #[test]
   #[ignore = "It ends with break statement"]
   fn no_fallthrough_invalid_2() {
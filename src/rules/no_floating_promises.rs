@@ -0,0 +1,398 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{async_function_names, is_async_function, unwrap_paren};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{CallExpr, Expr, ExprOrSuper, MemberExpr, UnaryOp};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoFloatingPromises {
+  options: NoFloatingPromisesOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoFloatingPromisesOptions {
+  /// Names of functions that are known to return a promise, e.g. `fetch` or
+  /// `Deno.readFile`. A `*` matches any sequence of characters, so
+  /// `Promise.*` matches all the static methods of `Promise`.
+  pub promise_functions: Vec<String>,
+  /// Allow discarding a promise explicitly with the `void` operator.
+  pub ignore_void: bool,
+}
+
+impl Default for NoFloatingPromisesOptions {
+  fn default() -> Self {
+    Self {
+      promise_functions: vec!["fetch".to_string(), "Promise.*".to_string()],
+      ignore_void: true,
+    }
+  }
+}
+
+impl NoFloatingPromises {
+  pub fn with_options(options: NoFloatingPromisesOptions) -> Arc<Self> {
+    Arc::new(NoFloatingPromises { options })
+  }
+}
+
+const CODE: &str = "no-floating-promises";
+
+#[derive(Display)]
+enum NoFloatingPromisesMessage {
+  #[display(fmt = "Promises must be awaited or have their rejections handled")]
+  Floating,
+}
+
+#[derive(Display)]
+enum NoFloatingPromisesHint {
+  #[display(
+    fmt = "Add `await`, return the promise, or handle rejections with `.catch()`"
+  )]
+  HandlePromise,
+}
+
+impl LintRule for NoFloatingPromises {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoFloatingPromisesOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "promiseFunctions": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Names of functions known to return a promise. `*` matches any sequence of characters.",
+          "default": ["fetch", "Promise.*"]
+        },
+        "ignoreVoid": {
+          "type": "boolean",
+          "description": "Allow discarding a promise with the `void` operator.",
+          "default": true
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoFloatingPromisesOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoFloatingPromisesHandler {
+      options: &self.options,
+      async_functions: async_function_names(program),
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_floating_promises.md")
+  }
+}
+
+/// Returns whether `name` matches `pattern`, where `*` in the pattern matches
+/// any sequence of characters.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+  match pattern.find('*') {
+    None => pattern == name,
+    Some(index) => {
+      let (prefix, rest) = (&pattern[..index], &pattern[index + 1..]);
+      name.starts_with(prefix)
+        && (prefix.len()..=name.len())
+          .any(|start| matches_pattern(rest, &name[start..]))
+    }
+  }
+}
+
+/// Returns the dotted path of the callee, e.g. `Deno.readFile` for
+/// `Deno.readFile(path)`, if it only consists of identifiers.
+fn callee_path(expr: &Expr) -> Option<String> {
+  match unwrap_paren(expr) {
+    Expr::Ident(ident) => Some(ident.sym.to_string()),
+    Expr::Member(member_expr) if !member_expr.computed => {
+      let obj = match &member_expr.obj {
+        ExprOrSuper::Expr(obj) => callee_path(obj)?,
+        ExprOrSuper::Super(_) => return None,
+      };
+      match &*member_expr.prop {
+        Expr::Ident(prop) => Some(format!("{}.{}", obj, prop.sym)),
+        _ => None,
+      }
+    }
+    Expr::This(_) => Some("this".to_string()),
+    _ => None,
+  }
+}
+
+/// Returns the member expression and the name of the method being called,
+/// e.g. `then` for `foo.then(bar)`.
+fn method_call(call_expr: &CallExpr) -> Option<(&MemberExpr, &str)> {
+  let member_expr = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => match unwrap_paren(callee) {
+      Expr::Member(member_expr) if !member_expr.computed => member_expr,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  match &*member_expr.prop {
+    Expr::Ident(prop) => Some((member_expr, prop.sym.as_ref())),
+    _ => None,
+  }
+}
+
+struct NoFloatingPromisesHandler<'a> {
+  options: &'a NoFloatingPromisesOptions,
+  async_functions: HashSet<JsWord>,
+}
+
+impl<'a> NoFloatingPromisesHandler<'a> {
+  /// Returns whether the call is known to return a promise whose rejection is
+  /// not handled.
+  fn is_unhandled_promise(&self, call_expr: &CallExpr) -> bool {
+    if let Some((member_expr, method)) = method_call(call_expr) {
+      let handled = match method {
+        "catch" => !call_expr.args.is_empty(),
+        "then" => call_expr.args.len() >= 2,
+        _ => false,
+      };
+      if handled {
+        return false;
+      }
+      if let ("then" | "catch" | "finally", ExprOrSuper::Expr(obj)) =
+        (method, &member_expr.obj)
+      {
+        return self.is_promise(obj);
+      }
+    }
+
+    let callee = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => unwrap_paren(callee),
+      ExprOrSuper::Super(_) => return false,
+    };
+    if let Expr::Ident(ident) = callee {
+      if self.async_functions.contains(&ident.sym) {
+        return true;
+      }
+    }
+    if is_async_function(callee) {
+      return true;
+    }
+    match callee_path(callee) {
+      Some(path) => self
+        .options
+        .promise_functions
+        .iter()
+        .any(|pattern| matches_pattern(pattern, &path)),
+      None => false,
+    }
+  }
+
+  fn is_promise(&self, expr: &Expr) -> bool {
+    match unwrap_paren(expr) {
+      Expr::Call(call_expr) => self.is_unhandled_promise(call_expr),
+      _ => false,
+    }
+  }
+}
+
+impl<'a> Handler for NoFloatingPromisesHandler<'a> {
+  fn expr_stmt(&mut self, expr_stmt: &ast_view::ExprStmt, ctx: &mut Context) {
+    let expr = unwrap_paren(&expr_stmt.inner.expr);
+    let expr = match expr {
+      Expr::Unary(unary) if unary.op == UnaryOp::Void => {
+        if self.options.ignore_void {
+          return;
+        }
+        unwrap_paren(&unary.arg)
+      }
+      _ => expr,
+    };
+
+    if self.is_promise(expr) {
+      ctx.add_diagnostic_with_hint(
+        expr.span(),
+        CODE,
+        NoFloatingPromisesMessage::Floating,
+        NoFloatingPromisesHint::HandlePromise,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_floating_promises_valid() {
+    assert_lint_ok! {
+      NoFloatingPromises,
+      "await fetch('https://deno.land');",
+      "const res = fetch('https://deno.land');",
+      "function foo() { return fetch('https://deno.land'); }",
+      "const foo = () => fetch('https://deno.land');",
+      "fetch('https://deno.land').catch(console.error);",
+      "fetch('https://deno.land').then(console.log, console.error);",
+      "fetch('https://deno.land').then(console.log).catch(console.error);",
+      "fetch('https://deno.land').finally(cleanup).catch(console.error);",
+      "fetch('https://deno.land').catch(console.error).finally(cleanup);",
+      "void fetch('https://deno.land');",
+      "await Promise.all([foo(), bar()]);",
+      "async function foo() {} await foo();",
+      "async function foo() {} foo().catch(console.error);",
+      "const foo = async () => {}; await foo();",
+      "function foo() {} foo();",
+      "const foo = () => {}; foo();",
+      "bar();",
+      "foo.fetch();",
+      "fetch;",
+      "foo().then(console.log);",
+      "(async () => {})().catch(console.error);",
+    };
+
+    assert_lint_ok! {
+      NoFloatingPromises,
+      options: NoFloatingPromisesOptions {
+        promise_functions: vec![],
+        ..Default::default()
+      },
+      "fetch('https://deno.land');",
+      "Promise.resolve();",
+    };
+  }
+
+  #[test]
+  fn no_floating_promises_invalid() {
+    assert_lint_err! {
+      NoFloatingPromises,
+      "fetch('https://deno.land');": [
+        {
+          col: 0,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+      "Promise.reject(new Error('foo'));": [
+        {
+          col: 0,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+      "fetch('https://deno.land').then(console.log);": [
+        {
+          col: 0,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+      "fetch('https://deno.land').finally(cleanup);": [
+        {
+          col: 0,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+      "fetch('https://deno.land').catch();": [
+        {
+          col: 0,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+      "(fetch('https://deno.land'));": [
+        {
+          col: 1,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+      "foo(); async function foo() {}": [
+        {
+          col: 0,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+      "const foo = async function () {}; function bar() { foo(); }": [
+        {
+          col: 51,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+      "(async () => { await bar(); })();": [
+        {
+          col: 0,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoFloatingPromises,
+      options: NoFloatingPromisesOptions {
+        promise_functions: vec!["Deno.read*".to_string(), "*Async".to_string()],
+        ignore_void: false,
+      },
+      "Deno.readFile('foo.txt');": [
+        {
+          col: 0,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+      "fs.readAsync('foo.txt');": [
+        {
+          col: 0,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+      "void Deno.readTextFile('foo.txt');": [
+        {
+          col: 5,
+          message: NoFloatingPromisesMessage::Floating,
+          hint: NoFloatingPromisesHint::HandlePromise,
+        }
+      ],
+    };
+  }
+}
@@ -1,7 +1,8 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
+use crate::globals::GlobalAccess;
+use crate::swc_util::find_lhs_ids;
 use crate::ProgramRef;
-use crate::{globals::GLOBALS, swc_util::find_lhs_ids};
 use deno_ast::swc::common::Span;
 use deno_ast::swc::{
   ast::*,
@@ -79,19 +80,15 @@ impl<'c, 'view> NoGlobalAssignVisitor<'c, 'view> {
       return;
     }
 
-    // We only care about globals.
-    let maybe_global = GLOBALS.iter().find(|(name, _)| name == &&*id.0);
-
-    if let Some(global) = maybe_global {
-      // If global can be overwritten then don't need to report anything
-      if !global.1 {
-        self.context.add_diagnostic_with_hint(
-          span,
-          CODE,
-          NoGlobalAssignMessage::NotAllowed,
-          NoGlobalAssignHint::Remove,
-        );
-      }
+    // We only care about globals. If a global can be overwritten then we
+    // don't need to report anything.
+    if self.context.globals().access(&id.0) == Some(GlobalAccess::Readonly) {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        NoGlobalAssignMessage::NotAllowed,
+        NoGlobalAssignHint::Remove,
+      );
     }
   }
 }
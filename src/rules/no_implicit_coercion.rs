@@ -0,0 +1,735 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  AssignOp, BinaryOp, Expr, ExprOrSuper, Lit, PatOrExpr, Tpl, UnaryOp,
+};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoImplicitCoercion {
+  options: NoImplicitCoercionOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoImplicitCoercionOptions {
+  /// Check coercions to boolean, i.e. `!!foo` and `~foo.indexOf(bar)`.
+  pub boolean: bool,
+  /// Check coercions to number, i.e. `+foo` and `1 * foo`.
+  pub number: bool,
+  /// Check coercions to string, i.e. `"" + foo` and `foo += ""`.
+  pub string: bool,
+  /// Also check `` `${foo}` `` as a coercion to string.
+  pub disallow_template_shorthand: bool,
+  /// Operators that are allowed to be used for coercions, any of `!!`, `~`,
+  /// `+` and `*`.
+  pub allow: Vec<String>,
+}
+
+impl Default for NoImplicitCoercionOptions {
+  fn default() -> Self {
+    Self {
+      boolean: true,
+      number: true,
+      string: true,
+      disallow_template_shorthand: false,
+      allow: Vec::new(),
+    }
+  }
+}
+
+impl NoImplicitCoercion {
+  pub fn with_options(options: NoImplicitCoercionOptions) -> Arc<Self> {
+    Arc::new(NoImplicitCoercion { options })
+  }
+}
+
+const CODE: &str = "no-implicit-coercion";
+const FIX_DESC: &str = "Use an explicit conversion";
+
+#[derive(Display)]
+enum NoImplicitCoercionMessage {
+  #[display(fmt = "Unexpected implicit coercion to {}", _0)]
+  Implicit(&'static str),
+}
+
+#[derive(Display)]
+enum NoImplicitCoercionHint {
+  #[display(fmt = "Use `{}` instead", _0)]
+  UseExplicit(String),
+}
+
+impl LintRule for NoImplicitCoercion {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoImplicitCoercionOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let check = |description: &str, default: bool| {
+      json!({
+        "type": "boolean",
+        "description": description,
+        "default": default
+      })
+    };
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "boolean": check("Check coercions to boolean.", true),
+        "number": check("Check coercions to number.", true),
+        "string": check("Check coercions to string.", true),
+        "disallowTemplateShorthand": check("Check `${foo}` as a coercion to string.", false),
+        "allow": {
+          "type": "array",
+          "items": { "type": "string", "enum": ["!!", "~", "+", "*"] },
+          "description": "Operators that are allowed to be used for coercions."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoImplicitCoercionOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoImplicitCoercionHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_implicit_coercion.md")
+  }
+}
+
+/// Checks if `expr` is already a number, i.e. a number literal or a call to
+/// `Number()`, `parseInt()` or `parseFloat()`.
+fn is_numeric(expr: &Expr) -> bool {
+  match unwrap_paren(expr) {
+    Expr::Lit(Lit::Num(_)) => true,
+    Expr::Call(call) => {
+      matches!(
+        callee_name(&call.callee),
+        Some("Number" | "parseInt" | "parseFloat")
+      )
+    }
+    _ => false,
+  }
+}
+
+/// Checks if `expr` is already a string, i.e. a string or template literal or
+/// a call to `String()`.
+fn is_string(expr: &Expr) -> bool {
+  match unwrap_paren(expr) {
+    Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => true,
+    Expr::Call(call) => callee_name(&call.callee) == Some("String"),
+    _ => false,
+  }
+}
+
+fn is_empty_string(expr: &Expr) -> bool {
+  match unwrap_paren(expr) {
+    Expr::Lit(Lit::Str(s)) => s.value.is_empty(),
+    Expr::Tpl(tpl) => tpl.exprs.is_empty() && is_empty_tpl_element(tpl, 0),
+    _ => false,
+  }
+}
+
+fn is_empty_tpl_element(tpl: &Tpl, idx: usize) -> bool {
+  matches!(
+    tpl.quasis.get(idx),
+    Some(quasi) if quasi.raw.value.is_empty()
+  )
+}
+
+fn is_one(expr: &Expr) -> bool {
+  matches!(unwrap_paren(expr), Expr::Lit(Lit::Num(num)) if num.value == 1.0)
+}
+
+fn callee_name(callee: &ExprOrSuper) -> Option<&str> {
+  match callee {
+    ExprOrSuper::Expr(expr) => match &**expr {
+      Expr::Ident(ident) => Some(&ident.sym),
+      _ => None,
+    },
+    ExprOrSuper::Super(_) => None,
+  }
+}
+
+/// Checks if `expr` is a call to `indexOf()` or `lastIndexOf()`.
+fn is_index_of_call(expr: &Expr) -> bool {
+  let callee = match unwrap_paren(expr) {
+    Expr::Call(call) => match &call.callee {
+      ExprOrSuper::Expr(callee) => callee,
+      ExprOrSuper::Super(_) => return false,
+    },
+    _ => return false,
+  };
+  let member = match &**callee {
+    Expr::Member(member) => member,
+    _ => return false,
+  };
+  let name: &str = match (&*member.prop, member.computed) {
+    (Expr::Ident(ident), false) => &ident.sym,
+    (Expr::Lit(Lit::Str(s)), true) => &s.value,
+    _ => return false,
+  };
+  matches!(name, "indexOf" | "lastIndexOf")
+}
+
+struct NoImplicitCoercionHandler<'a> {
+  options: &'a NoImplicitCoercionOptions,
+}
+
+impl<'a> NoImplicitCoercionHandler<'a> {
+  fn is_allowed(&self, operator: &str) -> bool {
+    self.options.allow.iter().any(|allowed| allowed == operator)
+  }
+
+  /// Returns the source text of `expr` to be passed to a conversion function.
+  fn operand_text(&self, ctx: &Context, expr: &Expr) -> String {
+    let mut expr = expr;
+    while let Expr::Paren(paren) = expr {
+      // `Boolean(a, b)` would mean something else
+      if matches!(&*paren.expr, Expr::Seq(_)) {
+        break;
+      }
+      expr = &paren.expr;
+    }
+    ctx.file_text_substring(&expr.span()).to_string()
+  }
+
+  /// Reports a coercion with `replacement` as the explicit form. It's only
+  /// offered as a fix if `function`, the conversion function, isn't
+  /// shadowed.
+  fn report(
+    &self,
+    ctx: &mut Context,
+    node: Node,
+    span: Span,
+    kind: &'static str,
+    replacement: String,
+    function: Option<&str>,
+  ) {
+    let fixable = match function {
+      Some(function) => {
+        let scope_tree = ctx.scope_tree();
+        scope_tree
+          .lookup(scope_tree.scope_of(node), &JsWord::from(function))
+          .is_none()
+      }
+      None => false,
+    };
+    let fixes = if fixable {
+      vec![LintFix {
+        description: FIX_DESC.to_string(),
+        changes: vec![ctx.fix_change(span, replacement.clone())],
+        applicability: None,
+      }]
+    } else {
+      vec![]
+    };
+    ctx.add_diagnostic_with_fixes(
+      span,
+      CODE,
+      NoImplicitCoercionMessage::Implicit(kind),
+      Some(NoImplicitCoercionHint::UseExplicit(replacement).to_string()),
+      fixes,
+    );
+  }
+}
+
+impl<'a> Handler for NoImplicitCoercionHandler<'a> {
+  fn unary_expr(
+    &mut self,
+    unary_expr: &ast_view::UnaryExpr,
+    ctx: &mut Context,
+  ) {
+    let node = unary_expr.as_node();
+    let unary_expr = unary_expr.inner;
+    let options = self.options;
+    let (kind, replacement, function) = match unary_expr.op {
+      // `!!foo`
+      UnaryOp::Bang => match &*unary_expr.arg {
+        Expr::Unary(inner)
+          if inner.op == UnaryOp::Bang
+            && options.boolean
+            && !self.is_allowed("!!") =>
+        {
+          let operand = self.operand_text(ctx, &inner.arg);
+          ("boolean", format!("Boolean({})", operand), Some("Boolean"))
+        }
+        _ => return,
+      },
+      // `~foo.indexOf(bar)`
+      UnaryOp::Tilde
+        if options.boolean
+          && !self.is_allowed("~")
+          && is_index_of_call(&unary_expr.arg) =>
+      {
+        let call = ctx.file_text_substring(&unary_expr.arg.span());
+        ("boolean", format!("{} !== -1", call), None)
+      }
+      // `+foo`
+      UnaryOp::Plus
+        if options.number
+          && !self.is_allowed("+")
+          && !is_numeric(&unary_expr.arg) =>
+      {
+        let operand = self.operand_text(ctx, &unary_expr.arg);
+        ("number", format!("Number({})", operand), Some("Number"))
+      }
+      _ => return,
+    };
+    self.report(ctx, node, unary_expr.span, kind, replacement, function);
+  }
+
+  fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
+    let node = bin_expr.as_node();
+    let bin_expr = bin_expr.inner;
+    let (left, right) = (&*bin_expr.left, &*bin_expr.right);
+    match bin_expr.op {
+      // `1 * foo` and `foo * 1`
+      BinaryOp::Mul if self.options.number && !self.is_allowed("*") => {
+        // Operands that are binary expressions aren't reported since they
+        // are often arithmetic, e.g. `1 * foo * 2`.
+        let is_coerced = |expr: &Expr| {
+          !is_numeric(expr) && !matches!(unwrap_paren(expr), Expr::Bin(_))
+        };
+        let operand = if is_one(left) && is_coerced(right) {
+          right
+        } else if is_one(right) && is_coerced(left) {
+          left
+        } else {
+          return;
+        };
+        let replacement =
+          format!("Number({})", self.operand_text(ctx, operand));
+        self.report(
+          ctx,
+          node,
+          bin_expr.span,
+          "number",
+          replacement,
+          Some("Number"),
+        );
+      }
+      // `"" + foo` and `foo + ""`
+      BinaryOp::Add if self.options.string && !self.is_allowed("+") => {
+        let operand = if is_empty_string(left) && !is_string(right) {
+          right
+        } else if is_empty_string(right) && !is_string(left) {
+          left
+        } else {
+          return;
+        };
+        let replacement =
+          format!("String({})", self.operand_text(ctx, operand));
+        self.report(
+          ctx,
+          node,
+          bin_expr.span,
+          "string",
+          replacement,
+          Some("String"),
+        );
+      }
+      _ => {}
+    }
+  }
+
+  // `foo += ""`
+  fn assign_expr(
+    &mut self,
+    assign_expr: &ast_view::AssignExpr,
+    ctx: &mut Context,
+  ) {
+    let node = assign_expr.as_node();
+    let assign_expr = assign_expr.inner;
+    if assign_expr.op != AssignOp::AddAssign
+      || !self.options.string
+      || self.is_allowed("+")
+      || !is_empty_string(&assign_expr.right)
+    {
+      return;
+    }
+
+    let left_span = match &assign_expr.left {
+      PatOrExpr::Pat(pat) => pat.span(),
+      PatOrExpr::Expr(expr) => expr.span(),
+    };
+    let left = ctx.file_text_substring(&left_span);
+    let replacement = format!("{} = String({})", left, left);
+    self.report(
+      ctx,
+      node,
+      assign_expr.span,
+      "string",
+      replacement,
+      Some("String"),
+    );
+  }
+
+  // `` `${foo}` ``
+  fn tpl(&mut self, tpl: &ast_view::Tpl, ctx: &mut Context) {
+    if !self.options.disallow_template_shorthand
+      || !self.options.string
+      || tpl.parent().is::<ast_view::TaggedTpl>()
+    {
+      return;
+    }
+    let node = tpl.as_node();
+    let tpl = tpl.inner;
+    let expr = match tpl.exprs.as_slice() {
+      [expr] => expr,
+      _ => return,
+    };
+    if !is_empty_tpl_element(tpl, 0)
+      || !is_empty_tpl_element(tpl, 1)
+      || is_string(expr)
+    {
+      return;
+    }
+
+    let replacement = format!("String({})", self.operand_text(ctx, expr));
+    self.report(ctx, node, tpl.span, "string", replacement, Some("String"));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-implicit-coercion.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_implicit_coercion_valid() {
+    assert_lint_ok! {
+      NoImplicitCoercion,
+      "Boolean(foo)",
+      "foo.indexOf(1) !== -1",
+      "Number(foo)",
+      "parseInt(foo)",
+      "parseFloat(foo)",
+      "String(foo)",
+      "!foo",
+      "~foo",
+      "-foo",
+      "+1234",
+      "-1234",
+      "- -1234",
+      "+Number(lol)",
+      "-parseFloat(lol)",
+      "2 * foo",
+      "1 * 1234",
+      "123 - 0",
+      "1 * Number(foo)",
+      "1 * parseInt(foo)",
+      "1 * parseFloat(foo)",
+      "Number(foo) * 1",
+      "Number(foo) - 0",
+      "parseInt(foo) * 1",
+      "parseFloat(foo) * 1",
+      "- -Number(foo)",
+      "1 * 1234 * 678 * Number(foo)",
+      "1 * 1234 * 678 * parseInt(foo)",
+      "(1 - 0) * parseInt(foo)",
+      "1234 * 1 * 678 * Number(foo)",
+      "1234 * 1 * Number(foo) * Number(bar)",
+      "1234 * 1 * Number(foo) * parseInt(bar)",
+      "1234 * 1 * Number(foo) * parseFloat(bar)",
+      "1234 * 1 * parseInt(foo) * parseFloat(bar)",
+      "1234 * 1 * parseInt(foo) * Number(bar)",
+      "1234 * 1 * parseFloat(foo) * Number(bar)",
+      "1234 * Number(foo) * 1 * Number(bar)",
+      "1234 * parseInt(foo) * 1 * Number(bar)",
+      "1234 * parseFloat(foo) * 1 * Number(bar)",
+      "1234 * 1 * Number(foo) * Number(bar) * 1",
+      "0 + foo",
+      "~foo.bar()",
+      "foo + 'bar'",
+      "`${foo}`",
+      "`foo${bar}`",
+      "foo += 'bar'",
+      "foo += `${bar}`",
+      "'' + 'foo'",
+      "'' + `${foo}`",
+      "'foo' + ''",
+      "`${foo}` + ''",
+      "'' + String(foo)",
+      "String(foo) + ''",
+      "tag`${foo}`",
+    };
+
+    assert_lint_ok! {
+      NoImplicitCoercion,
+      options: NoImplicitCoercionOptions {
+        boolean: false,
+        number: false,
+        string: false,
+        ..Default::default()
+      },
+      "!!foo",
+      "~foo.indexOf(1)",
+      "+foo",
+      "1 * foo",
+      "'' + foo",
+      "foo += ''",
+    };
+
+    assert_lint_ok! {
+      NoImplicitCoercion,
+      options: NoImplicitCoercionOptions {
+        allow: vec!["!!".to_string(), "~".to_string(), "+".to_string(), "*".to_string()],
+        ..Default::default()
+      },
+      "!!foo",
+      "~foo.indexOf(1)",
+      "+foo",
+      "1 * foo",
+      "'' + foo",
+      "foo += ''",
+    };
+
+    assert_lint_ok! {
+      NoImplicitCoercion,
+      options: NoImplicitCoercionOptions {
+        disallow_template_shorthand: true,
+        ..Default::default()
+      },
+      "`${'foo'}`",
+      "`${`foo`}`",
+      "`${String(foo)}`",
+      "`foo${bar}`",
+      "`${foo}bar`",
+      "`${foo}${bar}`",
+      "tag`${foo}`",
+    };
+  }
+
+  #[test]
+  fn no_implicit_coercion_invalid() {
+    assert_lint_err! {
+      NoImplicitCoercion,
+      "!!foo": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("boolean"),
+          hint: NoImplicitCoercionHint::UseExplicit("Boolean(foo)".to_string()),
+          fix: (FIX_DESC, "Boolean(foo)"),
+        }
+      ],
+      "!!foo.bar.baz": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("boolean"),
+          hint: NoImplicitCoercionHint::UseExplicit("Boolean(foo.bar.baz)".to_string()),
+          fix: (FIX_DESC, "Boolean(foo.bar.baz)"),
+        }
+      ],
+      "!!(foo + bar)": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("boolean"),
+          hint: NoImplicitCoercionHint::UseExplicit("Boolean(foo + bar)".to_string()),
+          fix: (FIX_DESC, "Boolean(foo + bar)"),
+        }
+      ],
+      "!!(foo, bar)": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("boolean"),
+          hint: NoImplicitCoercionHint::UseExplicit("Boolean((foo, bar))".to_string()),
+          fix: (FIX_DESC, "Boolean((foo, bar))"),
+        }
+      ],
+      "~foo.indexOf(1)": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("boolean"),
+          hint: NoImplicitCoercionHint::UseExplicit("foo.indexOf(1) !== -1".to_string()),
+        }
+      ],
+      "~foo.bar.lastIndexOf(1)": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("boolean"),
+          hint: NoImplicitCoercionHint::UseExplicit("foo.bar.lastIndexOf(1) !== -1".to_string()),
+        }
+      ],
+      "+foo": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("number"),
+          hint: NoImplicitCoercionHint::UseExplicit("Number(foo)".to_string()),
+          fix: (FIX_DESC, "Number(foo)"),
+        }
+      ],
+      "+foo.bar": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("number"),
+          hint: NoImplicitCoercionHint::UseExplicit("Number(foo.bar)".to_string()),
+          fix: (FIX_DESC, "Number(foo.bar)"),
+        }
+      ],
+      "1 * foo": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("number"),
+          hint: NoImplicitCoercionHint::UseExplicit("Number(foo)".to_string()),
+          fix: (FIX_DESC, "Number(foo)"),
+        }
+      ],
+      "foo * 1": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("number"),
+          hint: NoImplicitCoercionHint::UseExplicit("Number(foo)".to_string()),
+          fix: (FIX_DESC, "Number(foo)"),
+        }
+      ],
+      "1 * foo.bar": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("number"),
+          hint: NoImplicitCoercionHint::UseExplicit("Number(foo.bar)".to_string()),
+          fix: (FIX_DESC, "Number(foo.bar)"),
+        }
+      ],
+      "'' + foo": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("string"),
+          hint: NoImplicitCoercionHint::UseExplicit("String(foo)".to_string()),
+          fix: (FIX_DESC, "String(foo)"),
+        }
+      ],
+      "`` + foo": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("string"),
+          hint: NoImplicitCoercionHint::UseExplicit("String(foo)".to_string()),
+          fix: (FIX_DESC, "String(foo)"),
+        }
+      ],
+      "foo + ''": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("string"),
+          hint: NoImplicitCoercionHint::UseExplicit("String(foo)".to_string()),
+          fix: (FIX_DESC, "String(foo)"),
+        }
+      ],
+      "foo.bar + ''": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("string"),
+          hint: NoImplicitCoercionHint::UseExplicit("String(foo.bar)".to_string()),
+          fix: (FIX_DESC, "String(foo.bar)"),
+        }
+      ],
+      "foo += ''": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("string"),
+          hint: NoImplicitCoercionHint::UseExplicit("foo = String(foo)".to_string()),
+          fix: (FIX_DESC, "foo = String(foo)"),
+        }
+      ],
+      "var a = !!foo + bar;": [
+        {
+          col: 8,
+          message: NoImplicitCoercionMessage::Implicit("boolean"),
+          hint: NoImplicitCoercionHint::UseExplicit("Boolean(foo)".to_string()),
+          fix: (FIX_DESC, "var a = Boolean(foo) + bar;"),
+        }
+      ],
+      "function f(Boolean) { return !!foo; }": [
+        {
+          col: 29,
+          message: NoImplicitCoercionMessage::Implicit("boolean"),
+          hint: NoImplicitCoercionHint::UseExplicit("Boolean(foo)".to_string()),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoImplicitCoercion,
+      options: NoImplicitCoercionOptions {
+        disallow_template_shorthand: true,
+        ..Default::default()
+      },
+      "`${foo}`": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("string"),
+          hint: NoImplicitCoercionHint::UseExplicit("String(foo)".to_string()),
+          fix: (FIX_DESC, "String(foo)"),
+        }
+      ],
+      "`${foo.bar}`": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("string"),
+          hint: NoImplicitCoercionHint::UseExplicit("String(foo.bar)".to_string()),
+          fix: (FIX_DESC, "String(foo.bar)"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoImplicitCoercion,
+      options: NoImplicitCoercionOptions {
+        allow: vec!["!!".to_string()],
+        ..Default::default()
+      },
+      "~foo.indexOf(1)": [
+        {
+          col: 0,
+          message: NoImplicitCoercionMessage::Implicit("boolean"),
+          hint: NoImplicitCoercionHint::UseExplicit("foo.indexOf(1) !== -1".to_string()),
+        }
+      ],
+    };
+  }
+}
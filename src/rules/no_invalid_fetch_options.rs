@@ -0,0 +1,418 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  Expr, ExprOrSpread, ExprOrSuper, Lit, ObjectLit, Prop, PropName, PropOrSpread,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoInvalidFetchOptions;
+
+const CODE: &str = "no-invalid-fetch-options";
+
+#[derive(Display)]
+enum NoInvalidFetchOptionsMessage {
+  #[display(fmt = "`{}` is not a valid `fetch` option", _0)]
+  UnknownOption(String),
+  #[display(fmt = "`body` can't be used with a `{}` request", _0)]
+  BodyWithoutPayload(String),
+}
+
+#[derive(Display)]
+enum NoInvalidFetchOptionsHint {
+  #[display(fmt = "Did you mean `{}`?", _0)]
+  DidYouMean(&'static str),
+  #[display(
+    fmt = "Remove `body`, or set `method` to one that accepts a body, like `\"POST\"`"
+  )]
+  RemoveBodyOrChangeMethod,
+}
+
+#[derive(Display)]
+enum NoInvalidFetchOptionsFix {
+  #[display(fmt = "Rename to `{}`", _0)]
+  Rename(&'static str),
+}
+
+impl LintRule for NoInvalidFetchOptions {
+  fn new() -> Arc<Self> {
+    Arc::new(NoInvalidFetchOptions)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoInvalidFetchOptionsHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_invalid_fetch_options.md")
+  }
+}
+
+/// The keys of `RequestInit`, including the Deno specific `client`.
+const KNOWN_OPTIONS: &[&str] = &[
+  "body",
+  "cache",
+  "client",
+  "credentials",
+  "duplex",
+  "headers",
+  "integrity",
+  "keepalive",
+  "method",
+  "mode",
+  "priority",
+  "redirect",
+  "referrer",
+  "referrerPolicy",
+  "signal",
+  "window",
+];
+
+/// Methods that must not have a body.
+const METHODS_WITHOUT_BODY: &[&str] = &["GET", "HEAD"];
+
+fn edit_distance(a: &str, b: &str) -> usize {
+  let b = b.chars().collect::<Vec<_>>();
+  let mut previous = (0..=b.len()).collect::<Vec<_>>();
+  for (i, a_char) in a.chars().enumerate() {
+    let mut current = vec![i + 1];
+    for (j, b_char) in b.iter().enumerate() {
+      let substitution = previous[j] + usize::from(a_char != *b_char);
+      current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+    }
+    previous = current;
+  }
+  previous[b.len()]
+}
+
+/// Returns the known option `key` is most likely a typo of, if it is close
+/// enough to one to be clearly unintended.
+fn likely_option(key: &str) -> Option<&'static str> {
+  if KNOWN_OPTIONS.contains(&key) {
+    return None;
+  }
+  let lowercase = key.to_lowercase();
+  KNOWN_OPTIONS
+    .iter()
+    .copied()
+    .filter_map(|option| {
+      let distance = if option.to_lowercase() == lowercase {
+        0
+      } else {
+        edit_distance(key, option)
+      };
+      let max_distance = if option.len() <= 4 { 1 } else { 2 };
+      if distance <= max_distance {
+        Some((distance, option))
+      } else {
+        None
+      }
+    })
+    .min_by_key(|(distance, _)| *distance)
+    .map(|(_, option)| option)
+}
+
+struct OptionKey {
+  name: String,
+  /// The span of the key, if it can be renamed without changing the meaning
+  /// of the property.
+  rename_span: Option<Span>,
+}
+
+fn option_key(prop: &Prop) -> Option<OptionKey> {
+  let key = match prop {
+    Prop::Shorthand(ident) => {
+      return Some(OptionKey {
+        name: ident.sym.to_string(),
+        rename_span: None,
+      })
+    }
+    Prop::KeyValue(key_value) => &key_value.key,
+    Prop::Method(method) => &method.key,
+    Prop::Getter(getter) => &getter.key,
+    Prop::Setter(setter) => &setter.key,
+    Prop::Assign(_) => return None,
+  };
+  match key {
+    PropName::Ident(ident) => Some(OptionKey {
+      name: ident.sym.to_string(),
+      rename_span: Some(ident.span),
+    }),
+    PropName::Str(str) => Some(OptionKey {
+      name: str.value.to_string(),
+      rename_span: Some(str.span),
+    }),
+    _ => None,
+  }
+}
+
+fn is_nullish(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Null(_)) => true,
+    Expr::Ident(ident) => ident.sym == *"undefined",
+    _ => false,
+  }
+}
+
+struct NoInvalidFetchOptionsHandler;
+
+impl NoInvalidFetchOptionsHandler {
+  fn check_typos(&self, options: &ObjectLit, ctx: &mut Context) {
+    for prop in &options.props {
+      let prop = match prop {
+        PropOrSpread::Prop(prop) => prop,
+        PropOrSpread::Spread(_) => continue,
+      };
+      let key = match option_key(prop) {
+        Some(key) => key,
+        None => continue,
+      };
+      let option = match likely_option(&key.name) {
+        Some(option) => option,
+        None => continue,
+      };
+      let message = NoInvalidFetchOptionsMessage::UnknownOption(key.name);
+      let hint = NoInvalidFetchOptionsHint::DidYouMean(option);
+      match key.rename_span {
+        Some(span) => {
+          let text = ctx.file_text_substring(&span);
+          let renamed = match text.chars().next() {
+            Some(quote @ '"') | Some(quote @ '\'') => {
+              format!("{}{}{}", quote, option, quote)
+            }
+            _ => option.to_string(),
+          };
+          let fix = LintFix {
+            description: NoInvalidFetchOptionsFix::Rename(option).to_string(),
+            changes: vec![ctx.fix_change(span, renamed)],
+            applicability: None,
+          };
+          ctx.add_diagnostic_with_fixes(
+            prop.span(),
+            CODE,
+            message,
+            Some(hint.to_string()),
+            vec![fix],
+          );
+        }
+        None => {
+          ctx.add_diagnostic_with_hint(prop.span(), CODE, message, hint);
+        }
+      }
+    }
+  }
+
+  fn check_body_with_method(&self, options: &ObjectLit, ctx: &mut Context) {
+    // A spread may set any option, so the method isn't known.
+    if options
+      .props
+      .iter()
+      .any(|prop| matches!(prop, PropOrSpread::Spread(_)))
+    {
+      return;
+    }
+
+    let mut method = Some("GET".to_string());
+    let mut body = None;
+    for prop in &options.props {
+      let prop = match prop {
+        PropOrSpread::Prop(prop) => &**prop,
+        PropOrSpread::Spread(_) => continue,
+      };
+      let name = option_key(prop).map(|key| key.name);
+      match (name.as_deref(), prop) {
+        (Some("method"), Prop::KeyValue(key_value)) => {
+          method = match &*key_value.value {
+            Expr::Lit(Lit::Str(str)) => Some(str.value.to_uppercase()),
+            _ => None,
+          };
+        }
+        (Some("method"), _) => method = None,
+        (Some("body"), Prop::KeyValue(key_value)) => {
+          body = if is_nullish(&key_value.value) {
+            None
+          } else {
+            Some(prop.span())
+          };
+        }
+        (Some("body"), _) => body = Some(prop.span()),
+        _ => {}
+      }
+    }
+
+    if let (Some(method), Some(body)) = (method, body) {
+      if METHODS_WITHOUT_BODY.contains(&method.as_str()) {
+        ctx.add_diagnostic_with_hint(
+          body,
+          CODE,
+          NoInvalidFetchOptionsMessage::BodyWithoutPayload(method),
+          NoInvalidFetchOptionsHint::RemoveBodyOrChangeMethod,
+        );
+      }
+    }
+  }
+}
+
+impl Handler for NoInvalidFetchOptionsHandler {
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let call_expr = call_expr.inner;
+    let is_fetch = matches!(
+      &call_expr.callee,
+      ExprOrSuper::Expr(callee) if matches!(
+        &**callee,
+        Expr::Ident(ident)
+          if ident.sym == *"fetch" && ctx.scope().is_global(&ident.to_id())
+      )
+    );
+    if !is_fetch {
+      return;
+    }
+    let options = match call_expr.args.get(1) {
+      Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+        Expr::Object(options) => options,
+        _ => return,
+      },
+      _ => return,
+    };
+    self.check_typos(options, ctx);
+    self.check_body_with_method(options, ctx);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_invalid_fetch_options_valid() {
+    assert_lint_ok! {
+      NoInvalidFetchOptions,
+      "fetch(url);",
+      "fetch(url, { method: 'POST', body: JSON.stringify(data) });",
+      "fetch(url, { method: 'put', headers: { 'Content-Type': 'text/plain' }, body });",
+      "fetch(url, { method: 'GET', headers, signal });",
+      "fetch(url, { cache: 'no-store', credentials: 'include', mode: 'cors', redirect: 'follow' });",
+      "fetch(url, { referrerPolicy: 'no-referrer', keepalive: true, integrity });",
+      "fetch(url, { client: Deno.createHttpClient({}) });",
+      "fetch(url, { body: null });",
+      "fetch(url, { method: 'GET', body: undefined });",
+      "fetch(url, { method, body });",
+      "fetch(url, { ...init, body });",
+      "fetch(url, init);",
+      "fetch(url, { [key]: value });",
+      "fetch(url, { timeout: 1000 });",
+      "request(url, { methds: 'POST' });",
+      "foo.fetch(url, { methds: 'POST' });",
+      "function fetch(url, options) {} fetch(url, { methds: 'POST' });",
+    };
+  }
+
+  #[test]
+  fn no_invalid_fetch_options_invalid() {
+    assert_lint_err! {
+      NoInvalidFetchOptions,
+      "fetch(url, { methds: 'POST' });": [
+        {
+          col: 13,
+          message: NoInvalidFetchOptionsMessage::UnknownOption("methds".to_string()),
+          hint: NoInvalidFetchOptionsHint::DidYouMean("method"),
+          fix: (
+            NoInvalidFetchOptionsFix::Rename("method"),
+            "fetch(url, { method: 'POST' });",
+          ),
+        }
+      ],
+      "fetch(url, { 'header': {} });": [
+        {
+          col: 13,
+          message: NoInvalidFetchOptionsMessage::UnknownOption("header".to_string()),
+          hint: NoInvalidFetchOptionsHint::DidYouMean("headers"),
+          fix: (
+            NoInvalidFetchOptionsFix::Rename("headers"),
+            "fetch(url, { 'headers': {} });",
+          ),
+        }
+      ],
+      "fetch(url, { method: 'POST', referrerpolicy: 'origin' });": [
+        {
+          col: 29,
+          message: NoInvalidFetchOptionsMessage::UnknownOption("referrerpolicy".to_string()),
+          hint: NoInvalidFetchOptionsHint::DidYouMean("referrerPolicy"),
+          fix: (
+            NoInvalidFetchOptionsFix::Rename("referrerPolicy"),
+            "fetch(url, { method: 'POST', referrerPolicy: 'origin' });",
+          ),
+        }
+      ],
+      "fetch(url, { ...init, sigal });": [
+        {
+          col: 22,
+          message: NoInvalidFetchOptionsMessage::UnknownOption("sigal".to_string()),
+          hint: NoInvalidFetchOptionsHint::DidYouMean("signal"),
+        }
+      ],
+      "fetch(url, { method: 'GET', body: 'data' });": [
+        {
+          col: 28,
+          message: NoInvalidFetchOptionsMessage::BodyWithoutPayload("GET".to_string()),
+          hint: NoInvalidFetchOptionsHint::RemoveBodyOrChangeMethod,
+        }
+      ],
+      "f\\u0065tch(url, { method: 'GET', body: 'data' });": [
+        {
+          col: 33,
+          message: NoInvalidFetchOptionsMessage::BodyWithoutPayload("GET".to_string()),
+          hint: NoInvalidFetchOptionsHint::RemoveBodyOrChangeMethod,
+        }
+      ],
+      "fetch(url, { body: form, method: 'head' });": [
+        {
+          col: 13,
+          message: NoInvalidFetchOptionsMessage::BodyWithoutPayload("HEAD".to_string()),
+          hint: NoInvalidFetchOptionsHint::RemoveBodyOrChangeMethod,
+        }
+      ],
+      "fetch(url, { headers, body });": [
+        {
+          col: 22,
+          message: NoInvalidFetchOptionsMessage::BodyWithoutPayload("GET".to_string()),
+          hint: NoInvalidFetchOptionsHint::RemoveBodyOrChangeMethod,
+        }
+      ],
+      "async function f() {\n  await fetch(url, {\n    metod: 'POST',\n    body,\n  });\n}": [
+        {
+          line: 3,
+          col: 4,
+          message: NoInvalidFetchOptionsMessage::UnknownOption("metod".to_string()),
+          hint: NoInvalidFetchOptionsHint::DidYouMean("method"),
+        },
+        {
+          line: 4,
+          col: 4,
+          message: NoInvalidFetchOptionsMessage::BodyWithoutPayload("GET".to_string()),
+          hint: NoInvalidFetchOptionsHint::RemoveBodyOrChangeMethod,
+        }
+      ],
+    };
+  }
+}
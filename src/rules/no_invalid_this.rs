@@ -0,0 +1,339 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Pat, PatOrExpr};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoInvalidThis;
+
+const CODE: &str = "no-invalid-this";
+
+#[derive(Display)]
+enum NoInvalidThisMessage {
+  #[display(fmt = "`this` is not allowed outside of class or object methods")]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum NoInvalidThisHint {
+  #[display(
+    fmt = "Move the code into a method, or add a `this` parameter to the function"
+  )]
+  MoveIntoMethod,
+}
+
+impl LintRule for NoInvalidThis {
+  fn new() -> Arc<Self> {
+    Arc::new(NoInvalidThis)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoInvalidThisHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_invalid_this.md")
+  }
+}
+
+struct NoInvalidThisHandler;
+
+impl Handler for NoInvalidThisHandler {
+  fn this_expr(&mut self, this_expr: &ast_view::ThisExpr, ctx: &mut Context) {
+    if has_valid_this(this_expr.as_node()) {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(
+      this_expr.span(),
+      CODE,
+      NoInvalidThisMessage::Unexpected,
+      NoInvalidThisHint::MoveIntoMethod,
+    );
+  }
+}
+
+/// Walks up to the nearest function that `this` belongs to, skipping arrow
+/// functions since they inherit `this` from the enclosing scope.
+fn has_valid_this(node: Node) -> bool {
+  for ancestor in node.ancestors() {
+    match ancestor {
+      Node::Constructor(_)
+      | Node::ClassProp(_)
+      | Node::PrivateProp(_)
+      | Node::StaticBlock(_)
+      | Node::GetterProp(_)
+      | Node::SetterProp(_) => return true,
+      Node::Function(function) => return is_bound_function(function),
+      _ => {}
+    }
+  }
+  false
+}
+
+fn is_capitalized(name: &str) -> bool {
+  matches!(name.chars().next(), Some(c) if c.is_uppercase())
+}
+
+/// Returns whether `this` inside of the function is likely bound to an object,
+/// e.g. because it's a method, a constructor or has a `this` parameter.
+fn is_bound_function(function: &ast_view::Function) -> bool {
+  let has_this_param = function.inner.params.iter().any(
+    |param| matches!(&param.pat, Pat::Ident(binding) if binding.id.sym == *"this"),
+  );
+  if has_this_param {
+    return true;
+  }
+
+  match function.parent() {
+    Node::ClassMethod(_) | Node::PrivateMethod(_) | Node::MethodProp(_) => true,
+    Node::FnDecl(fn_decl) => is_capitalized(fn_decl.ident.sym()),
+    Node::FnExpr(fn_expr) => {
+      if let Some(ident) = &fn_expr.inner.ident {
+        if is_capitalized(&ident.sym) {
+          return true;
+        }
+      }
+      is_bound_by_context(fn_expr.as_node())
+    }
+    _ => false,
+  }
+}
+
+/// Checks where a function expression is used, e.g. `obj.foo = function() {}`
+/// or `function() {}.bind(obj)`.
+fn is_bound_by_context(fn_expr: Node) -> bool {
+  let parent = match fn_expr
+    .ancestors()
+    .find(|ancestor| !matches!(ancestor, Node::ParenExpr(_)))
+  {
+    Some(parent) => parent,
+    None => return false,
+  };
+
+  match parent {
+    Node::KeyValueProp(_) => true,
+    Node::AssignExpr(assign) => match &assign.inner.left {
+      PatOrExpr::Expr(expr) => match &**expr {
+        Expr::Member(_) => true,
+        Expr::Ident(ident) => is_capitalized(&ident.sym),
+        _ => false,
+      },
+      PatOrExpr::Pat(pat) => match &**pat {
+        Pat::Expr(expr) => matches!(&**expr, Expr::Member(_)),
+        Pat::Ident(binding) => is_capitalized(&binding.id.sym),
+        _ => false,
+      },
+    },
+    Node::VarDeclarator(declarator) => matches!(
+      &declarator.inner.name,
+      Pat::Ident(binding) if is_capitalized(&binding.id.sym)
+    ),
+    Node::MemberExpr(member) => {
+      !member.inner.computed
+        && matches!(
+          &*member.inner.prop,
+          Expr::Ident(prop) if matches!(prop.sym.as_ref(), "bind" | "call" | "apply")
+        )
+    }
+    Node::ExprOrSpread(arg) => is_array_method_callback(arg),
+    _ => false,
+  }
+}
+
+/// Checks if the argument is the callback of an array method that is given a
+/// `thisArg`, e.g. `arr.map(function() {}, obj)`.
+fn is_array_method_callback(arg: &ast_view::ExprOrSpread) -> bool {
+  let call = match arg.parent() {
+    Node::CallExpr(call) => call,
+    _ => return false,
+  };
+  let args = &call.inner.args;
+  if args.len() < 2 || !std::ptr::eq(&args[0], arg.inner) {
+    return false;
+  }
+  match &call.inner.callee {
+    ExprOrSuper::Expr(callee) => matches!(
+      &**callee,
+      Expr::Member(member) if !member.computed && matches!(
+        &*member.prop,
+        Expr::Ident(prop) if matches!(
+          prop.sym.as_ref(),
+          "map"
+            | "forEach"
+            | "filter"
+            | "some"
+            | "every"
+            | "find"
+            | "findIndex"
+            | "flatMap"
+        )
+      )
+    ),
+    ExprOrSuper::Super(_) => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.32.0/tests/lib/rules/no-invalid-this.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_invalid_this_valid() {
+    assert_lint_ok! {
+      NoInvalidThis,
+      "class A { constructor() { this.a = 1; } }",
+      "class A { foo() { this.a = 1; } }",
+      "class A { #foo() { this.a = 1; } }",
+      "class A { static foo() { this.a = 1; } }",
+      "class A { a = this.b; }",
+      "class A { #a = this.b; }",
+      "class A { static { this.a = 1; } }",
+      "class A { foo() { return () => this.a; } }",
+      "class A { foo = () => this.a; }",
+      "const obj = { foo() { this.a = 1; } };",
+      "const obj = { foo: function() { this.a = 1; } };",
+      "const obj = { get foo() { return this.a; } };",
+      "const obj = { set foo(v) { this.a = v; } };",
+      "const obj = { foo() { return () => () => this.a; } };",
+      "obj.foo = function() { this.a = 1; };",
+      "Foo.prototype.bar = function() { this.a = 1; };",
+      "function Foo() { this.a = 1; }",
+      "const Foo = function() { this.a = 1; };",
+      "const foo = function Foo() { this.a = 1; };",
+      "Foo = function() { this.a = 1; };",
+      "const foo = function() { this.a = 1; }.bind(obj);",
+      "const foo = (function() { this.a = 1; }).call(obj);",
+      "function foo(this: Foo) { this.a = 1; }",
+      "const foo = function(this: Foo) { return () => this.a; };",
+      "let a: this;",
+      "arr.map(function() { this.a = 1; }, obj);",
+      "arr.forEach(function() { this.a = 1; }, obj);",
+      "arr.filter(function() { return this.a; }, obj);",
+      "arr.some(function() { return this.a; }, obj);",
+      "arr.every(function() { return this.a; }, obj);",
+      "arr.find(function() { return this.a; }, obj);",
+      "arr.findIndex(function() { return this.a; }, obj);",
+      "arr.flatMap(function() { return this.a; }, obj);",
+      "arr.map((function() { return () => this.a; }), obj);",
+    };
+  }
+
+  #[test]
+  fn no_invalid_this_invalid() {
+    assert_lint_err! {
+      NoInvalidThis,
+      "this.a = 1;": [
+        {
+          col: 0,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "const foo = () => this.a;": [
+        {
+          col: 18,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "function foo() { this.a = 1; }": [
+        {
+          col: 17,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "const foo = function() { this.a = 1; };": [
+        {
+          col: 25,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "foo(function() { this.a = 1; });": [
+        {
+          col: 17,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "arr.map(function() { this.a = 1; });": [
+        {
+          col: 21,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "arr.map(obj, function() { this.a = 1; });": [
+        {
+          col: 26,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "foo(function() { this.a = 1; }, obj);": [
+        {
+          col: 17,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "class A { foo() { function bar() { this.a = 1; } } }": [
+        {
+          col: 35,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "const obj = { foo() { return function() { return this.a; }; } };": [
+        {
+          col: 49,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "function foo() { return () => { this.a = 1; this.b = 2; }; }": [
+        {
+          col: 32,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        },
+        {
+          col: 44,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+      "export default function() { this.a = 1; }": [
+        {
+          col: 28,
+          message: NoInvalidThisMessage::Unexpected,
+          hint: NoInvalidThisHint::MoveIntoMethod,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,360 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  Expr, ExprOrSuper, JSXAttrName, JSXAttrOrSpread, JSXAttrValue,
+  JSXElementName, JSXExpr, MemberExpr,
+};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoJsxBind {
+  options: NoJsxBindOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoJsxBindOptions {
+  /// Allow arrow functions, e.g. `onClick={() => foo()}`.
+  pub allow_arrow_functions: bool,
+  /// Allow `.bind()` calls, e.g. `onClick={this.foo.bind(this)}`.
+  pub allow_bind: bool,
+  /// Don't check the props of DOM components, e.g. `<div>`.
+  #[serde(rename = "ignoreDOMComponents")]
+  pub ignore_dom_components: bool,
+  /// Don't check the `ref` prop.
+  pub ignore_refs: bool,
+}
+
+impl NoJsxBind {
+  pub fn with_options(options: NoJsxBindOptions) -> Arc<Self> {
+    Arc::new(NoJsxBind { options })
+  }
+}
+
+const CODE: &str = "no-jsx-bind";
+
+#[derive(Display)]
+enum NoJsxBindMessage {
+  #[display(fmt = "JSX props should not use arrow functions")]
+  ArrowFunction,
+  #[display(fmt = "JSX props should not use functions")]
+  Function,
+  #[display(fmt = "JSX props should not use `.bind()`")]
+  Bind,
+}
+
+#[derive(Display)]
+enum NoJsxBindHint {
+  #[display(
+    fmt = "Define the function outside of the render, e.g. with `useCallback`, so that it isn't recreated on every render"
+  )]
+  Memoize,
+}
+
+impl LintRule for NoJsxBind {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoJsxBindOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let ignore = |description: &str| {
+      json!({
+        "type": "boolean",
+        "description": description,
+        "default": false
+      })
+    };
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowArrowFunctions": ignore("Allow arrow functions as props."),
+        "allowBind": ignore("Allow `.bind()` calls as props."),
+        "ignoreDOMComponents": ignore("Don't check the props of DOM components."),
+        "ignoreRefs": ignore("Don't check the `ref` prop.")
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoJsxBindOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoJsxBindHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_jsx_bind.md")
+  }
+}
+
+struct NoJsxBindHandler<'a> {
+  options: &'a NoJsxBindOptions,
+}
+
+impl<'a> NoJsxBindHandler<'a> {
+  /// Returns the kind of the newly created function if it's not allowed.
+  fn disallowed_expr(&self, expr: &Expr) -> Option<NoJsxBindMessage> {
+    match expr {
+      Expr::Arrow(_) if !self.options.allow_arrow_functions => {
+        Some(NoJsxBindMessage::ArrowFunction)
+      }
+      Expr::Fn(_) => Some(NoJsxBindMessage::Function),
+      Expr::Call(call) if !self.options.allow_bind && is_bind(&call.callee) => {
+        Some(NoJsxBindMessage::Bind)
+      }
+      Expr::Cond(cond) => self
+        .disallowed_expr(&cond.cons)
+        .or_else(|| self.disallowed_expr(&cond.alt)),
+      Expr::Paren(paren) => self.disallowed_expr(&paren.expr),
+      _ => None,
+    }
+  }
+}
+
+/// Whether the callee is `*.bind`.
+fn is_bind(callee: &ExprOrSuper) -> bool {
+  let member = match callee {
+    ExprOrSuper::Expr(expr) => match &**expr {
+      Expr::Member(member) => member,
+      _ => return false,
+    },
+    ExprOrSuper::Super(_) => return false,
+  };
+  let MemberExpr { prop, computed, .. } = member;
+  matches!(&**prop, Expr::Ident(ident) if !computed && ident.sym == *"bind")
+}
+
+/// Whether the element is a DOM component, e.g. `<div>`, rather than a
+/// user-defined one, e.g. `<Foo>` or `<foo.Bar>`.
+fn is_dom_component(name: &JSXElementName) -> bool {
+  match name {
+    JSXElementName::Ident(ident) => {
+      ident.sym.starts_with(|c: char| c.is_ascii_lowercase())
+    }
+    _ => false,
+  }
+}
+
+impl<'a> Handler for NoJsxBindHandler<'a> {
+  fn jsx_opening_element(
+    &mut self,
+    jsx_opening_element: &ast_view::JSXOpeningElement,
+    ctx: &mut Context,
+  ) {
+    let element = jsx_opening_element.inner;
+    if self.options.ignore_dom_components && is_dom_component(&element.name) {
+      return;
+    }
+
+    for attr in &element.attrs {
+      let attr = match attr {
+        JSXAttrOrSpread::JSXAttr(attr) => attr,
+        JSXAttrOrSpread::SpreadElement(_) => continue,
+      };
+      if self.options.ignore_refs
+        && matches!(&attr.name, JSXAttrName::Ident(ident) if ident.sym == *"ref")
+      {
+        continue;
+      }
+      let container = match &attr.value {
+        Some(JSXAttrValue::JSXExprContainer(container)) => container,
+        _ => continue,
+      };
+      if let JSXExpr::Expr(expr) = &container.expr {
+        if let Some(message) = self.disallowed_expr(expr) {
+          ctx.add_diagnostic_with_hint(
+            container.span(),
+            CODE,
+            message,
+            NoJsxBindHint::Memoize,
+          );
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.31.10/tests/lib/rules/jsx-no-bind.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_jsx_bind_valid() {
+    assert_lint_ok! {
+      NoJsxBind,
+      filename: "foo.tsx",
+      "<div onClick={this._handleClick}></div>",
+      "<div meaningOfLife={42}></div>",
+      "<div onClick={getHandler()}></div>",
+      "<div onClick={this.handlers.bind}></div>",
+      "<div onClick={this.foo[\"bind\"]()}></div>",
+      "<div {...props} />",
+      "<div disabled />",
+      "<div title=\"foo\" />",
+      "<div>{items.map((item) => <span>{item}</span>)}</div>",
+      "const onClick = () => {}; <div onClick={onClick} />",
+    };
+
+    assert_lint_ok! {
+      NoJsxBind,
+      options: NoJsxBindOptions {
+        allow_arrow_functions: true,
+        allow_bind: true,
+        ..Default::default()
+      },
+      filename: "foo.tsx",
+      "<div onClick={() => alert(\"1337\")}></div>",
+      "<div onClick={async () => alert(\"1337\")}></div>",
+      "<div onClick={this._handleClick.bind(this)}></div>",
+      "<div onClick={cond ? () => foo() : bar.bind(this)}></div>",
+    };
+
+    assert_lint_ok! {
+      NoJsxBind,
+      options: NoJsxBindOptions {
+        ignore_dom_components: true,
+        ignore_refs: true,
+        ..Default::default()
+      },
+      filename: "foo.tsx",
+      "<div onClick={() => alert(\"1337\")}></div>",
+      "<input onChange={this.onChange.bind(this)} />",
+      "<Foo ref={(c) => this.foo = c} />",
+      "<Foo ref={function (c) { this.foo = c; }} />",
+    };
+  }
+
+  #[test]
+  fn no_jsx_bind_invalid() {
+    assert_lint_err! {
+      NoJsxBind,
+      filename: "foo.tsx",
+      "<div onClick={this._handleClick.bind(this)}></div>": [
+        {
+          col: 13,
+          message: NoJsxBindMessage::Bind,
+          hint: NoJsxBindHint::Memoize,
+        }
+      ],
+      "<div onClick={someGlobalFunction.bind(this)}></div>": [
+        {
+          col: 13,
+          message: NoJsxBindMessage::Bind,
+          hint: NoJsxBindHint::Memoize,
+        }
+      ],
+      "<div onClick={() => alert(\"1337\")}></div>": [
+        {
+          col: 13,
+          message: NoJsxBindMessage::ArrowFunction,
+          hint: NoJsxBindHint::Memoize,
+        }
+      ],
+      "<div onClick={async () => alert(\"1337\")}></div>": [
+        {
+          col: 13,
+          message: NoJsxBindMessage::ArrowFunction,
+          hint: NoJsxBindHint::Memoize,
+        }
+      ],
+      "<div onClick={function () { alert(\"1337\"); }}></div>": [
+        {
+          col: 13,
+          message: NoJsxBindMessage::Function,
+          hint: NoJsxBindHint::Memoize,
+        }
+      ],
+      "<div onClick={(() => alert(\"1337\"))}></div>": [
+        {
+          col: 13,
+          message: NoJsxBindMessage::ArrowFunction,
+          hint: NoJsxBindHint::Memoize,
+        }
+      ],
+      "<div onClick={cond ? onClick : () => foo()}></div>": [
+        {
+          col: 13,
+          message: NoJsxBindMessage::ArrowFunction,
+          hint: NoJsxBindHint::Memoize,
+        }
+      ],
+      "<Foo.Bar ref={(c) => this.foo = c} onClick={this.foo.bind(this)} />": [
+        {
+          col: 13,
+          message: NoJsxBindMessage::ArrowFunction,
+          hint: NoJsxBindHint::Memoize,
+        },
+        {
+          col: 43,
+          message: NoJsxBindMessage::Bind,
+          hint: NoJsxBindHint::Memoize,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoJsxBind,
+      options: NoJsxBindOptions {
+        allow_arrow_functions: true,
+        ignore_dom_components: true,
+        ignore_refs: true,
+        ..Default::default()
+      },
+      filename: "foo.tsx",
+      "<Foo onClick={function () { alert(\"1337\"); }} />": [
+        {
+          col: 13,
+          message: NoJsxBindMessage::Function,
+          hint: NoJsxBindHint::Memoize,
+        }
+      ],
+      "<Foo ref={(c) => this.foo = c} onClick={this.foo.bind(this)} />": [
+        {
+          col: 39,
+          message: NoJsxBindMessage::Bind,
+          hint: NoJsxBindHint::Memoize,
+        }
+      ],
+    };
+  }
+}
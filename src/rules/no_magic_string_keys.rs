@@ -0,0 +1,335 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSpread, Lit, PropName, Str};
+use deno_ast::swc::common::Span;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoMagicStringKeys {
+  options: NoMagicStringKeysOptions,
+  ignore: Vec<Regex>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoMagicStringKeysOptions {
+  /// The number of times a string must be used as a key or event name to be
+  /// reported.
+  pub min_occurrences: usize,
+  /// Regular expressions matching strings that aren't checked. Invalid
+  /// patterns are ignored.
+  pub ignore: Vec<String>,
+}
+
+impl Default for NoMagicStringKeysOptions {
+  fn default() -> Self {
+    NoMagicStringKeysOptions {
+      min_occurrences: 3,
+      ignore: Vec::new(),
+    }
+  }
+}
+
+impl NoMagicStringKeys {
+  pub fn with_options(options: NoMagicStringKeysOptions) -> Arc<Self> {
+    let ignore = options
+      .ignore
+      .iter()
+      .filter_map(|pattern| Regex::new(pattern).ok())
+      .collect();
+    Arc::new(NoMagicStringKeys { options, ignore })
+  }
+}
+
+const CODE: &str = "no-magic-string-keys";
+const RELATED_MESSAGE: &str = "First used here";
+
+#[derive(Display)]
+enum NoMagicStringKeysMessage {
+  #[display(
+    fmt = "The string \"{}\" is used {} times as a key or event name",
+    _0,
+    _1
+  )]
+  Repeated(String, usize),
+}
+
+#[derive(Display)]
+enum NoMagicStringKeysHint {
+  #[display(fmt = "Extract the string into a named constant")]
+  ExtractConstant,
+}
+
+impl LintRule for NoMagicStringKeys {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoMagicStringKeysOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "minOccurrences": {
+          "type": "integer",
+          "minimum": 2,
+          "description": "The number of uses of a string from which it's reported.",
+          "default": 3
+        },
+        "ignore": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Regular expressions matching strings that aren't checked."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoMagicStringKeysOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    let mut handler = NoMagicStringKeysHandler::default();
+    handler.traverse(program, context);
+
+    for (value, spans) in handler.occurrences {
+      if spans.len() < self.options.min_occurrences
+        || self.ignore.iter().any(|pattern| pattern.is_match(&value))
+      {
+        continue;
+      }
+      let count = spans.len();
+      let related = context.related_information(spans[0], RELATED_MESSAGE);
+      for span in &spans[1..] {
+        context.add_diagnostic_with_related(
+          *span,
+          CODE,
+          NoMagicStringKeysMessage::Repeated(value.clone(), count),
+          Some(NoMagicStringKeysHint::ExtractConstant.to_string()),
+          vec![related.clone()],
+        );
+      }
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_magic_string_keys.md")
+  }
+}
+
+/// Methods whose first argument is the name of an event, e.g.
+/// `target.addEventListener("click", listener)`.
+const EVENT_METHODS: &[&str] = &[
+  "addEventListener",
+  "removeEventListener",
+  "on",
+  "once",
+  "off",
+  "emit",
+  "addListener",
+  "removeListener",
+];
+
+#[derive(Default)]
+struct NoMagicStringKeysHandler {
+  /// The spans of the strings used as keys or event names, in the order
+  /// they were first used.
+  occurrences: Vec<(String, Vec<Span>)>,
+  indices: HashMap<String, usize>,
+}
+
+impl NoMagicStringKeysHandler {
+  fn record(&mut self, str: &Str) {
+    let value = str.value.to_string();
+    match self.indices.get(&value) {
+      Some(index) => self.occurrences[*index].1.push(str.span),
+      None => {
+        self.indices.insert(value.clone(), self.occurrences.len());
+        self.occurrences.push((value, vec![str.span]));
+      }
+    }
+  }
+}
+
+impl Handler for NoMagicStringKeysHandler {
+  fn key_value_prop(
+    &mut self,
+    key_value_prop: &ast_view::KeyValueProp,
+    _ctx: &mut Context,
+  ) {
+    if let PropName::Str(str) = &key_value_prop.inner.key {
+      self.record(str);
+    }
+  }
+
+  fn member_expr(
+    &mut self,
+    member_expr: &ast_view::MemberExpr,
+    _ctx: &mut Context,
+  ) {
+    let inner = member_expr.inner;
+    if let (Expr::Lit(Lit::Str(str)), true) = (&*inner.prop, inner.computed) {
+      self.record(str);
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, _ctx: &mut Context) {
+    let method = match &call_expr.callee {
+      ast_view::ExprOrSuper::Expr(ast_view::Expr::Member(member))
+        if !member.computed() =>
+      {
+        match &member.prop {
+          ast_view::Expr::Ident(ident) => ident.sym(),
+          _ => return,
+        }
+      }
+      _ => return,
+    };
+    if !EVENT_METHODS.contains(&method.as_ref()) {
+      return;
+    }
+    if let Some(ExprOrSpread { spread: None, expr }) =
+      call_expr.inner.args.first()
+    {
+      if let Expr::Lit(Lit::Str(str)) = &**expr {
+        self.record(str);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_magic_string_keys_valid() {
+    assert_lint_ok! {
+      NoMagicStringKeys,
+      r#"const a = { "foo": 1 }; const b = { "foo": 2 };"#,
+      r#"obj["foo"]; obj["foo"];"#,
+      r#"const a = { foo: 1 }; const b = { foo: 2 }; const c = { foo: 3 };"#,
+      r#"obj.foo; obj.foo; obj.foo;"#,
+      r#"log("foo"); log("foo"); log("foo");"#,
+      r#"const a = "foo"; const b = "foo"; const c = "foo";"#,
+      r#"obj["foo"]; obj["bar"]; obj["baz"];"#,
+      r#"el.addEventListener("click", f); el.addEventListener("keydown", f);"#,
+      r#"on("click", f); on("click", f); on("click", f);"#,
+    };
+
+    assert_lint_ok! {
+      NoMagicStringKeys,
+      options: NoMagicStringKeysOptions {
+        min_occurrences: 3,
+        ignore: vec!["^data-".to_string(), "^id$".to_string()],
+      },
+      r#"a["data-x"]; a["data-x"]; a["data-x"];"#,
+      r#"const a = { "id": 1 }; a["id"]; b["id"];"#,
+    };
+
+    assert_lint_ok! {
+      NoMagicStringKeys,
+      options: NoMagicStringKeysOptions {
+        min_occurrences: 4,
+        ignore: vec![],
+      },
+      r#"obj["foo"]; obj["foo"]; obj["foo"];"#,
+    };
+  }
+
+  #[test]
+  fn no_magic_string_keys_invalid() {
+    assert_lint_err! {
+      NoMagicStringKeys,
+      r#"obj["foo"]; obj["foo"]; obj["foo"];"#: [
+        {
+          col: 16,
+          message: NoMagicStringKeysMessage::Repeated("foo".to_string(), 3),
+          hint: NoMagicStringKeysHint::ExtractConstant,
+          related: (1, 4, RELATED_MESSAGE),
+        },
+        {
+          col: 28,
+          message: NoMagicStringKeysMessage::Repeated("foo".to_string(), 3),
+          hint: NoMagicStringKeysHint::ExtractConstant,
+          related: (1, 4, RELATED_MESSAGE),
+        }
+      ],
+      r#"const a = { "x-y": 1 };
+a["x-y"] = 2;
+el.addEventListener("x-y", f);
+emitter.emit("x-y");"#: [
+        {
+          line: 2,
+          col: 2,
+          message: NoMagicStringKeysMessage::Repeated("x-y".to_string(), 4),
+          hint: NoMagicStringKeysHint::ExtractConstant,
+          related: (1, 12, RELATED_MESSAGE),
+        },
+        {
+          line: 3,
+          col: 20,
+          message: NoMagicStringKeysMessage::Repeated("x-y".to_string(), 4),
+          hint: NoMagicStringKeysHint::ExtractConstant,
+          related: (1, 12, RELATED_MESSAGE),
+        },
+        {
+          line: 4,
+          col: 13,
+          message: NoMagicStringKeysMessage::Repeated("x-y".to_string(), 4),
+          hint: NoMagicStringKeysHint::ExtractConstant,
+          related: (1, 12, RELATED_MESSAGE),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoMagicStringKeys,
+      options: NoMagicStringKeysOptions {
+        min_occurrences: 2,
+        ignore: vec!["[".to_string()],
+      },
+      r#"bus.on("ready", f); bus.off("ready", f);"#: [
+        {
+          col: 28,
+          message: NoMagicStringKeysMessage::Repeated("ready".to_string(), 2),
+          hint: NoMagicStringKeysHint::ExtractConstant,
+          related: (1, 7, RELATED_MESSAGE),
+        }
+      ],
+    };
+  }
+}
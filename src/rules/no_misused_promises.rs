@@ -0,0 +1,232 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{async_function_names, is_async_function, unwrap_paren};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{BinaryOp, Expr, ExprOrSuper, UnaryOp};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoMisusedPromises;
+
+const CODE: &str = "no-misused-promises";
+
+#[derive(Display)]
+enum NoMisusedPromisesMessage {
+  #[display(
+    fmt = "This condition is always true since a promise is always truthy"
+  )]
+  Conditional,
+}
+
+#[derive(Display)]
+enum NoMisusedPromisesHint {
+  #[display(fmt = "Did you forget to `await` the call?")]
+  AddAwait,
+}
+
+impl LintRule for NoMisusedPromises {
+  fn new() -> Arc<Self> {
+    Arc::new(NoMisusedPromises)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoMisusedPromisesHandler {
+      async_functions: async_function_names(program),
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_misused_promises.md")
+  }
+}
+
+struct NoMisusedPromisesHandler {
+  async_functions: HashSet<JsWord>,
+}
+
+impl NoMisusedPromisesHandler {
+  /// Reports calls of async functions used as a condition. Both operands of
+  /// `&&` and `||` are checked since they are converted to booleans as well.
+  fn check_condition(&self, expr: &Expr, ctx: &mut Context) {
+    match unwrap_paren(expr) {
+      Expr::Bin(bin)
+        if matches!(bin.op, BinaryOp::LogicalAnd | BinaryOp::LogicalOr) =>
+      {
+        self.check_condition(&bin.left, ctx);
+        self.check_condition(&bin.right, ctx);
+      }
+      Expr::Call(call_expr) => {
+        let callee = match &call_expr.callee {
+          ExprOrSuper::Expr(callee) => unwrap_paren(callee),
+          ExprOrSuper::Super(_) => return,
+        };
+        let is_async = match callee {
+          Expr::Ident(ident) => self.async_functions.contains(&ident.sym),
+          _ => is_async_function(callee),
+        };
+        if is_async {
+          ctx.add_diagnostic_with_hint(
+            call_expr.span,
+            CODE,
+            NoMisusedPromisesMessage::Conditional,
+            NoMisusedPromisesHint::AddAwait,
+          );
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+impl Handler for NoMisusedPromisesHandler {
+  fn if_stmt(&mut self, if_stmt: &ast_view::IfStmt, ctx: &mut Context) {
+    self.check_condition(&if_stmt.inner.test, ctx);
+  }
+
+  fn while_stmt(
+    &mut self,
+    while_stmt: &ast_view::WhileStmt,
+    ctx: &mut Context,
+  ) {
+    self.check_condition(&while_stmt.inner.test, ctx);
+  }
+
+  fn do_while_stmt(
+    &mut self,
+    do_while_stmt: &ast_view::DoWhileStmt,
+    ctx: &mut Context,
+  ) {
+    self.check_condition(&do_while_stmt.inner.test, ctx);
+  }
+
+  fn for_stmt(&mut self, for_stmt: &ast_view::ForStmt, ctx: &mut Context) {
+    if let Some(test) = &for_stmt.inner.test {
+      self.check_condition(test, ctx);
+    }
+  }
+
+  fn cond_expr(&mut self, cond_expr: &ast_view::CondExpr, ctx: &mut Context) {
+    self.check_condition(&cond_expr.inner.test, ctx);
+  }
+
+  fn unary_expr(
+    &mut self,
+    unary_expr: &ast_view::UnaryExpr,
+    ctx: &mut Context,
+  ) {
+    if unary_expr.op() == UnaryOp::Bang {
+      self.check_condition(&unary_expr.inner.arg, ctx);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_misused_promises_valid() {
+    assert_lint_ok! {
+      NoMisusedPromises,
+      "async function foo() {} if (await foo()) {}",
+      "async function foo() {} while (await foo()) {}",
+      "async function foo() {} const x = (await foo()) ? 1 : 2;",
+      "async function foo() {} if (!(await foo())) {}",
+      "function foo() {} if (foo()) {}",
+      "const foo = () => true; if (foo()) {}",
+      "if (bar()) {}",
+      "async function foo() {} const p = foo(); p.then(() => {});",
+      "async function foo() {} const x = foo() ?? 1;",
+      "async function foo() {} for (;;) { await foo(); }",
+    };
+  }
+
+  #[test]
+  fn no_misused_promises_invalid() {
+    assert_lint_err! {
+      NoMisusedPromises,
+      "async function foo() {} if (foo()) {}": [
+        {
+          col: 28,
+          message: NoMisusedPromisesMessage::Conditional,
+          hint: NoMisusedPromisesHint::AddAwait,
+        }
+      ],
+      "if (foo()) {} async function foo() {}": [
+        {
+          col: 4,
+          message: NoMisusedPromisesMessage::Conditional,
+          hint: NoMisusedPromisesHint::AddAwait,
+        }
+      ],
+      "const foo = async () => true; while (foo()) {}": [
+        {
+          col: 37,
+          message: NoMisusedPromisesMessage::Conditional,
+          hint: NoMisusedPromisesHint::AddAwait,
+        }
+      ],
+      "const foo = async function () {}; do {} while (foo());": [
+        {
+          col: 47,
+          message: NoMisusedPromisesMessage::Conditional,
+          hint: NoMisusedPromisesHint::AddAwait,
+        }
+      ],
+      "async function foo() {} for (; foo(); ) {}": [
+        {
+          col: 31,
+          message: NoMisusedPromisesMessage::Conditional,
+          hint: NoMisusedPromisesHint::AddAwait,
+        }
+      ],
+      "async function foo() {} const x = foo() ? 1 : 2;": [
+        {
+          col: 34,
+          message: NoMisusedPromisesMessage::Conditional,
+          hint: NoMisusedPromisesHint::AddAwait,
+        }
+      ],
+      "async function foo() {} if (!foo()) {}": [
+        {
+          col: 29,
+          message: NoMisusedPromisesMessage::Conditional,
+          hint: NoMisusedPromisesHint::AddAwait,
+        }
+      ],
+      "async function foo() {} if (bar && (foo() || baz)) {}": [
+        {
+          col: 36,
+          message: NoMisusedPromisesMessage::Conditional,
+          hint: NoMisusedPromisesHint::AddAwait,
+        }
+      ],
+      "if ((async () => true)()) {}": [
+        {
+          col: 4,
+          message: NoMisusedPromisesMessage::Conditional,
+          hint: NoMisusedPromisesHint::AddAwait,
+        }
+      ],
+    };
+  }
+}
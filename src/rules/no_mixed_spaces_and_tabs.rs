@@ -0,0 +1,265 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view as ast_view;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoMixedSpacesAndTabs {
+  options: NoMixedSpacesAndTabsOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoMixedSpacesAndTabsOptions {
+  /// Allow tabs for indentation followed by spaces for alignment, e.g.
+  /// `\t\t  foo`.
+  pub smart_tabs: bool,
+}
+
+impl NoMixedSpacesAndTabs {
+  pub fn with_options(options: NoMixedSpacesAndTabsOptions) -> Arc<Self> {
+    Arc::new(NoMixedSpacesAndTabs { options })
+  }
+}
+
+const CODE: &str = "no-mixed-spaces-and-tabs";
+const MESSAGE: &str = "Mixed spaces and tabs in indentation";
+const HINT: &str = "Indent the line with either spaces or tabs only";
+
+impl LintRule for NoMixedSpacesAndTabs {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoMixedSpacesAndTabsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "smartTabs": {
+          "type": "boolean",
+          "description": "Allow tabs for indentation followed by spaces for alignment.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoMixedSpacesAndTabsOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    let mut collector = LiteralCollector::default();
+    collector.traverse(program, context);
+
+    // Lines that continue a comment, a string or a template literal don't
+    // start with indentation.
+    let ignored_spans: Vec<Span> = context
+      .all_comments()
+      .map(|comment| comment.span)
+      .chain(collector.spans)
+      .collect();
+
+    let file_start = context.source_file().span().lo();
+    let mut reports = Vec::new();
+    let mut line_start = 0;
+    for line in context.source_file().text().split('\n') {
+      let indent_len =
+        line.len() - line.trim_start_matches(&[' ', '\t'][..]).len();
+      let indent = &line[..indent_len];
+      let start = file_start + BytePos(line_start as u32);
+      line_start += line.len() + 1;
+
+      if !self.is_mixed(indent)
+        || ignored_spans
+          .iter()
+          .any(|span| span.lo() < start && start < span.hi())
+      {
+        continue;
+      }
+      reports.push(Span::new(
+        start,
+        start + BytePos(indent_len as u32),
+        Default::default(),
+      ));
+    }
+
+    for span in reports {
+      context.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_mixed_spaces_and_tabs.md")
+  }
+}
+
+impl NoMixedSpacesAndTabs {
+  fn is_mixed(&self, indent: &str) -> bool {
+    if self.options.smart_tabs {
+      // Spaces are only allowed after all the tabs.
+      indent.trim_start_matches('\t').contains('\t')
+    } else {
+      indent.contains(' ') && indent.contains('\t')
+    }
+  }
+}
+
+/// Collects the spans of string literals and template literal elements, which
+/// may contain line breaks.
+#[derive(Default)]
+struct LiteralCollector {
+  spans: Vec<Span>,
+}
+
+impl Handler for LiteralCollector {
+  fn str(&mut self, str: &ast_view::Str, _ctx: &mut Context) {
+    self.spans.push(str.span());
+  }
+
+  fn tpl_element(
+    &mut self,
+    tpl_element: &ast_view::TplElement,
+    _ctx: &mut Context,
+  ) {
+    self.spans.push(tpl_element.span());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-mixed-spaces-and-tabs.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_mixed_spaces_and_tabs_valid() {
+    assert_lint_ok! {
+      NoMixedSpacesAndTabs,
+      "\tvar x = 5;",
+      "    var x = 5;",
+      "\t/*\n\t * Hello\n\t */",
+      "// foo\n\t\tbar",
+      "var x = 5,\n    y = 7;",
+      "/*\n\t */`\n\t   `;",
+      "/*\n\t */var a = `\n\t   `, b = `\n\t   `/*\t \n\t \n*/;",
+      "/*\t `template inside comment` */",
+      "var foo = `\t /* comment inside template\t */`;",
+      "`\n\t   `;",
+      "`\n\t   \n`;",
+      "`\t   `;",
+      "const foo = \"\\\n\t   foo\";",
+      "const foo = 1;\t \t// trailing whitespace",
+    };
+
+    assert_lint_ok! {
+      NoMixedSpacesAndTabs,
+      options: NoMixedSpacesAndTabsOptions { smart_tabs: true },
+      "\tvar x = 5,\n\t    y = 7;",
+      "\t\t  foo;",
+      "    foo;",
+    };
+  }
+
+  #[test]
+  fn no_mixed_spaces_and_tabs_invalid() {
+    assert_lint_err! {
+      NoMixedSpacesAndTabs,
+      "function add(x, y) {\n\t return x + y;\n}": [
+        {
+          line: 2,
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "\t ;\n/*\t\n\t */\n\t ;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          line: 4,
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const x = 5,\n\t    y = 7;": [
+        {
+          line: 2,
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "`foo${\n \t  5 }bar`;": [
+        {
+          line: 2,
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "\t\t  foo;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoMixedSpacesAndTabs,
+      options: NoMixedSpacesAndTabsOptions { smart_tabs: true },
+      "  \tfoo;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "\t \tfoo;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,341 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoMultipleEmptyLines {
+  options: NoMultipleEmptyLinesOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoMultipleEmptyLinesOptions {
+  /// The maximum number of consecutive empty lines.
+  pub max: usize,
+  /// The maximum number of empty lines at the end of the file. Defaults to
+  /// `max`.
+  #[serde(rename = "maxEOF", skip_serializing_if = "Option::is_none")]
+  pub max_eof: Option<usize>,
+  /// The maximum number of empty lines at the beginning of the file. Defaults
+  /// to `max`.
+  #[serde(rename = "maxBOF", skip_serializing_if = "Option::is_none")]
+  pub max_bof: Option<usize>,
+}
+
+impl Default for NoMultipleEmptyLinesOptions {
+  fn default() -> Self {
+    Self {
+      max: 2,
+      max_eof: None,
+      max_bof: None,
+    }
+  }
+}
+
+impl NoMultipleEmptyLines {
+  pub fn with_options(options: NoMultipleEmptyLinesOptions) -> Arc<Self> {
+    Arc::new(NoMultipleEmptyLines { options })
+  }
+}
+
+const CODE: &str = "no-multiple-empty-lines";
+const FIX_DESC: &str = "Remove the extra empty lines";
+
+#[derive(Display)]
+enum NoMultipleEmptyLinesMessage {
+  #[display(fmt = "More than {} empty lines are not allowed", _0)]
+  Consecutive(usize),
+  #[display(
+    fmt = "Too many empty lines at the beginning of file, only {} allowed",
+    _0
+  )]
+  AtBeginning(usize),
+  #[display(
+    fmt = "Too many empty lines at the end of file, only {} allowed",
+    _0
+  )]
+  AtEnd(usize),
+}
+
+impl LintRule for NoMultipleEmptyLines {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoMultipleEmptyLinesOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Automatic
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let max = |description: &str| {
+      json!({
+        "type": "integer",
+        "minimum": 0,
+        "description": description
+      })
+    };
+    let mut max_lines = max("The maximum number of consecutive empty lines.");
+    max_lines["default"] = json!(2);
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "max": max_lines,
+        "maxEOF": max("The maximum number of empty lines at the end of the file. Defaults to `max`."),
+        "maxBOF": max("The maximum number of empty lines at the beginning of the file. Defaults to `max`.")
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoMultipleEmptyLinesOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    let mut collector = TemplateCollector::default();
+    collector.traverse(program, context);
+
+    let text = context.source_file().text();
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    for line in text.split('\n') {
+      lines.push((line_start, line));
+      line_start += line.len() + 1;
+    }
+    // The line break at the end of the file doesn't start another line.
+    if text.ends_with('\n') {
+      lines.pop();
+    }
+
+    // Empty lines in template literals are part of their value.
+    let is_empty = |&(start, line): &(usize, &str)| {
+      let pos = BytePos(start as u32);
+      line.trim().is_empty()
+        && !collector
+          .spans
+          .iter()
+          .any(|span| span.lo() < pos && pos < span.hi())
+    };
+
+    let mut reports = Vec::new();
+    let mut index = 0;
+    while index < lines.len() {
+      if !is_empty(&lines[index]) {
+        index += 1;
+        continue;
+      }
+      let run_start = index;
+      while index < lines.len() && is_empty(&lines[index]) {
+        index += 1;
+      }
+
+      let (max, message): (usize, fn(usize) -> _) = if run_start == 0 {
+        (
+          self.options.max_bof.unwrap_or(self.options.max),
+          NoMultipleEmptyLinesMessage::AtBeginning,
+        )
+      } else if index == lines.len() {
+        (
+          self.options.max_eof.unwrap_or(self.options.max),
+          NoMultipleEmptyLinesMessage::AtEnd,
+        )
+      } else {
+        (self.options.max, NoMultipleEmptyLinesMessage::Consecutive)
+      };
+      if index - run_start <= max {
+        continue;
+      }
+
+      let lo = lines[run_start + max].0;
+      let hi = lines.get(index).map_or(text.len(), |(start, _)| *start);
+      reports.push((lo, hi, message(max)));
+    }
+
+    let file_start = context.source_file().span().lo();
+    for (lo, hi, message) in reports {
+      let span = Span::new(
+        file_start + BytePos(lo as u32),
+        file_start + BytePos(hi as u32),
+        Default::default(),
+      );
+      let fix = LintFix {
+        description: FIX_DESC.to_string(),
+        changes: vec![context.fix_change(span, "")],
+        applicability: None,
+      };
+      context.add_diagnostic_with_fixes(span, CODE, message, None, vec![fix]);
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_multiple_empty_lines.md")
+  }
+}
+
+/// Collects the spans of template literal elements.
+#[derive(Default)]
+struct TemplateCollector {
+  spans: Vec<Span>,
+}
+
+impl Handler for TemplateCollector {
+  fn tpl_element(
+    &mut self,
+    tpl_element: &ast_view::TplElement,
+    _ctx: &mut Context,
+  ) {
+    self.spans.push(tpl_element.span());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-multiple-empty-lines.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_multiple_empty_lines_valid() {
+    assert_lint_ok! {
+      NoMultipleEmptyLines,
+      "var a = 5;\nvar b = 3;",
+      "var a = 5;\n\n\nvar b = 3;",
+      "var a = 5;\n\n\nvar b = 3;\n",
+      "var a = 5;\n\n\n",
+      "\n\nvar a = 5;",
+      "var a = `\n\n\n\nfoo`;",
+      "var a = `foo\n\n\n\n${bar}\n\n\n\n`;",
+      "var a = 5;\n  \n \t\nvar b = 3;",
+      "",
+    };
+
+    assert_lint_ok! {
+      NoMultipleEmptyLines,
+      options: NoMultipleEmptyLinesOptions {
+        max: 0,
+        ..Default::default()
+      },
+      "var a = 5;\nvar b = 3;\n",
+    };
+
+    assert_lint_ok! {
+      NoMultipleEmptyLines,
+      options: NoMultipleEmptyLinesOptions {
+        max: 1,
+        max_eof: Some(3),
+        max_bof: Some(0),
+      },
+      "var a = 5;\n\nvar b = 3;\n\n\n\n",
+    };
+  }
+
+  #[test]
+  fn no_multiple_empty_lines_invalid() {
+    assert_lint_err! {
+      NoMultipleEmptyLines,
+      "var a = 5;\n\n\n\nvar b = 3;": [
+        {
+          line: 4,
+          col: 0,
+          message: NoMultipleEmptyLinesMessage::Consecutive(2),
+          fix: (FIX_DESC, "var a = 5;\n\n\nvar b = 3;"),
+        }
+      ],
+      "var a = 5;\n  \n\t\n \n\n\nvar b = 3;": [
+        {
+          line: 4,
+          col: 0,
+          message: NoMultipleEmptyLinesMessage::Consecutive(2),
+          fix: (FIX_DESC, "var a = 5;\n  \n\t\nvar b = 3;"),
+        }
+      ],
+      "\n\n\nvar a = 5;": [
+        {
+          line: 3,
+          col: 0,
+          message: NoMultipleEmptyLinesMessage::AtBeginning(2),
+          fix: (FIX_DESC, "\n\nvar a = 5;"),
+        }
+      ],
+      "var a = 5;\n\n\n\n": [
+        {
+          line: 4,
+          col: 0,
+          message: NoMultipleEmptyLinesMessage::AtEnd(2),
+          fix: (FIX_DESC, "var a = 5;\n\n\n"),
+        }
+      ],
+      "var a = `foo`;\n\n\n\nvar b = `\n\n\n\nbar`;": [
+        {
+          line: 4,
+          col: 0,
+          message: NoMultipleEmptyLinesMessage::Consecutive(2),
+          fix: (FIX_DESC, "var a = `foo`;\n\n\nvar b = `\n\n\n\nbar`;"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoMultipleEmptyLines,
+      options: NoMultipleEmptyLinesOptions {
+        max: 1,
+        max_eof: Some(0),
+        max_bof: Some(0),
+      },
+      "\nvar a = 5;\n\n\nvar b = 3;\n\n": [
+        {
+          line: 1,
+          col: 0,
+          message: NoMultipleEmptyLinesMessage::AtBeginning(0),
+          fix: (FIX_DESC, "var a = 5;\n\n\nvar b = 3;\n\n"),
+        },
+        {
+          line: 4,
+          col: 0,
+          message: NoMultipleEmptyLinesMessage::Consecutive(1),
+          fix: (FIX_DESC, "\nvar a = 5;\n\nvar b = 3;\n\n"),
+        },
+        {
+          line: 6,
+          col: 0,
+          message: NoMultipleEmptyLinesMessage::AtEnd(0),
+          fix: (FIX_DESC, "\nvar a = 5;\n\n\nvar b = 3;\n"),
+        }
+      ],
+    };
+  }
+}
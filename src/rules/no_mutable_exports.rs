@@ -0,0 +1,217 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::scopes::BindingKind;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Decl, ExportSpecifier, Expr, Ident, VarDeclKind};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoMutableExports;
+
+const CODE: &str = "no-mutable-exports";
+
+#[derive(Display)]
+enum NoMutableExportsMessage {
+  #[display(fmt = "Exporting mutable `{}` binding", _0)]
+  Mutable(&'static str),
+}
+
+#[derive(Display)]
+enum NoMutableExportsHint {
+  #[display(
+    fmt = "Use `const` instead, or export a function that returns the current value"
+  )]
+  UseConst,
+}
+
+impl LintRule for NoMutableExports {
+  fn new() -> Arc<Self> {
+    Arc::new(NoMutableExports)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoMutableExportsHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_mutable_exports.md")
+  }
+}
+
+fn var_decl_keyword(kind: VarDeclKind) -> Option<&'static str> {
+  match kind {
+    VarDeclKind::Var => Some("var"),
+    VarDeclKind::Let => Some("let"),
+    VarDeclKind::Const => None,
+  }
+}
+
+fn report(ctx: &mut Context, span: Span, keyword: &'static str) {
+  ctx.add_diagnostic_with_hint(
+    span,
+    CODE,
+    NoMutableExportsMessage::Mutable(keyword),
+    NoMutableExportsHint::UseConst,
+  );
+}
+
+/// Reports `ident` if it refers to a module-level `let` or `var` binding.
+fn check_exported_ident(ctx: &mut Context, span: Span, ident: &Ident) {
+  let scope_tree = ctx.scope_tree();
+  let keyword = match scope_tree
+    .lookup(scope_tree.root(), &ident.sym)
+    .map(|id| scope_tree.binding(id).kind())
+  {
+    Some(BindingKind::Var) => "var",
+    Some(BindingKind::Let) => "let",
+    _ => return,
+  };
+  report(ctx, span, keyword);
+}
+
+struct NoMutableExportsHandler;
+
+impl Handler for NoMutableExportsHandler {
+  fn export_decl(
+    &mut self,
+    export_decl: &ast_view::ExportDecl,
+    ctx: &mut Context,
+  ) {
+    if let Decl::Var(var_decl) = &export_decl.inner.decl {
+      if let Some(keyword) = var_decl_keyword(var_decl.kind) {
+        report(ctx, export_decl.span(), keyword);
+      }
+    }
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    // Re-exports don't refer to local bindings.
+    if named_export.inner.src.is_some() || named_export.inner.type_only {
+      return;
+    }
+    for specifier in &named_export.inner.specifiers {
+      if let ExportSpecifier::Named(named) = specifier {
+        check_exported_ident(ctx, named.span, &named.orig);
+      }
+    }
+  }
+
+  fn export_default_expr(
+    &mut self,
+    export_default_expr: &ast_view::ExportDefaultExpr,
+    ctx: &mut Context,
+  ) {
+    if let Expr::Ident(ident) = &*export_default_expr.inner.expr {
+      check_exported_ident(ctx, export_default_expr.span(), ident);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/import-js/eslint-plugin-import/blob/v2.26.0/tests/src/rules/no-mutable-exports.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_mutable_exports_valid() {
+    assert_lint_ok! {
+      NoMutableExports,
+      "export const count = 1;",
+      "export function getCount() {}",
+      "export class Counter {}",
+      "export default function getCount() {}",
+      "const count = 1; export { count };",
+      "const count = 1; export { count as total };",
+      "const count = 1; export default count;",
+      "function getCount() {} export { getCount };",
+      "class Counter {} export default Counter;",
+      "export { count } from './count.ts';",
+      "export * from './count.ts';",
+      "import { count } from './count.ts'; export { count };",
+      "let count = 1; export function getCount() { return count; }",
+      "function foo() { let count = 1; return count; } export { foo };",
+      "export type Foo = string;",
+      "export default 1;",
+    };
+  }
+
+  #[test]
+  fn no_mutable_exports_invalid() {
+    assert_lint_err! {
+      NoMutableExports,
+      "export let count = 1;": [
+        {
+          col: 0,
+          message: NoMutableExportsMessage::Mutable("let"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "export var count = 1;": [
+        {
+          col: 0,
+          message: NoMutableExportsMessage::Mutable("var"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "export let count = 1, total = 2;": [
+        {
+          col: 0,
+          message: NoMutableExportsMessage::Mutable("let"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "let count = 1; export { count };": [
+        {
+          col: 24,
+          message: NoMutableExportsMessage::Mutable("let"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "var count = 1; export { count as total };": [
+        {
+          col: 24,
+          message: NoMutableExportsMessage::Mutable("var"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "let count = 1; export default count;": [
+        {
+          col: 15,
+          message: NoMutableExportsMessage::Mutable("let"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "const a = 1; let b = 2; export { a, b };": [
+        {
+          col: 36,
+          message: NoMutableExportsMessage::Mutable("let"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+    };
+  }
+}
@@ -5,10 +5,39 @@ use crate::{Program, ProgramRef};
 use deno_ast::swc::common::Spanned;
 use deno_ast::view as ast_view;
 use deno_ast::view::NodeTrait;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::Arc;
 
 #[derive(Debug)]
-pub struct NoNamespace;
+pub struct NoNamespace {
+  options: NoNamespaceOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoNamespaceOptions {
+  /// Allow `namespace` and `module` declared in an ambient context, i.e. with
+  /// the `declare` keyword.
+  pub allow_declarations: bool,
+  /// Allow `namespace` and `module` in type definition files (`.d.ts`).
+  pub allow_definition_files: bool,
+}
+
+impl Default for NoNamespaceOptions {
+  fn default() -> Self {
+    Self {
+      allow_declarations: true,
+      allow_definition_files: true,
+    }
+  }
+}
+
+impl NoNamespace {
+  pub fn with_options(options: NoNamespaceOptions) -> Arc<Self> {
+    Arc::new(NoNamespace { options })
+  }
+}
 
 const CODE: &str = "no-namespace";
 const MESSAGE: &str = "TypeScript's `module` and `namespace` are discouraged to
@@ -18,7 +47,7 @@ the code instead";
 
 impl LintRule for NoNamespace {
   fn new() -> Arc<Self> {
-    Arc::new(NoNamespace)
+    Self::with_options(NoNamespaceOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -29,6 +58,40 @@ impl LintRule for NoNamespace {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowDeclarations": {
+          "type": "boolean",
+          "description": "Allow `namespace` and `module` declared with the `declare` keyword.",
+          "default": true
+        },
+        "allowDefinitionFiles": {
+          "type": "boolean",
+          "description": "Allow `namespace` and `module` in type definition files.",
+          "default": true
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoNamespaceOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
   fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
     unreachable!();
   }
@@ -38,11 +101,16 @@ impl LintRule for NoNamespace {
     context: &mut Context,
     program: Program<'_>,
   ) {
-    if context.file_name().ends_with(".d.ts") {
+    if self.options.allow_definition_files
+      && context.file_name().ends_with(".d.ts")
+    {
       return;
     }
 
-    NoNamespaceHandler.traverse(program, context);
+    NoNamespaceHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
   }
 
   #[cfg(feature = "docs")]
@@ -51,14 +119,24 @@ impl LintRule for NoNamespace {
   }
 }
 
-struct NoNamespaceHandler;
+struct NoNamespaceHandler<'a> {
+  options: &'a NoNamespaceOptions,
+}
 
-impl Handler for NoNamespaceHandler {
+impl<'a> Handler for NoNamespaceHandler<'a> {
   fn ts_module_decl(
     &mut self,
     module_decl: &ast_view::TsModuleDecl,
     ctx: &mut Context,
   ) {
+    // Global augmentations (`declare global {}`) and module declarations named
+    // by a string (`declare module "foo" {}`) can't be written with ES modules
+    if module_decl.inner.global
+      || matches!(module_decl.id, ast_view::TsModuleName::Str(_))
+    {
+      return;
+    }
+
     fn inside_ambient_context(current_node: ast_view::Node) -> bool {
       use deno_ast::view::Node::*;
       match current_node {
@@ -70,7 +148,9 @@ impl Handler for NoNamespaceHandler {
       }
     }
 
-    if !inside_ambient_context(module_decl.as_node()) {
+    if !self.options.allow_declarations
+      || !inside_ambient_context(module_decl.as_node())
+    {
       ctx.add_diagnostic_with_hint(module_decl.span(), CODE, MESSAGE, HINT);
     }
   }
@@ -183,4 +263,74 @@ export declare namespace Utility {
       ],
     };
   }
+
+  #[test]
+  fn no_namespace_disallow_declarations() {
+    let options = NoNamespaceOptions {
+      allow_declarations: false,
+      ..Default::default()
+    };
+
+    assert_lint_ok! {
+      NoNamespace,
+      options: options.clone(),
+      r#"declare global {}"#,
+      r#"declare module 'foo' {}"#,
+    };
+
+    assert_lint_err! {
+      NoNamespace,
+      options: options.clone(),
+      "declare module foo {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        },
+      ],
+      "declare namespace foo {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        },
+      ],
+      "declare global { namespace foo {} }": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+        },
+      ],
+    };
+  }
+
+  #[test]
+  fn no_namespace_disallow_definition_files() {
+    let options = NoNamespaceOptions {
+      allow_definition_files: false,
+      ..Default::default()
+    };
+
+    assert_lint_ok! {
+      NoNamespace,
+      options: options.clone(),
+      filename: "test.d.ts",
+      r#"declare namespace foo {}"#,
+      r#"declare module foo { namespace bar {} }"#,
+    };
+
+    assert_lint_err! {
+      NoNamespace,
+      options: options.clone(),
+      filename: "test.d.ts",
+      "namespace foo {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        },
+      ],
+    };
+  }
 }
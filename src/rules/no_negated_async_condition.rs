@@ -0,0 +1,168 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, UnaryOp};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoNegatedAsyncCondition;
+
+const CODE: &str = "no-negated-async-condition";
+const MESSAGE: &str =
+  "`!await` negates the awaited value, which is easy to misread";
+const HINT: &str =
+  "Wrap the `await` expression in parentheses, like `!(await foo())`";
+const FIX_DESC: &str = "Add parentheses around the `await` expression";
+
+impl LintRule for NoNegatedAsyncCondition {
+  fn new() -> Arc<Self> {
+    Arc::new(NoNegatedAsyncCondition)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["await"]
+  }
+
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Automatic
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoNegatedAsyncConditionHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_negated_async_condition.md")
+  }
+}
+
+struct NoNegatedAsyncConditionHandler;
+
+impl Handler for NoNegatedAsyncConditionHandler {
+  fn unary_expr(
+    &mut self,
+    unary_expr: &ast_view::UnaryExpr,
+    ctx: &mut Context,
+  ) {
+    if unary_expr.inner.op != UnaryOp::Bang {
+      return;
+    }
+    let arg = &unary_expr.inner.arg;
+    if !matches!(&**arg, Expr::Await(_)) {
+      return;
+    }
+
+    let arg_text = ctx.file_text_substring(&arg.span()).to_string();
+    let fix = LintFix {
+      description: FIX_DESC.to_string(),
+      changes: vec![ctx.fix_change(arg.span(), format!("({})", arg_text))],
+      applicability: None,
+    };
+    ctx.add_diagnostic_with_fixes(
+      unary_expr.span(),
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      vec![fix],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_negated_async_condition_valid() {
+    assert_lint_ok! {
+      NoNegatedAsyncCondition,
+      "async function f() { if (!(await isReady())) {} }",
+      "async function f() { if (await isReady()) {} }",
+      "async function f() { const done = !ready; }",
+      "async function f() { if ((await isReady()) === false) {} }",
+      "async function f() { await !ready; }",
+      "async function f() { const x = -await count(); }",
+      "async function f() { typeof await load(); }",
+      "if (!(await isReady())) {}",
+    };
+  }
+
+  #[test]
+  fn no_negated_async_condition_invalid() {
+    assert_lint_err! {
+      NoNegatedAsyncCondition,
+      "async function f() { if (!await isReady()) {} }": [
+        {
+          col: 25,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "async function f() { if (!(await isReady())) {} }",
+          ),
+        }
+      ],
+      "if (!await exists(path)) { await mkdir(path); }": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "if (!(await exists(path))) { await mkdir(path); }",
+          ),
+        }
+      ],
+      "const missing = async () => !await file.exists();": [
+        {
+          col: 28,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "const missing = async () => !(await file.exists());",
+          ),
+        }
+      ],
+      "async function f() { while (!await poll() && retries--) {} }": [
+        {
+          col: 28,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "async function f() { while (!(await poll()) && retries--) {} }",
+          ),
+        }
+      ],
+      "async function f() { return !!await check(); }": [
+        {
+          col: 29,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "async function f() { return !!(await check()); }",
+          ),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,160 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view::{self as ast_view, BinaryOp, Expr, UnaryOp};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoNegatedInLhs;
+
+const CODE: &str = "no-negated-in-lhs";
+const MESSAGE: &str = "The left operand of `in` is negated";
+const HINT: &str = "`!a in b` means `(!a) in b`; to check that `a` is not in `b`, write `!(a in b)`";
+const FIX_DESC: &str = "Negate the whole `in` expression";
+
+impl LintRule for NoNegatedInLhs {
+  fn new() -> Arc<Self> {
+    Arc::new(NoNegatedInLhs)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoNegatedInLhsHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_negated_in_lhs.md")
+  }
+}
+
+struct NoNegatedInLhsHandler;
+
+impl Handler for NoNegatedInLhsHandler {
+  fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
+    if bin_expr.op() != BinaryOp::In {
+      return;
+    }
+    let unary_expr = match &bin_expr.left {
+      Expr::Unary(unary_expr) if unary_expr.op() == UnaryOp::Bang => unary_expr,
+      _ => return,
+    };
+
+    // The `in` keyword is all there is between the operands, besides
+    // whitespace and comments.
+    let between = Span::new(
+      bin_expr.left.span().hi(),
+      bin_expr.right.span().lo(),
+      Default::default(),
+    );
+    let op_span = match ctx.file_text_substring(&between).find("in") {
+      Some(offset) => {
+        let lo = between.lo() + BytePos(offset as u32);
+        Span::new(lo, lo + BytePos(2), Default::default())
+      }
+      None => bin_expr.span(),
+    };
+
+    let negated = Span::new(
+      unary_expr.arg.span().lo(),
+      bin_expr.span().hi(),
+      Default::default(),
+    );
+    let fixed = format!("!({})", ctx.file_text_substring(&negated));
+    let fix = LintFix {
+      description: FIX_DESC.to_string(),
+      changes: vec![ctx.fix_change(bin_expr.span(), fixed)],
+      applicability: None,
+    };
+    ctx.add_diagnostic_with_fixes(
+      op_span,
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      vec![fix],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-negated-in-lhs.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_negated_in_lhs_valid() {
+    assert_lint_ok! {
+      NoNegatedInLhs,
+      "a in b",
+      "a in b === false",
+      "!(a in b)",
+      "(!a) in b",
+      "!a instanceof b",
+      "!a === b",
+    };
+  }
+
+  #[test]
+  fn no_negated_in_lhs_invalid() {
+    assert_lint_err! {
+      NoNegatedInLhs,
+      "!a in b": [
+        {
+          col: 3,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "!(a in b)"),
+        }
+      ],
+      "(!a in b)": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(!(a in b))"),
+        }
+      ],
+      "!(a) in b": [
+        {
+          col: 5,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "!((a) in b)"),
+        }
+      ],
+      "if (!key   in  obj) {}": [
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if (!(key   in  obj)) {}"),
+        }
+      ],
+      "!foo.bar in {}": [
+        {
+          col: 9,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "!(foo.bar in {})"),
+        }
+      ],
+    };
+  }
+}
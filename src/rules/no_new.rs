@@ -0,0 +1,92 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::Expr;
+use deno_ast::view as ast_view;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoNew;
+
+const CODE: &str = "no-new";
+const MESSAGE: &str = "Do not use `new` for side effects";
+const HINT: &str =
+  "Assign the instance to a variable, or call a function instead";
+
+impl LintRule for NoNew {
+  fn new() -> Arc<Self> {
+    Arc::new(NoNew)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoNewHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_new.md")
+  }
+}
+
+struct NoNewHandler;
+
+impl Handler for NoNewHandler {
+  fn expr_stmt(&mut self, expr_stmt: &ast_view::ExprStmt, ctx: &mut Context) {
+    if let Expr::New(new_expr) = unwrap_paren(&expr_stmt.inner.expr) {
+      ctx.add_diagnostic_with_hint(new_expr.span, CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-new.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_new_valid() {
+    assert_lint_ok! {
+      NoNew,
+      "var a = new Date()",
+      "var a; if (a === new Date()) { a = false; }",
+      "const foo = new Foo(); foo.bar();",
+      "new Foo().bar();",
+      "foo(new Bar());",
+      "export default new Foo();",
+      "function f() { return new Foo(); }",
+      "const f = () => new Foo();",
+      "throw new Error('foo');",
+    };
+  }
+
+  #[test]
+  fn no_new_invalid() {
+    assert_lint_err! {
+      NoNew,
+      "new Date()": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "new Date;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "(new Foo());": [{ col: 1, message: MESSAGE, hint: HINT }],
+      "function f() { new Foo(bar); }": [
+        { col: 15, message: MESSAGE, hint: HINT }
+      ],
+      "if (x) new Foo();": [{ col: 7, message: MESSAGE, hint: HINT }],
+    };
+  }
+}
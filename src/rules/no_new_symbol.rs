@@ -1,5 +1,6 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::unwrap_paren;
 use crate::ProgramRef;
 use deno_ast::swc::ast::{Expr, NewExpr};
 use deno_ast::swc::utils::ident::IdentLike;
@@ -14,6 +15,7 @@ pub struct NoNewSymbol;
 
 const CODE: &str = "no-new-symbol";
 const MESSAGE: &str = "`Symbol` cannot be called as a constructor.";
+const HINT: &str = "Call `Symbol()` without `new`";
 
 impl LintRule for NoNewSymbol {
   fn new() -> Arc<Self> {
@@ -60,12 +62,15 @@ impl<'c, 'view> VisitAll for NoNewSymbolVisitor<'c, 'view> {
   noop_visit_type!();
 
   fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    let callee = unwrap_paren(&new_expr.callee);
     if_chain! {
-      if let Expr::Ident(ident) = &*new_expr.callee;
+      if let Expr::Ident(ident) = callee;
       if ident.sym == *"Symbol";
       if self.context.scope().var(&ident.to_id()).is_none();
       then {
-        self.context.add_diagnostic(new_expr.span, CODE, MESSAGE);
+        self
+          .context
+          .add_diagnostic_with_hint(new_expr.span, CODE, MESSAGE, HINT);
       }
     }
   }
@@ -87,6 +92,8 @@ function f(Symbol: typeof SomeClass) {
   const foo = new Symbol();
 }
       "#,
+      "import { Symbol } from './symbol.ts'; new Symbol();",
+      "class Symbol {} new Symbol();",
     };
   }
 
@@ -94,9 +101,15 @@ function f(Symbol: typeof SomeClass) {
   fn no_new_symbol_invalid() {
     assert_lint_err! {
       NoNewSymbol,
-      "new Symbol()": [{ col: 0, message: MESSAGE }],
+      "new Symbol()": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "new (Symbol)('foo')": [{ col: 0, message: MESSAGE, hint: HINT }],
       // nested
-      "new class { foo() { new Symbol(); } }": [{ col: 20, message: MESSAGE }],
+      "new class { foo() { new Symbol(); } }": [
+        { col: 20, message: MESSAGE, hint: HINT }
+      ],
+      "function f() { const Symbol = 1; } new Symbol();": [
+        { col: 35, message: MESSAGE, hint: HINT }
+      ],
     };
   }
 }
@@ -0,0 +1,522 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::scopes::BindingKind;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  Expr, ExprOrSuper, Ident, ObjectPatProp, Pat, PatOrExpr, UnaryOp,
+  VarDeclOrPat,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoParamReassign {
+  options: NoParamReassignOptions,
+  ignore_regexes: Vec<Regex>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoParamReassignOptions {
+  /// Also report modifications of properties of parameters, e.g.
+  /// `param.foo = 1` or `delete param.foo`.
+  pub props: bool,
+  /// Names of parameters whose properties may be modified when `props` is
+  /// enabled.
+  pub ignore_property_modifications_for: Vec<String>,
+  /// Regular expressions matching names of parameters whose properties may be
+  /// modified when `props` is enabled. Invalid patterns are ignored.
+  pub ignore_property_modifications_for_regex: Vec<String>,
+}
+
+impl NoParamReassign {
+  pub fn with_options(options: NoParamReassignOptions) -> Arc<Self> {
+    let ignore_regexes = options
+      .ignore_property_modifications_for_regex
+      .iter()
+      .filter_map(|p| Regex::new(p).ok())
+      .collect();
+    Arc::new(NoParamReassign {
+      options,
+      ignore_regexes,
+    })
+  }
+
+  fn is_ignored_for_props(&self, name: &str) -> bool {
+    self
+      .options
+      .ignore_property_modifications_for
+      .iter()
+      .any(|n| n == name)
+      || self.ignore_regexes.iter().any(|r| r.is_match(name))
+  }
+}
+
+const CODE: &str = "no-param-reassign";
+
+#[derive(Display)]
+enum NoParamReassignMessage {
+  #[display(fmt = "Assignment to function parameter `{}` is not allowed", _0)]
+  Param(String),
+  #[display(
+    fmt = "Assignment to property of function parameter `{}` is not allowed",
+    _0
+  )]
+  ParamProp(String),
+}
+
+#[derive(Display)]
+enum NoParamReassignHint {
+  #[display(fmt = "Assign the value to a new local variable instead")]
+  UseLocalVariable,
+  #[display(
+    fmt = "Copy the parameter to a new local variable and modify the copy instead"
+  )]
+  CopyParam,
+}
+
+impl LintRule for NoParamReassign {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoParamReassignOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "props": {
+          "type": "boolean",
+          "description": "Also report modifications of properties of parameters.",
+          "default": false
+        },
+        "ignorePropertyModificationsFor": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Names of parameters whose properties may be modified."
+        },
+        "ignorePropertyModificationsForRegex": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Regular expressions matching names of parameters whose properties may be modified."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoParamReassignOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoParamReassignHandler { rule: self }.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_param_reassign.md")
+  }
+}
+
+struct NoParamReassignHandler<'a> {
+  rule: &'a NoParamReassign,
+}
+
+impl<'a> NoParamReassignHandler<'a> {
+  /// Checks every binding or property that is written to by `pat`.
+  fn check_pat(&self, span: Span, pat: &Pat, ctx: &mut Context) {
+    match pat {
+      Pat::Ident(binding) => self.check_ident(span, &binding.id, ctx),
+      Pat::Expr(expr) => self.check_expr(span, expr, ctx),
+      Pat::Array(array) => {
+        for elem in array.elems.iter().flatten() {
+          self.check_pat(span, elem, ctx);
+        }
+      }
+      Pat::Object(object) => {
+        for prop in &object.props {
+          match prop {
+            ObjectPatProp::KeyValue(kv) => self.check_pat(span, &kv.value, ctx),
+            ObjectPatProp::Assign(assign) => {
+              self.check_ident(span, &assign.key, ctx)
+            }
+            ObjectPatProp::Rest(rest) => self.check_pat(span, &rest.arg, ctx),
+          }
+        }
+      }
+      Pat::Assign(assign) => self.check_pat(span, &assign.left, ctx),
+      Pat::Rest(rest) => self.check_pat(span, &rest.arg, ctx),
+      Pat::Invalid(_) => {}
+    }
+  }
+
+  fn check_expr(&self, span: Span, expr: &Expr, ctx: &mut Context) {
+    match expr {
+      Expr::Ident(ident) => self.check_ident(span, ident, ctx),
+      Expr::Paren(paren) => self.check_expr(span, &paren.expr, ctx),
+      Expr::Member(_) => self.check_member(span, expr, ctx),
+      _ => {}
+    }
+  }
+
+  fn check_ident(&self, span: Span, ident: &Ident, ctx: &mut Context) {
+    if is_param(ctx, ident) {
+      ctx.add_diagnostic_with_hint(
+        span,
+        CODE,
+        NoParamReassignMessage::Param(ident.sym.to_string()),
+        NoParamReassignHint::UseLocalVariable,
+      );
+    }
+  }
+
+  /// Checks a modification of the property `expr`, e.g. `param.foo.bar`.
+  fn check_member(&self, span: Span, expr: &Expr, ctx: &mut Context) {
+    if !self.rule.options.props {
+      return;
+    }
+
+    if let Some(root) = root_ident(expr) {
+      if is_param(ctx, root) && !self.rule.is_ignored_for_props(&root.sym) {
+        ctx.add_diagnostic_with_hint(
+          span,
+          CODE,
+          NoParamReassignMessage::ParamProp(root.sym.to_string()),
+          NoParamReassignHint::CopyParam,
+        );
+      }
+    }
+  }
+}
+
+impl<'a> Handler for NoParamReassignHandler<'a> {
+  fn assign_expr(
+    &mut self,
+    assign_expr: &ast_view::AssignExpr,
+    ctx: &mut Context,
+  ) {
+    let span = assign_expr.span();
+    match &assign_expr.inner.left {
+      PatOrExpr::Pat(pat) => self.check_pat(span, pat, ctx),
+      PatOrExpr::Expr(expr) => self.check_expr(span, expr, ctx),
+    }
+  }
+
+  fn update_expr(
+    &mut self,
+    update_expr: &ast_view::UpdateExpr,
+    ctx: &mut Context,
+  ) {
+    self.check_expr(update_expr.span(), &update_expr.inner.arg, ctx);
+  }
+
+  fn unary_expr(
+    &mut self,
+    unary_expr: &ast_view::UnaryExpr,
+    ctx: &mut Context,
+  ) {
+    if unary_expr.op() == UnaryOp::Delete {
+      self.check_member(unary_expr.span(), &unary_expr.inner.arg, ctx);
+    }
+  }
+
+  fn for_in_stmt(&mut self, for_in: &ast_view::ForInStmt, ctx: &mut Context) {
+    if let VarDeclOrPat::Pat(pat) = &for_in.inner.left {
+      self.check_pat(pat.span(), pat, ctx);
+    }
+  }
+
+  fn for_of_stmt(&mut self, for_of: &ast_view::ForOfStmt, ctx: &mut Context) {
+    if let VarDeclOrPat::Pat(pat) = &for_of.inner.left {
+      self.check_pat(pat.span(), pat, ctx);
+    }
+  }
+}
+
+fn is_param(ctx: &Context, ident: &Ident) -> bool {
+  matches!(
+    ctx.scope().var(&ident.to_id()).map(|v| v.kind()),
+    Some(BindingKind::Param)
+  )
+}
+
+/// Returns the identifier that a member expression chain starts with, e.g.
+/// `foo` for `foo.bar[baz].qux`.
+fn root_ident(expr: &Expr) -> Option<&Ident> {
+  match expr {
+    Expr::Ident(ident) => Some(ident),
+    Expr::Paren(paren) => root_ident(&paren.expr),
+    Expr::Member(member) => match &member.obj {
+      ExprOrSuper::Expr(obj) => root_ident(obj),
+      ExprOrSuper::Super(_) => None,
+    },
+    Expr::TsNonNull(non_null) => root_ident(&non_null.expr),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.32.0/tests/lib/rules/no-param-reassign.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_param_reassign_valid() {
+    assert_lint_ok! {
+      NoParamReassign,
+      "function foo(a) { var b = a; }",
+      "function foo(a) { for (b in a); }",
+      "function foo(a) { for (b of a); }",
+      "function foo(a) { a.prop = 'value'; }",
+      "function foo(a) { for (a.prop in obj); }",
+      "function foo(a) { (function() { var a = 12; a++; })(); }",
+      "function foo() { someGlobal = 13; }",
+      "function foo() { someGlobal = 13; } var someGlobal;",
+      "function foo(a) { a.b = 0; }",
+      "function foo(a) { delete a.b; }",
+      "function foo(a) { ++a.b; }",
+      "function foo(a) { [a.b] = []; }",
+      "const foo = (a) => { let b = a; b = 1; };",
+      "class A { foo(a) { const b = a; } }",
+      "function foo(a) { function bar(a) {} a.b = 1; }",
+    };
+
+    assert_lint_ok! {
+      NoParamReassign,
+      options: NoParamReassignOptions {
+        props: true,
+        ..Default::default()
+      },
+      "function foo(a) { a.b; }",
+      "function foo(a) { a.b(); }",
+      "function foo(a) { const b = a; b.c = 0; }",
+      "function foo(a) { bar(a.b).c = 0; }",
+      "function foo(a) { a.get(0).b = 0; }",
+      "function foo(a) { delete b.c; }",
+    };
+
+    assert_lint_ok! {
+      NoParamReassign,
+      options: NoParamReassignOptions {
+        props: true,
+        ignore_property_modifications_for: vec!["a".to_string()],
+        ignore_property_modifications_for_regex: vec!["^acc".to_string()],
+      },
+      "function foo(a) { a.b = 0; }",
+      "function foo(a) { delete a.b; }",
+      "function foo(accumulator) { accumulator.b++; }",
+      "function foo(acc) { [acc.b] = []; }",
+    };
+  }
+
+  #[test]
+  fn no_param_reassign_invalid() {
+    assert_lint_err! {
+      NoParamReassign,
+      "function foo(bar) { bar = 13; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "function foo(bar) { bar += 13; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "function foo(bar) { (function() { bar = 13; })(); }": [
+        {
+          col: 34,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "function foo(bar) { ++bar; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "function foo(bar) { bar--; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "function foo({ bar }) { bar = 13; }": [
+        {
+          col: 24,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "function foo([, { bar }]) { bar = 13; }": [
+        {
+          col: 28,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "function foo(bar) { ({ bar } = {}); }": [
+        {
+          col: 21,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "function foo(bar) { [bar] = []; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "function foo(bar) { for (bar in baz); }": [
+        {
+          col: 25,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "function foo(bar) { for (bar of baz); }": [
+        {
+          col: 25,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "const foo = (bar) => { bar = 1; };": [
+        {
+          col: 23,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+      "class A { foo(bar) { bar = 1; } }": [
+        {
+          col: 21,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoParamReassign,
+      options: NoParamReassignOptions {
+        props: true,
+        ..Default::default()
+      },
+      "function foo(bar) { bar.a = 0; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::ParamProp("bar".to_string()),
+          hint: NoParamReassignHint::CopyParam,
+        }
+      ],
+      "function foo(bar) { delete bar.a; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::ParamProp("bar".to_string()),
+          hint: NoParamReassignHint::CopyParam,
+        }
+      ],
+      "function foo(bar) { ++bar.a; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::ParamProp("bar".to_string()),
+          hint: NoParamReassignHint::CopyParam,
+        }
+      ],
+      "function foo(bar) { for (bar.a in {}); }": [
+        {
+          col: 25,
+          message: NoParamReassignMessage::ParamProp("bar".to_string()),
+          hint: NoParamReassignHint::CopyParam,
+        }
+      ],
+      "function foo(bar) { [bar.a] = []; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::ParamProp("bar".to_string()),
+          hint: NoParamReassignHint::CopyParam,
+        }
+      ],
+      "function foo(bar) { ({ x: bar.a } = {}); }": [
+        {
+          col: 21,
+          message: NoParamReassignMessage::ParamProp("bar".to_string()),
+          hint: NoParamReassignHint::CopyParam,
+        }
+      ],
+      "function foo(bar) { bar = 1; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::Param("bar".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoParamReassign,
+      options: NoParamReassignOptions {
+        props: true,
+        ignore_property_modifications_for: vec!["a".to_string()],
+        ignore_property_modifications_for_regex: vec!["^acc".to_string()],
+      },
+      "function foo(bar) { bar.a = 0; }": [
+        {
+          col: 20,
+          message: NoParamReassignMessage::ParamProp("bar".to_string()),
+          hint: NoParamReassignHint::CopyParam,
+        }
+      ],
+      "function foo(a) { a = 0; }": [
+        {
+          col: 18,
+          message: NoParamReassignMessage::Param("a".to_string()),
+          hint: NoParamReassignHint::UseLocalVariable,
+        }
+      ],
+    };
+  }
+}
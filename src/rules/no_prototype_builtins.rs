@@ -1,11 +1,13 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
+use crate::diagnostic::LintFix;
 use crate::ProgramRef;
 use std::sync::Arc;
 
 use deno_ast::swc::ast::CallExpr;
 use deno_ast::swc::ast::Expr;
 use deno_ast::swc::ast::ExprOrSuper;
+use deno_ast::swc::common::Spanned;
 use deno_ast::swc::visit::noop_visit_type;
 use deno_ast::swc::visit::Node;
 use deno_ast::swc::visit::Visit;
@@ -17,6 +19,7 @@ const BANNED_PROPERTIES: &[&str] =
 pub struct NoPrototypeBuiltins;
 
 const CODE: &str = "no-prototype-builtins";
+const FIX_DESC: &str = "Use `Object.hasOwn` instead";
 
 fn get_message(prop: &str) -> String {
   format!(
@@ -25,6 +28,14 @@ fn get_message(prop: &str) -> String {
   )
 }
 
+fn get_hint(prop: &str) -> String {
+  if prop == "hasOwnProperty" {
+    "Use `Object.hasOwn(obj, prop)` or `Object.prototype.hasOwnProperty.call(obj, prop)` instead".to_string()
+  } else {
+    format!("Use `Object.prototype.{}.call(obj, ...)` instead", prop)
+  }
+}
+
 impl LintRule for NoPrototypeBuiltins {
   fn new() -> Arc<Self> {
     Arc::new(NoPrototypeBuiltins)
@@ -86,16 +97,54 @@ impl<'c, 'view> Visit for NoPrototypeBuiltinsVisitor<'c, 'view> {
     if let Expr::Ident(ident) = &*member_expr.prop {
       let prop_name = ident.sym.as_ref();
       if BANNED_PROPERTIES.contains(&prop_name) {
-        self.context.add_diagnostic(
+        let fixes =
+          self.has_own_fix(call_expr, prop_name).into_iter().collect();
+        self.context.add_diagnostic_with_fixes(
           call_expr.span,
           CODE,
           get_message(prop_name),
+          Some(get_hint(prop_name)),
+          fixes,
         );
       }
     }
   }
 }
 
+impl<'c, 'view> NoPrototypeBuiltinsVisitor<'c, 'view> {
+  /// Rewrites `foo.hasOwnProperty(bar)` to `Object.hasOwn(foo, bar)`.
+  fn has_own_fix(&self, call_expr: &CallExpr, prop: &str) -> Option<LintFix> {
+    if prop != "hasOwnProperty" {
+      return None;
+    }
+    let obj = match &call_expr.callee {
+      ExprOrSuper::Expr(expr) => match &**expr {
+        Expr::Member(member_expr) => match &member_expr.obj {
+          ExprOrSuper::Expr(obj) => obj,
+          ExprOrSuper::Super(_) => return None,
+        },
+        _ => return None,
+      },
+      ExprOrSuper::Super(_) => return None,
+    };
+    let arg = match call_expr.args.as_slice() {
+      [arg] if arg.spread.is_none() => &arg.expr,
+      _ => return None,
+    };
+
+    let replacement = format!(
+      "Object.hasOwn({}, {})",
+      self.context.file_text_substring(&obj.span()),
+      self.context.file_text_substring(&arg.span())
+    );
+    Some(LintFix {
+      description: FIX_DESC.to_string(),
+      changes: vec![self.context.fix_change(call_expr.span, replacement)],
+      applicability: None,
+    })
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -128,10 +177,51 @@ mod tests {
   fn no_prototype_builtins_invalid() {
     assert_lint_err! {
       NoPrototypeBuiltins,
-      "foo.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty")}],
-      "foo.isPrototypeOf('bar');": [{col: 0, message: get_message("isPrototypeOf")}],
-      "foo.propertyIsEnumerable('bar');": [{col: 0, message: get_message("propertyIsEnumerable")}],
-      "foo.bar.baz.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty")}],
+      "foo.hasOwnProperty('bar');": [
+        {
+          col: 0,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+          fix: (FIX_DESC, "Object.hasOwn(foo, 'bar');"),
+        }
+      ],
+      "foo.isPrototypeOf('bar');": [
+        {
+          col: 0,
+          message: get_message("isPrototypeOf"),
+          hint: get_hint("isPrototypeOf"),
+        }
+      ],
+      "foo.propertyIsEnumerable('bar');": [
+        {
+          col: 0,
+          message: get_message("propertyIsEnumerable"),
+          hint: get_hint("propertyIsEnumerable"),
+        }
+      ],
+      "foo.bar.baz.hasOwnProperty('bar');": [
+        {
+          col: 0,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+          fix: (FIX_DESC, "Object.hasOwn(foo.bar.baz, 'bar');"),
+        }
+      ],
+      "if ((a || b).hasOwnProperty(key)) {}": [
+        {
+          col: 4,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+          fix: (FIX_DESC, "if (Object.hasOwn((a || b), key)) {}"),
+        }
+      ],
+      "foo.hasOwnProperty(...args);": [
+        {
+          col: 0,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+        }
+      ],
     }
   }
 }
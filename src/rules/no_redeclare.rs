@@ -1,24 +1,43 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
-use super::{Context, LintRule, DUMMY_NODE};
-use crate::ProgramRef;
-use deno_ast::swc::visit::noop_visit_type;
-use deno_ast::swc::{
-  ast::*, utils::find_ids, utils::ident::IdentLike, utils::Id, visit::Node,
-  visit::Visit, visit::VisitWith,
-};
+use super::{Context, LintRule};
+use crate::scope_tree::Binding;
+use crate::scopes::BindingKind;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::Span;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use std::collections::HashSet;
-
 #[derive(Debug)]
-pub struct NoRedeclare;
+pub struct NoRedeclare {
+  options: NoRedeclareOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoRedeclareOptions {
+  /// Also report top-level declarations that redeclare globals like `Object`.
+  pub builtin_globals: bool,
+}
+
+impl NoRedeclare {
+  pub fn with_options(options: NoRedeclareOptions) -> Arc<Self> {
+    Arc::new(NoRedeclare { options })
+  }
+}
 
 const CODE: &str = "no-redeclare";
 const MESSAGE: &str = "Redeclaration is not allowed";
+const GLOBAL_MESSAGE: &str =
+  "Redeclaration of a global variable is not allowed";
+const RELATED_MESSAGE: &str = "First declared here";
 
 impl LintRule for NoRedeclare {
   fn new() -> Arc<Self> {
-    Arc::new(NoRedeclare)
+    Self::with_options(NoRedeclareOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -29,78 +48,104 @@ impl LintRule for NoRedeclare {
     CODE
   }
 
-  fn lint_program<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: ProgramRef<'view>,
-  ) {
-    let mut visitor = NoRedeclareVisitor {
-      context,
-      bindings: Default::default(),
-    };
-    match program {
-      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
-      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
-    }
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "builtinGlobals": {
+          "type": "boolean",
+          "description": "Also report top-level declarations that redeclare globals.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
   }
 
-  #[cfg(feature = "docs")]
-  fn docs(&self) -> &'static str {
-    include_str!("../../docs/rules/no_redeclare.md")
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoRedeclareOptions::default()).ok()
   }
-}
-
-struct NoRedeclareVisitor<'c, 'view> {
-  context: &'c mut Context<'view>,
-  /// TODO(kdy1): Change this to HashMap<Id, Vec<Span>> and use those spans to point previous bindings/
-  bindings: HashSet<Id>,
-}
-
-impl<'c, 'view> NoRedeclareVisitor<'c, 'view> {
-  fn declare(&mut self, i: &Ident) {
-    let id = i.to_id();
 
-    if !self.bindings.insert(id) {
-      self.context.add_diagnostic(i.span, CODE, MESSAGE);
-    }
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
   }
-}
-
-impl<'c, 'view> Visit for NoRedeclareVisitor<'c, 'view> {
-  noop_visit_type!();
-
-  fn visit_fn_decl(&mut self, f: &FnDecl, _: &dyn Node) {
-    if f.function.body.is_none() {
-      return;
-    }
 
-    self.declare(&f.ident);
-
-    f.visit_children_with(self);
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
   }
 
-  fn visit_var_declarator(&mut self, v: &VarDeclarator, _: &dyn Node) {
-    let ids: Vec<Ident> = find_ids(&v.name);
-
-    for id in ids {
-      self.declare(&id);
-    }
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
   }
 
-  fn visit_param(&mut self, p: &Param, _: &dyn Node) {
-    let ids: Vec<Ident> = find_ids(&p.pat);
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program<'_>,
+  ) {
+    let tree = context.scope_tree();
+    let mut redeclarations = Vec::new();
+    let mut globals = Vec::new();
+    for scope in tree.scopes() {
+      // The first declaration of each name in the scope
+      let mut first: HashMap<&JsWord, Span> = HashMap::new();
+      for binding in tree.scope(scope).bindings() {
+        let binding = tree.binding(*binding);
+        if !is_checked(binding) {
+          continue;
+        }
+        match first.entry(binding.name()) {
+          Entry::Occupied(entry) => {
+            redeclarations.push((binding.span(), *entry.get()));
+          }
+          Entry::Vacant(entry) => {
+            entry.insert(binding.span());
+            if self.options.builtin_globals
+              && scope == tree.root()
+              && context.is_global(binding.name())
+            {
+              globals.push(binding.span());
+            }
+          }
+        }
+      }
+    }
 
-    for id in ids {
-      self.declare(&id);
+    for span in globals {
+      context.add_diagnostic(span, CODE, GLOBAL_MESSAGE);
+    }
+    for (span, first) in redeclarations {
+      let related = context.related_information(first, RELATED_MESSAGE);
+      context.add_diagnostic_with_related(
+        span,
+        CODE,
+        MESSAGE,
+        None,
+        vec![related],
+      );
     }
   }
 
-  fn visit_class_prop(&mut self, p: &ClassProp, _: &dyn Node) {
-    if p.computed {
-      p.key.visit_with(p, self);
-    }
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_redeclare.md")
+  }
+}
 
-    p.value.visit_with(p, self);
+/// Checks if the binding is a variable, function or parameter, leaving out
+/// overload signatures, which are allowed to share the name with the
+/// implementation, and declarations that can be merged like enums.
+fn is_checked(binding: &Binding) -> bool {
+  match binding.kind() {
+    BindingKind::Var
+    | BindingKind::Let
+    | BindingKind::Const
+    | BindingKind::Param => true,
+    BindingKind::Function => !binding.is_overload(),
+    _ => false,
   }
 }
 
@@ -124,6 +169,18 @@ mod tests {
 
       // https://github.com/denoland/deno_lint/issues/615
       "class T { #foo(x) {} #bar(x) {} }",
+      "var Object = 0;",
+      "function f(a: string): void;\nfunction f(a: number): void;\nfunction f(a) {}",
+      "enum A { B }\nenum A { C = 1 }",
+      "try {} catch (e) { var e; }",
+    };
+
+    assert_lint_ok! {
+      NoRedeclare,
+      options: NoRedeclareOptions { builtin_globals: true },
+      "var a = 0; var b = 1;",
+      "function f() { var Object = 0; }",
+      "function f(Map) {}",
     };
   }
 
@@ -159,6 +216,37 @@ mod tests {
       "var a; var {a = 0, b: Object = 0} = {};": [{line: 1, col: 12, message: MESSAGE}],
       "var a; var {a = 0, b: globalThis = 0} = {};": [{line: 1, col: 12, message: MESSAGE}],
       "function f(foo: number, foo: string) {}": [{line: 1, col: 24, message: MESSAGE}],
+      "const f = (a) => { var a; };": [{col: 23, message: MESSAGE}],
+      "let a;\nfunction f() {}\nvar f;": [
+        {
+          line: 3,
+          col: 4,
+          message: MESSAGE,
+          related: (2, 9, RELATED_MESSAGE),
+        }
+      ],
+      "function f() { var a; if (test) { var a; } }": [
+        {
+          col: 38,
+          message: MESSAGE,
+          related: (1, 19, RELATED_MESSAGE),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoRedeclare,
+      options: NoRedeclareOptions { builtin_globals: true },
+      "var Object = 0;": [{col: 4, message: GLOBAL_MESSAGE}],
+      "let Map = 1; let Map = 2;": [
+        {col: 4, message: GLOBAL_MESSAGE},
+        {
+          col: 17,
+          message: MESSAGE,
+          related: (1, 4, RELATED_MESSAGE),
+        }
+      ],
+      "function parseInt() {}": [{col: 9, message: GLOBAL_MESSAGE}],
     }
   }
 }
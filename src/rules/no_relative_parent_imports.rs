@@ -0,0 +1,280 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::import_graph::normalize;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Lit, Str};
+use deno_ast::view as ast_view;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoRelativeParentImports {
+  options: NoRelativeParentImportsOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoRelativeParentImportsOptions {
+  /// Specifier prefixes that may be imported even though they reach into a
+  /// parent directory, e.g. `../shared/`.
+  pub allow: Vec<String>,
+}
+
+impl NoRelativeParentImports {
+  pub fn with_options(options: NoRelativeParentImportsOptions) -> Arc<Self> {
+    Arc::new(NoRelativeParentImports { options })
+  }
+}
+
+const CODE: &str = "no-relative-parent-imports";
+const MESSAGE: &str =
+  "Relative imports from parent directories are not allowed";
+const HINT: &str = "Import the module through an absolute path or an import map entry, or move it into the subtree of this module";
+
+impl LintRule for NoRelativeParentImports {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoRelativeParentImportsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allow": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Specifier prefixes that may reach into a parent directory, e.g. `../shared/`."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoRelativeParentImportsOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoRelativeParentImportsHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_relative_parent_imports.md")
+  }
+}
+
+/// Checks if a relative specifier leaves the directory of the importing
+/// module, also through `..` segments in the middle like `./a/../../b`.
+fn reaches_parent(specifier: &str) -> bool {
+  if specifier != "."
+    && specifier != ".."
+    && !specifier.starts_with("./")
+    && !specifier.starts_with("../")
+  {
+    return false;
+  }
+  let path = normalize(specifier);
+  path == ".." || path.starts_with("../")
+}
+
+struct NoRelativeParentImportsHandler<'a> {
+  options: &'a NoRelativeParentImportsOptions,
+}
+
+impl<'a> NoRelativeParentImportsHandler<'a> {
+  fn check_source(&self, ctx: &mut Context, src: &Str) {
+    let specifier = &*src.value;
+    if reaches_parent(specifier)
+      && !self
+        .options
+        .allow
+        .iter()
+        .any(|prefix| specifier.starts_with(prefix.as_str()))
+    {
+      ctx.add_diagnostic_with_hint(src.span, CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+impl<'a> Handler for NoRelativeParentImportsHandler<'a> {
+  fn import_decl(
+    &mut self,
+    import_decl: &ast_view::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    self.check_source(ctx, &import_decl.inner.src);
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    if let Some(src) = &named_export.inner.src {
+      self.check_source(ctx, src);
+    }
+  }
+
+  fn export_all(
+    &mut self,
+    export_all: &ast_view::ExportAll,
+    ctx: &mut Context,
+  ) {
+    self.check_source(ctx, &export_all.inner.src);
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let call_expr = call_expr.inner;
+    let is_import = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => {
+        matches!(&**callee, Expr::Ident(ident) if &*ident.sym == "import")
+      }
+      ExprOrSuper::Super(_) => false,
+    };
+    if !is_import {
+      return;
+    }
+    if let Some(arg) = call_expr.args.first() {
+      if let Expr::Lit(Lit::Str(src)) = &*arg.expr {
+        self.check_source(ctx, src);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/import-js/eslint-plugin-import/blob/v2.26.0/tests/src/rules/no-relative-parent-imports.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_relative_parent_imports_valid() {
+    assert_lint_ok! {
+      NoRelativeParentImports,
+      "import foo from './internal.ts';",
+      "import foo from './app/index.ts';",
+      "import foo from './a/../b.ts';",
+      "import foo from '.';",
+      "import foo from 'foo';",
+      "import foo from '/src/foo.ts';",
+      "import foo from 'https://deno.land/std/path/mod.ts';",
+      "import foo from '..foo';",
+      "export { foo } from './foo.ts';",
+      "export * from './foo.ts';",
+      "import('./app/index.ts');",
+      "import(foo);",
+      "require('../foo.ts');",
+    };
+
+    assert_lint_ok! {
+      NoRelativeParentImports,
+      options: NoRelativeParentImportsOptions {
+        allow: vec!["../shared/".to_string()],
+      },
+      "import foo from '../shared/foo.ts';",
+      "import('../shared/foo.ts');",
+    };
+  }
+
+  #[test]
+  fn no_relative_parent_imports_invalid() {
+    assert_lint_err! {
+      NoRelativeParentImports,
+      "import foo from '../plugin.ts';": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import foo from '..';": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import type { Foo } from '../../api/types.ts';": [
+        {
+          col: 25,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import foo from './a/../../b.ts';": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export { foo } from '../foo.ts';": [
+        {
+          col: 20,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export * from '../foo.ts';": [
+        {
+          col: 14,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import('../foo.ts');": [
+        {
+          col: 7,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoRelativeParentImports,
+      options: NoRelativeParentImportsOptions {
+        allow: vec!["../shared/".to_string()],
+      },
+      "import foo from '../other/foo.ts';": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,460 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Decl, ExportSpecifier, Ident, TsModuleName};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::utils::find_ids;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoRestrictedExports {
+  options: NoRestrictedExportsOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoRestrictedExportsOptions {
+  /// Names that must not be exported, e.g. `then`.
+  pub restricted_named_exports: Vec<String>,
+  pub restrict_default_exports: RestrictDefaultExports,
+}
+
+/// Kinds of default exports that are disallowed.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct RestrictDefaultExports {
+  /// `export default foo;` and `export default function foo() {}`
+  pub direct: bool,
+  /// `export { foo as default };`
+  pub named: bool,
+  /// `export { default } from "./mod.ts";`
+  pub default_from: bool,
+  /// `export { foo as default } from "./mod.ts";`
+  pub named_from: bool,
+  /// `export * as default from "./mod.ts";`
+  pub namespace_from: bool,
+}
+
+impl NoRestrictedExports {
+  pub fn with_options(options: NoRestrictedExportsOptions) -> Arc<Self> {
+    Arc::new(NoRestrictedExports { options })
+  }
+}
+
+const CODE: &str = "no-restricted-exports";
+
+#[derive(Display)]
+enum NoRestrictedExportsMessage {
+  #[display(
+    fmt = "`{}` is restricted from being used as an exported name",
+    _0
+  )]
+  RestrictedNamed(String),
+  #[display(fmt = "Exporting `default` is restricted")]
+  RestrictedDefault,
+}
+
+#[derive(Display)]
+enum NoRestrictedExportsHint {
+  #[display(fmt = "Export the value with a different name")]
+  Rename,
+  #[display(fmt = "Use a named export instead")]
+  UseNamedExport,
+}
+
+impl LintRule for NoRestrictedExports {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoRestrictedExportsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let restrict = |description: &str| {
+      json!({
+        "type": "boolean",
+        "description": description,
+        "default": false
+      })
+    };
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "restrictedNamedExports": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Names that must not be exported."
+        },
+        "restrictDefaultExports": {
+          "type": "object",
+          "properties": {
+            "direct": restrict("Disallow `export default` declarations."),
+            "named": restrict("Disallow `export { foo as default }`."),
+            "defaultFrom": restrict("Disallow `export { default } from`."),
+            "namedFrom": restrict("Disallow `export { foo as default } from`."),
+            "namespaceFrom": restrict("Disallow `export * as default from`.")
+          },
+          "additionalProperties": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoRestrictedExportsOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoRestrictedExportsHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_restricted_exports.md")
+  }
+}
+
+struct NoRestrictedExportsHandler<'a> {
+  options: &'a NoRestrictedExportsOptions,
+}
+
+impl<'a> NoRestrictedExportsHandler<'a> {
+  fn check_name(&self, ctx: &mut Context, ident: &Ident) {
+    let name: &str = &ident.sym;
+    if self
+      .options
+      .restricted_named_exports
+      .iter()
+      .any(|restricted| restricted == name)
+    {
+      ctx.add_diagnostic_with_hint(
+        ident.span,
+        CODE,
+        NoRestrictedExportsMessage::RestrictedNamed(name.to_string()),
+        NoRestrictedExportsHint::Rename,
+      );
+    }
+  }
+
+  fn report_default(&self, ctx: &mut Context, span: Span) {
+    ctx.add_diagnostic_with_hint(
+      span,
+      CODE,
+      NoRestrictedExportsMessage::RestrictedDefault,
+      NoRestrictedExportsHint::UseNamedExport,
+    );
+  }
+}
+
+impl<'a> Handler for NoRestrictedExportsHandler<'a> {
+  fn export_decl(
+    &mut self,
+    export_decl: &ast_view::ExportDecl,
+    ctx: &mut Context,
+  ) {
+    match &export_decl.inner.decl {
+      Decl::Class(class) => self.check_name(ctx, &class.ident),
+      Decl::Fn(function) => self.check_name(ctx, &function.ident),
+      Decl::Var(var) => {
+        for decl in &var.decls {
+          let idents: Vec<Ident> = find_ids(&decl.name);
+          for ident in &idents {
+            self.check_name(ctx, ident);
+          }
+        }
+      }
+      Decl::TsInterface(interface) => self.check_name(ctx, &interface.id),
+      Decl::TsTypeAlias(alias) => self.check_name(ctx, &alias.id),
+      Decl::TsEnum(enum_decl) => self.check_name(ctx, &enum_decl.id),
+      Decl::TsModule(module) => {
+        if let TsModuleName::Ident(ident) = &module.id {
+          self.check_name(ctx, ident);
+        }
+      }
+    }
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    let restrict = &self.options.restrict_default_exports;
+    let from = named_export.inner.src.is_some();
+
+    for specifier in &named_export.inner.specifiers {
+      match specifier {
+        ExportSpecifier::Named(named) => {
+          let exported = named.exported.as_ref().unwrap_or(&named.orig);
+          if exported.sym == *"default" {
+            let restricted = if !from {
+              restrict.named
+            } else if named.orig.sym == *"default" {
+              restrict.default_from
+            } else {
+              restrict.named_from
+            };
+            if restricted {
+              self.report_default(ctx, exported.span);
+              continue;
+            }
+          }
+          self.check_name(ctx, exported);
+        }
+        ExportSpecifier::Namespace(namespace) => {
+          if namespace.name.sym == *"default" && restrict.namespace_from {
+            self.report_default(ctx, namespace.name.span);
+            continue;
+          }
+          self.check_name(ctx, &namespace.name);
+        }
+        ExportSpecifier::Default(default) => {
+          self.check_name(ctx, &default.exported);
+        }
+      }
+    }
+  }
+
+  fn export_default_decl(
+    &mut self,
+    export_default_decl: &ast_view::ExportDefaultDecl,
+    ctx: &mut Context,
+  ) {
+    if self.options.restrict_default_exports.direct {
+      self.report_default(ctx, export_default_decl.span());
+    }
+  }
+
+  fn export_default_expr(
+    &mut self,
+    export_default_expr: &ast_view::ExportDefaultExpr,
+    ctx: &mut Context,
+  ) {
+    if self.options.restrict_default_exports.direct {
+      self.report_default(ctx, export_default_expr.span());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.33.0/tests/lib/rules/no-restricted-exports.js
+  // MIT Licensed.
+
+  fn restricted(names: &[&str]) -> NoRestrictedExportsOptions {
+    NoRestrictedExportsOptions {
+      restricted_named_exports: names.iter().map(|n| n.to_string()).collect(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn no_restricted_exports_valid() {
+    assert_lint_ok! {
+      NoRestrictedExports,
+      "export const then = 1;",
+      "export default foo;",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedExports,
+      options: restricted(&["x", "default"]),
+      "export var a;",
+      "export let a = 1, b = 2;",
+      "export function a() {}",
+      "export class A {}",
+      "export { a };",
+      "export { b as a };",
+      "export { a } from 'foo';",
+      "export * as a from 'foo';",
+      "export * from 'foo';",
+      "export default x;",
+      "export default function x() {}",
+      "var x; export { x as y };",
+      "export const { a, b: { c: d } } = obj;",
+      "export interface Foo {}",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedExports,
+      options: NoRestrictedExportsOptions {
+        restrict_default_exports: RestrictDefaultExports {
+          named: true,
+          ..Default::default()
+        },
+        ..Default::default()
+      },
+      "export default foo;",
+      "export { default } from 'mod';",
+      "export { foo as default } from 'mod';",
+      "export * as default from 'mod';",
+    };
+  }
+
+  #[test]
+  fn no_restricted_exports_invalid() {
+    assert_lint_err! {
+      NoRestrictedExports,
+      options: restricted(&["then", "x", "default"]),
+      "export const then = 1;": [
+        {
+          col: 13,
+          message: NoRestrictedExportsMessage::RestrictedNamed("then".to_string()),
+          hint: NoRestrictedExportsHint::Rename,
+        }
+      ],
+      "export function x() {}": [
+        {
+          col: 16,
+          message: NoRestrictedExportsMessage::RestrictedNamed("x".to_string()),
+          hint: NoRestrictedExportsHint::Rename,
+        }
+      ],
+      "export class x {}": [
+        {
+          col: 13,
+          message: NoRestrictedExportsMessage::RestrictedNamed("x".to_string()),
+          hint: NoRestrictedExportsHint::Rename,
+        }
+      ],
+      "export const { a, b: { c: x } } = obj;": [
+        {
+          col: 26,
+          message: NoRestrictedExportsMessage::RestrictedNamed("x".to_string()),
+          hint: NoRestrictedExportsHint::Rename,
+        }
+      ],
+      "let a; export { a as x };": [
+        {
+          col: 21,
+          message: NoRestrictedExportsMessage::RestrictedNamed("x".to_string()),
+          hint: NoRestrictedExportsHint::Rename,
+        }
+      ],
+      "let x; export { x };": [
+        {
+          col: 16,
+          message: NoRestrictedExportsMessage::RestrictedNamed("x".to_string()),
+          hint: NoRestrictedExportsHint::Rename,
+        }
+      ],
+      "export { then } from 'foo';": [
+        {
+          col: 9,
+          message: NoRestrictedExportsMessage::RestrictedNamed("then".to_string()),
+          hint: NoRestrictedExportsHint::Rename,
+        }
+      ],
+      "export * as x from 'foo';": [
+        {
+          col: 12,
+          message: NoRestrictedExportsMessage::RestrictedNamed("x".to_string()),
+          hint: NoRestrictedExportsHint::Rename,
+        }
+      ],
+      "let a; export { a as default };": [
+        {
+          col: 21,
+          message: NoRestrictedExportsMessage::RestrictedNamed("default".to_string()),
+          hint: NoRestrictedExportsHint::Rename,
+        }
+      ],
+      "export type x = string;": [
+        {
+          col: 12,
+          message: NoRestrictedExportsMessage::RestrictedNamed("x".to_string()),
+          hint: NoRestrictedExportsHint::Rename,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoRestrictedExports,
+      options: NoRestrictedExportsOptions {
+        restrict_default_exports: RestrictDefaultExports {
+          direct: true,
+          named: true,
+          default_from: true,
+          named_from: true,
+          namespace_from: true,
+        },
+        ..Default::default()
+      },
+      "export default foo;": [
+        {
+          col: 0,
+          message: NoRestrictedExportsMessage::RestrictedDefault,
+          hint: NoRestrictedExportsHint::UseNamedExport,
+        }
+      ],
+      "export default function () {}": [
+        {
+          col: 0,
+          message: NoRestrictedExportsMessage::RestrictedDefault,
+          hint: NoRestrictedExportsHint::UseNamedExport,
+        }
+      ],
+      "let foo; export { foo as default };": [
+        {
+          col: 25,
+          message: NoRestrictedExportsMessage::RestrictedDefault,
+          hint: NoRestrictedExportsHint::UseNamedExport,
+        }
+      ],
+      "export { default } from 'mod';": [
+        {
+          col: 9,
+          message: NoRestrictedExportsMessage::RestrictedDefault,
+          hint: NoRestrictedExportsHint::UseNamedExport,
+        }
+      ],
+      "export { foo as default } from 'mod';": [
+        {
+          col: 16,
+          message: NoRestrictedExportsMessage::RestrictedDefault,
+          hint: NoRestrictedExportsHint::UseNamedExport,
+        }
+      ],
+      "export * as default from 'mod';": [
+        {
+          col: 12,
+          message: NoRestrictedExportsMessage::RestrictedDefault,
+          hint: NoRestrictedExportsHint::UseNamedExport,
+        }
+      ],
+    };
+  }
+}
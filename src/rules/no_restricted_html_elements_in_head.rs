@@ -0,0 +1,300 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::no_restricted_jsx_elements::element_name;
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::jsx_attr_name;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::JSXAttrOrSpread;
+use deno_ast::swc::common::Spanned;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoRestrictedHtmlElementsInHead {
+  options: NoRestrictedHtmlElementsInHeadOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoRestrictedHtmlElementsInHeadOptions {
+  /// Names of the elements rendering the document head, e.g. `head` or the
+  /// `Head` component of a framework.
+  pub head_elements: Vec<String>,
+  /// Names of the elements that must not be used in the head.
+  pub elements: Vec<String>,
+  /// Names of the attributes that must not be used on elements in the head.
+  pub attributes: Vec<String>,
+}
+
+impl Default for NoRestrictedHtmlElementsInHeadOptions {
+  fn default() -> Self {
+    Self {
+      head_elements: vec!["head".to_string(), "Head".to_string()],
+      elements: vec![],
+      attributes: vec![],
+    }
+  }
+}
+
+impl NoRestrictedHtmlElementsInHead {
+  pub fn with_options(
+    options: NoRestrictedHtmlElementsInHeadOptions,
+  ) -> Arc<Self> {
+    Arc::new(NoRestrictedHtmlElementsInHead { options })
+  }
+}
+
+const CODE: &str = "no-restricted-html-elements-in-head";
+
+#[derive(Display)]
+enum NoRestrictedHtmlElementsInHeadMessage {
+  #[display(fmt = "`<{}>` is not allowed in the document head", _0)]
+  Element(String),
+  #[display(
+    fmt = "The `{}` attribute is not allowed in the document head",
+    _0
+  )]
+  Attribute(String),
+}
+
+#[derive(Display)]
+enum NoRestrictedHtmlElementsInHeadHint {
+  #[display(fmt = "Move the element out of `<{}>` or remove it", _0)]
+  MoveElement(String),
+  #[display(fmt = "Remove the attribute")]
+  RemoveAttribute,
+}
+
+impl LintRule for NoRestrictedHtmlElementsInHead {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoRestrictedHtmlElementsInHeadOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let names = |description: &str| {
+      json!({
+        "type": "array",
+        "items": { "type": "string" },
+        "description": description
+      })
+    };
+    let mut head_elements =
+      names("Names of the elements rendering the document head.");
+    head_elements["default"] = json!(["head", "Head"]);
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "headElements": head_elements,
+        "elements": names("Names of the elements that must not be used in the head."),
+        "attributes": names("Names of the attributes that must not be used on elements in the head.")
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoRestrictedHtmlElementsInHeadOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoRestrictedHtmlElementsInHeadHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_restricted_html_elements_in_head.md")
+  }
+}
+
+struct NoRestrictedHtmlElementsInHeadHandler<'a> {
+  options: &'a NoRestrictedHtmlElementsInHeadOptions,
+}
+
+impl<'a> NoRestrictedHtmlElementsInHeadHandler<'a> {
+  /// Returns the name of the head element `jsx_element` is nested in, also
+  /// through expressions like `{links.map((l) => <link {...l} />)}`.
+  fn enclosing_head(
+    &self,
+    jsx_element: &ast_view::JSXElement,
+  ) -> Option<String> {
+    jsx_element.ancestors().find_map(|ancestor| match ancestor {
+      Node::JSXElement(element) => {
+        let name = element_name(&element.inner.opening.name);
+        if self.options.head_elements.contains(&name) {
+          Some(name)
+        } else {
+          None
+        }
+      }
+      _ => None,
+    })
+  }
+}
+
+impl<'a> Handler for NoRestrictedHtmlElementsInHeadHandler<'a> {
+  fn jsx_element(
+    &mut self,
+    jsx_element: &ast_view::JSXElement,
+    ctx: &mut Context,
+  ) {
+    let head = match self.enclosing_head(jsx_element) {
+      Some(head) => head,
+      None => return,
+    };
+    let opening = &jsx_element.inner.opening;
+    let name = element_name(&opening.name);
+    if self.options.elements.contains(&name) {
+      ctx.add_diagnostic_with_hint(
+        opening.span,
+        CODE,
+        NoRestrictedHtmlElementsInHeadMessage::Element(name),
+        NoRestrictedHtmlElementsInHeadHint::MoveElement(head),
+      );
+    }
+
+    for attr in &opening.attrs {
+      let attr = match attr {
+        JSXAttrOrSpread::JSXAttr(attr) => attr,
+        JSXAttrOrSpread::SpreadElement(_) => continue,
+      };
+      let name = jsx_attr_name(&attr.name);
+      if self.options.attributes.contains(&name) {
+        ctx.add_diagnostic_with_hint(
+          attr.span(),
+          CODE,
+          NoRestrictedHtmlElementsInHeadMessage::Attribute(name),
+          NoRestrictedHtmlElementsInHeadHint::RemoveAttribute,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn options() -> NoRestrictedHtmlElementsInHeadOptions {
+    NoRestrictedHtmlElementsInHeadOptions {
+      elements: vec!["div".to_string(), "img".to_string()],
+      attributes: vec!["onLoad".to_string(), "xlink:href".to_string()],
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn no_restricted_html_elements_in_head_valid() {
+    assert_lint_ok! {
+      NoRestrictedHtmlElementsInHead,
+      filename: "foo.tsx",
+      "<head><div /></head>",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedHtmlElementsInHead,
+      options: options(),
+      filename: "foo.tsx",
+      "<head><title>Foo</title><meta charset=\"utf-8\" /></head>",
+      "<body><div><img onLoad={f} /></div></body>",
+      "<html><head><title /></head><body><div /></body></html>",
+      "<head onLoad={f}><link rel=\"icon\" /></head>",
+      "<Header><div /></Header>",
+      "<div />",
+    };
+  }
+
+  #[test]
+  fn no_restricted_html_elements_in_head_invalid() {
+    assert_lint_err! {
+      NoRestrictedHtmlElementsInHead,
+      options: options(),
+      filename: "foo.tsx",
+      "<head><div>Foo</div></head>": [
+        {
+          col: 6,
+          message: NoRestrictedHtmlElementsInHeadMessage::Element("div".to_string()),
+          hint: NoRestrictedHtmlElementsInHeadHint::MoveElement("head".to_string()),
+        }
+      ],
+      "<Head><title>Foo</title><img src=\"a.png\" /></Head>": [
+        {
+          col: 24,
+          message: NoRestrictedHtmlElementsInHeadMessage::Element("img".to_string()),
+          hint: NoRestrictedHtmlElementsInHeadHint::MoveElement("Head".to_string()),
+        }
+      ],
+      "<html><head><noscript><div /></noscript></head></html>": [
+        {
+          col: 22,
+          message: NoRestrictedHtmlElementsInHeadMessage::Element("div".to_string()),
+          hint: NoRestrictedHtmlElementsInHeadHint::MoveElement("head".to_string()),
+        }
+      ],
+      "<head>{links.map((link) => <link href={link} onLoad={f} />)}</head>": [
+        {
+          col: 45,
+          message: NoRestrictedHtmlElementsInHeadMessage::Attribute("onLoad".to_string()),
+          hint: NoRestrictedHtmlElementsInHeadHint::RemoveAttribute,
+        }
+      ],
+      "<head><div xlink:href=\"#a\" /></head>": [
+        {
+          col: 6,
+          message: NoRestrictedHtmlElementsInHeadMessage::Element("div".to_string()),
+          hint: NoRestrictedHtmlElementsInHeadHint::MoveElement("head".to_string()),
+        },
+        {
+          col: 11,
+          message: NoRestrictedHtmlElementsInHeadMessage::Attribute("xlink:href".to_string()),
+          hint: NoRestrictedHtmlElementsInHeadHint::RemoveAttribute,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoRestrictedHtmlElementsInHead,
+      options: NoRestrictedHtmlElementsInHeadOptions {
+        head_elements: vec!["Meta.Head".to_string()],
+        elements: vec!["script".to_string()],
+        attributes: vec![],
+      },
+      filename: "foo.tsx",
+      "<Meta.Head><script src=\"a.js\" /></Meta.Head>": [
+        {
+          col: 11,
+          message: NoRestrictedHtmlElementsInHeadMessage::Element("script".to_string()),
+          hint: NoRestrictedHtmlElementsInHeadHint::MoveElement("Meta.Head".to_string()),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,336 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{JSXElementName, JSXObject};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoRestrictedJsxElements {
+  options: NoRestrictedJsxElementsOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoRestrictedJsxElementsOptions {
+  pub elements: Vec<RestrictedJsxElement>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RestrictedJsxElement {
+  /// The element name as written in JSX, e.g. `marquee`, `Foo`, `Foo.Bar`
+  /// or `svg:rect`.
+  pub name: String,
+  /// A message reported instead of the default one, e.g. to explain why the
+  /// element is restricted.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+  /// The name of an element to use instead, which is proposed as a fix.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub replacement: Option<String>,
+}
+
+impl NoRestrictedJsxElements {
+  pub fn with_options(options: NoRestrictedJsxElementsOptions) -> Arc<Self> {
+    Arc::new(NoRestrictedJsxElements { options })
+  }
+}
+
+const CODE: &str = "no-restricted-jsx-elements";
+
+#[derive(Display)]
+enum NoRestrictedJsxElementsMessage {
+  #[display(fmt = "`<{}>` is restricted from being used", _0)]
+  Restricted(String),
+}
+
+#[derive(Display)]
+enum NoRestrictedJsxElementsHint {
+  #[display(fmt = "Use `<{}>` instead", _0)]
+  Replace(String),
+  #[display(fmt = "Remove the `<{}>` element", _0)]
+  Remove(String),
+}
+
+#[derive(Display)]
+enum NoRestrictedJsxElementsFix {
+  #[display(fmt = "Replace with `<{}>`", _0)]
+  Replace(String),
+}
+
+impl LintRule for NoRestrictedJsxElements {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoRestrictedJsxElementsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "elements": {
+          "type": "array",
+          "items": {
+            "type": "object",
+            "properties": {
+              "name": {
+                "type": "string",
+                "description": "The element name, e.g. `marquee` or `Foo.Bar`."
+              },
+              "message": {
+                "type": "string",
+                "description": "A custom message to report."
+              },
+              "replacement": {
+                "type": "string",
+                "description": "The name of an element to use instead."
+              }
+            },
+            "required": ["name"],
+            "additionalProperties": false
+          },
+          "description": "Elements that must not be used."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoRestrictedJsxElementsOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoRestrictedJsxElementsHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_restricted_jsx_elements.md")
+  }
+}
+
+pub(crate) fn element_name(name: &JSXElementName) -> String {
+  fn object_name(object: &JSXObject) -> String {
+    match object {
+      JSXObject::Ident(ident) => ident.sym.to_string(),
+      JSXObject::JSXMemberExpr(member) => {
+        format!("{}.{}", object_name(&member.obj), member.prop.sym)
+      }
+    }
+  }
+
+  match name {
+    JSXElementName::Ident(ident) => ident.sym.to_string(),
+    JSXElementName::JSXMemberExpr(member) => {
+      format!("{}.{}", object_name(&member.obj), member.prop.sym)
+    }
+    JSXElementName::JSXNamespacedName(name) => {
+      format!("{}:{}", name.ns.sym, name.name.sym)
+    }
+  }
+}
+
+struct NoRestrictedJsxElementsHandler<'a> {
+  options: &'a NoRestrictedJsxElementsOptions,
+}
+
+impl<'a> Handler for NoRestrictedJsxElementsHandler<'a> {
+  fn jsx_element(
+    &mut self,
+    jsx_element: &ast_view::JSXElement,
+    ctx: &mut Context,
+  ) {
+    let opening = &jsx_element.inner.opening;
+    let name = element_name(&opening.name);
+    let restricted = match self
+      .options
+      .elements
+      .iter()
+      .find(|element| element.name == name)
+    {
+      Some(restricted) => restricted,
+      None => return,
+    };
+
+    let message = match &restricted.message {
+      Some(message) => message.clone(),
+      None => {
+        NoRestrictedJsxElementsMessage::Restricted(name.clone()).to_string()
+      }
+    };
+    match &restricted.replacement {
+      Some(replacement) => {
+        let mut changes =
+          vec![ctx.fix_change(opening.name.span(), replacement)];
+        if let Some(closing) = &jsx_element.inner.closing {
+          changes.push(ctx.fix_change(closing.name.span(), replacement));
+        }
+        ctx.add_diagnostic_with_fixes(
+          opening.span,
+          CODE,
+          message,
+          Some(
+            NoRestrictedJsxElementsHint::Replace(replacement.clone())
+              .to_string(),
+          ),
+          vec![LintFix {
+            description: NoRestrictedJsxElementsFix::Replace(
+              replacement.clone(),
+            )
+            .to_string(),
+            changes,
+            applicability: None,
+          }],
+        );
+      }
+      None => {
+        ctx.add_diagnostic_with_hint(
+          opening.span,
+          CODE,
+          message,
+          NoRestrictedJsxElementsHint::Remove(name),
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn options() -> NoRestrictedJsxElementsOptions {
+    NoRestrictedJsxElementsOptions {
+      elements: vec![
+        RestrictedJsxElement {
+          name: "marquee".to_string(),
+          message: None,
+          replacement: None,
+        },
+        RestrictedJsxElement {
+          name: "OldButton".to_string(),
+          message: Some("`OldButton` is deprecated".to_string()),
+          replacement: Some("Button".to_string()),
+        },
+        RestrictedJsxElement {
+          name: "UI.Legacy".to_string(),
+          message: None,
+          replacement: Some("UI.Modern".to_string()),
+        },
+        RestrictedJsxElement {
+          name: "svg:blink".to_string(),
+          message: None,
+          replacement: None,
+        },
+      ],
+    }
+  }
+
+  #[test]
+  fn no_restricted_jsx_elements_valid() {
+    assert_lint_ok! {
+      NoRestrictedJsxElements,
+      filename: "foo.tsx",
+      "<marquee />",
+      "<OldButton />",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedJsxElements,
+      options: options(),
+      filename: "foo.tsx",
+      "<div />",
+      "<Marquee />",
+      "<Button />",
+      "<UI.Modern />",
+      "<UI.Legacy.Inner />",
+      "<svg:rect />",
+      "const marquee = 1; <div>{marquee}</div>;",
+    };
+  }
+
+  #[test]
+  fn no_restricted_jsx_elements_invalid() {
+    assert_lint_err! {
+      NoRestrictedJsxElements,
+      options: options(),
+      filename: "foo.tsx",
+      "<marquee>Hello</marquee>": [
+        {
+          col: 0,
+          message: NoRestrictedJsxElementsMessage::Restricted("marquee".to_string()),
+          hint: NoRestrictedJsxElementsHint::Remove("marquee".to_string()),
+        }
+      ],
+      "<div><marquee /></div>": [
+        {
+          col: 5,
+          message: NoRestrictedJsxElementsMessage::Restricted("marquee".to_string()),
+          hint: NoRestrictedJsxElementsHint::Remove("marquee".to_string()),
+        }
+      ],
+      "<OldButton onClick={f}>Save</OldButton>": [
+        {
+          col: 0,
+          message: "`OldButton` is deprecated",
+          hint: NoRestrictedJsxElementsHint::Replace("Button".to_string()),
+          fix: (
+            NoRestrictedJsxElementsFix::Replace("Button".to_string()),
+            "<Button onClick={f}>Save</Button>",
+          ),
+        }
+      ],
+      "<UI.Legacy />": [
+        {
+          col: 0,
+          message: NoRestrictedJsxElementsMessage::Restricted("UI.Legacy".to_string()),
+          hint: NoRestrictedJsxElementsHint::Replace("UI.Modern".to_string()),
+          fix: (
+            NoRestrictedJsxElementsFix::Replace("UI.Modern".to_string()),
+            "<UI.Modern />",
+          ),
+        }
+      ],
+      "<svg:blink />": [
+        {
+          col: 0,
+          message: NoRestrictedJsxElementsMessage::Restricted("svg:blink".to_string()),
+          hint: NoRestrictedJsxElementsHint::Remove("svg:blink".to_string()),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,314 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Lit};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoRestrictedModules {
+  options: NoRestrictedModulesOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoRestrictedModulesOptions {
+  /// Modules that must not be loaded by `import` declarations or
+  /// `export ... from` declarations.
+  pub static_imports: Vec<String>,
+  /// Modules that must not be loaded by dynamic `import()` calls.
+  pub dynamic_imports: Vec<String>,
+}
+
+impl NoRestrictedModules {
+  pub fn with_options(options: NoRestrictedModulesOptions) -> Arc<Self> {
+    Arc::new(NoRestrictedModules { options })
+  }
+}
+
+const CODE: &str = "no-restricted-modules";
+
+#[derive(Display)]
+enum NoRestrictedModulesMessage {
+  #[display(fmt = "`{}` must not be imported statically", _0)]
+  Static(String),
+  #[display(fmt = "`{}` must not be imported dynamically", _0)]
+  Dynamic(String),
+}
+
+#[derive(Display)]
+enum NoRestrictedModulesHint {
+  #[display(fmt = "Load the module on demand with `import()` instead")]
+  UseDynamic,
+  #[display(fmt = "Use an `import` declaration instead")]
+  UseStatic,
+  #[display(fmt = "Remove the import, this module is not allowed")]
+  Remove,
+}
+
+impl LintRule for NoRestrictedModules {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoRestrictedModulesOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let modules = |description: &str| {
+      json!({
+        "type": "array",
+        "items": { "type": "string" },
+        "description": description
+      })
+    };
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "staticImports": modules("Modules that must not be imported by `import` or `export ... from` declarations."),
+        "dynamicImports": modules("Modules that must not be imported by `import()` calls.")
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoRestrictedModulesOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoRestrictedModulesHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_restricted_modules.md")
+  }
+}
+
+struct NoRestrictedModulesHandler<'a> {
+  options: &'a NoRestrictedModulesOptions,
+}
+
+impl<'a> NoRestrictedModulesHandler<'a> {
+  fn check_static(&self, ctx: &mut Context, span: Span, specifier: &str) {
+    if !is_restricted(&self.options.static_imports, specifier) {
+      return;
+    }
+    let hint = if is_restricted(&self.options.dynamic_imports, specifier) {
+      NoRestrictedModulesHint::Remove
+    } else {
+      NoRestrictedModulesHint::UseDynamic
+    };
+    ctx.add_diagnostic_with_hint(
+      span,
+      CODE,
+      NoRestrictedModulesMessage::Static(specifier.to_string()),
+      hint,
+    );
+  }
+
+  fn check_dynamic(&self, ctx: &mut Context, span: Span, specifier: &str) {
+    if !is_restricted(&self.options.dynamic_imports, specifier) {
+      return;
+    }
+    let hint = if is_restricted(&self.options.static_imports, specifier) {
+      NoRestrictedModulesHint::Remove
+    } else {
+      NoRestrictedModulesHint::UseStatic
+    };
+    ctx.add_diagnostic_with_hint(
+      span,
+      CODE,
+      NoRestrictedModulesMessage::Dynamic(specifier.to_string()),
+      hint,
+    );
+  }
+}
+
+fn is_restricted(modules: &[String], specifier: &str) -> bool {
+  modules.iter().any(|module| module == specifier)
+}
+
+impl<'a> Handler for NoRestrictedModulesHandler<'a> {
+  fn import_decl(
+    &mut self,
+    import_decl: &ast_view::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    // Type-only imports are erased, so they never load the module.
+    if import_decl.inner.type_only {
+      return;
+    }
+    self.check_static(ctx, import_decl.span(), &import_decl.inner.src.value);
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    if named_export.inner.type_only {
+      return;
+    }
+    if let Some(src) = &named_export.inner.src {
+      self.check_static(ctx, named_export.span(), &src.value);
+    }
+  }
+
+  fn export_all(
+    &mut self,
+    export_all: &ast_view::ExportAll,
+    ctx: &mut Context,
+  ) {
+    self.check_static(ctx, export_all.span(), &export_all.inner.src.value);
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let call_expr = call_expr.inner;
+    let is_import = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => {
+        matches!(&**callee, Expr::Ident(ident) if &*ident.sym == "import")
+      }
+      ExprOrSuper::Super(_) => false,
+    };
+    if !is_import {
+      return;
+    }
+    // Only string literals can be checked statically.
+    if let Some(arg) = call_expr.args.first() {
+      if let Expr::Lit(Lit::Str(specifier)) = &*arg.expr {
+        self.check_dynamic(ctx, call_expr.span, &specifier.value);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn options() -> NoRestrictedModulesOptions {
+    NoRestrictedModulesOptions {
+      static_imports: vec!["heavy".to_string(), "banned".to_string()],
+      dynamic_imports: vec!["eager".to_string(), "banned".to_string()],
+    }
+  }
+
+  #[test]
+  fn no_restricted_modules_valid() {
+    assert_lint_ok! {
+      NoRestrictedModules,
+      "import heavy from 'heavy';",
+      "const eager = await import('eager');",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedModules,
+      options: options(),
+      "import foo from 'foo';",
+      "import eager from 'eager';",
+      "export { eager } from 'eager';",
+      "export * from 'eager';",
+      "const heavy = await import('heavy');",
+      "button.onclick = () => import('heavy').then((m) => m.run());",
+      "import type { Heavy } from 'heavy';",
+      "export type { Heavy } from 'heavy';",
+      "import heavy from 'heavy/light';",
+      "const eager = await import(specifier);",
+      "const foo = load('eager');",
+    };
+  }
+
+  #[test]
+  fn no_restricted_modules_invalid() {
+    assert_lint_err! {
+      NoRestrictedModules,
+      options: options(),
+      "import heavy from 'heavy';": [
+        {
+          col: 0,
+          message: NoRestrictedModulesMessage::Static("heavy".to_string()),
+          hint: NoRestrictedModulesHint::UseDynamic,
+        }
+      ],
+      "import 'heavy';": [
+        {
+          col: 0,
+          message: NoRestrictedModulesMessage::Static("heavy".to_string()),
+          hint: NoRestrictedModulesHint::UseDynamic,
+        }
+      ],
+      "export { run } from 'heavy';": [
+        {
+          col: 0,
+          message: NoRestrictedModulesMessage::Static("heavy".to_string()),
+          hint: NoRestrictedModulesHint::UseDynamic,
+        }
+      ],
+      "export * from 'heavy';": [
+        {
+          col: 0,
+          message: NoRestrictedModulesMessage::Static("heavy".to_string()),
+          hint: NoRestrictedModulesHint::UseDynamic,
+        }
+      ],
+      "const eager = await import('eager');": [
+        {
+          col: 20,
+          message: NoRestrictedModulesMessage::Dynamic("eager".to_string()),
+          hint: NoRestrictedModulesHint::UseStatic,
+        }
+      ],
+      "function load() { return import('eager'); }": [
+        {
+          col: 25,
+          message: NoRestrictedModulesMessage::Dynamic("eager".to_string()),
+          hint: NoRestrictedModulesHint::UseStatic,
+        }
+      ],
+      "import banned from 'banned';": [
+        {
+          col: 0,
+          message: NoRestrictedModulesMessage::Static("banned".to_string()),
+          hint: NoRestrictedModulesHint::Remove,
+        }
+      ],
+      "const banned = import('banned');": [
+        {
+          col: 15,
+          message: NoRestrictedModulesMessage::Dynamic("banned".to_string()),
+          hint: NoRestrictedModulesHint::Remove,
+        }
+      ],
+    };
+  }
+}
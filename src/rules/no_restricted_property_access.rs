@@ -0,0 +1,345 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::as_member;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, MemberExpr};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoRestrictedPropertyAccess {
+  options: NoRestrictedPropertyAccessOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoRestrictedPropertyAccessOptions {
+  /// The maximum number of names in a property access chain, including the
+  /// root, e.g. `a.b.c.d` has a depth of 4.
+  pub max_depth: usize,
+  /// Roots of chains that are never reported, e.g. `this.props`.
+  pub ignore: Vec<String>,
+}
+
+impl Default for NoRestrictedPropertyAccessOptions {
+  fn default() -> Self {
+    NoRestrictedPropertyAccessOptions {
+      max_depth: 4,
+      ignore: vec![],
+    }
+  }
+}
+
+impl NoRestrictedPropertyAccess {
+  pub fn with_options(options: NoRestrictedPropertyAccessOptions) -> Arc<Self> {
+    Arc::new(NoRestrictedPropertyAccess { options })
+  }
+}
+
+const CODE: &str = "no-restricted-property-access";
+
+#[derive(Display)]
+enum NoRestrictedPropertyAccessMessage {
+  #[display(
+    fmt = "Property access chain has a depth of {}, the maximum allowed is {}",
+    _0,
+    _1
+  )]
+  TooDeep(usize, usize),
+}
+
+#[derive(Display)]
+enum NoRestrictedPropertyAccessHint {
+  #[display(
+    fmt = "Store an intermediate object in a variable, or ask the object closer to the data for it"
+  )]
+  Shorten,
+}
+
+impl LintRule for NoRestrictedPropertyAccess {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoRestrictedPropertyAccessOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "maxDepth": {
+          "type": "integer",
+          "minimum": 1,
+          "description": "The maximum number of names in a property access chain, including the root.",
+          "default": 4
+        },
+        "ignore": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Roots of chains that are never reported, e.g. `this.props`."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoRestrictedPropertyAccessOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoRestrictedPropertyAccessHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_restricted_property_access.md")
+  }
+}
+
+/// A property access chain like `a.b[c].d`.
+struct Chain {
+  /// The number of names in the chain, including the root.
+  depth: usize,
+  /// The dotted path from the root up to the first computed or non-name
+  /// segment, e.g. `a.b` for `a.b[c].d`.
+  path: Option<String>,
+}
+
+fn chain(member: &MemberExpr) -> Chain {
+  let mut props = vec![];
+  let mut current = member;
+  let root = loop {
+    props.push(match (&*current.prop, current.computed) {
+      (Expr::Ident(ident), false) => Some(ident.sym.to_string()),
+      _ => None,
+    });
+    match &current.obj {
+      ExprOrSuper::Expr(obj) => match &**obj {
+        Expr::Ident(ident) => break Some(ident.sym.to_string()),
+        Expr::This(_) => break Some("this".to_string()),
+        obj => match as_member(obj) {
+          Some(member) => current = member,
+          None => break None,
+        },
+      },
+      ExprOrSuper::Super(_) => break Some("super".to_string()),
+    }
+  };
+
+  let depth = props.len() + 1;
+  let path = root.map(|root| {
+    let mut path = root;
+    for prop in props.into_iter().rev() {
+      match prop {
+        Some(prop) => {
+          path.push('.');
+          path.push_str(&prop);
+        }
+        None => break,
+      }
+    }
+    path
+  });
+  Chain { depth, path }
+}
+
+/// Checks if `member_expr` is the object of another property access, in
+/// which case the chain is reported at the outermost one.
+fn is_inner_access(member_expr: &ast_view::MemberExpr) -> bool {
+  let (parent, span) = match member_expr.parent() {
+    ast_view::Node::OptChainExpr(opt_chain) => {
+      (opt_chain.parent(), opt_chain.span())
+    }
+    parent => (parent, member_expr.span()),
+  };
+  match parent {
+    ast_view::Node::MemberExpr(parent) => {
+      matches!(&parent.inner.obj, ExprOrSuper::Expr(obj) if obj.span() == span)
+    }
+    _ => false,
+  }
+}
+
+struct NoRestrictedPropertyAccessHandler<'a> {
+  options: &'a NoRestrictedPropertyAccessOptions,
+}
+
+impl<'a> NoRestrictedPropertyAccessHandler<'a> {
+  fn is_ignored(&self, path: &str) -> bool {
+    self.options.ignore.iter().any(|root| {
+      path == root
+        || (path.starts_with(root.as_str())
+          && path[root.len()..].starts_with('.'))
+    })
+  }
+}
+
+impl<'a> Handler for NoRestrictedPropertyAccessHandler<'a> {
+  fn member_expr(
+    &mut self,
+    member_expr: &ast_view::MemberExpr,
+    ctx: &mut Context,
+  ) {
+    if is_inner_access(member_expr) {
+      return;
+    }
+    let chain = chain(member_expr.inner);
+    if chain.depth <= self.options.max_depth {
+      return;
+    }
+    if matches!(&chain.path, Some(path) if self.is_ignored(path)) {
+      return;
+    }
+    ctx.add_diagnostic_with_hint(
+      member_expr.span(),
+      CODE,
+      NoRestrictedPropertyAccessMessage::TooDeep(
+        chain.depth,
+        self.options.max_depth,
+      ),
+      NoRestrictedPropertyAccessHint::Shorten,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_restricted_property_access_valid() {
+    assert_lint_ok! {
+      NoRestrictedPropertyAccess,
+      "a.b.c.d;",
+      "a.b.c.d = 1;",
+      "this.a.b.c;",
+      "a.b.c.d();",
+      "a.b().c.d.e;",
+      "foo.bar().baz().qux().quux();",
+      "a[b.c.d].e.f;",
+      "a?.b.c.d;",
+      "super.a.b.c;",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedPropertyAccess,
+      options: NoRestrictedPropertyAccessOptions {
+        max_depth: 2,
+        ignore: vec!["this.props".to_string(), "Deno".to_string()],
+      },
+      "a.b;",
+      "this.props.user.address.city;",
+      "this.props;",
+      "Deno.permissions.request;",
+      "Deno[name].foo.bar;",
+    };
+  }
+
+  #[test]
+  fn no_restricted_property_access_invalid() {
+    assert_lint_err! {
+      NoRestrictedPropertyAccess,
+      "a.b.c.d.e;": [
+        {
+          col: 0,
+          message: NoRestrictedPropertyAccessMessage::TooDeep(5, 4),
+          hint: NoRestrictedPropertyAccessHint::Shorten,
+        }
+      ],
+      "const city = this.props.user.address.city;": [
+        {
+          col: 13,
+          message: NoRestrictedPropertyAccessMessage::TooDeep(5, 4),
+          hint: NoRestrictedPropertyAccessHint::Shorten,
+        }
+      ],
+      "a.b[0].c.d.e();": [
+        {
+          col: 0,
+          message: NoRestrictedPropertyAccessMessage::TooDeep(6, 4),
+          hint: NoRestrictedPropertyAccessHint::Shorten,
+        }
+      ],
+      "a?.b.c?.d.e;": [
+        {
+          col: 0,
+          message: NoRestrictedPropertyAccessMessage::TooDeep(5, 4),
+          hint: NoRestrictedPropertyAccessHint::Shorten,
+        }
+      ],
+      "a[b.c.d.e.f];": [
+        {
+          col: 2,
+          message: NoRestrictedPropertyAccessMessage::TooDeep(5, 4),
+          hint: NoRestrictedPropertyAccessHint::Shorten,
+        }
+      ],
+      "foo().a.b.c.d;": [
+        {
+          col: 0,
+          message: NoRestrictedPropertyAccessMessage::TooDeep(5, 4),
+          hint: NoRestrictedPropertyAccessHint::Shorten,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoRestrictedPropertyAccess,
+      options: NoRestrictedPropertyAccessOptions {
+        max_depth: 2,
+        ignore: vec!["this.props".to_string()],
+      },
+      "this.state.user;": [
+        {
+          col: 0,
+          message: NoRestrictedPropertyAccessMessage::TooDeep(3, 2),
+          hint: NoRestrictedPropertyAccessHint::Shorten,
+        }
+      ],
+      "this.propsLike.user;": [
+        {
+          col: 0,
+          message: NoRestrictedPropertyAccessMessage::TooDeep(3, 2),
+          hint: NoRestrictedPropertyAccessHint::Shorten,
+        }
+      ],
+      "this[props].user;": [
+        {
+          col: 0,
+          message: NoRestrictedPropertyAccessMessage::TooDeep(3, 2),
+          hint: NoRestrictedPropertyAccessHint::Shorten,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,283 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{BlockStmtOrExpr, Expr};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoReturnAssign {
+  options: NoReturnAssignOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NoReturnAssignMode {
+  /// Allow assignments that are wrapped in parentheses, e.g.
+  /// `return (x = 5);`.
+  ExceptParens,
+  /// Disallow all assignments.
+  Always,
+}
+
+impl Default for NoReturnAssignMode {
+  fn default() -> Self {
+    NoReturnAssignMode::ExceptParens
+  }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoReturnAssignOptions {
+  pub mode: NoReturnAssignMode,
+}
+
+impl NoReturnAssign {
+  pub fn with_options(options: NoReturnAssignOptions) -> Arc<Self> {
+    Arc::new(NoReturnAssign { options })
+  }
+}
+
+const CODE: &str = "no-return-assign";
+
+#[derive(Display)]
+enum NoReturnAssignMessage {
+  #[display(fmt = "Return statement should not contain an assignment")]
+  Return,
+  #[display(fmt = "Arrow function should not return an assignment")]
+  Arrow,
+}
+
+#[derive(Display)]
+enum NoReturnAssignHint {
+  #[display(fmt = "Assign the value in a separate statement before returning")]
+  Separate,
+  #[display(
+    fmt = "Assign the value in a separate statement before returning, or wrap the assignment in parentheses if it's intentional"
+  )]
+  SeparateOrParens,
+}
+
+impl LintRule for NoReturnAssign {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoReturnAssignOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "mode": {
+          "type": "string",
+          "enum": ["except-parens", "always"],
+          "description": "Whether assignments wrapped in parentheses are allowed (`except-parens`) or not (`always`).",
+          "default": "except-parens"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoReturnAssignOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoReturnAssignHandler {
+      mode: self.options.mode,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_return_assign.md")
+  }
+}
+
+struct NoReturnAssignHandler {
+  mode: NoReturnAssignMode,
+}
+
+impl NoReturnAssignHandler {
+  fn check(
+    &self,
+    ctx: &mut Context,
+    expr: &Expr,
+    message: NoReturnAssignMessage,
+  ) {
+    let expr = if self.mode == NoReturnAssignMode::Always {
+      unwrap_paren(expr)
+    } else {
+      expr
+    };
+
+    if let Expr::Assign(assign) = expr {
+      let hint = match self.mode {
+        NoReturnAssignMode::ExceptParens => {
+          NoReturnAssignHint::SeparateOrParens
+        }
+        NoReturnAssignMode::Always => NoReturnAssignHint::Separate,
+      };
+      ctx.add_diagnostic_with_hint(assign.span, CODE, message, hint);
+    }
+  }
+}
+
+impl Handler for NoReturnAssignHandler {
+  fn return_stmt(
+    &mut self,
+    return_stmt: &ast_view::ReturnStmt,
+    ctx: &mut Context,
+  ) {
+    if let Some(arg) = &return_stmt.inner.arg {
+      self.check(ctx, arg, NoReturnAssignMessage::Return);
+    }
+  }
+
+  fn arrow_expr(
+    &mut self,
+    arrow_expr: &ast_view::ArrowExpr,
+    ctx: &mut Context,
+  ) {
+    if let BlockStmtOrExpr::Expr(expr) = &arrow_expr.inner.body {
+      self.check(ctx, expr, NoReturnAssignMessage::Arrow);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-return-assign.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_return_assign_valid() {
+    assert_lint_ok! {
+      NoReturnAssign,
+      "module.exports = {'a': 1};",
+      "var result = a * b;",
+      "function x() { var result = a * b; return result; }",
+      "function x() { return (result = a * b); }",
+      "function x() { var result = a * b; return; }",
+      "function x() { return ((result = a * b)); }",
+      "function x() { return result == a * b; }",
+      "function x() { return result === a * b; }",
+      "const foo = (a) => (b = a);",
+      "const foo = (a) => { b = a; };",
+      "const foo = (a) => b === a;",
+      "function x() { return function() { result = a * b; }; }",
+    };
+
+    assert_lint_ok! {
+      NoReturnAssign,
+      options: NoReturnAssignOptions {
+        mode: NoReturnAssignMode::Always,
+      },
+      "function x() { var result = a * b; return result; }",
+      "function x() { return result == a * b; }",
+      "const foo = (a) => { b = a; };",
+    };
+  }
+
+  #[test]
+  fn no_return_assign_invalid() {
+    assert_lint_err! {
+      NoReturnAssign,
+      "function x() { return result = a * b; };": [
+        {
+          col: 22,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::SeparateOrParens,
+        }
+      ],
+      "function x() { return result += a * b; };": [
+        {
+          col: 22,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::SeparateOrParens,
+        }
+      ],
+      "const foo = (a, b) => a = b;": [
+        {
+          col: 22,
+          message: NoReturnAssignMessage::Arrow,
+          hint: NoReturnAssignHint::SeparateOrParens,
+        }
+      ],
+      "const foo = (a) => b = a;": [
+        {
+          col: 19,
+          message: NoReturnAssignMessage::Arrow,
+          hint: NoReturnAssignHint::SeparateOrParens,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoReturnAssign,
+      options: NoReturnAssignOptions {
+        mode: NoReturnAssignMode::Always,
+      },
+      "function x() { return result = a * b; };": [
+        {
+          col: 22,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Separate,
+        }
+      ],
+      "function x() { return (result = a * b); };": [
+        {
+          col: 23,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Separate,
+        }
+      ],
+      "function x() { return ((result = a * b)); };": [
+        {
+          col: 24,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Separate,
+        }
+      ],
+      "const foo = (a) => (b = a);": [
+        {
+          col: 20,
+          message: NoReturnAssignMessage::Arrow,
+          hint: NoReturnAssignHint::Separate,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,213 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{BlockStmtOrExpr, Expr};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoReturnInExpressionPosition;
+
+const CODE: &str = "no-return-in-expression-position";
+const HINT: &str = "Use a block body to make clear what the function returns";
+const FIX_DESC: &str = "Wrap in a block body";
+
+#[derive(Display)]
+enum NoReturnInExpressionPositionMessage {
+  #[display(fmt = "Arrow function implicitly returns an assignment")]
+  Assign,
+  #[display(fmt = "Arrow function implicitly returns a sequence expression")]
+  Seq,
+}
+
+impl LintRule for NoReturnInExpressionPosition {
+  fn new() -> Arc<Self> {
+    Arc::new(NoReturnInExpressionPosition)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["=>"]
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoReturnInExpressionPositionHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_return_in_expression_position.md")
+  }
+}
+
+struct NoReturnInExpressionPositionHandler;
+
+impl Handler for NoReturnInExpressionPositionHandler {
+  fn arrow_expr(
+    &mut self,
+    arrow_expr: &ast_view::ArrowExpr,
+    ctx: &mut Context,
+  ) {
+    let body = match &arrow_expr.inner.body {
+      BlockStmtOrExpr::Expr(body) => &**body,
+      BlockStmtOrExpr::BlockStmt(_) => return,
+    };
+    let span = body.span();
+    // An assignment's value is rarely meant to be returned, so dropping it
+    // is likely what's intended, but it changes what the function returns.
+    // The last expression of a sequence is still returned.
+    let (message, block, applicability) = match unwrap_paren(body) {
+      Expr::Assign(assign) => (
+        NoReturnInExpressionPositionMessage::Assign,
+        format!("{{ {}; }}", ctx.file_text_substring(&assign.span)),
+        Some(Applicability::Unsafe),
+      ),
+      Expr::Seq(seq) => {
+        let (last, init) = match seq.exprs.split_last() {
+          Some(split) => split,
+          None => return,
+        };
+        let stmts: String = init
+          .iter()
+          .map(|expr| format!("{}; ", ctx.file_text_substring(&expr.span())))
+          .collect();
+        (
+          NoReturnInExpressionPositionMessage::Seq,
+          format!(
+            "{{ {}return {}; }}",
+            stmts,
+            ctx.file_text_substring(&last.span())
+          ),
+          None,
+        )
+      }
+      _ => return,
+    };
+
+    // The comments within the body would be lost.
+    if ctx.comments_within(span).next().is_some() {
+      ctx.add_diagnostic_with_hint(span, CODE, message, HINT);
+      return;
+    }
+    ctx.add_diagnostic_with_fixes(
+      span,
+      CODE,
+      message,
+      Some(HINT.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.to_string(),
+        changes: vec![ctx.fix_change(span, block)],
+        applicability,
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_return_in_expression_position_valid() {
+    assert_lint_ok! {
+      NoReturnInExpressionPosition,
+      "(x) => x",
+      "(x) => a === x",
+      "(x) => { a = x; }",
+      "(x) => { return a = x; }",
+      "() => (a, b) => a",
+      "() => f(a = 1)",
+      "() => ({ a: b = 1 })",
+      "() => [a, b]",
+      "function f() { return a = 1; }",
+    };
+  }
+
+  #[test]
+  fn no_return_in_expression_position_invalid() {
+    assert_lint_err! {
+      NoReturnInExpressionPosition,
+      "(x) => (a = x)": [
+        {
+          col: 7,
+          message: NoReturnInExpressionPositionMessage::Assign,
+          hint: HINT,
+          fix: (FIX_DESC, "(x) => { a = x; }"),
+        }
+      ],
+      "x => a = x": [
+        {
+          col: 5,
+          message: NoReturnInExpressionPositionMessage::Assign,
+          hint: HINT,
+          fix: (FIX_DESC, "x => { a = x; }"),
+        }
+      ],
+      "items.forEach((item) => (total += item.price));": [
+        {
+          col: 24,
+          message: NoReturnInExpressionPositionMessage::Assign,
+          hint: HINT,
+          fix: (FIX_DESC, "items.forEach((item) => { total += item.price; });"),
+        }
+      ],
+      "const f = () => (a(), b(), c);": [
+        {
+          col: 16,
+          message: NoReturnInExpressionPositionMessage::Seq,
+          hint: HINT,
+          fix: (FIX_DESC, "const f = () => { a(); b(); return c; };"),
+        }
+      ],
+      "const f = () => ((a = 1, b));": [
+        {
+          col: 16,
+          message: NoReturnInExpressionPositionMessage::Seq,
+          hint: HINT,
+          fix: (FIX_DESC, "const f = () => { a = 1; return b; };"),
+        }
+      ],
+      "const f = () => (/* set */ a = 1);": [
+        {
+          col: 16,
+          message: NoReturnInExpressionPositionMessage::Assign,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_return_in_expression_position_fix_applicability() {
+    let diagnostics = crate::linter::lint_to_diagnostics(
+      "foo.ts",
+      "const f = (x) => (a = x);\nconst g = () => (a(), b);",
+      vec![NoReturnInExpressionPosition::new()],
+    )
+    .unwrap();
+    let applicability = diagnostics
+      .iter()
+      .map(|diagnostic| diagnostic.fixes[0].applicability)
+      .collect::<Vec<_>>();
+    assert_eq!(
+      applicability,
+      [Some(Applicability::Unsafe), Some(Applicability::Suggestion)]
+    );
+  }
+}
@@ -0,0 +1,236 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::import_graph::{normalize_file_name, resolve, strip_extension};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Lit, Str};
+use deno_ast::view as ast_view;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoSelfImport;
+
+const CODE: &str = "no-self-import";
+const MESSAGE: &str = "Module imports itself";
+const HINT: &str =
+  "Remove the import, a module can refer to its own bindings directly";
+
+impl LintRule for NoSelfImport {
+  fn new() -> Arc<Self> {
+    Arc::new(NoSelfImport)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoSelfImportHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_self_import.md")
+  }
+}
+
+fn is_self_import(file_name: &str, specifier: &str) -> bool {
+  let path = match resolve(file_name, specifier) {
+    Some(path) => path,
+    None => return false,
+  };
+  let file_name = normalize_file_name(file_name);
+  // An extensionless specifier refers to the file with any extension
+  path == file_name || strip_extension(&file_name) == Some(path.as_str())
+}
+
+fn check_source(ctx: &mut Context, src: &Str) {
+  if is_self_import(ctx.file_name(), &src.value) {
+    ctx.add_diagnostic_with_hint(src.span, CODE, MESSAGE, HINT);
+  }
+}
+
+struct NoSelfImportHandler;
+
+impl Handler for NoSelfImportHandler {
+  fn import_decl(
+    &mut self,
+    import_decl: &ast_view::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    check_source(ctx, &import_decl.inner.src);
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    if let Some(src) = &named_export.inner.src {
+      check_source(ctx, src);
+    }
+  }
+
+  fn export_all(
+    &mut self,
+    export_all: &ast_view::ExportAll,
+    ctx: &mut Context,
+  ) {
+    check_source(ctx, &export_all.inner.src);
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let call_expr = call_expr.inner;
+    let is_import = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Ident(ident) => {
+          &*ident.sym == "import" || &*ident.sym == "require"
+        }
+        _ => false,
+      },
+      ExprOrSuper::Super(_) => false,
+    };
+    if !is_import {
+      return;
+    }
+    if let Some(arg) = call_expr.args.first() {
+      if let Expr::Lit(Lit::Str(src)) = &*arg.expr {
+        check_source(ctx, src);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/import-js/eslint-plugin-import/blob/v2.26.0/tests/src/rules/no-self-import.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_self_import_valid() {
+    assert_lint_ok! {
+      NoSelfImport,
+      filename: "file:///src/foo/bar.ts",
+      "import baz from './baz';",
+      "import bar from '../bar';",
+      "import bar from './bar/index.ts';",
+      "import bar from './bar.js';",
+      "import bar from 'bar';",
+      "import bar from '/src/bar.ts';",
+      "export { bar } from './barbaz.ts';",
+      "export * from '../foo';",
+      "const bar = require('./baz');",
+      "import('./bar/');",
+      "import(bar);",
+      "foo('./bar.ts');",
+    };
+
+    assert_lint_ok! {
+      NoSelfImport,
+      filename: "bar.ts",
+      "import bar from './baz.ts';",
+      "import bar from '../bar.ts';",
+    };
+  }
+
+  #[test]
+  fn no_self_import_invalid() {
+    assert_lint_err! {
+      NoSelfImport,
+      filename: "file:///src/foo/bar.ts",
+      "import bar from './bar';": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import bar from './bar.ts';": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import { bar } from '../foo/bar.ts';": [
+        {
+          col: 20,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import type { Bar } from '.././foo/../foo/bar';": [
+        {
+          col: 25,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import '/src/foo/bar.ts';": [
+        {
+          col: 7,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import 'file:///src/foo/bar.ts';": [
+        {
+          col: 7,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export { bar } from './bar.ts';": [
+        {
+          col: 20,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export * from './bar';": [
+        {
+          col: 14,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const bar = await import('./bar.ts');": [
+        {
+          col: 25,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const bar = require('./bar');": [
+        {
+          col: 20,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoSelfImport,
+      filename: "bar.ts",
+      "import bar from './bar.ts';": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,547 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::scope_tree::{Binding, ScopeKind, ScopeTree};
+use crate::scopes::BindingKind;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view::{Node, NodeTrait};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoShadow {
+  options: NoShadowOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoShadowHoist {
+  /// Report shadowing before the outer variable is declared.
+  All,
+  /// Report shadowing before the outer function is declared, but not before
+  /// other kinds of outer variables are declared.
+  Functions,
+  /// Never report shadowing before the outer variable is declared.
+  Never,
+}
+
+impl Default for NoShadowHoist {
+  fn default() -> Self {
+    NoShadowHoist::Functions
+  }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoShadowOptions {
+  /// Report shadowing of global variables such as `Object`.
+  pub builtin_globals: bool,
+  pub hoist: NoShadowHoist,
+  /// Names of variables that are allowed to shadow.
+  pub allow: Vec<String>,
+  /// Allow shadowing in callbacks that are called while the shadowed
+  /// variable is still being initialized, e.g.
+  /// `const person = people.find((person) => person.id === id);`.
+  pub ignore_on_initialization: bool,
+}
+
+impl NoShadow {
+  pub fn with_options(options: NoShadowOptions) -> Arc<Self> {
+    Arc::new(NoShadow { options })
+  }
+}
+
+const CODE: &str = "no-shadow";
+const RELATED_MESSAGE: &str = "Shadowed variable is declared here";
+
+#[derive(Display)]
+enum NoShadowMessage {
+  #[display(fmt = "`{}` shadows a variable in an enclosing scope", _0)]
+  Shadow(String),
+  #[display(fmt = "`{}` shadows a global variable", _0)]
+  ShadowGlobal(String),
+}
+
+#[derive(Display)]
+enum NoShadowHint {
+  #[display(
+    fmt = "Rename the variable to avoid confusing it with the outer one"
+  )]
+  Rename,
+}
+
+impl LintRule for NoShadow {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoShadowOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "builtinGlobals": {
+          "type": "boolean",
+          "description": "Report shadowing of global variables.",
+          "default": false
+        },
+        "hoist": {
+          "type": "string",
+          "enum": ["all", "functions", "never"],
+          "default": "functions"
+        },
+        "allow": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Names of variables that are allowed to shadow."
+        },
+        "ignoreOnInitialization": {
+          "type": "boolean",
+          "description": "Allow shadowing in callbacks called during initialization of the shadowed variable.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoShadowOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    let mut calls = CallCollector {
+      calls: HashMap::new(),
+    };
+    calls.traverse(program, context);
+
+    let checker = NoShadowChecker {
+      options: &self.options,
+      allow: self.options.allow.iter().map(String::as_str).collect(),
+      tree: context.scope_tree(),
+      calls: &calls.calls,
+    };
+    let mut reports = checker.check(context);
+    reports.sort_by_key(|report| report.span.lo());
+
+    for report in reports {
+      match report.shadowed {
+        Some(shadowed) => {
+          let related = context.related_information(shadowed, RELATED_MESSAGE);
+          context.add_diagnostic_with_related(
+            report.span,
+            CODE,
+            NoShadowMessage::Shadow(report.name),
+            Some(NoShadowHint::Rename.to_string()),
+            vec![related],
+          );
+        }
+        None => context.add_diagnostic_with_hint(
+          report.span,
+          CODE,
+          NoShadowMessage::ShadowGlobal(report.name),
+          NoShadowHint::Rename,
+        ),
+      }
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_shadow.md")
+  }
+}
+
+/// Collects the nearest call containing each function and arrow function,
+/// keyed by the span of the function.
+struct CallCollector {
+  calls: HashMap<Span, Span>,
+}
+
+impl Handler for CallCollector {
+  fn on_enter_node(&mut self, node: Node, _ctx: &mut Context) {
+    if !matches!(node, Node::Function(_) | Node::ArrowExpr(_)) {
+      return;
+    }
+    let call = node
+      .ancestors()
+      .find(|n| matches!(n, Node::CallExpr(_) | Node::NewExpr(_)));
+    if let Some(call) = call {
+      self.calls.insert(node.span(), call.span());
+    }
+  }
+}
+
+struct Report {
+  span: Span,
+  name: String,
+  /// The span of the shadowed variable, or `None` for globals
+  shadowed: Option<Span>,
+}
+
+struct NoShadowChecker<'a> {
+  options: &'a NoShadowOptions,
+  allow: HashSet<&'a str>,
+  tree: &'a ScopeTree,
+  calls: &'a HashMap<Span, Span>,
+}
+
+impl<'a> NoShadowChecker<'a> {
+  fn check(&self, ctx: &Context) -> Vec<Report> {
+    let mut reports = Vec::new();
+    for scope in self.tree.scopes() {
+      for binding_id in self.tree.scope(scope).bindings() {
+        let binding = self.tree.binding(*binding_id);
+        if self.allow.contains(&**binding.name()) {
+          continue;
+        }
+        let shadowed = match self.tree.shadowed(*binding_id) {
+          Some(outer) => {
+            let outer = self.tree.binding(outer);
+            if self.is_ignored(binding, outer) {
+              continue;
+            }
+            Some(outer.span())
+          }
+          None
+            if self.options.builtin_globals
+              && ctx.is_global(binding.name()) =>
+          {
+            None
+          }
+          None => continue,
+        };
+        reports.push(Report {
+          span: binding.span(),
+          name: binding.name().to_string(),
+          shadowed,
+        });
+      }
+    }
+    reports
+  }
+
+  fn is_ignored(&self, binding: &Binding, outer: &Binding) -> bool {
+    let scope = self.tree.scope(binding.scope());
+
+    // `const a = function a() {}` and `const A = class A {}`
+    if scope.kind() == ScopeKind::Name
+      && scope.parent() == Some(outer.scope())
+      && matches!(outer.decl(), Some(decl) if decl.contains(binding.span()))
+    {
+      return true;
+    }
+
+    // The shadowing happens before the outer variable is declared
+    if binding.span().hi() <= outer.span().lo() {
+      let is_function_decl = outer.kind() == BindingKind::Function
+        && self.tree.scope(outer.scope()).kind() != ScopeKind::Name;
+      let in_tdz = match self.options.hoist {
+        NoShadowHoist::All => false,
+        NoShadowHoist::Functions => !is_function_decl,
+        NoShadowHoist::Never => true,
+      };
+      if in_tdz {
+        return true;
+      }
+    }
+
+    self.options.ignore_on_initialization
+      && self.is_in_initializer_callback(binding, outer)
+  }
+
+  /// Checks if the binding belongs to a callback that is called while the
+  /// outer variable is initialized, e.g. `const a = foo((a) => a);`.
+  fn is_in_initializer_callback(
+    &self,
+    binding: &Binding,
+    outer: &Binding,
+  ) -> bool {
+    let function = self
+      .tree
+      .ancestors(binding.scope())
+      .find(|id| self.tree.scope(*id).kind().is_var_scope());
+    let function = match function {
+      Some(function) => self.tree.scope(function),
+      None => return false,
+    };
+    let init_call = match self.calls.get(&function.span()) {
+      Some(init_call) => *init_call,
+      None => return false,
+    };
+
+    // The function must be directly inside of the outer variable's scope
+    let parent = function.parent().and_then(|parent| {
+      self
+        .tree
+        .ancestors(parent)
+        .find(|id| self.tree.scope(*id).kind() != ScopeKind::Name)
+    });
+    parent == Some(outer.scope())
+      && matches!(
+        outer.init(),
+        Some(init)
+          if init.lo() <= init_call.hi() && init_call.hi() <= init.hi()
+      )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.32.0/tests/lib/rules/no-shadow.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_shadow_valid() {
+    assert_lint_ok! {
+      NoShadow,
+      "var a = 3; function b(x) { a++; return x + a; }; setTimeout(function() { b(a); }, 0);",
+      "(function() { var doSomething = function doSomething() {}; doSomething() }())",
+      "var arguments;\nfunction bar() { }",
+      "var a = function a() {};",
+      "var a = class a {};",
+      "const a = class A { m() { return A; } };",
+      "class A {}",
+      "class A { constructor() { var a; } }",
+      "(function() { var A = class A {}; })()",
+      "{ var a; } var a;",
+      "{ let a; } let a;",
+      "{ let a; } var a;",
+      "{ const a = 0; } const a = 1;",
+      "function foo(a) { } let a;",
+      "function foo() { var Object = 0; }",
+      "function foo(a, b) { var c; }",
+      "function foo(a) { var a; }",
+      "function foo() { var a; { var a; } }",
+      "try {} catch (e) {} try {} catch (e) {}",
+      "let x = 1; foo.x = 2; ({ x: 3 });",
+      "function foo(this: Window) {} const x = { this: 1 };",
+      "type T = (a: string) => void; const a = 1;",
+      "function f(a: string): void; function f(a) {}",
+    };
+
+    assert_lint_ok! {
+      NoShadow,
+      options: NoShadowOptions {
+        allow: vec!["cb".to_string(), "done".to_string()],
+        ..Default::default()
+      },
+      "import async from 'async'; function foo(cb) {} async.map([1, 2, 3], function(cb) {});",
+      "function foo(done) { return new Promise((done) => {}); }",
+    };
+
+    assert_lint_ok! {
+      NoShadow,
+      options: NoShadowOptions {
+        hoist: NoShadowHoist::Never,
+        ..Default::default()
+      },
+      "function foo() { var a; } function a() {}",
+      "function outer() { function inner() { var x; } var x; }",
+    };
+
+    assert_lint_ok! {
+      NoShadow,
+      "function outer() { function inner() { var x; } var x; }",
+      "function foo() { return a; } let a = 1;",
+    };
+
+    assert_lint_ok! {
+      NoShadow,
+      options: NoShadowOptions {
+        ignore_on_initialization: true,
+        ..Default::default()
+      },
+      "const person = people.find(person => person.name === 'x');",
+      "const a = foo(function(a) { return a; });",
+      "for (const item of items.filter(item => item.ok)) {}",
+    };
+  }
+
+  #[test]
+  fn no_shadow_invalid() {
+    assert_lint_err! {
+      NoShadow,
+      "function a(x) { var b = function c() { var x = 'foo'; }; }": [
+        {
+          col: 43,
+          message: NoShadowMessage::Shadow("x".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 11, RELATED_MESSAGE),
+        }
+      ],
+      "var a = (x) => { var b = () => { var x = 'foo'; }; }": [
+        {
+          col: 37,
+          message: NoShadowMessage::Shadow("x".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 9, RELATED_MESSAGE),
+        }
+      ],
+      "function a(x) { var b = (x) => {}; }": [
+        {
+          col: 25,
+          message: NoShadowMessage::Shadow("x".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 11, RELATED_MESSAGE),
+        }
+      ],
+      "let x = 1; { const x = 2; }": [
+        {
+          col: 19,
+          message: NoShadowMessage::Shadow("x".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 4, RELATED_MESSAGE),
+        }
+      ],
+      "function foo() { var a; { var b; { let a; } } }": [
+        {
+          col: 39,
+          message: NoShadowMessage::Shadow("a".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 21, RELATED_MESSAGE),
+        }
+      ],
+      "function foo() { var a; } function a() {}": [
+        {
+          col: 21,
+          message: NoShadowMessage::Shadow("a".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 35, RELATED_MESSAGE),
+        }
+      ],
+      "const x = 1;\nfunction foo() {\n  try {} catch (x) {}\n}": [
+        {
+          line: 3,
+          col: 16,
+          message: NoShadowMessage::Shadow("x".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 6, RELATED_MESSAGE),
+        }
+      ],
+      "import { a } from './a.ts'; class Foo { constructor(private a: number) {} }": [
+        {
+          col: 60,
+          message: NoShadowMessage::Shadow("a".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 9, RELATED_MESSAGE),
+        }
+      ],
+      "const a = 1; for (const a of arr) {}": [
+        {
+          col: 24,
+          message: NoShadowMessage::Shadow("a".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 6, RELATED_MESSAGE),
+        }
+      ],
+      "(function a() { var a; })();": [
+        {
+          col: 20,
+          message: NoShadowMessage::Shadow("a".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 10, RELATED_MESSAGE),
+        }
+      ],
+      "const person = people.find(person => person.name === 'x');": [
+        {
+          col: 27,
+          message: NoShadowMessage::Shadow("person".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 6, RELATED_MESSAGE),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoShadow,
+      options: NoShadowOptions {
+        hoist: NoShadowHoist::All,
+        ..Default::default()
+      },
+      "function outer() { function inner() { var x; } var x; }": [
+        {
+          col: 42,
+          message: NoShadowMessage::Shadow("x".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 51, RELATED_MESSAGE),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoShadow,
+      options: NoShadowOptions {
+        builtin_globals: true,
+        ..Default::default()
+      },
+      "function foo() { var Object = 0; }": [
+        {
+          col: 21,
+          message: NoShadowMessage::ShadowGlobal("Object".to_string()),
+          hint: NoShadowHint::Rename,
+        }
+      ],
+      "const Map = 1;": [
+        {
+          col: 6,
+          message: NoShadowMessage::ShadowGlobal("Map".to_string()),
+          hint: NoShadowHint::Rename,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoShadow,
+      options: NoShadowOptions {
+        ignore_on_initialization: true,
+        ..Default::default()
+      },
+      "const a = 1; const b = foo((a) => a);": [
+        {
+          col: 28,
+          message: NoShadowMessage::Shadow("a".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 6, RELATED_MESSAGE),
+        }
+      ],
+      "const x = (x) => x;": [
+        {
+          col: 11,
+          message: NoShadowMessage::Shadow("x".to_string()),
+          hint: NoShadowHint::Rename,
+          related: (1, 6, RELATED_MESSAGE),
+        }
+      ],
+    };
+  }
+}
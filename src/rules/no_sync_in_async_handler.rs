@@ -0,0 +1,252 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Lit};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoSyncInAsyncHandler;
+
+const CODE: &str = "no-sync-in-async-handler";
+
+#[derive(Display)]
+enum NoSyncInAsyncHandlerMessage {
+  #[display(fmt = "`{}` blocks the event loop in an async function", _0)]
+  InAsyncFunction(String),
+  #[display(fmt = "`{}` blocks the event loop in a request handler", _0)]
+  InRequestHandler(String),
+}
+
+#[derive(Display)]
+enum NoSyncInAsyncHandlerHint {
+  #[display(fmt = "Use the asynchronous `{}` with `await` instead", _0)]
+  UseAsync(String),
+}
+
+impl LintRule for NoSyncInAsyncHandler {
+  fn new() -> Arc<Self> {
+    Arc::new(NoSyncInAsyncHandler)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoSyncInAsyncHandlerHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_sync_in_async_handler.md")
+  }
+}
+
+/// Returns the name of the called function if it is a synchronous variant of
+/// an API, e.g. `readFileSync` in `fs.readFileSync(path)`.
+fn sync_callee_name(callee: &Expr) -> Option<&str> {
+  let name: &str = match callee {
+    Expr::Ident(ident) => &ident.sym,
+    Expr::Member(member_expr) => {
+      match (&*member_expr.prop, member_expr.computed) {
+        (Expr::Ident(ident), false) => &ident.sym,
+        (Expr::Lit(Lit::Str(str)), true) => &str.value,
+        _ => return None,
+      }
+    }
+    Expr::Paren(paren) => return sync_callee_name(&paren.expr),
+    _ => return None,
+  };
+  if name.len() > "Sync".len() && name.ends_with("Sync") {
+    Some(name)
+  } else {
+    None
+  }
+}
+
+/// Returns whether the callee is `Deno.serve` or `serve` from `std/http`,
+/// whose function arguments handle requests.
+fn is_serve(callee: &Expr) -> bool {
+  match callee {
+    Expr::Ident(ident) => ident.sym == *"serve",
+    Expr::Member(member_expr) if !member_expr.computed => matches!(
+      (&member_expr.obj, &*member_expr.prop),
+      (ExprOrSuper::Expr(obj), Expr::Ident(prop))
+        if matches!(&**obj, Expr::Ident(obj) if obj.sym == *"Deno")
+          && prop.sym == *"serve"
+    ),
+    _ => false,
+  }
+}
+
+/// Returns whether the function is passed directly to a `serve` call, e.g.
+/// `Deno.serve((req) => {})`.
+fn is_request_handler(function: Node) -> bool {
+  let function = match function {
+    Node::Function(function) => function.parent(),
+    function => function,
+  };
+  let arg = match function
+    .ancestors()
+    .find(|ancestor| !matches!(ancestor, Node::ParenExpr(_)))
+  {
+    Some(Node::ExprOrSpread(arg)) => arg,
+    _ => return false,
+  };
+  match arg.parent() {
+    Node::CallExpr(call_expr) => matches!(
+      &call_expr.inner.callee,
+      ExprOrSuper::Expr(callee) if is_serve(callee)
+    ),
+    _ => false,
+  }
+}
+
+struct NoSyncInAsyncHandlerHandler;
+
+impl Handler for NoSyncInAsyncHandlerHandler {
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let name = match &call_expr.inner.callee {
+      ExprOrSuper::Expr(callee) => match sync_callee_name(callee) {
+        Some(name) => name.to_string(),
+        None => return,
+      },
+      ExprOrSuper::Super(_) => return,
+    };
+
+    // Only the innermost function matters: a synchronous callback nested in
+    // an async function may run at any time.
+    let function = match call_expr.as_node().ancestors().find(|ancestor| {
+      matches!(
+        ancestor,
+        Node::Function(_) | Node::ArrowExpr(_) | Node::Constructor(_)
+      )
+    }) {
+      Some(function) => function,
+      None => return,
+    };
+    let message = match function {
+      Node::Function(function) if function.inner.is_async => {
+        NoSyncInAsyncHandlerMessage::InAsyncFunction(name.clone())
+      }
+      Node::ArrowExpr(arrow) if arrow.inner.is_async => {
+        NoSyncInAsyncHandlerMessage::InAsyncFunction(name.clone())
+      }
+      Node::Function(_) | Node::ArrowExpr(_)
+        if is_request_handler(function) =>
+      {
+        NoSyncInAsyncHandlerMessage::InRequestHandler(name.clone())
+      }
+      _ => return,
+    };
+
+    let async_name = name.trim_end_matches("Sync").to_string();
+    ctx.add_diagnostic_with_hint(
+      call_expr.span(),
+      CODE,
+      message,
+      NoSyncInAsyncHandlerHint::UseAsync(async_name),
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_sync_in_async_handler_valid() {
+    assert_lint_ok! {
+      NoSyncInAsyncHandler,
+      "Deno.readTextFileSync('config.json');",
+      "function load() { return Deno.readTextFileSync('config.json'); }",
+      "const load = () => fs.readFileSync(path);",
+      "async function load() { return await Deno.readTextFile('config.json'); }",
+      "async function load() { function read() { return Deno.readFileSync(path); } }",
+      "async function load() { setTimeout(() => { fs.writeFileSync(path, data); }); }",
+      "async function f() { Sync(); }",
+      "async function f() { sync(); }",
+      "async function f() { foo[readFileSync](); }",
+      "async function f() { new FooSync(); }",
+      "class A { constructor() { Deno.readFileSync(path); } }",
+      "Deno.serve({ port: 8000 }, handler);",
+      "listen((req) => Deno.readFileSync(path));",
+      "Deno.serve((req) => { const f = () => Deno.readFileSync(path); });",
+    };
+  }
+
+  #[test]
+  fn no_sync_in_async_handler_invalid() {
+    assert_lint_err! {
+      NoSyncInAsyncHandler,
+      "async function load() { return Deno.readTextFileSync('config.json'); }": [
+        {
+          col: 31,
+          message: NoSyncInAsyncHandlerMessage::InAsyncFunction("readTextFileSync".to_string()),
+          hint: NoSyncInAsyncHandlerHint::UseAsync("readTextFile".to_string()),
+        }
+      ],
+      "const load = async () => fs.readFileSync(path);": [
+        {
+          col: 25,
+          message: NoSyncInAsyncHandlerMessage::InAsyncFunction("readFileSync".to_string()),
+          hint: NoSyncInAsyncHandlerHint::UseAsync("readFile".to_string()),
+        }
+      ],
+      "class A { async load() { if (existsSync(path)) {} } }": [
+        {
+          col: 29,
+          message: NoSyncInAsyncHandlerMessage::InAsyncFunction("existsSync".to_string()),
+          hint: NoSyncInAsyncHandlerHint::UseAsync("exists".to_string()),
+        }
+      ],
+      "const a = { async f() { Deno['statSync'](path); } };": [
+        {
+          col: 24,
+          message: NoSyncInAsyncHandlerMessage::InAsyncFunction("statSync".to_string()),
+          hint: NoSyncInAsyncHandlerHint::UseAsync("stat".to_string()),
+        }
+      ],
+      "async function f() { for (const x of xs) { await g(x); Deno.writeTextFileSync(x, ''); } }": [
+        {
+          col: 55,
+          message: NoSyncInAsyncHandlerMessage::InAsyncFunction("writeTextFileSync".to_string()),
+          hint: NoSyncInAsyncHandlerHint::UseAsync("writeTextFile".to_string()),
+        }
+      ],
+      "Deno.serve((req) => new Response(Deno.readFileSync(path)));": [
+        {
+          col: 33,
+          message: NoSyncInAsyncHandlerMessage::InRequestHandler("readFileSync".to_string()),
+          hint: NoSyncInAsyncHandlerHint::UseAsync("readFile".to_string()),
+        }
+      ],
+      "serve(function (req) { return new Response(Deno.readTextFileSync(path)); });": [
+        {
+          col: 43,
+          message: NoSyncInAsyncHandlerMessage::InRequestHandler("readTextFileSync".to_string()),
+          hint: NoSyncInAsyncHandlerHint::UseAsync("readTextFile".to_string()),
+        }
+      ],
+      "Deno.serve({ port: 8000 }, async (req) => { Deno.readFileSync(path); });": [
+        {
+          col: 44,
+          message: NoSyncInAsyncHandlerMessage::InAsyncFunction("readFileSync".to_string()),
+          hint: NoSyncInAsyncHandlerHint::UseAsync("readFile".to_string()),
+        }
+      ],
+    };
+  }
+}
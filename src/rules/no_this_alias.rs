@@ -6,17 +6,47 @@ use deno_ast::swc::visit::noop_visit_type;
 use deno_ast::swc::visit::Node;
 use deno_ast::swc::visit::{VisitAll, VisitAllWith};
 use if_chain::if_chain;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::Arc;
 
 #[derive(Debug)]
-pub struct NoThisAlias;
+pub struct NoThisAlias {
+  options: NoThisAliasOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoThisAliasOptions {
+  /// Allow destructuring members of `this`, e.g. `const { props } = this;`.
+  pub allow_destructuring: bool,
+  /// Names of variables that `this` may be assigned to, e.g. `self`.
+  pub allowed_names: Vec<String>,
+}
+
+impl Default for NoThisAliasOptions {
+  fn default() -> Self {
+    Self {
+      allow_destructuring: true,
+      allowed_names: Vec::new(),
+    }
+  }
+}
+
+impl NoThisAlias {
+  pub fn with_options(options: NoThisAliasOptions) -> Arc<Self> {
+    Arc::new(NoThisAlias { options })
+  }
+}
 
 const CODE: &str = "no-this-alias";
 const MESSAGE: &str = "assign `this` to declare a value is not allowed";
+const DESTRUCTURING_MESSAGE: &str =
+  "destructuring `this` to declare values is not allowed";
 
 impl LintRule for NoThisAlias {
   fn new() -> Arc<Self> {
-    Arc::new(NoThisAlias)
+    Self::with_options(NoThisAliasOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -27,12 +57,46 @@ impl LintRule for NoThisAlias {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowDestructuring": {
+          "type": "boolean",
+          "description": "Allow destructuring members of `this`.",
+          "default": true
+        },
+        "allowedNames": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Names of variables that `this` may be assigned to."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoThisAliasOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
   fn lint_program<'view>(
     &self,
     context: &mut Context<'view>,
     program: ProgramRef<'view>,
   ) {
-    let mut visitor = NoThisAliasVisitor::new(context);
+    let mut visitor = NoThisAliasVisitor::new(context, &self.options);
     match program {
       ProgramRef::Module(m) => m.visit_all_with(&DUMMY_NODE, &mut visitor),
       ProgramRef::Script(s) => s.visit_all_with(&DUMMY_NODE, &mut visitor),
@@ -47,11 +111,15 @@ impl LintRule for NoThisAlias {
 
 struct NoThisAliasVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  options: &'c NoThisAliasOptions,
 }
 
 impl<'c, 'view> NoThisAliasVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'c NoThisAliasOptions,
+  ) -> Self {
+    Self { context, options }
   }
 }
 
@@ -63,9 +131,25 @@ impl<'c, 'view> VisitAll for NoThisAliasVisitor<'c, 'view> {
       if_chain! {
         if let Some(init) = &decl.init;
         if matches!(&**init, Expr::This(_));
-        if matches!(&decl.name, Pat::Ident(_));
         then {
-          self.context.add_diagnostic(var_decl.span, CODE, MESSAGE);
+          match &decl.name {
+            Pat::Ident(ident) => {
+              let name: &str = &ident.id.sym;
+              if !self.options.allowed_names.iter().any(|n| n == name) {
+                self.context.add_diagnostic(var_decl.span, CODE, MESSAGE);
+              }
+            }
+            Pat::Object(_) | Pat::Array(_)
+              if !self.options.allow_destructuring =>
+            {
+              self.context.add_diagnostic(
+                var_decl.span,
+                CODE,
+                DESTRUCTURING_MESSAGE,
+              );
+            }
+            _ => {}
+          }
         }
       }
     }
@@ -85,6 +169,16 @@ mod tests {
       "const { props, state } = this;",
       "const [foo] = this;",
     };
+
+    assert_lint_ok! {
+      NoThisAlias,
+      options: NoThisAliasOptions {
+        allowed_names: vec!["self".to_string()],
+        ..Default::default()
+      },
+      "const self = this;",
+      "const { props, state } = this;",
+    };
   }
 
   #[test]
@@ -161,5 +255,31 @@ class TestClass {
         }
       ]
     };
+
+    assert_lint_err! {
+      NoThisAlias,
+      options: NoThisAliasOptions {
+        allow_destructuring: false,
+        allowed_names: vec!["self".to_string()],
+      },
+      "const that = this;": [
+        {
+          col: 0,
+          message: MESSAGE,
+        }
+      ],
+      "const { props, state } = this;": [
+        {
+          col: 0,
+          message: DESTRUCTURING_MESSAGE,
+        }
+      ],
+      "const [foo] = this;": [
+        {
+          col: 0,
+          message: DESTRUCTURING_MESSAGE,
+        }
+      ]
+    };
   }
 }
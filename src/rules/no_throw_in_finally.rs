@@ -0,0 +1,146 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoThrowInFinally;
+
+const CODE: &str = "no-throw-in-finally";
+const MESSAGE: &str = "`throw` is not allowed in a `finally` block";
+const HINT: &str = "Throwing in `finally` discards the result or error of the `try` and `catch` blocks; handle the error before the `finally` block instead";
+
+impl LintRule for NoThrowInFinally {
+  fn new() -> Arc<Self> {
+    Arc::new(NoThrowInFinally)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["finally"]
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoThrowInFinallyHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_throw_in_finally.md")
+  }
+}
+
+/// Checks if the statement is in a `finally` block, without a function or a
+/// `try` block catching the error in between.
+fn is_in_finally(stmt: Node) -> bool {
+  let span = stmt.span();
+  for ancestor in stmt.ancestors() {
+    match ancestor {
+      Node::Function(_) | Node::ArrowExpr(_) | Node::Constructor(_) => {
+        return false
+      }
+      Node::TryStmt(try_stmt) => {
+        if try_stmt.handler.is_some() && try_stmt.block.span().contains(span) {
+          return false;
+        }
+        if let Some(finalizer) = &try_stmt.finalizer {
+          if finalizer.span().contains(span) {
+            return true;
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+  false
+}
+
+struct NoThrowInFinallyHandler;
+
+impl Handler for NoThrowInFinallyHandler {
+  fn throw_stmt(
+    &mut self,
+    throw_stmt: &ast_view::ThrowStmt,
+    ctx: &mut Context,
+  ) {
+    if is_in_finally(throw_stmt.as_node()) {
+      ctx.add_diagnostic_with_hint(throw_stmt.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_throw_in_finally_valid() {
+    assert_lint_ok! {
+      NoThrowInFinally,
+      "try { throw a; } catch (e) { throw e; } finally { cleanup(); }",
+      "try { a(); } finally { return 1; }",
+      "try { a(); } finally { const f = () => { throw new Error(); }; }",
+      "try { a(); } finally { function f() { throw new Error(); } }",
+      "try { a(); } finally { class A { constructor() { throw 1; } foo() { throw 2; } } }",
+      "try { a(); } finally { try { throw new Error(); } catch (e) { log(e); } }",
+      "try { try { a(); } finally { cleanup(); } } finally { cleanup(); }",
+      "throw new Error();",
+    };
+  }
+
+  #[test]
+  fn no_throw_in_finally_invalid() {
+    assert_lint_err! {
+      NoThrowInFinally,
+      "try { a(); } finally { throw new Error(); }": [
+        {
+          col: 23,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "try { a(); } catch (e) {} finally { if (failed) { throw e; } }": [
+        {
+          col: 50,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "try { a(); } finally { try { b(); } finally { throw 1; } }": [
+        {
+          col: 46,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "try { a(); } finally { try { b(); } catch (e) { throw e; } }": [
+        {
+          col: 48,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "function f() { try { a(); } finally { for (;;) { throw 1; } } }": [
+        {
+          col: 49,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
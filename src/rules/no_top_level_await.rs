@@ -0,0 +1,159 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoTopLevelAwait;
+
+const CODE: &str = "no-top-level-await";
+const MESSAGE: &str = "Top-level `await` is not allowed";
+const HINT: &str = "Move the code into an async function, or enable top-level `await` for the target and disable this rule";
+
+impl LintRule for NoTopLevelAwait {
+  fn new() -> Arc<Self> {
+    Arc::new(NoTopLevelAwait)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["await"]
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    // `await` is only an operator at the top level of modules.
+    if !matches!(program, Program::Module(_)) {
+      return;
+    }
+    NoTopLevelAwaitHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_top_level_await.md")
+  }
+}
+
+fn is_top_level(node: Node) -> bool {
+  !node.ancestors().any(|ancestor| {
+    matches!(
+      ancestor,
+      Node::Function(_) | Node::ArrowExpr(_) | Node::Constructor(_)
+    )
+  })
+}
+
+struct NoTopLevelAwaitHandler;
+
+impl NoTopLevelAwaitHandler {
+  fn check(&self, ctx: &mut Context, span: Span, node: Node) {
+    if is_top_level(node) {
+      ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+impl Handler for NoTopLevelAwaitHandler {
+  fn await_expr(
+    &mut self,
+    await_expr: &ast_view::AwaitExpr,
+    ctx: &mut Context,
+  ) {
+    self.check(ctx, await_expr.span(), await_expr.as_node());
+  }
+
+  fn for_of_stmt(
+    &mut self,
+    for_of_stmt: &ast_view::ForOfStmt,
+    ctx: &mut Context,
+  ) {
+    if let Some(await_token) = for_of_stmt.inner.await_token {
+      self.check(ctx, await_token, for_of_stmt.as_node());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_top_level_await_valid() {
+    assert_lint_ok! {
+      NoTopLevelAwait,
+      "async function foo() { await bar(); }",
+      "const foo = async () => { await bar(); };",
+      "const foo = async () => await bar();",
+      "class A { async foo() { await bar(); } }",
+      "export async function foo() { for await (const a of b) {} }",
+      "export const a = (async () => { await foo(); })();",
+      "bar().then(() => {});",
+    };
+  }
+
+  #[test]
+  fn no_top_level_await_invalid() {
+    assert_lint_err! {
+      NoTopLevelAwait,
+      "export const data = await fetchData();": [
+        {
+          col: 20,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "import { a } from './a.ts';\nif (a) { await a(); }": [
+        {
+          line: 2,
+          col: 9,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export {};\nfor await (const chunk of stream) {}": [
+        {
+          line: 2,
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export {};\nconst a = [await b(), await c()];": [
+        {
+          line: 2,
+          col: 11,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          line: 2,
+          col: 22,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export {};\nconst f = async () => {};\nawait f();": [
+        {
+          line: 3,
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
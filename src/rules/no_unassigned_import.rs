@@ -0,0 +1,278 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUnassignedImport {
+  options: NoUnassignedImportOptions,
+  allow: Vec<Regex>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnassignedImportOptions {
+  /// Glob patterns of module specifiers that may be imported for their side
+  /// effects only, e.g. `**/*.css`. `*` matches any characters except `/`,
+  /// and `**` matches any characters.
+  pub allow: Vec<String>,
+  /// Module specifiers that may be imported for their side effects only, e.g.
+  /// `./polyfill.ts`.
+  pub allow_modules: Vec<String>,
+}
+
+impl NoUnassignedImport {
+  pub fn with_options(options: NoUnassignedImportOptions) -> Arc<Self> {
+    let allow = options
+      .allow
+      .iter()
+      .map(|glob| glob_to_regex(glob))
+      .collect();
+    Arc::new(NoUnassignedImport { options, allow })
+  }
+}
+
+const CODE: &str = "no-unassigned-import";
+
+#[derive(Display)]
+enum NoUnassignedImportMessage {
+  #[display(fmt = "Imported module `{}` is not assigned to anything", _0)]
+  Unassigned(String),
+}
+
+#[derive(Display)]
+enum NoUnassignedImportHint {
+  #[display(
+    fmt = "Import the values you need, or allow the module if it's imported for its side effects"
+  )]
+  ImportOrAllow,
+}
+
+impl LintRule for NoUnassignedImport {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoUnassignedImportOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allow": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Glob patterns of module specifiers that may be imported for their side effects.",
+          "default": []
+        },
+        "allowModules": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Module specifiers that may be imported for their side effects.",
+          "default": []
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoUnassignedImportOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUnassignedImportHandler { rule: self }.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unassigned_import.md")
+  }
+}
+
+/// Converts a glob pattern into a regular expression matching the whole
+/// string.
+fn glob_to_regex(glob: &str) -> Regex {
+  let mut pattern = String::from("^");
+  let mut chars = glob.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '*' if chars.peek() == Some(&'*') => {
+        chars.next();
+        pattern.push_str(".*");
+      }
+      '*' => pattern.push_str("[^/]*"),
+      '?' => pattern.push_str("[^/]"),
+      c => pattern.push_str(&regex::escape(&c.to_string())),
+    }
+  }
+  pattern.push('$');
+  Regex::new(&pattern).unwrap()
+}
+
+struct NoUnassignedImportHandler<'a> {
+  rule: &'a NoUnassignedImport,
+}
+
+impl<'a> NoUnassignedImportHandler<'a> {
+  fn is_allowed(&self, specifier: &str) -> bool {
+    self
+      .rule
+      .options
+      .allow_modules
+      .iter()
+      .any(|module| module == specifier)
+      || self.rule.allow.iter().any(|glob| glob.is_match(specifier))
+  }
+}
+
+impl<'a> Handler for NoUnassignedImportHandler<'a> {
+  fn import_decl(
+    &mut self,
+    import_decl: &ast_view::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    let specifier = import_decl.inner.src.value.as_ref();
+    if !import_decl.inner.specifiers.is_empty()
+      || import_decl.inner.type_only
+      || self.is_allowed(specifier)
+    {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(
+      import_decl.span(),
+      CODE,
+      NoUnassignedImportMessage::Unassigned(specifier.to_string()),
+      NoUnassignedImportHint::ImportOrAllow,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/import-js/eslint-plugin-import/blob/v2.26.0/tests/src/rules/no-unassigned-import.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_unassigned_import_valid() {
+    assert_lint_ok! {
+      NoUnassignedImport,
+      "import _, { foo } from './foo.ts';",
+      "import _ from './foo.ts';",
+      "import * as foo from './foo.ts';",
+      "import { foo } from './foo.ts';",
+      "import { foo as bar } from './foo.ts';",
+      "import type {} from './foo.ts';",
+      "import('./foo.ts');",
+      "export * from './foo.ts';",
+      "export {} from './foo.ts';",
+    };
+
+    assert_lint_ok! {
+      NoUnassignedImport,
+      options: NoUnassignedImportOptions {
+        allow: vec![
+          "**/*.css".to_string(),
+          "./styles/*.scss".to_string(),
+          "https://esm.sh/?reflect-metadata".to_string(),
+        ],
+        allow_modules: vec!["./polyfill.ts".to_string()],
+      },
+      "import './styles.css';",
+      "import '../app/components/button.css';",
+      "import './styles/main.scss';",
+      "import 'https://esm.sh/@reflect-metadata';",
+      "import './polyfill.ts';",
+    };
+  }
+
+  #[test]
+  fn no_unassigned_import_invalid() {
+    assert_lint_err! {
+      NoUnassignedImport,
+      "import './foo.ts';": [
+        {
+          col: 0,
+          message: NoUnassignedImportMessage::Unassigned("./foo.ts".to_string()),
+          hint: NoUnassignedImportHint::ImportOrAllow,
+        }
+      ],
+      "import {} from './foo.ts';": [
+        {
+          col: 0,
+          message: NoUnassignedImportMessage::Unassigned("./foo.ts".to_string()),
+          hint: NoUnassignedImportHint::ImportOrAllow,
+        }
+      ],
+      "import { foo } from './foo.ts';\nimport './bar.ts';": [
+        {
+          line: 2,
+          col: 0,
+          message: NoUnassignedImportMessage::Unassigned("./bar.ts".to_string()),
+          hint: NoUnassignedImportHint::ImportOrAllow,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnassignedImport,
+      options: NoUnassignedImportOptions {
+        allow: vec!["./styles/*.css".to_string()],
+        allow_modules: vec!["./polyfill.ts".to_string()],
+      },
+      "import './styles/nested/main.css';": [
+        {
+          col: 0,
+          message: NoUnassignedImportMessage::Unassigned("./styles/nested/main.css".to_string()),
+          hint: NoUnassignedImportHint::ImportOrAllow,
+        }
+      ],
+      "import './styles/main.scss';": [
+        {
+          col: 0,
+          message: NoUnassignedImportMessage::Unassigned("./styles/main.scss".to_string()),
+          hint: NoUnassignedImportHint::ImportOrAllow,
+        }
+      ],
+      "import '../polyfill.ts';": [
+        {
+          col: 0,
+          message: NoUnassignedImportMessage::Unassigned("../polyfill.ts".to_string()),
+          hint: NoUnassignedImportHint::ImportOrAllow,
+        }
+      ],
+    };
+  }
+}
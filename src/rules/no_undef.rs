@@ -1,6 +1,5 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
-use crate::globals::GLOBALS;
 use crate::ProgramRef;
 use deno_ast::swc::atoms::js_word;
 use deno_ast::swc::{
@@ -9,26 +8,81 @@ use deno_ast::swc::{
   visit::Node,
   visit::{noop_visit_type, Visit, VisitWith},
 };
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::Arc;
 
 #[derive(Debug)]
-pub struct NoUndef;
+pub struct NoUndef {
+  options: NoUndefOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUndefOptions {
+  /// Additional globals that are available at runtime, e.g. ones defined by
+  /// a bundler or an embedding environment.
+  pub globals: Vec<String>,
+  /// Also report undefined identifiers used in `typeof`, e.g. `typeof foo`.
+  #[serde(rename = "typeof")]
+  pub check_typeof: bool,
+}
+
+impl NoUndef {
+  pub fn with_options(options: NoUndefOptions) -> Arc<Self> {
+    Arc::new(NoUndef { options })
+  }
+}
 
 impl LintRule for NoUndef {
   fn new() -> Arc<Self> {
-    Arc::new(NoUndef)
+    Self::with_options(NoUndefOptions::default())
   }
 
   fn code(&self) -> &'static str {
     "no-undef"
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "globals": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Additional globals that are available at runtime."
+        },
+        "typeof": {
+          "type": "boolean",
+          "description": "Also report undefined identifiers used in `typeof`.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoUndefOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
   fn lint_program<'view>(
     &self,
     context: &mut Context<'view>,
     program: ProgramRef<'view>,
   ) {
-    let mut visitor = NoUndefVisitor::new(context);
+    let mut visitor = NoUndefVisitor::new(context, &self.options);
     match program {
       ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
       ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
@@ -43,11 +97,12 @@ impl LintRule for NoUndef {
 
 struct NoUndefVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  options: &'c NoUndefOptions,
 }
 
 impl<'c, 'view> NoUndefVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+  fn new(context: &'c mut Context<'view>, options: &'c NoUndefOptions) -> Self {
+    Self { context, options }
   }
 
   fn check(&mut self, ident: &Ident) {
@@ -70,7 +125,9 @@ impl<'c, 'view> NoUndefVisitor<'c, 'view> {
     }
 
     // Globals
-    if GLOBALS.iter().any(|(name, _)| name == &&*ident.sym) {
+    if self.context.is_global(&ident.sym)
+      || self.options.globals.iter().any(|name| *name == *ident.sym)
+    {
       return;
     }
 
@@ -93,7 +150,7 @@ impl<'c, 'view> Visit for NoUndefVisitor<'c, 'view> {
   }
 
   fn visit_unary_expr(&mut self, e: &UnaryExpr, _: &dyn Node) {
-    if e.op == UnaryOp::TypeOf {
+    if e.op == UnaryOp::TypeOf && !self.options.check_typeof {
       return;
     }
 
@@ -306,6 +363,66 @@ mod tests {
     };
   }
 
+  #[test]
+  fn no_undef_valid_with_options() {
+    assert_lint_ok! {
+      NoUndef,
+      options: NoUndefOptions {
+        globals: vec!["__DEV__".to_string(), "$".to_string()],
+        ..Default::default()
+      },
+      "if (__DEV__) { $('#app'); }",
+      "typeof a",
+    };
+
+    assert_lint_ok! {
+      NoUndef,
+      options: NoUndefOptions {
+        check_typeof: true,
+        ..Default::default()
+      },
+      "var a; typeof a",
+      "typeof Array",
+    };
+  }
+
+  #[test]
+  fn no_undef_invalid_with_options() {
+    assert_lint_err! {
+      NoUndef,
+      options: NoUndefOptions {
+        globals: vec!["__DEV__".to_string()],
+        ..Default::default()
+      },
+      "if (__DEV__) { __PROD__; }": [
+        {
+          col: 15,
+          message: "__PROD__ is not defined",
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoUndef,
+      options: NoUndefOptions {
+        check_typeof: true,
+        ..Default::default()
+      },
+      "typeof a": [
+        {
+          col: 7,
+          message: "a is not defined",
+        },
+      ],
+      "if (typeof a === 'undefined') {}": [
+        {
+          col: 11,
+          message: "a is not defined",
+        },
+      ],
+    };
+  }
+
   #[test]
   fn no_undef_invalid() {
     assert_lint_err! {
@@ -0,0 +1,493 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Ident, Pat, PropName};
+use deno_ast::swc::common::Span;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUnderscoreDangle {
+  options: NoUnderscoreDangleOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnderscoreDangleOptions {
+  /// Identifiers that are allowed to have dangling underscores.
+  pub allow: Vec<String>,
+  /// Allow dangling underscores in members of `this`, e.g. `this._foo`.
+  pub allow_after_this: bool,
+  /// Allow dangling underscores in members of `super`, e.g. `super._foo`.
+  pub allow_after_super: bool,
+  /// Allow dangling underscores in members of `this.constructor`, e.g.
+  /// `this.constructor._foo`.
+  pub allow_after_this_constructor: bool,
+  /// Also check names of methods.
+  pub enforce_in_method_names: bool,
+  /// Allow dangling underscores in names of function parameters.
+  pub allow_function_params: bool,
+}
+
+impl Default for NoUnderscoreDangleOptions {
+  fn default() -> Self {
+    Self {
+      allow: Vec::new(),
+      allow_after_this: false,
+      allow_after_super: false,
+      allow_after_this_constructor: false,
+      enforce_in_method_names: false,
+      allow_function_params: true,
+    }
+  }
+}
+
+impl NoUnderscoreDangle {
+  pub fn with_options(options: NoUnderscoreDangleOptions) -> Arc<Self> {
+    Arc::new(NoUnderscoreDangle { options })
+  }
+}
+
+const CODE: &str = "no-underscore-dangle";
+
+#[derive(Display)]
+enum NoUnderscoreDangleMessage {
+  #[display(fmt = "Unexpected dangling `_` in `{}`", _0)]
+  Dangling(String),
+}
+
+#[derive(Display)]
+enum NoUnderscoreDangleHint {
+  #[display(fmt = "Remove the leading and trailing underscores from the name")]
+  Rename,
+}
+
+impl LintRule for NoUnderscoreDangle {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoUnderscoreDangleOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allow": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Identifiers that are allowed to have dangling underscores."
+        },
+        "allowAfterThis": {
+          "type": "boolean",
+          "description": "Allow dangling underscores in members of `this`.",
+          "default": false
+        },
+        "allowAfterSuper": {
+          "type": "boolean",
+          "description": "Allow dangling underscores in members of `super`.",
+          "default": false
+        },
+        "allowAfterThisConstructor": {
+          "type": "boolean",
+          "description": "Allow dangling underscores in members of `this.constructor`.",
+          "default": false
+        },
+        "enforceInMethodNames": {
+          "type": "boolean",
+          "description": "Also check names of methods.",
+          "default": false
+        },
+        "allowFunctionParams": {
+          "type": "boolean",
+          "description": "Allow dangling underscores in names of function parameters.",
+          "default": true
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoUnderscoreDangleOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUnderscoreDangleHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_underscore_dangle.md")
+  }
+}
+
+fn has_dangling_underscore(name: &str) -> bool {
+  name != "_" && (name.starts_with('_') || name.ends_with('_'))
+}
+
+struct NoUnderscoreDangleHandler<'a> {
+  options: &'a NoUnderscoreDangleOptions,
+}
+
+impl<'a> NoUnderscoreDangleHandler<'a> {
+  fn check(&self, ctx: &mut Context, span: Span, name: &str, display: String) {
+    if !has_dangling_underscore(name)
+      || self.options.allow.iter().any(|allowed| allowed == name)
+    {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(
+      span,
+      CODE,
+      NoUnderscoreDangleMessage::Dangling(display),
+      NoUnderscoreDangleHint::Rename,
+    );
+  }
+
+  fn check_ident(&self, ctx: &mut Context, ident: &Ident) {
+    self.check(ctx, ident.span, &ident.sym, ident.sym.to_string());
+  }
+
+  fn check_params<'p>(
+    &self,
+    ctx: &mut Context,
+    params: impl Iterator<Item = &'p Pat>,
+  ) {
+    if self.options.allow_function_params {
+      return;
+    }
+
+    for param in params {
+      let ident = match param {
+        Pat::Ident(binding) => &binding.id,
+        Pat::Assign(assign) => match &*assign.left {
+          Pat::Ident(binding) => &binding.id,
+          _ => continue,
+        },
+        Pat::Rest(rest) => match &*rest.arg {
+          Pat::Ident(binding) => &binding.id,
+          _ => continue,
+        },
+        _ => continue,
+      };
+      self.check_ident(ctx, ident);
+    }
+  }
+
+  fn check_method_name(&self, ctx: &mut Context, key: &PropName) {
+    if !self.options.enforce_in_method_names {
+      return;
+    }
+    if let PropName::Ident(ident) = key {
+      self.check_ident(ctx, ident);
+    }
+  }
+
+  fn is_allowed_object(&self, obj: &ExprOrSuper) -> bool {
+    match obj {
+      ExprOrSuper::Super(_) => self.options.allow_after_super,
+      ExprOrSuper::Expr(expr) => match &**expr {
+        Expr::This(_) => self.options.allow_after_this,
+        Expr::Member(member) => {
+          self.options.allow_after_this_constructor
+            && matches!(member.obj, ExprOrSuper::Expr(ref obj) if matches!(**obj, Expr::This(_)))
+            && !member.computed
+            && matches!(&*member.prop, Expr::Ident(prop) if prop.sym == *"constructor")
+        }
+        _ => false,
+      },
+    }
+  }
+}
+
+impl<'a> Handler for NoUnderscoreDangleHandler<'a> {
+  fn var_declarator(
+    &mut self,
+    var_declarator: &ast_view::VarDeclarator,
+    ctx: &mut Context,
+  ) {
+    if let Pat::Ident(binding) = &var_declarator.inner.name {
+      self.check_ident(ctx, &binding.id);
+    }
+  }
+
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    self.check_ident(ctx, fn_decl.ident.inner);
+  }
+
+  fn fn_expr(&mut self, fn_expr: &ast_view::FnExpr, ctx: &mut Context) {
+    if let Some(ident) = &fn_expr.inner.ident {
+      self.check_ident(ctx, ident);
+    }
+  }
+
+  fn function(&mut self, function: &ast_view::Function, ctx: &mut Context) {
+    self.check_params(ctx, function.inner.params.iter().map(|p| &p.pat));
+  }
+
+  fn arrow_expr(&mut self, arrow: &ast_view::ArrowExpr, ctx: &mut Context) {
+    self.check_params(ctx, arrow.inner.params.iter());
+  }
+
+  fn member_expr(&mut self, member: &ast_view::MemberExpr, ctx: &mut Context) {
+    if member.inner.computed {
+      return;
+    }
+    let prop = match &*member.inner.prop {
+      Expr::Ident(prop) => prop,
+      _ => return,
+    };
+    if prop.sym == *"__proto__" || self.is_allowed_object(&member.inner.obj) {
+      return;
+    }
+    self.check_ident(ctx, prop);
+  }
+
+  fn class_method(
+    &mut self,
+    method: &ast_view::ClassMethod,
+    ctx: &mut Context,
+  ) {
+    self.check_method_name(ctx, &method.inner.key);
+  }
+
+  fn method_prop(&mut self, method: &ast_view::MethodProp, ctx: &mut Context) {
+    self.check_method_name(ctx, &method.inner.key);
+  }
+
+  fn private_method(
+    &mut self,
+    method: &ast_view::PrivateMethod,
+    ctx: &mut Context,
+  ) {
+    if !self.options.enforce_in_method_names {
+      return;
+    }
+    let ident = &method.inner.key.id;
+    self.check(ctx, ident.span, &ident.sym, format!("#{}", ident.sym));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.32.0/tests/lib/rules/no-underscore-dangle.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_underscore_dangle_valid() {
+    assert_lint_ok! {
+      NoUnderscoreDangle,
+      "var foo_bar = 1;",
+      "function foo_bar() {}",
+      "foo.bar.__proto__;",
+      "console.log(__filename); console.log(__dirname);",
+      "var _ = require('underscore');",
+      "var a = b._;",
+      "function foo(_bar) {}",
+      "function foo(bar_) {}",
+      "const foo = { onClick(..._args) {} };",
+      "class foo { _bar() {} }",
+      "class foo { #_bar() {} }",
+      "const o = { _bar() {} };",
+      "const { _foo } = obj;",
+      "foo[_bar];",
+      "foo['_bar'];",
+    };
+
+    assert_lint_ok! {
+      NoUnderscoreDangle,
+      options: NoUnderscoreDangleOptions {
+        allow: vec!["foo_".to_string(), "_bar".to_string()],
+        ..Default::default()
+      },
+      "var foo_ = 1;",
+      "x._bar;",
+    };
+
+    assert_lint_ok! {
+      NoUnderscoreDangle,
+      options: NoUnderscoreDangleOptions {
+        allow_after_this: true,
+        allow_after_super: true,
+        allow_after_this_constructor: true,
+        ..Default::default()
+      },
+      "var a = this.foo_;",
+      "this._bar = 1;",
+      "class foo extends bar { baz() { return super._qux; } }",
+      "class foo { bar() { return this.constructor._baz; } }",
+    };
+  }
+
+  #[test]
+  fn no_underscore_dangle_invalid() {
+    assert_lint_err! {
+      NoUnderscoreDangle,
+      "var _foo = 1": [
+        {
+          col: 4,
+          message: NoUnderscoreDangleMessage::Dangling("_foo".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+      "var foo_ = 1": [
+        {
+          col: 4,
+          message: NoUnderscoreDangleMessage::Dangling("foo_".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+      "function _foo() {}": [
+        {
+          col: 9,
+          message: NoUnderscoreDangleMessage::Dangling("_foo".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+      "const f = function bar_() {};": [
+        {
+          col: 19,
+          message: NoUnderscoreDangleMessage::Dangling("bar_".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+      "foo.bar._baz;": [
+        {
+          col: 8,
+          message: NoUnderscoreDangleMessage::Dangling("_baz".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+      "this._bar = 1;": [
+        {
+          col: 5,
+          message: NoUnderscoreDangleMessage::Dangling("_bar".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+      "class foo extends bar { baz() { return super._qux; } }": [
+        {
+          col: 45,
+          message: NoUnderscoreDangleMessage::Dangling("_qux".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+      "class foo { bar() { return this.constructor._baz; } }": [
+        {
+          col: 44,
+          message: NoUnderscoreDangleMessage::Dangling("_baz".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnderscoreDangle,
+      options: NoUnderscoreDangleOptions {
+        allow_after_this: true,
+        ..Default::default()
+      },
+      "this._foo._bar;": [
+        {
+          col: 10,
+          message: NoUnderscoreDangleMessage::Dangling("_bar".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnderscoreDangle,
+      options: NoUnderscoreDangleOptions {
+        enforce_in_method_names: true,
+        ..Default::default()
+      },
+      "class foo { _bar() {} }": [
+        {
+          col: 12,
+          message: NoUnderscoreDangleMessage::Dangling("_bar".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+      "class foo { #bar_() {} }": [
+        {
+          col: 13,
+          message: NoUnderscoreDangleMessage::Dangling("#bar_".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+      "const o = { _bar() {} };": [
+        {
+          col: 12,
+          message: NoUnderscoreDangleMessage::Dangling("_bar".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnderscoreDangle,
+      options: NoUnderscoreDangleOptions {
+        allow_function_params: false,
+        ..Default::default()
+      },
+      "function foo(_bar, baz_ = 1, ...qux_) {}": [
+        {
+          col: 13,
+          message: NoUnderscoreDangleMessage::Dangling("_bar".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        },
+        {
+          col: 19,
+          message: NoUnderscoreDangleMessage::Dangling("baz_".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        },
+        {
+          col: 32,
+          message: NoUnderscoreDangleMessage::Dangling("qux_".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+      "const f = (_bar) => {};": [
+        {
+          col: 11,
+          message: NoUnderscoreDangleMessage::Dangling("_bar".to_string()),
+          hint: NoUnderscoreDangleHint::Rename,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,302 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUnescapedEntities {
+  options: NoUnescapedEntitiesOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnescapedEntitiesOptions {
+  /// Characters that must be escaped in JSX text.
+  pub forbid: Vec<char>,
+}
+
+impl Default for NoUnescapedEntitiesOptions {
+  fn default() -> Self {
+    NoUnescapedEntitiesOptions {
+      forbid: vec!['>', '"', '\'', '}'],
+    }
+  }
+}
+
+impl NoUnescapedEntities {
+  pub fn with_options(options: NoUnescapedEntitiesOptions) -> Arc<Self> {
+    Arc::new(NoUnescapedEntities { options })
+  }
+}
+
+const CODE: &str = "no-unescaped-entities";
+
+#[derive(Display)]
+enum NoUnescapedEntitiesMessage {
+  #[display(fmt = "`{}` must be escaped in JSX text", _0)]
+  Unescaped(char),
+}
+
+#[derive(Display)]
+enum NoUnescapedEntitiesHint {
+  #[display(fmt = "Replace it with {}", _0)]
+  Replace(String),
+}
+
+#[derive(Display)]
+enum NoUnescapedEntitiesFix {
+  #[display(fmt = "Replace with `{}`", _0)]
+  Replace(String),
+}
+
+impl LintRule for NoUnescapedEntities {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoUnescapedEntitiesOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "forbid": {
+          "type": "array",
+          "items": { "type": "string", "minLength": 1, "maxLength": 1 },
+          "description": "Characters that must be escaped in JSX text.",
+          "default": [">", "\"", "'", "}"]
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoUnescapedEntitiesOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUnescapedEntitiesHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unescaped_entities.md")
+  }
+}
+
+/// Returns the entities `c` can be written as.
+fn alternatives(c: char) -> Vec<String> {
+  let named: &[&str] = match c {
+    '>' => &["&gt;"],
+    '<' => &["&lt;"],
+    '"' => &["&quot;", "&ldquo;", "&#34;", "&rdquo;"],
+    '\'' => &["&apos;", "&lsquo;", "&#39;", "&rsquo;"],
+    '}' => &["&#125;"],
+    '{' => &["&#123;"],
+    '&' => &["&amp;"],
+    _ => &[],
+  };
+  if named.is_empty() {
+    vec![format!("&#{};", c as u32)]
+  } else {
+    named.iter().map(|entity| entity.to_string()).collect()
+  }
+}
+
+struct NoUnescapedEntitiesHandler<'a> {
+  options: &'a NoUnescapedEntitiesOptions,
+}
+
+impl<'a> Handler for NoUnescapedEntitiesHandler<'a> {
+  fn jsx_text(&mut self, jsx_text: &ast_view::JSXText, ctx: &mut Context) {
+    let span = jsx_text.span();
+    let text = ctx.file_text_substring(&span).to_string();
+    for (index, c) in text.char_indices() {
+      if !self.options.forbid.contains(&c) {
+        continue;
+      }
+      let lo = span.lo() + BytePos(index as u32);
+      let char_span =
+        Span::new(lo, lo + BytePos(c.len_utf8() as u32), Default::default());
+      let alternatives = alternatives(c);
+      let hint = alternatives
+        .iter()
+        .map(|entity| format!("`{}`", entity))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let fixes = alternatives
+        .into_iter()
+        .map(|entity| LintFix {
+          description: NoUnescapedEntitiesFix::Replace(entity.clone())
+            .to_string(),
+          changes: vec![ctx.fix_change(char_span, entity)],
+          applicability: None,
+        })
+        .collect();
+      ctx.add_diagnostic_with_fixes(
+        char_span,
+        CODE,
+        NoUnescapedEntitiesMessage::Unescaped(c),
+        Some(NoUnescapedEntitiesHint::Replace(hint).to_string()),
+        fixes,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.31.10/tests/lib/rules/no-unescaped-entities.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_unescaped_entities_valid() {
+    assert_lint_ok! {
+      NoUnescapedEntities,
+      filename: "foo.tsx",
+      "<div>Here is some text!</div>",
+      "<div>I&rsquo;ve escaped some entities: &gt; &lt; &amp;</div>",
+      "<div>{\">\" + \"}\"}</div>",
+      "<div>{\"Don't\"}</div>",
+      "<div title=\"'quoted'\">text</div>",
+      "<div>&lt; is fine</div>",
+      "<div>{}</div>",
+      "const a = '>';",
+    };
+
+    assert_lint_ok! {
+      NoUnescapedEntities,
+      options: NoUnescapedEntitiesOptions { forbid: vec!['&'] },
+      filename: "foo.tsx",
+      "<div>Don't > stop</div>",
+    };
+  }
+
+  #[test]
+  fn no_unescaped_entities_invalid() {
+    assert_lint_err! {
+      NoUnescapedEntities,
+      filename: "foo.tsx",
+      "<div>></div>": [
+        {
+          col: 5,
+          message: NoUnescapedEntitiesMessage::Unescaped('>'),
+          hint: NoUnescapedEntitiesHint::Replace("`&gt;`".to_string()),
+          fix: (
+            NoUnescapedEntitiesFix::Replace("&gt;".to_string()),
+            "<div>&gt;</div>",
+          ),
+        }
+      ],
+      "<div>Don't</div>": [
+        {
+          col: 8,
+          message: NoUnescapedEntitiesMessage::Unescaped('\''),
+          hint: NoUnescapedEntitiesHint::Replace(
+            "`&apos;`, `&lsquo;`, `&#39;`, `&rsquo;`".to_string()
+          ),
+          fix: (
+            NoUnescapedEntitiesFix::Replace("&apos;".to_string()),
+            "<div>Don&apos;t</div>",
+          ),
+        }
+      ],
+      "<div>\"quoted\"</div>": [
+        {
+          col: 5,
+          message: NoUnescapedEntitiesMessage::Unescaped('"'),
+          hint: NoUnescapedEntitiesHint::Replace(
+            "`&quot;`, `&ldquo;`, `&#34;`, `&rdquo;`".to_string()
+          ),
+        },
+        {
+          col: 12,
+          message: NoUnescapedEntitiesMessage::Unescaped('"'),
+          hint: NoUnescapedEntitiesHint::Replace(
+            "`&quot;`, `&ldquo;`, `&#34;`, `&rdquo;`".to_string()
+          ),
+        }
+      ],
+      "<div>{foo}}</div>": [
+        {
+          col: 10,
+          message: NoUnescapedEntitiesMessage::Unescaped('}'),
+          hint: NoUnescapedEntitiesHint::Replace("`&#125;`".to_string()),
+          fix: (
+            NoUnescapedEntitiesFix::Replace("&#125;".to_string()),
+            "<div>{foo}&#125;</div>",
+          ),
+        }
+      ],
+      "<>\n  <span>café ></span>\n</>": [
+        {
+          line: 2,
+          col: 13,
+          message: NoUnescapedEntitiesMessage::Unescaped('>'),
+          hint: NoUnescapedEntitiesHint::Replace("`&gt;`".to_string()),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnescapedEntities,
+      options: NoUnescapedEntitiesOptions { forbid: vec!['&', '~'] },
+      filename: "foo.tsx",
+      "<div>Tom & Jerry ~</div>": [
+        {
+          col: 9,
+          message: NoUnescapedEntitiesMessage::Unescaped('&'),
+          hint: NoUnescapedEntitiesHint::Replace("`&amp;`".to_string()),
+          fix: (
+            NoUnescapedEntitiesFix::Replace("&amp;".to_string()),
+            "<div>Tom &amp; Jerry ~</div>",
+          ),
+        },
+        {
+          col: 17,
+          message: NoUnescapedEntitiesMessage::Unescaped('~'),
+          hint: NoUnescapedEntitiesHint::Replace("`&#126;`".to_string()),
+          fix: (
+            NoUnescapedEntitiesFix::Replace("&#126;".to_string()),
+            "<div>Tom & Jerry &#126;</div>",
+          ),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,874 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::jsx_attr_name;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{JSXAttrOrSpread, JSXElementName};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUnknownProperty {
+  options: NoUnknownPropertyOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnknownPropertyOptions {
+  /// Names of properties that are allowed in addition to the known ones.
+  pub ignore: Vec<String>,
+}
+
+impl NoUnknownProperty {
+  pub fn with_options(options: NoUnknownPropertyOptions) -> Arc<Self> {
+    Arc::new(NoUnknownProperty { options })
+  }
+}
+
+const CODE: &str = "no-unknown-property";
+
+#[derive(Display)]
+enum NoUnknownPropertyMessage {
+  #[display(fmt = "Unknown property `{}` found", _0)]
+  Unknown(String),
+  #[display(fmt = "Unknown property `{}` found, use `{}` instead", _0, _1)]
+  Rename(String, &'static str),
+}
+
+#[derive(Display)]
+enum NoUnknownPropertyHint {
+  #[display(
+    fmt = "Remove the property, or use a `data-*` attribute to store custom data"
+  )]
+  Remove,
+}
+
+#[derive(Display)]
+enum NoUnknownPropertyFix {
+  #[display(fmt = "Rename to `{}`", _0)]
+  Rename(&'static str),
+}
+
+impl LintRule for NoUnknownProperty {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoUnknownPropertyOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "ignore": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Names of properties that are allowed in addition to the known ones.",
+          "default": []
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoUnknownPropertyOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUnknownPropertyHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unknown_property.md")
+  }
+}
+
+/// Props of DOM elements as they are named in React, including the props
+/// that React itself handles.
+const DOM_PROPERTIES: &[&str] = &[
+  // React
+  "children",
+  "dangerouslySetInnerHTML",
+  "key",
+  "ref",
+  "suppressContentEditableWarning",
+  "suppressHydrationWarning",
+  "defaultChecked",
+  "defaultValue",
+  // HTML
+  "accept",
+  "acceptCharset",
+  "accessKey",
+  "action",
+  "allow",
+  "allowFullScreen",
+  "alt",
+  "as",
+  "async",
+  "autoCapitalize",
+  "autoComplete",
+  "autoCorrect",
+  "autoFocus",
+  "autoPlay",
+  "autoSave",
+  "capture",
+  "cellPadding",
+  "cellSpacing",
+  "challenge",
+  "charSet",
+  "checked",
+  "cite",
+  "className",
+  "color",
+  "cols",
+  "colSpan",
+  "content",
+  "contentEditable",
+  "contextMenu",
+  "controls",
+  "controlsList",
+  "coords",
+  "crossOrigin",
+  "data",
+  "dateTime",
+  "decoding",
+  "default",
+  "defer",
+  "dir",
+  "disabled",
+  "disablePictureInPicture",
+  "disableRemotePlayback",
+  "download",
+  "draggable",
+  "encType",
+  "enterKeyHint",
+  "fetchPriority",
+  "form",
+  "formAction",
+  "formEncType",
+  "formMethod",
+  "formNoValidate",
+  "formTarget",
+  "frameBorder",
+  "headers",
+  "height",
+  "hidden",
+  "high",
+  "href",
+  "hrefLang",
+  "htmlFor",
+  "httpEquiv",
+  "icon",
+  "id",
+  "imageSizes",
+  "imageSrcSet",
+  "inert",
+  "inputMode",
+  "integrity",
+  "is",
+  "itemID",
+  "itemProp",
+  "itemRef",
+  "itemScope",
+  "itemType",
+  "keyParams",
+  "keyType",
+  "kind",
+  "label",
+  "lang",
+  "list",
+  "loading",
+  "loop",
+  "low",
+  "manifest",
+  "marginHeight",
+  "marginWidth",
+  "max",
+  "maxLength",
+  "media",
+  "mediaGroup",
+  "method",
+  "min",
+  "minLength",
+  "multiple",
+  "muted",
+  "name",
+  "noModule",
+  "nonce",
+  "noValidate",
+  "open",
+  "optimum",
+  "pattern",
+  "placeholder",
+  "playsInline",
+  "popover",
+  "popoverTarget",
+  "popoverTargetAction",
+  "poster",
+  "preload",
+  "profile",
+  "radioGroup",
+  "readOnly",
+  "referrerPolicy",
+  "rel",
+  "required",
+  "results",
+  "reversed",
+  "role",
+  "rows",
+  "rowSpan",
+  "sandbox",
+  "scope",
+  "scoped",
+  "scrolling",
+  "seamless",
+  "security",
+  "selected",
+  "shape",
+  "size",
+  "sizes",
+  "slot",
+  "span",
+  "spellCheck",
+  "src",
+  "srcDoc",
+  "srcLang",
+  "srcSet",
+  "start",
+  "step",
+  "style",
+  "summary",
+  "tabIndex",
+  "target",
+  "title",
+  "translate",
+  "type",
+  "unselectable",
+  "useMap",
+  "value",
+  "width",
+  "wmode",
+  "wrap",
+  // Obsolete HTML
+  "align",
+  "bgColor",
+  "border",
+  "noWrap",
+  "vAlign",
+  // RDFa
+  "about",
+  "datatype",
+  "inlist",
+  "prefix",
+  "property",
+  "resource",
+  "typeof",
+  "vocab",
+  // SVG
+  "accentHeight",
+  "accumulate",
+  "additive",
+  "alignmentBaseline",
+  "allowReorder",
+  "alphabetic",
+  "amplitude",
+  "arabicForm",
+  "ascent",
+  "attributeName",
+  "attributeType",
+  "autoReverse",
+  "azimuth",
+  "baseFrequency",
+  "baselineShift",
+  "baseProfile",
+  "bbox",
+  "begin",
+  "bias",
+  "by",
+  "calcMode",
+  "capHeight",
+  "clip",
+  "clipPath",
+  "clipPathUnits",
+  "clipRule",
+  "colorInterpolation",
+  "colorInterpolationFilters",
+  "colorProfile",
+  "colorRendering",
+  "contentScriptType",
+  "contentStyleType",
+  "cursor",
+  "cx",
+  "cy",
+  "d",
+  "decelerate",
+  "descent",
+  "diffuseConstant",
+  "direction",
+  "display",
+  "divisor",
+  "dominantBaseline",
+  "dur",
+  "dx",
+  "dy",
+  "edgeMode",
+  "elevation",
+  "enableBackground",
+  "end",
+  "exponent",
+  "externalResourcesRequired",
+  "fill",
+  "fillOpacity",
+  "fillRule",
+  "filter",
+  "filterRes",
+  "filterUnits",
+  "floodColor",
+  "floodOpacity",
+  "focusable",
+  "fontFamily",
+  "fontSize",
+  "fontSizeAdjust",
+  "fontStretch",
+  "fontStyle",
+  "fontVariant",
+  "fontWeight",
+  "format",
+  "fr",
+  "from",
+  "fx",
+  "fy",
+  "g1",
+  "g2",
+  "glyphName",
+  "glyphOrientationHorizontal",
+  "glyphOrientationVertical",
+  "glyphRef",
+  "gradientTransform",
+  "gradientUnits",
+  "hanging",
+  "horizAdvX",
+  "horizOriginX",
+  "ideographic",
+  "imageRendering",
+  "in",
+  "in2",
+  "intercept",
+  "k",
+  "k1",
+  "k2",
+  "k3",
+  "k4",
+  "kernelMatrix",
+  "kernelUnitLength",
+  "kerning",
+  "keyPoints",
+  "keySplines",
+  "keyTimes",
+  "lengthAdjust",
+  "letterSpacing",
+  "lightingColor",
+  "limitingConeAngle",
+  "local",
+  "markerEnd",
+  "markerHeight",
+  "markerMid",
+  "markerStart",
+  "markerUnits",
+  "markerWidth",
+  "mask",
+  "maskContentUnits",
+  "maskUnits",
+  "mathematical",
+  "mode",
+  "numOctaves",
+  "offset",
+  "opacity",
+  "operator",
+  "order",
+  "orient",
+  "orientation",
+  "origin",
+  "overflow",
+  "overlinePosition",
+  "overlineThickness",
+  "paintOrder",
+  "panose1",
+  "pathLength",
+  "patternContentUnits",
+  "patternTransform",
+  "patternUnits",
+  "pointerEvents",
+  "points",
+  "pointsAtX",
+  "pointsAtY",
+  "pointsAtZ",
+  "preserveAlpha",
+  "preserveAspectRatio",
+  "primitiveUnits",
+  "r",
+  "radius",
+  "refX",
+  "refY",
+  "renderingIntent",
+  "repeatCount",
+  "repeatDur",
+  "requiredExtensions",
+  "requiredFeatures",
+  "restart",
+  "result",
+  "rotate",
+  "rx",
+  "ry",
+  "scale",
+  "seed",
+  "shapeRendering",
+  "slope",
+  "spacing",
+  "specularConstant",
+  "specularExponent",
+  "speed",
+  "spreadMethod",
+  "startOffset",
+  "stdDeviation",
+  "stemh",
+  "stemv",
+  "stitchTiles",
+  "stopColor",
+  "stopOpacity",
+  "strikethroughPosition",
+  "strikethroughThickness",
+  "string",
+  "stroke",
+  "strokeDasharray",
+  "strokeDashoffset",
+  "strokeLinecap",
+  "strokeLinejoin",
+  "strokeMiterlimit",
+  "strokeOpacity",
+  "strokeWidth",
+  "surfaceScale",
+  "systemLanguage",
+  "tableValues",
+  "targetX",
+  "targetY",
+  "textAnchor",
+  "textDecoration",
+  "textLength",
+  "textRendering",
+  "to",
+  "transform",
+  "transformOrigin",
+  "u1",
+  "u2",
+  "underlinePosition",
+  "underlineThickness",
+  "unicode",
+  "unicodeBidi",
+  "unicodeRange",
+  "unitsPerEm",
+  "vAlphabetic",
+  "values",
+  "vectorEffect",
+  "version",
+  "vertAdvY",
+  "vertOriginX",
+  "vertOriginY",
+  "vHanging",
+  "vIdeographic",
+  "viewBox",
+  "viewTarget",
+  "visibility",
+  "vMathematical",
+  "widths",
+  "wordSpacing",
+  "writingMode",
+  "x",
+  "x1",
+  "x2",
+  "xChannelSelector",
+  "xHeight",
+  "xlinkActuate",
+  "xlinkArcrole",
+  "xlinkHref",
+  "xlinkRole",
+  "xlinkShow",
+  "xlinkTitle",
+  "xlinkType",
+  "xmlBase",
+  "xmlLang",
+  "xmlns",
+  "xmlnsXlink",
+  "xmlSpace",
+  "y",
+  "y1",
+  "y2",
+  "yChannelSelector",
+  "z",
+  "zoomAndPan",
+];
+
+/// Event handler props. Each of them may also be suffixed with `Capture`.
+const EVENT_PROPERTIES: &[&str] = &[
+  "onAbort",
+  "onAnimationEnd",
+  "onAnimationIteration",
+  "onAnimationStart",
+  "onAuxClick",
+  "onBeforeInput",
+  "onBlur",
+  "onCanPlay",
+  "onCanPlayThrough",
+  "onChange",
+  "onClick",
+  "onCompositionEnd",
+  "onCompositionStart",
+  "onCompositionUpdate",
+  "onContextMenu",
+  "onCopy",
+  "onCut",
+  "onDoubleClick",
+  "onDrag",
+  "onDragEnd",
+  "onDragEnter",
+  "onDragExit",
+  "onDragLeave",
+  "onDragOver",
+  "onDragStart",
+  "onDrop",
+  "onDurationChange",
+  "onEmptied",
+  "onEncrypted",
+  "onEnded",
+  "onError",
+  "onFocus",
+  "onGotPointerCapture",
+  "onInput",
+  "onInvalid",
+  "onKeyDown",
+  "onKeyPress",
+  "onKeyUp",
+  "onLoad",
+  "onLoadedData",
+  "onLoadedMetadata",
+  "onLoadStart",
+  "onLostPointerCapture",
+  "onMouseDown",
+  "onMouseEnter",
+  "onMouseLeave",
+  "onMouseMove",
+  "onMouseOut",
+  "onMouseOver",
+  "onMouseUp",
+  "onPaste",
+  "onPause",
+  "onPlay",
+  "onPlaying",
+  "onPointerCancel",
+  "onPointerDown",
+  "onPointerEnter",
+  "onPointerLeave",
+  "onPointerMove",
+  "onPointerOut",
+  "onPointerOver",
+  "onPointerUp",
+  "onProgress",
+  "onRateChange",
+  "onReset",
+  "onResize",
+  "onScroll",
+  "onSeeked",
+  "onSeeking",
+  "onSelect",
+  "onStalled",
+  "onSubmit",
+  "onSuspend",
+  "onTimeUpdate",
+  "onToggle",
+  "onTouchCancel",
+  "onTouchEnd",
+  "onTouchMove",
+  "onTouchStart",
+  "onTransitionEnd",
+  "onVolumeChange",
+  "onWaiting",
+  "onWheel",
+];
+
+/// HTML attribute names whose React name can't be derived from them.
+const RENAMES: &[(&str, &str)] = &[
+  ("class", "className"),
+  ("for", "htmlFor"),
+  ("ondblclick", "onDoubleClick"),
+];
+
+static KNOWN_PROPERTIES: Lazy<HashSet<String>> = Lazy::new(|| {
+  DOM_PROPERTIES
+    .iter()
+    .map(|prop| prop.to_string())
+    .chain(
+      EVENT_PROPERTIES
+        .iter()
+        .flat_map(|event| vec![event.to_string(), format!("{}Capture", event)]),
+    )
+    .collect()
+});
+
+/// Known properties by their lowercase name, e.g. `tabindex` -> `tabIndex`.
+static LOWERCASE_PROPERTIES: Lazy<HashMap<String, &'static str>> =
+  Lazy::new(|| {
+    DOM_PROPERTIES
+      .iter()
+      .chain(EVENT_PROPERTIES)
+      .map(|prop| (prop.to_ascii_lowercase(), *prop))
+      .collect()
+  });
+
+/// Returns the React name of an attribute written as in HTML or SVG, e.g.
+/// `class`, `tabindex`, `stroke-width` or `xlink:href`.
+fn suggestion(name: &str) -> Option<&'static str> {
+  if let Some((_, rename)) = RENAMES.iter().find(|(from, _)| *from == name) {
+    return Some(rename);
+  }
+  let lowercase = name
+    .chars()
+    .filter(|c| !matches!(c, '-' | ':'))
+    .collect::<String>()
+    .to_ascii_lowercase();
+  LOWERCASE_PROPERTIES.get(&lowercase).copied()
+}
+
+/// Whether the element is a built-in DOM element, e.g. `<div>`, rather than a
+/// component, e.g. `<Foo>`, or a custom element, e.g. `<my-element>`.
+fn is_dom_element(name: &JSXElementName) -> bool {
+  match name {
+    JSXElementName::Ident(ident) => {
+      ident.sym.starts_with(|c: char| c.is_ascii_lowercase())
+        && !ident.sym.contains('-')
+    }
+    _ => false,
+  }
+}
+
+struct NoUnknownPropertyHandler<'a> {
+  options: &'a NoUnknownPropertyOptions,
+}
+
+impl<'a> NoUnknownPropertyHandler<'a> {
+  fn is_allowed(&self, name: &str) -> bool {
+    name.starts_with("data-")
+      || name.starts_with("aria-")
+      || KNOWN_PROPERTIES.contains(name)
+      || self.options.ignore.iter().any(|ignored| ignored == name)
+  }
+}
+
+impl<'a> Handler for NoUnknownPropertyHandler<'a> {
+  fn jsx_opening_element(
+    &mut self,
+    jsx_opening_element: &ast_view::JSXOpeningElement,
+    ctx: &mut Context,
+  ) {
+    let element = jsx_opening_element.inner;
+    if !is_dom_element(&element.name) {
+      return;
+    }
+
+    for attr in &element.attrs {
+      let attr = match attr {
+        JSXAttrOrSpread::JSXAttr(attr) => attr,
+        JSXAttrOrSpread::SpreadElement(_) => continue,
+      };
+      let name = jsx_attr_name(&attr.name);
+      if self.is_allowed(&name) {
+        continue;
+      }
+
+      match suggestion(&name) {
+        Some(suggestion) => {
+          // Well-known renames like `class` are always right, while a
+          // property that only differs in case may be a custom attribute.
+          let is_rename = RENAMES.iter().any(|(from, _)| *from == name);
+          let fix = LintFix {
+            description: NoUnknownPropertyFix::Rename(suggestion).to_string(),
+            changes: vec![ctx.fix_change(attr.name.span(), suggestion)],
+            applicability: if is_rename {
+              Some(Applicability::Automatic)
+            } else {
+              None
+            },
+          };
+          ctx.add_diagnostic_with_fixes(
+            attr.span,
+            CODE,
+            NoUnknownPropertyMessage::Rename(name, suggestion),
+            None,
+            vec![fix],
+          );
+        }
+        None => ctx.add_diagnostic_with_hint(
+          attr.span,
+          CODE,
+          NoUnknownPropertyMessage::Unknown(name),
+          NoUnknownPropertyHint::Remove,
+        ),
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.31.10/tests/lib/rules/no-unknown-property.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_unknown_property_valid() {
+    assert_lint_ok! {
+      NoUnknownProperty,
+      filename: "foo.tsx",
+      "<App class=\"bar\" />;",
+      "<App for=\"bar\" />;",
+      "<Foo.bar for=\"bar\" />;",
+      "<App accept-charset=\"bar\" />;",
+      "<my-element class=\"foo\" for=\"bar\" />;",
+      "<div className=\"bar\" htmlFor=\"baz\" />;",
+      "<div data-foo=\"bar\" aria-label=\"baz\" />;",
+      "<div onClick={onClick} onMouseDownCapture={onMouseDown} />;",
+      "<div key=\"foo\" ref={ref} dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+      "<input tabIndex={0} readOnly autoFocus />;",
+      "<meta charSet=\"utf-8\" httpEquiv=\"refresh\" />;",
+      "<svg viewBox=\"0 0 24 24\"><path strokeWidth={2} d=\"M0 0\" /></svg>;",
+      "<use xlinkHref=\"#foo\" />;",
+      "<div {...props} />;",
+    };
+
+    assert_lint_ok! {
+      NoUnknownProperty,
+      options: NoUnknownPropertyOptions {
+        ignore: vec!["css".to_string()],
+      },
+      filename: "foo.tsx",
+      "<div css={styles} />;",
+    };
+  }
+
+  #[test]
+  fn no_unknown_property_invalid() {
+    assert_lint_err! {
+      NoUnknownProperty,
+      filename: "foo.tsx",
+      "<div class=\"bar\" />;": [
+        {
+          col: 5,
+          message: NoUnknownPropertyMessage::Rename("class".to_string(), "className"),
+          fix: (NoUnknownPropertyFix::Rename("className"), "<div className=\"bar\" />;"),
+        }
+      ],
+      "<label for=\"bar\" />;": [
+        {
+          col: 7,
+          message: NoUnknownPropertyMessage::Rename("for".to_string(), "htmlFor"),
+          fix: (NoUnknownPropertyFix::Rename("htmlFor"), "<label htmlFor=\"bar\" />;"),
+        }
+      ],
+      "<div onclick={onClick} />;": [
+        {
+          col: 5,
+          message: NoUnknownPropertyMessage::Rename("onclick".to_string(), "onClick"),
+          fix: (NoUnknownPropertyFix::Rename("onClick"), "<div onClick={onClick} />;"),
+        }
+      ],
+      "<div ondblclick={onClick} />;": [
+        {
+          col: 5,
+          message: NoUnknownPropertyMessage::Rename("ondblclick".to_string(), "onDoubleClick"),
+          fix: (NoUnknownPropertyFix::Rename("onDoubleClick"), "<div onDoubleClick={onClick} />;"),
+        }
+      ],
+      "<input tabindex=\"0\" readonly />;": [
+        {
+          col: 7,
+          message: NoUnknownPropertyMessage::Rename("tabindex".to_string(), "tabIndex"),
+          fix: (NoUnknownPropertyFix::Rename("tabIndex"), "<input tabIndex=\"0\" readonly />;"),
+        },
+        {
+          col: 20,
+          message: NoUnknownPropertyMessage::Rename("readonly".to_string(), "readOnly"),
+          fix: (NoUnknownPropertyFix::Rename("readOnly"), "<input tabindex=\"0\" readOnly />;"),
+        }
+      ],
+      "<meta http-equiv=\"refresh\" />;": [
+        {
+          col: 6,
+          message: NoUnknownPropertyMessage::Rename("http-equiv".to_string(), "httpEquiv"),
+          fix: (NoUnknownPropertyFix::Rename("httpEquiv"), "<meta httpEquiv=\"refresh\" />;"),
+        }
+      ],
+      "<path stroke-width={2} />;": [
+        {
+          col: 6,
+          message: NoUnknownPropertyMessage::Rename("stroke-width".to_string(), "strokeWidth"),
+          fix: (NoUnknownPropertyFix::Rename("strokeWidth"), "<path strokeWidth={2} />;"),
+        }
+      ],
+      "<use xlink:href=\"#foo\" />;": [
+        {
+          col: 5,
+          message: NoUnknownPropertyMessage::Rename("xlink:href".to_string(), "xlinkHref"),
+          fix: (NoUnknownPropertyFix::Rename("xlinkHref"), "<use xlinkHref=\"#foo\" />;"),
+        }
+      ],
+      "<div foo=\"bar\" />;": [
+        {
+          col: 5,
+          message: NoUnknownPropertyMessage::Unknown("foo".to_string()),
+          hint: NoUnknownPropertyHint::Remove,
+        }
+      ],
+      "<div onClickk={onClick} />;": [
+        {
+          col: 5,
+          message: NoUnknownPropertyMessage::Unknown("onClickk".to_string()),
+          hint: NoUnknownPropertyHint::Remove,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,443 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{BinaryOp, Expr, Lit, UnaryOp};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUnnecessaryCondition {
+  options: NoUnnecessaryConditionOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnnecessaryConditionOptions {
+  /// Allow constant loop conditions, e.g. `while (true)`.
+  pub allow_constant_loop_conditions: bool,
+}
+
+impl NoUnnecessaryCondition {
+  pub fn with_options(options: NoUnnecessaryConditionOptions) -> Arc<Self> {
+    Arc::new(NoUnnecessaryCondition { options })
+  }
+}
+
+const CODE: &str = "no-unnecessary-condition";
+
+#[derive(Display)]
+enum NoUnnecessaryConditionMessage {
+  #[display(fmt = "Unnecessary conditional, the value is always truthy")]
+  AlwaysTruthy,
+  #[display(fmt = "Unnecessary conditional, the value is always falsy")]
+  AlwaysFalsy,
+  #[display(
+    fmt = "Unnecessary conditional, the left-hand side of `??` is always nullish"
+  )]
+  AlwaysNullish,
+  #[display(
+    fmt = "Unnecessary conditional, the left-hand side of `??` is never nullish"
+  )]
+  NeverNullish,
+  #[display(fmt = "Unnecessary default, the value is `undefined` either way")]
+  DefaultUndefined,
+}
+
+#[derive(Display)]
+enum NoUnnecessaryConditionHint {
+  #[display(fmt = "Remove the redundant expression")]
+  Remove,
+}
+
+impl LintRule for NoUnnecessaryCondition {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoUnnecessaryConditionOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowConstantLoopConditions": {
+          "type": "boolean",
+          "description": "Allow constant loop conditions, e.g. `while (true)`.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoUnnecessaryConditionOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUnnecessaryConditionHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unnecessary_condition.md")
+  }
+}
+
+/// The statically known value of an expression.
+#[derive(Clone, Copy)]
+struct Constant {
+  truthy: bool,
+  nullish: bool,
+}
+
+fn is_undefined(expr: &Expr) -> bool {
+  match unwrap_paren(expr) {
+    Expr::Ident(ident) => ident.sym == *"undefined",
+    Expr::Unary(unary) => {
+      unary.op == UnaryOp::Void && matches!(&*unary.arg, Expr::Lit(_))
+    }
+    _ => false,
+  }
+}
+
+/// Returns the value of the expression if it is known from its syntax alone,
+/// i.e. it is a literal or always evaluates to an object.
+fn constant_of(expr: &Expr) -> Option<Constant> {
+  let expr = unwrap_paren(expr);
+  if is_undefined(expr) {
+    return Some(Constant {
+      truthy: false,
+      nullish: true,
+    });
+  }
+  let truthy = match expr {
+    Expr::Lit(Lit::Bool(b)) => b.value,
+    Expr::Lit(Lit::Num(n)) => n.value != 0.0 && !n.value.is_nan(),
+    Expr::Lit(Lit::Str(s)) => !s.value.is_empty(),
+    Expr::Lit(Lit::BigInt(b)) => b.value != Default::default(),
+    Expr::Lit(Lit::Regex(_))
+    | Expr::Array(_)
+    | Expr::Object(_)
+    | Expr::Fn(_)
+    | Expr::Arrow(_)
+    | Expr::Class(_) => true,
+    Expr::Lit(Lit::Null(_)) => {
+      return Some(Constant {
+        truthy: false,
+        nullish: true,
+      })
+    }
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+      tpl.quasis.iter().any(|quasi| !quasi.raw.value.is_empty())
+    }
+    _ => return None,
+  };
+  Some(Constant {
+    truthy,
+    nullish: false,
+  })
+}
+
+fn truthiness_message(constant: Constant) -> NoUnnecessaryConditionMessage {
+  if constant.truthy {
+    NoUnnecessaryConditionMessage::AlwaysTruthy
+  } else {
+    NoUnnecessaryConditionMessage::AlwaysFalsy
+  }
+}
+
+fn is_logical(op: BinaryOp) -> bool {
+  matches!(op, BinaryOp::LogicalAnd | BinaryOp::LogicalOr)
+}
+
+struct NoUnnecessaryConditionHandler<'a> {
+  options: &'a NoUnnecessaryConditionOptions,
+}
+
+impl<'a> NoUnnecessaryConditionHandler<'a> {
+  fn report(
+    &self,
+    ctx: &mut Context,
+    expr: &Expr,
+    message: NoUnnecessaryConditionMessage,
+  ) {
+    ctx.add_diagnostic_with_hint(
+      expr.span(),
+      CODE,
+      message,
+      NoUnnecessaryConditionHint::Remove,
+    );
+  }
+
+  fn check_condition(&self, ctx: &mut Context, test: &Expr, is_loop: bool) {
+    let test = unwrap_paren(test);
+    match constant_of(test) {
+      Some(_) if is_loop && self.options.allow_constant_loop_conditions => {}
+      Some(constant) => self.report(ctx, test, truthiness_message(constant)),
+      None => self.check_boolean_operands(ctx, test),
+    }
+  }
+
+  /// Reports right-hand operands of `&&` and `||` that don't change the result
+  /// when it's converted to a boolean, e.g. `true` in `if (x && true)`. The
+  /// left-hand operands are reported by `bin_expr` regardless of the context.
+  fn check_boolean_operands(&self, ctx: &mut Context, expr: &Expr) {
+    let bin = match unwrap_paren(expr) {
+      Expr::Bin(bin) if is_logical(bin.op) => bin,
+      _ => return,
+    };
+    match constant_of(&bin.right) {
+      Some(constant)
+        if constant.truthy == (bin.op == BinaryOp::LogicalAnd)
+          && constant_of(&bin.left).is_none() =>
+      {
+        self.report(ctx, unwrap_paren(&bin.right), truthiness_message(constant))
+      }
+      _ => {}
+    }
+    self.check_boolean_operands(ctx, &bin.left);
+    self.check_boolean_operands(ctx, &bin.right);
+  }
+}
+
+impl<'a> Handler for NoUnnecessaryConditionHandler<'a> {
+  fn if_stmt(&mut self, if_stmt: &ast_view::IfStmt, ctx: &mut Context) {
+    self.check_condition(ctx, &if_stmt.inner.test, false);
+  }
+
+  fn cond_expr(&mut self, cond_expr: &ast_view::CondExpr, ctx: &mut Context) {
+    self.check_condition(ctx, &cond_expr.inner.test, false);
+  }
+
+  fn while_stmt(
+    &mut self,
+    while_stmt: &ast_view::WhileStmt,
+    ctx: &mut Context,
+  ) {
+    self.check_condition(ctx, &while_stmt.inner.test, true);
+  }
+
+  fn do_while_stmt(
+    &mut self,
+    do_while_stmt: &ast_view::DoWhileStmt,
+    ctx: &mut Context,
+  ) {
+    self.check_condition(ctx, &do_while_stmt.inner.test, true);
+  }
+
+  fn for_stmt(&mut self, for_stmt: &ast_view::ForStmt, ctx: &mut Context) {
+    if let Some(test) = &for_stmt.inner.test {
+      self.check_condition(ctx, test, true);
+    }
+  }
+
+  fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
+    let bin = bin_expr.inner;
+    let left = unwrap_paren(&bin.left);
+    match bin.op {
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr => {
+        if let Some(constant) = constant_of(left) {
+          self.report(ctx, left, truthiness_message(constant));
+        }
+      }
+      BinaryOp::NullishCoalescing => {
+        if let Some(constant) = constant_of(left) {
+          let message = if constant.nullish {
+            NoUnnecessaryConditionMessage::AlwaysNullish
+          } else {
+            NoUnnecessaryConditionMessage::NeverNullish
+          };
+          self.report(ctx, left, message);
+        } else if is_undefined(&bin.right) {
+          self.report(
+            ctx,
+            unwrap_paren(&bin.right),
+            NoUnnecessaryConditionMessage::DefaultUndefined,
+          );
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unnecessary_condition_valid() {
+    assert_lint_ok! {
+      NoUnnecessaryCondition,
+      "if (x) {}",
+      "if (x && y) {}",
+      "if (x || 'default') {}",
+      "const y = x && true;",
+      "const y = x || false;",
+      "const y = x ?? 'default';",
+      "const y = x ?? null;",
+      "const y = x?.y ?? z;",
+      "while (x) {}",
+      "for (;;) {}",
+      "for (; i < 10; i++) {}",
+      "do {} while (x);",
+      "const y = x ? 1 : 2;",
+      "if (`${x}`) {}",
+      "if (x === true) {}",
+      "if (!x) {}",
+    };
+
+    assert_lint_ok! {
+      NoUnnecessaryCondition,
+      options: NoUnnecessaryConditionOptions {
+        allow_constant_loop_conditions: true,
+      },
+      "while (true) {}",
+      "do {} while (1);",
+      "for (; true; ) {}",
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_condition_invalid() {
+    assert_lint_err! {
+      NoUnnecessaryCondition,
+      "if (true && x) {}": [
+        {
+          col: 4,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "if (x && true) {}": [
+        {
+          col: 9,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "if (x || (y && 0) || '') {}": [
+        {
+          col: 21,
+          message: NoUnnecessaryConditionMessage::AlwaysFalsy,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "const y = 0 || x;": [
+        {
+          col: 10,
+          message: NoUnnecessaryConditionMessage::AlwaysFalsy,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "const y = {} && x;": [
+        {
+          col: 10,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "while (1) {}": [
+        {
+          col: 7,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "do {} while (false);": [
+        {
+          col: 13,
+          message: NoUnnecessaryConditionMessage::AlwaysFalsy,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "for (; 'foo'; ) {}": [
+        {
+          col: 7,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "if (null) {}": [
+        {
+          col: 4,
+          message: NoUnnecessaryConditionMessage::AlwaysFalsy,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "const y = (() => {}) ? 1 : 2;": [
+        {
+          col: 11,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "const y = x ?? undefined;": [
+        {
+          col: 15,
+          message: NoUnnecessaryConditionMessage::DefaultUndefined,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "const y = x ?? void 0;": [
+        {
+          col: 15,
+          message: NoUnnecessaryConditionMessage::DefaultUndefined,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "const y = null ?? x;": [
+        {
+          col: 10,
+          message: NoUnnecessaryConditionMessage::AlwaysNullish,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "const y = 'foo' ?? x;": [
+        {
+          col: 10,
+          message: NoUnnecessaryConditionMessage::NeverNullish,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+      "const y = [] ?? x;": [
+        {
+          col: 10,
+          message: NoUnnecessaryConditionMessage::NeverNullish,
+          hint: NoUnnecessaryConditionHint::Remove,
+        }
+      ],
+    };
+  }
+}
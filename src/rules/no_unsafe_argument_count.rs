@@ -0,0 +1,276 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Function, Ident, Pat, PatOrExpr};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::utils::find_ids;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::utils::Id;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUnsafeArgumentCount;
+
+const CODE: &str = "no-unsafe-argument-count";
+
+#[derive(Display)]
+enum NoUnsafeArgumentCountMessage {
+  #[display(fmt = "Expected {} arguments, but got {}", _0, _1)]
+  TooMany(usize, usize),
+}
+
+#[derive(Display)]
+enum NoUnsafeArgumentCountHint {
+  #[display(fmt = "Remove the extra arguments, or add parameters to `{}`", _0)]
+  RemoveOrAddParams(String),
+}
+
+impl LintRule for NoUnsafeArgumentCount {
+  fn new() -> Arc<Self> {
+    Arc::new(NoUnsafeArgumentCount)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    let mut collector = CallCollector::default();
+    collector.traverse(program, context);
+
+    for call in collector.calls {
+      if collector.reassigned.contains(&call.callee) {
+        continue;
+      }
+      let arity = match collector.arities.get(&call.callee) {
+        Some(Some(arity)) => *arity,
+        _ => continue,
+      };
+      if call.arg_spans.len() <= arity {
+        continue;
+      }
+
+      let extra = &call.arg_spans[arity..];
+      let span = extra[0].with_hi(extra[extra.len() - 1].hi);
+      context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        NoUnsafeArgumentCountMessage::TooMany(arity, call.arg_spans.len()),
+        NoUnsafeArgumentCountHint::RemoveOrAddParams(call.name),
+      );
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unsafe_argument_count.md")
+  }
+}
+
+/// Returns the maximum number of arguments `function` can use, or `None` if
+/// it accepts any number of arguments.
+fn arity(function: &Function) -> Option<usize> {
+  let mut arity = 0;
+  for param in &function.params {
+    match &param.pat {
+      Pat::Rest(_) => return None,
+      // The `this` parameter of TypeScript is not an actual parameter.
+      Pat::Ident(ident) if &*ident.id.sym == "this" => {}
+      _ => arity += 1,
+    }
+  }
+
+  let mut finder = ArgumentsFinder { found: false };
+  function.body.visit_with(&DUMMY_NODE, &mut finder);
+  if finder.found {
+    None
+  } else {
+    Some(arity)
+  }
+}
+
+struct Call {
+  callee: Id,
+  name: String,
+  arg_spans: Vec<Span>,
+}
+
+/// Collects the arity of function declarations and the calls to them.
+#[derive(Default)]
+struct CallCollector {
+  /// `None` means that the function accepts any number of arguments.
+  arities: HashMap<Id, Option<usize>>,
+  reassigned: HashSet<Id>,
+  calls: Vec<Call>,
+}
+
+impl Handler for CallCollector {
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, _ctx: &mut Context) {
+    let function = &fn_decl.inner.function;
+    // Overload signatures and ambient declarations have no body
+    if function.body.is_none() {
+      return;
+    }
+    let arity = arity(function);
+    // A redeclared function could be any of the declarations
+    self
+      .arities
+      .entry(fn_decl.inner.ident.to_id())
+      .and_modify(|prev| {
+        *prev = match (*prev, arity) {
+          (Some(a), Some(b)) => Some(a.max(b)),
+          _ => None,
+        }
+      })
+      .or_insert(arity);
+  }
+
+  fn assign_expr(
+    &mut self,
+    assign_expr: &ast_view::AssignExpr,
+    _ctx: &mut Context,
+  ) {
+    match &assign_expr.inner.left {
+      PatOrExpr::Pat(pat) => {
+        self.reassigned.extend(find_ids::<_, Id>(&**pat));
+      }
+      PatOrExpr::Expr(expr) => {
+        if let Expr::Ident(ident) = &**expr {
+          self.reassigned.insert(ident.to_id());
+        }
+      }
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, _ctx: &mut Context) {
+    let call_expr = call_expr.inner;
+    let callee: &Ident = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Ident(ident) => ident,
+        _ => return,
+      },
+      ExprOrSuper::Super(_) => return,
+    };
+    // The number of spread arguments is unknown.
+    if call_expr.args.iter().any(|arg| arg.spread.is_some()) {
+      return;
+    }
+
+    self.calls.push(Call {
+      callee: callee.to_id(),
+      name: callee.sym.to_string(),
+      arg_spans: call_expr.args.iter().map(|arg| arg.expr.span()).collect(),
+    });
+  }
+}
+
+/// Checks if a function body uses `arguments`, which makes the function
+/// variadic.
+struct ArgumentsFinder {
+  found: bool,
+}
+
+impl Visit for ArgumentsFinder {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident, _: &dyn Node) {
+    if &*ident.sym == "arguments" {
+      self.found = true;
+    }
+  }
+
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {
+    // Nested functions have their own `arguments`
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unsafe_argument_count_valid() {
+    assert_lint_ok! {
+      NoUnsafeArgumentCount,
+      "function foo(a, b) {} foo(1, 2);",
+      "function foo(a, b) {} foo(1);",
+      "function foo(a, b = 2) {} foo(1, 2);",
+      "function foo({ a }, [b]) {} foo({ a: 1 }, [2]);",
+      "function foo(a, ...rest) {} foo(1, 2, 3);",
+      "function foo() { return arguments.length; } foo(1, 2);",
+      "function foo() { const f = () => arguments[0]; return f(); } foo(1);",
+      "function foo(a) {} foo(...args);",
+      "function foo(a) {} foo(1, ...args);",
+      "function foo(this: Window, a: number) {} foo(1);",
+      "function foo(a: number): void; function foo(a: number, b: number): void; function foo(a: number, b?: number) {} foo(1, 2);",
+      "declare function foo(): void; foo(1);",
+      "bar(1, 2, 3);",
+      "const foo = (a) => a; foo(1, 2);",
+      "obj.foo(1, 2); function foo() {}",
+      "function foo() {} foo = (a, b) => a + b; foo(1, 2);",
+      "function foo(a) {} function bar() { function foo(a, b) {} foo(1, 2); }",
+    };
+  }
+
+  #[test]
+  fn no_unsafe_argument_count_invalid() {
+    assert_lint_err! {
+      NoUnsafeArgumentCount,
+      "function foo() {} foo(1);": [
+        {
+          col: 22,
+          message: NoUnsafeArgumentCountMessage::TooMany(0, 1),
+          hint: NoUnsafeArgumentCountHint::RemoveOrAddParams("foo".to_string()),
+        }
+      ],
+      "function foo(a, b) {} foo(1, 2, 3, 4);": [
+        {
+          col: 32,
+          message: NoUnsafeArgumentCountMessage::TooMany(2, 4),
+          hint: NoUnsafeArgumentCountHint::RemoveOrAddParams("foo".to_string()),
+        }
+      ],
+      "foo(1, 2); function foo(a) {}": [
+        {
+          col: 7,
+          message: NoUnsafeArgumentCountMessage::TooMany(1, 2),
+          hint: NoUnsafeArgumentCountHint::RemoveOrAddParams("foo".to_string()),
+        }
+      ],
+      "function foo(a) { function bar() { return arguments; } } foo(1, 2);": [
+        {
+          col: 64,
+          message: NoUnsafeArgumentCountMessage::TooMany(1, 2),
+          hint: NoUnsafeArgumentCountHint::RemoveOrAddParams("foo".to_string()),
+        }
+      ],
+      "function foo(this: Window) {} foo(window);": [
+        {
+          col: 34,
+          message: NoUnsafeArgumentCountMessage::TooMany(0, 1),
+          hint: NoUnsafeArgumentCountHint::RemoveOrAddParams("foo".to_string()),
+        }
+      ],
+      "function foo(a) {} function bar() { function foo(a, b) {} } foo(1, 2);": [
+        {
+          col: 67,
+          message: NoUnsafeArgumentCountMessage::TooMany(1, 2),
+          hint: NoUnsafeArgumentCountHint::RemoveOrAddParams("foo".to_string()),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,224 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::scope_tree::ScopeId;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view::{self as ast_view, NodeTrait};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUnsafeDeclarationMerging;
+
+const CODE: &str = "no-unsafe-declaration-merging";
+const MESSAGE: &str =
+  "Unsafe declaration merging between classes and interfaces";
+const HINT: &str =
+  "Rename the interface, or declare the members in the class instead";
+const CLASS_RELATED_MESSAGE: &str = "Class with the same name declared here";
+const INTERFACE_RELATED_MESSAGE: &str =
+  "Interface with the same name declared here";
+
+impl LintRule for NoUnsafeDeclarationMerging {
+  fn new() -> Arc<Self> {
+    Arc::new(NoUnsafeDeclarationMerging)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    let mut collector = DeclarationCollector::default();
+    collector.traverse(program, context);
+
+    let mut reports = Vec::new();
+    for (key, classes) in &collector.classes {
+      let interfaces = match collector.interfaces.get(key) {
+        Some(interfaces) => interfaces,
+        None => continue,
+      };
+      for class in classes {
+        reports.extend(
+          interfaces
+            .iter()
+            .map(|interface| (*class, *interface, INTERFACE_RELATED_MESSAGE)),
+        );
+      }
+      for interface in interfaces {
+        reports.extend(
+          classes
+            .iter()
+            .map(|class| (*interface, *class, CLASS_RELATED_MESSAGE)),
+        );
+      }
+    }
+    reports.sort_by_key(|(span, related, _)| (span.lo, related.lo));
+
+    for (span, related, related_message) in reports {
+      let related = context.related_information(related, related_message);
+      context.add_diagnostic_with_related(
+        span,
+        CODE,
+        MESSAGE,
+        Some(HINT.to_string()),
+        vec![related],
+      );
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unsafe_declaration_merging.md")
+  }
+}
+
+/// Collects the names of classes and interfaces declared in each scope.
+#[derive(Default)]
+struct DeclarationCollector {
+  classes: HashMap<(ScopeId, JsWord), Vec<Span>>,
+  interfaces: HashMap<(ScopeId, JsWord), Vec<Span>>,
+}
+
+impl Handler for DeclarationCollector {
+  fn class_decl(
+    &mut self,
+    class_decl: &ast_view::ClassDecl,
+    ctx: &mut Context,
+  ) {
+    let scope = ctx.scope_tree().scope_of(class_decl.as_node());
+    let ident = class_decl.ident;
+    self
+      .classes
+      .entry((scope, ident.sym().clone()))
+      .or_default()
+      .push(ident.span());
+  }
+
+  fn ts_interface_decl(
+    &mut self,
+    interface_decl: &ast_view::TsInterfaceDecl,
+    ctx: &mut Context,
+  ) {
+    let scope = ctx.scope_tree().scope_of(interface_decl.as_node());
+    let ident = interface_decl.id;
+    self
+      .interfaces
+      .entry((scope, ident.sym().clone()))
+      .or_default()
+      .push(ident.span());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/typescript-eslint/typescript-eslint/blob/v5.40.0/packages/eslint-plugin/tests/rules/no-unsafe-declaration-merging.test.ts
+  // MIT Licensed.
+
+  #[test]
+  fn no_unsafe_declaration_merging_valid() {
+    assert_lint_ok! {
+      NoUnsafeDeclarationMerging,
+      "interface Foo {} class Bar implements Foo {}",
+      "namespace Foo {} namespace Foo {}",
+      "enum Foo {} namespace Foo {}",
+      "namespace Fooo {} function Foo() {}",
+      "const Foo = class {};",
+      "interface Foo { props: string; } function bar() { return class Foo {}; }",
+      "interface Foo { props: string; } (function bar() { class Foo {} })();",
+      "declare global { interface Foo {} } class Foo {}",
+      "interface Foo {} namespace Bar { class Foo {} }",
+      "class Foo {} if (x) { interface Foo {} }",
+    };
+  }
+
+  #[test]
+  fn no_unsafe_declaration_merging_invalid() {
+    assert_lint_err! {
+      NoUnsafeDeclarationMerging,
+      "interface Foo {} class Foo {}": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          related: (1, 23, CLASS_RELATED_MESSAGE),
+        },
+        {
+          col: 23,
+          message: MESSAGE,
+          hint: HINT,
+          related: (1, 10, INTERFACE_RELATED_MESSAGE),
+        }
+      ],
+      "class Foo {} interface Foo {}": [
+        {
+          col: 6,
+          message: MESSAGE,
+          hint: HINT,
+          related: (1, 23, INTERFACE_RELATED_MESSAGE),
+        },
+        {
+          col: 23,
+          message: MESSAGE,
+          hint: HINT,
+          related: (1, 6, CLASS_RELATED_MESSAGE),
+        }
+      ],
+      "export interface Foo {} export class Foo {}": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+          related: (1, 37, CLASS_RELATED_MESSAGE),
+        },
+        {
+          col: 37,
+          message: MESSAGE,
+          hint: HINT,
+          related: (1, 17, INTERFACE_RELATED_MESSAGE),
+        }
+      ],
+      "namespace Qux { interface Foo {} class Foo {} }": [
+        {
+          col: 26,
+          message: MESSAGE,
+          hint: HINT,
+          related: (1, 39, CLASS_RELATED_MESSAGE),
+        },
+        {
+          col: 39,
+          message: MESSAGE,
+          hint: HINT,
+          related: (1, 26, INTERFACE_RELATED_MESSAGE),
+        }
+      ],
+      "function foo() { interface Foo {} class Foo {} }": [
+        {
+          col: 27,
+          message: MESSAGE,
+          hint: HINT,
+          related: (1, 40, CLASS_RELATED_MESSAGE),
+        },
+        {
+          col: 40,
+          message: MESSAGE,
+          hint: HINT,
+          related: (1, 27, INTERFACE_RELATED_MESSAGE),
+        }
+      ],
+    };
+  }
+}
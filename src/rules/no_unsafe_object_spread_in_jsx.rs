@@ -0,0 +1,227 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  Expr, JSXAttrOrSpread, ObjectLit, Prop, PropName, PropOrSpread,
+};
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view as ast_view;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUnsafeObjectSpreadInJsx;
+
+const CODE: &str = "no-unsafe-object-spread-in-jsx";
+const MESSAGE: &str =
+  "Spreading an object literal into JSX props creates a new object on every render";
+const HINT: &str = "Pass the properties as attributes directly";
+const FIX_DESC: &str = "Pass the properties as attributes";
+
+impl LintRule for NoUnsafeObjectSpreadInJsx {
+  fn new() -> Arc<Self> {
+    Arc::new(NoUnsafeObjectSpreadInJsx)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUnsafeObjectSpreadInJsxHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unsafe_object_spread_in_jsx.md")
+  }
+}
+
+fn object_literal(expr: &Expr) -> Option<&ObjectLit> {
+  match expr {
+    Expr::Object(object) => Some(object),
+    Expr::Paren(paren) => object_literal(&paren.expr),
+    _ => None,
+  }
+}
+
+fn is_jsx_attr_name(name: &str) -> bool {
+  let mut chars = name.chars();
+  matches!(
+    chars.next(),
+    Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$'
+  ) && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '-'))
+}
+
+/// Turns the properties of `object` into JSX attributes, e.g. `{ a, b: 1 }`
+/// into `a={a} b={1}`. Returns `None` if some property can't be written as an
+/// attribute, like a spread or a method.
+fn to_attributes(ctx: &Context, object: &ObjectLit) -> Option<String> {
+  let mut attributes = Vec::new();
+  for prop in &object.props {
+    let prop = match prop {
+      PropOrSpread::Prop(prop) => prop,
+      PropOrSpread::Spread(_) => return None,
+    };
+    let (name, value) = match &**prop {
+      Prop::Shorthand(ident) => (ident.sym.to_string(), ident.span),
+      Prop::KeyValue(key_value) => {
+        let name = match &key_value.key {
+          PropName::Ident(ident) => ident.sym.to_string(),
+          PropName::Str(str) => str.value.to_string(),
+          _ => return None,
+        };
+        (name, key_value.value.span())
+      }
+      _ => return None,
+    };
+    if !is_jsx_attr_name(&name) {
+      return None;
+    }
+    attributes.push(format!(
+      "{}={{{}}}",
+      name,
+      ctx.file_text_substring(&value)
+    ));
+  }
+  Some(attributes.join(" "))
+}
+
+/// Extends the span of a spread attribute to the braces around it.
+fn with_braces(ctx: &Context, spread: Span) -> Option<Span> {
+  let text = ctx.source_file().text();
+  let file_start = ctx.source_file().span().lo();
+  let lo = (spread.lo() - file_start).0 as usize;
+  let hi = (spread.hi() - file_start).0 as usize;
+  let before = text[..lo].trim_end();
+  let after = text[hi..].trim_start();
+  if !before.ends_with('{') || !after.starts_with('}') {
+    return None;
+  }
+  let lo = before.len() - 1;
+  let hi = text.len() - after.len() + 1;
+  Some(Span::new(
+    file_start + BytePos(lo as u32),
+    file_start + BytePos(hi as u32),
+    Default::default(),
+  ))
+}
+
+struct NoUnsafeObjectSpreadInJsxHandler;
+
+impl Handler for NoUnsafeObjectSpreadInJsxHandler {
+  fn jsx_opening_element(
+    &mut self,
+    jsx_opening_element: &ast_view::JSXOpeningElement,
+    ctx: &mut Context,
+  ) {
+    for attr in &jsx_opening_element.inner.attrs {
+      let spread = match attr {
+        JSXAttrOrSpread::SpreadElement(spread) => spread,
+        JSXAttrOrSpread::JSXAttr(_) => continue,
+      };
+      let object = match object_literal(&spread.expr) {
+        Some(object) => object,
+        None => continue,
+      };
+      let span =
+        with_braces(ctx, spread.span()).unwrap_or_else(|| spread.span());
+
+      let fix = to_attributes(ctx, object)
+        .filter(|attributes| !attributes.is_empty())
+        .map(|attributes| LintFix {
+          description: FIX_DESC.to_string(),
+          changes: vec![ctx.fix_change(span, attributes)],
+          applicability: None,
+        });
+      ctx.add_diagnostic_with_fixes(
+        span,
+        CODE,
+        MESSAGE,
+        Some(HINT.to_string()),
+        fix.into_iter().collect(),
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unsafe_object_spread_in_jsx_valid() {
+    assert_lint_ok! {
+      NoUnsafeObjectSpreadInJsx,
+      filename: "foo.tsx",
+      "<Foo {...props} />",
+      "<Foo {...this.props} />",
+      "<Foo {...getProps()} />",
+      "<Foo a={a} b={1} />",
+      "<Foo style={{ color: 'red' }} />",
+      "const props = { a, b }; <Foo {...props} />",
+    };
+  }
+
+  #[test]
+  fn no_unsafe_object_spread_in_jsx_invalid() {
+    assert_lint_err! {
+      NoUnsafeObjectSpreadInJsx,
+      filename: "foo.tsx",
+      "<Foo {...{ a, b }} />": [
+        {
+          col: 5,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "<Foo a={a} b={b} />"),
+        }
+      ],
+      "<Foo x={1} { ...{ a: 1, 'aria-label': label, on: () => f() } } y />": [
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "<Foo x={1} a={1} aria-label={label} on={() => f()} y />"),
+        }
+      ],
+      "<div {...{ ...rest, a }}>text</div>": [
+        {
+          col: 5,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "<Foo {...{ [key]: value }} />": [
+        {
+          col: 5,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "<Foo {...({ a })} />": [
+        {
+          col: 5,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "<Foo a={a} />"),
+        }
+      ],
+      "<Foo {...{}} />": [
+        {
+          col: 5,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,502 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  Accessibility, Class, ClassMember, Expr, ExprOrSuper, ExprStmt, Lit,
+  MemberExpr, MethodKind, ObjectPat, ObjectPatProp, ParamOrTsParamProp, Pat,
+  PatOrExpr, PrivateMethod, PrivateProp, PropName, TsParamPropParam,
+};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::Span;
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node as SwcNode;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUnusedPrivateClassMembers;
+
+const CODE: &str = "no-unused-private-class-members";
+
+#[derive(Display)]
+enum NoUnusedPrivateClassMembersMessage {
+  #[display(fmt = "`{}` is defined but never used", _0)]
+  Unused(String),
+}
+
+#[derive(Display)]
+enum NoUnusedPrivateClassMembersHint {
+  #[display(fmt = "Remove the unused member, or use it within the class")]
+  RemoveOrUse,
+}
+
+impl LintRule for NoUnusedPrivateClassMembers {
+  fn new() -> Arc<Self> {
+    Arc::new(NoUnusedPrivateClassMembers)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUnusedPrivateClassMembersHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unused_private_class_members.md")
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum PrivateKind {
+  /// `#foo`, which can only be accessed as `#foo` within the class.
+  Hash,
+  /// TypeScript's `private foo`, which is accessed like any property.
+  TypeScript,
+}
+
+struct PrivateMember {
+  kind: PrivateKind,
+  name: JsWord,
+  span: Span,
+  /// Getters and setters are used by writes as well as reads.
+  is_accessor: bool,
+}
+
+fn prop_name(key: &PropName) -> Option<JsWord> {
+  match key {
+    PropName::Ident(ident) => Some(ident.sym.clone()),
+    PropName::Str(str) => Some(str.value.clone()),
+    _ => None,
+  }
+}
+
+fn is_ts_private(accessibility: Option<Accessibility>) -> bool {
+  accessibility == Some(Accessibility::Private)
+}
+
+/// Collects the private members declared by the class. Only the first
+/// declaration of a name is kept, e.g. of a getter and setter pair or of
+/// method overloads.
+fn private_members(class: &Class) -> Vec<PrivateMember> {
+  let mut members: Vec<PrivateMember> = Vec::new();
+  let mut add = |member: PrivateMember| {
+    if !members
+      .iter()
+      .any(|m| m.kind == member.kind && m.name == member.name)
+    {
+      members.push(member);
+    }
+  };
+
+  for member in &class.body {
+    match member {
+      ClassMember::PrivateProp(prop) => add(PrivateMember {
+        kind: PrivateKind::Hash,
+        name: prop.key.id.sym.clone(),
+        span: prop.span,
+        is_accessor: false,
+      }),
+      ClassMember::PrivateMethod(method) => add(PrivateMember {
+        kind: PrivateKind::Hash,
+        name: method.key.id.sym.clone(),
+        span: method.span,
+        is_accessor: method.kind != MethodKind::Method,
+      }),
+      ClassMember::ClassProp(prop)
+        if is_ts_private(prop.accessibility) && !prop.computed =>
+      {
+        if let Expr::Ident(ident) = &*prop.key {
+          add(PrivateMember {
+            kind: PrivateKind::TypeScript,
+            name: ident.sym.clone(),
+            span: prop.span,
+            is_accessor: false,
+          });
+        }
+      }
+      ClassMember::Method(method) if is_ts_private(method.accessibility) => {
+        if let Some(name) = prop_name(&method.key) {
+          add(PrivateMember {
+            kind: PrivateKind::TypeScript,
+            name,
+            span: method.span,
+            is_accessor: method.kind != MethodKind::Method,
+          });
+        }
+      }
+      ClassMember::Constructor(constructor) => {
+        for param in &constructor.params {
+          let param = match param {
+            ParamOrTsParamProp::TsParamProp(param)
+              if is_ts_private(param.accessibility) =>
+            {
+              param
+            }
+            _ => continue,
+          };
+          let ident = match &param.param {
+            TsParamPropParam::Ident(binding) => &binding.id,
+            TsParamPropParam::Assign(assign) => match &*assign.left {
+              Pat::Ident(binding) => &binding.id,
+              _ => continue,
+            },
+          };
+          add(PrivateMember {
+            kind: PrivateKind::TypeScript,
+            name: ident.sym.clone(),
+            span: param.span,
+            is_accessor: false,
+          });
+        }
+      }
+      _ => {}
+    }
+  }
+  members
+}
+
+/// Collects the accesses of members within a class.
+#[derive(Default)]
+struct MemberAccesses {
+  hash_reads: HashSet<JsWord>,
+  hash_writes: HashSet<JsWord>,
+  reads: HashSet<JsWord>,
+  writes: HashSet<JsWord>,
+  /// Whether a member of `this` is accessed with a computed key, e.g.
+  /// `this[key]`, so that any TypeScript private member may be used.
+  dynamic: bool,
+  /// `#names` redeclared by nested classes, which refer to the members of
+  /// those classes within them.
+  shadowed: Vec<HashSet<JsWord>>,
+  is_root_visited: bool,
+}
+
+impl MemberAccesses {
+  fn is_used(&self, member: &PrivateMember) -> bool {
+    let (reads, writes) = match member.kind {
+      PrivateKind::Hash => (&self.hash_reads, &self.hash_writes),
+      PrivateKind::TypeScript => {
+        if self.dynamic {
+          return true;
+        }
+        (&self.reads, &self.writes)
+      }
+    };
+    reads.contains(&member.name)
+      || (member.is_accessor && writes.contains(&member.name))
+  }
+
+  fn is_shadowed(&self, name: &JsWord) -> bool {
+    self.shadowed.iter().any(|names| names.contains(name))
+  }
+
+  /// Records the access of the member's property, as a write if `is_write`.
+  fn record(&mut self, member: &MemberExpr, is_write: bool) {
+    let (kind, name) = match (&*member.prop, member.computed) {
+      (Expr::PrivateName(name), _) => {
+        if self.is_shadowed(&name.id.sym) {
+          return;
+        }
+        (PrivateKind::Hash, name.id.sym.clone())
+      }
+      (Expr::Ident(ident), false) => {
+        (PrivateKind::TypeScript, ident.sym.clone())
+      }
+      (Expr::Lit(Lit::Str(str)), true) => {
+        (PrivateKind::TypeScript, str.value.clone())
+      }
+      (prop, true) => {
+        let is_this = matches!(
+          &member.obj,
+          ExprOrSuper::Expr(obj) if matches!(&**obj, Expr::This(_))
+        );
+        if is_this {
+          self.dynamic = true;
+        }
+        prop.visit_with(&DUMMY_NODE, self);
+        return;
+      }
+      _ => return,
+    };
+    let names = match (kind, is_write) {
+      (PrivateKind::Hash, false) => &mut self.hash_reads,
+      (PrivateKind::Hash, true) => &mut self.hash_writes,
+      (PrivateKind::TypeScript, false) => &mut self.reads,
+      (PrivateKind::TypeScript, true) => &mut self.writes,
+    };
+    names.insert(name);
+  }
+}
+
+fn assigned_member(target: &PatOrExpr) -> Option<&MemberExpr> {
+  let expr = match target {
+    PatOrExpr::Expr(expr) => &**expr,
+    PatOrExpr::Pat(pat) => match &**pat {
+      Pat::Expr(expr) => &**expr,
+      _ => return None,
+    },
+  };
+  match expr {
+    Expr::Member(member) => Some(member),
+    _ => None,
+  }
+}
+
+impl Visit for MemberAccesses {
+  noop_visit_type!();
+
+  fn visit_class(&mut self, class: &Class, _: &dyn SwcNode) {
+    if !self.is_root_visited {
+      self.is_root_visited = true;
+      class.visit_children_with(self);
+      return;
+    }
+    let names = private_members(class)
+      .into_iter()
+      .filter(|member| member.kind == PrivateKind::Hash)
+      .map(|member| member.name)
+      .collect();
+    self.shadowed.push(names);
+    class.visit_children_with(self);
+    self.shadowed.pop();
+  }
+
+  // The keys of private members are declarations, not accesses.
+  fn visit_private_prop(&mut self, prop: &PrivateProp, _: &dyn SwcNode) {
+    prop.decorators.visit_with(&DUMMY_NODE, self);
+    prop.value.visit_with(&DUMMY_NODE, self);
+  }
+
+  fn visit_private_method(&mut self, method: &PrivateMethod, _: &dyn SwcNode) {
+    method.function.visit_with(&DUMMY_NODE, self);
+  }
+
+  fn visit_member_expr(&mut self, member: &MemberExpr, _: &dyn SwcNode) {
+    member.obj.visit_with(&DUMMY_NODE, self);
+    self.record(member, false);
+  }
+
+  /// A member that is assigned to or updated in an expression statement
+  /// is only written, e.g. `this.#foo = 1;`.
+  fn visit_expr_stmt(&mut self, expr_stmt: &ExprStmt, _: &dyn SwcNode) {
+    match &*expr_stmt.expr {
+      Expr::Assign(assign) => {
+        if let Some(member) = assigned_member(&assign.left) {
+          member.obj.visit_with(&DUMMY_NODE, self);
+          self.record(member, true);
+          assign.right.visit_with(&DUMMY_NODE, self);
+          return;
+        }
+      }
+      Expr::Update(update) => {
+        if let Expr::Member(member) = &*update.arg {
+          member.obj.visit_with(&DUMMY_NODE, self);
+          self.record(member, true);
+          return;
+        }
+      }
+      _ => {}
+    }
+    expr_stmt.visit_children_with(self);
+  }
+
+  /// `const { foo } = this;`
+  fn visit_object_pat(&mut self, object_pat: &ObjectPat, _: &dyn SwcNode) {
+    for prop in &object_pat.props {
+      match prop {
+        ObjectPatProp::KeyValue(key_value) => {
+          if let Some(name) = prop_name(&key_value.key) {
+            self.reads.insert(name);
+          }
+        }
+        ObjectPatProp::Assign(assign) => {
+          self.reads.insert(assign.key.sym.clone());
+        }
+        ObjectPatProp::Rest(_) => self.dynamic = true,
+      }
+    }
+    object_pat.visit_children_with(self);
+  }
+}
+
+struct NoUnusedPrivateClassMembersHandler;
+
+impl Handler for NoUnusedPrivateClassMembersHandler {
+  fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
+    let members = private_members(class.inner);
+    if members.is_empty() {
+      return;
+    }
+
+    let mut accesses = MemberAccesses::default();
+    class.inner.visit_with(&DUMMY_NODE, &mut accesses);
+
+    for member in members {
+      if accesses.is_used(&member) {
+        continue;
+      }
+      let name = match member.kind {
+        PrivateKind::Hash => format!("#{}", member.name),
+        PrivateKind::TypeScript => member.name.to_string(),
+      };
+      ctx.add_diagnostic_with_hint(
+        member.span,
+        CODE,
+        NoUnusedPrivateClassMembersMessage::Unused(name),
+        NoUnusedPrivateClassMembersHint::RemoveOrUse,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-unused-private-class-members.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_unused_private_class_members_valid() {
+    assert_lint_ok! {
+      NoUnusedPrivateClassMembers,
+      "class Foo {}",
+      "class Foo { publicMember = 42; }",
+      "class Foo { #usedMember = 42; method() { return this.#usedMember; } }",
+      "class Foo { #usedMember = 42; anotherMember = this.#usedMember; }",
+      "class Foo { #usedMember = 42; method() { return someGlobalMethod(this.#usedMember); } }",
+      "class C { #usedMember; foo() { bar(this.#usedMember += 1); } }",
+      "class Foo { #usedMember = 42; method() { return this.#usedMember = 1; } }",
+      "class Foo { #usedMethod() { return 42; } anotherMethod() { return this.#usedMethod(); } }",
+      "class Foo { get #accessor() { return 42; } method() { return this.#accessor; } }",
+      "class Foo { set #accessor(value) {} method() { this.#accessor = 42; } }",
+      "class Foo { set #accessor(value) {} method() { this.#accessor++; } }",
+      "class Foo { static #used = 1; static foo() { return Foo.#used; } }",
+      "class Foo { #used = 1; method(other) { return other.#used; } }",
+      "class Foo { #used = 1; method() { return class { bar() { return this.#used; } }; } }",
+      "class Foo { #used = 1; method() { return () => this.#used; } }",
+      "class Foo { private used = 1; method() { return this.used; } }",
+      "class Foo { private used = 1; method() { return this['used']; } }",
+      "class Foo { private used = 1; method() { const { used } = this; return used; } }",
+      "class Foo { private used = 1; method(key: string) { return this[key]; } }",
+      "class Foo { private used() {} method() { this.used(); } }",
+      "class Foo { private get used() { return 1; } method() { return this.used; } }",
+      "class Foo { constructor(private used: number) {} method() { return this.used; } }",
+      "class Foo { constructor(private readonly used = 1) {} method() { return this.used; } }",
+      "class Foo { protected member = 1; }",
+      "class Foo { public member = 1; }",
+    };
+  }
+
+  #[test]
+  fn no_unused_private_class_members_invalid() {
+    assert_lint_err! {
+      NoUnusedPrivateClassMembers,
+      "class Foo { #unusedMember = 5; }": [
+        {
+          col: 12,
+          message: NoUnusedPrivateClassMembersMessage::Unused("#unusedMember".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class Foo { #usedOnlyInWrite = 5; method() { this.#usedOnlyInWrite = 42; } }": [
+        {
+          col: 12,
+          message: NoUnusedPrivateClassMembersMessage::Unused("#usedOnlyInWrite".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class Foo { #usedOnlyInIncrement; method() { this.#usedOnlyInIncrement++; } }": [
+        {
+          col: 12,
+          message: NoUnusedPrivateClassMembersMessage::Unused("#usedOnlyInIncrement".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class Foo { #unusedMethod() {} }": [
+        {
+          col: 12,
+          message: NoUnusedPrivateClassMembersMessage::Unused("#unusedMethod".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class Foo { get #unusedAccessor() {} set #unusedAccessor(value) {} }": [
+        {
+          col: 12,
+          message: NoUnusedPrivateClassMembersMessage::Unused("#unusedAccessor".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class First {} class Second { #unusedMemberInSecondClass = 5; }": [
+        {
+          col: 30,
+          message: NoUnusedPrivateClassMembersMessage::Unused("#unusedMemberInSecondClass".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class Foo { #x = 1; method() { return class { #x = 2; bar() { return this.#x; } }; } }": [
+        {
+          col: 12,
+          message: NoUnusedPrivateClassMembersMessage::Unused("#x".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class Foo { #x = 1; method() { return class { #x = 2; }; } }": [
+        {
+          col: 12,
+          message: NoUnusedPrivateClassMembersMessage::Unused("#x".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        },
+        {
+          col: 46,
+          message: NoUnusedPrivateClassMembersMessage::Unused("#x".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class Foo { private unused = 1; }": [
+        {
+          col: 12,
+          message: NoUnusedPrivateClassMembersMessage::Unused("unused".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class Foo { private unused() {} method() { this.unused = null; } }": [
+        {
+          col: 12,
+          message: NoUnusedPrivateClassMembersMessage::Unused("unused".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class Foo { constructor(private unused: string) {} }": [
+        {
+          col: 24,
+          message: NoUnusedPrivateClassMembersMessage::Unused("unused".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+      "class Foo { private #x = 1; private y = this.#x; }": [
+        {
+          col: 28,
+          message: NoUnusedPrivateClassMembersMessage::Unused("y".to_string()),
+          hint: NoUnusedPrivateClassMembersHint::RemoveOrUse,
+        }
+      ],
+    };
+  }
+}
@@ -1,28 +1,95 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
+use crate::scope_tree::{Reference, ReferenceKind, ScopeTree};
 use crate::ProgramRef;
 use deno_ast::swc::ast::{
   ArrowExpr, AssignPatProp, CallExpr, CatchClause, ClassDecl, ClassMethod,
-  ClassProp, Constructor, Decl, DefaultDecl, ExportDecl, ExportDefaultDecl,
-  ExportNamedSpecifier, Expr, FnDecl, FnExpr, Function, Ident,
+  Constructor, Decl, DefaultDecl, ExportDecl, ExportDefaultDecl, FnDecl, Ident,
   ImportDefaultSpecifier, ImportNamedSpecifier, ImportStarAsSpecifier,
-  MemberExpr, MethodKind, NamedExport, Param, Pat, PrivateMethod, Prop,
-  PropName, SetterProp, TsEntityName, TsEnumDecl, TsExprWithTypeArgs,
-  TsInterfaceDecl, TsModuleDecl, TsNamespaceDecl, TsPropertySignature,
-  TsTypeAliasDecl, TsTypeQueryExpr, TsTypeRef, VarDecl, VarDeclarator,
+  MethodKind, NamedExport, ObjectPatProp, Param, ParamOrTsParamProp, Pat,
+  PrivateMethod, SetterProp, TsEnumDecl, TsModuleDecl, TsNamespaceDecl,
+  VarDecl, VarDeclarator,
 };
-use deno_ast::swc::atoms::js_word;
+use deno_ast::swc::common::{Span, Spanned, SyntaxContext};
 use deno_ast::swc::utils::ident::IdentLike;
 use deno_ast::swc::utils::{find_ids, Id};
 use deno_ast::swc::visit::{Node, Visit, VisitWith};
 use derive_more::Display;
-use if_chain::if_chain;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::collections::HashSet;
-use std::iter;
 use std::sync::Arc;
 
 #[derive(Debug)]
-pub struct NoUnusedVars;
+pub struct NoUnusedVars {
+  options: NoUnusedVarsOptions,
+  args_ignore_pattern: Option<Regex>,
+  vars_ignore_pattern: Option<Regex>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoUnusedVarsVars {
+  /// Check all variables.
+  All,
+  /// Don't check variables declared at the top level of the file.
+  Local,
+}
+
+impl Default for NoUnusedVarsVars {
+  fn default() -> Self {
+    NoUnusedVarsVars::All
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NoUnusedVarsArgs {
+  /// Only check parameters after the last used one.
+  AfterUsed,
+  /// Check all parameters.
+  All,
+  /// Don't check parameters.
+  None,
+}
+
+impl Default for NoUnusedVarsArgs {
+  fn default() -> Self {
+    NoUnusedVarsArgs::All
+  }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnusedVarsOptions {
+  pub vars: NoUnusedVarsVars,
+  pub args: NoUnusedVarsArgs,
+  /// Ignore variables that are destructured along with a rest property, e.g.
+  /// `a` in `const { a, ...rest } = obj;`.
+  pub ignore_rest_siblings: bool,
+  /// Regular expression matching names of parameters that aren't checked.
+  /// An invalid pattern is ignored.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub args_ignore_pattern: Option<String>,
+  /// Regular expression matching names of variables that aren't checked.
+  /// An invalid pattern is ignored.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub vars_ignore_pattern: Option<String>,
+}
+
+impl NoUnusedVars {
+  pub fn with_options(options: NoUnusedVarsOptions) -> Arc<Self> {
+    let compile = |pattern: &Option<String>| {
+      pattern.as_ref().and_then(|p| Regex::new(p).ok())
+    };
+    Arc::new(NoUnusedVars {
+      args_ignore_pattern: compile(&options.args_ignore_pattern),
+      vars_ignore_pattern: compile(&options.vars_ignore_pattern),
+      options,
+    })
+  }
+}
 
 const CODE: &str = "no-unused-vars";
 
@@ -49,7 +116,7 @@ enum NoUnusedVarsHint {
 
 impl LintRule for NoUnusedVars {
   fn new() -> Arc<Self> {
-    Arc::new(NoUnusedVars)
+    Self::with_options(NoUnusedVarsOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -60,6 +127,53 @@ impl LintRule for NoUnusedVars {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "vars": {
+          "type": "string",
+          "enum": ["all", "local"],
+          "default": "all"
+        },
+        "args": {
+          "type": "string",
+          "enum": ["after-used", "all", "none"],
+          "default": "all"
+        },
+        "ignoreRestSiblings": {
+          "type": "boolean",
+          "description": "Ignore variables that are destructured along with a rest property.",
+          "default": false
+        },
+        "argsIgnorePattern": {
+          "type": "string",
+          "description": "Regular expression matching names of parameters that aren't checked."
+        },
+        "varsIgnorePattern": {
+          "type": "string",
+          "description": "Regular expression matching names of variables that aren't checked."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoUnusedVarsOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
   fn lint_program<'view>(
     &self,
     context: &mut Context<'view>,
@@ -72,21 +186,29 @@ impl LintRule for NoUnusedVars {
       return;
     }
 
-    let mut collector = Collector::default();
+    let mut deferred = DeferredCollector::default();
     match program {
-      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut collector),
-      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut collector),
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut deferred),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut deferred),
     }
 
-    let mut visitor = NoUnusedVarVisitor::new(
-      context,
-      collector.used_vars,
-      collector.used_types,
-    );
+    let mut visitor = NoUnusedVarVisitor {
+      rule: self,
+      usage: Usage {
+        tree: context.scope_tree(),
+        deferred: deferred.spans,
+      },
+      top_level_ctxt: context.top_level_ctxt(),
+      diagnostics: Vec::new(),
+    };
     match program {
       ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
       ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
     }
+
+    for (span, message, hint) in visitor.diagnostics {
+      context.add_diagnostic_with_hint(span, CODE, message, hint);
+    }
   }
 
   #[cfg(feature = "docs")]
@@ -101,183 +223,21 @@ fn is_jsx_file(filename: &str) -> bool {
   filename.ends_with(".jsx") || filename.ends_with(".tsx")
 }
 
-/// Collects information about variable usages.
+/// Collects the spans of call arguments and of default values in object
+/// patterns. A variable used in them counts as used even while it's being
+/// declared, e.g. `i` in `const i = setInterval(() => clearInterval(i));`, as
+/// the callback is invoked later on.
 #[derive(Default)]
-struct Collector {
-  used_vars: HashSet<Id>,
-  used_types: HashSet<Id>,
-  /// Currently defining functions or variables.
-  ///
-  ///
-  /// Note: As resolver handles binding-binding conflict of identifiers,
-  /// we can safely remove an ident from the set after declaration.
-  /// I mean, all binding identifiers are unique up to symbol and syntax context.
-  ///
-  ///
-  /// Type of this should be hashset, but we don't have a way to
-  /// restore hashset after handling bindings
-  cur_defining: Vec<Id>,
+struct DeferredCollector {
+  spans: Vec<Span>,
 }
 
-impl Collector {
-  /// The variable usage during its declaration should _NOT_ be treated as used.
-  /// For example:
-  ///
-  /// ```typescript
-  /// // `a` is called, but effectively nothing occurs until `a` is called from _outside_ of this
-  /// // function body.
-  /// const a = () => { a(); };
-  ///
-  /// // Same goes for type or interface definitions.
-  /// type JsonValue = number | string | boolean | Array<JsonValue> | {
-  ///   [key: string]: JsonValue;
-  /// };
-  /// interface Foo {
-  ///   a: Foo;
-  /// }
-  /// ```
-  ///
-  /// To handle it, we need to store the variables that are currently being declared.
-  /// This is a helper method, responsible for preserving and then restoring variables data.
-  fn with_cur_defining<I, F>(&mut self, ids: I, op: F)
-  where
-    I: IntoIterator<Item = Id>,
-    F: FnOnce(&mut Collector),
-  {
-    // Preserve the original state
-    let prev_len = self.cur_defining.len();
-    self.cur_defining.extend(ids);
-
-    op(self);
-
-    // Restore the original state
-    self.cur_defining.drain(prev_len..);
-    assert_eq!(self.cur_defining.len(), prev_len);
-  }
-
-  /// This is a helper method, responsible for temporarily ignoring `cur_defining` while doing
-  /// the given operation (`op`).
-  ///
-  /// For some context, we need to ignore variables that are being declared (which we call
-  /// `cur_defining`).
-  /// Take function arguments as an example. If `cur_defining` is used inside the arguments, we
-  /// have to think of it as _used_.
-  ///
-  /// ```typescript
-  /// const i = setInterval(() => {
-  ///  clearInterval(i);
-  /// }, 1000);
-  /// ```
-  ///
-  /// In the above example, when visiting `setInterval`, we have `i` included in `cur_defining`.
-  /// `setInterval` is taking a closure as an argument and `i` is used in it.
-  /// Naturally we have to treat `i` as used, because this closure is effectively invoked
-  /// lazily; not invoked at the time when `i` is being defined.
-  fn without_cur_defining<F>(&mut self, op: F)
-  where
-    F: FnOnce(&mut Collector),
-  {
-    let prev = std::mem::take(&mut self.cur_defining);
-    op(self);
-    self.cur_defining = prev;
-  }
-
-  fn mark_as_usage(&mut self, i: &Ident) {
-    let id = i.to_id();
-
-    // Recursive calls are not usage
-    if self.cur_defining.contains(&id) {
-      return;
-    }
-
-    // Mark the variable as used.
-    self.used_vars.insert(id);
-  }
-}
-
-impl Visit for Collector {
-  fn visit_class_prop(&mut self, n: &ClassProp, _: &dyn Node) {
-    n.decorators.visit_with(n, self);
-
-    if n.computed {
-      n.key.visit_with(n, self);
-    }
-
-    n.value.visit_with(n, self);
-    n.type_ann.visit_with(n, self);
-  }
-
-  fn visit_ts_property_signature(
-    &mut self,
-    n: &TsPropertySignature,
-    _: &dyn Node,
-  ) {
-    if n.computed {
-      n.key.visit_with(n, self);
-    }
-
-    n.type_params.visit_with(n, self);
-    n.type_ann.visit_with(n, self);
-    n.params.visit_with(n, self);
-    n.init.visit_with(n, self);
-  }
-
-  fn visit_ts_type_ref(&mut self, ty: &TsTypeRef, _: &dyn Node) {
-    ty.type_params.visit_with(ty, self);
-
-    let id = get_id(&ty.type_name);
-    self.used_types.insert(id);
-  }
-
-  fn visit_ts_expr_with_type_args(
-    &mut self,
-    n: &TsExprWithTypeArgs,
-    _: &dyn Node,
-  ) {
-    let id = get_id(&n.expr);
-    self.used_vars.insert(id);
-    n.type_args.visit_with(n, self);
-  }
-
-  fn visit_ts_type_query_expr(&mut self, n: &TsTypeQueryExpr, _: &dyn Node) {
-    if let TsTypeQueryExpr::TsEntityName(e) = n {
-      let id = get_id(e);
-      self.used_vars.insert(id);
-    }
-    n.visit_children_with(self);
-  }
-
-  fn visit_prop(&mut self, n: &Prop, _: &dyn Node) {
-    match n {
-      Prop::Shorthand(i) => self.mark_as_usage(i),
-      _ => n.visit_children_with(self),
-    }
-  }
-
-  fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
-    if let PropName::Computed(computed) = n {
-      computed.visit_children_with(self);
-    }
-    // Don't check Ident, Str, Num and BigInt
-  }
-
-  fn visit_expr(&mut self, expr: &Expr, _: &dyn Node) {
-    match expr {
-      Expr::Ident(i) => self.mark_as_usage(i),
-      _ => expr.visit_children_with(self),
-    }
-  }
-
-  fn visit_pat(&mut self, pat: &Pat, _: &dyn Node) {
-    match pat {
-      // Ignore patterns
-      Pat::Ident(i) => {
-        i.type_ann.visit_with(pat, self);
-      }
-      Pat::Invalid(..) => {}
-      //
-      _ => pat.visit_children_with(self),
-    }
+impl Visit for DeferredCollector {
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    self
+      .spans
+      .extend(call_expr.args.iter().map(|arg| arg.expr.span()));
+    call_expr.visit_children_with(self);
   }
 
   fn visit_assign_pat_prop(
@@ -286,140 +246,69 @@ impl Visit for Collector {
     _: &dyn Node,
   ) {
     // handle codes like `const { foo, bar = foo } = { foo: 42 };`
-    self.without_cur_defining(|a| {
-      assign_pat_prop.value.visit_children_with(a);
-    });
-  }
-
-  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
-    member_expr.obj.visit_with(member_expr, self);
-    if member_expr.computed {
-      member_expr.prop.visit_with(member_expr, self);
+    if let Some(value) = &assign_pat_prop.value {
+      self.spans.push(value.span());
     }
+    assign_pat_prop.visit_children_with(self);
   }
+}
 
-  /// export is kind of usage
-  fn visit_export_named_specifier(
-    &mut self,
-    export: &ExportNamedSpecifier,
-    _: &dyn Node,
-  ) {
-    self.used_vars.insert(export.orig.to_id());
-  }
-
-  fn visit_fn_decl(&mut self, decl: &FnDecl, _: &dyn Node) {
-    let id = decl.ident.to_id();
-    self.with_cur_defining(iter::once(id), |a| {
-      decl.function.visit_with(decl, a);
-    });
-  }
-
-  fn visit_fn_expr(&mut self, expr: &FnExpr, _: &dyn Node) {
-    // We have to do nothing special for identifiers of FnExprs (if any), because they are allowed
-    // to be not-used.
-    expr.function.visit_with(expr, self);
-  }
-
-  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
-    if_chain! {
-      if let Some(first_param) = function.params.get(0);
-      if let Pat::Ident(ident) = &first_param.pat;
-      if ident.type_ann.is_some();
-      if ident.id.sym == js_word!("this");
-      then {
-        // If the first parameter of a function is `this` keyword with type annotated, it is a
-        // fake parameter specifying what type `this` becomes inside the function body.
-        // (See https://www.typescriptlang.org/docs/handbook/functions.html#this-parameters
-        // for more info)
-        // Since it's just a fake parameter, we can mark it as used.
-        self.mark_as_usage(&ident.id);
-      }
-    }
-
-    function.visit_children_with(self);
-  }
-
-  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
-    call_expr.callee.visit_children_with(self);
-
-    for arg in &call_expr.args {
-      self.without_cur_defining(|a| {
-        arg.visit_children_with(a);
-      });
-    }
+/// Tells whether variables are used, based on the references in the scope
+/// tree.
+struct Usage<'a> {
+  tree: &'a ScopeTree,
+  deferred: Vec<Span>,
+}
 
-    call_expr.type_args.visit_children_with(self);
+impl<'a> Usage<'a> {
+  fn references(&self, ident: &Ident) -> impl Iterator<Item = &Reference> {
+    self
+      .tree
+      .binding_of(ident)
+      .into_iter()
+      .flat_map(move |binding| self.tree.binding(binding).references())
   }
 
-  fn visit_class_decl(&mut self, decl: &ClassDecl, _: &dyn Node) {
-    let id = decl.ident.to_id();
-    self.with_cur_defining(iter::once(id), |a| {
-      decl.class.visit_with(decl, a);
-    });
+  /// Checks if the value of the variable declared by `ident` is read.
+  ///
+  /// The variable usage during its declaration `definition` should _NOT_ be
+  /// treated as used. For example:
+  ///
+  /// ```typescript
+  /// // `a` is called, but effectively nothing occurs until `a` is called from _outside_ of this
+  /// // function body.
+  /// const a = () => { a(); };
+  /// ```
+  fn is_used(&self, ident: &Ident, definition: Option<Span>) -> bool {
+    self.references(ident).any(|reference| {
+      reference.kind() == ReferenceKind::Read
+        && !self.is_in_definition(reference.span(), definition)
+    })
   }
 
-  fn visit_ts_interface_decl(&mut self, decl: &TsInterfaceDecl, _: &dyn Node) {
-    let id = decl.id.to_id();
-    self.with_cur_defining(iter::once(id), |a| {
-      decl.extends.visit_with(decl, a);
-      decl.body.visit_with(decl, a);
-      if let Some(type_params) = &decl.type_params {
-        type_params.visit_with(decl, a);
-      }
-    });
+  fn is_used_as_type(&self, ident: &Ident) -> bool {
+    self
+      .references(ident)
+      .any(|reference| reference.kind() == ReferenceKind::Type)
   }
 
-  fn visit_ts_type_alias_decl(&mut self, decl: &TsTypeAliasDecl, _: &dyn Node) {
-    let id = decl.id.to_id();
-    self.with_cur_defining(iter::once(id), |a| {
-      decl.type_ann.visit_with(decl, a);
-      if let Some(type_params) = &decl.type_params {
-        type_params.visit_with(decl, a);
+  fn is_in_definition(&self, span: Span, definition: Option<Span>) -> bool {
+    match definition {
+      Some(definition) if definition.contains(span) => {
+        !self.deferred.iter().any(|deferred| {
+          definition.contains(*deferred) && deferred.contains(span)
+        })
       }
-    });
-  }
-
-  fn visit_ts_enum_decl(&mut self, decl: &TsEnumDecl, _: &dyn Node) {
-    let id = decl.id.to_id();
-    self.with_cur_defining(iter::once(id), |a| {
-      decl.members.visit_with(decl, a);
-    });
-  }
-
-  fn visit_var_declarator(&mut self, declarator: &VarDeclarator, _: &dyn Node) {
-    let declaring_ids: Vec<Id> = find_ids(&declarator.name);
-    self.with_cur_defining(declaring_ids, |a| {
-      declarator.name.visit_with(declarator, a);
-      declarator.init.visit_with(declarator, a);
-    });
-  }
-}
-
-fn get_id(r: &TsEntityName) -> Id {
-  match r {
-    TsEntityName::TsQualifiedName(q) => get_id(&q.left),
-    TsEntityName::Ident(i) => i.to_id(),
+      _ => false,
+    }
   }
 }
 
-struct NoUnusedVarVisitor<'c, 'view> {
-  context: &'c mut Context<'view>,
-  used_vars: HashSet<Id>,
-  used_types: HashSet<Id>,
-}
-
-impl<'c, 'view> NoUnusedVarVisitor<'c, 'view> {
-  fn new(
-    context: &'c mut Context<'view>,
-    used_vars: HashSet<Id>,
-    used_types: HashSet<Id>,
-  ) -> Self {
-    Self {
-      context,
-      used_vars,
-      used_types,
-    }
-  }
+struct NoUnusedVarVisitor<'c> {
+  rule: &'c NoUnusedVars,
+  usage: Usage<'c>,
+  top_level_ctxt: SyntaxContext,
+  diagnostics: Vec<(Span, NoUnusedVarsMessage, NoUnusedVarsHint)>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -427,6 +316,7 @@ enum IdentKind<'a> {
   NamedImport(&'a Ident),
   DefaultImport(&'a Ident),
   StarAsImport(&'a Ident),
+  Param(&'a Ident),
   Other(&'a Ident),
 }
 
@@ -436,6 +326,7 @@ impl<'a> IdentKind<'a> {
       IdentKind::NamedImport(ident) => ident,
       IdentKind::DefaultImport(ident) => ident,
       IdentKind::StarAsImport(ident) => ident,
+      IdentKind::Param(ident) => ident,
       IdentKind::Other(ident) => ident,
     }
   }
@@ -451,37 +342,153 @@ impl<'a> IdentKind<'a> {
       IdentKind::NamedImport(_) => NoUnusedVarsHint::Alias(symbol),
       IdentKind::DefaultImport(_)
       | IdentKind::StarAsImport(_)
+      | IdentKind::Param(_)
       | IdentKind::Other(_) => NoUnusedVarsHint::AddPrefix(symbol),
     }
   }
 }
 
-impl<'c, 'view> NoUnusedVarVisitor<'c, 'view> {
-  fn handle_id(&mut self, ident: IdentKind) {
+impl<'c> NoUnusedVarVisitor<'c> {
+  /// Checks the variable declared by `ident`, whose declaration spans
+  /// `definition`.
+  fn handle_id(&mut self, ident: IdentKind, definition: Option<Span>) {
     let inner = ident.inner();
     if inner.sym.starts_with('_') {
       return;
     }
 
-    if !self.used_vars.contains(&inner.to_id()) {
+    let options = &self.rule.options;
+    let ignore_pattern = match ident {
+      IdentKind::Param(_) => &self.rule.args_ignore_pattern,
+      IdentKind::Other(_) => {
+        if options.vars == NoUnusedVarsVars::Local
+          && inner.span.ctxt == self.top_level_ctxt
+        {
+          return;
+        }
+        &self.rule.vars_ignore_pattern
+      }
+      _ => &self.rule.vars_ignore_pattern,
+    };
+    if matches!(ignore_pattern, Some(pattern) if pattern.is_match(&inner.sym)) {
+      return;
+    }
+
+    if !self.usage.is_used(inner, definition) {
       // The variable is not used.
-      self.context.add_diagnostic_with_hint(
-        inner.span,
-        CODE,
-        ident.to_message(),
-        ident.to_hint(),
-      );
+      self
+        .diagnostics
+        .push((inner.span, ident.to_message(), ident.to_hint()));
     }
   }
-}
 
-impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
-  fn visit_arrow_expr(&mut self, expr: &ArrowExpr, _: &dyn Node) {
-    let declared_idents: Vec<Ident> = find_ids(&expr.params);
+  fn handle_pat(
+    &mut self,
+    pat: &Pat,
+    is_param: bool,
+    definition: Option<Span>,
+  ) {
+    let rest_siblings = if self.rule.options.ignore_rest_siblings {
+      rest_siblings(pat)
+    } else {
+      HashSet::new()
+    };
 
+    let declared_idents: Vec<Ident> = find_ids(pat);
     for ident in declared_idents {
-      self.handle_id(IdentKind::Other(&ident));
+      if rest_siblings.contains(&ident.to_id()) {
+        continue;
+      }
+      if is_param {
+        self.handle_id(IdentKind::Param(&ident), definition);
+      } else {
+        self.handle_id(IdentKind::Other(&ident), definition);
+      }
+    }
+  }
+
+  /// Checks the parameters of a function according to the `args` option.
+  fn handle_params<'a>(&mut self, params: impl Iterator<Item = &'a Pat>) {
+    let params: Vec<&Pat> = params.collect();
+    let last_used = params.iter().rposition(|pat| {
+      let idents: Vec<Ident> = find_ids(*pat);
+      idents.iter().any(|ident| self.usage.is_used(ident, None))
+    });
+
+    for (idx, pat) in params.into_iter().enumerate() {
+      let should_check = match self.rule.options.args {
+        NoUnusedVarsArgs::AfterUsed => {
+          !matches!(last_used, Some(last) if idx <= last)
+        }
+        NoUnusedVarsArgs::All => true,
+        NoUnusedVarsArgs::None => false,
+      };
+      if should_check {
+        self.handle_pat(pat, true, None);
+      }
     }
+  }
+
+  fn visit_fn_params(&mut self, params: &[Param], parent: &dyn Node) {
+    let params = match params.first() {
+      Some(Param {
+        pat: Pat::Ident(i), ..
+      }) if i.id.sym == *"this" => &params[1..],
+      _ => params,
+    };
+
+    self.handle_params(params.iter().map(|param| &param.pat));
+    for param in params {
+      param.visit_with(parent, self);
+    }
+  }
+}
+
+/// Collects bindings that are direct properties of an object pattern that has
+/// a rest property, e.g. `a` and `c` in `{ a, b: { c, ...d }, ...e }`.
+fn rest_siblings(pat: &Pat) -> HashSet<Id> {
+  let mut ids = HashSet::new();
+  collect_rest_siblings(pat, &mut ids);
+  ids
+}
+
+fn collect_rest_siblings(pat: &Pat, ids: &mut HashSet<Id>) {
+  match pat {
+    Pat::Object(object) => {
+      let has_rest =
+        matches!(object.props.last(), Some(ObjectPatProp::Rest(_)));
+      for prop in &object.props {
+        match prop {
+          ObjectPatProp::KeyValue(kv) => match &*kv.value {
+            Pat::Ident(binding) if has_rest => {
+              ids.insert(binding.id.to_id());
+            }
+            value => collect_rest_siblings(value, ids),
+          },
+          ObjectPatProp::Assign(assign)
+            if has_rest && assign.value.is_none() =>
+          {
+            ids.insert(assign.key.to_id());
+          }
+          ObjectPatProp::Assign(_) => {}
+          ObjectPatProp::Rest(rest) => collect_rest_siblings(&rest.arg, ids),
+        }
+      }
+    }
+    Pat::Array(array) => {
+      for elem in array.elems.iter().flatten() {
+        collect_rest_siblings(elem, ids);
+      }
+    }
+    Pat::Assign(assign) => collect_rest_siblings(&assign.left, ids),
+    Pat::Rest(rest) => collect_rest_siblings(&rest.arg, ids),
+    _ => {}
+  }
+}
+
+impl<'c> Visit for NoUnusedVarVisitor<'c> {
+  fn visit_arrow_expr(&mut self, expr: &ArrowExpr, _: &dyn Node) {
+    self.handle_params(expr.params.iter());
     expr.body.visit_with(expr, self)
   }
 
@@ -490,7 +497,7 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
       return;
     }
 
-    self.handle_id(IdentKind::Other(&decl.ident));
+    self.handle_id(IdentKind::Other(&decl.ident), Some(decl.function.span));
 
     // If function body is not present, it's an overload definition
     if decl.function.body.is_some() {
@@ -507,11 +514,7 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
   }
 
   fn visit_var_declarator(&mut self, declarator: &VarDeclarator, _: &dyn Node) {
-    let declared_idents: Vec<Ident> = find_ids(&declarator.name);
-
-    for ident in declared_idents {
-      self.handle_id(IdentKind::Other(&ident));
-    }
+    self.handle_pat(&declarator.name, false, Some(declarator.span));
     declarator.name.visit_with(declarator, self);
     declarator.init.visit_with(declarator, self);
   }
@@ -521,7 +524,7 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
       return;
     }
 
-    self.handle_id(IdentKind::Other(&n.ident));
+    self.handle_id(IdentKind::Other(&n.ident), Some(n.class.span));
     n.visit_children_with(self);
   }
 
@@ -529,7 +532,7 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
     let declared_idents: Vec<Ident> = find_ids(&clause.param);
 
     for ident in declared_idents {
-      self.handle_id(IdentKind::Other(&ident));
+      self.handle_id(IdentKind::Other(&ident), None);
     }
 
     clause.body.visit_with(clause, self);
@@ -546,6 +549,12 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
       return;
     }
 
+    self.handle_params(constructor.params.iter().filter_map(
+      |param| match param {
+        ParamOrTsParamProp::Param(param) => Some(&param.pat),
+        ParamOrTsParamProp::TsParamProp(_) => None,
+      },
+    ));
     constructor.visit_children_with(self);
   }
 
@@ -556,7 +565,7 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
     // If method body is not present, it's an overload definition
     if matches!(method.kind, MethodKind::Method if method.function.body.is_some())
     {
-      method.function.params.visit_children_with(self);
+      self.visit_fn_params(&method.function.params, method);
     }
 
     method.function.body.visit_with(method, self);
@@ -568,18 +577,15 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
 
     // If method body is not present, it's an overload definition
     if method.function.body.is_some() {
-      method.function.params.visit_children_with(self);
+      self.visit_fn_params(&method.function.params, method);
     }
 
     method.function.body.visit_with(method, self);
   }
 
+  /// Parameters themselves are checked by `handle_params`, as whether they
+  /// are reported depends on the other parameters.
   fn visit_param(&mut self, param: &Param, _: &dyn Node) {
-    let declared_idents: Vec<Ident> = find_ids(&param.pat);
-
-    for ident in declared_idents {
-      self.handle_id(IdentKind::Other(&ident));
-    }
     param.visit_children_with(self);
   }
 
@@ -588,10 +594,10 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
     import: &ImportNamedSpecifier,
     _: &dyn Node,
   ) {
-    if self.used_types.contains(&import.local.to_id()) {
+    if self.usage.is_used_as_type(&import.local) {
       return;
     }
-    self.handle_id(IdentKind::NamedImport(&import.local));
+    self.handle_id(IdentKind::NamedImport(&import.local), None);
   }
 
   fn visit_import_default_specifier(
@@ -599,11 +605,11 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
     import: &ImportDefaultSpecifier,
     _: &dyn Node,
   ) {
-    if self.used_types.contains(&import.local.to_id()) {
+    if self.usage.is_used_as_type(&import.local) {
       return;
     }
 
-    self.handle_id(IdentKind::DefaultImport(&import.local));
+    self.handle_id(IdentKind::DefaultImport(&import.local), None);
   }
 
   fn visit_import_star_as_specifier(
@@ -611,10 +617,10 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
     import: &ImportStarAsSpecifier,
     _: &dyn Node,
   ) {
-    if self.used_types.contains(&import.local.to_id()) {
+    if self.usage.is_used_as_type(&import.local) {
       return;
     }
-    self.handle_id(IdentKind::StarAsImport(&import.local));
+    self.handle_id(IdentKind::StarAsImport(&import.local), None);
   }
 
   /// No error as export is kind of usage
@@ -623,11 +629,9 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
       Decl::Class(c) if !c.declare => {
         c.class.visit_with(c, self);
       }
-      Decl::Fn(f) if !f.declare => {
-        // If function body is not present, it's an overload definition
-        if f.function.body.is_some() {
-          f.function.visit_with(f, self);
-        }
+      // If function body is not present, it's an overload definition
+      Decl::Fn(f) if !f.declare && f.function.body.is_some() => {
+        f.function.visit_with(f, self);
       }
       Decl::Var(v) if !v.declare => {
         for decl in &v.decls {
@@ -661,17 +665,7 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
   }
 
   fn visit_params(&mut self, params: &[Param], parent: &dyn Node) {
-    match params.first() {
-      Some(Param {
-        pat: Pat::Ident(i), ..
-      }) if i.id.sym == *"this" => params
-        .iter()
-        .skip(1)
-        .for_each(|param| param.visit_with(parent, self)),
-      _ => params
-        .iter()
-        .for_each(|param| param.visit_with(parent, self)),
-    }
+    self.visit_fn_params(params, parent);
   }
 
   fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl, _: &dyn Node) {
@@ -679,10 +673,10 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
       return;
     }
 
-    if self.used_types.contains(&n.id.to_id()) {
+    if self.usage.is_used_as_type(&n.id) {
       return;
     }
-    self.handle_id(IdentKind::Other(&n.id));
+    self.handle_id(IdentKind::Other(&n.id), Some(n.span));
   }
 
   fn visit_ts_module_decl(&mut self, n: &TsModuleDecl, _: &dyn Node) {
@@ -2227,6 +2221,169 @@ export class Foo {
     };
   }
 
+  #[test]
+  fn no_unused_vars_valid_with_options() {
+    assert_lint_ok! {
+      NoUnusedVars,
+      options: NoUnusedVarsOptions {
+        vars: NoUnusedVarsVars::Local,
+        ..Default::default()
+      },
+      "var a = 1; function foo() {} class Bar {}",
+      "function foo() { var a = 1; return a; }",
+    };
+
+    assert_lint_ok! {
+      NoUnusedVars,
+      options: NoUnusedVarsOptions {
+        args: NoUnusedVarsArgs::AfterUsed,
+        ..Default::default()
+      },
+      "function foo(a, b) { return b; } foo();",
+      "const foo = (a, b, c) => c; foo();",
+      "class Foo { bar(a, b) { return b; } } new Foo();",
+      "class Foo { constructor(a, b) { console.log(b); } } new Foo();",
+    };
+
+    assert_lint_ok! {
+      NoUnusedVars,
+      options: NoUnusedVarsOptions {
+        args: NoUnusedVarsArgs::None,
+        ..Default::default()
+      },
+      "function foo(a, b) {} foo();",
+      "const foo = ({ a }) => {}; foo();",
+    };
+
+    assert_lint_ok! {
+      NoUnusedVars,
+      options: NoUnusedVarsOptions {
+        ignore_rest_siblings: true,
+        ..Default::default()
+      },
+      "const { a, ...rest } = obj; console.log(rest);",
+      "const { a: b, c, ...rest } = obj; console.log(rest);",
+      "const { a: { b, ...c }, ...d } = obj; console.log(c, d);",
+      "function foo({ a, ...rest }) { return rest; } foo();",
+    };
+
+    assert_lint_ok! {
+      NoUnusedVars,
+      options: NoUnusedVarsOptions {
+        vars_ignore_pattern: Some("^ignored".to_string()),
+        args_ignore_pattern: Some("^unused".to_string()),
+        ..Default::default()
+      },
+      "const ignoredFoo = 1;",
+      "function foo(unusedBar) {} foo();",
+    };
+  }
+
+  #[test]
+  fn no_unused_vars_invalid_with_options() {
+    assert_lint_err! {
+      NoUnusedVars,
+      options: NoUnusedVarsOptions {
+        vars: NoUnusedVarsVars::Local,
+        ..Default::default()
+      },
+      "function foo() { var a = 1; } foo();": [
+        {
+          col: 21,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+        }
+      ],
+      "import { a } from 'a';": [
+        {
+          col: 9,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
+          hint: variant!(NoUnusedVarsHint, Alias, "a"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnusedVars,
+      options: NoUnusedVarsOptions {
+        args: NoUnusedVarsArgs::AfterUsed,
+        ..Default::default()
+      },
+      "function foo(a, b, c) { return b; } foo();": [
+        {
+          col: 19,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "c"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "c"),
+        }
+      ],
+      "const foo = (a) => {}; foo();": [
+        {
+          col: 13,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnusedVars,
+      options: NoUnusedVarsOptions {
+        args: NoUnusedVarsArgs::None,
+        ..Default::default()
+      },
+      "function foo(a) { const b = a; } foo();": [
+        {
+          col: 24,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "b"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "b"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnusedVars,
+      options: NoUnusedVarsOptions {
+        ignore_rest_siblings: true,
+        ..Default::default()
+      },
+      "const { a = 1, ...rest } = obj; console.log(rest);": [
+        {
+          col: 8,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+        }
+      ],
+      "const { a, b } = obj; console.log(b);": [
+        {
+          col: 8,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnusedVars,
+      options: NoUnusedVarsOptions {
+        vars_ignore_pattern: Some("^ignored".to_string()),
+        args_ignore_pattern: Some("^unused".to_string()),
+        ..Default::default()
+      },
+      "function foo(ignoredBar) {} const unusedBaz = 1; foo();": [
+        {
+          col: 13,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "ignoredBar"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "ignoredBar"),
+        },
+        {
+          col: 34,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "unusedBaz"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "unusedBaz"),
+        }
+      ],
+    };
+  }
+
   // TODO(magurotuna): deals with this using ControlFlow
   #[test]
   #[ignore = "control flow analysis is not implemented yet"]
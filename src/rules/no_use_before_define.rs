@@ -0,0 +1,489 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::scope_tree::{Binding, ScopeId, ScopeKind, ScopeTree};
+use crate::scopes::BindingKind;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view::{self as ast_view, NodeTrait};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUseBeforeDefine {
+  options: NoUseBeforeDefineOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUseBeforeDefineOptions {
+  /// Report functions used before their declaration.
+  pub functions: bool,
+  /// Report classes used before their declaration. If disabled, uses inside
+  /// of nested functions are still allowed.
+  pub classes: bool,
+  /// Report variables used before their declaration. If disabled, uses inside
+  /// of nested functions are still allowed.
+  pub variables: bool,
+  /// Allow `export { foo }` before `foo` is declared.
+  pub allow_named_exports: bool,
+}
+
+impl Default for NoUseBeforeDefineOptions {
+  fn default() -> Self {
+    Self {
+      functions: true,
+      classes: true,
+      variables: true,
+      allow_named_exports: false,
+    }
+  }
+}
+
+impl NoUseBeforeDefine {
+  pub fn with_options(options: NoUseBeforeDefineOptions) -> Arc<Self> {
+    Arc::new(NoUseBeforeDefine { options })
+  }
+}
+
+const CODE: &str = "no-use-before-define";
+
+#[derive(Display)]
+enum NoUseBeforeDefineMessage {
+  #[display(fmt = "`{}` was used before it was defined", _0)]
+  UsedBeforeDefined(String),
+}
+
+#[derive(Display)]
+enum NoUseBeforeDefineHint {
+  #[display(fmt = "Move the declaration of `{}` before its first use", _0)]
+  MoveDeclaration(String),
+}
+
+impl LintRule for NoUseBeforeDefine {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoUseBeforeDefineOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "functions": {
+          "type": "boolean",
+          "description": "Report functions used before their declaration.",
+          "default": true
+        },
+        "classes": {
+          "type": "boolean",
+          "description": "Report classes used before their declaration.",
+          "default": true
+        },
+        "variables": {
+          "type": "boolean",
+          "description": "Report variables used before their declaration.",
+          "default": true
+        },
+        "allowNamedExports": {
+          "type": "boolean",
+          "description": "Allow `export { foo }` before `foo` is declared.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoUseBeforeDefineOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUseBeforeDefineHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_use_before_define.md")
+  }
+}
+
+struct NoUseBeforeDefineHandler<'a> {
+  options: &'a NoUseBeforeDefineOptions,
+}
+
+impl<'a> NoUseBeforeDefineHandler<'a> {
+  fn is_used_before_defined(
+    &self,
+    ident: &ast_view::Ident,
+    tree: &ScopeTree,
+  ) -> bool {
+    let binding = match tree.binding_of(ident.inner) {
+      Some(binding) => tree.binding(binding),
+      None => return false,
+    };
+    if !matches!(
+      binding.kind(),
+      BindingKind::Var
+        | BindingKind::Let
+        | BindingKind::Const
+        | BindingKind::Function
+        | BindingKind::Class
+        | BindingKind::Enum
+    ) || binding.is_declare()
+    {
+      return false;
+    }
+
+    let node = ident.as_node();
+    let used_before = ident.span().lo() < binding.span().lo();
+    let used_in_init = matches!(
+      tdz_span(node, binding),
+      Some(tdz) if !in_nested_function(node, tdz)
+    );
+    (used_before || used_in_init)
+      && !in_type_position(node)
+      && !self.is_allowed(tree, binding, ident)
+  }
+
+  fn is_allowed(
+    &self,
+    tree: &ScopeTree,
+    binding: &Binding,
+    ident: &ast_view::Ident,
+  ) -> bool {
+    if self.options.allow_named_exports
+      && matches!(ident.parent(), ast_view::Node::ExportNamedSpecifier(_))
+    {
+      return true;
+    }
+
+    let is_outer = || {
+      function_scope(tree, binding.scope())
+        != function_scope(tree, tree.scope_of(ident.as_node()))
+    };
+    match binding.kind() {
+      BindingKind::Function => !self.options.functions,
+      BindingKind::Class => !self.options.classes && is_outer(),
+      _ => !self.options.variables && is_outer(),
+    }
+  }
+}
+
+impl<'a> Handler for NoUseBeforeDefineHandler<'a> {
+  fn ident(&mut self, ident: &ast_view::Ident, ctx: &mut Context) {
+    if !self.is_used_before_defined(ident, ctx.scope_tree()) {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(
+      ident.span(),
+      CODE,
+      NoUseBeforeDefineMessage::UsedBeforeDefined(ident.sym().to_string()),
+      NoUseBeforeDefineHint::MoveDeclaration(ident.sym().to_string()),
+    );
+  }
+}
+
+/// Returns the expression around `node` that is evaluated before `binding`
+/// is initialized, e.g. `b` of `const a = b;` or of `const { a = b } = obj;`.
+fn tdz_span(node: ast_view::Node, binding: &Binding) -> Option<Span> {
+  use deno_ast::view::Node;
+
+  let span = node.span();
+  if let Some(init) = binding.init().filter(|init| init.contains(span)) {
+    return Some(init);
+  }
+  node
+    .ancestors()
+    .take_while(|n| !matches!(n, Node::VarDeclarator(_)))
+    .find_map(|n| match n {
+      Node::AssignPat(assign)
+        if assign.left.span().contains(binding.span()) =>
+      {
+        Some(assign.right.span())
+      }
+      Node::AssignPatProp(prop) if prop.key.inner.span == binding.span() => {
+        prop.value.map(|value| value.span())
+      }
+      _ => None,
+    })
+    .filter(|tdz| tdz.contains(span))
+}
+
+/// Returns the function, or the top level of the program, that `scope`
+/// belongs to.
+fn function_scope(tree: &ScopeTree, scope: ScopeId) -> ScopeId {
+  tree
+    .ancestors(scope)
+    .find(|id| {
+      matches!(
+        tree.scope(*id).kind(),
+        ScopeKind::Program | ScopeKind::Function | ScopeKind::Arrow
+      )
+    })
+    .unwrap_or_else(|| tree.root())
+}
+
+fn is_function_like(node: ast_view::Node) -> bool {
+  matches!(
+    node,
+    ast_view::Node::Function(_)
+      | ast_view::Node::ArrowExpr(_)
+      | ast_view::Node::Module(_)
+      | ast_view::Node::Script(_)
+  )
+}
+
+/// Checks if the node is inside of a function that is itself inside of
+/// `span`, in which case it is evaluated later than the code around it.
+fn in_nested_function(node: ast_view::Node, span: Span) -> bool {
+  node
+    .ancestors()
+    .take_while(|n| span.contains(n.span()))
+    .any(is_function_like)
+}
+
+/// Checks if the identifier is used as a type, e.g. `let a: Foo;`, which is
+/// fine regardless of where `Foo` is declared.
+fn in_type_position(node: ast_view::Node) -> bool {
+  use deno_ast::view::Node;
+
+  node.ancestors().any(|n| {
+    matches!(
+      n,
+      Node::TsTypeAnn(_)
+        | Node::TsTypeRef(_)
+        | Node::TsTypeQuery(_)
+        | Node::TsTypeAliasDecl(_)
+        | Node::TsInterfaceDecl(_)
+        | Node::TsExprWithTypeArgs(_)
+        | Node::TsTypeParamInstantiation(_)
+    )
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.32.0/tests/lib/rules/no-use-before-define.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_use_before_define_valid() {
+    assert_lint_ok! {
+      NoUseBeforeDefine,
+      "var a = 10; alert(a);",
+      "function b(a) { alert(a); }",
+      "Object.hasOwnProperty.call(a);",
+      "function a() { alert(arguments); }",
+      "a(); const b = 1;",
+      "const a = () => 1; a();",
+      "function foo() { return foo(); }",
+      "class A { foo() { return new A(); } }",
+      "let a = () => a;",
+      "const a = function () { return a; };",
+      "let a: Foo; class Foo {}",
+      "function f(): Foo { return null; } interface Foo {}",
+      "type A = B; type B = string;",
+      "var a; a = 1; var a;",
+      "const obj = { a: 1 }; obj.b; const b = 1;",
+      "declare const a: number; a;",
+      "try {} catch (e) { e; }",
+      "import foo from 'foo'; foo();",
+      "const [a, b = a] = arr;",
+      "const { a, b = a } = obj;",
+      "a; declare const a: number;",
+      "function f(a) { a; var a; }",
+    };
+
+    assert_lint_ok! {
+      NoUseBeforeDefine,
+      options: NoUseBeforeDefineOptions {
+        functions: false,
+        ..Default::default()
+      },
+      "a(); function a() { alert(arguments); }",
+      "function f() { g(); } function g() {}",
+    };
+
+    assert_lint_ok! {
+      NoUseBeforeDefine,
+      options: NoUseBeforeDefineOptions {
+        classes: false,
+        ..Default::default()
+      },
+      "function foo() { return new A(); } class A {}",
+    };
+
+    assert_lint_ok! {
+      NoUseBeforeDefine,
+      options: NoUseBeforeDefineOptions {
+        variables: false,
+        ..Default::default()
+      },
+      "function foo() { return a; } const a = 1;",
+      "const f = () => a; const a = 1;",
+    };
+
+    assert_lint_ok! {
+      NoUseBeforeDefine,
+      options: NoUseBeforeDefineOptions {
+        allow_named_exports: true,
+        ..Default::default()
+      },
+      "export { a }; const a = 1;",
+      "export { a as b }; let a = 1;",
+    };
+  }
+
+  #[test]
+  fn no_use_before_define_invalid() {
+    assert_lint_err! {
+      NoUseBeforeDefine,
+      "a++; var a = 19;": [
+        {
+          col: 0,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("a".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("a".to_string()),
+        }
+      ],
+      "alert(a[1]); var a = [1, 3];": [
+        {
+          col: 6,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("a".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("a".to_string()),
+        }
+      ],
+      "a(); function a() { alert(b); var b = 10; a(); }": [
+        {
+          col: 0,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("a".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("a".to_string()),
+        },
+        {
+          col: 26,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("b".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("b".to_string()),
+        }
+      ],
+      "new A(); class A {}": [
+        {
+          col: 4,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("A".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("A".to_string()),
+        }
+      ],
+      "function foo() { return new A(); } class A {}": [
+        {
+          col: 28,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("A".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("A".to_string()),
+        }
+      ],
+      "let a = a + 1;": [
+        {
+          col: 8,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("a".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("a".to_string()),
+        }
+      ],
+      "const { a = a } = obj;": [
+        {
+          col: 12,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("a".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("a".to_string()),
+        }
+      ],
+      "const [a = a] = arr;": [
+        {
+          col: 11,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("a".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("a".to_string()),
+        }
+      ],
+      "for (const x of x) {}": [
+        {
+          col: 16,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("x".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("x".to_string()),
+        }
+      ],
+      "E.A; enum E { A }": [
+        {
+          col: 0,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("E".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("E".to_string()),
+        }
+      ],
+      "export { a }; const a = 1;": [
+        {
+          col: 9,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("a".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("a".to_string()),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUseBeforeDefine,
+      options: NoUseBeforeDefineOptions {
+        functions: false,
+        classes: false,
+        variables: false,
+        allow_named_exports: false,
+      },
+      "new A(); class A {}": [
+        {
+          col: 4,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("A".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("A".to_string()),
+        }
+      ],
+      "a; const a = 1;": [
+        {
+          col: 0,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("a".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("a".to_string()),
+        }
+      ],
+      "function f() { a; var a = 1; }": [
+        {
+          col: 15,
+          message: NoUseBeforeDefineMessage::UsedBeforeDefined("a".to_string()),
+          hint: NoUseBeforeDefineHint::MoveDeclaration("a".to_string()),
+        }
+      ],
+    };
+  }
+}
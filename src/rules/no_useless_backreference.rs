@@ -0,0 +1,635 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::extract_regex;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view::{
+  CallExpr, Expr, ExprOrSpread, ExprOrSuper, Ident, Lit, NewExpr, Regex,
+};
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoUselessBackreference;
+
+const CODE: &str = "no-useless-backreference";
+
+#[derive(Display)]
+enum NoUselessBackreferenceMessage {
+  #[display(
+    fmt = "Backreference `{}` will be ignored. It references group `{}` from within that group",
+    _0,
+    _1
+  )]
+  Nested(String, String),
+  #[display(
+    fmt = "Backreference `{}` will be ignored. It references group `{}` which appears later in the pattern",
+    _0,
+    _1
+  )]
+  Forward(String, String),
+  #[display(
+    fmt = "Backreference `{}` will be ignored. It references group `{}` which appears before in the same lookbehind",
+    _0,
+    _1
+  )]
+  Backward(String, String),
+  #[display(
+    fmt = "Backreference `{}` will be ignored. It references group `{}` which is in another alternative",
+    _0,
+    _1
+  )]
+  Disjunctive(String, String),
+  #[display(
+    fmt = "Backreference `{}` will be ignored. It references group `{}` which is in a negative lookaround",
+    _0,
+    _1
+  )]
+  IntoNegativeLookaround(String, String),
+}
+
+const HINT: &str = "Remove the backreference, or rework the regular expression so that the group is matched before the backreference";
+
+impl LintRule for NoUselessBackreference {
+  fn new() -> Arc<Self> {
+    Arc::new(NoUselessBackreference)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoUselessBackreferenceHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_useless_backreference.md")
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum GroupKind {
+  /// The whole pattern
+  Pattern,
+  Capturing,
+  NonCapturing,
+  Lookahead {
+    negative: bool,
+  },
+  Lookbehind {
+    negative: bool,
+  },
+}
+
+/// A parenthesized part of a pattern, or the whole pattern.
+struct Group {
+  kind: GroupKind,
+  name: Option<String>,
+  /// The group containing this group and the index of the alternative this
+  /// group is in.
+  parent: Option<(usize, usize)>,
+  /// The number of `|` seen so far, i.e. the index of the last alternative.
+  alternative: usize,
+  start: usize,
+  end: usize,
+}
+
+enum Reference {
+  Index(usize),
+  Name(String),
+}
+
+struct Backreference {
+  reference: Reference,
+  parent: (usize, usize),
+  start: usize,
+  end: usize,
+}
+
+/// The groups and backreferences of a pattern. Only the structure needed to
+/// find useless backreferences is parsed.
+struct ParsedPattern {
+  chars: Vec<char>,
+  /// All groups in the order of their opening parentheses. The first one is
+  /// the whole pattern.
+  groups: Vec<Group>,
+  backreferences: Vec<Backreference>,
+}
+
+impl ParsedPattern {
+  /// Returns `None` if the parentheses are unbalanced.
+  fn parse(pattern: &str, unicode: bool) -> Option<Self> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let (capturing_count, has_named_group) = count_capturing_groups(&chars);
+    let mut groups = vec![Group {
+      kind: GroupKind::Pattern,
+      name: None,
+      parent: None,
+      alternative: 0,
+      start: 0,
+      end: chars.len(),
+    }];
+    let mut backreferences = Vec::new();
+    let mut stack = vec![0];
+
+    let mut i = 0;
+    while i < chars.len() {
+      let current = *stack.last().unwrap();
+      let parent = (current, groups[current].alternative);
+      match chars[i] {
+        '\\' => {
+          let start = i;
+          i += 1;
+          match chars.get(i) {
+            Some('1'..='9') => {
+              let digits: String = chars[i..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+              i += digits.len();
+              let index: usize = digits.parse().ok()?;
+              // Without the `u` flag, it's an octal escape if there aren't
+              // enough groups
+              if unicode || index <= capturing_count {
+                backreferences.push(Backreference {
+                  reference: Reference::Index(index),
+                  parent,
+                  start,
+                  end: i,
+                });
+              }
+              continue;
+            }
+            Some('k')
+              if (unicode || has_named_group)
+                && chars.get(i + 1) == Some(&'<') =>
+            {
+              let name: String =
+                chars[i + 2..].iter().take_while(|c| **c != '>').collect();
+              i += name.len() + 3;
+              backreferences.push(Backreference {
+                reference: Reference::Name(name),
+                parent,
+                start,
+                end: i,
+              });
+              continue;
+            }
+            _ => {}
+          }
+        }
+        '[' => {
+          i += 1;
+          while i < chars.len() && chars[i] != ']' {
+            if chars[i] == '\\' {
+              i += 1;
+            }
+            i += 1;
+          }
+        }
+        '(' => {
+          let rest: String = chars[i + 1..].iter().take(3).collect();
+          let (kind, name) = if rest.starts_with("?<=") {
+            (GroupKind::Lookbehind { negative: false }, None)
+          } else if rest.starts_with("?<!") {
+            (GroupKind::Lookbehind { negative: true }, None)
+          } else if rest.starts_with("?<") {
+            let name: String =
+              chars[i + 3..].iter().take_while(|c| **c != '>').collect();
+            (GroupKind::Capturing, Some(name))
+          } else if rest.starts_with("?=") {
+            (GroupKind::Lookahead { negative: false }, None)
+          } else if rest.starts_with("?!") {
+            (GroupKind::Lookahead { negative: true }, None)
+          } else if rest.starts_with('?') {
+            (GroupKind::NonCapturing, None)
+          } else {
+            (GroupKind::Capturing, None)
+          };
+          groups.push(Group {
+            kind,
+            name,
+            parent: Some(parent),
+            alternative: 0,
+            start: i,
+            end: i,
+          });
+          stack.push(groups.len() - 1);
+        }
+        ')' => {
+          if stack.len() == 1 {
+            return None;
+          }
+          groups[current].end = i + 1;
+          stack.pop();
+        }
+        '|' => {
+          groups[current].alternative += 1;
+        }
+        _ => {}
+      }
+      i += 1;
+    }
+
+    if stack.len() != 1 {
+      return None;
+    }
+    Some(Self {
+      chars,
+      groups,
+      backreferences,
+    })
+  }
+
+  fn text(&self, start: usize, end: usize) -> String {
+    self.chars[start..end.min(self.chars.len())]
+      .iter()
+      .collect()
+  }
+
+  fn find_group(&self, reference: &Reference) -> Option<usize> {
+    let mut capturing = self
+      .groups
+      .iter()
+      .enumerate()
+      .filter(|(_, group)| group.kind == GroupKind::Capturing);
+    match reference {
+      Reference::Index(index) => capturing.nth(index - 1).map(|(id, _)| id),
+      Reference::Name(name) => capturing
+        .find(|(_, group)| group.name.as_deref() == Some(name))
+        .map(|(id, _)| id),
+    }
+  }
+
+  /// Returns the groups containing `location`, from the innermost to the
+  /// whole pattern, with the index of the alternative in each group.
+  fn ancestors(&self, location: (usize, usize)) -> Vec<(usize, usize)> {
+    let mut ancestors = vec![location];
+    let mut group = location.0;
+    while let Some(parent) = self.groups[group].parent {
+      ancestors.push(parent);
+      group = parent.0;
+    }
+    ancestors
+  }
+
+  /// Checks if the group is matched from right to left, i.e. the innermost
+  /// lookaround containing it is a lookbehind.
+  fn is_matched_backward(&self, group: usize) -> bool {
+    let mut group = group;
+    loop {
+      match self.groups[group].kind {
+        GroupKind::Lookbehind { .. } => return true,
+        GroupKind::Lookahead { .. } | GroupKind::Pattern => return false,
+        _ => {}
+      }
+      group = self.groups[group].parent.unwrap().0;
+    }
+  }
+
+  fn useless_backreferences(&self) -> Vec<NoUselessBackreferenceMessage> {
+    use NoUselessBackreferenceMessage::*;
+
+    let mut messages = Vec::new();
+    for backreference in &self.backreferences {
+      let group_id = match self.find_group(&backreference.reference) {
+        Some(group_id) => group_id,
+        None => continue,
+      };
+      let group = &self.groups[group_id];
+      let backreference_text =
+        self.text(backreference.start, backreference.end);
+      let group_text = self.text(group.start, group.end);
+
+      let backreference_ancestors = self.ancestors(backreference.parent);
+      if backreference_ancestors
+        .iter()
+        .any(|(id, _)| *id == group_id)
+      {
+        messages.push(Nested(backreference_text, group_text));
+        continue;
+      }
+
+      let group_ancestors = self.ancestors(group.parent.unwrap());
+      // The pattern itself is always a common ancestor
+      let (depth, common, alternative) = group_ancestors
+        .iter()
+        .enumerate()
+        .find_map(|(depth, (id, alternative))| {
+          backreference_ancestors
+            .iter()
+            .find(|(other, _)| other == id)
+            .map(|(_, other_alternative)| {
+              (depth, *id, (*alternative, *other_alternative))
+            })
+        })
+        .unwrap();
+
+      let message = if alternative.0 != alternative.1 {
+        Disjunctive(backreference_text, group_text)
+      } else if self.is_matched_backward(common) {
+        if group.end <= backreference.start {
+          Backward(backreference_text, group_text)
+        } else {
+          continue;
+        }
+      } else if backreference.end <= group.start {
+        Forward(backreference_text, group_text)
+      } else if group_ancestors[..depth].iter().any(|(id, _)| {
+        matches!(
+          self.groups[*id].kind,
+          GroupKind::Lookahead { negative: true }
+            | GroupKind::Lookbehind { negative: true }
+        )
+      }) {
+        IntoNegativeLookaround(backreference_text, group_text)
+      } else {
+        continue;
+      };
+      messages.push(message);
+    }
+    messages
+  }
+}
+
+/// Returns the number of capturing groups and whether any of them is named.
+fn count_capturing_groups(chars: &[char]) -> (usize, bool) {
+  let mut in_class = false;
+  let mut escaped = false;
+  let mut count = 0;
+  let mut has_named = false;
+
+  for (i, &c) in chars.iter().enumerate() {
+    if escaped {
+      escaped = false;
+    } else if c == '\\' {
+      escaped = true;
+    } else if c == '[' {
+      in_class = true;
+    } else if c == ']' {
+      in_class = false;
+    } else if c == '(' && !in_class {
+      match (chars.get(i + 1), chars.get(i + 2), chars.get(i + 3)) {
+        (Some('?'), Some('<'), Some(c)) if *c != '=' && *c != '!' => {
+          count += 1;
+          has_named = true;
+        }
+        (Some('?'), _, _) => {}
+        _ => count += 1,
+      }
+    }
+  }
+  (count, has_named)
+}
+
+fn check_regex(ctx: &mut Context, span: Span, pattern: &str, flags: &str) {
+  let unicode = flags.contains('u') || flags.contains('v');
+  let parsed = match ParsedPattern::parse(pattern, unicode) {
+    Some(parsed) => parsed,
+    None => return,
+  };
+  for message in parsed.useless_backreferences() {
+    ctx.add_diagnostic_with_hint(span, CODE, message, HINT);
+  }
+}
+
+fn check_constructor(
+  ctx: &mut Context,
+  span: Span,
+  ident: &Ident,
+  args: &[&ExprOrSpread],
+) {
+  if !matches!(args.first(), Some(arg) if matches!(arg.expr, Expr::Lit(Lit::Str(_))))
+  {
+    return;
+  }
+  let pattern = match extract_regex(ctx.scope(), ident, args) {
+    Some(pattern) => pattern,
+    None => return,
+  };
+  let flags = match args.get(1).map(|arg| arg.expr) {
+    Some(Expr::Lit(Lit::Str(flags))) => flags.inner.value.to_string(),
+    Some(_) => return,
+    None => String::new(),
+  };
+  check_regex(ctx, span, &pattern, &flags);
+}
+
+struct NoUselessBackreferenceHandler;
+
+impl Handler for NoUselessBackreferenceHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    check_regex(ctx, regex.span(), &regex.inner.exp, &regex.inner.flags);
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Expr::Ident(ident) = new_expr.callee {
+      if let Some(args) = &new_expr.args {
+        check_constructor(ctx, new_expr.span(), ident, args);
+      }
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let ExprOrSuper::Expr(Expr::Ident(ident)) = &call_expr.callee {
+      check_constructor(ctx, call_expr.span(), ident, &call_expr.args);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/no-useless-backreference.js
+  // MIT Licensed.
+
+  fn nested(bref: &str, group: &str) -> NoUselessBackreferenceMessage {
+    NoUselessBackreferenceMessage::Nested(bref.to_string(), group.to_string())
+  }
+
+  fn forward(bref: &str, group: &str) -> NoUselessBackreferenceMessage {
+    NoUselessBackreferenceMessage::Forward(bref.to_string(), group.to_string())
+  }
+
+  fn backward(bref: &str, group: &str) -> NoUselessBackreferenceMessage {
+    NoUselessBackreferenceMessage::Backward(bref.to_string(), group.to_string())
+  }
+
+  fn disjunctive(bref: &str, group: &str) -> NoUselessBackreferenceMessage {
+    NoUselessBackreferenceMessage::Disjunctive(
+      bref.to_string(),
+      group.to_string(),
+    )
+  }
+
+  fn negative(bref: &str, group: &str) -> NoUselessBackreferenceMessage {
+    NoUselessBackreferenceMessage::IntoNegativeLookaround(
+      bref.to_string(),
+      group.to_string(),
+    )
+  }
+
+  #[test]
+  fn no_useless_backreference_valid() {
+    assert_lint_ok! {
+      NoUselessBackreference,
+      r"/.(?=(b))\1/",
+      r"/(a)\1/",
+      r"/(a)(b)\2\1/",
+      r"/(a)\1b/",
+      r"/(?<foo>a)\k<foo>/",
+      r"/(?<foo>a)(?<bar>b)\k<bar>\k<foo>/u",
+      r"/(?:(a)|b)\1/",
+      r"/(a)(?:b|\1)/",
+      r"/(a)|b/",
+      r"/(?:a|(b))\1/",
+      r"/(?<=(a)b)\1/",
+      r"/(?<=\1(a))b/",
+      r"/(?<=(?:\1b)(a))c/",
+      r"/(?!(a))b/",
+      r"/(?=(a))\1/",
+      r"/(?!(a)\1)b/",
+      r"/(a)[\1]/",
+      r"/\(a\)\1/",
+      r"/\1/",
+      r"/(a)\2/",
+      r"/\k<foo>(foo)/",
+      r"/\1(/",
+      "/foo/",
+      "new RegExp('(a)\\\\1')",
+      "new RegExp('\\\\1(a)', flags)",
+      "new RegExp(pattern)",
+      "var RegExp = Foo; new RegExp('\\\\1(a)');",
+    };
+  }
+
+  #[test]
+  fn no_useless_backreference_invalid() {
+    assert_lint_err! {
+      NoUselessBackreference,
+      r"/(a\1)/": [
+        {
+          col: 0,
+          message: nested(r"\1", r"(a\1)"),
+          hint: HINT,
+        }
+      ],
+      r"/(?<foo>(.)b\1)/": [
+        {
+          col: 0,
+          message: nested(r"\1", r"(?<foo>(.)b\1)"),
+          hint: HINT,
+        }
+      ],
+      r"/(?<foo>a\k<foo>)/": [
+        {
+          col: 0,
+          message: nested(r"\k<foo>", r"(?<foo>a\k<foo>)"),
+          hint: HINT,
+        }
+      ],
+      r"/\1(a)/": [
+        {
+          col: 0,
+          message: forward(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+      r"/\k<foo>(?<foo>a)/": [
+        {
+          col: 0,
+          message: forward(r"\k<foo>", "(?<foo>a)"),
+          hint: HINT,
+        }
+      ],
+      r"/(?:\1)(a)/": [
+        {
+          col: 0,
+          message: forward(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+      r"/(?<=(a)\1)b/": [
+        {
+          col: 0,
+          message: backward(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+      r"/(a)|\1b/": [
+        {
+          col: 0,
+          message: disjunctive(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+      r"/(?:(a)|\1)b/": [
+        {
+          col: 0,
+          message: disjunctive(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+      r"/(?!(a))\1b/": [
+        {
+          col: 0,
+          message: negative(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+      r"/(?<!(a))b\1/": [
+        {
+          col: 0,
+          message: negative(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+      r"/\1(a)(b)\3/u": [
+        {
+          col: 0,
+          message: forward(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+      r"/\2(a)(b)|\1/": [
+        {
+          col: 0,
+          message: forward(r"\2", "(b)"),
+          hint: HINT,
+        },
+        {
+          col: 0,
+          message: disjunctive(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+      "new RegExp('\\\\1(a)')": [
+        {
+          col: 0,
+          message: forward(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+      "RegExp('(a)|\\\\1b', 'u')": [
+        {
+          col: 0,
+          message: disjunctive(r"\1", "(a)"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -1,11 +1,12 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
-use super::{Context, LintRule, DUMMY_NODE};
-use crate::ProgramRef;
-use deno_ast::swc::ast::VarDecl;
-use deno_ast::swc::ast::VarDeclKind;
-use deno_ast::swc::visit::noop_visit_type;
-use deno_ast::swc::visit::Node;
-use deno_ast::swc::visit::Visit;
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Ident, VarDeclKind};
+use deno_ast::swc::common::{BytePos, Span};
+use deno_ast::swc::utils::find_ids;
+use deno_ast::view::{self as ast_view, NodeTrait};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -13,6 +14,7 @@ pub struct NoVar;
 
 const MESSAGE: &str = "`var` keyword is not allowed.";
 const CODE: &str = "no-var";
+const FIX_DESC: &str = "Replace `var` with `let`";
 
 impl LintRule for NoVar {
   fn new() -> Arc<Self> {
@@ -27,16 +29,20 @@ impl LintRule for NoVar {
     CODE
   }
 
-  fn lint_program<'view>(
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["var"]
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
     &self,
-    context: &mut Context<'view>,
-    program: ProgramRef<'view>,
+    context: &mut Context,
+    program: Program<'_>,
   ) {
-    let mut visitor = NoVarVisitor::new(context);
-    match program {
-      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
-      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
-    }
+    NoVarHandler.traverse(program, context);
   }
 
   #[cfg(feature = "docs")]
@@ -45,23 +51,62 @@ impl LintRule for NoVar {
   }
 }
 
-struct NoVarVisitor<'c, 'view> {
-  context: &'c mut Context<'view>,
-}
-
-impl<'c, 'view> NoVarVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+/// Checks if replacing `var` with `let` keeps the meaning of the program: the
+/// declaration must not be nested in a block, none of its variables may be
+/// declared again, and none of them may be used before the declaration.
+fn can_fix(var_decl: &ast_view::VarDecl, ctx: &Context) -> bool {
+  let scope_tree = ctx.scope_tree();
+  let scope = scope_tree.scope_of(var_decl.as_node());
+  if !scope_tree.scope(scope).kind().is_var_scope() {
+    return false;
   }
+  var_decl
+    .inner
+    .decls
+    .iter()
+    .flat_map(|decl| find_ids::<_, Ident>(&decl.name))
+    .all(|ident| {
+      let binding = match scope_tree.binding_of(&ident) {
+        Some(id) => scope_tree.binding(id),
+        None => return false,
+      };
+      let declarations = scope_tree
+        .scope(binding.scope())
+        .bindings()
+        .iter()
+        .filter(|id| scope_tree.binding(**id).name() == &ident.sym)
+        .count();
+      declarations == 1
+        && binding.span() == ident.span
+        && binding
+          .references()
+          .iter()
+          .all(|reference| reference.span().lo >= var_decl.inner.span.hi)
+    })
 }
 
-impl<'c, 'view> Visit for NoVarVisitor<'c, 'view> {
-  noop_visit_type!();
+struct NoVarHandler;
 
-  fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
-    if var_decl.kind == VarDeclKind::Var {
-      self.context.add_diagnostic(var_decl.span, CODE, MESSAGE);
+impl Handler for NoVarHandler {
+  fn var_decl(&mut self, var_decl: &ast_view::VarDecl, ctx: &mut Context) {
+    if var_decl.inner.kind != VarDeclKind::Var {
+      return;
     }
+
+    let span = var_decl.inner.span;
+    let mut fixes = Vec::new();
+    if can_fix(var_decl, ctx) {
+      if let Some(offset) = ctx.file_text_substring(&span).find("var") {
+        let start = span.lo + BytePos(offset as u32);
+        let keyword = Span::new(start, start + BytePos(3), span.ctxt);
+        fixes.push(LintFix {
+          description: FIX_DESC.to_string(),
+          changes: vec![ctx.fix_change(keyword, "let")],
+          applicability: None,
+        });
+      }
+    }
+    ctx.add_diagnostic_with_fixes(span, CODE, MESSAGE, None, fixes);
   }
 }
 
@@ -81,11 +126,48 @@ mod tests {
       "var foo = 0;": [{
         col: 0,
         message: MESSAGE,
+        fix: (FIX_DESC, "let foo = 0;"),
+      }],
+      "export var foo = 0; foo++;": [{
+        col: 7,
+        message: MESSAGE,
+        fix: (FIX_DESC, "export let foo = 0; foo++;"),
+      }],
+      "function f() { var { a, b: [c] } = obj; return a + c; }": [{
+        col: 15,
+        message: MESSAGE,
+        fix: (FIX_DESC, "function f() { let { a, b: [c] } = obj; return a + c; }"),
       }],
       "let foo = 0; var bar = 1;": [{
         col: 13,
         message: MESSAGE,
       }],
+      "if (x) { var foo = 0; }": [{
+        col: 9,
+        message: MESSAGE,
+      }],
+      "for (var i = 0; i < 3; i++) {}": [{
+        col: 5,
+        message: MESSAGE,
+      }],
+      "var foo = 0; var foo = 1;": [
+        {
+          col: 0,
+          message: MESSAGE,
+        },
+        {
+          col: 13,
+          message: MESSAGE,
+        }
+      ],
+      "foo = 1; var foo;": [{
+        col: 9,
+        message: MESSAGE,
+      }],
+      "function f(a) { var a = 1; }": [{
+        col: 16,
+        message: MESSAGE,
+      }],
       "let foo = 0; var bar = 1; var x = 2;": [
         {
           col: 13,
@@ -0,0 +1,242 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct NoVarRequires {
+  options: NoVarRequiresOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoVarRequiresOptions {
+  /// Allow `require` calls inside the block of a `try` statement, which is a
+  /// common way to load optional dependencies.
+  pub allow_in_try: bool,
+}
+
+impl NoVarRequires {
+  pub fn with_options(options: NoVarRequiresOptions) -> Arc<Self> {
+    Arc::new(NoVarRequires { options })
+  }
+}
+
+const CODE: &str = "no-var-requires";
+const MESSAGE: &str = "`require` is not allowed in ES modules";
+const HINT: &str =
+  "Use an `import` declaration or a dynamic `import()` instead";
+
+impl LintRule for NoVarRequires {
+  fn new() -> Arc<Self> {
+    Self::with_options(NoVarRequiresOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowInTry": {
+          "type": "boolean",
+          "description": "Allow `require` calls inside a `try` block.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(NoVarRequiresOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    // CommonJS is only a problem in files that are ES modules. Scripts, i.e.
+    // files without any `import` or `export`, are left alone.
+    if !matches!(program, Program::Module(_)) {
+      return;
+    }
+    NoVarRequiresHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_var_requires.md")
+  }
+}
+
+/// Checks if `node` is inside the block of a `try` statement in the same
+/// function.
+fn is_in_try_block(node: Node) -> bool {
+  let span = node.span();
+  for ancestor in node.ancestors() {
+    match ancestor {
+      Node::TryStmt(try_stmt) if try_stmt.block.span().contains(span) => {
+        return true;
+      }
+      Node::Function(_) | Node::ArrowExpr(_) => return false,
+      _ => {}
+    }
+  }
+  false
+}
+
+struct NoVarRequiresHandler<'a> {
+  options: &'a NoVarRequiresOptions,
+}
+
+impl<'a> Handler for NoVarRequiresHandler<'a> {
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let ident = match &call_expr.inner.callee {
+      ExprOrSuper::Expr(expr) => match &**expr {
+        Expr::Ident(ident) => ident,
+        _ => return,
+      },
+      ExprOrSuper::Super(_) => return,
+    };
+    if &*ident.sym != "require" || !ctx.scope().is_global(&ident.to_id()) {
+      return;
+    }
+    if self.options.allow_in_try && is_in_try_block(call_expr.as_node()) {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(call_expr.span(), CODE, MESSAGE, HINT);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/typescript-eslint/typescript-eslint/blob/v5.40.0/packages/eslint-plugin/tests/rules/no-var-requires.test.ts
+  // MIT Licensed.
+
+  #[test]
+  fn no_var_requires_valid() {
+    assert_lint_ok! {
+      NoVarRequires,
+      "import foo from 'foo';",
+      "import foo = require('foo');",
+      "export const foo = await import('foo');",
+      "import { createRequire } from 'module'; const req = createRequire(import.meta.url); req('foo');",
+      "export function foo(require: (id: string) => unknown) { return require('foo'); }",
+      "import { require } from './require.ts'; require('foo');",
+      "export const foo = bar.require('foo');",
+      "export const foo = require.resolve;",
+
+      // Scripts aren't ES modules.
+      "const foo = require('foo');",
+      "require('foo');",
+    };
+
+    assert_lint_ok! {
+      NoVarRequires,
+      options: NoVarRequiresOptions { allow_in_try: true },
+      "let foo; try { foo = require('foo'); } catch {} export { foo };",
+      "export let foo; try { if (bar) { foo = require('foo'); } } catch {}",
+    };
+  }
+
+  #[test]
+  fn no_var_requires_invalid() {
+    assert_lint_err! {
+      NoVarRequires,
+      "import bar from 'bar'; const foo = require('foo');": [
+        {
+          col: 35,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export const foo = require('foo');": [
+        {
+          col: 19,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export const foo = require('foo')?.bar;": [
+        {
+          col: 19,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export const { foo } = require('foo');": [
+        {
+          col: 23,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export {}; require('foo');": [
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export {}; let foo; try { foo = require('foo'); } catch {}": [
+        {
+          col: 32,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoVarRequires,
+      options: NoVarRequiresOptions { allow_in_try: true },
+      "export {}; try {} catch { require('foo'); }": [
+        {
+          col: 26,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "export {}; try { (() => require('foo'))(); } catch {}": [
+        {
+          col: 24,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
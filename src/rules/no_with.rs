@@ -25,6 +25,10 @@ impl LintRule for NoWith {
     CODE
   }
 
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["with"]
+  }
+
   fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
     unreachable!();
   }
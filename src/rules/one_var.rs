@@ -0,0 +1,441 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::VarDeclKind;
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view::{self as ast_view, NodeTrait};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct OneVar {
+  options: OneVarOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OneVarMode {
+  /// Declare all variables of a kind in a single statement per scope.
+  Always,
+  /// Declare one variable per statement.
+  Never,
+}
+
+impl Default for OneVarMode {
+  fn default() -> Self {
+    OneVarMode::Always
+  }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OneVarOptions {
+  /// Mode applied to kinds of declarations that aren't configured separately.
+  pub mode: OneVarMode,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub var: Option<OneVarMode>,
+  #[serde(rename = "let", skip_serializing_if = "Option::is_none")]
+  pub let_: Option<OneVarMode>,
+  #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+  pub const_: Option<OneVarMode>,
+}
+
+impl OneVarOptions {
+  fn mode_for(&self, kind: VarDeclKind) -> OneVarMode {
+    match kind {
+      VarDeclKind::Var => self.var,
+      VarDeclKind::Let => self.let_,
+      VarDeclKind::Const => self.const_,
+    }
+    .unwrap_or(self.mode)
+  }
+}
+
+impl OneVar {
+  pub fn with_options(options: OneVarOptions) -> Arc<Self> {
+    Arc::new(OneVar { options })
+  }
+}
+
+const CODE: &str = "one-var";
+
+#[derive(Display)]
+enum OneVarMessage {
+  #[display(fmt = "Combine this with the previous `{}` declaration", _0)]
+  Combine(&'static str),
+  #[display(fmt = "Split `{}` declarations into multiple statements", _0)]
+  Split(&'static str),
+}
+
+#[derive(Display)]
+enum OneVarHint {
+  #[display(
+    fmt = "Declare all `{}` variables of this scope in a single statement",
+    _0
+  )]
+  Combine(&'static str),
+  #[display(fmt = "Declare each variable in its own statement")]
+  Split,
+}
+
+const COMBINE_FIX_DESC: &str = "Combine the declarations";
+const SPLIT_FIX_DESC: &str = "Split the declaration";
+
+impl LintRule for OneVar {
+  fn new() -> Arc<Self> {
+    Self::with_options(OneVarOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Automatic
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some({
+      let mode = json!({
+        "type": "string",
+        "enum": ["always", "never"]
+      });
+      json!({
+        "type": "object",
+        "properties": {
+          "mode": {
+            "type": "string",
+            "enum": ["always", "never"],
+            "default": "always"
+          },
+          "var": mode,
+          "let": mode,
+          "const": mode
+        },
+        "additionalProperties": false
+      })
+    })
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(OneVarOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    OneVarHandler {
+      options: &self.options,
+      last_decls: HashMap::new(),
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/one_var.md")
+  }
+}
+
+struct OneVarHandler<'a> {
+  options: &'a OneVarOptions,
+  /// The span of the last declarator of the latest declaration statement,
+  /// keyed by the span of the scope and the kind of the declaration.
+  last_decls: HashMap<(Span, VarDeclKind), Span>,
+}
+
+impl<'a> Handler for OneVarHandler<'a> {
+  fn var_decl(&mut self, var_decl: &ast_view::VarDecl, ctx: &mut Context) {
+    // Declarations in loop heads can't be combined or split, and `declare`d
+    // or exported declarations are left as they are written.
+    if var_decl.declare()
+      || matches!(
+        var_decl.parent(),
+        ast_view::Node::ForStmt(_)
+          | ast_view::Node::ForInStmt(_)
+          | ast_view::Node::ForOfStmt(_)
+          | ast_view::Node::ExportDecl(_)
+      )
+    {
+      return;
+    }
+
+    let kind = var_decl.inner.kind;
+    match self.options.mode_for(kind) {
+      OneVarMode::Always => {
+        let scope = scope_span(var_decl.as_node(), kind);
+        let first = var_decl.decls.first().unwrap().span();
+        let last = var_decl.decls.last().unwrap().span();
+
+        if let Some(prev) = self.last_decls.insert((scope, kind), last) {
+          let fixes = combine_fix(ctx, kind, prev, first).into_iter().collect();
+          ctx.add_diagnostic_with_fixes(
+            var_decl.span(),
+            CODE,
+            OneVarMessage::Combine(kind_str(kind)),
+            Some(OneVarHint::Combine(kind_str(kind)).to_string()),
+            fixes,
+          );
+        }
+      }
+      OneVarMode::Never => {
+        if var_decl.decls.len() > 1 {
+          let fixes = split_fix(ctx, var_decl).into_iter().collect();
+          ctx.add_diagnostic_with_fixes(
+            var_decl.span(),
+            CODE,
+            OneVarMessage::Split(kind_str(kind)),
+            Some(OneVarHint::Split.to_string()),
+            fixes,
+          );
+        }
+      }
+    }
+  }
+}
+
+/// Returns the span of the scope that a declaration of `kind` belongs to:
+/// the enclosing function for `var` and the enclosing block otherwise.
+fn scope_span(node: ast_view::Node, kind: VarDeclKind) -> Span {
+  use deno_ast::view::Node;
+
+  for ancestor in node.ancestors() {
+    let is_scope = match ancestor {
+      Node::Function(_)
+      | Node::ArrowExpr(_)
+      | Node::Module(_)
+      | Node::Script(_)
+      | Node::TsModuleBlock(_) => true,
+      Node::BlockStmt(_) | Node::SwitchStmt(_) => kind != VarDeclKind::Var,
+      _ => false,
+    };
+    if is_scope {
+      return ancestor.span();
+    }
+  }
+  node.span()
+}
+
+/// Merges the declaration into the previous one if the previous one directly
+/// precedes it, e.g. `let a = 1; let b = 2;` into `let a = 1, b = 2;`.
+fn combine_fix(
+  ctx: &Context,
+  kind: VarDeclKind,
+  prev_last: Span,
+  first: Span,
+) -> Option<LintFix> {
+  let between = Span::new(prev_last.hi(), first.lo(), Default::default());
+  let text = ctx.file_text_substring(&between).trim();
+  let keyword = text.strip_prefix(';').unwrap_or(text).trim_start();
+  if keyword != kind_str(kind) {
+    return None;
+  }
+
+  Some(LintFix {
+    description: COMBINE_FIX_DESC.to_string(),
+    changes: vec![ctx.fix_change(between, ", ")],
+    applicability: None,
+  })
+}
+
+/// Splits every declarator into its own statement, e.g. `let a = 1, b = 2;`
+/// into `let a = 1; let b = 2;`.
+fn split_fix(ctx: &Context, var_decl: &ast_view::VarDecl) -> Option<LintFix> {
+  let replacement = format!("; {} ", kind_str(var_decl.inner.kind));
+  let mut changes = Vec::new();
+
+  for pair in var_decl.decls.windows(2) {
+    let between =
+      Span::new(pair[0].span().hi(), pair[1].span().lo(), Default::default());
+    if ctx.file_text_substring(&between).trim() != "," {
+      return None;
+    }
+    changes.push(ctx.fix_change(between, &replacement));
+  }
+
+  Some(LintFix {
+    description: SPLIT_FIX_DESC.to_string(),
+    changes,
+    applicability: None,
+  })
+}
+
+fn kind_str(kind: VarDeclKind) -> &'static str {
+  match kind {
+    VarDeclKind::Var => "var",
+    VarDeclKind::Let => "let",
+    VarDeclKind::Const => "const",
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn one_var_valid() {
+    assert_lint_ok! {
+      OneVar,
+      "var a = 1, b = 2;",
+      "let a, b; const c = 1;",
+      "var a = 1; let b = 2; const c = 3;",
+      "function foo() { var a; } function bar() { var b; }",
+      "let a; { let b; }",
+      "let a; if (x) { let b; } else { let c; }",
+      "var a; for (var i = 0; i < 1; i++) {}",
+      "for (let x of xs) {} for (let y of ys) {}",
+      "export const a = 1; export const b = 2;",
+      "declare var a: number; declare var b: number;",
+      "switch (x) { case 1: let a; break; } let b;",
+      "const foo = () => { let a; }; let b;",
+    };
+
+    assert_lint_ok! {
+      OneVar,
+      options: OneVarOptions {
+        mode: OneVarMode::Never,
+        ..Default::default()
+      },
+      "var a; var b;",
+      "let a = 1; let b = 2;",
+      "for (let i = 0, j = 0; i < 1; i++) {}",
+    };
+
+    assert_lint_ok! {
+      OneVar,
+      options: OneVarOptions {
+        var: Some(OneVarMode::Never),
+        ..Default::default()
+      },
+      "var a; var b; let c, d;",
+    };
+  }
+
+  #[test]
+  fn one_var_invalid() {
+    assert_lint_err! {
+      OneVar,
+      "var a; var b;": [
+        {
+          col: 7,
+          message: OneVarMessage::Combine("var"),
+          hint: OneVarHint::Combine("var"),
+          fix: (COMBINE_FIX_DESC, "var a, b;"),
+        }
+      ],
+      "let a = 1;\nlet b = 2;\nlet c = 3;": [
+        {
+          line: 2,
+          col: 0,
+          message: OneVarMessage::Combine("let"),
+          hint: OneVarHint::Combine("let"),
+          fix: (COMBINE_FIX_DESC, "let a = 1, b = 2;\nlet c = 3;"),
+        },
+        {
+          line: 3,
+          col: 0,
+          message: OneVarMessage::Combine("let"),
+          hint: OneVarHint::Combine("let"),
+          fix: (COMBINE_FIX_DESC, "let a = 1;\nlet b = 2, c = 3;"),
+        }
+      ],
+      "function foo() { var a; if (x) { var b; } }": [
+        {
+          col: 33,
+          message: OneVarMessage::Combine("var"),
+          hint: OneVarHint::Combine("var"),
+        }
+      ],
+      "const a = 1; foo(); const b = 2;": [
+        {
+          col: 20,
+          message: OneVarMessage::Combine("const"),
+          hint: OneVarHint::Combine("const"),
+        }
+      ],
+      "{ let a; let b; }": [
+        {
+          col: 9,
+          message: OneVarMessage::Combine("let"),
+          hint: OneVarHint::Combine("let"),
+          fix: (COMBINE_FIX_DESC, "{ let a, b; }"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      OneVar,
+      options: OneVarOptions {
+        mode: OneVarMode::Never,
+        ..Default::default()
+      },
+      "var a, b;": [
+        {
+          col: 0,
+          message: OneVarMessage::Split("var"),
+          hint: OneVarHint::Split,
+          fix: (SPLIT_FIX_DESC, "var a; var b;"),
+        }
+      ],
+      "const a = 1, b = 2, c = 3;": [
+        {
+          col: 0,
+          message: OneVarMessage::Split("const"),
+          hint: OneVarHint::Split,
+          fix: (SPLIT_FIX_DESC, "const a = 1; const b = 2; const c = 3;"),
+        }
+      ],
+      "let a, /* b */ b;": [
+        {
+          col: 0,
+          message: OneVarMessage::Split("let"),
+          hint: OneVarHint::Split,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      OneVar,
+      options: OneVarOptions {
+        let_: Some(OneVarMode::Never),
+        ..Default::default()
+      },
+      "let a, b; const c = 1; const d = 2;": [
+        {
+          col: 0,
+          message: OneVarMessage::Split("let"),
+          hint: OneVarHint::Split,
+          fix: (SPLIT_FIX_DESC, "let a; let b; const c = 1; const d = 2;"),
+        },
+        {
+          col: 23,
+          message: OneVarMessage::Combine("const"),
+          hint: OneVarHint::Combine("const"),
+          fix: (COMBINE_FIX_DESC, "let a, b; const c = 1, d = 2;"),
+        }
+      ],
+    };
+  }
+}
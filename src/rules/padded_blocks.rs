@@ -0,0 +1,605 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::member_start;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PaddedBlocks {
+  options: PaddedBlocksOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PaddedBlocksMode {
+  /// The body must start and end with an empty line.
+  Always,
+  /// The body must not start or end with an empty line.
+  Never,
+  /// The body isn't checked.
+  Ignore,
+}
+
+impl Default for PaddedBlocksMode {
+  fn default() -> Self {
+    PaddedBlocksMode::Always
+  }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PaddedBlocksOptions {
+  /// The style of block statements, including function bodies.
+  pub blocks: PaddedBlocksMode,
+  /// The style of class bodies.
+  pub classes: PaddedBlocksMode,
+  /// The style of `switch` statement bodies.
+  pub switches: PaddedBlocksMode,
+  /// Allow blocks that start and end on the same line, e.g. `{ foo(); }`.
+  pub allow_single_line_blocks: bool,
+}
+
+impl PaddedBlocks {
+  pub fn with_options(options: PaddedBlocksOptions) -> Arc<Self> {
+    Arc::new(PaddedBlocks { options })
+  }
+}
+
+const CODE: &str = "padded-blocks";
+
+#[derive(Display)]
+enum PaddedBlocksMessage {
+  #[display(fmt = "Block must be padded by empty lines")]
+  Always,
+  #[display(fmt = "Block must not be padded by empty lines")]
+  Never,
+}
+
+#[derive(Display)]
+enum PaddedBlocksFix {
+  #[display(fmt = "Add an empty line")]
+  Add,
+  #[display(fmt = "Remove the empty lines")]
+  Remove,
+}
+
+impl LintRule for PaddedBlocks {
+  fn new() -> Arc<Self> {
+    Self::with_options(PaddedBlocksOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Automatic
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let mode = |description: &str| {
+      json!({
+        "type": "string",
+        "enum": ["always", "never", "ignore"],
+        "description": description,
+        "default": "always"
+      })
+    };
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "blocks": mode("The style of block statements."),
+        "classes": mode("The style of class bodies."),
+        "switches": mode("The style of `switch` statement bodies."),
+        "allowSingleLineBlocks": {
+          "type": "boolean",
+          "description": "Allow blocks that start and end on the same line.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(PaddedBlocksOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    let mut comments: Vec<Span> =
+      context.all_comments().map(|comment| comment.span).collect();
+    comments.sort_by_key(|span| span.lo);
+
+    PaddedBlocksHandler {
+      options: &self.options,
+      comments,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/padded_blocks.md")
+  }
+}
+
+struct PaddedBlocksHandler<'a> {
+  options: &'a PaddedBlocksOptions,
+  comments: Vec<Span>,
+}
+
+impl<'a> PaddedBlocksHandler<'a> {
+  /// Returns the position of the `{` in front of `pos`, skipping whitespace
+  /// and comments.
+  fn open_brace_before(&self, ctx: &Context, pos: BytePos) -> Option<BytePos> {
+    let text = ctx.source_file().text();
+    let file_start = ctx.source_file().span().lo;
+    let mut pos = pos;
+    loop {
+      let before = &text[..(pos - file_start).0 as usize];
+      pos = file_start + BytePos(before.trim_end().len() as u32);
+      match self.comments.iter().find(|comment| comment.hi == pos) {
+        Some(comment) => pos = comment.lo,
+        None => break,
+      }
+    }
+    let brace = pos - BytePos(1);
+    if ctx.file_text_substring(&Span::new(brace, pos, Default::default()))
+      == "{"
+    {
+      Some(brace)
+    } else {
+      None
+    }
+  }
+
+  /// Checks the body between the braces at `open` and `close`, whose content
+  /// spans from `first` to `last`.
+  #[allow(clippy::too_many_arguments)]
+  fn check(
+    &self,
+    ctx: &mut Context,
+    span: Span,
+    mode: PaddedBlocksMode,
+    open: BytePos,
+    close: BytePos,
+    first: BytePos,
+    last: BytePos,
+  ) {
+    if mode == PaddedBlocksMode::Ignore {
+      return;
+    }
+    let line_of = |pos: BytePos| ctx.source_file().line_index(pos);
+
+    // Comments on the same line as a brace are considered part of the brace.
+    let mut top_end = open + BytePos(1);
+    let mut top_content = first;
+    for comment in self
+      .comments
+      .iter()
+      .filter(|comment| open < comment.lo && comment.hi <= first)
+    {
+      if line_of(comment.lo) == line_of(top_end - BytePos(1)) {
+        top_end = comment.hi;
+      } else {
+        top_content = comment.lo;
+        break;
+      }
+    }
+    let mut bottom_start = close;
+    let mut bottom_content = last;
+    for comment in self
+      .comments
+      .iter()
+      .rev()
+      .filter(|comment| last <= comment.lo && comment.hi <= close)
+    {
+      if line_of(comment.hi) == line_of(bottom_start) {
+        bottom_start = comment.lo;
+      } else {
+        bottom_content = comment.hi;
+        break;
+      }
+    }
+
+    if self.options.allow_single_line_blocks
+      && line_of(top_end - BytePos(1)) == line_of(bottom_start)
+    {
+      return;
+    }
+
+    let top_padded = line_of(top_content) - line_of(top_end - BytePos(1)) >= 2;
+    let bottom_padded = line_of(bottom_start) - line_of(bottom_content) >= 2;
+    let line_start_of =
+      |pos: BytePos| ctx.source_file().line_start(line_of(pos));
+    // The whitespace in front of the content of the line containing `pos`.
+    let indent_of = |pos: BytePos| {
+      let line_start = line_start_of(pos);
+      let line = ctx.file_text_substring(&Span::new(
+        line_start,
+        pos,
+        Default::default(),
+      ));
+      line[..line.len() - line.trim_start().len()].to_string()
+    };
+    // The indentation to use for something at `pos`: its own if it starts its
+    // line, and that of the line the block starts on otherwise.
+    let indent_at = |pos: BytePos, prev: BytePos| {
+      if line_of(prev) < line_of(pos) {
+        indent_of(pos)
+      } else {
+        indent_of(open)
+      }
+    };
+
+    let mut changes = Vec::new();
+    match mode {
+      PaddedBlocksMode::Always => {
+        // The padding regions only contain whitespace, so each one is replaced
+        // as a whole, which also moves content sharing a line with a brace to
+        // a line of its own.
+        if !top_padded {
+          let text = format!("\n\n{}", indent_at(top_content, top_end));
+          changes
+            .push((Span::new(top_end, top_content, Default::default()), text));
+        }
+        if !bottom_padded {
+          let text = format!("\n\n{}", indent_at(bottom_start, bottom_content));
+          changes.push((
+            Span::new(bottom_content, bottom_start, Default::default()),
+            text,
+          ));
+        }
+      }
+      PaddedBlocksMode::Never => {
+        if top_padded {
+          let hi = line_start_of(top_content);
+          changes.push((
+            Span::new(top_end, hi, Default::default()),
+            "\n".to_string(),
+          ));
+        }
+        if bottom_padded {
+          let hi = line_start_of(bottom_start);
+          changes.push((
+            Span::new(bottom_content, hi, Default::default()),
+            "\n".to_string(),
+          ));
+        }
+      }
+      PaddedBlocksMode::Ignore => unreachable!(),
+    }
+
+    let (message, fix) = match mode {
+      PaddedBlocksMode::Always => {
+        (PaddedBlocksMessage::Always, PaddedBlocksFix::Add)
+      }
+      _ => (PaddedBlocksMessage::Never, PaddedBlocksFix::Remove),
+    };
+    for (change_span, text) in changes {
+      let fix = LintFix {
+        description: fix.to_string(),
+        changes: vec![ctx.fix_change(change_span, text)],
+        applicability: None,
+      };
+      ctx.add_diagnostic_with_fixes(
+        span,
+        CODE,
+        message.to_string(),
+        None,
+        vec![fix],
+      );
+    }
+  }
+}
+
+impl<'a> Handler for PaddedBlocksHandler<'a> {
+  fn block_stmt(
+    &mut self,
+    block_stmt: &ast_view::BlockStmt,
+    ctx: &mut Context,
+  ) {
+    let stmts = &block_stmt.inner.stmts;
+    if let (Some(first), Some(last)) = (stmts.first(), stmts.last()) {
+      let span = block_stmt.inner.span;
+      self.check(
+        ctx,
+        span,
+        self.options.blocks,
+        span.lo,
+        span.hi - BytePos(1),
+        first.span().lo,
+        last.span().hi,
+      );
+    }
+  }
+
+  fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
+    let body = &class.inner.body;
+    if let (Some(first), Some(last)) = (body.first(), body.last()) {
+      let first_start = member_start(first);
+      if let Some(open) = self.open_brace_before(ctx, first_start) {
+        let span = class.inner.span;
+        self.check(
+          ctx,
+          span,
+          self.options.classes,
+          open,
+          span.hi - BytePos(1),
+          first_start,
+          last.span().hi,
+        );
+      }
+    }
+  }
+
+  fn switch_stmt(
+    &mut self,
+    switch_stmt: &ast_view::SwitchStmt,
+    ctx: &mut Context,
+  ) {
+    let cases = &switch_stmt.inner.cases;
+    if let (Some(first), Some(last)) = (cases.first(), cases.last()) {
+      if let Some(open) = self.open_brace_before(ctx, first.span.lo) {
+        let span = switch_stmt.inner.span;
+        self.check(
+          ctx,
+          span,
+          self.options.switches,
+          open,
+          span.hi - BytePos(1),
+          first.span.lo,
+          last.span.hi,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/padded-blocks.js
+  // MIT Licensed.
+
+  const NEVER: PaddedBlocksOptions = PaddedBlocksOptions {
+    blocks: PaddedBlocksMode::Never,
+    classes: PaddedBlocksMode::Never,
+    switches: PaddedBlocksMode::Never,
+    allow_single_line_blocks: false,
+  };
+
+  #[test]
+  fn padded_blocks_valid() {
+    assert_lint_ok! {
+      PaddedBlocks,
+      "{\n\na();\n\n}",
+      "{\n\n\na();\n\n\n}",
+      "{\n\n//comment\na();\n\n}",
+      "{\n\na();\n//comment\n\n}",
+      "{\n\na()\n//comment\n\n}",
+      "{\n\na = 1\n\n}",
+      "{//comment\n\na();\n\n}",
+      "{ /* comment */\n\na();\n\n/* comment */ }",
+      "{}",
+      "{\n}",
+      "switch (a) {\n\ncase 0: foo();\ncase 1: bar();\n\n}",
+      "switch (a) {}",
+      "class A {\n\nfoo() {\n\nbar();\n\n}\n\n}",
+      "class A {}",
+      "function foo() {\n\nbar();\n\n}",
+    };
+
+    assert_lint_ok! {
+      PaddedBlocks,
+      options: NEVER,
+      "{\na();\n}",
+      "{a();}",
+      "{ a(); }",
+      "{\n//comment\na();\n}",
+      "{\na();\n//comment\n}",
+      "{//comment\na();\n/* comment */}",
+      "switch (a) {\ncase 0: foo();\n}",
+      "class A {\n@dec foo() {}\n}",
+      "class A {\nfoo() {}\n}",
+      "function foo() {\n  bar();\n}",
+    };
+
+    assert_lint_ok! {
+      PaddedBlocks,
+      options: PaddedBlocksOptions {
+        allow_single_line_blocks: true,
+        ..Default::default()
+      },
+      "{ a(); }",
+      "{ a(); /* comment */ }",
+      "class A { foo() { bar(); } }",
+    };
+
+    assert_lint_ok! {
+      PaddedBlocks,
+      options: PaddedBlocksOptions {
+        blocks: PaddedBlocksMode::Ignore,
+        classes: PaddedBlocksMode::Never,
+        switches: PaddedBlocksMode::Ignore,
+        allow_single_line_blocks: false,
+      },
+      "class A {\nfoo() {\nbar();\n}\n}",
+    };
+  }
+
+  #[test]
+  fn padded_blocks_invalid() {
+    assert_lint_err! {
+      PaddedBlocks,
+      "{\n//comment\na();\n\n}": [
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Always,
+          fix: (PaddedBlocksFix::Add, "{\n\n//comment\na();\n\n}"),
+        }
+      ],
+      "{\n\na();\n}": [
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Always,
+          fix: (PaddedBlocksFix::Add, "{\n\na();\n\n}"),
+        }
+      ],
+      "function foo() {\n  bar();\n\n}": [
+        {
+          col: 15,
+          message: PaddedBlocksMessage::Always,
+          fix: (PaddedBlocksFix::Add, "function foo() {\n\n  bar();\n\n}"),
+        }
+      ],
+      "if (a) {\n  if (b) {\n\n    c();\n  }\n\n}": [
+        {
+          col: 7,
+          message: PaddedBlocksMessage::Always,
+          fix: (PaddedBlocksFix::Add, "if (a) {\n\n  if (b) {\n\n    c();\n  }\n\n}"),
+        },
+        {
+          line: 2,
+          col: 9,
+          message: PaddedBlocksMessage::Always,
+          fix: (PaddedBlocksFix::Add, "if (a) {\n  if (b) {\n\n    c();\n\n  }\n\n}"),
+        }
+      ],
+      "switch (a) {\ncase 0: foo();\n\n}": [
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Always,
+          fix: (PaddedBlocksFix::Add, "switch (a) {\n\ncase 0: foo();\n\n}"),
+        }
+      ],
+      "class A {\n\nfoo() {}\n}": [
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Always,
+          fix: (PaddedBlocksFix::Add, "class A {\n\nfoo() {}\n\n}"),
+        }
+      ],
+      "if (a) { b(); }": [
+        {
+          col: 7,
+          message: PaddedBlocksMessage::Always,
+          fix: (PaddedBlocksFix::Add, "if (a) {\n\nb(); }"),
+        },
+        {
+          col: 7,
+          message: PaddedBlocksMessage::Always,
+          fix: (PaddedBlocksFix::Add, "if (a) { b();\n\n}"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PaddedBlocks,
+      options: NEVER,
+      "{\n\na();\n\n}": [
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Never,
+          fix: (PaddedBlocksFix::Remove, "{\na();\n\n}"),
+        },
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Never,
+          fix: (PaddedBlocksFix::Remove, "{\n\na();\n}"),
+        }
+      ],
+      "function foo() {\n\n\n  bar();\n}": [
+        {
+          col: 15,
+          message: PaddedBlocksMessage::Never,
+          fix: (PaddedBlocksFix::Remove, "function foo() {\n  bar();\n}"),
+        }
+      ],
+      "{ // comment\n\n  a();\n\n  /* comment */ }": [
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Never,
+          fix: (PaddedBlocksFix::Remove, "{ // comment\n  a();\n\n  /* comment */ }"),
+        },
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Never,
+          fix: (PaddedBlocksFix::Remove, "{ // comment\n\n  a();\n  /* comment */ }"),
+        }
+      ],
+      "switch (a) {\n\n  case 0:\n    foo();\n}": [
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Never,
+          fix: (PaddedBlocksFix::Remove, "switch (a) {\n  case 0:\n    foo();\n}"),
+        }
+      ],
+      "class A {\n  foo() {}\n\n}": [
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Never,
+          fix: (PaddedBlocksFix::Remove, "class A {\n  foo() {}\n}"),
+        }
+      ],
+      "class A {\n\n  @dec foo() {}\n}": [
+        {
+          col: 0,
+          message: PaddedBlocksMessage::Never,
+          fix: (PaddedBlocksFix::Remove, "class A {\n  @dec foo() {}\n}"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn padded_blocks_fix_single_line_blocks() {
+    use crate::linter::fix_source;
+
+    let fix = |src: &str| {
+      let (fixed, diagnostics) =
+        fix_source("file.ts", src, vec![PaddedBlocks::new()], 10)
+          .expect("Failed to lint");
+      assert!(diagnostics.is_empty());
+      fixed
+    };
+
+    assert_eq!(fix("if (a) { b(); }"), "if (a) {\n\nb();\n\n}");
+    assert_eq!(
+      fix("function foo() {\n  if (a) { b(); }\n}"),
+      "function foo() {\n\n  if (a) {\n\n  b();\n\n  }\n\n}"
+    );
+    assert_eq!(
+      fix("class A { foo() {} /* comment */ }"),
+      "class A {\n\nfoo() {}\n\n/* comment */ }"
+    );
+  }
+}
@@ -0,0 +1,260 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BinaryOp, Expr, ExprOrSuper, Lit, MemberExpr, UnaryOp,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::utils::drop_span;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PreferAt;
+
+const CODE: &str = "prefer-at";
+const FIX_DESC: &str = "Replace with `.at()`";
+
+#[derive(Display)]
+enum PreferAtMessage {
+  #[display(fmt = "Use `.at(-{})` to access elements from the end", _0)]
+  PreferAt(String),
+}
+
+#[derive(Display)]
+enum PreferAtHint {
+  #[display(fmt = "Replace with `{}`", _0)]
+  Replace(String),
+}
+
+impl LintRule for PreferAt {
+  fn new() -> Arc<Self> {
+    Arc::new(PreferAt)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferAtHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_at.md")
+  }
+}
+
+/// Returns whether evaluating the expression twice yields the same value
+/// without side effects, e.g. `arr`, `this.items` or `a.b[0]`.
+fn is_reference(expr: &Expr) -> bool {
+  match unwrap_paren(expr) {
+    Expr::Ident(_) | Expr::This(_) => true,
+    Expr::Member(member) => {
+      matches!(&member.obj, ExprOrSuper::Expr(obj) if is_reference(obj))
+        && (!member.computed
+          || matches!(&*member.prop, Expr::Lit(_))
+          || is_reference(&member.prop))
+    }
+    _ => false,
+  }
+}
+
+/// Returns the object of a `<obj>.length` expression.
+fn length_object(expr: &Expr) -> Option<&Expr> {
+  match unwrap_paren(expr) {
+    Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      prop,
+      computed: false,
+      ..
+    }) if matches!(&**prop, Expr::Ident(ident) if ident.sym == *"length") => {
+      Some(obj)
+    }
+    _ => None,
+  }
+}
+
+/// Returns the offset `N` in an `<obj>.length - N` index whose object is the
+/// same as `obj`.
+fn offset_from_end<'a>(obj: &Expr, index: &'a Expr) -> Option<&'a Expr> {
+  let bin = match unwrap_paren(index) {
+    Expr::Bin(bin) if bin.op == BinaryOp::Sub => bin,
+    _ => return None,
+  };
+  let offset = &*bin.right;
+  let is_positive_integer = matches!(
+    offset,
+    Expr::Lit(Lit::Num(n)) if n.value >= 1.0 && n.value.fract() == 0.0
+  );
+  if !is_positive_integer {
+    return None;
+  }
+  let length_obj = length_object(&bin.left)?;
+  if drop_span(unwrap_paren(length_obj).clone())
+    == drop_span(unwrap_paren(obj).clone())
+  {
+    Some(offset)
+  } else {
+    None
+  }
+}
+
+/// Returns whether the member expression is written to, where `.at()` can't
+/// be used, e.g. `arr[arr.length - 1] = x`.
+fn is_written(member: Node, span: Span) -> bool {
+  match member.parent() {
+    Some(Node::AssignExpr(assign)) => assign.inner.left.span() == span,
+    Some(Node::KeyValuePatProp(prop)) => prop.inner.value.span() == span,
+    Some(Node::AssignPat(pat)) => pat.inner.left.span() == span,
+    Some(Node::ForOfStmt(stmt)) => stmt.inner.left.span() == span,
+    Some(Node::ForInStmt(stmt)) => stmt.inner.left.span() == span,
+    Some(Node::UpdateExpr(_) | Node::ArrayPat(_) | Node::RestPat(_)) => true,
+    Some(Node::UnaryExpr(unary)) => unary.inner.op == UnaryOp::Delete,
+    _ => false,
+  }
+}
+
+struct PreferAtHandler;
+
+impl Handler for PreferAtHandler {
+  fn member_expr(
+    &mut self,
+    member_expr: &ast_view::MemberExpr,
+    ctx: &mut Context,
+  ) {
+    let member = member_expr.inner;
+    if !member.computed {
+      return;
+    }
+    let obj = match &member.obj {
+      ExprOrSuper::Expr(obj) if is_reference(obj) => &**obj,
+      _ => return,
+    };
+    let offset = match offset_from_end(obj, &member.prop) {
+      Some(offset) => offset,
+      None => return,
+    };
+    if is_written(member_expr.as_node(), member.span) {
+      return;
+    }
+
+    let offset = ctx.file_text_substring(&offset.span()).to_string();
+    let replacement =
+      format!("{}.at(-{})", ctx.file_text_substring(&obj.span()), offset);
+    let fix = LintFix {
+      description: FIX_DESC.to_string(),
+      changes: vec![ctx.fix_change(member.span, &replacement)],
+      applicability: None,
+    };
+
+    ctx.add_diagnostic_with_fixes(
+      member.span,
+      CODE,
+      PreferAtMessage::PreferAt(offset),
+      Some(PreferAtHint::Replace(replacement).to_string()),
+      vec![fix],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_at_valid() {
+    assert_lint_ok! {
+      PreferAt,
+      "arr.at(-1);",
+      "arr[0];",
+      "arr[arr.length];",
+      "arr[arr.length + 1];",
+      "arr[arr.length - i];",
+      "arr[arr.length - 0];",
+      "arr[arr.length - 1.5];",
+      "arr[other.length - 1];",
+      "arr.length - 1;",
+      "arr[arr.size - 1];",
+      "arr[arr['length'] - 1];",
+      "foo()[foo().length - 1];",
+      "a.b[a.c.length - 1];",
+      "arr[arr.length - 1] = 0;",
+      "arr[arr.length - 1] += 1;",
+      "arr[arr.length - 1]++;",
+      "delete arr[arr.length - 1];",
+      "[arr[arr.length - 1]] = [0];",
+      "({ a: arr[arr.length - 1] } = obj);",
+      "[...arr[arr.length - 1]] = [0];",
+      "for (arr[arr.length - 1] of items) {}",
+    };
+  }
+
+  #[test]
+  fn prefer_at_invalid() {
+    assert_lint_err! {
+      PreferAt,
+      "const last = arr[arr.length - 1];": [
+        {
+          col: 13,
+          message: PreferAtMessage::PreferAt("1".to_string()),
+          hint: PreferAtHint::Replace("arr.at(-1)".to_string()),
+          fix: (FIX_DESC, "const last = arr.at(-1);"),
+        }
+      ],
+      "str[str.length - 2];": [
+        {
+          col: 0,
+          message: PreferAtMessage::PreferAt("2".to_string()),
+          hint: PreferAtHint::Replace("str.at(-2)".to_string()),
+          fix: (FIX_DESC, "str.at(-2);"),
+        }
+      ],
+      "this.items[this.items.length - 1].name;": [
+        {
+          col: 0,
+          message: PreferAtMessage::PreferAt("1".to_string()),
+          hint: PreferAtHint::Replace("this.items.at(-1)".to_string()),
+          fix: (FIX_DESC, "this.items.at(-1).name;"),
+        }
+      ],
+      "a.b[(a.b).length - 3];": [
+        {
+          col: 0,
+          message: PreferAtMessage::PreferAt("3".to_string()),
+          hint: PreferAtHint::Replace("a.b.at(-3)".to_string()),
+          fix: (FIX_DESC, "a.b.at(-3);"),
+        }
+      ],
+      "x = arr[arr.length - 1];": [
+        {
+          col: 4,
+          message: PreferAtMessage::PreferAt("1".to_string()),
+          hint: PreferAtHint::Replace("arr.at(-1)".to_string()),
+          fix: (FIX_DESC, "x = arr.at(-1);"),
+        }
+      ],
+      "rows[i][rows[i].length - 1];": [
+        {
+          col: 0,
+          message: PreferAtMessage::PreferAt("1".to_string()),
+          hint: PreferAtHint::Replace("rows[i].at(-1)".to_string()),
+          fix: (FIX_DESC, "rows[i].at(-1);"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,241 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, Lit, TsEnumMemberId};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view::{self as ast_view, Node};
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PreferConstAssertionOverEnum;
+
+const CODE: &str = "prefer-const-assertion-over-enum";
+const FIX_DESC: &str = "Convert to an object literal with `as const`";
+
+#[derive(Display)]
+enum PreferConstAssertionOverEnumMessage {
+  #[display(fmt = "The string enum `{}` can be an object literal", _0)]
+  StringEnum(String),
+}
+
+#[derive(Display)]
+enum PreferConstAssertionOverEnumHint {
+  #[display(
+    fmt = "Use an object literal with `as const`, and `typeof {0}[keyof typeof {0}]` for the type of its values",
+    _0
+  )]
+  UseConstAssertion(String),
+}
+
+impl LintRule for PreferConstAssertionOverEnum {
+  fn new() -> Arc<Self> {
+    Arc::new(PreferConstAssertionOverEnum)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["enum"]
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferConstAssertionOverEnumHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_const_assertion_over_enum.md")
+  }
+}
+
+/// Returns the indentation of the line `node` starts on.
+fn indentation(ctx: &Context, node: &ast_view::TsEnumDecl) -> String {
+  let file_start = ctx.source_file().span().lo();
+  let text = ctx.source_file().text();
+  let start = (node.span().lo() - file_start).0 as usize;
+  let line_start = text[..start].rfind('\n').map_or(0, |index| index + 1);
+  text[line_start..start]
+    .chars()
+    .take_while(|c| *c == ' ' || *c == '\t')
+    .collect()
+}
+
+struct PreferConstAssertionOverEnumHandler;
+
+impl Handler for PreferConstAssertionOverEnumHandler {
+  fn ts_enum_decl(
+    &mut self,
+    enum_decl: &ast_view::TsEnumDecl,
+    ctx: &mut Context,
+  ) {
+    let inner = enum_decl.inner;
+    // Declared enums have no values to put in an object literal.
+    if inner.declare || inner.members.is_empty() {
+      return;
+    }
+    let mut properties = Vec::new();
+    for member in &inner.members {
+      let value = match member.init.as_deref() {
+        Some(Expr::Lit(Lit::Str(value))) => value,
+        _ => return,
+      };
+      let key = match &member.id {
+        TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
+        TsEnumMemberId::Str(str) => {
+          ctx.file_text_substring(&str.span).to_string()
+        }
+      };
+      properties.push(format!(
+        "{}: {}",
+        key,
+        ctx.file_text_substring(&value.span)
+      ));
+    }
+
+    let span = enum_decl.span();
+    let name = inner.id.sym.to_string();
+    let message = PreferConstAssertionOverEnumMessage::StringEnum(name.clone());
+    let hint =
+      PreferConstAssertionOverEnumHint::UseConstAssertion(name.clone())
+        .to_string();
+    // The comments within the enum would be lost.
+    if ctx.comments_within(span).next().is_some() {
+      ctx.add_diagnostic_with_hint(span, CODE, message, hint);
+      return;
+    }
+
+    let indentation = indentation(ctx, enum_decl);
+    let object = if ctx.file_text_substring(&span).contains('\n') {
+      let properties: String = properties
+        .iter()
+        .map(|property| format!("{}  {},\n", indentation, property))
+        .collect();
+      format!("{{\n{}{}}}", properties, indentation)
+    } else {
+      format!("{{ {} }}", properties.join(", "))
+    };
+    let export = match enum_decl.parent() {
+      Node::ExportDecl(_) => "export ",
+      _ => "",
+    };
+    let new_text = format!(
+      "const {0} = {1} as const;\n{2}{3}type {0} = typeof {0}[keyof typeof {0}];",
+      name, object, indentation, export
+    );
+    ctx.add_diagnostic_with_fixes(
+      span,
+      CODE,
+      message,
+      Some(hint),
+      vec![LintFix {
+        description: FIX_DESC.to_string(),
+        changes: vec![ctx.fix_change(span, new_text)],
+        applicability: None,
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_const_assertion_over_enum_valid() {
+    assert_lint_ok! {
+      PreferConstAssertionOverEnum,
+      "enum E {}",
+      "enum E { A, B }",
+      "enum E { A = 1, B = 2 }",
+      "enum E { A = 'a', B }",
+      "enum E { A = 'a', B = 1 }",
+      "enum E { A = 'a', B = `b` }",
+      "enum E { A = 'a', B = A }",
+      "declare enum E { A = 'a', B = 'b' }",
+      "const E = { A: 'a', B: 'b' } as const;",
+    };
+  }
+
+  #[test]
+  fn prefer_const_assertion_over_enum_invalid() {
+    assert_lint_err! {
+      PreferConstAssertionOverEnum,
+      "enum E { A = 'a', B = 'b' }": [
+        {
+          col: 0,
+          message: PreferConstAssertionOverEnumMessage::StringEnum("E".to_string()),
+          hint: PreferConstAssertionOverEnumHint::UseConstAssertion("E".to_string()),
+          fix: (
+            FIX_DESC,
+            "const E = { A: 'a', B: 'b' } as const;\ntype E = typeof E[keyof typeof E];",
+          ),
+        }
+      ],
+      "export enum Direction { Up = \"UP\", \"Down-Left\" = \"DOWN_LEFT\" }": [
+        {
+          col: 7,
+          message: PreferConstAssertionOverEnumMessage::StringEnum("Direction".to_string()),
+          hint: PreferConstAssertionOverEnumHint::UseConstAssertion("Direction".to_string()),
+          fix: (
+            FIX_DESC,
+            "export const Direction = { Up: \"UP\", \"Down-Left\": \"DOWN_LEFT\" } as const;\nexport type Direction = typeof Direction[keyof typeof Direction];",
+          ),
+        }
+      ],
+      "const enum E { A = 'a' }": [
+        {
+          col: 0,
+          message: PreferConstAssertionOverEnumMessage::StringEnum("E".to_string()),
+          hint: PreferConstAssertionOverEnumHint::UseConstAssertion("E".to_string()),
+          fix: (
+            FIX_DESC,
+            "const E = { A: 'a' } as const;\ntype E = typeof E[keyof typeof E];",
+          ),
+        }
+      ],
+      r#"namespace N {
+  enum Color {
+    Red = "red",
+    Green = "green",
+  }
+}"#: [
+        {
+          line: 2,
+          col: 2,
+          message: PreferConstAssertionOverEnumMessage::StringEnum("Color".to_string()),
+          hint: PreferConstAssertionOverEnumHint::UseConstAssertion("Color".to_string()),
+          fix: (
+            FIX_DESC,
+            r#"namespace N {
+  const Color = {
+    Red: "red",
+    Green: "green",
+  } as const;
+  type Color = typeof Color[keyof typeof Color];
+}"#,
+          ),
+        }
+      ],
+      "enum E {\n  // The first\n  A = 'a',\n}": [
+        {
+          col: 0,
+          message: PreferConstAssertionOverEnumMessage::StringEnum("E".to_string()),
+          hint: PreferConstAssertionOverEnumHint::UseConstAssertion("E".to_string()),
+        }
+      ],
+    };
+  }
+}
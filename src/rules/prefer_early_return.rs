@@ -0,0 +1,229 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{BlockStmt, BlockStmtOrExpr, Stmt};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PreferEarlyReturn {
+  options: PreferEarlyReturnOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PreferEarlyReturnOptions {
+  /// The maximum number of statements the `if` may wrap before an early return
+  /// is preferred.
+  pub maximum_statements: usize,
+}
+
+impl Default for PreferEarlyReturnOptions {
+  fn default() -> Self {
+    Self {
+      maximum_statements: 1,
+    }
+  }
+}
+
+impl PreferEarlyReturn {
+  pub fn with_options(options: PreferEarlyReturnOptions) -> Arc<Self> {
+    Arc::new(PreferEarlyReturn { options })
+  }
+}
+
+const CODE: &str = "prefer-early-return";
+const MESSAGE: &str = "The function body is wrapped in a single `if` statement";
+const HINT: &str =
+  "Invert the condition and return early to reduce the nesting";
+
+impl LintRule for PreferEarlyReturn {
+  fn new() -> Arc<Self> {
+    Self::with_options(PreferEarlyReturnOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "maximumStatements": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "The maximum number of statements the `if` may wrap before an early return is preferred.",
+          "default": 1
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(PreferEarlyReturnOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferEarlyReturnHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_early_return.md")
+  }
+}
+
+struct PreferEarlyReturnHandler<'a> {
+  options: &'a PreferEarlyReturnOptions,
+}
+
+impl<'a> PreferEarlyReturnHandler<'a> {
+  fn check_body(&self, ctx: &mut Context, body: &BlockStmt) {
+    let if_stmt = match body.stmts.as_slice() {
+      [Stmt::If(if_stmt)] if if_stmt.alt.is_none() => if_stmt,
+      _ => return,
+    };
+    let statements = match &*if_stmt.cons {
+      Stmt::Block(block) => block.stmts.len(),
+      _ => 1,
+    };
+    if statements > self.options.maximum_statements {
+      ctx.add_diagnostic_with_hint(if_stmt.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+impl<'a> Handler for PreferEarlyReturnHandler<'a> {
+  fn function(&mut self, function: &ast_view::Function, ctx: &mut Context) {
+    if let Some(body) = &function.inner.body {
+      self.check_body(ctx, body);
+    }
+  }
+
+  fn arrow_expr(
+    &mut self,
+    arrow_expr: &ast_view::ArrowExpr,
+    ctx: &mut Context,
+  ) {
+    if let BlockStmtOrExpr::BlockStmt(body) = &arrow_expr.inner.body {
+      self.check_body(ctx, body);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_early_return_valid() {
+    assert_lint_ok! {
+      PreferEarlyReturn,
+      "function foo() { if (a) { b(); } }",
+      "function foo() { if (a) b(); }",
+      "function foo() { if (!a) { return; } b(); c(); }",
+      "function foo() { if (a) { b(); c(); } else { d(); } }",
+      "function foo() { if (a) { b(); c(); } d(); }",
+      "function foo() { const x = 1; if (a) { b(); c(); } }",
+      "function foo() {}",
+      "const foo = () => a ? b() : c();",
+      "if (a) { b(); c(); }",
+      "for (const a of b) { if (a) { c(); d(); } }",
+    };
+
+    assert_lint_ok! {
+      PreferEarlyReturn,
+      options: PreferEarlyReturnOptions { maximum_statements: 2 },
+      "function foo() { if (a) { b(); c(); } }",
+    };
+  }
+
+  #[test]
+  fn prefer_early_return_invalid() {
+    assert_lint_err! {
+      PreferEarlyReturn,
+      "function foo() { if (a) { b(); c(); } }": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const foo = function() { if (a) { b(); c(); } };": [
+        {
+          col: 25,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const foo = () => { if (a) { b(); c(); } };": [
+        {
+          col: 20,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "class A { foo() { if (a) { b(); c(); } } }": [
+        {
+          col: 18,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "function foo() { if (a) { b(); if (c) { d(); } } }": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PreferEarlyReturn,
+      options: PreferEarlyReturnOptions { maximum_statements: 0 },
+      "function foo() { if (a) { b(); } }": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "function foo() { if (a) b(); }": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
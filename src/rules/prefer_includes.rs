@@ -0,0 +1,312 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BinaryOp, CallExpr, Expr, ExprOrSpread, ExprOrSuper, Lit, UnaryOp,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PreferIncludes;
+
+const CODE: &str = "prefer-includes";
+const FIX_DESC: &str = "Replace with `includes()`";
+
+#[derive(Display)]
+enum PreferIncludesMessage {
+  #[display(
+    fmt = "Use `includes()` instead of comparing the result of `indexOf()`"
+  )]
+  PreferIncludes,
+}
+
+#[derive(Display)]
+enum PreferIncludesHint {
+  #[display(fmt = "Replace with `{}`", _0)]
+  Replace(String),
+}
+
+impl LintRule for PreferIncludes {
+  fn new() -> Arc<Self> {
+    Arc::new(PreferIncludes)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferIncludesHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_includes.md")
+  }
+}
+
+/// Returns the object and the arguments of an `indexOf()` call, e.g. `arr`
+/// and `[x]` for `arr.indexOf(x)`.
+fn index_of_call(expr: &Expr) -> Option<(&Expr, &[ExprOrSpread])> {
+  let CallExpr { callee, args, .. } = match unwrap_paren(expr) {
+    Expr::Call(call_expr) => call_expr,
+    _ => return None,
+  };
+  let member_expr = match callee {
+    ExprOrSuper::Expr(callee) => match &**callee {
+      Expr::Member(member_expr) if !member_expr.computed => member_expr,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  let obj = match &member_expr.obj {
+    ExprOrSuper::Expr(obj) => &**obj,
+    ExprOrSuper::Super(_) => return None,
+  };
+  let is_index_of =
+    matches!(&*member_expr.prop, Expr::Ident(ident) if ident.sym == *"indexOf");
+  if is_index_of
+    && !args.is_empty()
+    && args.len() <= 2
+    && args.iter().all(|arg| arg.spread.is_none())
+  {
+    Some((obj, args))
+  } else {
+    None
+  }
+}
+
+/// Returns the number if the expression is `-1` or `0`.
+fn comparison_constant(expr: &Expr) -> Option<i8> {
+  match unwrap_paren(expr) {
+    Expr::Lit(Lit::Num(n)) if n.value == 0.0 => Some(0),
+    Expr::Unary(unary) if unary.op == UnaryOp::Minus => match &*unary.arg {
+      Expr::Lit(Lit::Num(n)) if n.value == 1.0 => Some(-1),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Returns the operator of the same comparison with the operands swapped, e.g.
+/// `<` for `>`.
+fn flip(op: BinaryOp) -> BinaryOp {
+  match op {
+    BinaryOp::Lt => BinaryOp::Gt,
+    BinaryOp::LtEq => BinaryOp::GtEq,
+    BinaryOp::Gt => BinaryOp::Lt,
+    BinaryOp::GtEq => BinaryOp::LtEq,
+    op => op,
+  }
+}
+
+/// Returns whether `indexOf(...) <op> <constant>` checks that the element is
+/// included (`Some(true)`) or not included (`Some(false)`).
+fn checks_inclusion(op: BinaryOp, constant: i8) -> Option<bool> {
+  use BinaryOp::*;
+  match (op, constant) {
+    (NotEqEq | NotEq | Gt, -1) | (GtEq, 0) => Some(true),
+    (EqEqEq | EqEq | LtEq, -1) | (Lt, 0) => Some(false),
+    _ => None,
+  }
+}
+
+struct PreferIncludesHandler;
+
+impl Handler for PreferIncludesHandler {
+  fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
+    let bin = bin_expr.inner;
+    let (call, constant, op) =
+      if let Some(constant) = comparison_constant(&bin.right) {
+        (&*bin.left, constant, bin.op)
+      } else if let Some(constant) = comparison_constant(&bin.left) {
+        (&*bin.right, constant, flip(bin.op))
+      } else {
+        return;
+      };
+    let ((obj, args), included) =
+      match (index_of_call(call), checks_inclusion(op, constant)) {
+        (Some(index_of), Some(included)) => (index_of, included),
+        _ => return,
+      };
+
+    let args_span = Span::new(
+      args[0].span().lo(),
+      args[args.len() - 1].span().hi(),
+      Default::default(),
+    );
+    let replacement = format!(
+      "{}{}.includes({})",
+      if included { "" } else { "!" },
+      ctx.file_text_substring(&obj.span()),
+      ctx.file_text_substring(&args_span),
+    );
+    let fix = LintFix {
+      description: FIX_DESC.to_string(),
+      changes: vec![ctx.fix_change(bin.span, &replacement)],
+      applicability: None,
+    };
+
+    ctx.add_diagnostic_with_fixes(
+      bin.span,
+      CODE,
+      PreferIncludesMessage::PreferIncludes,
+      Some(PreferIncludesHint::Replace(replacement).to_string()),
+      vec![fix],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/typescript-eslint/typescript-eslint/blob/v5.40.0/packages/eslint-plugin/tests/rules/prefer-includes.test.ts
+  // MIT Licensed.
+
+  #[test]
+  fn prefer_includes_valid() {
+    assert_lint_ok! {
+      PreferIncludes,
+      "a.indexOf(b);",
+      "a.indexOf(b) !== 0;",
+      "a.indexOf(b) === 0;",
+      "a.indexOf(b) > 0;",
+      "a.indexOf(b) >= -1;",
+      "a.indexOf(b) < -1;",
+      "a.indexOf(b) + 0 !== -1;",
+      "a.indexOf() !== -1;",
+      "a.indexOf(...b) !== -1;",
+      "a[indexOf](b) !== -1;",
+      "a.lastIndexOf(b) !== -1;",
+      "indexOf(b) !== -1;",
+      "a.includes(b);",
+    };
+  }
+
+  #[test]
+  fn prefer_includes_invalid() {
+    assert_lint_err! {
+      PreferIncludes,
+      "a.indexOf(b) !== -1;": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("a.includes(b)".to_string()),
+          fix: (FIX_DESC, "a.includes(b);"),
+        }
+      ],
+      "a.indexOf(b) != -1;": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("a.includes(b)".to_string()),
+          fix: (FIX_DESC, "a.includes(b);"),
+        }
+      ],
+      "a.indexOf(b) > -1;": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("a.includes(b)".to_string()),
+          fix: (FIX_DESC, "a.includes(b);"),
+        }
+      ],
+      "a.indexOf(b) >= 0;": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("a.includes(b)".to_string()),
+          fix: (FIX_DESC, "a.includes(b);"),
+        }
+      ],
+      "a.indexOf(b) === -1;": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("!a.includes(b)".to_string()),
+          fix: (FIX_DESC, "!a.includes(b);"),
+        }
+      ],
+      "a.indexOf(b) == -1;": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("!a.includes(b)".to_string()),
+          fix: (FIX_DESC, "!a.includes(b);"),
+        }
+      ],
+      "a.indexOf(b) <= -1;": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("!a.includes(b)".to_string()),
+          fix: (FIX_DESC, "!a.includes(b);"),
+        }
+      ],
+      "a.indexOf(b) < 0;": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("!a.includes(b)".to_string()),
+          fix: (FIX_DESC, "!a.includes(b);"),
+        }
+      ],
+      "-1 !== a.indexOf(b);": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("a.includes(b)".to_string()),
+          fix: (FIX_DESC, "a.includes(b);"),
+        }
+      ],
+      "0 > a.indexOf(b);": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("!a.includes(b)".to_string()),
+          fix: (FIX_DESC, "!a.includes(b);"),
+        }
+      ],
+      "if (str.indexOf('foo', 1) !== -1) {}": [
+        {
+          col: 4,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("str.includes('foo', 1)".to_string()),
+          fix: (FIX_DESC, "if (str.includes('foo', 1)) {}"),
+        }
+      ],
+      "const x = (a || b).indexOf(c) === -1 && y;": [
+        {
+          col: 10,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("!(a || b).includes(c)".to_string()),
+          fix: (FIX_DESC, "const x = !(a || b).includes(c) && y;"),
+        }
+      ],
+      "foo.bar().indexOf(x) !== -1;": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::PreferIncludes,
+          hint: PreferIncludesHint::Replace("foo.bar().includes(x)".to_string()),
+          fix: (FIX_DESC, "foo.bar().includes(x);"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,300 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{BinaryOp, Expr, Lit, UnaryOp};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PreferLiteralEnumMember {
+  options: PreferLiteralEnumMemberOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PreferLiteralEnumMemberOptions {
+  /// Allow bitwise expressions of literals and other members, e.g.
+  /// `C = A | B`.
+  pub allow_bitwise_expressions: bool,
+}
+
+impl PreferLiteralEnumMember {
+  pub fn with_options(options: PreferLiteralEnumMemberOptions) -> Arc<Self> {
+    Arc::new(PreferLiteralEnumMember { options })
+  }
+}
+
+const CODE: &str = "prefer-literal-enum-member";
+
+#[derive(Display)]
+enum PreferLiteralEnumMemberMessage {
+  #[display(fmt = "Explicit enum value must only be a literal value")]
+  NotLiteral,
+}
+
+#[derive(Display)]
+enum PreferLiteralEnumMemberHint {
+  #[display(
+    fmt = "Initialize the member with a string or number literal instead"
+  )]
+  UseLiteral,
+}
+
+impl LintRule for PreferLiteralEnumMember {
+  fn new() -> Arc<Self> {
+    Self::with_options(PreferLiteralEnumMemberOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowBitwiseExpressions": {
+          "type": "boolean",
+          "description": "Allow bitwise expressions of literals and other members.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(PreferLiteralEnumMemberOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferLiteralEnumMemberHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_literal_enum_member.md")
+  }
+}
+
+fn is_literal(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Str(_)) | Expr::Lit(Lit::Num(_)) => true,
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    Expr::Unary(unary) => {
+      matches!(unary.op, UnaryOp::Minus | UnaryOp::Plus)
+        && matches!(&*unary.arg, Expr::Lit(Lit::Num(_)))
+    }
+    Expr::Paren(paren) => is_literal(&paren.expr),
+    _ => false,
+  }
+}
+
+/// Returns whether the expression only consists of bitwise operations on
+/// number literals and other members of the enum, e.g. `A | B` or `1 << 2`.
+fn is_bitwise_expression(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Num(_)) | Expr::Ident(_) => true,
+    Expr::Unary(unary) => {
+      unary.op == UnaryOp::Tilde && is_bitwise_expression(&unary.arg)
+    }
+    Expr::Bin(bin) => {
+      matches!(
+        bin.op,
+        BinaryOp::BitOr
+          | BinaryOp::BitAnd
+          | BinaryOp::BitXor
+          | BinaryOp::LShift
+          | BinaryOp::RShift
+          | BinaryOp::ZeroFillRShift
+      ) && is_bitwise_expression(&bin.left)
+        && is_bitwise_expression(&bin.right)
+    }
+    Expr::Paren(paren) => is_bitwise_expression(&paren.expr),
+    _ => false,
+  }
+}
+
+struct PreferLiteralEnumMemberHandler<'a> {
+  options: &'a PreferLiteralEnumMemberOptions,
+}
+
+impl<'a> Handler for PreferLiteralEnumMemberHandler<'a> {
+  fn ts_enum_member(
+    &mut self,
+    member: &ast_view::TsEnumMember,
+    ctx: &mut Context,
+  ) {
+    let init = match &member.inner.init {
+      Some(init) => &**init,
+      None => return,
+    };
+    if is_literal(init)
+      || (self.options.allow_bitwise_expressions
+        && !matches!(init, Expr::Ident(_))
+        && is_bitwise_expression(init))
+    {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(
+      member.span(),
+      CODE,
+      PreferLiteralEnumMemberMessage::NotLiteral,
+      PreferLiteralEnumMemberHint::UseLiteral,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/typescript-eslint/typescript-eslint/blob/v4.31.0/packages/eslint-plugin/tests/rules/prefer-literal-enum-member.test.ts
+  // MIT Licensed.
+
+  #[test]
+  fn prefer_literal_enum_member_valid() {
+    assert_lint_ok! {
+      PreferLiteralEnumMember,
+      "enum ValidString { A = 'TestStr' }",
+      "enum ValidNumber { A = 42 }",
+      "enum ValidNumber { A = -42 }",
+      "enum ValidNumber { A = +42 }",
+      "enum ValidTemplate { A = `TestStr` }",
+      "enum ValidKey { 'a-b' = 1 }",
+      "enum ValidNone { A, B }",
+      "enum ValidParen { A = (1) }",
+      "declare enum ValidDeclare { A = 1 }",
+    };
+
+    assert_lint_ok! {
+      PreferLiteralEnumMember,
+      options: PreferLiteralEnumMemberOptions {
+        allow_bitwise_expressions: true,
+      },
+      "enum Foo { A = 1 << 0, B = 1 << 1, C = A | B, D = ~A, E = A & B ^ 1 }",
+      "enum Foo { A = 1, B = 2, C = (A | B) >> 1, D = C >>> 1 }",
+    };
+  }
+
+  #[test]
+  fn prefer_literal_enum_member_invalid() {
+    assert_lint_err! {
+      PreferLiteralEnumMember,
+      "enum InvalidObject { A = {} }": [
+        {
+          col: 21,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        }
+      ],
+      "enum InvalidArray { A = [] }": [
+        {
+          col: 20,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        }
+      ],
+      "enum InvalidTemplate { A = `foo ${0}` }": [
+        {
+          col: 23,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        }
+      ],
+      "enum InvalidConstructor { A = new Set() }": [
+        {
+          col: 26,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        }
+      ],
+      "enum InvalidCall { A = foo() }": [
+        {
+          col: 19,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        }
+      ],
+      "enum InvalidExpression { A = 2 + 2 }": [
+        {
+          col: 25,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        }
+      ],
+      "const x = 1; enum InvalidVariable { A = x }": [
+        {
+          col: 36,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        }
+      ],
+      "enum Foo { A = 1 << 0, B = A | 2 }": [
+        {
+          col: 11,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        },
+        {
+          col: 23,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PreferLiteralEnumMember,
+      options: PreferLiteralEnumMemberOptions {
+        allow_bitwise_expressions: true,
+      },
+      "enum Foo { A = 1, B = A + 1, C = foo() | 1, D = x }": [
+        {
+          col: 18,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        },
+        {
+          col: 29,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        },
+        {
+          col: 44,
+          message: PreferLiteralEnumMemberMessage::NotLiteral,
+          hint: PreferLiteralEnumMemberHint::UseLiteral,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,211 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::extract_regex;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view::{
+  CallExpr, Expr, ExprOrSpread, ExprOrSuper, Ident, Lit, NewExpr, Regex,
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PreferNamedCaptureGroup;
+
+const CODE: &str = "prefer-named-capture-group";
+const MESSAGE: &str = "Capture groups in regular expressions should be named";
+const HINT: &str = "Use a named group `(?<name>...)` instead, or a non-capturing group `(?:...)` if the group doesn't need to be captured";
+
+impl LintRule for PreferNamedCaptureGroup {
+  fn new() -> Arc<Self> {
+    Arc::new(PreferNamedCaptureGroup)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    PreferNamedCaptureGroupHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_named_capture_group.md")
+  }
+}
+
+/// Checks if `pattern` has a capturing group that isn't named, skipping
+/// escaped parentheses, character classes, non-capturing groups and
+/// lookarounds.
+fn has_unnamed_capture_group(pattern: &str) -> bool {
+  let chars: Vec<char> = pattern.chars().collect();
+  let mut in_class = false;
+  let mut escaped = false;
+
+  for (i, &c) in chars.iter().enumerate() {
+    if escaped {
+      escaped = false;
+    } else if c == '\\' {
+      escaped = true;
+    } else if c == '[' {
+      in_class = true;
+    } else if c == ']' {
+      in_class = false;
+    } else if c == '(' && !in_class && chars.get(i + 1) != Some(&'?') {
+      return true;
+    }
+  }
+  false
+}
+
+fn check_regex(pattern: &str, span: Span, ctx: &mut Context) {
+  if has_unnamed_capture_group(pattern) {
+    ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+  }
+}
+
+/// Returns the pattern of `RegExp("...")` calls. A regex literal passed to
+/// `RegExp` is left to the `regex` handler.
+fn constructor_pattern(
+  ctx: &Context,
+  ident: &Ident,
+  args: &[&ExprOrSpread],
+) -> Option<String> {
+  match args.first() {
+    Some(arg) if matches!(arg.expr, Expr::Lit(Lit::Str(_))) => {
+      extract_regex(ctx.scope(), ident, args)
+    }
+    _ => None,
+  }
+}
+
+struct PreferNamedCaptureGroupHandler;
+
+impl Handler for PreferNamedCaptureGroupHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    check_regex(&regex.inner.exp, regex.span(), ctx);
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Expr::Ident(ident) = new_expr.callee {
+      if let Some(args) = &new_expr.args {
+        if let Some(pattern) = constructor_pattern(ctx, ident, args) {
+          check_regex(&pattern, new_expr.span(), ctx);
+        }
+      }
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let ExprOrSuper::Expr(Expr::Ident(ident)) = &call_expr.callee {
+      if let Some(pattern) = constructor_pattern(ctx, ident, &call_expr.args) {
+        check_regex(&pattern, call_expr.span(), ctx);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/prefer-named-capture-group.js
+  // MIT Licensed.
+
+  #[test]
+  fn prefer_named_capture_group_valid() {
+    assert_lint_ok! {
+      PreferNamedCaptureGroup,
+      "/normal_regex/",
+      "/(?:[0-9]{4})/",
+      "/(?<year>[0-9]{4})/",
+      "/(?=a)(?!b)(?<=c)(?<!d)/",
+      r"/\u{1F680}/u",
+      r"/\(foo\)/",
+      "/[(]foo[)]/",
+      r"/[\]( ]/",
+      "new RegExp()",
+      "new RegExp('')",
+      "new RegExp('(?<year>[0-9]{4})')",
+      "new RegExp('(?:[0-9]{4})')",
+      "RegExp('(?<year>[0-9]{4})')",
+      "new RegExp(pattern)",
+      "new foo.RegExp('([0-9]{4})')",
+      "var RegExp = function() {}; new RegExp('([0-9]{4})');",
+      "function foo(RegExp) { RegExp('([0-9]{4})'); }",
+    };
+  }
+
+  #[test]
+  fn prefer_named_capture_group_invalid() {
+    assert_lint_err! {
+      PreferNamedCaptureGroup,
+      "/([0-9]{4})/": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const regex = /(?<year>[0-9]{4})-([0-9]{2})/;": [
+        {
+          col: 14,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "/(?:a)|(b)/": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r"/\(foo\)(bar)/": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "/[(]()/": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "new RegExp('([0-9]{4})')": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "RegExp('([0-9]{4})')": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "new RegExp(/([0-9]{4})/)": [
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
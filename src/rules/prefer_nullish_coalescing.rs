@@ -0,0 +1,390 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{BinExpr, BinaryOp, Expr, Lit, UnaryOp};
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::view::{self as ast_view, NodeTrait};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PreferNullishCoalescing {
+  options: PreferNullishCoalescingOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PreferNullishCoalescingOptions {
+  /// Don't report defaulting to a primitive literal, e.g. `count || 0` or
+  /// `name || ""`.
+  pub ignore_primitives: bool,
+  /// Attach a fix replacing `||` with `??` to each diagnostic. The fix is a
+  /// suggestion only, since it changes behavior for falsy values such as `0`
+  /// and `""`.
+  pub suggest_fix: bool,
+}
+
+impl PreferNullishCoalescing {
+  pub fn with_options(options: PreferNullishCoalescingOptions) -> Arc<Self> {
+    Arc::new(PreferNullishCoalescing { options })
+  }
+}
+
+const CODE: &str = "prefer-nullish-coalescing";
+
+#[derive(Display)]
+enum PreferNullishCoalescingMessage {
+  #[display(
+    fmt = "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`) for default values"
+  )]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum PreferNullishCoalescingHint {
+  #[display(
+    fmt = "`||` also falls back for falsy values like `0` and `\"\"`; use `??` if only `null` and `undefined` should be replaced"
+  )]
+  UseNullishCoalescing,
+}
+
+const FIX_DESC: &str = "Replace `||` with `??`";
+
+impl LintRule for PreferNullishCoalescing {
+  fn new() -> Arc<Self> {
+    Self::with_options(PreferNullishCoalescingOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Unsafe
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "ignorePrimitives": {
+          "type": "boolean",
+          "description": "Don't report defaulting to a primitive literal.",
+          "default": false
+        },
+        "suggestFix": {
+          "type": "boolean",
+          "description": "Attach a fix replacing `||` with `??` to each diagnostic.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(PreferNullishCoalescingOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferNullishCoalescingHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_nullish_coalescing.md")
+  }
+}
+
+struct PreferNullishCoalescingHandler<'a> {
+  options: &'a PreferNullishCoalescingOptions,
+}
+
+impl<'a> Handler for PreferNullishCoalescingHandler<'a> {
+  fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
+    if bin_expr.op() != BinaryOp::LogicalOr {
+      return;
+    }
+
+    // `a || b || c` is reported once, at the outermost expression
+    if let ast_view::Node::BinExpr(parent) = bin_expr.parent() {
+      if parent.op() == BinaryOp::LogicalOr
+        && parent.left.span() == bin_expr.span()
+      {
+        return;
+      }
+    }
+
+    if in_test_position(bin_expr.as_node()) {
+      return;
+    }
+
+    let operands = flatten_or_chain(bin_expr.inner);
+    if operands.iter().any(|e| is_boolean_like(e)) {
+      return;
+    }
+
+    if self.options.ignore_primitives
+      && matches!(operands.last(), Some(e) if is_primitive_literal(e))
+    {
+      return;
+    }
+
+    let fixes = if self.options.suggest_fix {
+      vec![nullish_coalescing_fix(ctx, &operands)]
+    } else {
+      vec![]
+    };
+
+    ctx.add_diagnostic_with_fixes(
+      bin_expr.span(),
+      CODE,
+      PreferNullishCoalescingMessage::Unexpected,
+      Some(PreferNullishCoalescingHint::UseNullishCoalescing.to_string()),
+      fixes,
+    );
+  }
+}
+
+/// Checks if the value of the expression is only used as a condition, e.g.
+/// `if (a || b)` or `!(a || b)`. `||` is the right operator in such places.
+fn in_test_position(node: ast_view::Node) -> bool {
+  use deno_ast::view::Node;
+
+  let mut current = node;
+  while let Some(parent) = current.parent() {
+    let is_test_of = |test: Option<Span>| test == Some(current.span());
+    match parent {
+      Node::ParenExpr(_) => {}
+      Node::BinExpr(bin)
+        if matches!(
+          bin.op(),
+          BinaryOp::LogicalAnd
+            | BinaryOp::LogicalOr
+            | BinaryOp::NullishCoalescing
+        ) => {}
+      Node::UnaryExpr(unary) => return unary.op() == UnaryOp::Bang,
+      Node::IfStmt(s) => return is_test_of(Some(s.test.span())),
+      Node::WhileStmt(s) => return is_test_of(Some(s.test.span())),
+      Node::DoWhileStmt(s) => return is_test_of(Some(s.test.span())),
+      Node::ForStmt(s) => return is_test_of(s.test.map(|t| t.span())),
+      Node::CondExpr(c) => return is_test_of(Some(c.test.span())),
+      _ => return false,
+    }
+    current = parent;
+  }
+  false
+}
+
+/// Collects the operands of a left-associative `||` chain in source order.
+fn flatten_or_chain(bin_expr: &BinExpr) -> Vec<&Expr> {
+  let mut operands = vec![&*bin_expr.right];
+  let mut left = &*bin_expr.left;
+  while let Expr::Bin(BinExpr {
+    op: BinaryOp::LogicalOr,
+    left: l,
+    right: r,
+    ..
+  }) = left
+  {
+    operands.push(&**r);
+    left = &**l;
+  }
+  operands.push(left);
+  operands.reverse();
+  operands
+}
+
+/// Checks if the expression evaluates to a boolean, in which case the `||`
+/// is part of boolean logic rather than a default value.
+fn is_boolean_like(expr: &Expr) -> bool {
+  use BinaryOp::*;
+  match expr {
+    Expr::Lit(Lit::Bool(_)) => true,
+    Expr::Unary(unary) => unary.op == UnaryOp::Bang,
+    Expr::Bin(bin) => matches!(
+      bin.op,
+      EqEq | NotEq | EqEqEq | NotEqEq | Lt | LtEq | Gt | GtEq | In | InstanceOf
+    ),
+    Expr::Paren(paren) => is_boolean_like(&paren.expr),
+    _ => false,
+  }
+}
+
+fn is_primitive_literal(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(lit) => {
+      matches!(
+        lit,
+        Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::BigInt(_)
+      )
+    }
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    _ => false,
+  }
+}
+
+/// Replaces every `||` of the chain with `??`. Since `??` can't be mixed with
+/// `&&` or `||` without parentheses, such operands get parenthesized.
+fn nullish_coalescing_fix(ctx: &Context, operands: &[&Expr]) -> LintFix {
+  let mut changes = Vec::new();
+
+  for pair in operands.windows(2) {
+    let (left, right) = (pair[0], pair[1]);
+    let between =
+      Span::new(left.span().hi(), right.span().lo(), Default::default());
+    if let Some(offset) = ctx.file_text_substring(&between).find("||") {
+      let lo = between.lo() + BytePos(offset as u32);
+      let op_span = Span::new(lo, lo + BytePos(2), Default::default());
+      changes.push(ctx.fix_change(op_span, "??"));
+    }
+  }
+
+  for operand in operands {
+    if matches!(operand, Expr::Bin(bin) if matches!(bin.op, BinaryOp::LogicalAnd | BinaryOp::LogicalOr))
+    {
+      changes.push(parenthesize(ctx, operand.span(), true));
+      changes.push(parenthesize(ctx, operand.span(), false));
+    }
+  }
+  changes.sort_by_key(|c| c.range.start.byte_pos);
+
+  LintFix {
+    description: FIX_DESC.to_string(),
+    changes,
+    applicability: None,
+  }
+}
+
+fn parenthesize(ctx: &Context, span: Span, open: bool) -> LintFixChange {
+  if open {
+    ctx.fix_change(span.shrink_to_lo(), "(")
+  } else {
+    ctx.fix_change(span.shrink_to_hi(), ")")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_nullish_coalescing_valid() {
+    assert_lint_ok! {
+      PreferNullishCoalescing,
+      "const a = b ?? c;",
+      "if (a || b) {}",
+      "if ((a || b) && c) {}",
+      "while (a || b) {}",
+      "do {} while (a || b)",
+      "for (;a || b;) {}",
+      "const x = a || b ? c : d;",
+      "const x = !(a || b);",
+      "const x = a === 1 || b;",
+      "const x = a || b > 2;",
+      "const x = a || false;",
+      "const x = !a || b;",
+      "const x = a && b;",
+    };
+
+    assert_lint_ok! {
+      PreferNullishCoalescing,
+      options: PreferNullishCoalescingOptions {
+        ignore_primitives: true,
+        ..Default::default()
+      },
+      "const x = count || 0;",
+      "const x = name || '';",
+      "const x = name || `default`;",
+    };
+  }
+
+  #[test]
+  fn prefer_nullish_coalescing_invalid() {
+    assert_lint_err! {
+      PreferNullishCoalescing,
+      PreferNullishCoalescingMessage::Unexpected,
+      PreferNullishCoalescingHint::UseNullishCoalescing,
+      "const x = a || b;": [{ col: 10 }],
+      "const x = a || b || c;": [{ col: 10 }],
+      "foo(a || 'default');": [{ col: 4 }],
+      "function f() { return a.b || {}; }": [{ col: 22 }],
+      "const x = cond ? a || b : c;": [{ col: 17 }],
+      "const x = count || 0;": [{ col: 10 }],
+      "const x = c && (a || b);": [{ col: 16 }],
+    };
+  }
+
+  #[test]
+  fn prefer_nullish_coalescing_suggest_fix() {
+    assert_lint_err! {
+      PreferNullishCoalescing,
+      options: PreferNullishCoalescingOptions {
+        suggest_fix: true,
+        ..Default::default()
+      },
+      PreferNullishCoalescingMessage::Unexpected,
+      PreferNullishCoalescingHint::UseNullishCoalescing,
+      "const x = a || b;": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "const x = a ?? b;"),
+        }
+      ],
+      "const x = a || b || c;": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "const x = a ?? b ?? c;"),
+        }
+      ],
+      "const x = a || b && c;": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "const x = a ?? (b && c);"),
+        }
+      ],
+      "const x = a && b || c;": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "const x = (a && b) ?? c;"),
+        }
+      ],
+      "const x = (a || b) || c;": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "const x = (a || b) ?? c;"),
+        },
+        {
+          col: 11,
+          fix: (FIX_DESC, "const x = (a ?? b) || c;"),
+        }
+      ],
+    };
+  }
+}
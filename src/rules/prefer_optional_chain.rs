@@ -0,0 +1,354 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::{Applicability, LintFix};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{BinExpr, BinaryOp, Expr, ExprOrSuper};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PreferOptionalChain;
+
+const CODE: &str = "prefer-optional-chain";
+
+#[derive(Display)]
+enum PreferOptionalChainMessage {
+  #[display(
+    fmt = "Prefer using an optional chain expression instead of chained `&&` checks"
+  )]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum PreferOptionalChainHint {
+  #[display(fmt = "Consider rewriting this as `{}`", _0)]
+  RewriteAs(String),
+  #[display(fmt = "Consider rewriting this using the `?.` operator")]
+  UseOptionalChain,
+}
+
+const FIX_DESC: &str = "Convert to an optional chain";
+
+impl LintRule for PreferOptionalChain {
+  fn new() -> Arc<Self> {
+    Arc::new(PreferOptionalChain)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fix_applicability(&self) -> Applicability {
+    Applicability::Unsafe
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferOptionalChainHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_optional_chain.md")
+  }
+}
+
+struct PreferOptionalChainHandler;
+
+impl Handler for PreferOptionalChainHandler {
+  fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
+    if bin_expr.op() != BinaryOp::LogicalAnd {
+      return;
+    }
+
+    // Only look at the outermost `&&` of a chain; `a && b && c` is parsed as
+    // `(a && b) && c`, so the inner expression is the left operand of its
+    // parent.
+    if let ast_view::Node::BinExpr(parent) = bin_expr.parent() {
+      if parent.op() == BinaryOp::LogicalAnd
+        && parent.left.span() == bin_expr.span()
+      {
+        return;
+      }
+    }
+
+    let operands = flatten_and_chain(bin_expr.inner);
+
+    let mut i = 0;
+    while i < operands.len() {
+      let mut j = i;
+      while j + 1 < operands.len()
+        && is_plain_member_chain(operands[j])
+        && extends(ctx, operands[j], operands[j + 1])
+      {
+        j += 1;
+      }
+
+      if j > i {
+        report(ctx, &operands[i..=j]);
+      }
+      i = j + 1;
+    }
+  }
+}
+
+fn report(ctx: &mut Context, run: &[&Expr]) {
+  let first = run.first().unwrap();
+  let last = run.last().unwrap();
+  let span = Span::new(first.span().lo(), last.span().hi(), Default::default());
+
+  match build_optional_chain(ctx, run) {
+    Some(optional_chain) => {
+      let fix = LintFix {
+        description: FIX_DESC.to_string(),
+        changes: vec![ctx.fix_change(span, &optional_chain)],
+        applicability: None,
+      };
+      ctx.add_diagnostic_with_fixes(
+        span,
+        CODE,
+        PreferOptionalChainMessage::Unexpected,
+        Some(PreferOptionalChainHint::RewriteAs(optional_chain).to_string()),
+        vec![fix],
+      );
+    }
+    None => ctx.add_diagnostic_with_hint(
+      span,
+      CODE,
+      PreferOptionalChainMessage::Unexpected,
+      PreferOptionalChainHint::UseOptionalChain,
+    ),
+  }
+}
+
+/// Collects the operands of a left-associative `&&` chain in source order.
+fn flatten_and_chain(bin_expr: &BinExpr) -> Vec<&Expr> {
+  let mut operands = vec![&*bin_expr.right];
+  let mut left = &*bin_expr.left;
+  loop {
+    match left {
+      Expr::Bin(BinExpr {
+        op: BinaryOp::LogicalAnd,
+        left: l,
+        right: r,
+        ..
+      }) => {
+        operands.push(&**r);
+        left = &**l;
+      }
+      _ => {
+        operands.push(left);
+        break;
+      }
+    }
+  }
+  operands.reverse();
+  operands
+}
+
+/// Checks if the expression consists only of identifiers and property
+/// accesses, e.g. `foo.bar["baz"]`. Expressions containing calls are excluded
+/// because turning `foo() && foo().bar` into `foo()?.bar` changes how many
+/// times `foo` is called.
+fn is_plain_member_chain(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) | Expr::This(_) => true,
+    Expr::Member(member) => {
+      let plain_obj = match &member.obj {
+        ExprOrSuper::Expr(obj) => is_plain_member_chain(obj),
+        ExprOrSuper::Super(_) => true,
+      };
+      let plain_prop = !member.computed
+        || matches!(&*member.prop, Expr::Lit(_) | Expr::Ident(_));
+      plain_obj && plain_prop
+    }
+    Expr::OptChain(opt_chain) => is_plain_member_chain(&opt_chain.expr),
+    _ => false,
+  }
+}
+
+/// Returns the expression that `expr` accesses a property of or calls, e.g.
+/// `foo.bar` for `foo.bar.baz` and `foo.bar()`.
+fn object_of(expr: &Expr) -> Option<&Expr> {
+  match expr {
+    Expr::Member(member) => match &member.obj {
+      ExprOrSuper::Expr(obj) => Some(&**obj),
+      ExprOrSuper::Super(_) => None,
+    },
+    Expr::Call(call) => match &call.callee {
+      ExprOrSuper::Expr(callee) => Some(&**callee),
+      ExprOrSuper::Super(_) => None,
+    },
+    Expr::OptChain(opt_chain) => object_of(&opt_chain.expr),
+    _ => None,
+  }
+}
+
+/// Checks if `next` accesses properties of, or calls, the very same expression
+/// as `prev`, e.g. `prev` is `foo.bar` and `next` is `foo.bar.baz`.
+fn extends(ctx: &Context, prev: &Expr, next: &Expr) -> bool {
+  let prev_text = ctx.file_text_substring(&prev.span());
+  let mut current = next;
+  while let Some(obj) = object_of(current) {
+    if ctx.file_text_substring(&obj.span()) == prev_text {
+      return true;
+    }
+    current = obj;
+  }
+  false
+}
+
+/// Builds the text of the optional chain equivalent to the `&&` chain, e.g.
+/// `foo?.bar?.baz` for `foo && foo.bar && foo.bar.baz`. Returns `None` if the
+/// operands are formatted in a way that can't be rewritten reliably.
+fn build_optional_chain(ctx: &Context, run: &[&Expr]) -> Option<String> {
+  let last_text = ctx.file_text_substring(&run.last()?.span());
+  let mut result = String::new();
+  let mut last_pos = 0;
+
+  for operand in &run[..run.len() - 1] {
+    let pos = ctx.file_text_substring(&operand.span()).len();
+    let rest = last_text.get(pos..)?;
+    result.push_str(&last_text[last_pos..pos]);
+    if rest.starts_with("?.") {
+      // already optional
+      last_pos = pos;
+    } else if rest.starts_with('.') {
+      result.push_str("?.");
+      last_pos = pos + 1;
+    } else if rest.starts_with('[') || rest.starts_with('(') {
+      result.push_str("?.");
+      last_pos = pos;
+    } else {
+      return None;
+    }
+  }
+
+  result.push_str(&last_text[last_pos..]);
+  Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_optional_chain_valid() {
+    assert_lint_ok! {
+      PreferOptionalChain,
+      "foo && bar",
+      "foo && bar.baz",
+      "foo.bar && foo.baz",
+      "foo && foo",
+      "foo || foo.bar",
+      "foo && fooBar.baz",
+      "foo?.bar",
+      "foo() && foo().bar",
+      "foo[bar()] && foo[bar()].baz",
+      "(foo && bar) && baz.qux",
+      "foo && foo.bar === 1",
+    };
+  }
+
+  #[test]
+  fn prefer_optional_chain_invalid() {
+    assert_lint_err! {
+      PreferOptionalChain,
+      "foo && foo.bar": [
+        {
+          col: 0,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::RewriteAs("foo?.bar".to_string()),
+          fix: (FIX_DESC, "foo?.bar"),
+        }
+      ],
+      "foo && foo.bar && foo.bar.baz": [
+        {
+          col: 0,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::RewriteAs("foo?.bar?.baz".to_string()),
+          fix: (FIX_DESC, "foo?.bar?.baz"),
+        }
+      ],
+      "foo && foo.bar.baz": [
+        {
+          col: 0,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::RewriteAs("foo?.bar.baz".to_string()),
+          fix: (FIX_DESC, "foo?.bar.baz"),
+        }
+      ],
+      "foo && foo[bar] && foo[bar].baz": [
+        {
+          col: 0,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::RewriteAs("foo?.[bar]?.baz".to_string()),
+          fix: (FIX_DESC, "foo?.[bar]?.baz"),
+        }
+      ],
+      "foo && foo.bar && foo.bar()": [
+        {
+          col: 0,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::RewriteAs("foo?.bar?.()".to_string()),
+          fix: (FIX_DESC, "foo?.bar?.()"),
+        }
+      ],
+      "foo?.bar && foo?.bar.baz": [
+        {
+          col: 0,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::RewriteAs("foo?.bar?.baz".to_string()),
+          fix: (FIX_DESC, "foo?.bar?.baz"),
+        }
+      ],
+      "this.foo && this.foo.bar": [
+        {
+          col: 0,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::RewriteAs("this.foo?.bar".to_string()),
+          fix: (FIX_DESC, "this.foo?.bar"),
+        }
+      ],
+      "if (x && foo && foo.bar) {}": [
+        {
+          col: 9,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::RewriteAs("foo?.bar".to_string()),
+          fix: (FIX_DESC, "if (x && foo?.bar) {}"),
+        }
+      ],
+      "foo && foo.bar && baz && baz.qux": [
+        {
+          col: 0,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::RewriteAs("foo?.bar".to_string()),
+          fix: (FIX_DESC, "foo?.bar && baz && baz.qux"),
+        },
+        {
+          col: 18,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::RewriteAs("baz?.qux".to_string()),
+          fix: (FIX_DESC, "foo && foo.bar && baz?.qux"),
+        }
+      ],
+      "foo && foo\n  .bar": [
+        {
+          col: 0,
+          message: PreferOptionalChainMessage::Unexpected,
+          hint: PreferOptionalChainHint::UseOptionalChain,
+        }
+      ],
+    };
+  }
+}
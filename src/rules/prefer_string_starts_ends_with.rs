@@ -0,0 +1,495 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_paren;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BinaryOp, CallExpr, Expr, ExprOrSpread, ExprOrSuper, Lit, MemberExpr,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PreferStringStartsEndsWith;
+
+const CODE: &str = "prefer-string-starts-ends-with";
+
+#[derive(Display)]
+enum PreferStringStartsEndsWithMessage {
+  #[display(fmt = "Use `startsWith()` to check the start of a string")]
+  StartsWith,
+  #[display(fmt = "Use `endsWith()` to check the end of a string")]
+  EndsWith,
+}
+
+#[derive(Display)]
+enum PreferStringStartsEndsWithHint {
+  #[display(fmt = "Replace with `{}`", _0)]
+  Replace(String),
+}
+
+#[derive(Display)]
+enum PreferStringStartsEndsWithFix {
+  #[display(fmt = "Replace with `{}()`", _0)]
+  Replace(Method),
+}
+
+impl LintRule for PreferStringStartsEndsWith {
+  fn new() -> Arc<Self> {
+    Arc::new(PreferStringStartsEndsWith)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferStringStartsEndsWithHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_string_starts_ends_with.md")
+  }
+}
+
+#[derive(Clone, Copy, Display)]
+enum Method {
+  #[display(fmt = "startsWith")]
+  StartsWith,
+  #[display(fmt = "endsWith")]
+  EndsWith,
+}
+
+/// A check that can be replaced with `startsWith()` or `endsWith()`.
+struct Suggestion {
+  method: Method,
+  /// The source text of the string being checked.
+  string: String,
+  /// The source text of the argument of `startsWith()` or `endsWith()`.
+  arg: String,
+  negated: bool,
+  /// Whether the replacement is known to behave the same, so it can be
+  /// offered as a fix.
+  fixable: bool,
+}
+
+impl Suggestion {
+  fn replacement(&self) -> String {
+    format!(
+      "{}{}.{}({})",
+      if self.negated { "!" } else { "" },
+      self.string,
+      self.method,
+      self.arg
+    )
+  }
+}
+
+/// Returns the source text of the expression, parenthesized if it would
+/// otherwise be the operand of a member access with lower precedence.
+fn operand_text(ctx: &Context, expr: &Expr) -> String {
+  let text = ctx.file_text_substring(&expr.span());
+  match expr {
+    Expr::Ident(_)
+    | Expr::This(_)
+    | Expr::Member(_)
+    | Expr::Call(_)
+    | Expr::Lit(_)
+    | Expr::Tpl(_)
+    | Expr::TaggedTpl(_)
+    | Expr::Array(_)
+    | Expr::Paren(_) => text.to_string(),
+    _ => format!("({})", text),
+  }
+}
+
+/// Returns the object, the method name and the argument of a method call with
+/// a single argument, e.g. `str`, `indexOf` and `x` for `str.indexOf(x)`.
+fn method_call(call_expr: &CallExpr) -> Option<(&Expr, &str, &Expr)> {
+  let member_expr = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => match unwrap_paren(callee) {
+      Expr::Member(member_expr) if !member_expr.computed => member_expr,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  let obj = match &member_expr.obj {
+    ExprOrSuper::Expr(obj) => &**obj,
+    ExprOrSuper::Super(_) => return None,
+  };
+  match (&*member_expr.prop, call_expr.args.as_slice()) {
+    (Expr::Ident(prop), [ExprOrSpread { spread: None, expr }]) => {
+      Some((obj, prop.sym.as_ref(), &**expr))
+    }
+    _ => None,
+  }
+}
+
+fn is_number(expr: &Expr, value: f64) -> bool {
+  matches!(unwrap_paren(expr), Expr::Lit(Lit::Num(n)) if n.value == value)
+}
+
+fn is_single_char_string(expr: &Expr) -> bool {
+  matches!(
+    unwrap_paren(expr),
+    Expr::Lit(Lit::Str(s)) if s.value.chars().count() == 1
+  )
+}
+
+/// Returns whether the expression is `<string>.length - 1`.
+fn is_last_index(ctx: &Context, expr: &Expr, string: &Expr) -> bool {
+  let bin = match unwrap_paren(expr) {
+    Expr::Bin(bin) if bin.op == BinaryOp::Sub => bin,
+    _ => return false,
+  };
+  let obj = match unwrap_paren(&bin.left) {
+    Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      prop,
+      computed: false,
+      ..
+    }) if matches!(&**prop, Expr::Ident(ident) if ident.sym == *"length") => {
+      obj
+    }
+    _ => return false,
+  };
+  is_number(&bin.right, 1.0)
+    && ctx.file_text_substring(&obj.span())
+      == ctx.file_text_substring(&string.span())
+}
+
+/// Matches `str.indexOf(x) === 0`, `str.charAt(0) === "a"` and
+/// `str[0] === "a"`, as well as their equivalents checking the last
+/// character, where `expr` is the left-hand side and `other` the right-hand
+/// side of the comparison.
+fn match_comparison(
+  ctx: &Context,
+  expr: &Expr,
+  other: &Expr,
+  negated: bool,
+) -> Option<Suggestion> {
+  let call = match unwrap_paren(expr) {
+    Expr::Call(call_expr) => method_call(call_expr),
+    _ => None,
+  };
+  let (string, method, arg) = if let Some((obj, name, index)) = call {
+    match name {
+      "indexOf" if is_number(other, 0.0) => (obj, Method::StartsWith, index),
+      "charAt" if is_single_char_string(other) && is_number(index, 0.0) => {
+        (obj, Method::StartsWith, other)
+      }
+      "charAt"
+        if is_single_char_string(other) && is_last_index(ctx, index, obj) =>
+      {
+        (obj, Method::EndsWith, other)
+      }
+      _ => return None,
+    }
+  } else {
+    let member_expr = match unwrap_paren(expr) {
+      Expr::Member(member_expr) if member_expr.computed => member_expr,
+      _ => return None,
+    };
+    let obj = match &member_expr.obj {
+      ExprOrSuper::Expr(obj) => &**obj,
+      ExprOrSuper::Super(_) => return None,
+    };
+    if !is_single_char_string(other) {
+      return None;
+    }
+    if is_number(&member_expr.prop, 0.0) {
+      (obj, Method::StartsWith, other)
+    } else if is_last_index(ctx, &member_expr.prop, obj) {
+      (obj, Method::EndsWith, other)
+    } else {
+      return None;
+    }
+  };
+
+  Some(Suggestion {
+    method,
+    string: operand_text(ctx, string),
+    arg: ctx.file_text_substring(&arg.span()).to_string(),
+    negated,
+    fixable: matches!(unwrap_paren(arg), Expr::Lit(Lit::Str(_))),
+  })
+}
+
+/// Converts the pattern of a regular expression into the string it matches,
+/// if it only consists of literal characters.
+fn regex_literal_text(pattern: &str) -> Option<String> {
+  let mut text = String::new();
+  let mut chars = pattern.chars();
+  while let Some(c) = chars.next() {
+    match c {
+      '\\' => match chars.next() {
+        Some(escaped) if escaped.is_ascii_punctuation() => text.push(escaped),
+        _ => return None,
+      },
+      '^' | '$' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}'
+      | '|' => return None,
+      _ => text.push(c),
+    }
+  }
+  if text.is_empty() {
+    None
+  } else {
+    Some(text)
+  }
+}
+
+/// Matches `/^abc/.test(str)` and `/abc$/.test(str)`.
+fn match_regex_test(ctx: &Context, call_expr: &CallExpr) -> Option<Suggestion> {
+  let (regex, string) = match method_call(call_expr)? {
+    (obj, "test", string) => match unwrap_paren(obj) {
+      Expr::Lit(Lit::Regex(regex)) => (regex, string),
+      _ => return None,
+    },
+    _ => return None,
+  };
+  if !matches!(regex.flags.as_ref(), "" | "u") {
+    return None;
+  }
+
+  let pattern: &str = regex.exp.as_ref();
+  let (method, text) = if let Some(rest) = pattern.strip_prefix('^') {
+    (Method::StartsWith, regex_literal_text(rest)?)
+  } else if let Some(rest) = pattern.strip_suffix('$') {
+    (Method::EndsWith, regex_literal_text(rest)?)
+  } else {
+    return None;
+  };
+
+  Some(Suggestion {
+    method,
+    string: operand_text(ctx, string),
+    arg: format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"")),
+    negated: false,
+    fixable: true,
+  })
+}
+
+fn report(ctx: &mut Context, span: Span, suggestion: Suggestion) {
+  let replacement = suggestion.replacement();
+  let fixes = if suggestion.fixable {
+    vec![LintFix {
+      description: PreferStringStartsEndsWithFix::Replace(suggestion.method)
+        .to_string(),
+      changes: vec![ctx.fix_change(span, &replacement)],
+      applicability: None,
+    }]
+  } else {
+    Vec::new()
+  };
+  let message = match suggestion.method {
+    Method::StartsWith => PreferStringStartsEndsWithMessage::StartsWith,
+    Method::EndsWith => PreferStringStartsEndsWithMessage::EndsWith,
+  };
+
+  ctx.add_diagnostic_with_fixes(
+    span,
+    CODE,
+    message,
+    Some(PreferStringStartsEndsWithHint::Replace(replacement).to_string()),
+    fixes,
+  );
+}
+
+struct PreferStringStartsEndsWithHandler;
+
+impl Handler for PreferStringStartsEndsWithHandler {
+  fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
+    let bin = bin_expr.inner;
+    let negated = match bin.op {
+      BinaryOp::EqEqEq | BinaryOp::EqEq => false,
+      BinaryOp::NotEqEq | BinaryOp::NotEq => true,
+      _ => return,
+    };
+    let suggestion = match_comparison(ctx, &bin.left, &bin.right, negated)
+      .or_else(|| match_comparison(ctx, &bin.right, &bin.left, negated));
+    if let Some(suggestion) = suggestion {
+      report(ctx, bin.span, suggestion);
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    if let Some(suggestion) = match_regex_test(ctx, call_expr.inner) {
+      report(ctx, call_expr.inner.span, suggestion);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/typescript-eslint/typescript-eslint/blob/v5.40.0/packages/eslint-plugin/tests/rules/prefer-string-starts-ends-with.test.ts
+  // MIT Licensed.
+
+  #[test]
+  fn prefer_string_starts_ends_with_valid() {
+    assert_lint_ok! {
+      PreferStringStartsEndsWith,
+      "s.startsWith('a');",
+      "s.endsWith('a');",
+      "s.indexOf('a') === 1;",
+      "s.indexOf('a') !== -1;",
+      "s.charAt(1) === 'a';",
+      "s.charAt(0) === 'ab';",
+      "s.charAt(0) === c;",
+      "s[0] === c;",
+      "s[1] === 'a';",
+      "s[0] === 'ab';",
+      "s[t.length - 1] === 'a';",
+      "s.charAt(t.length - 1) === 'a';",
+      "s[0] < 'a';",
+      "/^a/i.test(s);",
+      "/^a/g.test(s);",
+      "/a/.test(s);",
+      "/^a$/.test(s);",
+      "/^a+/.test(s);",
+      r"/^\d/.test(s);",
+      "/^/.test(s);",
+      "/^a/.exec(s);",
+      "/^a/.test(s, t);",
+      "re.test(s);",
+    };
+  }
+
+  #[test]
+  fn prefer_string_starts_ends_with_invalid() {
+    assert_lint_err! {
+      PreferStringStartsEndsWith,
+      "s.indexOf('bar') === 0;": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::Replace("s.startsWith('bar')".to_string()),
+          fix: (
+            PreferStringStartsEndsWithFix::Replace(Method::StartsWith),
+            "s.startsWith('bar');"
+          ),
+        }
+      ],
+      "s.indexOf('bar') != 0;": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::Replace("!s.startsWith('bar')".to_string()),
+          fix: (
+            PreferStringStartsEndsWithFix::Replace(Method::StartsWith),
+            "!s.startsWith('bar');"
+          ),
+        }
+      ],
+      "0 === s.indexOf(bar);": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::Replace("s.startsWith(bar)".to_string()),
+        }
+      ],
+      "s.charAt(0) === 'a';": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::Replace("s.startsWith('a')".to_string()),
+          fix: (
+            PreferStringStartsEndsWithFix::Replace(Method::StartsWith),
+            "s.startsWith('a');"
+          ),
+        }
+      ],
+      "s.charAt(s.length - 1) === 'a';": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::EndsWith,
+          hint: PreferStringStartsEndsWithHint::Replace("s.endsWith('a')".to_string()),
+          fix: (
+            PreferStringStartsEndsWithFix::Replace(Method::EndsWith),
+            "s.endsWith('a');"
+          ),
+        }
+      ],
+      "if (s[0] !== 'a') {}": [
+        {
+          col: 4,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::Replace("!s.startsWith('a')".to_string()),
+          fix: (
+            PreferStringStartsEndsWithFix::Replace(Method::StartsWith),
+            "if (!s.startsWith('a')) {}"
+          ),
+        }
+      ],
+      "foo.bar[foo.bar.length - 1] === 'a';": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::EndsWith,
+          hint: PreferStringStartsEndsWithHint::Replace("foo.bar.endsWith('a')".to_string()),
+          fix: (
+            PreferStringStartsEndsWithFix::Replace(Method::EndsWith),
+            "foo.bar.endsWith('a');"
+          ),
+        }
+      ],
+      "'a' === s[0];": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::Replace("s.startsWith('a')".to_string()),
+          fix: (
+            PreferStringStartsEndsWithFix::Replace(Method::StartsWith),
+            "s.startsWith('a');"
+          ),
+        }
+      ],
+      "/^bar/.test(s);": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::Replace(r#"s.startsWith("bar")"#.to_string()),
+          fix: (
+            PreferStringStartsEndsWithFix::Replace(Method::StartsWith),
+            r#"s.startsWith("bar");"#
+          ),
+        }
+      ],
+      r#"/\.ts$/.test(path);"#: [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::EndsWith,
+          hint: PreferStringStartsEndsWithHint::Replace(r#"path.endsWith(".ts")"#.to_string()),
+          fix: (
+            PreferStringStartsEndsWithFix::Replace(Method::EndsWith),
+            r#"path.endsWith(".ts");"#
+          ),
+        }
+      ],
+      r#"/^"\\/u.test(a + b);"#: [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::Replace(r#"(a + b).startsWith("\"\\")"#.to_string()),
+          fix: (
+            PreferStringStartsEndsWithFix::Replace(Method::StartsWith),
+            r#"(a + b).startsWith("\"\\");"#
+          ),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,291 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, Lit, MemberExpr, Pat, PatOrExpr};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PreferTextContentOverInnerHtml {
+  options: PreferTextContentOverInnerHtmlOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PreferTextContentOverInnerHtmlOptions {
+  /// Whether assignments of static strings are reported as well.
+  pub check_literals: bool,
+}
+
+impl PreferTextContentOverInnerHtml {
+  pub fn with_options(
+    options: PreferTextContentOverInnerHtmlOptions,
+  ) -> Arc<Self> {
+    Arc::new(PreferTextContentOverInnerHtml { options })
+  }
+}
+
+const CODE: &str = "prefer-text-content-over-inner-html";
+
+#[derive(Display)]
+enum PreferTextContentOverInnerHtmlMessage {
+  #[display(
+    fmt = "Assigning a dynamic value to `{}` can lead to cross-site scripting",
+    _0
+  )]
+  Dynamic(&'static str),
+  #[display(fmt = "Assigning to `{}` parses the value as HTML", _0)]
+  Literal(&'static str),
+}
+
+#[derive(Display)]
+enum PreferTextContentOverInnerHtmlHint {
+  #[display(
+    fmt = "Use `textContent` for plain text, or sanitize the value before assigning it"
+  )]
+  UseTextContent,
+}
+
+impl LintRule for PreferTextContentOverInnerHtml {
+  fn new() -> Arc<Self> {
+    Self::with_options(PreferTextContentOverInnerHtmlOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "checkLiterals": {
+          "type": "boolean",
+          "description": "Whether assignments of static strings are reported as well.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(PreferTextContentOverInnerHtmlOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferTextContentOverInnerHtmlHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_text_content_over_inner_html.md")
+  }
+}
+
+const HTML_PROPERTIES: &[&str] = &["innerHTML", "outerHTML"];
+
+/// Returns the property if the member expression accesses `innerHTML` or
+/// `outerHTML`, e.g. `el.innerHTML` or `el["outerHTML"]`.
+fn html_property(member_expr: &MemberExpr) -> Option<&'static str> {
+  let name: &str = match (&*member_expr.prop, member_expr.computed) {
+    (Expr::Ident(ident), false) => &ident.sym,
+    (Expr::Lit(Lit::Str(str)), true) => &str.value,
+    _ => return None,
+  };
+  HTML_PROPERTIES
+    .iter()
+    .copied()
+    .find(|property| *property == name)
+}
+
+/// Returns whether the value is a string known at compile time, e.g. `"<b>"`
+/// or `` `<br>` ``.
+fn is_static_string(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Str(_)) => true,
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    Expr::Paren(paren) => is_static_string(&paren.expr),
+    _ => false,
+  }
+}
+
+struct PreferTextContentOverInnerHtmlHandler<'a> {
+  options: &'a PreferTextContentOverInnerHtmlOptions,
+}
+
+impl<'a> Handler for PreferTextContentOverInnerHtmlHandler<'a> {
+  fn assign_expr(
+    &mut self,
+    assign_expr: &ast_view::AssignExpr,
+    ctx: &mut Context,
+  ) {
+    let target = match &assign_expr.inner.left {
+      PatOrExpr::Expr(expr) => &**expr,
+      PatOrExpr::Pat(pat) => match &**pat {
+        Pat::Expr(expr) => &**expr,
+        _ => return,
+      },
+    };
+    let property = match target {
+      Expr::Member(member_expr) => match html_property(member_expr) {
+        Some(property) => property,
+        None => return,
+      },
+      _ => return,
+    };
+
+    let message = if is_static_string(&assign_expr.inner.right) {
+      if !self.options.check_literals {
+        return;
+      }
+      PreferTextContentOverInnerHtmlMessage::Literal(property)
+    } else {
+      PreferTextContentOverInnerHtmlMessage::Dynamic(property)
+    };
+    ctx.add_diagnostic_with_hint(
+      assign_expr.span(),
+      CODE,
+      message,
+      PreferTextContentOverInnerHtmlHint::UseTextContent,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_text_content_over_inner_html_valid() {
+    assert_lint_ok! {
+      PreferTextContentOverInnerHtml,
+      "el.textContent = name;",
+      "el.innerHTML = '';",
+      "el.innerHTML = '<b>Hello</b>';",
+      "el.outerHTML = \"<hr>\";",
+      "el.innerHTML = `<br>`;",
+      "el.innerHTML += '<li></li>';",
+      "const html = el.innerHTML;",
+      "el[innerHTML] = value;",
+      "innerHTML = value;",
+      "el.innerText = value;",
+      "el.dataset.innerHTML = 'x';",
+    };
+  }
+
+  #[test]
+  fn prefer_text_content_over_inner_html_invalid() {
+    assert_lint_err! {
+      PreferTextContentOverInnerHtml,
+      "el.innerHTML = name;": [
+        {
+          col: 0,
+          message: PreferTextContentOverInnerHtmlMessage::Dynamic("innerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        }
+      ],
+      "el.outerHTML = `<p>${text}</p>`;": [
+        {
+          col: 0,
+          message: PreferTextContentOverInnerHtmlMessage::Dynamic("outerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        }
+      ],
+      "document.body['innerHTML'] = '<p>' + text + '</p>';": [
+        {
+          col: 0,
+          message: PreferTextContentOverInnerHtmlMessage::Dynamic("innerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        }
+      ],
+      "list.innerHTML += item;": [
+        {
+          col: 0,
+          message: PreferTextContentOverInnerHtmlMessage::Dynamic("innerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        }
+      ],
+      "el.inner\\u0048TML = name;": [
+        {
+          col: 0,
+          message: PreferTextContentOverInnerHtmlMessage::Dynamic("innerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        }
+      ],
+      "function render(el, data) {\n  el.innerHTML = data.html;\n}": [
+        {
+          line: 2,
+          col: 2,
+          message: PreferTextContentOverInnerHtmlMessage::Dynamic("innerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        }
+      ],
+      "a.innerHTML = b.innerHTML = html;": [
+        {
+          col: 0,
+          message: PreferTextContentOverInnerHtmlMessage::Dynamic("innerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        },
+        {
+          col: 14,
+          message: PreferTextContentOverInnerHtmlMessage::Dynamic("innerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PreferTextContentOverInnerHtml,
+      options: PreferTextContentOverInnerHtmlOptions { check_literals: true },
+      "el.innerHTML = '<b>Hello</b>';": [
+        {
+          col: 0,
+          message: PreferTextContentOverInnerHtmlMessage::Literal("innerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        }
+      ],
+      "el.outerHTML = `<hr>`;": [
+        {
+          col: 0,
+          message: PreferTextContentOverInnerHtmlMessage::Literal("outerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        }
+      ],
+      "el.innerHTML = html;": [
+        {
+          col: 0,
+          message: PreferTextContentOverInnerHtmlMessage::Dynamic("innerHTML"),
+          hint: PreferTextContentOverInnerHtmlHint::UseTextContent,
+        }
+      ],
+    };
+  }
+}
@@ -51,6 +51,10 @@ impl LintRule for RequireAwait {
     CODE
   }
 
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["async"]
+  }
+
   fn lint_program<'view>(
     &self,
     context: &mut Context<'view>,
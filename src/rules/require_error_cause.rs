@@ -0,0 +1,353 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  Expr, ExprOrSpread, NewExpr, Pat, Prop, PropName, PropOrSpread,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct RequireErrorCause {
+  options: RequireErrorCauseOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct RequireErrorCauseOptions {
+  /// Whether errors thrown in functions nested in the `catch` block are
+  /// ignored.
+  pub ignore_nested_functions: bool,
+}
+
+impl RequireErrorCause {
+  pub fn with_options(options: RequireErrorCauseOptions) -> Arc<Self> {
+    Arc::new(RequireErrorCause { options })
+  }
+}
+
+const CODE: &str = "require-error-cause";
+
+#[derive(Display)]
+enum RequireErrorCauseMessage {
+  #[display(
+    fmt = "The caught error `{}` isn't passed as the `cause` of the new error",
+    _0
+  )]
+  MissingCause(String),
+}
+
+#[derive(Display)]
+enum RequireErrorCauseHint {
+  #[display(fmt = "Pass it as the cause, like `{{ cause: {} }}`", _0)]
+  AddCause(String),
+}
+
+#[derive(Display)]
+enum RequireErrorCauseFix {
+  #[display(fmt = "Add `{{ cause: {} }}`", _0)]
+  AddCause(String),
+}
+
+impl LintRule for RequireErrorCause {
+  fn new() -> Arc<Self> {
+    Self::with_options(RequireErrorCauseOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn trigger_tokens(&self) -> &'static [&'static str] {
+    &["catch"]
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "ignoreNestedFunctions": {
+          "type": "boolean",
+          "description": "Whether errors thrown in functions nested in the `catch` block are ignored.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(RequireErrorCauseOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    RequireErrorCauseHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/require_error_cause.md")
+  }
+}
+
+/// The built-in error constructors whose second argument takes a `cause`.
+const ERROR_CONSTRUCTORS: &[&str] = &[
+  "Error",
+  "EvalError",
+  "RangeError",
+  "ReferenceError",
+  "SyntaxError",
+  "TypeError",
+  "URIError",
+];
+
+fn is_error_constructor(ctx: &Context, new_expr: &NewExpr) -> bool {
+  matches!(
+    &*new_expr.callee,
+    Expr::Ident(ident)
+      if ERROR_CONSTRUCTORS.contains(&&*ident.sym)
+        && ctx.scope().is_global(&ident.to_id())
+  )
+}
+
+/// Returns whether the options passed to the error may set a `cause`. Options
+/// that aren't an object literal are assumed to do so.
+fn may_have_cause(options: &ExprOrSpread) -> bool {
+  let object = match &*options.expr {
+    Expr::Object(object) if options.spread.is_none() => object,
+    _ => return true,
+  };
+  object.props.iter().any(|prop| match prop {
+    PropOrSpread::Spread(_) => true,
+    PropOrSpread::Prop(prop) => match &**prop {
+      Prop::Shorthand(ident) => ident.sym == *"cause",
+      Prop::KeyValue(key_value) => match &key_value.key {
+        PropName::Ident(ident) => ident.sym == *"cause",
+        PropName::Str(str) => str.value == *"cause",
+        PropName::Computed(_) => true,
+        _ => false,
+      },
+      _ => false,
+    },
+  })
+}
+
+struct RequireErrorCauseHandler<'a> {
+  options: &'a RequireErrorCauseOptions,
+}
+
+impl<'a> RequireErrorCauseHandler<'a> {
+  /// Returns the name the error is bound to in the innermost `catch` block
+  /// around `node`, if any.
+  fn caught_error_name(&self, node: Node) -> Option<String> {
+    for ancestor in node.ancestors() {
+      match ancestor {
+        Node::CatchClause(catch_clause) => {
+          return match &catch_clause.inner.param {
+            Some(Pat::Ident(binding)) => Some(binding.id.sym.to_string()),
+            _ => None,
+          };
+        }
+        Node::Function(_) | Node::ArrowExpr(_)
+          if self.options.ignore_nested_functions =>
+        {
+          return None;
+        }
+        Node::Class(_) => return None,
+        _ => {}
+      }
+    }
+    None
+  }
+}
+
+impl<'a> Handler for RequireErrorCauseHandler<'a> {
+  fn throw_stmt(
+    &mut self,
+    throw_stmt: &ast_view::ThrowStmt,
+    ctx: &mut Context,
+  ) {
+    let new_expr = match &*throw_stmt.inner.arg {
+      Expr::New(new_expr) if is_error_constructor(ctx, new_expr) => new_expr,
+      _ => return,
+    };
+    let args = new_expr.args.as_deref().unwrap_or_default();
+    if matches!(args.get(1), Some(options) if may_have_cause(options)) {
+      return;
+    }
+    if args.iter().any(|arg| arg.spread.is_some()) {
+      return;
+    }
+    let name = match self.caught_error_name(throw_stmt.as_node()) {
+      Some(name) => name,
+      None => return,
+    };
+
+    let message = RequireErrorCauseMessage::MissingCause(name.clone());
+    let hint = RequireErrorCauseHint::AddCause(name.clone()).to_string();
+    match args {
+      [message_arg] => {
+        let insert_at = message_arg.expr.span().hi();
+        let fix = LintFix {
+          description: RequireErrorCauseFix::AddCause(name.clone()).to_string(),
+          changes: vec![ctx.fix_change(
+            Span::new(insert_at, insert_at, Default::default()),
+            format!(", {{ cause: {} }}", name),
+          )],
+          applicability: None,
+        };
+        ctx.add_diagnostic_with_fixes(
+          throw_stmt.span(),
+          CODE,
+          message,
+          Some(hint),
+          vec![fix],
+        );
+      }
+      _ => {
+        ctx.add_diagnostic_with_hint(throw_stmt.span(), CODE, message, hint);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn require_error_cause_valid() {
+    assert_lint_ok! {
+      RequireErrorCause,
+      "throw new Error('failed');",
+      "try { f(); } catch (e) { throw new Error('failed', { cause: e }); }",
+      "try { f(); } catch (err) { throw new TypeError('failed', { cause: err }); }",
+      "try { f(); } catch (cause) { throw new Error('failed', { cause }); }",
+      "try { f(); } catch (e) { throw new Error('failed', { 'cause': e }); }",
+      "try { f(); } catch (e) { throw new Error('failed', { ...options }); }",
+      "try { f(); } catch (e) { throw new Error('failed', options); }",
+      "try { f(); } catch (e) { throw new Error(...args); }",
+      "try { f(); } catch (e) { throw e; }",
+      "try { f(); } catch { throw new Error('failed'); }",
+      "try { f(); } catch ({ message }) { throw new Error(message); }",
+      "try { f(); } catch (e) { throw new HttpError(404); }",
+      "try { f(); } catch (e) { throw Error('failed'); }",
+      "try { f(); } catch (e) { class A { m() { throw new Error('x'); } } }",
+      "try { throw new Error('failed'); } catch (e) {}",
+      "try { f(); } catch (e) {} finally { throw new Error('failed'); }",
+      "class Error {} try { f(); } catch (e) { throw new Error('failed'); }",
+    };
+
+    assert_lint_ok! {
+      RequireErrorCause,
+      options: RequireErrorCauseOptions { ignore_nested_functions: true },
+      "try { f(); } catch (e) { promise.then(() => { throw new Error('failed'); }); }",
+      "try { f(); } catch (e) { function g() { throw new Error('failed'); } }",
+    };
+  }
+
+  #[test]
+  fn require_error_cause_invalid() {
+    assert_lint_err! {
+      RequireErrorCause,
+      "try { f(); } catch (e) { throw new Error('failed'); }": [
+        {
+          col: 25,
+          message: RequireErrorCauseMessage::MissingCause("e".to_string()),
+          hint: RequireErrorCauseHint::AddCause("e".to_string()),
+          fix: (
+            RequireErrorCauseFix::AddCause("e".to_string()),
+            "try { f(); } catch (e) { throw new Error('failed', { cause: e }); }",
+          ),
+        }
+      ],
+      "try { f(); } catch (err) { throw new RangeError(`bad: ${err}`); }": [
+        {
+          col: 27,
+          message: RequireErrorCauseMessage::MissingCause("err".to_string()),
+          hint: RequireErrorCauseHint::AddCause("err".to_string()),
+          fix: (
+            RequireErrorCauseFix::AddCause("err".to_string()),
+            "try { f(); } catch (err) { throw new RangeError(`bad: ${err}`, { cause: err }); }",
+          ),
+        }
+      ],
+      "try { f(); } catch (e) { throw new Error(); }": [
+        {
+          col: 25,
+          message: RequireErrorCauseMessage::MissingCause("e".to_string()),
+          hint: RequireErrorCauseHint::AddCause("e".to_string()),
+        }
+      ],
+      "try { f(); } catch (e) { throw new Error('failed', { message: 'x' }); }": [
+        {
+          col: 25,
+          message: RequireErrorCauseMessage::MissingCause("e".to_string()),
+          hint: RequireErrorCauseHint::AddCause("e".to_string()),
+        }
+      ],
+      "try { f(); } catch (e) { if (retry) { g(); } else { throw new Error('failed'); } }": [
+        {
+          col: 52,
+          message: RequireErrorCauseMessage::MissingCause("e".to_string()),
+          hint: RequireErrorCauseHint::AddCause("e".to_string()),
+        }
+      ],
+      "try { f(); } catch (outer) { try { g(); } catch (inner) { throw new Error('g failed'); } }": [
+        {
+          col: 58,
+          message: RequireErrorCauseMessage::MissingCause("inner".to_string()),
+          hint: RequireErrorCauseHint::AddCause("inner".to_string()),
+        }
+      ],
+      "try { f(); } catch (e) { promise.then(() => { throw new Error('failed'); }); }": [
+        {
+          col: 46,
+          message: RequireErrorCauseMessage::MissingCause("e".to_string()),
+          hint: RequireErrorCauseHint::AddCause("e".to_string()),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      RequireErrorCause,
+      options: RequireErrorCauseOptions { ignore_nested_functions: true },
+      "try { f(); } catch (e) { throw new Error('failed'); }": [
+        {
+          col: 25,
+          message: RequireErrorCauseMessage::MissingCause("e".to_string()),
+          hint: RequireErrorCauseHint::AddCause("e".to_string()),
+        }
+      ],
+    };
+  }
+}
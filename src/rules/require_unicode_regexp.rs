@@ -0,0 +1,226 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view::{
+  CallExpr, Expr, ExprOrSpread, ExprOrSuper, Ident, Lit, NewExpr, Regex,
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct RequireUnicodeRegexp;
+
+const CODE: &str = "require-unicode-regexp";
+const MESSAGE: &str = "Use the `u` flag";
+const HINT: &str =
+  "Add the `u` flag so that characters outside the BMP, like emoji, are handled correctly";
+
+impl LintRule for RequireUnicodeRegexp {
+  fn new() -> Arc<Self> {
+    Arc::new(RequireUnicodeRegexp)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    RequireUnicodeRegexpHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/require_unicode_regexp.md")
+  }
+}
+
+/// The `v` flag is a superset of the `u` flag.
+fn has_unicode_flag(flags: &str) -> bool {
+  flags.contains('u') || flags.contains('v')
+}
+
+fn check_constructor(
+  ctx: &mut Context,
+  span: Span,
+  ident: &Ident,
+  args: &[&ExprOrSpread],
+) {
+  if ident.inner.sym != *"RegExp"
+    || ctx.scope().var(&ident.inner.to_id()).is_some()
+  {
+    return;
+  }
+
+  let missing = match args {
+    // `new RegExp()` creates an empty regex without flags
+    [] => true,
+    // A regex literal keeps its own flags, which are checked separately
+    [pattern] => !matches!(pattern.expr, Expr::Lit(Lit::Regex(_))),
+    [_, flags, ..] => match flags.expr {
+      Expr::Lit(Lit::Str(flags)) => !has_unicode_flag(&flags.inner.value),
+      // Flags that aren't known statically aren't checked
+      _ => false,
+    },
+  };
+  if missing {
+    ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+  }
+}
+
+struct RequireUnicodeRegexpHandler;
+
+impl Handler for RequireUnicodeRegexpHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    if !has_unicode_flag(&regex.inner.flags) {
+      ctx.add_diagnostic_with_hint(regex.span(), CODE, MESSAGE, HINT);
+    }
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Expr::Ident(ident) = new_expr.callee {
+      let args = new_expr.args.as_deref().unwrap_or_default();
+      check_constructor(ctx, new_expr.span(), ident, args);
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let ExprOrSuper::Expr(Expr::Ident(ident)) = &call_expr.callee {
+      check_constructor(ctx, call_expr.span(), ident, &call_expr.args);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.26.0/tests/lib/rules/require-unicode-regexp.js
+  // MIT Licensed.
+
+  #[test]
+  fn require_unicode_regexp_valid() {
+    assert_lint_ok! {
+      RequireUnicodeRegexp,
+      "/foo/u",
+      "/foo/gimuy",
+      "RegExp('', 'u')",
+      "new RegExp('', 'u')",
+      "RegExp('', 'gimuy')",
+      "new RegExp('', 'gimuy')",
+      "new RegExp('', 'v')",
+      "const flags = 'gi'; new RegExp('foo', flags)",
+      "new RegExp('foo', getFlags())",
+      "new RegExp(/foo/u)",
+      "new window.RegExp('foo')",
+      "function f(RegExp) { return new RegExp('foo'); }",
+      "class RegExp {} new RegExp('foo')",
+    };
+  }
+
+  #[test]
+  fn require_unicode_regexp_invalid() {
+    assert_lint_err! {
+      RequireUnicodeRegexp,
+      "/foo/": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "/foo/gimy": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "RegExp()": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "RegExp('foo')": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "RegExp('foo', '')": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "RegExp('foo', 'gimy')": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "new RegExp('foo')": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "new RegExp('foo', 'gimy')": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "new RegExp(pattern)": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "new RegExp(/foo/)": [
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "new RegExp(/foo/, 'g')": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "new RegExp(/foo/u, 'g')": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -7,10 +7,53 @@ use deno_ast::swc::common::Span;
 use derive_more::Display;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::Arc;
 
 #[derive(Debug)]
-pub struct TripleSlashReference;
+pub struct TripleSlashReference {
+  options: TripleSlashReferenceOptions,
+}
+
+/// Whether a kind of reference directive is allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReferenceOption {
+  Always,
+  Never,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TripleSlashReferenceOptions {
+  /// `/// <reference path="..." />`
+  pub path: ReferenceOption,
+  /// `/// <reference types="..." />`
+  pub types: ReferenceOption,
+  /// `/// <reference lib="..." />`
+  pub lib: ReferenceOption,
+}
+
+impl Default for TripleSlashReferenceOptions {
+  fn default() -> Self {
+    Self {
+      path: ReferenceOption::Never,
+      types: ReferenceOption::Never,
+      lib: ReferenceOption::Never,
+    }
+  }
+}
+
+impl TripleSlashReferenceOptions {
+  fn get(&self, kind: ReferenceKind) -> ReferenceOption {
+    match kind {
+      ReferenceKind::Path => self.path,
+      ReferenceKind::Types => self.types,
+      ReferenceKind::Lib => self.lib,
+    }
+  }
+}
 
 const CODE: &str = "triple-slash-reference";
 
@@ -21,6 +64,10 @@ enum TripleSlashReferenceMessage {
 }
 
 impl TripleSlashReference {
+  pub fn with_options(options: TripleSlashReferenceOptions) -> Arc<Self> {
+    Arc::new(TripleSlashReference { options })
+  }
+
   fn report(&self, context: &mut Context, span: Span) {
     context.add_diagnostic(span, CODE, TripleSlashReferenceMessage::Unexpected);
   }
@@ -28,18 +75,53 @@ impl TripleSlashReference {
 
 impl LintRule for TripleSlashReference {
   fn new() -> Arc<Self> {
-    Arc::new(TripleSlashReference)
+    Self::with_options(TripleSlashReferenceOptions::default())
   }
 
   fn code(&self) -> &'static str {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some({
+      let reference = json!({
+        "type": "string",
+        "enum": ["always", "never"],
+        "default": "never"
+      });
+      json!({
+        "type": "object",
+        "properties": {
+          "path": reference,
+          "types": reference,
+          "lib": reference
+        },
+        "additionalProperties": false
+      })
+    })
+  }
+
+  fn default_options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(TripleSlashReferenceOptions::default()).ok()
+  }
+
+  fn options(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn with_options_value(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Arc<dyn LintRule>, serde_json::Error> {
+    Ok(Self::with_options(serde_json::from_value(options)?))
+  }
+
   fn lint_program(&self, context: &mut Context, _program: ProgramRef<'_>) {
     let mut violated_comment_spans = Vec::new();
 
     violated_comment_spans.extend(context.all_comments().filter_map(|c| {
-      if check_comment(c) {
+      let directive = parse_reference_directive(c)?;
+      if self.options.get(directive.kind) == ReferenceOption::Never {
         Some(c.span)
       } else {
         None
@@ -57,24 +139,105 @@ impl LintRule for TripleSlashReference {
   }
 }
 
-/// Returns `true` if the comment should be reported.
-fn check_comment(comment: &Comment) -> bool {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReferenceKind {
+  Path,
+  Types,
+  Lib,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct ReferenceDirective<'a> {
+  kind: ReferenceKind,
+  value: &'a str,
+}
+
+/// Parses a comment of the form `/// <reference types="foo" />`. Returns
+/// `None` if the comment isn't a reference directive of a known kind.
+fn parse_reference_directive(
+  comment: &Comment,
+) -> Option<ReferenceDirective<'_>> {
   if comment.kind != CommentKind::Line {
-    return false;
+    return None;
   }
 
   static TSR_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"^/\s*<reference\s*(types|path|lib)\s*=\s*["|'](.*)["|']"#)
-      .unwrap()
+    Regex::new(
+      r#"^/\s*<reference\s+(?P<kind>types|path|lib)\s*=\s*(?:"(?P<dq>[^"]*)"|'(?P<sq>[^']*)')"#,
+    )
+    .unwrap()
   });
 
-  TSR_REGEX.is_match(&comment.text)
+  let caps = TSR_REGEX.captures(&comment.text)?;
+  let kind = match &caps["kind"] {
+    "path" => ReferenceKind::Path,
+    "types" => ReferenceKind::Types,
+    "lib" => ReferenceKind::Lib,
+    _ => unreachable!(),
+  };
+  let value = caps.name("dq").or_else(|| caps.name("sq"))?.as_str();
+  Some(ReferenceDirective { kind, value })
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  fn line_comment(text: &str) -> Comment {
+    Comment {
+      kind: CommentKind::Line,
+      span: deno_ast::swc::common::DUMMY_SP,
+      text: text.into(),
+    }
+  }
+
+  #[test]
+  fn parse_reference_directive_test() {
+    assert_eq!(
+      parse_reference_directive(&line_comment(
+        r#"/ <reference types="foo" />"#
+      )),
+      Some(ReferenceDirective {
+        kind: ReferenceKind::Types,
+        value: "foo",
+      })
+    );
+    assert_eq!(
+      parse_reference_directive(&line_comment(
+        r#"/<reference path='./a.d.ts'/>"#
+      )),
+      Some(ReferenceDirective {
+        kind: ReferenceKind::Path,
+        value: "./a.d.ts",
+      })
+    );
+    assert_eq!(
+      parse_reference_directive(&line_comment(
+        r#"/ <reference lib="es2017.string" />"#
+      )),
+      Some(ReferenceDirective {
+        kind: ReferenceKind::Lib,
+        value: "es2017.string",
+      })
+    );
+    assert_eq!(
+      parse_reference_directive(&line_comment(
+        r#"/ <reference no-default-lib="true" />"#
+      )),
+      None
+    );
+    assert_eq!(
+      parse_reference_directive(&line_comment(r#" <reference types="foo" />"#)),
+      None
+    );
+    assert_eq!(
+      parse_reference_directive(&line_comment(
+        r#"/ <reference types="foo' />"#
+      )),
+      None
+    );
+  }
+
   #[test]
   fn triple_slash_reference_valid() {
     assert_lint_ok! {
@@ -140,4 +303,35 @@ import foo = require('foo');
       }],
     }
   }
+
+  #[test]
+  fn triple_slash_reference_options() {
+    let options = TripleSlashReferenceOptions {
+      path: ReferenceOption::Never,
+      types: ReferenceOption::Always,
+      lib: ReferenceOption::Always,
+    };
+
+    assert_lint_ok! {
+      TripleSlashReference,
+      options: options.clone(),
+      r#"/// <reference types="foo" />"#,
+      r#"/// <reference lib="es2017.string" />"#,
+    };
+
+    assert_lint_err! {
+      TripleSlashReference,
+      options: options.clone(),
+      r#"
+/// <reference types="foo" />
+/// <reference path="./bar.d.ts" />
+/// <reference lib="es2017.string" />
+"#: [
+      {
+        line: 3,
+        col: 0,
+        message: TripleSlashReferenceMessage::Unexpected,
+      }],
+    };
+  }
 }
@@ -0,0 +1,227 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  Decl, Expr, Lit, ModuleDecl, ModuleItem, Stmt, VarDeclKind,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct VarsOnTop;
+
+const CODE: &str = "vars-on-top";
+
+#[derive(Display)]
+enum VarsOnTopMessage {
+  #[display(
+    fmt = "`var` declarations must be at the top of the function or program"
+  )]
+  NotOnTop,
+}
+
+#[derive(Display)]
+enum VarsOnTopHint {
+  #[display(
+    fmt = "Move the declaration to the top of its scope, or use `let` or `const` instead"
+  )]
+  MoveToTop,
+}
+
+impl LintRule for VarsOnTop {
+  fn new() -> Arc<Self> {
+    Arc::new(VarsOnTop)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    VarsOnTopHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/vars_on_top.md")
+  }
+}
+
+struct VarsOnTopHandler;
+
+impl Handler for VarsOnTopHandler {
+  fn var_decl(&mut self, var_decl: &ast_view::VarDecl, ctx: &mut Context) {
+    if var_decl.inner.kind != VarDeclKind::Var || var_decl.declare() {
+      return;
+    }
+
+    if !is_on_top(var_decl) {
+      ctx.add_diagnostic_with_hint(
+        var_decl.span(),
+        CODE,
+        VarsOnTopMessage::NotOnTop,
+        VarsOnTopHint::MoveToTop,
+      );
+    }
+  }
+}
+
+/// What a statement at the top of a scope is, as far as this rule is
+/// concerned.
+#[derive(PartialEq)]
+enum TopStmt {
+  /// A directive like `"use strict"` or an import.
+  Prologue,
+  VarDecl,
+  Other,
+}
+
+fn classify_stmt(stmt: &Stmt) -> TopStmt {
+  match stmt {
+    Stmt::Expr(expr_stmt)
+      if matches!(&*expr_stmt.expr, Expr::Lit(Lit::Str(_))) =>
+    {
+      TopStmt::Prologue
+    }
+    Stmt::Decl(Decl::Var(_)) => TopStmt::VarDecl,
+    _ => TopStmt::Other,
+  }
+}
+
+fn classify_module_item(item: &ModuleItem) -> TopStmt {
+  match item {
+    ModuleItem::Stmt(stmt) => classify_stmt(stmt),
+    ModuleItem::ModuleDecl(ModuleDecl::Import(_))
+    | ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(_)) => {
+      TopStmt::Prologue
+    }
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export))
+      if matches!(export.decl, Decl::Var(_)) =>
+    {
+      TopStmt::VarDecl
+    }
+    ModuleItem::ModuleDecl(_) => TopStmt::Other,
+  }
+}
+
+/// Checks if the statement `target` only follows directives, imports and
+/// other variable declarations in `stmts`.
+fn is_first_stmts(
+  target: Span,
+  stmts: impl Iterator<Item = (Span, TopStmt)>,
+) -> bool {
+  let mut in_prologue = true;
+  for (span, kind) in stmts {
+    if in_prologue && kind == TopStmt::Prologue {
+      continue;
+    }
+    in_prologue = false;
+    if kind != TopStmt::VarDecl {
+      return false;
+    }
+    if span == target {
+      return true;
+    }
+  }
+  false
+}
+
+fn is_on_top(var_decl: &ast_view::VarDecl) -> bool {
+  use deno_ast::view::Node;
+
+  let (target, parent) = match var_decl.parent() {
+    Node::ExportDecl(export) => (export.span(), export.parent()),
+    parent => (var_decl.span(), parent),
+  };
+
+  let module_items = |items: &[ModuleItem]| {
+    is_first_stmts(
+      target,
+      items.iter().map(|i| (i.span(), classify_module_item(i))),
+    )
+  };
+  let stmts = |stmts: &[Stmt]| {
+    is_first_stmts(target, stmts.iter().map(|s| (s.span(), classify_stmt(s))))
+  };
+
+  match parent {
+    Node::Module(module) => module_items(&module.inner.body),
+    Node::Script(script) => stmts(&script.inner.body),
+    Node::TsModuleBlock(block) => module_items(&block.inner.body),
+    Node::BlockStmt(block)
+      if matches!(block.parent(), Node::Function(_) | Node::ArrowExpr(_)) =>
+    {
+      stmts(&block.inner.stmts)
+    }
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.32.0/tests/lib/rules/vars-on-top.js
+  // MIT Licensed.
+
+  #[test]
+  fn vars_on_top_valid() {
+    assert_lint_ok! {
+      VarsOnTop,
+      "var first = 0; function foo() { first = 2; }",
+      "function foo() {}",
+      "function foo() { var first; if (true) { first = true; } else { first = 1; } }",
+      "function foo() { var first; var second = 1; var third; var fourth = 1, fifth, sixth = third; var seventh; if (true) { third = true; } first = second; }",
+      "function foo() { var i; for (i = 0; i < 10; i++) { alert(i); } }",
+      "function foo() { var outer; function inner() { var inner = 1; var outer = inner; } outer = 1; }",
+      "function foo() { var first; first = 5; first = first * 5; }",
+      "function foo() { 'use strict'; var first = 5; }",
+      "'use strict'; var x; f();",
+      "'use strict'; 'directive'; var x; var y; f();",
+      "import React from 'react'; var y; function f() { 'use strict'; var x; var y; f(); }",
+      "import * as foo from 'foo'; var bar = 1;",
+      "export var x; var y; var z;",
+      "var x; export var y; var z;",
+      "let a; var b; const c = 1;",
+      "const foo = () => { var a = 1; return a; };",
+      "foo(); let a = 1;",
+      "declare var a: number;",
+      "namespace Foo { var a; }",
+    };
+  }
+
+  #[test]
+  fn vars_on_top_invalid() {
+    assert_lint_err! {
+      VarsOnTop,
+      VarsOnTopMessage::NotOnTop,
+      VarsOnTopHint::MoveToTop,
+      "var first = 0; function foo() { first = 2; second = 2; } var second = 0;": [{ col: 57 }],
+      "function foo() { var first; first = 1; first = 2; first = 3; first = 4; var second = 1; second = 2; first = second; }": [{ col: 72 }],
+      "function foo() { var first; if (true) { var second = true; } first = second; }": [{ col: 40 }],
+      "function foo() { for (var i = 0; i < 10; i++) { alert(i); } }": [{ col: 22 }],
+      "function foo() { var first = 10; var i; for (i = 0; i < first; i++) { var second = i; } }": [{ col: 70 }],
+      "function foo() { var first = 10; var i; switch (first) { case 10: var hello = 1; break; } }": [{ col: 66 }],
+      "function foo() { var first = 10; var i; try { var hello = 1; } catch (e) { alert('error'); } }": [{ col: 46 }],
+      "function foo() { var first = 10; var i; while (first) { var item = first; } }": [{ col: 56 }],
+      "function foo() { 'use strict'; 'directive'; var x; var y; f(); var z; }": [{ col: 63 }],
+      "'use strict'; 0; var x; f();": [{ col: 17 }],
+      "f(); export var x;": [{ col: 12 }],
+      "import foo from 'foo'; foo(); var bar;": [{ col: 30 }],
+      "const foo = () => { foo(); var a; };": [{ col: 27 }],
+      "namespace Foo { foo(); var a; }": [{ col: 23 }],
+    };
+  }
+}
@@ -0,0 +1,822 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::scopes::BindingKind;
+use deno_ast::swc::ast::{Ident, ParamOrTsParamProp, Pat, TsParamPropParam};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::utils::find_ids;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::utils::Id;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use std::collections::HashMap;
+
+/// A tree of the scopes of a program, holding the bindings declared in each
+/// scope and the references to them.
+///
+/// Unlike [`crate::scopes::Scope`], which only knows about bindings, this
+/// keeps the nesting of scopes, so it can be used to answer questions like
+/// "which variable does this declaration shadow?".
+#[derive(Debug)]
+pub struct ScopeTree {
+  scopes: Vec<Scope>,
+  bindings: Vec<Binding>,
+  /// Scopes keyed by the span and the kind of the node creating them
+  scopes_by_node: HashMap<(Span, ScopeKind), ScopeId>,
+  /// The first binding of each `Id`
+  bindings_by_id: HashMap<Id, BindingId>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScopeId(usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BindingId(usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScopeKind {
+  /// The top level of a module or script.
+  Program,
+  Function,
+  Arrow,
+  /// A class static block.
+  StaticBlock,
+  /// The body of a namespace or module declaration.
+  TsModule,
+  Block,
+  Catch,
+  /// The head of a `for`, `for-in` or `for-of` loop.
+  For,
+  Switch,
+  /// A scope only containing the name of a function or class expression,
+  /// e.g. `a` in `const b = function a() {};`.
+  Name,
+}
+
+impl ScopeKind {
+  /// Checks if `var` declarations in this scope belong to it, rather than to
+  /// an enclosing scope.
+  pub fn is_var_scope(self) -> bool {
+    matches!(
+      self,
+      ScopeKind::Program
+        | ScopeKind::Function
+        | ScopeKind::Arrow
+        | ScopeKind::StaticBlock
+        | ScopeKind::TsModule
+    )
+  }
+}
+
+#[derive(Debug)]
+pub struct Scope {
+  kind: ScopeKind,
+  span: Span,
+  parent: Option<ScopeId>,
+  children: Vec<ScopeId>,
+  bindings: Vec<BindingId>,
+}
+
+impl Scope {
+  pub fn kind(&self) -> ScopeKind {
+    self.kind
+  }
+
+  /// The span of the node creating this scope.
+  pub fn span(&self) -> Span {
+    self.span
+  }
+
+  pub fn parent(&self) -> Option<ScopeId> {
+    self.parent
+  }
+
+  pub fn children(&self) -> &[ScopeId] {
+    &self.children
+  }
+
+  /// Bindings declared in this scope, in the order of declaration.
+  pub fn bindings(&self) -> &[BindingId] {
+    &self.bindings
+  }
+}
+
+#[derive(Debug)]
+pub struct Binding {
+  name: JsWord,
+  id: Id,
+  span: Span,
+  kind: BindingKind,
+  scope: ScopeId,
+  decl: Option<Span>,
+  init: Option<Span>,
+  is_declare: bool,
+  is_overload: bool,
+  references: Vec<Reference>,
+}
+
+impl Binding {
+  pub fn name(&self) -> &JsWord {
+    &self.name
+  }
+
+  pub fn id(&self) -> &Id {
+    &self.id
+  }
+
+  /// The span of the identifier declaring the binding.
+  pub fn span(&self) -> Span {
+    self.span
+  }
+
+  pub fn kind(&self) -> BindingKind {
+    self.kind
+  }
+
+  pub fn scope(&self) -> ScopeId {
+    self.scope
+  }
+
+  /// For variables, the span of the whole declaration statement.
+  pub fn decl(&self) -> Option<Span> {
+    self.decl
+  }
+
+  /// For variables, the span of the expression that initializes the
+  /// variable, which is the right hand side of the loop for variables of
+  /// `for-in` and `for-of` loops.
+  pub fn init(&self) -> Option<Span> {
+    self.init
+  }
+
+  /// Checks if the binding is declared with `declare`, e.g.
+  /// `declare const a: number;`, so it only describes a value that is
+  /// defined elsewhere.
+  pub fn is_declare(&self) -> bool {
+    self.is_declare
+  }
+
+  /// Checks if the binding is declared by an overload signature, i.e. a
+  /// function declaration without a body like `function a(b: string): void;`.
+  pub fn is_overload(&self) -> bool {
+    self.is_overload
+  }
+
+  pub fn references(&self) -> &[Reference] {
+    &self.references
+  }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Reference {
+  span: Span,
+  scope: ScopeId,
+  kind: ReferenceKind,
+}
+
+impl Reference {
+  pub fn span(&self) -> Span {
+    self.span
+  }
+
+  /// The scope the reference appears in.
+  pub fn scope(&self) -> ScopeId {
+    self.scope
+  }
+
+  pub fn kind(&self) -> ReferenceKind {
+    self.kind
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReferenceKind {
+  /// The value of the binding is used, e.g. `a` in `f(a)` or `a += 1`.
+  Read,
+  /// The binding is assigned to or declared again without reading it, e.g.
+  /// `a` in `a = 1` or in the second `var a;` of `var a; var a;`.
+  Write,
+  /// The binding is used as a type, e.g. `A` in `let b: A;`.
+  Type,
+}
+
+impl ScopeTree {
+  pub fn analyze(program: ast_view::Program) -> Self {
+    let mut builder = Builder {
+      tree: ScopeTree {
+        scopes: vec![Scope {
+          kind: ScopeKind::Program,
+          span: program.span(),
+          parent: None,
+          children: Vec::new(),
+          bindings: Vec::new(),
+        }],
+        bindings: Vec::new(),
+        scopes_by_node: HashMap::new(),
+        bindings_by_id: HashMap::new(),
+      },
+      stack: vec![ScopeId(0)],
+      idents: Vec::new(),
+    };
+    builder.visit(program.as_node());
+    builder.finish()
+  }
+
+  /// The scope of the top level of the program.
+  pub fn root(&self) -> ScopeId {
+    ScopeId(0)
+  }
+
+  pub fn scope(&self, id: ScopeId) -> &Scope {
+    &self.scopes[id.0]
+  }
+
+  pub fn binding(&self, id: BindingId) -> &Binding {
+    &self.bindings[id.0]
+  }
+
+  /// All scopes in the order they appear in the program.
+  pub fn scopes(&self) -> impl Iterator<Item = ScopeId> {
+    (0..self.scopes.len()).map(ScopeId)
+  }
+
+  /// `scope` and all scopes enclosing it, innermost first.
+  pub fn ancestors(
+    &self,
+    scope: ScopeId,
+  ) -> impl Iterator<Item = ScopeId> + '_ {
+    std::iter::successors(Some(scope), move |id| self.scope(*id).parent)
+  }
+
+  /// Returns the innermost scope that contains `node`. A node that creates a
+  /// scope, like a function, belongs to the scope it creates.
+  pub fn scope_of(&self, node: Node) -> ScopeId {
+    std::iter::once(node)
+      .chain(node.ancestors())
+      .find_map(|n| {
+        let kind = scope_kind(n)?;
+        self.scopes_by_node.get(&(n.span(), kind)).copied()
+      })
+      .unwrap_or_else(|| self.root())
+  }
+
+  /// Resolves an identifier, either declaring or referencing a binding, to
+  /// the binding.
+  pub fn binding_of(&self, ident: &Ident) -> Option<BindingId> {
+    self.bindings_by_id.get(&ident.to_id()).copied()
+  }
+
+  /// Finds the binding named `name` that is visible in `scope`.
+  pub fn lookup(&self, scope: ScopeId, name: &JsWord) -> Option<BindingId> {
+    self.ancestors(scope).find_map(|id| {
+      self
+        .scope(id)
+        .bindings
+        .iter()
+        .copied()
+        .find(|b| self.binding(*b).name == *name)
+    })
+  }
+
+  /// Returns the binding of an enclosing scope that `binding` shadows.
+  pub fn shadowed(&self, binding: BindingId) -> Option<BindingId> {
+    let binding = self.binding(binding);
+    let parent = self.scope(binding.scope).parent?;
+    self.lookup(parent, &binding.name)
+  }
+}
+
+/// Returns the kind of scope `node` creates, if any.
+fn scope_kind(node: Node) -> Option<ScopeKind> {
+  match node {
+    Node::Function(_)
+    | Node::Constructor(_)
+    | Node::GetterProp(_)
+    | Node::SetterProp(_) => Some(ScopeKind::Function),
+    Node::ArrowExpr(_) => Some(ScopeKind::Arrow),
+    Node::StaticBlock(_) => Some(ScopeKind::StaticBlock),
+    Node::TsModuleBlock(_) => Some(ScopeKind::TsModule),
+    Node::FnExpr(fn_expr) if fn_expr.ident.is_some() => Some(ScopeKind::Name),
+    Node::ClassExpr(class_expr) if class_expr.ident.is_some() => {
+      Some(ScopeKind::Name)
+    }
+    // The body of a function shares the scope with the parameters
+    Node::BlockStmt(block) => match block.parent() {
+      Node::Function(_)
+      | Node::ArrowExpr(_)
+      | Node::Constructor(_)
+      | Node::GetterProp(_)
+      | Node::SetterProp(_)
+      | Node::StaticBlock(_)
+      | Node::CatchClause(_) => None,
+      _ => Some(ScopeKind::Block),
+    },
+    Node::CatchClause(_) => Some(ScopeKind::Catch),
+    Node::ForStmt(_) | Node::ForInStmt(_) | Node::ForOfStmt(_) => {
+      Some(ScopeKind::For)
+    }
+    Node::SwitchStmt(_) => Some(ScopeKind::Switch),
+    _ => None,
+  }
+}
+
+/// Returns how `ident` refers to the binding it resolves to.
+fn reference_kind(ident: &ast_view::Ident) -> ReferenceKind {
+  match ident.parent() {
+    // The key of `{ a = b }` in a pattern, but not the default value
+    Node::AssignPatProp(prop) if std::ptr::eq(&prop.inner.key, ident.inner) => {
+      ReferenceKind::Write
+    }
+    Node::BindingIdent(_)
+    | Node::FnDecl(_)
+    | Node::ClassDecl(_)
+    | Node::TsEnumDecl(_)
+    | Node::TsModuleDecl(_)
+    | Node::TsInterfaceDecl(_)
+    | Node::TsTypeAliasDecl(_) => ReferenceKind::Write,
+    Node::TsTypeRef(_) | Node::TsTypePredicate(_) => ReferenceKind::Type,
+    // The namespace of a qualified name like `a.B` is a type only if the
+    // qualified name is
+    Node::TsQualifiedName(name) => {
+      match name
+        .ancestors()
+        .find(|ancestor| !matches!(ancestor, Node::TsQualifiedName(_)))
+      {
+        Some(Node::TsTypeRef(_)) => ReferenceKind::Type,
+        _ => ReferenceKind::Read,
+      }
+    }
+    _ => ReferenceKind::Read,
+  }
+}
+
+struct Builder {
+  tree: ScopeTree,
+  stack: Vec<ScopeId>,
+  /// Identifiers that may reference a binding, and the scope they are in
+  idents: Vec<(Id, Span, ScopeId, ReferenceKind)>,
+}
+
+impl Builder {
+  fn current(&self) -> ScopeId {
+    *self.stack.last().unwrap()
+  }
+
+  fn nearest_var_scope(&self) -> ScopeId {
+    *self
+      .stack
+      .iter()
+      .rev()
+      .find(|id| self.tree.scope(**id).kind.is_var_scope())
+      .unwrap()
+  }
+
+  fn declare(&mut self, scope: ScopeId, ident: &Ident, kind: BindingKind) {
+    self.declare_with(scope, ident, kind, None, None, false);
+  }
+
+  fn declare_with(
+    &mut self,
+    scope: ScopeId,
+    ident: &Ident,
+    kind: BindingKind,
+    decl: Option<Span>,
+    init: Option<Span>,
+    is_declare: bool,
+  ) {
+    // `this` parameters only annotate the type of `this`
+    if ident.sym == *"this" {
+      return;
+    }
+
+    let id = BindingId(self.tree.bindings.len());
+    self.tree.bindings.push(Binding {
+      name: ident.sym.clone(),
+      id: ident.to_id(),
+      span: ident.span,
+      kind,
+      scope,
+      decl,
+      init,
+      is_declare,
+      is_overload: false,
+      references: Vec::new(),
+    });
+    self.tree.scopes[scope.0].bindings.push(id);
+    self.tree.bindings_by_id.entry(ident.to_id()).or_insert(id);
+  }
+
+  fn declare_pat(&mut self, pat: &Pat, kind: BindingKind) {
+    let scope = self.current();
+    for ident in find_ids::<_, Ident>(pat) {
+      self.declare(scope, &ident, kind);
+    }
+  }
+
+  fn declare_var_decl(&mut self, var_decl: &ast_view::VarDecl) {
+    use deno_ast::swc::ast::VarDeclKind;
+
+    let (scope, kind) = match var_decl.inner.kind {
+      VarDeclKind::Var => (self.nearest_var_scope(), BindingKind::Var),
+      VarDeclKind::Let => (self.current(), BindingKind::Let),
+      VarDeclKind::Const => (self.current(), BindingKind::Const),
+    };
+    let loop_right = match var_decl.parent() {
+      Node::ForInStmt(for_in) => Some(for_in.right.span()),
+      Node::ForOfStmt(for_of) => Some(for_of.right.span()),
+      _ => None,
+    };
+
+    for decl in &var_decl.decls {
+      let init = decl.init.map(|init| init.span()).or(loop_right);
+      for ident in find_ids::<_, Ident>(&decl.inner.name) {
+        self.declare_with(
+          scope,
+          &ident,
+          kind,
+          Some(var_decl.span()),
+          init,
+          var_decl.declare(),
+        );
+      }
+    }
+  }
+
+  fn push_scope(&mut self, kind: ScopeKind, span: Span) {
+    let id = ScopeId(self.tree.scopes.len());
+    let parent = self.current();
+    self.tree.scopes.push(Scope {
+      kind,
+      span,
+      parent: Some(parent),
+      children: Vec::new(),
+      bindings: Vec::new(),
+    });
+    self.tree.scopes[parent.0].children.push(id);
+    self.tree.scopes_by_node.insert((span, kind), id);
+    self.stack.push(id);
+  }
+
+  fn visit(&mut self, node: Node) {
+    self.enter(node);
+    for child in node.children() {
+      self.visit(child);
+    }
+    if scope_kind(node).is_some() {
+      self.stack.pop();
+    }
+  }
+
+  fn enter(&mut self, node: Node) {
+    // Declarations that belong to the enclosing scope
+    let scope = self.current();
+    match node {
+      Node::Ident(ident) => self.idents.push((
+        ident.inner.to_id(),
+        ident.inner.span,
+        scope,
+        reference_kind(ident),
+      )),
+      Node::VarDecl(var_decl) => self.declare_var_decl(var_decl),
+      Node::FnDecl(fn_decl) => {
+        self.declare_with(
+          scope,
+          fn_decl.ident.inner,
+          BindingKind::Function,
+          None,
+          None,
+          fn_decl.declare(),
+        );
+        // A function name is never `this`, so the binding was just declared
+        if fn_decl.function.inner.body.is_none() {
+          self.tree.bindings.last_mut().unwrap().is_overload = true;
+        }
+      }
+      Node::ClassDecl(class_decl) => self.declare_with(
+        scope,
+        class_decl.ident.inner,
+        BindingKind::Class,
+        None,
+        None,
+        class_decl.declare(),
+      ),
+      Node::TsEnumDecl(enum_decl) => self.declare_with(
+        scope,
+        enum_decl.id.inner,
+        BindingKind::Enum,
+        None,
+        None,
+        enum_decl.declare(),
+      ),
+      Node::ImportNamedSpecifier(specifier) => {
+        self.declare(scope, specifier.local.inner, BindingKind::ValueImport)
+      }
+      Node::ImportDefaultSpecifier(specifier) => {
+        self.declare(scope, specifier.local.inner, BindingKind::ValueImport)
+      }
+      Node::ImportStarAsSpecifier(specifier) => {
+        self.declare(scope, specifier.local.inner, BindingKind::NamespaceImport)
+      }
+      _ => {}
+    }
+
+    let kind = match scope_kind(node) {
+      Some(kind) => kind,
+      None => return,
+    };
+    self.push_scope(kind, node.span());
+
+    // Declarations that belong to the new scope
+    let scope = self.current();
+    match node {
+      // Parameters of overload signatures don't declare anything
+      Node::Function(function) if function.inner.body.is_some() => {
+        for param in &function.inner.params {
+          self.declare_pat(&param.pat, BindingKind::Param);
+        }
+      }
+      Node::ArrowExpr(arrow) => {
+        for param in &arrow.inner.params {
+          self.declare_pat(param, BindingKind::Param);
+        }
+      }
+      Node::Constructor(constructor) if constructor.inner.body.is_some() => {
+        for param in &constructor.inner.params {
+          match param {
+            ParamOrTsParamProp::Param(param) => {
+              self.declare_pat(&param.pat, BindingKind::Param)
+            }
+            ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+              TsParamPropParam::Ident(ident) => {
+                self.declare(scope, &ident.id, BindingKind::Param)
+              }
+              TsParamPropParam::Assign(assign) => {
+                self.declare_pat(&assign.left, BindingKind::Param)
+              }
+            },
+          }
+        }
+      }
+      Node::SetterProp(setter) => {
+        self.declare_pat(&setter.inner.param, BindingKind::Param)
+      }
+      Node::CatchClause(catch) => {
+        if let Some(param) = &catch.inner.param {
+          self.declare_pat(param, BindingKind::CatchClause);
+        }
+      }
+      Node::FnExpr(fn_expr) => {
+        let ident = fn_expr.inner.ident.as_ref().unwrap();
+        self.declare(scope, ident, BindingKind::Function);
+      }
+      Node::ClassExpr(class_expr) => {
+        let ident = class_expr.inner.ident.as_ref().unwrap();
+        self.declare(scope, ident, BindingKind::Class);
+      }
+      _ => {}
+    }
+  }
+
+  /// Resolves the collected identifiers now that all bindings, including
+  /// hoisted ones, are known.
+  fn finish(mut self) -> ScopeTree {
+    for (id, span, scope, kind) in std::mem::take(&mut self.idents) {
+      if let Some(binding) = self.tree.bindings_by_id.get(&id) {
+        let binding = &mut self.tree.bindings[binding.0];
+        if binding.span != span {
+          binding.references.push(Reference { span, scope, kind });
+        }
+      }
+    }
+    self.tree
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util;
+
+  fn test_tree(source_code: &str, test: impl Fn(&ScopeTree)) {
+    test_util::parse_and_then(source_code, |program| {
+      let tree = ScopeTree::analyze(program);
+      test(&tree);
+    });
+  }
+
+  fn find<'a>(tree: &'a ScopeTree, name: &str) -> (BindingId, &'a Binding) {
+    let mut found = tree
+      .bindings
+      .iter()
+      .enumerate()
+      .filter(|(_, b)| &*b.name == name);
+    let (idx, binding) = found.next().expect("no such binding");
+    assert!(found.next().is_none(), "multiple bindings named {}", name);
+    (BindingId(idx), binding)
+  }
+
+  #[test]
+  fn scope_nesting() {
+    let source_code = r#"
+import { a } from "./a.ts";
+function foo(b) {
+  var c;
+  {
+    let d;
+    var e;
+  }
+  for (const f of []) {}
+  try {} catch (g) {}
+}
+const h = function i() {};
+"#;
+    test_tree(source_code, |tree| {
+      let root = tree.root();
+      assert_eq!(tree.scope(root).kind(), ScopeKind::Program);
+
+      assert_eq!(find(tree, "a").1.kind(), BindingKind::ValueImport);
+      assert_eq!(find(tree, "a").1.scope(), root);
+      assert_eq!(find(tree, "foo").1.scope(), root);
+      assert_eq!(find(tree, "h").1.scope(), root);
+
+      let foo_scope = find(tree, "b").1.scope();
+      assert_eq!(tree.scope(foo_scope).kind(), ScopeKind::Function);
+      assert_eq!(tree.scope(foo_scope).parent(), Some(root));
+      assert_eq!(find(tree, "b").1.kind(), BindingKind::Param);
+      assert_eq!(find(tree, "c").1.scope(), foo_scope);
+      // `var` is hoisted to the function scope
+      assert_eq!(find(tree, "e").1.scope(), foo_scope);
+
+      let d_scope = find(tree, "d").1.scope();
+      assert_eq!(tree.scope(d_scope).kind(), ScopeKind::Block);
+      assert_eq!(tree.scope(d_scope).parent(), Some(foo_scope));
+
+      let f_scope = find(tree, "f").1.scope();
+      assert_eq!(tree.scope(f_scope).kind(), ScopeKind::For);
+      let g_scope = find(tree, "g").1.scope();
+      assert_eq!(tree.scope(g_scope).kind(), ScopeKind::Catch);
+      // The block of `try` is a scope as well
+      let children = tree.scope(foo_scope).children();
+      assert_eq!(children.len(), 4);
+      assert_eq!(children[0], d_scope);
+      assert_eq!(children[1], f_scope);
+      assert_eq!(tree.scope(children[2]).kind(), ScopeKind::Block);
+      assert_eq!(children[3], g_scope);
+
+      let i_scope = find(tree, "i").1.scope();
+      assert_eq!(tree.scope(i_scope).kind(), ScopeKind::Name);
+      assert_eq!(tree.scope(i_scope).parent(), Some(root));
+    });
+  }
+
+  #[test]
+  fn references() {
+    let source_code = r#"
+foo();
+function foo() {
+  return a + obj.a + { a: 1 }.a;
+}
+const a = 1;
+const obj = { a };
+"#;
+    test_tree(source_code, |tree| {
+      let (_, foo) = find(tree, "foo");
+      assert_eq!(foo.references().len(), 1);
+      assert_eq!(foo.references()[0].scope(), tree.root());
+
+      let (_, a) = find(tree, "a");
+      assert_eq!(a.references().len(), 2);
+      assert_ne!(a.references()[0].scope(), tree.root());
+      assert_eq!(a.references()[1].scope(), tree.root());
+
+      let (_, obj) = find(tree, "obj");
+      assert_eq!(obj.references().len(), 1);
+    });
+  }
+
+  #[test]
+  fn reference_kinds() {
+    let source_code = r#"
+var a = 1;
+a = 2;
+a += 3;
+[a] = [4];
+({ a, b = a } = { a });
+var a;
+type B = typeof a;
+enum C {}
+let d: C;
+let e: C.D;
+"#;
+    test_tree(source_code, |tree| {
+      let kinds = |name: &str| -> Vec<ReferenceKind> {
+        let (id, _) = tree
+          .bindings
+          .iter()
+          .enumerate()
+          .find(|(_, b)| &*b.name == name)
+          .unwrap();
+        tree
+          .binding(BindingId(id))
+          .references()
+          .iter()
+          .map(Reference::kind)
+          .collect()
+      };
+      use ReferenceKind::*;
+      assert_eq!(
+        kinds("a"),
+        vec![Write, Read, Write, Write, Read, Read, Write, Read]
+      );
+      assert_eq!(kinds("C"), vec![Type, Type]);
+    });
+  }
+
+  #[test]
+  fn declare() {
+    let source_code = r#"
+declare const a: number;
+declare function b(): void;
+declare class C {}
+const d = 1;
+"#;
+    test_tree(source_code, |tree| {
+      assert!(find(tree, "a").1.is_declare());
+      assert!(find(tree, "b").1.is_declare());
+      assert!(find(tree, "C").1.is_declare());
+      assert!(!find(tree, "d").1.is_declare());
+    });
+  }
+
+  #[test]
+  fn overloads() {
+    let source_code = r#"
+function a(b: string): void;
+function a(b) {}
+"#;
+    test_tree(source_code, |tree| {
+      let overloads: Vec<bool> = tree
+        .bindings
+        .iter()
+        .filter(|b| &*b.name == "a")
+        .map(Binding::is_overload)
+        .collect();
+      assert_eq!(overloads, vec![true, false]);
+      // Parameters of overload signatures don't declare anything
+      assert!(!find(tree, "b").1.is_overload());
+    });
+  }
+
+  #[test]
+  fn shadowing() {
+    let source_code = r#"
+const a = 1;
+function foo(a) {
+  {
+    const a = 2;
+  }
+}
+const b = 1;
+"#;
+    test_tree(source_code, |tree| {
+      let a_ids: Vec<BindingId> = (0..tree.bindings.len())
+        .map(BindingId)
+        .filter(|id| &*tree.binding(*id).name == "a")
+        .collect();
+      assert_eq!(a_ids.len(), 3);
+      assert_eq!(tree.shadowed(a_ids[0]), None);
+      assert_eq!(tree.shadowed(a_ids[1]), Some(a_ids[0]));
+      assert_eq!(tree.shadowed(a_ids[2]), Some(a_ids[1]));
+
+      let (b, _) = find(tree, "b");
+      assert_eq!(tree.shadowed(b), None);
+      assert_eq!(
+        tree.lookup(tree.binding(a_ids[2]).scope(), &"b".into()),
+        Some(b)
+      );
+    });
+  }
+
+  #[test]
+  fn scope_of_node() {
+    let source_code = "function foo() { if (x) { bar; } }";
+    test_util::parse_and_then(source_code, |program| {
+      let tree = ScopeTree::analyze(program);
+      let ident = find_ident(program.as_node(), "bar").unwrap();
+      let scope = tree.scope_of(ident);
+      assert_eq!(tree.scope(scope).kind(), ScopeKind::Block);
+      let parent = tree.scope(scope).parent().unwrap();
+      assert_eq!(tree.scope(parent).kind(), ScopeKind::Function);
+    });
+  }
+
+  fn find_ident<'a>(node: Node<'a>, name: &str) -> Option<Node<'a>> {
+    if let Node::Ident(ident) = node {
+      if &*ident.inner.sym == name {
+        return Some(node);
+      }
+    }
+    node
+      .children()
+      .into_iter()
+      .find_map(|n| find_ident(n, name))
+  }
+}
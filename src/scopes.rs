@@ -110,6 +110,8 @@ pub enum BindingKind {
   ValueImport,
 
   TypeAlias,
+
+  Enum,
 }
 
 impl BindingKind {
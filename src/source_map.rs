@@ -0,0 +1,314 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+//! A minimal reader of [source maps](https://sourcemaps.info/spec.html), used
+//! to map diagnostics of generated code back to the code it was generated
+//! from.
+
+use crate::diagnostic::{LintDiagnostic, Range};
+use anyhow::{anyhow, bail};
+use serde::Deserialize;
+use std::convert::TryFrom;
+
+#[derive(Deserialize)]
+struct RawSourceMap {
+  version: u32,
+  #[serde(default)]
+  sources: Vec<Option<String>>,
+  mappings: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Mapping {
+  generated_column: u32,
+  /// The index of the source, the line and the column in the original code,
+  /// if the generated code has one.
+  original: Option<(u32, u32, u32)>,
+}
+
+/// A position in one of the original sources of a source map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OriginalPosition {
+  pub source: Option<String>,
+  /// The 0-indexed line index.
+  pub line_index: usize,
+  /// The 0-indexed column index.
+  pub column_index: usize,
+}
+
+/// A parsed source map of a generated file.
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+  sources: Vec<Option<String>>,
+  /// The mappings of each generated line, sorted by the generated column.
+  lines: Vec<Vec<Mapping>>,
+}
+
+impl SourceMap {
+  /// Parses a source map in the version 3 JSON format.
+  pub fn from_json(json: &str) -> Result<Self, anyhow::Error> {
+    let raw: RawSourceMap = serde_json::from_str(json)?;
+    if raw.version != 3 {
+      bail!("Unsupported source map version: {}", raw.version);
+    }
+    let lines = decode_mappings(&raw.mappings)?;
+    Ok(SourceMap {
+      sources: raw.sources,
+      lines,
+    })
+  }
+
+  /// Returns the original position of a position in the generated file, if
+  /// the source map has a mapping for it. The closest mapping at or before
+  /// the column on the same line is used.
+  pub fn lookup(
+    &self,
+    line_index: usize,
+    column_index: usize,
+  ) -> Option<OriginalPosition> {
+    let mappings = self.lines.get(line_index)?;
+    let index =
+      mappings.partition_point(|m| m.generated_column as usize <= column_index);
+    let mapping = mappings.get(index.checked_sub(1)?)?;
+    let (source, line, column) = mapping.original?;
+    Some(OriginalPosition {
+      source: self.sources.get(source as usize).cloned().flatten(),
+      line_index: line as usize,
+      column_index: column as usize
+        + (column_index - mapping.generated_column as usize),
+    })
+  }
+
+  /// Maps the lines and columns of the diagnostic and its related locations
+  /// to the original code, along with the file names. A range is only
+  /// remapped if both its start and its end map to the same source, so it
+  /// never mixes original and generated positions. Other ranges are left as
+  /// they are, and so are byte positions and the ranges of fixes, which still
+  /// refer to the generated file.
+  pub fn remap_diagnostic(&self, diagnostic: &mut LintDiagnostic) {
+    if let Some(source) = self.remap_range(&mut diagnostic.range) {
+      diagnostic.filename = source;
+    }
+    for related in &mut diagnostic.related {
+      if let Some(source) = self.remap_range(&mut related.range) {
+        related.filename = Some(source);
+      }
+    }
+  }
+
+  /// Remaps the range and returns its original source, if it has a name.
+  fn remap_range(&self, range: &mut Range) -> Option<String> {
+    let start =
+      self.lookup(range.start.line_index, range.start.column_index)?;
+    let end = self.lookup(range.end.line_index, range.end.column_index)?;
+    if start.source != end.source {
+      return None;
+    }
+    range.start.line_index = start.line_index;
+    range.start.column_index = start.column_index;
+    range.end.line_index = end.line_index;
+    range.end.column_index = end.column_index;
+    start.source
+  }
+}
+
+/// Decodes the `mappings` field, a list of lines separated by `;`, each
+/// consisting of `,`-separated segments of Base64 VLQ encoded fields.
+fn decode_mappings(mappings: &str) -> Result<Vec<Vec<Mapping>>, anyhow::Error> {
+  // The fields other than the generated column are relative to the previous
+  // segment, even across lines.
+  let mut source = 0i64;
+  let mut original_line = 0i64;
+  let mut original_column = 0i64;
+
+  let mut lines = Vec::new();
+  for line in mappings.split(';') {
+    let mut generated_column = 0i64;
+    let mut segments = Vec::new();
+    for segment in line.split(',').filter(|s| !s.is_empty()) {
+      let fields = decode_vlq(segment)?;
+      let to_u32 = |value: i64| {
+        u32::try_from(value)
+          .map_err(|_| anyhow!("Invalid source map segment: {}", segment))
+      };
+      generated_column += fields[0];
+      let original = match fields.len() {
+        1 => None,
+        4 | 5 => {
+          source += fields[1];
+          original_line += fields[2];
+          original_column += fields[3];
+          Some((
+            to_u32(source)?,
+            to_u32(original_line)?,
+            to_u32(original_column)?,
+          ))
+        }
+        _ => bail!("Invalid source map segment: {}", segment),
+      };
+      segments.push(Mapping {
+        generated_column: to_u32(generated_column)?,
+        original,
+      });
+    }
+    segments.sort_by_key(|m| m.generated_column);
+    lines.push(segments);
+  }
+  Ok(lines)
+}
+
+fn decode_vlq(segment: &str) -> Result<Vec<i64>, anyhow::Error> {
+  let mut values = Vec::new();
+  let mut value = 0i64;
+  let mut shift = 0;
+  for c in segment.chars() {
+    let digit = match c {
+      'A'..='Z' => c as i64 - 'A' as i64,
+      'a'..='z' => c as i64 - 'a' as i64 + 26,
+      '0'..='9' => c as i64 - '0' as i64 + 52,
+      '+' => 62,
+      '/' => 63,
+      _ => bail!("Invalid Base64 VLQ character: {:?}", c),
+    };
+    if shift > 32 {
+      bail!("Invalid source map segment: {}", segment);
+    }
+    value += (digit & 0b11111) << shift;
+    if digit & 0b100000 != 0 {
+      shift += 5;
+      continue;
+    }
+    let magnitude = value >> 1;
+    values.push(if value & 1 == 1 {
+      -magnitude
+    } else {
+      magnitude
+    });
+    value = 0;
+    shift = 0;
+  }
+  if shift != 0 {
+    bail!("Invalid source map segment: {}", segment);
+  }
+  Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn decode_vlq_values() {
+    assert_eq!(decode_vlq("AAAA").unwrap(), vec![0, 0, 0, 0]);
+    assert_eq!(decode_vlq("CADF").unwrap(), vec![1, 0, -1, -2]);
+    assert_eq!(decode_vlq("gB").unwrap(), vec![16]);
+    assert_eq!(decode_vlq("2HwrB").unwrap(), vec![123, 696]);
+    assert!(decode_vlq("g").is_err());
+    assert!(decode_vlq("A*").is_err());
+  }
+
+  #[test]
+  fn lookup_positions() {
+    // Line 0: column 0 maps to 2:0 of `a.ts`, column 6 to 2:10, and column 8
+    // has no original position.
+    // Line 1: column 2 has no original position, column 4 maps to 5:3 of
+    // `b.ts`.
+    let source_map = SourceMap::from_json(
+      r#"{
+        "version": 3,
+        "sources": ["a.ts", "b.ts"],
+        "names": [],
+        "mappings": "AAEA,MAAU,E;E,ECGP"
+      }"#,
+    )
+    .unwrap();
+
+    let position = |source: &str, line_index, column_index| {
+      Some(OriginalPosition {
+        source: Some(source.to_string()),
+        line_index,
+        column_index,
+      })
+    };
+    assert_eq!(source_map.lookup(0, 0), position("a.ts", 2, 0));
+    assert_eq!(source_map.lookup(0, 3), position("a.ts", 2, 3));
+    assert_eq!(source_map.lookup(0, 6), position("a.ts", 2, 10));
+    assert_eq!(source_map.lookup(0, 7), position("a.ts", 2, 11));
+    assert_eq!(source_map.lookup(0, 8), None);
+    assert_eq!(source_map.lookup(1, 3), None);
+    assert_eq!(source_map.lookup(1, 4), position("b.ts", 5, 3));
+    assert_eq!(source_map.lookup(2, 0), None);
+  }
+
+  #[test]
+  fn remap_diagnostics() {
+    use crate::diagnostic::{LintRelatedInformation, Position};
+
+    // The same mappings as in `lookup_positions`
+    let source_map = SourceMap::from_json(
+      r#"{
+        "version": 3,
+        "sources": ["a.ts", "b.ts"],
+        "names": [],
+        "mappings": "AAEA,MAAU,E;E,ECGP"
+      }"#,
+    )
+    .unwrap();
+
+    let position = |line_index, column_index| Position {
+      line_index,
+      column_index,
+      byte_pos: 0,
+    };
+    let range = |start: (usize, usize), end: (usize, usize)| Range {
+      start: position(start.0, start.1),
+      end: position(end.0, end.1),
+    };
+    let related = |range| LintRelatedInformation {
+      range,
+      message: "related".to_string(),
+      filename: None,
+    };
+    let diagnostic = |range, related| LintDiagnostic {
+      range,
+      span: Default::default(),
+      filename: "generated.ts".to_string(),
+      message: "message".to_string(),
+      code: "code".to_string(),
+      sub_code: None,
+      hint: None,
+      fixes: vec![],
+      related,
+      data: None,
+    };
+
+    let mut mapped = diagnostic(
+      range((0, 0), (0, 7)),
+      vec![
+        related(range((1, 4), (1, 6))),
+        // The end has no mapping
+        related(range((0, 0), (0, 9))),
+      ],
+    );
+    source_map.remap_diagnostic(&mut mapped);
+    assert_eq!(mapped.range, range((2, 0), (2, 11)));
+    assert_eq!(mapped.filename, "a.ts");
+    assert_eq!(mapped.related[0].range, range((5, 3), (5, 5)));
+    assert_eq!(mapped.related[0].filename.as_deref(), Some("b.ts"));
+    assert_eq!(mapped.related[1].range, range((0, 0), (0, 9)));
+    assert_eq!(mapped.related[1].filename, None);
+
+    // The start and the end map to different sources
+    let mut unmapped = diagnostic(range((0, 6), (1, 4)), vec![]);
+    source_map.remap_diagnostic(&mut unmapped);
+    assert_eq!(unmapped.range, range((0, 6), (1, 4)));
+    assert_eq!(unmapped.filename, "generated.ts");
+  }
+
+  #[test]
+  fn invalid_source_maps() {
+    assert!(SourceMap::from_json("{}").is_err());
+    assert!(SourceMap::from_json(r#"{"version": 2, "mappings": ""}"#).is_err());
+    assert!(
+      SourceMap::from_json(r#"{"version": 3, "mappings": "AA"}"#).is_err()
+    );
+  }
+}
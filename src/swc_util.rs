@@ -1,12 +1,15 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use crate::scopes::Scope;
 use deno_ast::swc::ast::{
-  BigInt, Bool, ComputedPropName, Expr, Ident, JSXText, Lit, MemberExpr, Null,
-  Number, PatOrExpr, PrivateName, Prop, PropName, PropOrSpread, Regex, Str,
-  Tpl,
+  BigInt, Bool, ClassMember, ComputedPropName, Expr, Ident, JSXAttrName,
+  JSXText, Lit, MemberExpr, Null, Number, Pat, PatOrExpr, PrivateName, Prop,
+  PropName, PropOrSpread, Regex, Str, Tpl,
 };
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::{BytePos, Spanned};
 use deno_ast::swc::utils::{find_ids, ident::IdentLike};
-use deno_ast::view as ast_view;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use std::collections::HashSet;
 
 /// Extracts regex string from an expression, using ScopeManager.
 /// If the passed expression is not regular expression, this will return `None`.
@@ -296,3 +299,89 @@ where
     PatOrExpr::Pat(p) => find_ids(p),
   }
 }
+
+/// Strips the parentheses around an expression, e.g. `a` of `((a))`.
+pub(crate) fn unwrap_paren(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(paren) => unwrap_paren(&paren.expr),
+    _ => expr,
+  }
+}
+
+/// Unwraps `a?.b` so that optional chains count like regular member
+/// expressions.
+pub(crate) fn as_member(expr: &Expr) -> Option<&MemberExpr> {
+  match expr {
+    Expr::Member(member) => Some(member),
+    Expr::OptChain(opt_chain) => match &*opt_chain.expr {
+      Expr::Member(member) => Some(member),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Checks if the expression, ignoring parentheses, is an async function or
+/// arrow function.
+pub(crate) fn is_async_function(expr: &Expr) -> bool {
+  match unwrap_paren(expr) {
+    Expr::Fn(fn_expr) => fn_expr.function.is_async,
+    Expr::Arrow(arrow_expr) => arrow_expr.is_async,
+    _ => false,
+  }
+}
+
+/// Collects the names of the async functions declared anywhere in the
+/// program, e.g. `async function foo() {}` or `const foo = async () => {};`.
+pub(crate) fn async_function_names(
+  program: ast_view::Program,
+) -> HashSet<JsWord> {
+  fn collect(node: Node, names: &mut HashSet<JsWord>) {
+    match node {
+      Node::FnDecl(fn_decl) if fn_decl.function.is_async() => {
+        names.insert(fn_decl.ident.sym().clone());
+      }
+      Node::VarDeclarator(var_declarator) => {
+        if let (Pat::Ident(ident), Some(init)) =
+          (&var_declarator.inner.name, &var_declarator.inner.init)
+        {
+          if is_async_function(init) {
+            names.insert(ident.id.sym.clone());
+          }
+        }
+      }
+      _ => {}
+    }
+    for child in node.children() {
+      collect(child, names);
+    }
+  }
+
+  let mut names = HashSet::new();
+  collect(program.as_node(), &mut names);
+  names
+}
+
+/// Returns the name of a JSX attribute, e.g. `class` or `xlink:href`.
+pub(crate) fn jsx_attr_name(name: &JSXAttrName) -> String {
+  match name {
+    JSXAttrName::Ident(ident) => ident.sym.to_string(),
+    JSXAttrName::JSXNamespacedName(name) => {
+      format!("{}:{}", name.ns.sym, name.name.sym)
+    }
+  }
+}
+
+/// Returns the start of a class member, including its decorators.
+pub(crate) fn member_start(member: &ClassMember) -> BytePos {
+  let decorators = match member {
+    ClassMember::Method(method) => &method.function.decorators,
+    ClassMember::PrivateMethod(method) => &method.function.decorators,
+    ClassMember::ClassProp(prop) => &prop.decorators,
+    ClassMember::PrivateProp(prop) => &prop.decorators,
+    _ => return member.span().lo,
+  };
+  decorators.first().map_or(member.span().lo, |decorator| {
+    decorator.span.lo.min(member.span().lo)
+  })
+}
@@ -7,12 +7,34 @@ use crate::rules::LintRule;
 use deno_ast::view as ast_view;
 use deno_ast::MediaType;
 use deno_ast::ParsedSource;
-use std::marker::PhantomData;
 use std::path::Path;
 use std::sync::Arc;
 
 #[macro_export]
 macro_rules! assert_lint_ok {
+  (
+    $rule:ty,
+    options: $options:expr,
+    filename: $filename:literal,
+    $($src:literal),+
+    $(,)?
+  ) => {
+    $(
+      $crate::test_util::assert_lint_ok_with_rule(
+        <$rule>::with_options($options),
+        $src,
+        $filename,
+      );
+    )*
+  };
+  ($rule:ty, options: $options:expr, $($src:literal),+ $(,)?) => {
+    assert_lint_ok! {
+      $rule,
+      options: $options,
+      filename: "deno_lint_ok_test.ts",
+      $($src,)*
+    };
+  };
   (
     $rule:ty,
     filename: $filename:literal,
@@ -34,6 +56,56 @@ macro_rules! assert_lint_ok {
 
 #[macro_export]
 macro_rules! assert_lint_err {
+  (
+    $rule:ty,
+    options: $options:expr,
+    filename: $filename:literal,
+    $($src:literal : $test:tt),+
+    $(,)?
+  ) => {
+    $(
+      let errors = parse_err_test!($test);
+      let tester = $crate::test_util::LintErrTester::<$rule>::with_rule(
+        <$rule>::with_options($options),
+        $src,
+        errors,
+        $filename,
+      );
+      tester.run();
+    )*
+  };
+  (
+    $rule:ty,
+    options: $options:expr,
+    $($src:literal : $test:tt),+
+    $(,)?
+  ) => {
+    assert_lint_err! {
+      $rule,
+      options: $options,
+      filename: "deno_lint_err_test.ts",
+      $($src: $test,)*
+    }
+  };
+  (
+    $rule: ty,
+    options: $options:expr,
+    $message: expr,
+    $hint: expr,
+    $($src:literal : $test:tt),+
+    $(,)?
+  ) => {
+    $(
+      let errors = parse_err_test!($message, $hint, $test);
+      let tester = $crate::test_util::LintErrTester::<$rule>::with_rule(
+        <$rule>::with_options($options),
+        $src,
+        errors,
+        "deno_lint_err_test.ts",
+      );
+      tester.run();
+    )*
+  };
   (
     $rule:ty,
     filename: $filename:literal,
@@ -155,8 +227,8 @@ macro_rules! parse_err_test {
     ]
   ) => {{
     let errors = parse_err_test!(
-      $(
-        [
+      [
+        $(
           {
             message: $message,
             hint: $hint,
@@ -164,19 +236,18 @@ macro_rules! parse_err_test {
               $field: $value,
             )*
           },
-        ]
-      )*
+        )*
+      ]
     );
     errors
   }};
 }
 
-#[derive(Default)]
 pub struct LintErrTester<T: LintRule + 'static> {
   src: &'static str,
   errors: Vec<LintErr>,
   filename: &'static str,
-  rule: PhantomData<T>,
+  rule: Arc<T>,
 }
 
 impl<T: LintRule + 'static> LintErrTester<T> {
@@ -184,19 +255,29 @@ impl<T: LintRule + 'static> LintErrTester<T> {
     src: &'static str,
     errors: Vec<LintErr>,
     filename: &'static str,
+  ) -> Self {
+    Self::with_rule(T::new(), src, errors, filename)
+  }
+
+  /// Creates a tester that runs the given rule instance, e.g. one that has
+  /// been configured with non-default options.
+  pub fn with_rule(
+    rule: Arc<T>,
+    src: &'static str,
+    errors: Vec<LintErr>,
+    filename: &'static str,
   ) -> Self {
     Self {
       src,
       errors,
       filename,
-      rule: PhantomData,
+      rule,
     }
   }
 
   pub fn run(self) {
-    let rule = T::new();
-    let rule_code = rule.code();
-    let diagnostics = lint(rule, self.src, self.filename);
+    let rule_code = self.rule.code();
+    let diagnostics = lint(self.rule.clone(), self.src, self.filename);
     assert_eq!(
       self.errors.len(),
       diagnostics.len(),
@@ -212,6 +293,10 @@ impl<T: LintRule + 'static> LintErrTester<T> {
         col,
         message,
         hint,
+        fix,
+        related,
+        data,
+        sub_code,
       } = error;
       assert_diagnostic_2(
         diagnostic,
@@ -222,6 +307,34 @@ impl<T: LintRule + 'static> LintErrTester<T> {
         message,
         hint.as_deref(),
       );
+      if let Some((description, fixed_src)) = fix {
+        assert_fix(diagnostic, self.src, description, fixed_src);
+      }
+      if !related.is_empty() {
+        assert_related(diagnostic, self.src, related);
+      }
+      if data.is_some() {
+        assert_eq!(
+          data, &diagnostic.data,
+          "Diagnostic data is expected to be {:?}, but got {:?}\n\nsource:\n{}\n",
+          data, diagnostic.data, self.src
+        );
+      }
+      if let Some(actual) = &diagnostic.sub_code {
+        assert!(
+          self.rule.sub_codes().contains(&actual.as_str()),
+          "Sub-code \"{}\" is not declared by the rule\n\nsource:\n{}\n",
+          actual,
+          self.src
+        );
+      }
+      if sub_code.is_some() {
+        assert_eq!(
+          sub_code, &diagnostic.sub_code,
+          "Diagnostic sub-code is expected to be {:?}, but got {:?}\n\nsource:\n{}\n",
+          sub_code, diagnostic.sub_code, self.src
+        );
+      }
     }
   }
 }
@@ -232,6 +345,14 @@ pub struct LintErr {
   pub col: usize,
   pub message: String,
   pub hint: Option<String>,
+  /// Description of an expected fix and the source after applying it.
+  pub fix: Option<(String, String)>,
+  /// Expected related locations as line, column and message.
+  pub related: Vec<(usize, usize, String)>,
+  /// Expected data attached to the diagnostic.
+  pub data: Option<serde_json::Value>,
+  /// Expected sub-code of the diagnostic.
+  pub sub_code: Option<String>,
 }
 
 #[derive(Default)]
@@ -240,6 +361,10 @@ pub struct LintErrBuilder {
   col: Option<usize>,
   message: Option<String>,
   hint: Option<String>,
+  fix: Option<(String, String)>,
+  related: Vec<(usize, usize, String)>,
+  data: Option<serde_json::Value>,
+  sub_code: Option<String>,
 }
 
 impl LintErrBuilder {
@@ -269,12 +394,44 @@ impl LintErrBuilder {
     self
   }
 
+  pub fn fix(&mut self, fix: (impl ToString, impl ToString)) -> &mut Self {
+    self.fix = Some((fix.0.to_string(), fix.1.to_string()));
+    self
+  }
+
+  /// Add an expected related location; can be called multiple times.
+  pub fn related(
+    &mut self,
+    related: (usize, usize, impl ToString),
+  ) -> &mut Self {
+    // Line is 1-based in deno_lint
+    assert!(related.0 >= 1);
+    self
+      .related
+      .push((related.0, related.1, related.2.to_string()));
+    self
+  }
+
+  pub fn data(&mut self, data: serde_json::Value) -> &mut Self {
+    self.data = Some(data);
+    self
+  }
+
+  pub fn sub_code(&mut self, sub_code: impl ToString) -> &mut Self {
+    self.sub_code = Some(sub_code.to_string());
+    self
+  }
+
   pub fn build(self) -> LintErr {
     LintErr {
       line: self.line.unwrap_or(1),
       col: self.col.unwrap_or(0),
       message: self.message.unwrap_or_else(|| "".to_string()),
       hint: self.hint,
+      fix: self.fix,
+      related: self.related,
+      data: self.data,
+      sub_code: self.sub_code,
     }
   }
 }
@@ -366,11 +523,76 @@ fn assert_diagnostic_2(
   );
 }
 
+fn assert_fix(
+  diagnostic: &LintDiagnostic,
+  source: &str,
+  description: &str,
+  fixed_source: &str,
+) {
+  let fix = diagnostic
+    .fixes
+    .iter()
+    .find(|f| f.description == description)
+    .unwrap_or_else(|| {
+      panic!(
+        "Fix \"{}\" is expected, but got {:?}\n\nsource:\n{}\n",
+        description, diagnostic.fixes, source
+      )
+    });
+
+  let mut changes = fix.changes.iter().collect::<Vec<_>>();
+  changes.sort_by_key(|c| c.range.start.byte_pos);
+  let mut actual = String::new();
+  let mut last_pos = 0;
+  for change in changes {
+    actual.push_str(&source[last_pos..change.range.start.byte_pos]);
+    actual.push_str(&change.new_text);
+    last_pos = change.range.end.byte_pos;
+  }
+  actual.push_str(&source[last_pos..]);
+
+  assert_eq!(
+    fixed_source, actual,
+    "Fixed source is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
+    fixed_source, actual, source
+  );
+}
+
+fn assert_related(
+  diagnostic: &LintDiagnostic,
+  source: &str,
+  expected: &[(usize, usize, String)],
+) {
+  let actual = diagnostic
+    .related
+    .iter()
+    .map(|r| {
+      (
+        r.range.start.line_index + 1,
+        r.range.start.column_index,
+        r.message.clone(),
+      )
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    expected, actual,
+    "Related locations are expected to be {:?}, but got {:?}\n\nsource:\n{}\n",
+    expected, actual, source
+  );
+}
+
 pub fn assert_lint_ok<T: LintRule + 'static>(
   source: &str,
   filename: &'static str,
 ) {
-  let rule = T::new();
+  assert_lint_ok_with_rule(T::new(), source, filename);
+}
+
+pub fn assert_lint_ok_with_rule(
+  rule: Arc<dyn LintRule>,
+  source: &str,
+  filename: &'static str,
+) {
   let diagnostics = lint(rule, source, filename);
   if !diagnostics.is_empty() {
     panic!(
@@ -0,0 +1,2034 @@
+pub type JsWord = :: string_cache :: Atom < JsWordStaticSet > ;
+# [derive (PartialEq , Eq , PartialOrd , Ord)] pub struct JsWordStaticSet ;
+impl :: string_cache :: StaticAtomSet for JsWordStaticSet { fn get () -> & 'static :: string_cache :: PhfStrSet { static SET : :: string_cache :: PhfStrSet = :: string_cache :: PhfStrSet { key : 3213172566270843353u64 , disps : & [(0u32 , 363u32) , (0u32 , 1u32) , (0u32 , 18u32) , (0u32 , 262u32) , (0u32 , 130u32) , (0u32 , 3u32) , (0u32 , 12u32) , (1u32 , 643u32) , (0u32 , 44u32) , (0u32 , 0u32) , (0u32 , 8u32) , (0u32 , 90u32) , (0u32 , 39u32) , (1u32 , 4u32) , (0u32 , 521u32) , (1u32 , 3u32) , (0u32 , 126u32) , (0u32 , 70u32) , (0u32 , 67u32) , (0u32 , 141u32) , (0u32 , 16u32) , (0u32 , 401u32) , (0u32 , 454u32) , (1u32 , 552u32) , (0u32 , 440u32) , (0u32 , 129u32) , (0u32 , 212u32) , (0u32 , 27u32) , (0u32 , 0u32) , (0u32 , 8u32) , (0u32 , 25u32) , (1u32 , 132u32) , (0u32 , 171u32) , (1u32 , 632u32) , (0u32 , 107u32) , (0u32 , 5u32) , (0u32 , 614u32) , (1u32 , 177u32) , (0u32 , 44u32) , (0u32 , 5u32) , (0u32 , 652u32) , (0u32 , 18u32) , (0u32 , 3u32) , (0u32 , 1u32) , (0u32 , 0u32) , (1u32 , 407u32) , (1u32 , 247u32) , (2u32 , 61u32) , (0u32 , 326u32) , (1u32 , 5u32) , (3u32 , 126u32) , (0u32 , 421u32) , (0u32 , 502u32) , (0u32 , 597u32) , (0u32 , 0u32) , (0u32 , 0u32) , (0u32 , 0u32) , (0u32 , 1u32) , (0u32 , 66u32) , (0u32 , 34u32) , (1u32 , 0u32) , (0u32 , 49u32) , (0u32 , 112u32) , (0u32 , 0u32) , (0u32 , 393u32) , (0u32 , 74u32) , (0u32 , 616u32) , (0u32 , 429u32) , (0u32 , 112u32) , (0u32 , 14u32) , (0u32 , 270u32) , (0u32 , 132u32) , (0u32 , 4u32) , (1u32 , 186u32) , (0u32 , 3u32) , (1u32 , 61u32) , (2u32 , 89u32) , (0u32 , 136u32) , (0u32 , 467u32) , (0u32 , 242u32) , (0u32 , 5u32) , (0u32 , 2u32) , (0u32 , 469u32) , (13u32 , 620u32) , (0u32 , 258u32) , (0u32 , 0u32) , (0u32 , 42u32) , (0u32 , 160u32) , (0u32 , 94u32) , (0u32 , 165u32) , (5u32 , 459u32) , (0u32 , 639u32) , (3u32 , 262u32) , (0u32 , 1u32) , (5u32 , 560u32) , (0u32 , 449u32) , (0u32 , 219u32) , (1u32 , 337u32) , (3u32 , 594u32) , (0u32 , 30u32) , (0u32 , 485u32) , (20u32 , 487u32) , (0u32 , 0u32) , (0u32 , 6u32) , (0u32 , 466u32) , (0u32 , 16u32) , (0u32 , 0u32) , (3u32 , 67u32) , (0u32 , 1u32) , (8u32 , 68u32) , (0u32 , 32u32) , (6u32 , 622u32) , (13u32 , 390u32) , (0u32 , 72u32) , (2u32 , 505u32) , (0u32 , 5u32) , (1u32 , 314u32) , (2u32 , 352u32) , (0u32 , 31u32) , (3u32 , 156u32) , (0u32 , 20u32) , (0u32 , 19u32) , (0u32 , 2u32) , (0u32 , 1u32) , (0u32 , 251u32) , (25u32 , 60u32) , (0u32 , 15u32) , (0u32 , 0u32) , (0u32 , 21u32) , (0u32 , 40u32) , (27u32 , 311u32) , (107u32 , 190u32) , (0u32 , 30u32) , (0u32 , 144u32) , (0u32 , 0u32) , (0u32 , 290u32)] , atoms : & ["CSSPageRule",
+"SVGAElement",
+"DOMStringList",
+"RemotePlayback",
+"HTMLMetaElement",
+"SVGAngle",
+"HTMLDivElement",
+"PeriodicWave",
+"SVGAnimationElement",
+"HTMLSpanElement",
+"HTMLHeadingElement",
+"PageTransitionEvent",
+"HTMLImageElement",
+"MediaKeySystemAccess",
+"IDBCursor",
+"SVGTextElement",
+"SVGLinearGradientElement",
+"SVGFEDropShadowElement",
+"HTMLObjectElement",
+"SVGPoint",
+"MediaStreamEvent",
+"SVGUseElement",
+"Uint8ClampedArray",
+"interface",
+"SVGFEMergeElement",
+"PresentationConnectionAvailableEvent",
+"CSSSupportsRule",
+"MediaStreamTrackEvent",
+"throw",
+"DOMParser",
+"SVGAnimatedBoolean",
+"GamepadButton",
+"File",
+"XMLHttpRequest",
+"SourceBufferList",
+"DOMPointReadOnly",
+"WebGLUniformLocation",
+"OscillatorNode",
+"MediaDeviceInfo",
+"const",
+"Intl",
+"RTCDataChannelEvent",
+"Touch",
+"ServiceWorkerRegistration",
+"NonNullable",
+"SVGPolygonElement",
+"DOMQuad",
+"SVGFEImageElement",
+"HTMLTableSectionElement",
+"IDBTransaction",
+"HTMLVideoElement",
+"SVGStopElement",
+"TextEncoder",
+"WebGLVertexArrayObject",
+"RTCSessionDescription",
+"ScriptProcessorNode",
+"Selection",
+"SVGDiscardElement",
+"extends",
+"PluginArray",
+"VisualViewport",
+"Map",
+"MediaEncryptedEvent",
+"RTCDataChannel",
+"SubtleCrypto",
+"SVGPointList",
+"HTMLEmbedElement",
+"MediaStream",
+"HTMLHRElement",
+"DataTransferItemList",
+"HTMLPictureElement",
+"Required",
+"ApplicationCache",
+"Worker",
+"BigInt",
+"PerformanceMeasure",
+"AudioWorkletProcessor",
+"RTCTrackEvent",
+"Atomics",
+"PaymentRequestUpdateEvent",
+"env",
+"SVGFESpotLightElement",
+"for",
+"do",
+"arguments",
+"SVGFECompositeElement",
+"AudioWorkletNode",
+"PushManager",
+"ValidityState",
+"BlobEvent",
+"only",
+"CharacterData",
+"HTMLFontElement",
+"AudioScheduledSourceNode",
+"WebGLShaderPrecisionFormat",
+"ResizeObserver",
+"WebGL2RenderingContext",
+"HTMLOptionsCollection",
+"Boolean",
+"PerformanceResourceTiming",
+"WebGLTexture",
+"VTTCue",
+"XMLHttpRequestUpload",
+"SVGMatrix",
+"HTMLDirectoryElement",
+"SVGViewElement",
+"SVGAnimatedPreserveAspectRatio",
+"HTMLElement",
+"PerformanceNavigation",
+"declare",
+"unknown",
+"SVGAnimateMotionElement",
+"SVGGElement",
+"meta",
+"ImageCapture",
+"AudioListener",
+"Image",
+"TreeWalker",
+"CredentialsContainer",
+"readonly",
+"Response",
+"SVGFEOffsetElement",
+"never",
+"Set",
+"PhotoCapabilities",
+"PannerNode",
+"delete",
+"HTMLModElement",
+"SVGTSpanElement",
+"HTMLDialogElement",
+"PerformanceObserver",
+"IntersectionObserverEntry",
+"IDBCursorWithValue",
+"NamedNodeMap",
+"typeof",
+"Comment",
+"AbortSignal",
+"false",
+"Date",
+"Int32Array",
+"HTMLTableColElement",
+"unique",
+"HTMLDataElement",
+"ChannelSplitterNode",
+"MediaStreamAudioSourceNode",
+"try",
+"AudioNode",
+"PushSubscription",
+"CanvasPattern",
+"createClass",
+"JSON",
+"type",
+"MediaQueryListEvent",
+"FocusEvent",
+"DOMImplementation",
+"SpeechSynthesisUtterance",
+"HTMLMenuElement",
+"PerformancePaintTiming",
+"HTMLDListElement",
+"ReadonlyArray",
+"HTMLStyleElement",
+"main",
+"SVGAnimatedLength",
+"SVGTextContentElement",
+"MimeType",
+"get",
+"SVGTransform",
+"DataView",
+"MediaList",
+"URL",
+"SVGAnimatedEnumeration",
+"CSSConditionRule",
+"MediaStreamTrack",
+"HTMLTimeElement",
+"ClipboardEvent",
+"SVGTitleElement",
+"module",
+"CustomElementRegistry",
+"OfflineAudioContext",
+"WebGLShader",
+"ProcessingInstruction",
+"SVGAnimateTransformElement",
+"SVGFEMorphologyElement",
+"CSSNamespaceRule",
+"DOMTokenList",
+"Option",
+"PerformanceTiming",
+"CanvasRenderingContext2D",
+"SVGDefsElement",
+"WebGLSync",
+"HTMLMeterElement",
+"UIEvent",
+"RTCCertificate",
+"Record",
+"CSSStyleDeclaration",
+"not",
+"any",
+"NODE_ENV",
+"Permissions",
+"undefined",
+"Partial",
+"SVGPreserveAspectRatio",
+"IntersectionObserver",
+"MediaSettingsRange",
+"SVGFETileElement",
+"Uint8Array",
+"StorageEvent",
+"Readonly",
+"MessageChannel",
+"process",
+"while",
+"PresentationConnection",
+"constructor",
+"protected",
+"GamepadEvent",
+"target",
+"CountQueuingStrategy",
+"Object",
+"MediaDevices",
+"HTMLSourceElement",
+"TimeRanges",
+"Screen",
+"set",
+"true",
+"HTMLSelectElement",
+"MediaQueryList",
+"StyleSheet",
+"URIError",
+"Path2D",
+"Infinity",
+"SVGFEDisplacementMapElement",
+"keyof",
+"AbortController",
+"package",
+"DynamicsCompressorNode",
+"MIDIInputMap",
+"TouchList",
+"SVGImageElement",
+"PerformanceMark",
+"HTMLLabelElement",
+"NaN",
+"HTMLOptGroupElement",
+"RTCRtpSender",
+"ReferenceError",
+"HTMLUnknownElement",
+"CSSFontFaceRule",
+"HTMLTableCaptionElement",
+"BigInt64Array",
+"WebGLFramebuffer",
+"SourceBuffer",
+"IDBDatabase",
+"HTMLFormElement",
+"Crypto",
+"StyleSheetList",
+"HTMLTableCellElement",
+"AudioContext",
+"MediaStreamAudioDestinationNode",
+"SVGTransformList",
+"DragEvent",
+"NodeFilter",
+"OfflineAudioCompletionEvent",
+"SVGTextPathElement",
+"SVGFEColorMatrixElement",
+"ErrorEvent",
+"RTCRtpContributingSource",
+"SVGFEBlendElement",
+"HTMLAllCollection",
+"assert",
+"KeyframeEffect",
+"Presentation",
+"TextTrackList",
+"Plugin",
+"PopStateEvent",
+"CSSMediaRule",
+"SVGAnimatedNumberList",
+"SVGFEConvolveMatrixElement",
+"WebGLActiveInfo",
+"SVGFEDistantLightElement",
+"AnalyserNode",
+"SVGFEFuncBElement",
+"DOMStringMap",
+"call",
+"ServiceWorker",
+"from",
+"MessageEvent",
+"enum",
+"HTMLQuoteElement",
+"WebGLBuffer",
+"KeyframeEffectReadOnly",
+"_toConsumableArray",
+"PermissionStatus",
+"createReactClass",
+"Range",
+"bigint",
+"HTMLParagraphElement",
+"break",
+"XSLTProcessor",
+"RTCStatsReport",
+"HTMLHtmlElement",
+"CSSStyleRule",
+"Navigator",
+"CacheStorage",
+"SVGTextPositioningElement",
+"PushSubscriptionOptions",
+"AudioDestinationNode",
+"number",
+"SVGAnimatedInteger",
+"MIDIAccess",
+"Error",
+"PerformanceLongTaskTiming",
+"TransitionEvent",
+"SVGFEMergeNodeElement",
+"TextEvent",
+"is",
+"DeviceOrientationEvent",
+"MediaKeyStatusMap",
+"Blob",
+"EventTarget",
+"DataTransferItem",
+"ConvolverNode",
+"function",
+"XPathExpression",
+"SVGCircleElement",
+"SVGScriptElement",
+"SVGEllipseElement",
+"let",
+"Event",
+"FontFace",
+"SharedArrayBuffer",
+"MutationObserver",
+"CSSKeyframesRule",
+"ReturnType",
+"SVGElement",
+"ResizeObserverEntry",
+"with",
+"WebAssembly",
+"XPathResult",
+"HTMLFrameElement",
+"MIDIInput",
+"HTMLBodyElement",
+"important",
+"WebGLQuery",
+"",
+"override",
+"MIDIMessageEvent",
+"WeakMap",
+"SVGDescElement",
+"ArrayBuffer",
+"RegExp",
+"FontFaceSetLoadEvent",
+"XMLSerializer",
+"RTCSctpTransport",
+"IDBObjectStore",
+"WebSocket",
+"Pick",
+"null",
+"RTCRtpReceiver",
+"XMLDocument",
+"async",
+"ShadowRoot",
+"SVGFEFuncAElement",
+"HTMLContentElement",
+"DOMRect",
+"SVGUnitTypes",
+"SVGAnimatedRect",
+"Number",
+"SVGFEFloodElement",
+"export",
+"WaveShaperNode",
+"DOMRectReadOnly",
+"DOMException",
+"Extract",
+"Float64Array",
+"SVGComponentTransferFunctionElement",
+"DOMError",
+"SVGMarkerElement",
+"IDBVersionChangeEvent",
+"ReadableStream",
+"SVGPathElement",
+"InputEvent",
+"StaticRange",
+"HTMLTitleElement",
+"var",
+"HTMLDocument",
+"React",
+"HTMLFormControlsCollection",
+"XPathEvaluator",
+"CSS",
+"case",
+"String",
+"Request",
+"CryptoKey",
+"SVGAnimatedString",
+"HTMLDataListElement",
+"HTMLLinkElement",
+"MessagePort",
+"HTMLScriptElement",
+"SVGForeignObjectElement",
+"Attr",
+"CanvasCaptureMediaStreamTrack",
+"PromiseRejectionEvent",
+"public",
+"HTMLSlotElement",
+"SVGMetadataElement",
+"Node",
+"Uint32Array",
+"Array",
+"HTMLInputElement",
+"SVGAnimatedAngle",
+"concat",
+"DelayNode",
+"AnimationPlaybackEvent",
+"MimeTypeArray",
+"HTMLAnchorElement",
+"HTMLPreElement",
+"CSSKeyframeRule",
+"MediaElementAudioSourceNode",
+"NetworkInformation",
+"Element",
+"SVGSetElement",
+"TaskAttributionTiming",
+"SVGSVGElement",
+"DOMPoint",
+"DocumentFragment",
+"MediaSource",
+"SVGRadialGradientElement",
+"SVGClipPathElement",
+"BaseAudioContext",
+"SVGFESpecularLightingElement",
+"SVGSymbolElement",
+"AudioBufferSourceNode",
+"BroadcastChannel",
+"HTMLTrackElement",
+"HTMLBaseElement",
+"HTMLOutputElement",
+"length",
+"private",
+"HTMLLIElement",
+"HTMLAudioElement",
+"SVGAnimatedTransformList",
+"PresentationRequest",
+"WebGLRenderbuffer",
+"object",
+"void",
+"SVGPolylineElement",
+"NodeIterator",
+"TextTrackCue",
+"symbol",
+"SVGPatternElement",
+"RTCIceTransport",
+"MediaError",
+"ChannelMergerNode",
+"GainNode",
+"TextTrackCueList",
+"HTMLTemplateElement",
+"continue",
+"EvalError",
+"IDBIndex",
+"IDBRequest",
+"MediaKeySession",
+"Symbol",
+"PerformanceObserverEntryList",
+"namespace",
+"Animation",
+"FormData",
+"AudioParam",
+"implements",
+"global",
+"AnimationEvent",
+"WebGLProgram",
+"CSSStyleSheet",
+"History",
+"CSSRuleList",
+"IDBOpenDBRequest",
+"WebGLSampler",
+"PresentationConnectionList",
+"HTMLFrameSetElement",
+"ConstantSourceNode",
+"MouseEvent",
+"_extends",
+"SVGGeometryElement",
+"SVGLineElement",
+"SVGRect",
+"Int16Array",
+"this",
+"ApplicationCacheErrorEvent",
+"SVGLength",
+"asserts",
+"or",
+"require",
+"WheelEvent",
+"NodeList",
+"DOMMatrixReadOnly",
+"Uint16Array",
+"HTMLTextAreaElement",
+"HTMLProgressElement",
+"Cache",
+"PerformanceNavigationTiming",
+"CSSImportRule",
+"ByteLengthQueuingStrategy",
+"Float32Array",
+"Promise",
+"CompositionEvent",
+"Proxy",
+"HTMLHeadElement",
+"HTMLOListElement",
+"SVGNumberList",
+"as",
+"SVGAnimatedLengthList",
+"Bottom line",
+"if",
+"Text",
+"CSSRule",
+"ImageBitmapRenderingContext",
+"abstract",
+"SecurityPolicyViolationEvent",
+"Credential",
+"AnimationTimeline",
+"HTMLOptionElement",
+"HTMLIFrameElement",
+"AnimationEffectTimingReadOnly",
+"finally",
+"WebGLRenderingContext",
+"WeakSet",
+"Exclude",
+"SVGAnimateElement",
+"RTCDtlsTransport",
+"SVGLengthList",
+"WebGLTransformFeedback",
+"Document",
+"class",
+"PresentationReceiver",
+"Headers",
+"ImageData",
+"Math",
+"HTMLButtonElement",
+"AnimationEffectTiming",
+"MediaKeyMessageEvent",
+"SVGFEDiffuseLightingElement",
+"of",
+"MIDIPort",
+"instanceof",
+"SVGGradientElement",
+"MediaRecorder",
+"IDBFactory",
+"BudgetService",
+"FileList",
+"FileReader",
+"BatteryManager",
+"IIRFilterNode",
+"OffscreenCanvas",
+"string",
+"StereoPannerNode",
+"DocumentType",
+"url",
+"AudioWorkletGlobalScope",
+"Audio",
+"HTMLBRElement",
+"SVGFEFuncGElement",
+"default",
+"infer",
+"ServiceWorkerContainer",
+"Reflect",
+"HTMLMapElement",
+"BigUint64Array",
+"PaymentRequest",
+"HTMLParamElement",
+"SVGGraphicsElement",
+"switch",
+"SVGFilterElement",
+"RangeError",
+"SVGStringList",
+"super",
+"eval",
+"import",
+"SVGNumber",
+"Int8Array",
+"SVGFETurbulenceElement",
+"RTCIceGatherer",
+"intrinsic",
+"Performance",
+"displayName",
+"BeforeUnloadEvent",
+"ScreenOrientation",
+"ImageBitmap",
+"AudioProcessingEvent",
+"HTMLLegendElement",
+"SVGFEPointLightElement",
+"HTMLMarqueeElement",
+"HTMLDetailsElement",
+"SpeechSynthesisEvent",
+"XMLHttpRequestEventTarget",
+"_defineProperty",
+"CustomEvent",
+"BiquadFilterNode",
+"Notification",
+"in",
+"SVGFEFuncRElement",
+"SVGStyleElement",
+"Storage",
+"HTMLMediaElement",
+"key",
+"SVGFEGaussianBlurElement",
+"KeyboardEvent",
+"ProgressEvent",
+"HTMLCanvasElement",
+"RTCPeerConnectionIceEvent",
+"SVGRectElement",
+"HTMLAreaElement",
+"return",
+"TextTrack",
+"yield",
+"HTMLUListElement",
+"TypeError",
+"HashChangeEvent",
+"MutationRecord",
+"CSSGroupingRule",
+"PerformanceEntry",
+"DataTransfer",
+"SVGFEComponentTransferElement",
+"WritableStream",
+"toString",
+"CloseEvent",
+"SVGMPathElement",
+"SVGAnimatedNumber",
+"catch",
+"static",
+"EventSource",
+"DOMMatrix",
+"PaymentAddress",
+"IDBKeyRange",
+"TextDecoder",
+"BarProp",
+"boolean",
+"RTCIceCandidate",
+"HTMLTableElement",
+"TrackEvent",
+"AudioBuffer",
+"new",
+"SharedWorker",
+"IdleDeadline",
+"StorageManager",
+"WebGLContextEvent",
+"PointerEvent",
+"Location",
+"HTMLCollection",
+"HTMLFieldSetElement",
+"await",
+"SyntaxError",
+"apply",
+"TextMetrics",
+"Function",
+"RadioNodeList",
+"Gamepad",
+"DeviceMotionEvent",
+"CanvasGradient",
+"MIDIConnectionEvent",
+"NavigationPreloadManager",
+"AnimationEffectReadOnly",
+"iterator",
+"PresentationAvailability",
+"MIDIOutput",
+"MIDIOutputMap",
+"and",
+"HTMLShadowElement",
+"URLSearchParams",
+"PaymentResponse",
+"RTCPeerConnection",
+"MutationEvent",
+"SVGMaskElement",
+"SVGSwitchElement",
+"debugger",
+"TouchEvent",
+"HTMLTableRowElement",
+"else",
+"PresentationConnectionCloseEvent",
+"Window"] , hashes : & [3288576901u32 , 296425511u32 , 3514715315u32 , 1977613651u32 , 1497934608u32 , 3920230716u32 , 915619911u32 , 3559054908u32 , 1529314439u32 , 1920741745u32 , 2383571796u32 , 923034639u32 , 2212121u32 , 3453503251u32 , 2268510045u32 , 116069763u32 , 3463009791u32 , 4166886992u32 , 3917303287u32 , 2855520390u32 , 560184280u32 , 1443260988u32 , 3449531232u32 , 1452970328u32 , 784203666u32 , 2247983443u32 , 395974295u32 , 3487489887u32 , 3113457299u32 , 1664055964u32 , 239646434u32 , 85079458u32 , 1351961950u32 , 1365159887u32 , 3083648741u32 , 1183197979u32 , 107280596u32 , 1646977854u32 , 468255646u32 , 2369497060u32 , 4255604704u32 , 1797947856u32 , 3440145562u32 , 3040515297u32 , 613422906u32 , 2745166894u32 , 262643759u32 , 2981796005u32 , 281322u32 , 2350564310u32 , 3936527404u32 , 3426789763u32 , 1811848551u32 , 1639632979u32 , 1522405627u32 , 2515945078u32 , 2059894387u32 , 4202304162u32 , 1531530418u32 , 3244144174u32 , 1215544256u32 , 1416792399u32 , 4200401072u32 , 269157366u32 , 3992593545u32 , 3491536087u32 , 768038347u32 , 2954339156u32 , 3172637696u32 , 928459760u32 , 421414040u32 , 234196700u32 , 2827988746u32 , 3511538290u32 , 2514808636u32 , 1625809356u32 , 2213044933u32 , 487335722u32 , 2373277982u32 , 1560750395u32 , 2249065658u32 , 3424129296u32 , 986749529u32 , 3947890096u32 , 2965180208u32 , 151349083u32 , 1760054978u32 , 2771692790u32 , 1892695651u32 , 2646085027u32 , 3014238897u32 , 1727180071u32 , 563957993u32 , 3816808173u32 , 2412555377u32 , 572786561u32 , 968179389u32 , 2241640728u32 , 1238021005u32 , 1642490507u32 , 2823364092u32 , 1595609221u32 , 4167203121u32 , 942263424u32 , 1198555702u32 , 498643228u32 , 1035315019u32 , 1135885120u32 , 1399290210u32 , 3284239627u32 , 4081200593u32 , 167104980u32 , 2194419037u32 , 4276227721u32 , 2141069667u32 , 3724599155u32 , 3864087753u32 , 3836569275u32 , 3600433181u32 , 1011923177u32 , 932361363u32 , 2925334099u32 , 3199904255u32 , 2998567516u32 , 1470010477u32 , 1842966164u32 , 3798435140u32 , 782521661u32 , 3315685484u32 , 3057607136u32 , 4284137451u32 , 3060347325u32 , 3203259623u32 , 1900532562u32 , 690396162u32 , 1560143096u32 , 1381077813u32 , 3690608760u32 , 3322520225u32 , 3453396522u32 , 4005025102u32 , 600034016u32 , 1254682398u32 , 3372400342u32 , 1030071206u32 , 4293851495u32 , 985602191u32 , 1131124537u32 , 1981866657u32 , 3512881405u32 , 2340502400u32 , 105740319u32 , 1646156375u32 , 455679012u32 , 68247981u32 , 2787749704u32 , 574809078u32 , 1763366759u32 , 3879723064u32 , 3612242473u32 , 2688308486u32 , 3341489812u32 , 196701240u32 , 1669420391u32 , 3854687779u32 , 1720636687u32 , 2648630063u32 , 3743631801u32 , 2436293057u32 , 1316556409u32 , 3067625418u32 , 3836096566u32 , 340984934u32 , 1044809875u32 , 321510023u32 , 2836527250u32 , 843786125u32 , 3296082502u32 , 2600085926u32 , 1760595023u32 , 4174801272u32 , 2523378005u32 , 3668505280u32 , 1049064311u32 , 731902597u32 , 1610366598u32 , 3019931871u32 , 1617631868u32 , 4096972031u32 , 669622459u32 , 2701681989u32 , 390748476u32 , 1894264357u32 , 324054949u32 , 159327594u32 , 1638024518u32 , 3928803828u32 , 3509948974u32 , 795077585u32 , 1116891278u32 , 2148621014u32 , 4162837622u32 , 3008621434u32 , 2356134714u32 , 2318754203u32 , 381693574u32 , 3963245549u32 , 2205017242u32 , 3467279839u32 , 3784242375u32 , 471176764u32 , 3271244037u32 , 568273345u32 , 2724727354u32 , 1033613498u32 , 891539590u32 , 187476584u32 , 4118034526u32 , 3461869320u32 , 3938598891u32 , 1901607263u32 , 2045805560u32 , 761794092u32 , 339846337u32 , 1471174208u32 , 1774291977u32 , 3514491607u32 , 2957308527u32 , 659537035u32 , 2127019096u32 , 304012374u32 , 3662844837u32 , 1722670728u32 , 4011026544u32 , 1286845998u32 , 2305926713u32 , 3460031232u32 , 636000157u32 , 2207718331u32 , 1146035977u32 , 895919703u32 , 2536628134u32 , 4118553759u32 , 1158173423u32 , 658183197u32 , 3937141258u32 , 436417350u32 , 3316135312u32 , 4266996031u32 , 847813585u32 , 696104403u32 , 4037920297u32 , 1008103082u32 , 578233565u32 , 583446228u32 , 3744611603u32 , 1033762332u32 , 745759105u32 , 1805831459u32 , 4043500993u32 , 2276962245u32 , 1342014888u32 , 629339763u32 , 1317689864u32 , 3689555529u32 , 2854094278u32 , 524160850u32 , 3547986469u32 , 3371888436u32 , 582674777u32 , 3853885537u32 , 1942213122u32 , 3101570734u32 , 608888259u32 , 2193519674u32 , 2289960586u32 , 2099546614u32 , 2388095709u32 , 4175720922u32 , 1267335111u32 , 3423362009u32 , 4184764933u32 , 2274096630u32 , 2768425519u32 , 1142525895u32 , 1330314420u32 , 3757958546u32 , 4026616063u32 , 2504336514u32 , 4101980282u32 , 1913319748u32 , 10771604u32 , 2821662150u32 , 2657498402u32 , 85061397u32 , 3064670151u32 , 290452130u32 , 2188357707u32 , 3540019895u32 , 1070128560u32 , 841205649u32 , 289389167u32 , 1995603835u32 , 3740162206u32 , 2489085532u32 , 344839749u32 , 3088460997u32 , 3275974076u32 , 1021788190u32 , 1130982120u32 , 635225265u32 , 3767031504u32 , 3941827392u32 , 2896622824u32 , 360034778u32 , 2800117505u32 , 556795497u32 , 4049286798u32 , 1379814875u32 , 1683995826u32 , 140314808u32 , 4102762383u32 , 3491332455u32 , 289133518u32 , 4255017156u32 , 3117853114u32 , 4201706185u32 , 3218147222u32 , 2887163242u32 , 2595025810u32 , 2394645847u32 , 2176424236u32 , 3065570288u32 , 174578631u32 , 1651632915u32 , 1143031178u32 , 1465126142u32 , 1130752359u32 , 2619457293u32 , 1059875425u32 , 2949218592u32 , 1951281781u32 , 502452354u32 , 660927748u32 , 1366842948u32 , 2395173343u32 , 455430331u32 , 3272620689u32 , 3936046242u32 , 1117466920u32 , 4137764610u32 , 3773803874u32 , 114240722u32 , 1617974593u32 , 258018464u32 , 2453996919u32 , 1648332718u32 , 2960921614u32 , 2993493318u32 , 4041818123u32 , 2118280493u32 , 3465153126u32 , 636493557u32 , 2150384250u32 , 345532885u32 , 1495935975u32 , 981200442u32 , 559733247u32 , 256493250u32 , 1593811461u32 , 940156512u32 , 191163422u32 , 621257903u32 , 193441311u32 , 3449122843u32 , 715753731u32 , 3263099358u32 , 1185171220u32 , 1462974044u32 , 2354062395u32 , 1520356877u32 , 2849498735u32 , 1642745889u32 , 345958830u32 , 1687498249u32 , 3316845975u32 , 1940621185u32 , 418181185u32 , 2674689296u32 , 3844806042u32 , 200147756u32 , 172777032u32 , 2271993756u32 , 41534173u32 , 2324656777u32 , 199214371u32 , 974542026u32 , 1235762146u32 , 3772119530u32 , 1155843280u32 , 2830027367u32 , 4167087931u32 , 1685964000u32 , 3312025375u32 , 3376072095u32 , 1268166875u32 , 1280767947u32 , 3891231915u32 , 2099692389u32 , 3223187039u32 , 2285813906u32 , 2296479198u32 , 3213135135u32 , 4153332705u32 , 2096458825u32 , 2481871385u32 , 1504404303u32 , 2415199606u32 , 2374713575u32 , 712671188u32 , 1993960115u32 , 13342382u32 , 377076666u32 , 1552840048u32 , 1633164065u32 , 2805212385u32 , 3657592077u32 , 2644480790u32 , 1375075388u32 , 4118815577u32 , 1897584230u32 , 3106667683u32 , 892251585u32 , 2097394270u32 , 2584793383u32 , 2710801707u32 , 2545764835u32 , 2739978254u32 , 3287004975u32 , 3018293251u32 , 2240564550u32 , 3408629689u32 , 445395086u32 , 998299201u32 , 2429226143u32 , 2803845748u32 , 1739632623u32 , 2688899723u32 , 4032167574u32 , 3389518863u32 , 1904697642u32 , 132104193u32 , 3345997567u32 , 3668908555u32 , 389947242u32 , 2360633700u32 , 2462758710u32 , 510769903u32 , 1865438204u32 , 2359140076u32 , 1514644820u32 , 382677747u32 , 4134327717u32 , 3365899334u32 , 4057745646u32 , 4025426445u32 , 1811357768u32 , 1069213895u32 , 2718589538u32 , 3843470358u32 , 2238985831u32 , 2723113929u32 , 2094195367u32 , 4200818437u32 , 910588129u32 , 896411513u32 , 1286055327u32 , 898825909u32 , 194933619u32 , 4004643267u32 , 1610438746u32 , 112162387u32 , 605391354u32 , 3036258959u32 , 1599800443u32 , 3041655862u32 , 3045303584u32 , 534699730u32 , 334602519u32 , 3999943333u32 , 1051848258u32 , 79475083u32 , 1032974454u32 , 2868715114u32 , 2272944756u32 , 1488137756u32 , 3515273669u32 , 2262231881u32 , 1046257078u32 , 2916090672u32 , 4171487858u32 , 3670895176u32 , 3039645427u32 , 1778103280u32 , 922801009u32 , 3219506263u32 , 1290453487u32 , 2281890085u32 , 2182116144u32 , 3586809196u32 , 1420562131u32 , 2393327141u32 , 2457762065u32 , 1125057186u32 , 239608593u32 , 1993180800u32 , 1142414332u32 , 3009584989u32 , 1045979327u32 , 1198960630u32 , 584714108u32 , 147999670u32 , 4001897753u32 , 1984325791u32 , 2669348838u32 , 2443863163u32 , 746478712u32 , 851165702u32 , 2324239996u32 , 1136598548u32 , 1886512265u32 , 2300631894u32 , 2387610176u32 , 3001390998u32 , 2478150161u32 , 3530157349u32 , 872209436u32 , 2822401152u32 , 40628779u32 , 1568053175u32 , 2529444310u32 , 2543985816u32 , 3564168790u32 , 3351391990u32 , 3934811011u32 , 2044944197u32 , 2577057637u32 , 940806613u32 , 4062076892u32 , 297078463u32 , 188193410u32 , 2345830539u32 , 2597570583u32 , 2011382948u32 , 3222054822u32 , 2133611570u32 , 2324328041u32 , 2749782489u32 , 2839936026u32 , 1071548865u32 , 852397821u32 , 2630646050u32 , 1740719784u32 , 3803698734u32 , 728129095u32 , 2837556578u32 , 1139020281u32 , 2893250983u32 , 1501000134u32 , 3027793596u32 , 2971368104u32 , 315121988u32 , 3929983713u32 , 672602458u32 , 3703039095u32 , 1922586868u32 , 2559426646u32 , 3279138710u32 , 4069615757u32 , 1271636253u32 , 3990974019u32 , 406665058u32 , 778801035u32 , 2236695830u32 , 3618722919u32 , 4101923592u32 , 1967742445u32 , 1279107316u32 , 3222053916u32 , 3541773273u32 , 3283980171u32 , 4266761903u32 , 2785152233u32 , 1370047959u32 , 1473516638u32 , 424421875u32 , 2702364577u32 , 1183737753u32 , 3622415074u32 , 3031162714u32 , 1226783410u32 , 1119422612u32 , 2026377482u32 , 372147083u32 , 1867495804u32 , 3192413132u32 , 3721663223u32 , 3253713072u32 , 2091788840u32 , 218811137u32 , 2666275346u32 , 3864207787u32 , 3894441991u32 , 2745553241u32 , 3132522805u32 , 3843711801u32 , 814985913u32 , 3248307669u32 , 3218594507u32 , 691788130u32 , 3110101654u32 , 3403433710u32 , 3260556010u32 , 3690919996u32 , 2867516599u32 , 726453616u32 , 898307990u32 , 873770716u32 , 749310818u32 , 3826234358u32 , 3292202049u32 , 2309382065u32 , 3719520472u32 , 1776309820u32 , 3231142833u32 , 3396691953u32 , 1546042048u32 , 3433209661u32 , 1609916306u32 , 1768806124u32 , 1191688316u32 , 1012269256u32 , 3575327806u32 , 2407576510u32 , 2127666898u32 , 1032176497u32 , 1787245541u32 , 904304175u32 , 2517785143u32 , 2553058516u32 , 246393020u32 , 83637011u32 , 1013207636u32 , 3209640755u32 , 476031208u32 , 2019518601u32 , 3542752545u32 , 4222175830u32 , 1515645539u32 , 3404090231u32 , 2657972586u32 , 2802901053u32 , 1822206107u32 , 1579436359u32 , 1547689923u32 , 805632453u32 , 2317663439u32 , 2311508062u32 , 1361626078u32 , 3024344926u32 , 1286409199u32 , 774671413u32 , 3161340027u32 , 3495407234u32 , 3467782733u32 , 1434990131u32 , 621212355u32] } ;
+& SET } fn empty_string_index () -> u32 { 342u32 } } pub const ATOM_JSWORD__43_53_53_50_61_67_65_52_75_6C_65 : JsWord = JsWord :: pack_static (0u32) ;
+pub const ATOM_JSWORD__53_56_47_41_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (1u32) ;
+pub const ATOM_JSWORD__44_4F_4D_53_74_72_69_6E_67_4C_69_73_74 : JsWord = JsWord :: pack_static (2u32) ;
+pub const ATOM_JSWORD__52_65_6D_6F_74_65_50_6C_61_79_62_61_63_6B : JsWord = JsWord :: pack_static (3u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4D_65_74_61_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (4u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_67_6C_65 : JsWord = JsWord :: pack_static (5u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_44_69_76_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (6u32) ;
+pub const ATOM_JSWORD__50_65_72_69_6F_64_69_63_57_61_76_65 : JsWord = JsWord :: pack_static (7u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_69_6F_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (8u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_53_70_61_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (9u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_48_65_61_64_69_6E_67_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (10u32) ;
+pub const ATOM_JSWORD__50_61_67_65_54_72_61_6E_73_69_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (11u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_49_6D_61_67_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (12u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_4B_65_79_53_79_73_74_65_6D_41_63_63_65_73_73 : JsWord = JsWord :: pack_static (13u32) ;
+pub const ATOM_JSWORD__49_44_42_43_75_72_73_6F_72 : JsWord = JsWord :: pack_static (14u32) ;
+pub const ATOM_JSWORD__53_56_47_54_65_78_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (15u32) ;
+pub const ATOM_JSWORD__53_56_47_4C_69_6E_65_61_72_47_72_61_64_69_65_6E_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (16u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_44_72_6F_70_53_68_61_64_6F_77_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (17u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4F_62_6A_65_63_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (18u32) ;
+pub const ATOM_JSWORD__53_56_47_50_6F_69_6E_74 : JsWord = JsWord :: pack_static (19u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D_45_76_65_6E_74 : JsWord = JsWord :: pack_static (20u32) ;
+pub const ATOM_JSWORD__53_56_47_55_73_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (21u32) ;
+pub const ATOM_JSWORD__55_69_6E_74_38_43_6C_61_6D_70_65_64_41_72_72_61_79 : JsWord = JsWord :: pack_static (22u32) ;
+pub const ATOM_JSWORD__69_6E_74_65_72_66_61_63_65 : JsWord = JsWord :: pack_static (23u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_4D_65_72_67_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (24u32) ;
+pub const ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_43_6F_6E_6E_65_63_74_69_6F_6E_41_76_61_69_6C_61_62_6C_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (25u32) ;
+pub const ATOM_JSWORD__43_53_53_53_75_70_70_6F_72_74_73_52_75_6C_65 : JsWord = JsWord :: pack_static (26u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D_54_72_61_63_6B_45_76_65_6E_74 : JsWord = JsWord :: pack_static (27u32) ;
+pub const ATOM_JSWORD__74_68_72_6F_77 : JsWord = JsWord :: pack_static (28u32) ;
+pub const ATOM_JSWORD__44_4F_4D_50_61_72_73_65_72 : JsWord = JsWord :: pack_static (29u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_42_6F_6F_6C_65_61_6E : JsWord = JsWord :: pack_static (30u32) ;
+pub const ATOM_JSWORD__47_61_6D_65_70_61_64_42_75_74_74_6F_6E : JsWord = JsWord :: pack_static (31u32) ;
+pub const ATOM_JSWORD__46_69_6C_65 : JsWord = JsWord :: pack_static (32u32) ;
+pub const ATOM_JSWORD__58_4D_4C_48_74_74_70_52_65_71_75_65_73_74 : JsWord = JsWord :: pack_static (33u32) ;
+pub const ATOM_JSWORD__53_6F_75_72_63_65_42_75_66_66_65_72_4C_69_73_74 : JsWord = JsWord :: pack_static (34u32) ;
+pub const ATOM_JSWORD__44_4F_4D_50_6F_69_6E_74_52_65_61_64_4F_6E_6C_79 : JsWord = JsWord :: pack_static (35u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_55_6E_69_66_6F_72_6D_4C_6F_63_61_74_69_6F_6E : JsWord = JsWord :: pack_static (36u32) ;
+pub const ATOM_JSWORD__4F_73_63_69_6C_6C_61_74_6F_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (37u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_44_65_76_69_63_65_49_6E_66_6F : JsWord = JsWord :: pack_static (38u32) ;
+pub const ATOM_JSWORD__63_6F_6E_73_74 : JsWord = JsWord :: pack_static (39u32) ;
+pub const ATOM_JSWORD__49_6E_74_6C : JsWord = JsWord :: pack_static (40u32) ;
+pub const ATOM_JSWORD__52_54_43_44_61_74_61_43_68_61_6E_6E_65_6C_45_76_65_6E_74 : JsWord = JsWord :: pack_static (41u32) ;
+pub const ATOM_JSWORD__54_6F_75_63_68 : JsWord = JsWord :: pack_static (42u32) ;
+pub const ATOM_JSWORD__53_65_72_76_69_63_65_57_6F_72_6B_65_72_52_65_67_69_73_74_72_61_74_69_6F_6E : JsWord = JsWord :: pack_static (43u32) ;
+pub const ATOM_JSWORD__4E_6F_6E_4E_75_6C_6C_61_62_6C_65 : JsWord = JsWord :: pack_static (44u32) ;
+pub const ATOM_JSWORD__53_56_47_50_6F_6C_79_67_6F_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (45u32) ;
+pub const ATOM_JSWORD__44_4F_4D_51_75_61_64 : JsWord = JsWord :: pack_static (46u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_49_6D_61_67_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (47u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_53_65_63_74_69_6F_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (48u32) ;
+pub const ATOM_JSWORD__49_44_42_54_72_61_6E_73_61_63_74_69_6F_6E : JsWord = JsWord :: pack_static (49u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_56_69_64_65_6F_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (50u32) ;
+pub const ATOM_JSWORD__53_56_47_53_74_6F_70_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (51u32) ;
+pub const ATOM_JSWORD__54_65_78_74_45_6E_63_6F_64_65_72 : JsWord = JsWord :: pack_static (52u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_56_65_72_74_65_78_41_72_72_61_79_4F_62_6A_65_63_74 : JsWord = JsWord :: pack_static (53u32) ;
+pub const ATOM_JSWORD__52_54_43_53_65_73_73_69_6F_6E_44_65_73_63_72_69_70_74_69_6F_6E : JsWord = JsWord :: pack_static (54u32) ;
+pub const ATOM_JSWORD__53_63_72_69_70_74_50_72_6F_63_65_73_73_6F_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (55u32) ;
+pub const ATOM_JSWORD__53_65_6C_65_63_74_69_6F_6E : JsWord = JsWord :: pack_static (56u32) ;
+pub const ATOM_JSWORD__53_56_47_44_69_73_63_61_72_64_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (57u32) ;
+pub const ATOM_JSWORD__65_78_74_65_6E_64_73 : JsWord = JsWord :: pack_static (58u32) ;
+pub const ATOM_JSWORD__50_6C_75_67_69_6E_41_72_72_61_79 : JsWord = JsWord :: pack_static (59u32) ;
+pub const ATOM_JSWORD__56_69_73_75_61_6C_56_69_65_77_70_6F_72_74 : JsWord = JsWord :: pack_static (60u32) ;
+pub const ATOM_JSWORD__4D_61_70 : JsWord = JsWord :: pack_static (61u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_45_6E_63_72_79_70_74_65_64_45_76_65_6E_74 : JsWord = JsWord :: pack_static (62u32) ;
+pub const ATOM_JSWORD__52_54_43_44_61_74_61_43_68_61_6E_6E_65_6C : JsWord = JsWord :: pack_static (63u32) ;
+pub const ATOM_JSWORD__53_75_62_74_6C_65_43_72_79_70_74_6F : JsWord = JsWord :: pack_static (64u32) ;
+pub const ATOM_JSWORD__53_56_47_50_6F_69_6E_74_4C_69_73_74 : JsWord = JsWord :: pack_static (65u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_45_6D_62_65_64_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (66u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D : JsWord = JsWord :: pack_static (67u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_48_52_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (68u32) ;
+pub const ATOM_JSWORD__44_61_74_61_54_72_61_6E_73_66_65_72_49_74_65_6D_4C_69_73_74 : JsWord = JsWord :: pack_static (69u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_50_69_63_74_75_72_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (70u32) ;
+pub const ATOM_JSWORD__52_65_71_75_69_72_65_64 : JsWord = JsWord :: pack_static (71u32) ;
+pub const ATOM_JSWORD__41_70_70_6C_69_63_61_74_69_6F_6E_43_61_63_68_65 : JsWord = JsWord :: pack_static (72u32) ;
+pub const ATOM_JSWORD__57_6F_72_6B_65_72 : JsWord = JsWord :: pack_static (73u32) ;
+pub const ATOM_JSWORD__42_69_67_49_6E_74 : JsWord = JsWord :: pack_static (74u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4D_65_61_73_75_72_65 : JsWord = JsWord :: pack_static (75u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_57_6F_72_6B_6C_65_74_50_72_6F_63_65_73_73_6F_72 : JsWord = JsWord :: pack_static (76u32) ;
+pub const ATOM_JSWORD__52_54_43_54_72_61_63_6B_45_76_65_6E_74 : JsWord = JsWord :: pack_static (77u32) ;
+pub const ATOM_JSWORD__41_74_6F_6D_69_63_73 : JsWord = JsWord :: pack_static (78u32) ;
+pub const ATOM_JSWORD__50_61_79_6D_65_6E_74_52_65_71_75_65_73_74_55_70_64_61_74_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (79u32) ;
+pub const ATOM_JSWORD__65_6E_76 : JsWord = JsWord :: pack_static (80u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_53_70_6F_74_4C_69_67_68_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (81u32) ;
+pub const ATOM_JSWORD__66_6F_72 : JsWord = JsWord :: pack_static (82u32) ;
+pub const ATOM_JSWORD__64_6F : JsWord = JsWord :: pack_static (83u32) ;
+pub const ATOM_JSWORD__61_72_67_75_6D_65_6E_74_73 : JsWord = JsWord :: pack_static (84u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_43_6F_6D_70_6F_73_69_74_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (85u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_57_6F_72_6B_6C_65_74_4E_6F_64_65 : JsWord = JsWord :: pack_static (86u32) ;
+pub const ATOM_JSWORD__50_75_73_68_4D_61_6E_61_67_65_72 : JsWord = JsWord :: pack_static (87u32) ;
+pub const ATOM_JSWORD__56_61_6C_69_64_69_74_79_53_74_61_74_65 : JsWord = JsWord :: pack_static (88u32) ;
+pub const ATOM_JSWORD__42_6C_6F_62_45_76_65_6E_74 : JsWord = JsWord :: pack_static (89u32) ;
+pub const ATOM_JSWORD__6F_6E_6C_79 : JsWord = JsWord :: pack_static (90u32) ;
+pub const ATOM_JSWORD__43_68_61_72_61_63_74_65_72_44_61_74_61 : JsWord = JsWord :: pack_static (91u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_46_6F_6E_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (92u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_53_63_68_65_64_75_6C_65_64_53_6F_75_72_63_65_4E_6F_64_65 : JsWord = JsWord :: pack_static (93u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_53_68_61_64_65_72_50_72_65_63_69_73_69_6F_6E_46_6F_72_6D_61_74 : JsWord = JsWord :: pack_static (94u32) ;
+pub const ATOM_JSWORD__52_65_73_69_7A_65_4F_62_73_65_72_76_65_72 : JsWord = JsWord :: pack_static (95u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_32_52_65_6E_64_65_72_69_6E_67_43_6F_6E_74_65_78_74 : JsWord = JsWord :: pack_static (96u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4F_70_74_69_6F_6E_73_43_6F_6C_6C_65_63_74_69_6F_6E : JsWord = JsWord :: pack_static (97u32) ;
+pub const ATOM_JSWORD__42_6F_6F_6C_65_61_6E : JsWord = JsWord :: pack_static (98u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_52_65_73_6F_75_72_63_65_54_69_6D_69_6E_67 : JsWord = JsWord :: pack_static (99u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_54_65_78_74_75_72_65 : JsWord = JsWord :: pack_static (100u32) ;
+pub const ATOM_JSWORD__56_54_54_43_75_65 : JsWord = JsWord :: pack_static (101u32) ;
+pub const ATOM_JSWORD__58_4D_4C_48_74_74_70_52_65_71_75_65_73_74_55_70_6C_6F_61_64 : JsWord = JsWord :: pack_static (102u32) ;
+pub const ATOM_JSWORD__53_56_47_4D_61_74_72_69_78 : JsWord = JsWord :: pack_static (103u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_44_69_72_65_63_74_6F_72_79_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (104u32) ;
+pub const ATOM_JSWORD__53_56_47_56_69_65_77_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (105u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_50_72_65_73_65_72_76_65_41_73_70_65_63_74_52_61_74_69_6F : JsWord = JsWord :: pack_static (106u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (107u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4E_61_76_69_67_61_74_69_6F_6E : JsWord = JsWord :: pack_static (108u32) ;
+pub const ATOM_JSWORD__64_65_63_6C_61_72_65 : JsWord = JsWord :: pack_static (109u32) ;
+pub const ATOM_JSWORD__75_6E_6B_6E_6F_77_6E : JsWord = JsWord :: pack_static (110u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_4D_6F_74_69_6F_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (111u32) ;
+pub const ATOM_JSWORD__53_56_47_47_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (112u32) ;
+pub const ATOM_JSWORD__6D_65_74_61 : JsWord = JsWord :: pack_static (113u32) ;
+pub const ATOM_JSWORD__49_6D_61_67_65_43_61_70_74_75_72_65 : JsWord = JsWord :: pack_static (114u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_4C_69_73_74_65_6E_65_72 : JsWord = JsWord :: pack_static (115u32) ;
+pub const ATOM_JSWORD__49_6D_61_67_65 : JsWord = JsWord :: pack_static (116u32) ;
+pub const ATOM_JSWORD__54_72_65_65_57_61_6C_6B_65_72 : JsWord = JsWord :: pack_static (117u32) ;
+pub const ATOM_JSWORD__43_72_65_64_65_6E_74_69_61_6C_73_43_6F_6E_74_61_69_6E_65_72 : JsWord = JsWord :: pack_static (118u32) ;
+pub const ATOM_JSWORD__72_65_61_64_6F_6E_6C_79 : JsWord = JsWord :: pack_static (119u32) ;
+pub const ATOM_JSWORD__52_65_73_70_6F_6E_73_65 : JsWord = JsWord :: pack_static (120u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_4F_66_66_73_65_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (121u32) ;
+pub const ATOM_JSWORD__6E_65_76_65_72 : JsWord = JsWord :: pack_static (122u32) ;
+pub const ATOM_JSWORD__53_65_74 : JsWord = JsWord :: pack_static (123u32) ;
+pub const ATOM_JSWORD__50_68_6F_74_6F_43_61_70_61_62_69_6C_69_74_69_65_73 : JsWord = JsWord :: pack_static (124u32) ;
+pub const ATOM_JSWORD__50_61_6E_6E_65_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (125u32) ;
+pub const ATOM_JSWORD__64_65_6C_65_74_65 : JsWord = JsWord :: pack_static (126u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4D_6F_64_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (127u32) ;
+pub const ATOM_JSWORD__53_56_47_54_53_70_61_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (128u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_44_69_61_6C_6F_67_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (129u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4F_62_73_65_72_76_65_72 : JsWord = JsWord :: pack_static (130u32) ;
+pub const ATOM_JSWORD__49_6E_74_65_72_73_65_63_74_69_6F_6E_4F_62_73_65_72_76_65_72_45_6E_74_72_79 : JsWord = JsWord :: pack_static (131u32) ;
+pub const ATOM_JSWORD__49_44_42_43_75_72_73_6F_72_57_69_74_68_56_61_6C_75_65 : JsWord = JsWord :: pack_static (132u32) ;
+pub const ATOM_JSWORD__4E_61_6D_65_64_4E_6F_64_65_4D_61_70 : JsWord = JsWord :: pack_static (133u32) ;
+pub const ATOM_JSWORD__74_79_70_65_6F_66 : JsWord = JsWord :: pack_static (134u32) ;
+pub const ATOM_JSWORD__43_6F_6D_6D_65_6E_74 : JsWord = JsWord :: pack_static (135u32) ;
+pub const ATOM_JSWORD__41_62_6F_72_74_53_69_67_6E_61_6C : JsWord = JsWord :: pack_static (136u32) ;
+pub const ATOM_JSWORD__66_61_6C_73_65 : JsWord = JsWord :: pack_static (137u32) ;
+pub const ATOM_JSWORD__44_61_74_65 : JsWord = JsWord :: pack_static (138u32) ;
+pub const ATOM_JSWORD__49_6E_74_33_32_41_72_72_61_79 : JsWord = JsWord :: pack_static (139u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_43_6F_6C_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (140u32) ;
+pub const ATOM_JSWORD__75_6E_69_71_75_65 : JsWord = JsWord :: pack_static (141u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_44_61_74_61_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (142u32) ;
+pub const ATOM_JSWORD__43_68_61_6E_6E_65_6C_53_70_6C_69_74_74_65_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (143u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D_41_75_64_69_6F_53_6F_75_72_63_65_4E_6F_64_65 : JsWord = JsWord :: pack_static (144u32) ;
+pub const ATOM_JSWORD__74_72_79 : JsWord = JsWord :: pack_static (145u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_4E_6F_64_65 : JsWord = JsWord :: pack_static (146u32) ;
+pub const ATOM_JSWORD__50_75_73_68_53_75_62_73_63_72_69_70_74_69_6F_6E : JsWord = JsWord :: pack_static (147u32) ;
+pub const ATOM_JSWORD__43_61_6E_76_61_73_50_61_74_74_65_72_6E : JsWord = JsWord :: pack_static (148u32) ;
+pub const ATOM_JSWORD__63_72_65_61_74_65_43_6C_61_73_73 : JsWord = JsWord :: pack_static (149u32) ;
+pub const ATOM_JSWORD__4A_53_4F_4E : JsWord = JsWord :: pack_static (150u32) ;
+pub const ATOM_JSWORD__74_79_70_65 : JsWord = JsWord :: pack_static (151u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_51_75_65_72_79_4C_69_73_74_45_76_65_6E_74 : JsWord = JsWord :: pack_static (152u32) ;
+pub const ATOM_JSWORD__46_6F_63_75_73_45_76_65_6E_74 : JsWord = JsWord :: pack_static (153u32) ;
+pub const ATOM_JSWORD__44_4F_4D_49_6D_70_6C_65_6D_65_6E_74_61_74_69_6F_6E : JsWord = JsWord :: pack_static (154u32) ;
+pub const ATOM_JSWORD__53_70_65_65_63_68_53_79_6E_74_68_65_73_69_73_55_74_74_65_72_61_6E_63_65 : JsWord = JsWord :: pack_static (155u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4D_65_6E_75_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (156u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_50_61_69_6E_74_54_69_6D_69_6E_67 : JsWord = JsWord :: pack_static (157u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_44_4C_69_73_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (158u32) ;
+pub const ATOM_JSWORD__52_65_61_64_6F_6E_6C_79_41_72_72_61_79 : JsWord = JsWord :: pack_static (159u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_53_74_79_6C_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (160u32) ;
+pub const ATOM_JSWORD__6D_61_69_6E : JsWord = JsWord :: pack_static (161u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_4C_65_6E_67_74_68 : JsWord = JsWord :: pack_static (162u32) ;
+pub const ATOM_JSWORD__53_56_47_54_65_78_74_43_6F_6E_74_65_6E_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (163u32) ;
+pub const ATOM_JSWORD__4D_69_6D_65_54_79_70_65 : JsWord = JsWord :: pack_static (164u32) ;
+pub const ATOM_JSWORD__67_65_74 : JsWord = JsWord :: pack_static (165u32) ;
+pub const ATOM_JSWORD__53_56_47_54_72_61_6E_73_66_6F_72_6D : JsWord = JsWord :: pack_static (166u32) ;
+pub const ATOM_JSWORD__44_61_74_61_56_69_65_77 : JsWord = JsWord :: pack_static (167u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_4C_69_73_74 : JsWord = JsWord :: pack_static (168u32) ;
+pub const ATOM_JSWORD__55_52_4C : JsWord = JsWord :: pack_static (169u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_45_6E_75_6D_65_72_61_74_69_6F_6E : JsWord = JsWord :: pack_static (170u32) ;
+pub const ATOM_JSWORD__43_53_53_43_6F_6E_64_69_74_69_6F_6E_52_75_6C_65 : JsWord = JsWord :: pack_static (171u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D_54_72_61_63_6B : JsWord = JsWord :: pack_static (172u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_69_6D_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (173u32) ;
+pub const ATOM_JSWORD__43_6C_69_70_62_6F_61_72_64_45_76_65_6E_74 : JsWord = JsWord :: pack_static (174u32) ;
+pub const ATOM_JSWORD__53_56_47_54_69_74_6C_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (175u32) ;
+pub const ATOM_JSWORD__6D_6F_64_75_6C_65 : JsWord = JsWord :: pack_static (176u32) ;
+pub const ATOM_JSWORD__43_75_73_74_6F_6D_45_6C_65_6D_65_6E_74_52_65_67_69_73_74_72_79 : JsWord = JsWord :: pack_static (177u32) ;
+pub const ATOM_JSWORD__4F_66_66_6C_69_6E_65_41_75_64_69_6F_43_6F_6E_74_65_78_74 : JsWord = JsWord :: pack_static (178u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_53_68_61_64_65_72 : JsWord = JsWord :: pack_static (179u32) ;
+pub const ATOM_JSWORD__50_72_6F_63_65_73_73_69_6E_67_49_6E_73_74_72_75_63_74_69_6F_6E : JsWord = JsWord :: pack_static (180u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_54_72_61_6E_73_66_6F_72_6D_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (181u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_4D_6F_72_70_68_6F_6C_6F_67_79_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (182u32) ;
+pub const ATOM_JSWORD__43_53_53_4E_61_6D_65_73_70_61_63_65_52_75_6C_65 : JsWord = JsWord :: pack_static (183u32) ;
+pub const ATOM_JSWORD__44_4F_4D_54_6F_6B_65_6E_4C_69_73_74 : JsWord = JsWord :: pack_static (184u32) ;
+pub const ATOM_JSWORD__4F_70_74_69_6F_6E : JsWord = JsWord :: pack_static (185u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_54_69_6D_69_6E_67 : JsWord = JsWord :: pack_static (186u32) ;
+pub const ATOM_JSWORD__43_61_6E_76_61_73_52_65_6E_64_65_72_69_6E_67_43_6F_6E_74_65_78_74_32_44 : JsWord = JsWord :: pack_static (187u32) ;
+pub const ATOM_JSWORD__53_56_47_44_65_66_73_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (188u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_53_79_6E_63 : JsWord = JsWord :: pack_static (189u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4D_65_74_65_72_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (190u32) ;
+pub const ATOM_JSWORD__55_49_45_76_65_6E_74 : JsWord = JsWord :: pack_static (191u32) ;
+pub const ATOM_JSWORD__52_54_43_43_65_72_74_69_66_69_63_61_74_65 : JsWord = JsWord :: pack_static (192u32) ;
+pub const ATOM_JSWORD__52_65_63_6F_72_64 : JsWord = JsWord :: pack_static (193u32) ;
+pub const ATOM_JSWORD__43_53_53_53_74_79_6C_65_44_65_63_6C_61_72_61_74_69_6F_6E : JsWord = JsWord :: pack_static (194u32) ;
+pub const ATOM_JSWORD__6E_6F_74 : JsWord = JsWord :: pack_static (195u32) ;
+pub const ATOM_JSWORD__61_6E_79 : JsWord = JsWord :: pack_static (196u32) ;
+pub const ATOM_JSWORD__4E_4F_44_45_5F_45_4E_56 : JsWord = JsWord :: pack_static (197u32) ;
+pub const ATOM_JSWORD__50_65_72_6D_69_73_73_69_6F_6E_73 : JsWord = JsWord :: pack_static (198u32) ;
+pub const ATOM_JSWORD__75_6E_64_65_66_69_6E_65_64 : JsWord = JsWord :: pack_static (199u32) ;
+pub const ATOM_JSWORD__50_61_72_74_69_61_6C : JsWord = JsWord :: pack_static (200u32) ;
+pub const ATOM_JSWORD__53_56_47_50_72_65_73_65_72_76_65_41_73_70_65_63_74_52_61_74_69_6F : JsWord = JsWord :: pack_static (201u32) ;
+pub const ATOM_JSWORD__49_6E_74_65_72_73_65_63_74_69_6F_6E_4F_62_73_65_72_76_65_72 : JsWord = JsWord :: pack_static (202u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_53_65_74_74_69_6E_67_73_52_61_6E_67_65 : JsWord = JsWord :: pack_static (203u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_54_69_6C_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (204u32) ;
+pub const ATOM_JSWORD__55_69_6E_74_38_41_72_72_61_79 : JsWord = JsWord :: pack_static (205u32) ;
+pub const ATOM_JSWORD__53_74_6F_72_61_67_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (206u32) ;
+pub const ATOM_JSWORD__52_65_61_64_6F_6E_6C_79 : JsWord = JsWord :: pack_static (207u32) ;
+pub const ATOM_JSWORD__4D_65_73_73_61_67_65_43_68_61_6E_6E_65_6C : JsWord = JsWord :: pack_static (208u32) ;
+pub const ATOM_JSWORD__70_72_6F_63_65_73_73 : JsWord = JsWord :: pack_static (209u32) ;
+pub const ATOM_JSWORD__77_68_69_6C_65 : JsWord = JsWord :: pack_static (210u32) ;
+pub const ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_43_6F_6E_6E_65_63_74_69_6F_6E : JsWord = JsWord :: pack_static (211u32) ;
+pub const ATOM_JSWORD__63_6F_6E_73_74_72_75_63_74_6F_72 : JsWord = JsWord :: pack_static (212u32) ;
+pub const ATOM_JSWORD__70_72_6F_74_65_63_74_65_64 : JsWord = JsWord :: pack_static (213u32) ;
+pub const ATOM_JSWORD__47_61_6D_65_70_61_64_45_76_65_6E_74 : JsWord = JsWord :: pack_static (214u32) ;
+pub const ATOM_JSWORD__74_61_72_67_65_74 : JsWord = JsWord :: pack_static (215u32) ;
+pub const ATOM_JSWORD__43_6F_75_6E_74_51_75_65_75_69_6E_67_53_74_72_61_74_65_67_79 : JsWord = JsWord :: pack_static (216u32) ;
+pub const ATOM_JSWORD__4F_62_6A_65_63_74 : JsWord = JsWord :: pack_static (217u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_44_65_76_69_63_65_73 : JsWord = JsWord :: pack_static (218u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_53_6F_75_72_63_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (219u32) ;
+pub const ATOM_JSWORD__54_69_6D_65_52_61_6E_67_65_73 : JsWord = JsWord :: pack_static (220u32) ;
+pub const ATOM_JSWORD__53_63_72_65_65_6E : JsWord = JsWord :: pack_static (221u32) ;
+pub const ATOM_JSWORD__73_65_74 : JsWord = JsWord :: pack_static (222u32) ;
+pub const ATOM_JSWORD__74_72_75_65 : JsWord = JsWord :: pack_static (223u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_53_65_6C_65_63_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (224u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_51_75_65_72_79_4C_69_73_74 : JsWord = JsWord :: pack_static (225u32) ;
+pub const ATOM_JSWORD__53_74_79_6C_65_53_68_65_65_74 : JsWord = JsWord :: pack_static (226u32) ;
+pub const ATOM_JSWORD__55_52_49_45_72_72_6F_72 : JsWord = JsWord :: pack_static (227u32) ;
+pub const ATOM_JSWORD__50_61_74_68_32_44 : JsWord = JsWord :: pack_static (228u32) ;
+pub const ATOM_JSWORD__49_6E_66_69_6E_69_74_79 : JsWord = JsWord :: pack_static (229u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_44_69_73_70_6C_61_63_65_6D_65_6E_74_4D_61_70_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (230u32) ;
+pub const ATOM_JSWORD__6B_65_79_6F_66 : JsWord = JsWord :: pack_static (231u32) ;
+pub const ATOM_JSWORD__41_62_6F_72_74_43_6F_6E_74_72_6F_6C_6C_65_72 : JsWord = JsWord :: pack_static (232u32) ;
+pub const ATOM_JSWORD__70_61_63_6B_61_67_65 : JsWord = JsWord :: pack_static (233u32) ;
+pub const ATOM_JSWORD__44_79_6E_61_6D_69_63_73_43_6F_6D_70_72_65_73_73_6F_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (234u32) ;
+pub const ATOM_JSWORD__4D_49_44_49_49_6E_70_75_74_4D_61_70 : JsWord = JsWord :: pack_static (235u32) ;
+pub const ATOM_JSWORD__54_6F_75_63_68_4C_69_73_74 : JsWord = JsWord :: pack_static (236u32) ;
+pub const ATOM_JSWORD__53_56_47_49_6D_61_67_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (237u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4D_61_72_6B : JsWord = JsWord :: pack_static (238u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4C_61_62_65_6C_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (239u32) ;
+pub const ATOM_JSWORD__4E_61_4E : JsWord = JsWord :: pack_static (240u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4F_70_74_47_72_6F_75_70_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (241u32) ;
+pub const ATOM_JSWORD__52_54_43_52_74_70_53_65_6E_64_65_72 : JsWord = JsWord :: pack_static (242u32) ;
+pub const ATOM_JSWORD__52_65_66_65_72_65_6E_63_65_45_72_72_6F_72 : JsWord = JsWord :: pack_static (243u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_55_6E_6B_6E_6F_77_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (244u32) ;
+pub const ATOM_JSWORD__43_53_53_46_6F_6E_74_46_61_63_65_52_75_6C_65 : JsWord = JsWord :: pack_static (245u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_43_61_70_74_69_6F_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (246u32) ;
+pub const ATOM_JSWORD__42_69_67_49_6E_74_36_34_41_72_72_61_79 : JsWord = JsWord :: pack_static (247u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_46_72_61_6D_65_62_75_66_66_65_72 : JsWord = JsWord :: pack_static (248u32) ;
+pub const ATOM_JSWORD__53_6F_75_72_63_65_42_75_66_66_65_72 : JsWord = JsWord :: pack_static (249u32) ;
+pub const ATOM_JSWORD__49_44_42_44_61_74_61_62_61_73_65 : JsWord = JsWord :: pack_static (250u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_46_6F_72_6D_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (251u32) ;
+pub const ATOM_JSWORD__43_72_79_70_74_6F : JsWord = JsWord :: pack_static (252u32) ;
+pub const ATOM_JSWORD__53_74_79_6C_65_53_68_65_65_74_4C_69_73_74 : JsWord = JsWord :: pack_static (253u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_43_65_6C_6C_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (254u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_43_6F_6E_74_65_78_74 : JsWord = JsWord :: pack_static (255u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D_41_75_64_69_6F_44_65_73_74_69_6E_61_74_69_6F_6E_4E_6F_64_65 : JsWord = JsWord :: pack_static (256u32) ;
+pub const ATOM_JSWORD__53_56_47_54_72_61_6E_73_66_6F_72_6D_4C_69_73_74 : JsWord = JsWord :: pack_static (257u32) ;
+pub const ATOM_JSWORD__44_72_61_67_45_76_65_6E_74 : JsWord = JsWord :: pack_static (258u32) ;
+pub const ATOM_JSWORD__4E_6F_64_65_46_69_6C_74_65_72 : JsWord = JsWord :: pack_static (259u32) ;
+pub const ATOM_JSWORD__4F_66_66_6C_69_6E_65_41_75_64_69_6F_43_6F_6D_70_6C_65_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (260u32) ;
+pub const ATOM_JSWORD__53_56_47_54_65_78_74_50_61_74_68_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (261u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_43_6F_6C_6F_72_4D_61_74_72_69_78_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (262u32) ;
+pub const ATOM_JSWORD__45_72_72_6F_72_45_76_65_6E_74 : JsWord = JsWord :: pack_static (263u32) ;
+pub const ATOM_JSWORD__52_54_43_52_74_70_43_6F_6E_74_72_69_62_75_74_69_6E_67_53_6F_75_72_63_65 : JsWord = JsWord :: pack_static (264u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_42_6C_65_6E_64_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (265u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_41_6C_6C_43_6F_6C_6C_65_63_74_69_6F_6E : JsWord = JsWord :: pack_static (266u32) ;
+pub const ATOM_JSWORD__61_73_73_65_72_74 : JsWord = JsWord :: pack_static (267u32) ;
+pub const ATOM_JSWORD__4B_65_79_66_72_61_6D_65_45_66_66_65_63_74 : JsWord = JsWord :: pack_static (268u32) ;
+pub const ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E : JsWord = JsWord :: pack_static (269u32) ;
+pub const ATOM_JSWORD__54_65_78_74_54_72_61_63_6B_4C_69_73_74 : JsWord = JsWord :: pack_static (270u32) ;
+pub const ATOM_JSWORD__50_6C_75_67_69_6E : JsWord = JsWord :: pack_static (271u32) ;
+pub const ATOM_JSWORD__50_6F_70_53_74_61_74_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (272u32) ;
+pub const ATOM_JSWORD__43_53_53_4D_65_64_69_61_52_75_6C_65 : JsWord = JsWord :: pack_static (273u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_4E_75_6D_62_65_72_4C_69_73_74 : JsWord = JsWord :: pack_static (274u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_43_6F_6E_76_6F_6C_76_65_4D_61_74_72_69_78_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (275u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_41_63_74_69_76_65_49_6E_66_6F : JsWord = JsWord :: pack_static (276u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_44_69_73_74_61_6E_74_4C_69_67_68_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (277u32) ;
+pub const ATOM_JSWORD__41_6E_61_6C_79_73_65_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (278u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_46_75_6E_63_42_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (279u32) ;
+pub const ATOM_JSWORD__44_4F_4D_53_74_72_69_6E_67_4D_61_70 : JsWord = JsWord :: pack_static (280u32) ;
+pub const ATOM_JSWORD__63_61_6C_6C : JsWord = JsWord :: pack_static (281u32) ;
+pub const ATOM_JSWORD__53_65_72_76_69_63_65_57_6F_72_6B_65_72 : JsWord = JsWord :: pack_static (282u32) ;
+pub const ATOM_JSWORD__66_72_6F_6D : JsWord = JsWord :: pack_static (283u32) ;
+pub const ATOM_JSWORD__4D_65_73_73_61_67_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (284u32) ;
+pub const ATOM_JSWORD__65_6E_75_6D : JsWord = JsWord :: pack_static (285u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_51_75_6F_74_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (286u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_42_75_66_66_65_72 : JsWord = JsWord :: pack_static (287u32) ;
+pub const ATOM_JSWORD__4B_65_79_66_72_61_6D_65_45_66_66_65_63_74_52_65_61_64_4F_6E_6C_79 : JsWord = JsWord :: pack_static (288u32) ;
+pub const ATOM_JSWORD__5F_74_6F_43_6F_6E_73_75_6D_61_62_6C_65_41_72_72_61_79 : JsWord = JsWord :: pack_static (289u32) ;
+pub const ATOM_JSWORD__50_65_72_6D_69_73_73_69_6F_6E_53_74_61_74_75_73 : JsWord = JsWord :: pack_static (290u32) ;
+pub const ATOM_JSWORD__63_72_65_61_74_65_52_65_61_63_74_43_6C_61_73_73 : JsWord = JsWord :: pack_static (291u32) ;
+pub const ATOM_JSWORD__52_61_6E_67_65 : JsWord = JsWord :: pack_static (292u32) ;
+pub const ATOM_JSWORD__62_69_67_69_6E_74 : JsWord = JsWord :: pack_static (293u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_50_61_72_61_67_72_61_70_68_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (294u32) ;
+pub const ATOM_JSWORD__62_72_65_61_6B : JsWord = JsWord :: pack_static (295u32) ;
+pub const ATOM_JSWORD__58_53_4C_54_50_72_6F_63_65_73_73_6F_72 : JsWord = JsWord :: pack_static (296u32) ;
+pub const ATOM_JSWORD__52_54_43_53_74_61_74_73_52_65_70_6F_72_74 : JsWord = JsWord :: pack_static (297u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_48_74_6D_6C_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (298u32) ;
+pub const ATOM_JSWORD__43_53_53_53_74_79_6C_65_52_75_6C_65 : JsWord = JsWord :: pack_static (299u32) ;
+pub const ATOM_JSWORD__4E_61_76_69_67_61_74_6F_72 : JsWord = JsWord :: pack_static (300u32) ;
+pub const ATOM_JSWORD__43_61_63_68_65_53_74_6F_72_61_67_65 : JsWord = JsWord :: pack_static (301u32) ;
+pub const ATOM_JSWORD__53_56_47_54_65_78_74_50_6F_73_69_74_69_6F_6E_69_6E_67_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (302u32) ;
+pub const ATOM_JSWORD__50_75_73_68_53_75_62_73_63_72_69_70_74_69_6F_6E_4F_70_74_69_6F_6E_73 : JsWord = JsWord :: pack_static (303u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_44_65_73_74_69_6E_61_74_69_6F_6E_4E_6F_64_65 : JsWord = JsWord :: pack_static (304u32) ;
+pub const ATOM_JSWORD__6E_75_6D_62_65_72 : JsWord = JsWord :: pack_static (305u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_49_6E_74_65_67_65_72 : JsWord = JsWord :: pack_static (306u32) ;
+pub const ATOM_JSWORD__4D_49_44_49_41_63_63_65_73_73 : JsWord = JsWord :: pack_static (307u32) ;
+pub const ATOM_JSWORD__45_72_72_6F_72 : JsWord = JsWord :: pack_static (308u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4C_6F_6E_67_54_61_73_6B_54_69_6D_69_6E_67 : JsWord = JsWord :: pack_static (309u32) ;
+pub const ATOM_JSWORD__54_72_61_6E_73_69_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (310u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_4D_65_72_67_65_4E_6F_64_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (311u32) ;
+pub const ATOM_JSWORD__54_65_78_74_45_76_65_6E_74 : JsWord = JsWord :: pack_static (312u32) ;
+pub const ATOM_JSWORD__69_73 : JsWord = JsWord :: pack_static (313u32) ;
+pub const ATOM_JSWORD__44_65_76_69_63_65_4F_72_69_65_6E_74_61_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (314u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_4B_65_79_53_74_61_74_75_73_4D_61_70 : JsWord = JsWord :: pack_static (315u32) ;
+pub const ATOM_JSWORD__42_6C_6F_62 : JsWord = JsWord :: pack_static (316u32) ;
+pub const ATOM_JSWORD__45_76_65_6E_74_54_61_72_67_65_74 : JsWord = JsWord :: pack_static (317u32) ;
+pub const ATOM_JSWORD__44_61_74_61_54_72_61_6E_73_66_65_72_49_74_65_6D : JsWord = JsWord :: pack_static (318u32) ;
+pub const ATOM_JSWORD__43_6F_6E_76_6F_6C_76_65_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (319u32) ;
+pub const ATOM_JSWORD__66_75_6E_63_74_69_6F_6E : JsWord = JsWord :: pack_static (320u32) ;
+pub const ATOM_JSWORD__58_50_61_74_68_45_78_70_72_65_73_73_69_6F_6E : JsWord = JsWord :: pack_static (321u32) ;
+pub const ATOM_JSWORD__53_56_47_43_69_72_63_6C_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (322u32) ;
+pub const ATOM_JSWORD__53_56_47_53_63_72_69_70_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (323u32) ;
+pub const ATOM_JSWORD__53_56_47_45_6C_6C_69_70_73_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (324u32) ;
+pub const ATOM_JSWORD__6C_65_74 : JsWord = JsWord :: pack_static (325u32) ;
+pub const ATOM_JSWORD__45_76_65_6E_74 : JsWord = JsWord :: pack_static (326u32) ;
+pub const ATOM_JSWORD__46_6F_6E_74_46_61_63_65 : JsWord = JsWord :: pack_static (327u32) ;
+pub const ATOM_JSWORD__53_68_61_72_65_64_41_72_72_61_79_42_75_66_66_65_72 : JsWord = JsWord :: pack_static (328u32) ;
+pub const ATOM_JSWORD__4D_75_74_61_74_69_6F_6E_4F_62_73_65_72_76_65_72 : JsWord = JsWord :: pack_static (329u32) ;
+pub const ATOM_JSWORD__43_53_53_4B_65_79_66_72_61_6D_65_73_52_75_6C_65 : JsWord = JsWord :: pack_static (330u32) ;
+pub const ATOM_JSWORD__52_65_74_75_72_6E_54_79_70_65 : JsWord = JsWord :: pack_static (331u32) ;
+pub const ATOM_JSWORD__53_56_47_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (332u32) ;
+pub const ATOM_JSWORD__52_65_73_69_7A_65_4F_62_73_65_72_76_65_72_45_6E_74_72_79 : JsWord = JsWord :: pack_static (333u32) ;
+pub const ATOM_JSWORD__77_69_74_68 : JsWord = JsWord :: pack_static (334u32) ;
+pub const ATOM_JSWORD__57_65_62_41_73_73_65_6D_62_6C_79 : JsWord = JsWord :: pack_static (335u32) ;
+pub const ATOM_JSWORD__58_50_61_74_68_52_65_73_75_6C_74 : JsWord = JsWord :: pack_static (336u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_46_72_61_6D_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (337u32) ;
+pub const ATOM_JSWORD__4D_49_44_49_49_6E_70_75_74 : JsWord = JsWord :: pack_static (338u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_42_6F_64_79_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (339u32) ;
+pub const ATOM_JSWORD__69_6D_70_6F_72_74_61_6E_74 : JsWord = JsWord :: pack_static (340u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_51_75_65_72_79 : JsWord = JsWord :: pack_static (341u32) ;
+pub const ATOM_JSWORD_ : JsWord = JsWord :: pack_static (342u32) ;
+pub const ATOM_JSWORD__6F_76_65_72_72_69_64_65 : JsWord = JsWord :: pack_static (343u32) ;
+pub const ATOM_JSWORD__4D_49_44_49_4D_65_73_73_61_67_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (344u32) ;
+pub const ATOM_JSWORD__57_65_61_6B_4D_61_70 : JsWord = JsWord :: pack_static (345u32) ;
+pub const ATOM_JSWORD__53_56_47_44_65_73_63_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (346u32) ;
+pub const ATOM_JSWORD__41_72_72_61_79_42_75_66_66_65_72 : JsWord = JsWord :: pack_static (347u32) ;
+pub const ATOM_JSWORD__52_65_67_45_78_70 : JsWord = JsWord :: pack_static (348u32) ;
+pub const ATOM_JSWORD__46_6F_6E_74_46_61_63_65_53_65_74_4C_6F_61_64_45_76_65_6E_74 : JsWord = JsWord :: pack_static (349u32) ;
+pub const ATOM_JSWORD__58_4D_4C_53_65_72_69_61_6C_69_7A_65_72 : JsWord = JsWord :: pack_static (350u32) ;
+pub const ATOM_JSWORD__52_54_43_53_63_74_70_54_72_61_6E_73_70_6F_72_74 : JsWord = JsWord :: pack_static (351u32) ;
+pub const ATOM_JSWORD__49_44_42_4F_62_6A_65_63_74_53_74_6F_72_65 : JsWord = JsWord :: pack_static (352u32) ;
+pub const ATOM_JSWORD__57_65_62_53_6F_63_6B_65_74 : JsWord = JsWord :: pack_static (353u32) ;
+pub const ATOM_JSWORD__50_69_63_6B : JsWord = JsWord :: pack_static (354u32) ;
+pub const ATOM_JSWORD__6E_75_6C_6C : JsWord = JsWord :: pack_static (355u32) ;
+pub const ATOM_JSWORD__52_54_43_52_74_70_52_65_63_65_69_76_65_72 : JsWord = JsWord :: pack_static (356u32) ;
+pub const ATOM_JSWORD__58_4D_4C_44_6F_63_75_6D_65_6E_74 : JsWord = JsWord :: pack_static (357u32) ;
+pub const ATOM_JSWORD__61_73_79_6E_63 : JsWord = JsWord :: pack_static (358u32) ;
+pub const ATOM_JSWORD__53_68_61_64_6F_77_52_6F_6F_74 : JsWord = JsWord :: pack_static (359u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_46_75_6E_63_41_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (360u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_43_6F_6E_74_65_6E_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (361u32) ;
+pub const ATOM_JSWORD__44_4F_4D_52_65_63_74 : JsWord = JsWord :: pack_static (362u32) ;
+pub const ATOM_JSWORD__53_56_47_55_6E_69_74_54_79_70_65_73 : JsWord = JsWord :: pack_static (363u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_52_65_63_74 : JsWord = JsWord :: pack_static (364u32) ;
+pub const ATOM_JSWORD__4E_75_6D_62_65_72 : JsWord = JsWord :: pack_static (365u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_46_6C_6F_6F_64_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (366u32) ;
+pub const ATOM_JSWORD__65_78_70_6F_72_74 : JsWord = JsWord :: pack_static (367u32) ;
+pub const ATOM_JSWORD__57_61_76_65_53_68_61_70_65_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (368u32) ;
+pub const ATOM_JSWORD__44_4F_4D_52_65_63_74_52_65_61_64_4F_6E_6C_79 : JsWord = JsWord :: pack_static (369u32) ;
+pub const ATOM_JSWORD__44_4F_4D_45_78_63_65_70_74_69_6F_6E : JsWord = JsWord :: pack_static (370u32) ;
+pub const ATOM_JSWORD__45_78_74_72_61_63_74 : JsWord = JsWord :: pack_static (371u32) ;
+pub const ATOM_JSWORD__46_6C_6F_61_74_36_34_41_72_72_61_79 : JsWord = JsWord :: pack_static (372u32) ;
+pub const ATOM_JSWORD__53_56_47_43_6F_6D_70_6F_6E_65_6E_74_54_72_61_6E_73_66_65_72_46_75_6E_63_74_69_6F_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (373u32) ;
+pub const ATOM_JSWORD__44_4F_4D_45_72_72_6F_72 : JsWord = JsWord :: pack_static (374u32) ;
+pub const ATOM_JSWORD__53_56_47_4D_61_72_6B_65_72_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (375u32) ;
+pub const ATOM_JSWORD__49_44_42_56_65_72_73_69_6F_6E_43_68_61_6E_67_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (376u32) ;
+pub const ATOM_JSWORD__52_65_61_64_61_62_6C_65_53_74_72_65_61_6D : JsWord = JsWord :: pack_static (377u32) ;
+pub const ATOM_JSWORD__53_56_47_50_61_74_68_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (378u32) ;
+pub const ATOM_JSWORD__49_6E_70_75_74_45_76_65_6E_74 : JsWord = JsWord :: pack_static (379u32) ;
+pub const ATOM_JSWORD__53_74_61_74_69_63_52_61_6E_67_65 : JsWord = JsWord :: pack_static (380u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_69_74_6C_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (381u32) ;
+pub const ATOM_JSWORD__76_61_72 : JsWord = JsWord :: pack_static (382u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_44_6F_63_75_6D_65_6E_74 : JsWord = JsWord :: pack_static (383u32) ;
+pub const ATOM_JSWORD__52_65_61_63_74 : JsWord = JsWord :: pack_static (384u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_46_6F_72_6D_43_6F_6E_74_72_6F_6C_73_43_6F_6C_6C_65_63_74_69_6F_6E : JsWord = JsWord :: pack_static (385u32) ;
+pub const ATOM_JSWORD__58_50_61_74_68_45_76_61_6C_75_61_74_6F_72 : JsWord = JsWord :: pack_static (386u32) ;
+pub const ATOM_JSWORD__43_53_53 : JsWord = JsWord :: pack_static (387u32) ;
+pub const ATOM_JSWORD__63_61_73_65 : JsWord = JsWord :: pack_static (388u32) ;
+pub const ATOM_JSWORD__53_74_72_69_6E_67 : JsWord = JsWord :: pack_static (389u32) ;
+pub const ATOM_JSWORD__52_65_71_75_65_73_74 : JsWord = JsWord :: pack_static (390u32) ;
+pub const ATOM_JSWORD__43_72_79_70_74_6F_4B_65_79 : JsWord = JsWord :: pack_static (391u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_53_74_72_69_6E_67 : JsWord = JsWord :: pack_static (392u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_44_61_74_61_4C_69_73_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (393u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4C_69_6E_6B_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (394u32) ;
+pub const ATOM_JSWORD__4D_65_73_73_61_67_65_50_6F_72_74 : JsWord = JsWord :: pack_static (395u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_53_63_72_69_70_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (396u32) ;
+pub const ATOM_JSWORD__53_56_47_46_6F_72_65_69_67_6E_4F_62_6A_65_63_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (397u32) ;
+pub const ATOM_JSWORD__41_74_74_72 : JsWord = JsWord :: pack_static (398u32) ;
+pub const ATOM_JSWORD__43_61_6E_76_61_73_43_61_70_74_75_72_65_4D_65_64_69_61_53_74_72_65_61_6D_54_72_61_63_6B : JsWord = JsWord :: pack_static (399u32) ;
+pub const ATOM_JSWORD__50_72_6F_6D_69_73_65_52_65_6A_65_63_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (400u32) ;
+pub const ATOM_JSWORD__70_75_62_6C_69_63 : JsWord = JsWord :: pack_static (401u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_53_6C_6F_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (402u32) ;
+pub const ATOM_JSWORD__53_56_47_4D_65_74_61_64_61_74_61_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (403u32) ;
+pub const ATOM_JSWORD__4E_6F_64_65 : JsWord = JsWord :: pack_static (404u32) ;
+pub const ATOM_JSWORD__55_69_6E_74_33_32_41_72_72_61_79 : JsWord = JsWord :: pack_static (405u32) ;
+pub const ATOM_JSWORD__41_72_72_61_79 : JsWord = JsWord :: pack_static (406u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_49_6E_70_75_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (407u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_41_6E_67_6C_65 : JsWord = JsWord :: pack_static (408u32) ;
+pub const ATOM_JSWORD__63_6F_6E_63_61_74 : JsWord = JsWord :: pack_static (409u32) ;
+pub const ATOM_JSWORD__44_65_6C_61_79_4E_6F_64_65 : JsWord = JsWord :: pack_static (410u32) ;
+pub const ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_50_6C_61_79_62_61_63_6B_45_76_65_6E_74 : JsWord = JsWord :: pack_static (411u32) ;
+pub const ATOM_JSWORD__4D_69_6D_65_54_79_70_65_41_72_72_61_79 : JsWord = JsWord :: pack_static (412u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_41_6E_63_68_6F_72_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (413u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_50_72_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (414u32) ;
+pub const ATOM_JSWORD__43_53_53_4B_65_79_66_72_61_6D_65_52_75_6C_65 : JsWord = JsWord :: pack_static (415u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_45_6C_65_6D_65_6E_74_41_75_64_69_6F_53_6F_75_72_63_65_4E_6F_64_65 : JsWord = JsWord :: pack_static (416u32) ;
+pub const ATOM_JSWORD__4E_65_74_77_6F_72_6B_49_6E_66_6F_72_6D_61_74_69_6F_6E : JsWord = JsWord :: pack_static (417u32) ;
+pub const ATOM_JSWORD__45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (418u32) ;
+pub const ATOM_JSWORD__53_56_47_53_65_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (419u32) ;
+pub const ATOM_JSWORD__54_61_73_6B_41_74_74_72_69_62_75_74_69_6F_6E_54_69_6D_69_6E_67 : JsWord = JsWord :: pack_static (420u32) ;
+pub const ATOM_JSWORD__53_56_47_53_56_47_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (421u32) ;
+pub const ATOM_JSWORD__44_4F_4D_50_6F_69_6E_74 : JsWord = JsWord :: pack_static (422u32) ;
+pub const ATOM_JSWORD__44_6F_63_75_6D_65_6E_74_46_72_61_67_6D_65_6E_74 : JsWord = JsWord :: pack_static (423u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_53_6F_75_72_63_65 : JsWord = JsWord :: pack_static (424u32) ;
+pub const ATOM_JSWORD__53_56_47_52_61_64_69_61_6C_47_72_61_64_69_65_6E_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (425u32) ;
+pub const ATOM_JSWORD__53_56_47_43_6C_69_70_50_61_74_68_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (426u32) ;
+pub const ATOM_JSWORD__42_61_73_65_41_75_64_69_6F_43_6F_6E_74_65_78_74 : JsWord = JsWord :: pack_static (427u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_53_70_65_63_75_6C_61_72_4C_69_67_68_74_69_6E_67_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (428u32) ;
+pub const ATOM_JSWORD__53_56_47_53_79_6D_62_6F_6C_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (429u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_42_75_66_66_65_72_53_6F_75_72_63_65_4E_6F_64_65 : JsWord = JsWord :: pack_static (430u32) ;
+pub const ATOM_JSWORD__42_72_6F_61_64_63_61_73_74_43_68_61_6E_6E_65_6C : JsWord = JsWord :: pack_static (431u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_72_61_63_6B_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (432u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_42_61_73_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (433u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4F_75_74_70_75_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (434u32) ;
+pub const ATOM_JSWORD__6C_65_6E_67_74_68 : JsWord = JsWord :: pack_static (435u32) ;
+pub const ATOM_JSWORD__70_72_69_76_61_74_65 : JsWord = JsWord :: pack_static (436u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4C_49_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (437u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_41_75_64_69_6F_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (438u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_54_72_61_6E_73_66_6F_72_6D_4C_69_73_74 : JsWord = JsWord :: pack_static (439u32) ;
+pub const ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_52_65_71_75_65_73_74 : JsWord = JsWord :: pack_static (440u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_52_65_6E_64_65_72_62_75_66_66_65_72 : JsWord = JsWord :: pack_static (441u32) ;
+pub const ATOM_JSWORD__6F_62_6A_65_63_74 : JsWord = JsWord :: pack_static (442u32) ;
+pub const ATOM_JSWORD__76_6F_69_64 : JsWord = JsWord :: pack_static (443u32) ;
+pub const ATOM_JSWORD__53_56_47_50_6F_6C_79_6C_69_6E_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (444u32) ;
+pub const ATOM_JSWORD__4E_6F_64_65_49_74_65_72_61_74_6F_72 : JsWord = JsWord :: pack_static (445u32) ;
+pub const ATOM_JSWORD__54_65_78_74_54_72_61_63_6B_43_75_65 : JsWord = JsWord :: pack_static (446u32) ;
+pub const ATOM_JSWORD__73_79_6D_62_6F_6C : JsWord = JsWord :: pack_static (447u32) ;
+pub const ATOM_JSWORD__53_56_47_50_61_74_74_65_72_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (448u32) ;
+pub const ATOM_JSWORD__52_54_43_49_63_65_54_72_61_6E_73_70_6F_72_74 : JsWord = JsWord :: pack_static (449u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_45_72_72_6F_72 : JsWord = JsWord :: pack_static (450u32) ;
+pub const ATOM_JSWORD__43_68_61_6E_6E_65_6C_4D_65_72_67_65_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (451u32) ;
+pub const ATOM_JSWORD__47_61_69_6E_4E_6F_64_65 : JsWord = JsWord :: pack_static (452u32) ;
+pub const ATOM_JSWORD__54_65_78_74_54_72_61_63_6B_43_75_65_4C_69_73_74 : JsWord = JsWord :: pack_static (453u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_65_6D_70_6C_61_74_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (454u32) ;
+pub const ATOM_JSWORD__63_6F_6E_74_69_6E_75_65 : JsWord = JsWord :: pack_static (455u32) ;
+pub const ATOM_JSWORD__45_76_61_6C_45_72_72_6F_72 : JsWord = JsWord :: pack_static (456u32) ;
+pub const ATOM_JSWORD__49_44_42_49_6E_64_65_78 : JsWord = JsWord :: pack_static (457u32) ;
+pub const ATOM_JSWORD__49_44_42_52_65_71_75_65_73_74 : JsWord = JsWord :: pack_static (458u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_4B_65_79_53_65_73_73_69_6F_6E : JsWord = JsWord :: pack_static (459u32) ;
+pub const ATOM_JSWORD__53_79_6D_62_6F_6C : JsWord = JsWord :: pack_static (460u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4F_62_73_65_72_76_65_72_45_6E_74_72_79_4C_69_73_74 : JsWord = JsWord :: pack_static (461u32) ;
+pub const ATOM_JSWORD__6E_61_6D_65_73_70_61_63_65 : JsWord = JsWord :: pack_static (462u32) ;
+pub const ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E : JsWord = JsWord :: pack_static (463u32) ;
+pub const ATOM_JSWORD__46_6F_72_6D_44_61_74_61 : JsWord = JsWord :: pack_static (464u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_50_61_72_61_6D : JsWord = JsWord :: pack_static (465u32) ;
+pub const ATOM_JSWORD__69_6D_70_6C_65_6D_65_6E_74_73 : JsWord = JsWord :: pack_static (466u32) ;
+pub const ATOM_JSWORD__67_6C_6F_62_61_6C : JsWord = JsWord :: pack_static (467u32) ;
+pub const ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (468u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_50_72_6F_67_72_61_6D : JsWord = JsWord :: pack_static (469u32) ;
+pub const ATOM_JSWORD__43_53_53_53_74_79_6C_65_53_68_65_65_74 : JsWord = JsWord :: pack_static (470u32) ;
+pub const ATOM_JSWORD__48_69_73_74_6F_72_79 : JsWord = JsWord :: pack_static (471u32) ;
+pub const ATOM_JSWORD__43_53_53_52_75_6C_65_4C_69_73_74 : JsWord = JsWord :: pack_static (472u32) ;
+pub const ATOM_JSWORD__49_44_42_4F_70_65_6E_44_42_52_65_71_75_65_73_74 : JsWord = JsWord :: pack_static (473u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_53_61_6D_70_6C_65_72 : JsWord = JsWord :: pack_static (474u32) ;
+pub const ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_43_6F_6E_6E_65_63_74_69_6F_6E_4C_69_73_74 : JsWord = JsWord :: pack_static (475u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_46_72_61_6D_65_53_65_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (476u32) ;
+pub const ATOM_JSWORD__43_6F_6E_73_74_61_6E_74_53_6F_75_72_63_65_4E_6F_64_65 : JsWord = JsWord :: pack_static (477u32) ;
+pub const ATOM_JSWORD__4D_6F_75_73_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (478u32) ;
+pub const ATOM_JSWORD__5F_65_78_74_65_6E_64_73 : JsWord = JsWord :: pack_static (479u32) ;
+pub const ATOM_JSWORD__53_56_47_47_65_6F_6D_65_74_72_79_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (480u32) ;
+pub const ATOM_JSWORD__53_56_47_4C_69_6E_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (481u32) ;
+pub const ATOM_JSWORD__53_56_47_52_65_63_74 : JsWord = JsWord :: pack_static (482u32) ;
+pub const ATOM_JSWORD__49_6E_74_31_36_41_72_72_61_79 : JsWord = JsWord :: pack_static (483u32) ;
+pub const ATOM_JSWORD__74_68_69_73 : JsWord = JsWord :: pack_static (484u32) ;
+pub const ATOM_JSWORD__41_70_70_6C_69_63_61_74_69_6F_6E_43_61_63_68_65_45_72_72_6F_72_45_76_65_6E_74 : JsWord = JsWord :: pack_static (485u32) ;
+pub const ATOM_JSWORD__53_56_47_4C_65_6E_67_74_68 : JsWord = JsWord :: pack_static (486u32) ;
+pub const ATOM_JSWORD__61_73_73_65_72_74_73 : JsWord = JsWord :: pack_static (487u32) ;
+pub const ATOM_JSWORD__6F_72 : JsWord = JsWord :: pack_static (488u32) ;
+pub const ATOM_JSWORD__72_65_71_75_69_72_65 : JsWord = JsWord :: pack_static (489u32) ;
+pub const ATOM_JSWORD__57_68_65_65_6C_45_76_65_6E_74 : JsWord = JsWord :: pack_static (490u32) ;
+pub const ATOM_JSWORD__4E_6F_64_65_4C_69_73_74 : JsWord = JsWord :: pack_static (491u32) ;
+pub const ATOM_JSWORD__44_4F_4D_4D_61_74_72_69_78_52_65_61_64_4F_6E_6C_79 : JsWord = JsWord :: pack_static (492u32) ;
+pub const ATOM_JSWORD__55_69_6E_74_31_36_41_72_72_61_79 : JsWord = JsWord :: pack_static (493u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_65_78_74_41_72_65_61_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (494u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_50_72_6F_67_72_65_73_73_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (495u32) ;
+pub const ATOM_JSWORD__43_61_63_68_65 : JsWord = JsWord :: pack_static (496u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4E_61_76_69_67_61_74_69_6F_6E_54_69_6D_69_6E_67 : JsWord = JsWord :: pack_static (497u32) ;
+pub const ATOM_JSWORD__43_53_53_49_6D_70_6F_72_74_52_75_6C_65 : JsWord = JsWord :: pack_static (498u32) ;
+pub const ATOM_JSWORD__42_79_74_65_4C_65_6E_67_74_68_51_75_65_75_69_6E_67_53_74_72_61_74_65_67_79 : JsWord = JsWord :: pack_static (499u32) ;
+pub const ATOM_JSWORD__46_6C_6F_61_74_33_32_41_72_72_61_79 : JsWord = JsWord :: pack_static (500u32) ;
+pub const ATOM_JSWORD__50_72_6F_6D_69_73_65 : JsWord = JsWord :: pack_static (501u32) ;
+pub const ATOM_JSWORD__43_6F_6D_70_6F_73_69_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (502u32) ;
+pub const ATOM_JSWORD__50_72_6F_78_79 : JsWord = JsWord :: pack_static (503u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_48_65_61_64_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (504u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4F_4C_69_73_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (505u32) ;
+pub const ATOM_JSWORD__53_56_47_4E_75_6D_62_65_72_4C_69_73_74 : JsWord = JsWord :: pack_static (506u32) ;
+pub const ATOM_JSWORD__61_73 : JsWord = JsWord :: pack_static (507u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_4C_65_6E_67_74_68_4C_69_73_74 : JsWord = JsWord :: pack_static (508u32) ;
+pub const ATOM_JSWORD__42_6F_74_74_6F_6D_20_6C_69_6E_65 : JsWord = JsWord :: pack_static (509u32) ;
+pub const ATOM_JSWORD__69_66 : JsWord = JsWord :: pack_static (510u32) ;
+pub const ATOM_JSWORD__54_65_78_74 : JsWord = JsWord :: pack_static (511u32) ;
+pub const ATOM_JSWORD__43_53_53_52_75_6C_65 : JsWord = JsWord :: pack_static (512u32) ;
+pub const ATOM_JSWORD__49_6D_61_67_65_42_69_74_6D_61_70_52_65_6E_64_65_72_69_6E_67_43_6F_6E_74_65_78_74 : JsWord = JsWord :: pack_static (513u32) ;
+pub const ATOM_JSWORD__61_62_73_74_72_61_63_74 : JsWord = JsWord :: pack_static (514u32) ;
+pub const ATOM_JSWORD__53_65_63_75_72_69_74_79_50_6F_6C_69_63_79_56_69_6F_6C_61_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (515u32) ;
+pub const ATOM_JSWORD__43_72_65_64_65_6E_74_69_61_6C : JsWord = JsWord :: pack_static (516u32) ;
+pub const ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_54_69_6D_65_6C_69_6E_65 : JsWord = JsWord :: pack_static (517u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4F_70_74_69_6F_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (518u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_49_46_72_61_6D_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (519u32) ;
+pub const ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_45_66_66_65_63_74_54_69_6D_69_6E_67_52_65_61_64_4F_6E_6C_79 : JsWord = JsWord :: pack_static (520u32) ;
+pub const ATOM_JSWORD__66_69_6E_61_6C_6C_79 : JsWord = JsWord :: pack_static (521u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_52_65_6E_64_65_72_69_6E_67_43_6F_6E_74_65_78_74 : JsWord = JsWord :: pack_static (522u32) ;
+pub const ATOM_JSWORD__57_65_61_6B_53_65_74 : JsWord = JsWord :: pack_static (523u32) ;
+pub const ATOM_JSWORD__45_78_63_6C_75_64_65 : JsWord = JsWord :: pack_static (524u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (525u32) ;
+pub const ATOM_JSWORD__52_54_43_44_74_6C_73_54_72_61_6E_73_70_6F_72_74 : JsWord = JsWord :: pack_static (526u32) ;
+pub const ATOM_JSWORD__53_56_47_4C_65_6E_67_74_68_4C_69_73_74 : JsWord = JsWord :: pack_static (527u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_54_72_61_6E_73_66_6F_72_6D_46_65_65_64_62_61_63_6B : JsWord = JsWord :: pack_static (528u32) ;
+pub const ATOM_JSWORD__44_6F_63_75_6D_65_6E_74 : JsWord = JsWord :: pack_static (529u32) ;
+pub const ATOM_JSWORD__63_6C_61_73_73 : JsWord = JsWord :: pack_static (530u32) ;
+pub const ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_52_65_63_65_69_76_65_72 : JsWord = JsWord :: pack_static (531u32) ;
+pub const ATOM_JSWORD__48_65_61_64_65_72_73 : JsWord = JsWord :: pack_static (532u32) ;
+pub const ATOM_JSWORD__49_6D_61_67_65_44_61_74_61 : JsWord = JsWord :: pack_static (533u32) ;
+pub const ATOM_JSWORD__4D_61_74_68 : JsWord = JsWord :: pack_static (534u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_42_75_74_74_6F_6E_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (535u32) ;
+pub const ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_45_66_66_65_63_74_54_69_6D_69_6E_67 : JsWord = JsWord :: pack_static (536u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_4B_65_79_4D_65_73_73_61_67_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (537u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_44_69_66_66_75_73_65_4C_69_67_68_74_69_6E_67_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (538u32) ;
+pub const ATOM_JSWORD__6F_66 : JsWord = JsWord :: pack_static (539u32) ;
+pub const ATOM_JSWORD__4D_49_44_49_50_6F_72_74 : JsWord = JsWord :: pack_static (540u32) ;
+pub const ATOM_JSWORD__69_6E_73_74_61_6E_63_65_6F_66 : JsWord = JsWord :: pack_static (541u32) ;
+pub const ATOM_JSWORD__53_56_47_47_72_61_64_69_65_6E_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (542u32) ;
+pub const ATOM_JSWORD__4D_65_64_69_61_52_65_63_6F_72_64_65_72 : JsWord = JsWord :: pack_static (543u32) ;
+pub const ATOM_JSWORD__49_44_42_46_61_63_74_6F_72_79 : JsWord = JsWord :: pack_static (544u32) ;
+pub const ATOM_JSWORD__42_75_64_67_65_74_53_65_72_76_69_63_65 : JsWord = JsWord :: pack_static (545u32) ;
+pub const ATOM_JSWORD__46_69_6C_65_4C_69_73_74 : JsWord = JsWord :: pack_static (546u32) ;
+pub const ATOM_JSWORD__46_69_6C_65_52_65_61_64_65_72 : JsWord = JsWord :: pack_static (547u32) ;
+pub const ATOM_JSWORD__42_61_74_74_65_72_79_4D_61_6E_61_67_65_72 : JsWord = JsWord :: pack_static (548u32) ;
+pub const ATOM_JSWORD__49_49_52_46_69_6C_74_65_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (549u32) ;
+pub const ATOM_JSWORD__4F_66_66_73_63_72_65_65_6E_43_61_6E_76_61_73 : JsWord = JsWord :: pack_static (550u32) ;
+pub const ATOM_JSWORD__73_74_72_69_6E_67 : JsWord = JsWord :: pack_static (551u32) ;
+pub const ATOM_JSWORD__53_74_65_72_65_6F_50_61_6E_6E_65_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (552u32) ;
+pub const ATOM_JSWORD__44_6F_63_75_6D_65_6E_74_54_79_70_65 : JsWord = JsWord :: pack_static (553u32) ;
+pub const ATOM_JSWORD__75_72_6C : JsWord = JsWord :: pack_static (554u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_57_6F_72_6B_6C_65_74_47_6C_6F_62_61_6C_53_63_6F_70_65 : JsWord = JsWord :: pack_static (555u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F : JsWord = JsWord :: pack_static (556u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_42_52_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (557u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_46_75_6E_63_47_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (558u32) ;
+pub const ATOM_JSWORD__64_65_66_61_75_6C_74 : JsWord = JsWord :: pack_static (559u32) ;
+pub const ATOM_JSWORD__69_6E_66_65_72 : JsWord = JsWord :: pack_static (560u32) ;
+pub const ATOM_JSWORD__53_65_72_76_69_63_65_57_6F_72_6B_65_72_43_6F_6E_74_61_69_6E_65_72 : JsWord = JsWord :: pack_static (561u32) ;
+pub const ATOM_JSWORD__52_65_66_6C_65_63_74 : JsWord = JsWord :: pack_static (562u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4D_61_70_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (563u32) ;
+pub const ATOM_JSWORD__42_69_67_55_69_6E_74_36_34_41_72_72_61_79 : JsWord = JsWord :: pack_static (564u32) ;
+pub const ATOM_JSWORD__50_61_79_6D_65_6E_74_52_65_71_75_65_73_74 : JsWord = JsWord :: pack_static (565u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_50_61_72_61_6D_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (566u32) ;
+pub const ATOM_JSWORD__53_56_47_47_72_61_70_68_69_63_73_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (567u32) ;
+pub const ATOM_JSWORD__73_77_69_74_63_68 : JsWord = JsWord :: pack_static (568u32) ;
+pub const ATOM_JSWORD__53_56_47_46_69_6C_74_65_72_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (569u32) ;
+pub const ATOM_JSWORD__52_61_6E_67_65_45_72_72_6F_72 : JsWord = JsWord :: pack_static (570u32) ;
+pub const ATOM_JSWORD__53_56_47_53_74_72_69_6E_67_4C_69_73_74 : JsWord = JsWord :: pack_static (571u32) ;
+pub const ATOM_JSWORD__73_75_70_65_72 : JsWord = JsWord :: pack_static (572u32) ;
+pub const ATOM_JSWORD__65_76_61_6C : JsWord = JsWord :: pack_static (573u32) ;
+pub const ATOM_JSWORD__69_6D_70_6F_72_74 : JsWord = JsWord :: pack_static (574u32) ;
+pub const ATOM_JSWORD__53_56_47_4E_75_6D_62_65_72 : JsWord = JsWord :: pack_static (575u32) ;
+pub const ATOM_JSWORD__49_6E_74_38_41_72_72_61_79 : JsWord = JsWord :: pack_static (576u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_54_75_72_62_75_6C_65_6E_63_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (577u32) ;
+pub const ATOM_JSWORD__52_54_43_49_63_65_47_61_74_68_65_72_65_72 : JsWord = JsWord :: pack_static (578u32) ;
+pub const ATOM_JSWORD__69_6E_74_72_69_6E_73_69_63 : JsWord = JsWord :: pack_static (579u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65 : JsWord = JsWord :: pack_static (580u32) ;
+pub const ATOM_JSWORD__64_69_73_70_6C_61_79_4E_61_6D_65 : JsWord = JsWord :: pack_static (581u32) ;
+pub const ATOM_JSWORD__42_65_66_6F_72_65_55_6E_6C_6F_61_64_45_76_65_6E_74 : JsWord = JsWord :: pack_static (582u32) ;
+pub const ATOM_JSWORD__53_63_72_65_65_6E_4F_72_69_65_6E_74_61_74_69_6F_6E : JsWord = JsWord :: pack_static (583u32) ;
+pub const ATOM_JSWORD__49_6D_61_67_65_42_69_74_6D_61_70 : JsWord = JsWord :: pack_static (584u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_50_72_6F_63_65_73_73_69_6E_67_45_76_65_6E_74 : JsWord = JsWord :: pack_static (585u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4C_65_67_65_6E_64_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (586u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_50_6F_69_6E_74_4C_69_67_68_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (587u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4D_61_72_71_75_65_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (588u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_44_65_74_61_69_6C_73_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (589u32) ;
+pub const ATOM_JSWORD__53_70_65_65_63_68_53_79_6E_74_68_65_73_69_73_45_76_65_6E_74 : JsWord = JsWord :: pack_static (590u32) ;
+pub const ATOM_JSWORD__58_4D_4C_48_74_74_70_52_65_71_75_65_73_74_45_76_65_6E_74_54_61_72_67_65_74 : JsWord = JsWord :: pack_static (591u32) ;
+pub const ATOM_JSWORD__5F_64_65_66_69_6E_65_50_72_6F_70_65_72_74_79 : JsWord = JsWord :: pack_static (592u32) ;
+pub const ATOM_JSWORD__43_75_73_74_6F_6D_45_76_65_6E_74 : JsWord = JsWord :: pack_static (593u32) ;
+pub const ATOM_JSWORD__42_69_71_75_61_64_46_69_6C_74_65_72_4E_6F_64_65 : JsWord = JsWord :: pack_static (594u32) ;
+pub const ATOM_JSWORD__4E_6F_74_69_66_69_63_61_74_69_6F_6E : JsWord = JsWord :: pack_static (595u32) ;
+pub const ATOM_JSWORD__69_6E : JsWord = JsWord :: pack_static (596u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_46_75_6E_63_52_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (597u32) ;
+pub const ATOM_JSWORD__53_56_47_53_74_79_6C_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (598u32) ;
+pub const ATOM_JSWORD__53_74_6F_72_61_67_65 : JsWord = JsWord :: pack_static (599u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_4D_65_64_69_61_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (600u32) ;
+pub const ATOM_JSWORD__6B_65_79 : JsWord = JsWord :: pack_static (601u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_47_61_75_73_73_69_61_6E_42_6C_75_72_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (602u32) ;
+pub const ATOM_JSWORD__4B_65_79_62_6F_61_72_64_45_76_65_6E_74 : JsWord = JsWord :: pack_static (603u32) ;
+pub const ATOM_JSWORD__50_72_6F_67_72_65_73_73_45_76_65_6E_74 : JsWord = JsWord :: pack_static (604u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_43_61_6E_76_61_73_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (605u32) ;
+pub const ATOM_JSWORD__52_54_43_50_65_65_72_43_6F_6E_6E_65_63_74_69_6F_6E_49_63_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (606u32) ;
+pub const ATOM_JSWORD__53_56_47_52_65_63_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (607u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_41_72_65_61_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (608u32) ;
+pub const ATOM_JSWORD__72_65_74_75_72_6E : JsWord = JsWord :: pack_static (609u32) ;
+pub const ATOM_JSWORD__54_65_78_74_54_72_61_63_6B : JsWord = JsWord :: pack_static (610u32) ;
+pub const ATOM_JSWORD__79_69_65_6C_64 : JsWord = JsWord :: pack_static (611u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_55_4C_69_73_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (612u32) ;
+pub const ATOM_JSWORD__54_79_70_65_45_72_72_6F_72 : JsWord = JsWord :: pack_static (613u32) ;
+pub const ATOM_JSWORD__48_61_73_68_43_68_61_6E_67_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (614u32) ;
+pub const ATOM_JSWORD__4D_75_74_61_74_69_6F_6E_52_65_63_6F_72_64 : JsWord = JsWord :: pack_static (615u32) ;
+pub const ATOM_JSWORD__43_53_53_47_72_6F_75_70_69_6E_67_52_75_6C_65 : JsWord = JsWord :: pack_static (616u32) ;
+pub const ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_45_6E_74_72_79 : JsWord = JsWord :: pack_static (617u32) ;
+pub const ATOM_JSWORD__44_61_74_61_54_72_61_6E_73_66_65_72 : JsWord = JsWord :: pack_static (618u32) ;
+pub const ATOM_JSWORD__53_56_47_46_45_43_6F_6D_70_6F_6E_65_6E_74_54_72_61_6E_73_66_65_72_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (619u32) ;
+pub const ATOM_JSWORD__57_72_69_74_61_62_6C_65_53_74_72_65_61_6D : JsWord = JsWord :: pack_static (620u32) ;
+pub const ATOM_JSWORD__74_6F_53_74_72_69_6E_67 : JsWord = JsWord :: pack_static (621u32) ;
+pub const ATOM_JSWORD__43_6C_6F_73_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (622u32) ;
+pub const ATOM_JSWORD__53_56_47_4D_50_61_74_68_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (623u32) ;
+pub const ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_4E_75_6D_62_65_72 : JsWord = JsWord :: pack_static (624u32) ;
+pub const ATOM_JSWORD__63_61_74_63_68 : JsWord = JsWord :: pack_static (625u32) ;
+pub const ATOM_JSWORD__73_74_61_74_69_63 : JsWord = JsWord :: pack_static (626u32) ;
+pub const ATOM_JSWORD__45_76_65_6E_74_53_6F_75_72_63_65 : JsWord = JsWord :: pack_static (627u32) ;
+pub const ATOM_JSWORD__44_4F_4D_4D_61_74_72_69_78 : JsWord = JsWord :: pack_static (628u32) ;
+pub const ATOM_JSWORD__50_61_79_6D_65_6E_74_41_64_64_72_65_73_73 : JsWord = JsWord :: pack_static (629u32) ;
+pub const ATOM_JSWORD__49_44_42_4B_65_79_52_61_6E_67_65 : JsWord = JsWord :: pack_static (630u32) ;
+pub const ATOM_JSWORD__54_65_78_74_44_65_63_6F_64_65_72 : JsWord = JsWord :: pack_static (631u32) ;
+pub const ATOM_JSWORD__42_61_72_50_72_6F_70 : JsWord = JsWord :: pack_static (632u32) ;
+pub const ATOM_JSWORD__62_6F_6F_6C_65_61_6E : JsWord = JsWord :: pack_static (633u32) ;
+pub const ATOM_JSWORD__52_54_43_49_63_65_43_61_6E_64_69_64_61_74_65 : JsWord = JsWord :: pack_static (634u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (635u32) ;
+pub const ATOM_JSWORD__54_72_61_63_6B_45_76_65_6E_74 : JsWord = JsWord :: pack_static (636u32) ;
+pub const ATOM_JSWORD__41_75_64_69_6F_42_75_66_66_65_72 : JsWord = JsWord :: pack_static (637u32) ;
+pub const ATOM_JSWORD__6E_65_77 : JsWord = JsWord :: pack_static (638u32) ;
+pub const ATOM_JSWORD__53_68_61_72_65_64_57_6F_72_6B_65_72 : JsWord = JsWord :: pack_static (639u32) ;
+pub const ATOM_JSWORD__49_64_6C_65_44_65_61_64_6C_69_6E_65 : JsWord = JsWord :: pack_static (640u32) ;
+pub const ATOM_JSWORD__53_74_6F_72_61_67_65_4D_61_6E_61_67_65_72 : JsWord = JsWord :: pack_static (641u32) ;
+pub const ATOM_JSWORD__57_65_62_47_4C_43_6F_6E_74_65_78_74_45_76_65_6E_74 : JsWord = JsWord :: pack_static (642u32) ;
+pub const ATOM_JSWORD__50_6F_69_6E_74_65_72_45_76_65_6E_74 : JsWord = JsWord :: pack_static (643u32) ;
+pub const ATOM_JSWORD__4C_6F_63_61_74_69_6F_6E : JsWord = JsWord :: pack_static (644u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_43_6F_6C_6C_65_63_74_69_6F_6E : JsWord = JsWord :: pack_static (645u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_46_69_65_6C_64_53_65_74_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (646u32) ;
+pub const ATOM_JSWORD__61_77_61_69_74 : JsWord = JsWord :: pack_static (647u32) ;
+pub const ATOM_JSWORD__53_79_6E_74_61_78_45_72_72_6F_72 : JsWord = JsWord :: pack_static (648u32) ;
+pub const ATOM_JSWORD__61_70_70_6C_79 : JsWord = JsWord :: pack_static (649u32) ;
+pub const ATOM_JSWORD__54_65_78_74_4D_65_74_72_69_63_73 : JsWord = JsWord :: pack_static (650u32) ;
+pub const ATOM_JSWORD__46_75_6E_63_74_69_6F_6E : JsWord = JsWord :: pack_static (651u32) ;
+pub const ATOM_JSWORD__52_61_64_69_6F_4E_6F_64_65_4C_69_73_74 : JsWord = JsWord :: pack_static (652u32) ;
+pub const ATOM_JSWORD__47_61_6D_65_70_61_64 : JsWord = JsWord :: pack_static (653u32) ;
+pub const ATOM_JSWORD__44_65_76_69_63_65_4D_6F_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (654u32) ;
+pub const ATOM_JSWORD__43_61_6E_76_61_73_47_72_61_64_69_65_6E_74 : JsWord = JsWord :: pack_static (655u32) ;
+pub const ATOM_JSWORD__4D_49_44_49_43_6F_6E_6E_65_63_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (656u32) ;
+pub const ATOM_JSWORD__4E_61_76_69_67_61_74_69_6F_6E_50_72_65_6C_6F_61_64_4D_61_6E_61_67_65_72 : JsWord = JsWord :: pack_static (657u32) ;
+pub const ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_45_66_66_65_63_74_52_65_61_64_4F_6E_6C_79 : JsWord = JsWord :: pack_static (658u32) ;
+pub const ATOM_JSWORD__69_74_65_72_61_74_6F_72 : JsWord = JsWord :: pack_static (659u32) ;
+pub const ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_41_76_61_69_6C_61_62_69_6C_69_74_79 : JsWord = JsWord :: pack_static (660u32) ;
+pub const ATOM_JSWORD__4D_49_44_49_4F_75_74_70_75_74 : JsWord = JsWord :: pack_static (661u32) ;
+pub const ATOM_JSWORD__4D_49_44_49_4F_75_74_70_75_74_4D_61_70 : JsWord = JsWord :: pack_static (662u32) ;
+pub const ATOM_JSWORD__61_6E_64 : JsWord = JsWord :: pack_static (663u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_53_68_61_64_6F_77_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (664u32) ;
+pub const ATOM_JSWORD__55_52_4C_53_65_61_72_63_68_50_61_72_61_6D_73 : JsWord = JsWord :: pack_static (665u32) ;
+pub const ATOM_JSWORD__50_61_79_6D_65_6E_74_52_65_73_70_6F_6E_73_65 : JsWord = JsWord :: pack_static (666u32) ;
+pub const ATOM_JSWORD__52_54_43_50_65_65_72_43_6F_6E_6E_65_63_74_69_6F_6E : JsWord = JsWord :: pack_static (667u32) ;
+pub const ATOM_JSWORD__4D_75_74_61_74_69_6F_6E_45_76_65_6E_74 : JsWord = JsWord :: pack_static (668u32) ;
+pub const ATOM_JSWORD__53_56_47_4D_61_73_6B_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (669u32) ;
+pub const ATOM_JSWORD__53_56_47_53_77_69_74_63_68_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (670u32) ;
+pub const ATOM_JSWORD__64_65_62_75_67_67_65_72 : JsWord = JsWord :: pack_static (671u32) ;
+pub const ATOM_JSWORD__54_6F_75_63_68_45_76_65_6E_74 : JsWord = JsWord :: pack_static (672u32) ;
+pub const ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_52_6F_77_45_6C_65_6D_65_6E_74 : JsWord = JsWord :: pack_static (673u32) ;
+pub const ATOM_JSWORD__65_6C_73_65 : JsWord = JsWord :: pack_static (674u32) ;
+pub const ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_43_6F_6E_6E_65_63_74_69_6F_6E_43_6C_6F_73_65_45_76_65_6E_74 : JsWord = JsWord :: pack_static (675u32) ;
+pub const ATOM_JSWORD__57_69_6E_64_6F_77 : JsWord = JsWord :: pack_static (676u32) ;
+# [macro_export] macro_rules ! js_word { ("CSSPageRule") => { $ crate :: ATOM_JSWORD__43_53_53_50_61_67_65_52_75_6C_65 } ;
+("SVGAElement") => { $ crate :: ATOM_JSWORD__53_56_47_41_45_6C_65_6D_65_6E_74 } ;
+("DOMStringList") => { $ crate :: ATOM_JSWORD__44_4F_4D_53_74_72_69_6E_67_4C_69_73_74 } ;
+("RemotePlayback") => { $ crate :: ATOM_JSWORD__52_65_6D_6F_74_65_50_6C_61_79_62_61_63_6B } ;
+("HTMLMetaElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4D_65_74_61_45_6C_65_6D_65_6E_74 } ;
+("SVGAngle") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_67_6C_65 } ;
+("HTMLDivElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_44_69_76_45_6C_65_6D_65_6E_74 } ;
+("PeriodicWave") => { $ crate :: ATOM_JSWORD__50_65_72_69_6F_64_69_63_57_61_76_65 } ;
+("SVGAnimationElement") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_69_6F_6E_45_6C_65_6D_65_6E_74 } ;
+("HTMLSpanElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_53_70_61_6E_45_6C_65_6D_65_6E_74 } ;
+("HTMLHeadingElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_48_65_61_64_69_6E_67_45_6C_65_6D_65_6E_74 } ;
+("PageTransitionEvent") => { $ crate :: ATOM_JSWORD__50_61_67_65_54_72_61_6E_73_69_74_69_6F_6E_45_76_65_6E_74 } ;
+("HTMLImageElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_49_6D_61_67_65_45_6C_65_6D_65_6E_74 } ;
+("MediaKeySystemAccess") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_4B_65_79_53_79_73_74_65_6D_41_63_63_65_73_73 } ;
+("IDBCursor") => { $ crate :: ATOM_JSWORD__49_44_42_43_75_72_73_6F_72 } ;
+("SVGTextElement") => { $ crate :: ATOM_JSWORD__53_56_47_54_65_78_74_45_6C_65_6D_65_6E_74 } ;
+("SVGLinearGradientElement") => { $ crate :: ATOM_JSWORD__53_56_47_4C_69_6E_65_61_72_47_72_61_64_69_65_6E_74_45_6C_65_6D_65_6E_74 } ;
+("SVGFEDropShadowElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_44_72_6F_70_53_68_61_64_6F_77_45_6C_65_6D_65_6E_74 } ;
+("HTMLObjectElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4F_62_6A_65_63_74_45_6C_65_6D_65_6E_74 } ;
+("SVGPoint") => { $ crate :: ATOM_JSWORD__53_56_47_50_6F_69_6E_74 } ;
+("MediaStreamEvent") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D_45_76_65_6E_74 } ;
+("SVGUseElement") => { $ crate :: ATOM_JSWORD__53_56_47_55_73_65_45_6C_65_6D_65_6E_74 } ;
+("Uint8ClampedArray") => { $ crate :: ATOM_JSWORD__55_69_6E_74_38_43_6C_61_6D_70_65_64_41_72_72_61_79 } ;
+("interface") => { $ crate :: ATOM_JSWORD__69_6E_74_65_72_66_61_63_65 } ;
+("SVGFEMergeElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_4D_65_72_67_65_45_6C_65_6D_65_6E_74 } ;
+("PresentationConnectionAvailableEvent") => { $ crate :: ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_43_6F_6E_6E_65_63_74_69_6F_6E_41_76_61_69_6C_61_62_6C_65_45_76_65_6E_74 } ;
+("CSSSupportsRule") => { $ crate :: ATOM_JSWORD__43_53_53_53_75_70_70_6F_72_74_73_52_75_6C_65 } ;
+("MediaStreamTrackEvent") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D_54_72_61_63_6B_45_76_65_6E_74 } ;
+("throw") => { $ crate :: ATOM_JSWORD__74_68_72_6F_77 } ;
+("DOMParser") => { $ crate :: ATOM_JSWORD__44_4F_4D_50_61_72_73_65_72 } ;
+("SVGAnimatedBoolean") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_42_6F_6F_6C_65_61_6E } ;
+("GamepadButton") => { $ crate :: ATOM_JSWORD__47_61_6D_65_70_61_64_42_75_74_74_6F_6E } ;
+("File") => { $ crate :: ATOM_JSWORD__46_69_6C_65 } ;
+("XMLHttpRequest") => { $ crate :: ATOM_JSWORD__58_4D_4C_48_74_74_70_52_65_71_75_65_73_74 } ;
+("SourceBufferList") => { $ crate :: ATOM_JSWORD__53_6F_75_72_63_65_42_75_66_66_65_72_4C_69_73_74 } ;
+("DOMPointReadOnly") => { $ crate :: ATOM_JSWORD__44_4F_4D_50_6F_69_6E_74_52_65_61_64_4F_6E_6C_79 } ;
+("WebGLUniformLocation") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_55_6E_69_66_6F_72_6D_4C_6F_63_61_74_69_6F_6E } ;
+("OscillatorNode") => { $ crate :: ATOM_JSWORD__4F_73_63_69_6C_6C_61_74_6F_72_4E_6F_64_65 } ;
+("MediaDeviceInfo") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_44_65_76_69_63_65_49_6E_66_6F } ;
+("const") => { $ crate :: ATOM_JSWORD__63_6F_6E_73_74 } ;
+("Intl") => { $ crate :: ATOM_JSWORD__49_6E_74_6C } ;
+("RTCDataChannelEvent") => { $ crate :: ATOM_JSWORD__52_54_43_44_61_74_61_43_68_61_6E_6E_65_6C_45_76_65_6E_74 } ;
+("Touch") => { $ crate :: ATOM_JSWORD__54_6F_75_63_68 } ;
+("ServiceWorkerRegistration") => { $ crate :: ATOM_JSWORD__53_65_72_76_69_63_65_57_6F_72_6B_65_72_52_65_67_69_73_74_72_61_74_69_6F_6E } ;
+("NonNullable") => { $ crate :: ATOM_JSWORD__4E_6F_6E_4E_75_6C_6C_61_62_6C_65 } ;
+("SVGPolygonElement") => { $ crate :: ATOM_JSWORD__53_56_47_50_6F_6C_79_67_6F_6E_45_6C_65_6D_65_6E_74 } ;
+("DOMQuad") => { $ crate :: ATOM_JSWORD__44_4F_4D_51_75_61_64 } ;
+("SVGFEImageElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_49_6D_61_67_65_45_6C_65_6D_65_6E_74 } ;
+("HTMLTableSectionElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_53_65_63_74_69_6F_6E_45_6C_65_6D_65_6E_74 } ;
+("IDBTransaction") => { $ crate :: ATOM_JSWORD__49_44_42_54_72_61_6E_73_61_63_74_69_6F_6E } ;
+("HTMLVideoElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_56_69_64_65_6F_45_6C_65_6D_65_6E_74 } ;
+("SVGStopElement") => { $ crate :: ATOM_JSWORD__53_56_47_53_74_6F_70_45_6C_65_6D_65_6E_74 } ;
+("TextEncoder") => { $ crate :: ATOM_JSWORD__54_65_78_74_45_6E_63_6F_64_65_72 } ;
+("WebGLVertexArrayObject") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_56_65_72_74_65_78_41_72_72_61_79_4F_62_6A_65_63_74 } ;
+("RTCSessionDescription") => { $ crate :: ATOM_JSWORD__52_54_43_53_65_73_73_69_6F_6E_44_65_73_63_72_69_70_74_69_6F_6E } ;
+("ScriptProcessorNode") => { $ crate :: ATOM_JSWORD__53_63_72_69_70_74_50_72_6F_63_65_73_73_6F_72_4E_6F_64_65 } ;
+("Selection") => { $ crate :: ATOM_JSWORD__53_65_6C_65_63_74_69_6F_6E } ;
+("SVGDiscardElement") => { $ crate :: ATOM_JSWORD__53_56_47_44_69_73_63_61_72_64_45_6C_65_6D_65_6E_74 } ;
+("extends") => { $ crate :: ATOM_JSWORD__65_78_74_65_6E_64_73 } ;
+("PluginArray") => { $ crate :: ATOM_JSWORD__50_6C_75_67_69_6E_41_72_72_61_79 } ;
+("VisualViewport") => { $ crate :: ATOM_JSWORD__56_69_73_75_61_6C_56_69_65_77_70_6F_72_74 } ;
+("Map") => { $ crate :: ATOM_JSWORD__4D_61_70 } ;
+("MediaEncryptedEvent") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_45_6E_63_72_79_70_74_65_64_45_76_65_6E_74 } ;
+("RTCDataChannel") => { $ crate :: ATOM_JSWORD__52_54_43_44_61_74_61_43_68_61_6E_6E_65_6C } ;
+("SubtleCrypto") => { $ crate :: ATOM_JSWORD__53_75_62_74_6C_65_43_72_79_70_74_6F } ;
+("SVGPointList") => { $ crate :: ATOM_JSWORD__53_56_47_50_6F_69_6E_74_4C_69_73_74 } ;
+("HTMLEmbedElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_45_6D_62_65_64_45_6C_65_6D_65_6E_74 } ;
+("MediaStream") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D } ;
+("HTMLHRElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_48_52_45_6C_65_6D_65_6E_74 } ;
+("DataTransferItemList") => { $ crate :: ATOM_JSWORD__44_61_74_61_54_72_61_6E_73_66_65_72_49_74_65_6D_4C_69_73_74 } ;
+("HTMLPictureElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_50_69_63_74_75_72_65_45_6C_65_6D_65_6E_74 } ;
+("Required") => { $ crate :: ATOM_JSWORD__52_65_71_75_69_72_65_64 } ;
+("ApplicationCache") => { $ crate :: ATOM_JSWORD__41_70_70_6C_69_63_61_74_69_6F_6E_43_61_63_68_65 } ;
+("Worker") => { $ crate :: ATOM_JSWORD__57_6F_72_6B_65_72 } ;
+("BigInt") => { $ crate :: ATOM_JSWORD__42_69_67_49_6E_74 } ;
+("PerformanceMeasure") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4D_65_61_73_75_72_65 } ;
+("AudioWorkletProcessor") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_57_6F_72_6B_6C_65_74_50_72_6F_63_65_73_73_6F_72 } ;
+("RTCTrackEvent") => { $ crate :: ATOM_JSWORD__52_54_43_54_72_61_63_6B_45_76_65_6E_74 } ;
+("Atomics") => { $ crate :: ATOM_JSWORD__41_74_6F_6D_69_63_73 } ;
+("PaymentRequestUpdateEvent") => { $ crate :: ATOM_JSWORD__50_61_79_6D_65_6E_74_52_65_71_75_65_73_74_55_70_64_61_74_65_45_76_65_6E_74 } ;
+("env") => { $ crate :: ATOM_JSWORD__65_6E_76 } ;
+("SVGFESpotLightElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_53_70_6F_74_4C_69_67_68_74_45_6C_65_6D_65_6E_74 } ;
+("for") => { $ crate :: ATOM_JSWORD__66_6F_72 } ;
+("do") => { $ crate :: ATOM_JSWORD__64_6F } ;
+("arguments") => { $ crate :: ATOM_JSWORD__61_72_67_75_6D_65_6E_74_73 } ;
+("SVGFECompositeElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_43_6F_6D_70_6F_73_69_74_65_45_6C_65_6D_65_6E_74 } ;
+("AudioWorkletNode") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_57_6F_72_6B_6C_65_74_4E_6F_64_65 } ;
+("PushManager") => { $ crate :: ATOM_JSWORD__50_75_73_68_4D_61_6E_61_67_65_72 } ;
+("ValidityState") => { $ crate :: ATOM_JSWORD__56_61_6C_69_64_69_74_79_53_74_61_74_65 } ;
+("BlobEvent") => { $ crate :: ATOM_JSWORD__42_6C_6F_62_45_76_65_6E_74 } ;
+("only") => { $ crate :: ATOM_JSWORD__6F_6E_6C_79 } ;
+("CharacterData") => { $ crate :: ATOM_JSWORD__43_68_61_72_61_63_74_65_72_44_61_74_61 } ;
+("HTMLFontElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_46_6F_6E_74_45_6C_65_6D_65_6E_74 } ;
+("AudioScheduledSourceNode") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_53_63_68_65_64_75_6C_65_64_53_6F_75_72_63_65_4E_6F_64_65 } ;
+("WebGLShaderPrecisionFormat") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_53_68_61_64_65_72_50_72_65_63_69_73_69_6F_6E_46_6F_72_6D_61_74 } ;
+("ResizeObserver") => { $ crate :: ATOM_JSWORD__52_65_73_69_7A_65_4F_62_73_65_72_76_65_72 } ;
+("WebGL2RenderingContext") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_32_52_65_6E_64_65_72_69_6E_67_43_6F_6E_74_65_78_74 } ;
+("HTMLOptionsCollection") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4F_70_74_69_6F_6E_73_43_6F_6C_6C_65_63_74_69_6F_6E } ;
+("Boolean") => { $ crate :: ATOM_JSWORD__42_6F_6F_6C_65_61_6E } ;
+("PerformanceResourceTiming") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_52_65_73_6F_75_72_63_65_54_69_6D_69_6E_67 } ;
+("WebGLTexture") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_54_65_78_74_75_72_65 } ;
+("VTTCue") => { $ crate :: ATOM_JSWORD__56_54_54_43_75_65 } ;
+("XMLHttpRequestUpload") => { $ crate :: ATOM_JSWORD__58_4D_4C_48_74_74_70_52_65_71_75_65_73_74_55_70_6C_6F_61_64 } ;
+("SVGMatrix") => { $ crate :: ATOM_JSWORD__53_56_47_4D_61_74_72_69_78 } ;
+("HTMLDirectoryElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_44_69_72_65_63_74_6F_72_79_45_6C_65_6D_65_6E_74 } ;
+("SVGViewElement") => { $ crate :: ATOM_JSWORD__53_56_47_56_69_65_77_45_6C_65_6D_65_6E_74 } ;
+("SVGAnimatedPreserveAspectRatio") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_50_72_65_73_65_72_76_65_41_73_70_65_63_74_52_61_74_69_6F } ;
+("HTMLElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_45_6C_65_6D_65_6E_74 } ;
+("PerformanceNavigation") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4E_61_76_69_67_61_74_69_6F_6E } ;
+("declare") => { $ crate :: ATOM_JSWORD__64_65_63_6C_61_72_65 } ;
+("unknown") => { $ crate :: ATOM_JSWORD__75_6E_6B_6E_6F_77_6E } ;
+("SVGAnimateMotionElement") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_4D_6F_74_69_6F_6E_45_6C_65_6D_65_6E_74 } ;
+("SVGGElement") => { $ crate :: ATOM_JSWORD__53_56_47_47_45_6C_65_6D_65_6E_74 } ;
+("meta") => { $ crate :: ATOM_JSWORD__6D_65_74_61 } ;
+("ImageCapture") => { $ crate :: ATOM_JSWORD__49_6D_61_67_65_43_61_70_74_75_72_65 } ;
+("AudioListener") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_4C_69_73_74_65_6E_65_72 } ;
+("Image") => { $ crate :: ATOM_JSWORD__49_6D_61_67_65 } ;
+("TreeWalker") => { $ crate :: ATOM_JSWORD__54_72_65_65_57_61_6C_6B_65_72 } ;
+("CredentialsContainer") => { $ crate :: ATOM_JSWORD__43_72_65_64_65_6E_74_69_61_6C_73_43_6F_6E_74_61_69_6E_65_72 } ;
+("readonly") => { $ crate :: ATOM_JSWORD__72_65_61_64_6F_6E_6C_79 } ;
+("Response") => { $ crate :: ATOM_JSWORD__52_65_73_70_6F_6E_73_65 } ;
+("SVGFEOffsetElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_4F_66_66_73_65_74_45_6C_65_6D_65_6E_74 } ;
+("never") => { $ crate :: ATOM_JSWORD__6E_65_76_65_72 } ;
+("Set") => { $ crate :: ATOM_JSWORD__53_65_74 } ;
+("PhotoCapabilities") => { $ crate :: ATOM_JSWORD__50_68_6F_74_6F_43_61_70_61_62_69_6C_69_74_69_65_73 } ;
+("PannerNode") => { $ crate :: ATOM_JSWORD__50_61_6E_6E_65_72_4E_6F_64_65 } ;
+("delete") => { $ crate :: ATOM_JSWORD__64_65_6C_65_74_65 } ;
+("HTMLModElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4D_6F_64_45_6C_65_6D_65_6E_74 } ;
+("SVGTSpanElement") => { $ crate :: ATOM_JSWORD__53_56_47_54_53_70_61_6E_45_6C_65_6D_65_6E_74 } ;
+("HTMLDialogElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_44_69_61_6C_6F_67_45_6C_65_6D_65_6E_74 } ;
+("PerformanceObserver") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4F_62_73_65_72_76_65_72 } ;
+("IntersectionObserverEntry") => { $ crate :: ATOM_JSWORD__49_6E_74_65_72_73_65_63_74_69_6F_6E_4F_62_73_65_72_76_65_72_45_6E_74_72_79 } ;
+("IDBCursorWithValue") => { $ crate :: ATOM_JSWORD__49_44_42_43_75_72_73_6F_72_57_69_74_68_56_61_6C_75_65 } ;
+("NamedNodeMap") => { $ crate :: ATOM_JSWORD__4E_61_6D_65_64_4E_6F_64_65_4D_61_70 } ;
+("typeof") => { $ crate :: ATOM_JSWORD__74_79_70_65_6F_66 } ;
+("Comment") => { $ crate :: ATOM_JSWORD__43_6F_6D_6D_65_6E_74 } ;
+("AbortSignal") => { $ crate :: ATOM_JSWORD__41_62_6F_72_74_53_69_67_6E_61_6C } ;
+("false") => { $ crate :: ATOM_JSWORD__66_61_6C_73_65 } ;
+("Date") => { $ crate :: ATOM_JSWORD__44_61_74_65 } ;
+("Int32Array") => { $ crate :: ATOM_JSWORD__49_6E_74_33_32_41_72_72_61_79 } ;
+("HTMLTableColElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_43_6F_6C_45_6C_65_6D_65_6E_74 } ;
+("unique") => { $ crate :: ATOM_JSWORD__75_6E_69_71_75_65 } ;
+("HTMLDataElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_44_61_74_61_45_6C_65_6D_65_6E_74 } ;
+("ChannelSplitterNode") => { $ crate :: ATOM_JSWORD__43_68_61_6E_6E_65_6C_53_70_6C_69_74_74_65_72_4E_6F_64_65 } ;
+("MediaStreamAudioSourceNode") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D_41_75_64_69_6F_53_6F_75_72_63_65_4E_6F_64_65 } ;
+("try") => { $ crate :: ATOM_JSWORD__74_72_79 } ;
+("AudioNode") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_4E_6F_64_65 } ;
+("PushSubscription") => { $ crate :: ATOM_JSWORD__50_75_73_68_53_75_62_73_63_72_69_70_74_69_6F_6E } ;
+("CanvasPattern") => { $ crate :: ATOM_JSWORD__43_61_6E_76_61_73_50_61_74_74_65_72_6E } ;
+("createClass") => { $ crate :: ATOM_JSWORD__63_72_65_61_74_65_43_6C_61_73_73 } ;
+("JSON") => { $ crate :: ATOM_JSWORD__4A_53_4F_4E } ;
+("type") => { $ crate :: ATOM_JSWORD__74_79_70_65 } ;
+("MediaQueryListEvent") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_51_75_65_72_79_4C_69_73_74_45_76_65_6E_74 } ;
+("FocusEvent") => { $ crate :: ATOM_JSWORD__46_6F_63_75_73_45_76_65_6E_74 } ;
+("DOMImplementation") => { $ crate :: ATOM_JSWORD__44_4F_4D_49_6D_70_6C_65_6D_65_6E_74_61_74_69_6F_6E } ;
+("SpeechSynthesisUtterance") => { $ crate :: ATOM_JSWORD__53_70_65_65_63_68_53_79_6E_74_68_65_73_69_73_55_74_74_65_72_61_6E_63_65 } ;
+("HTMLMenuElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4D_65_6E_75_45_6C_65_6D_65_6E_74 } ;
+("PerformancePaintTiming") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_50_61_69_6E_74_54_69_6D_69_6E_67 } ;
+("HTMLDListElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_44_4C_69_73_74_45_6C_65_6D_65_6E_74 } ;
+("ReadonlyArray") => { $ crate :: ATOM_JSWORD__52_65_61_64_6F_6E_6C_79_41_72_72_61_79 } ;
+("HTMLStyleElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_53_74_79_6C_65_45_6C_65_6D_65_6E_74 } ;
+("main") => { $ crate :: ATOM_JSWORD__6D_61_69_6E } ;
+("SVGAnimatedLength") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_4C_65_6E_67_74_68 } ;
+("SVGTextContentElement") => { $ crate :: ATOM_JSWORD__53_56_47_54_65_78_74_43_6F_6E_74_65_6E_74_45_6C_65_6D_65_6E_74 } ;
+("MimeType") => { $ crate :: ATOM_JSWORD__4D_69_6D_65_54_79_70_65 } ;
+("get") => { $ crate :: ATOM_JSWORD__67_65_74 } ;
+("SVGTransform") => { $ crate :: ATOM_JSWORD__53_56_47_54_72_61_6E_73_66_6F_72_6D } ;
+("DataView") => { $ crate :: ATOM_JSWORD__44_61_74_61_56_69_65_77 } ;
+("MediaList") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_4C_69_73_74 } ;
+("URL") => { $ crate :: ATOM_JSWORD__55_52_4C } ;
+("SVGAnimatedEnumeration") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_45_6E_75_6D_65_72_61_74_69_6F_6E } ;
+("CSSConditionRule") => { $ crate :: ATOM_JSWORD__43_53_53_43_6F_6E_64_69_74_69_6F_6E_52_75_6C_65 } ;
+("MediaStreamTrack") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D_54_72_61_63_6B } ;
+("HTMLTimeElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_69_6D_65_45_6C_65_6D_65_6E_74 } ;
+("ClipboardEvent") => { $ crate :: ATOM_JSWORD__43_6C_69_70_62_6F_61_72_64_45_76_65_6E_74 } ;
+("SVGTitleElement") => { $ crate :: ATOM_JSWORD__53_56_47_54_69_74_6C_65_45_6C_65_6D_65_6E_74 } ;
+("module") => { $ crate :: ATOM_JSWORD__6D_6F_64_75_6C_65 } ;
+("CustomElementRegistry") => { $ crate :: ATOM_JSWORD__43_75_73_74_6F_6D_45_6C_65_6D_65_6E_74_52_65_67_69_73_74_72_79 } ;
+("OfflineAudioContext") => { $ crate :: ATOM_JSWORD__4F_66_66_6C_69_6E_65_41_75_64_69_6F_43_6F_6E_74_65_78_74 } ;
+("WebGLShader") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_53_68_61_64_65_72 } ;
+("ProcessingInstruction") => { $ crate :: ATOM_JSWORD__50_72_6F_63_65_73_73_69_6E_67_49_6E_73_74_72_75_63_74_69_6F_6E } ;
+("SVGAnimateTransformElement") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_54_72_61_6E_73_66_6F_72_6D_45_6C_65_6D_65_6E_74 } ;
+("SVGFEMorphologyElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_4D_6F_72_70_68_6F_6C_6F_67_79_45_6C_65_6D_65_6E_74 } ;
+("CSSNamespaceRule") => { $ crate :: ATOM_JSWORD__43_53_53_4E_61_6D_65_73_70_61_63_65_52_75_6C_65 } ;
+("DOMTokenList") => { $ crate :: ATOM_JSWORD__44_4F_4D_54_6F_6B_65_6E_4C_69_73_74 } ;
+("Option") => { $ crate :: ATOM_JSWORD__4F_70_74_69_6F_6E } ;
+("PerformanceTiming") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_54_69_6D_69_6E_67 } ;
+("CanvasRenderingContext2D") => { $ crate :: ATOM_JSWORD__43_61_6E_76_61_73_52_65_6E_64_65_72_69_6E_67_43_6F_6E_74_65_78_74_32_44 } ;
+("SVGDefsElement") => { $ crate :: ATOM_JSWORD__53_56_47_44_65_66_73_45_6C_65_6D_65_6E_74 } ;
+("WebGLSync") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_53_79_6E_63 } ;
+("HTMLMeterElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4D_65_74_65_72_45_6C_65_6D_65_6E_74 } ;
+("UIEvent") => { $ crate :: ATOM_JSWORD__55_49_45_76_65_6E_74 } ;
+("RTCCertificate") => { $ crate :: ATOM_JSWORD__52_54_43_43_65_72_74_69_66_69_63_61_74_65 } ;
+("Record") => { $ crate :: ATOM_JSWORD__52_65_63_6F_72_64 } ;
+("CSSStyleDeclaration") => { $ crate :: ATOM_JSWORD__43_53_53_53_74_79_6C_65_44_65_63_6C_61_72_61_74_69_6F_6E } ;
+("not") => { $ crate :: ATOM_JSWORD__6E_6F_74 } ;
+("any") => { $ crate :: ATOM_JSWORD__61_6E_79 } ;
+("NODE_ENV") => { $ crate :: ATOM_JSWORD__4E_4F_44_45_5F_45_4E_56 } ;
+("Permissions") => { $ crate :: ATOM_JSWORD__50_65_72_6D_69_73_73_69_6F_6E_73 } ;
+("undefined") => { $ crate :: ATOM_JSWORD__75_6E_64_65_66_69_6E_65_64 } ;
+("Partial") => { $ crate :: ATOM_JSWORD__50_61_72_74_69_61_6C } ;
+("SVGPreserveAspectRatio") => { $ crate :: ATOM_JSWORD__53_56_47_50_72_65_73_65_72_76_65_41_73_70_65_63_74_52_61_74_69_6F } ;
+("IntersectionObserver") => { $ crate :: ATOM_JSWORD__49_6E_74_65_72_73_65_63_74_69_6F_6E_4F_62_73_65_72_76_65_72 } ;
+("MediaSettingsRange") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_53_65_74_74_69_6E_67_73_52_61_6E_67_65 } ;
+("SVGFETileElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_54_69_6C_65_45_6C_65_6D_65_6E_74 } ;
+("Uint8Array") => { $ crate :: ATOM_JSWORD__55_69_6E_74_38_41_72_72_61_79 } ;
+("StorageEvent") => { $ crate :: ATOM_JSWORD__53_74_6F_72_61_67_65_45_76_65_6E_74 } ;
+("Readonly") => { $ crate :: ATOM_JSWORD__52_65_61_64_6F_6E_6C_79 } ;
+("MessageChannel") => { $ crate :: ATOM_JSWORD__4D_65_73_73_61_67_65_43_68_61_6E_6E_65_6C } ;
+("process") => { $ crate :: ATOM_JSWORD__70_72_6F_63_65_73_73 } ;
+("while") => { $ crate :: ATOM_JSWORD__77_68_69_6C_65 } ;
+("PresentationConnection") => { $ crate :: ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_43_6F_6E_6E_65_63_74_69_6F_6E } ;
+("constructor") => { $ crate :: ATOM_JSWORD__63_6F_6E_73_74_72_75_63_74_6F_72 } ;
+("protected") => { $ crate :: ATOM_JSWORD__70_72_6F_74_65_63_74_65_64 } ;
+("GamepadEvent") => { $ crate :: ATOM_JSWORD__47_61_6D_65_70_61_64_45_76_65_6E_74 } ;
+("target") => { $ crate :: ATOM_JSWORD__74_61_72_67_65_74 } ;
+("CountQueuingStrategy") => { $ crate :: ATOM_JSWORD__43_6F_75_6E_74_51_75_65_75_69_6E_67_53_74_72_61_74_65_67_79 } ;
+("Object") => { $ crate :: ATOM_JSWORD__4F_62_6A_65_63_74 } ;
+("MediaDevices") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_44_65_76_69_63_65_73 } ;
+("HTMLSourceElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_53_6F_75_72_63_65_45_6C_65_6D_65_6E_74 } ;
+("TimeRanges") => { $ crate :: ATOM_JSWORD__54_69_6D_65_52_61_6E_67_65_73 } ;
+("Screen") => { $ crate :: ATOM_JSWORD__53_63_72_65_65_6E } ;
+("set") => { $ crate :: ATOM_JSWORD__73_65_74 } ;
+("true") => { $ crate :: ATOM_JSWORD__74_72_75_65 } ;
+("HTMLSelectElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_53_65_6C_65_63_74_45_6C_65_6D_65_6E_74 } ;
+("MediaQueryList") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_51_75_65_72_79_4C_69_73_74 } ;
+("StyleSheet") => { $ crate :: ATOM_JSWORD__53_74_79_6C_65_53_68_65_65_74 } ;
+("URIError") => { $ crate :: ATOM_JSWORD__55_52_49_45_72_72_6F_72 } ;
+("Path2D") => { $ crate :: ATOM_JSWORD__50_61_74_68_32_44 } ;
+("Infinity") => { $ crate :: ATOM_JSWORD__49_6E_66_69_6E_69_74_79 } ;
+("SVGFEDisplacementMapElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_44_69_73_70_6C_61_63_65_6D_65_6E_74_4D_61_70_45_6C_65_6D_65_6E_74 } ;
+("keyof") => { $ crate :: ATOM_JSWORD__6B_65_79_6F_66 } ;
+("AbortController") => { $ crate :: ATOM_JSWORD__41_62_6F_72_74_43_6F_6E_74_72_6F_6C_6C_65_72 } ;
+("package") => { $ crate :: ATOM_JSWORD__70_61_63_6B_61_67_65 } ;
+("DynamicsCompressorNode") => { $ crate :: ATOM_JSWORD__44_79_6E_61_6D_69_63_73_43_6F_6D_70_72_65_73_73_6F_72_4E_6F_64_65 } ;
+("MIDIInputMap") => { $ crate :: ATOM_JSWORD__4D_49_44_49_49_6E_70_75_74_4D_61_70 } ;
+("TouchList") => { $ crate :: ATOM_JSWORD__54_6F_75_63_68_4C_69_73_74 } ;
+("SVGImageElement") => { $ crate :: ATOM_JSWORD__53_56_47_49_6D_61_67_65_45_6C_65_6D_65_6E_74 } ;
+("PerformanceMark") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4D_61_72_6B } ;
+("HTMLLabelElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4C_61_62_65_6C_45_6C_65_6D_65_6E_74 } ;
+("NaN") => { $ crate :: ATOM_JSWORD__4E_61_4E } ;
+("HTMLOptGroupElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4F_70_74_47_72_6F_75_70_45_6C_65_6D_65_6E_74 } ;
+("RTCRtpSender") => { $ crate :: ATOM_JSWORD__52_54_43_52_74_70_53_65_6E_64_65_72 } ;
+("ReferenceError") => { $ crate :: ATOM_JSWORD__52_65_66_65_72_65_6E_63_65_45_72_72_6F_72 } ;
+("HTMLUnknownElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_55_6E_6B_6E_6F_77_6E_45_6C_65_6D_65_6E_74 } ;
+("CSSFontFaceRule") => { $ crate :: ATOM_JSWORD__43_53_53_46_6F_6E_74_46_61_63_65_52_75_6C_65 } ;
+("HTMLTableCaptionElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_43_61_70_74_69_6F_6E_45_6C_65_6D_65_6E_74 } ;
+("BigInt64Array") => { $ crate :: ATOM_JSWORD__42_69_67_49_6E_74_36_34_41_72_72_61_79 } ;
+("WebGLFramebuffer") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_46_72_61_6D_65_62_75_66_66_65_72 } ;
+("SourceBuffer") => { $ crate :: ATOM_JSWORD__53_6F_75_72_63_65_42_75_66_66_65_72 } ;
+("IDBDatabase") => { $ crate :: ATOM_JSWORD__49_44_42_44_61_74_61_62_61_73_65 } ;
+("HTMLFormElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_46_6F_72_6D_45_6C_65_6D_65_6E_74 } ;
+("Crypto") => { $ crate :: ATOM_JSWORD__43_72_79_70_74_6F } ;
+("StyleSheetList") => { $ crate :: ATOM_JSWORD__53_74_79_6C_65_53_68_65_65_74_4C_69_73_74 } ;
+("HTMLTableCellElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_43_65_6C_6C_45_6C_65_6D_65_6E_74 } ;
+("AudioContext") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_43_6F_6E_74_65_78_74 } ;
+("MediaStreamAudioDestinationNode") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_53_74_72_65_61_6D_41_75_64_69_6F_44_65_73_74_69_6E_61_74_69_6F_6E_4E_6F_64_65 } ;
+("SVGTransformList") => { $ crate :: ATOM_JSWORD__53_56_47_54_72_61_6E_73_66_6F_72_6D_4C_69_73_74 } ;
+("DragEvent") => { $ crate :: ATOM_JSWORD__44_72_61_67_45_76_65_6E_74 } ;
+("NodeFilter") => { $ crate :: ATOM_JSWORD__4E_6F_64_65_46_69_6C_74_65_72 } ;
+("OfflineAudioCompletionEvent") => { $ crate :: ATOM_JSWORD__4F_66_66_6C_69_6E_65_41_75_64_69_6F_43_6F_6D_70_6C_65_74_69_6F_6E_45_76_65_6E_74 } ;
+("SVGTextPathElement") => { $ crate :: ATOM_JSWORD__53_56_47_54_65_78_74_50_61_74_68_45_6C_65_6D_65_6E_74 } ;
+("SVGFEColorMatrixElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_43_6F_6C_6F_72_4D_61_74_72_69_78_45_6C_65_6D_65_6E_74 } ;
+("ErrorEvent") => { $ crate :: ATOM_JSWORD__45_72_72_6F_72_45_76_65_6E_74 } ;
+("RTCRtpContributingSource") => { $ crate :: ATOM_JSWORD__52_54_43_52_74_70_43_6F_6E_74_72_69_62_75_74_69_6E_67_53_6F_75_72_63_65 } ;
+("SVGFEBlendElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_42_6C_65_6E_64_45_6C_65_6D_65_6E_74 } ;
+("HTMLAllCollection") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_41_6C_6C_43_6F_6C_6C_65_63_74_69_6F_6E } ;
+("assert") => { $ crate :: ATOM_JSWORD__61_73_73_65_72_74 } ;
+("KeyframeEffect") => { $ crate :: ATOM_JSWORD__4B_65_79_66_72_61_6D_65_45_66_66_65_63_74 } ;
+("Presentation") => { $ crate :: ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E } ;
+("TextTrackList") => { $ crate :: ATOM_JSWORD__54_65_78_74_54_72_61_63_6B_4C_69_73_74 } ;
+("Plugin") => { $ crate :: ATOM_JSWORD__50_6C_75_67_69_6E } ;
+("PopStateEvent") => { $ crate :: ATOM_JSWORD__50_6F_70_53_74_61_74_65_45_76_65_6E_74 } ;
+("CSSMediaRule") => { $ crate :: ATOM_JSWORD__43_53_53_4D_65_64_69_61_52_75_6C_65 } ;
+("SVGAnimatedNumberList") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_4E_75_6D_62_65_72_4C_69_73_74 } ;
+("SVGFEConvolveMatrixElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_43_6F_6E_76_6F_6C_76_65_4D_61_74_72_69_78_45_6C_65_6D_65_6E_74 } ;
+("WebGLActiveInfo") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_41_63_74_69_76_65_49_6E_66_6F } ;
+("SVGFEDistantLightElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_44_69_73_74_61_6E_74_4C_69_67_68_74_45_6C_65_6D_65_6E_74 } ;
+("AnalyserNode") => { $ crate :: ATOM_JSWORD__41_6E_61_6C_79_73_65_72_4E_6F_64_65 } ;
+("SVGFEFuncBElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_46_75_6E_63_42_45_6C_65_6D_65_6E_74 } ;
+("DOMStringMap") => { $ crate :: ATOM_JSWORD__44_4F_4D_53_74_72_69_6E_67_4D_61_70 } ;
+("call") => { $ crate :: ATOM_JSWORD__63_61_6C_6C } ;
+("ServiceWorker") => { $ crate :: ATOM_JSWORD__53_65_72_76_69_63_65_57_6F_72_6B_65_72 } ;
+("from") => { $ crate :: ATOM_JSWORD__66_72_6F_6D } ;
+("MessageEvent") => { $ crate :: ATOM_JSWORD__4D_65_73_73_61_67_65_45_76_65_6E_74 } ;
+("enum") => { $ crate :: ATOM_JSWORD__65_6E_75_6D } ;
+("HTMLQuoteElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_51_75_6F_74_65_45_6C_65_6D_65_6E_74 } ;
+("WebGLBuffer") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_42_75_66_66_65_72 } ;
+("KeyframeEffectReadOnly") => { $ crate :: ATOM_JSWORD__4B_65_79_66_72_61_6D_65_45_66_66_65_63_74_52_65_61_64_4F_6E_6C_79 } ;
+("_toConsumableArray") => { $ crate :: ATOM_JSWORD__5F_74_6F_43_6F_6E_73_75_6D_61_62_6C_65_41_72_72_61_79 } ;
+("PermissionStatus") => { $ crate :: ATOM_JSWORD__50_65_72_6D_69_73_73_69_6F_6E_53_74_61_74_75_73 } ;
+("createReactClass") => { $ crate :: ATOM_JSWORD__63_72_65_61_74_65_52_65_61_63_74_43_6C_61_73_73 } ;
+("Range") => { $ crate :: ATOM_JSWORD__52_61_6E_67_65 } ;
+("bigint") => { $ crate :: ATOM_JSWORD__62_69_67_69_6E_74 } ;
+("HTMLParagraphElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_50_61_72_61_67_72_61_70_68_45_6C_65_6D_65_6E_74 } ;
+("break") => { $ crate :: ATOM_JSWORD__62_72_65_61_6B } ;
+("XSLTProcessor") => { $ crate :: ATOM_JSWORD__58_53_4C_54_50_72_6F_63_65_73_73_6F_72 } ;
+("RTCStatsReport") => { $ crate :: ATOM_JSWORD__52_54_43_53_74_61_74_73_52_65_70_6F_72_74 } ;
+("HTMLHtmlElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_48_74_6D_6C_45_6C_65_6D_65_6E_74 } ;
+("CSSStyleRule") => { $ crate :: ATOM_JSWORD__43_53_53_53_74_79_6C_65_52_75_6C_65 } ;
+("Navigator") => { $ crate :: ATOM_JSWORD__4E_61_76_69_67_61_74_6F_72 } ;
+("CacheStorage") => { $ crate :: ATOM_JSWORD__43_61_63_68_65_53_74_6F_72_61_67_65 } ;
+("SVGTextPositioningElement") => { $ crate :: ATOM_JSWORD__53_56_47_54_65_78_74_50_6F_73_69_74_69_6F_6E_69_6E_67_45_6C_65_6D_65_6E_74 } ;
+("PushSubscriptionOptions") => { $ crate :: ATOM_JSWORD__50_75_73_68_53_75_62_73_63_72_69_70_74_69_6F_6E_4F_70_74_69_6F_6E_73 } ;
+("AudioDestinationNode") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_44_65_73_74_69_6E_61_74_69_6F_6E_4E_6F_64_65 } ;
+("number") => { $ crate :: ATOM_JSWORD__6E_75_6D_62_65_72 } ;
+("SVGAnimatedInteger") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_49_6E_74_65_67_65_72 } ;
+("MIDIAccess") => { $ crate :: ATOM_JSWORD__4D_49_44_49_41_63_63_65_73_73 } ;
+("Error") => { $ crate :: ATOM_JSWORD__45_72_72_6F_72 } ;
+("PerformanceLongTaskTiming") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4C_6F_6E_67_54_61_73_6B_54_69_6D_69_6E_67 } ;
+("TransitionEvent") => { $ crate :: ATOM_JSWORD__54_72_61_6E_73_69_74_69_6F_6E_45_76_65_6E_74 } ;
+("SVGFEMergeNodeElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_4D_65_72_67_65_4E_6F_64_65_45_6C_65_6D_65_6E_74 } ;
+("TextEvent") => { $ crate :: ATOM_JSWORD__54_65_78_74_45_76_65_6E_74 } ;
+("is") => { $ crate :: ATOM_JSWORD__69_73 } ;
+("DeviceOrientationEvent") => { $ crate :: ATOM_JSWORD__44_65_76_69_63_65_4F_72_69_65_6E_74_61_74_69_6F_6E_45_76_65_6E_74 } ;
+("MediaKeyStatusMap") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_4B_65_79_53_74_61_74_75_73_4D_61_70 } ;
+("Blob") => { $ crate :: ATOM_JSWORD__42_6C_6F_62 } ;
+("EventTarget") => { $ crate :: ATOM_JSWORD__45_76_65_6E_74_54_61_72_67_65_74 } ;
+("DataTransferItem") => { $ crate :: ATOM_JSWORD__44_61_74_61_54_72_61_6E_73_66_65_72_49_74_65_6D } ;
+("ConvolverNode") => { $ crate :: ATOM_JSWORD__43_6F_6E_76_6F_6C_76_65_72_4E_6F_64_65 } ;
+("function") => { $ crate :: ATOM_JSWORD__66_75_6E_63_74_69_6F_6E } ;
+("XPathExpression") => { $ crate :: ATOM_JSWORD__58_50_61_74_68_45_78_70_72_65_73_73_69_6F_6E } ;
+("SVGCircleElement") => { $ crate :: ATOM_JSWORD__53_56_47_43_69_72_63_6C_65_45_6C_65_6D_65_6E_74 } ;
+("SVGScriptElement") => { $ crate :: ATOM_JSWORD__53_56_47_53_63_72_69_70_74_45_6C_65_6D_65_6E_74 } ;
+("SVGEllipseElement") => { $ crate :: ATOM_JSWORD__53_56_47_45_6C_6C_69_70_73_65_45_6C_65_6D_65_6E_74 } ;
+("let") => { $ crate :: ATOM_JSWORD__6C_65_74 } ;
+("Event") => { $ crate :: ATOM_JSWORD__45_76_65_6E_74 } ;
+("FontFace") => { $ crate :: ATOM_JSWORD__46_6F_6E_74_46_61_63_65 } ;
+("SharedArrayBuffer") => { $ crate :: ATOM_JSWORD__53_68_61_72_65_64_41_72_72_61_79_42_75_66_66_65_72 } ;
+("MutationObserver") => { $ crate :: ATOM_JSWORD__4D_75_74_61_74_69_6F_6E_4F_62_73_65_72_76_65_72 } ;
+("CSSKeyframesRule") => { $ crate :: ATOM_JSWORD__43_53_53_4B_65_79_66_72_61_6D_65_73_52_75_6C_65 } ;
+("ReturnType") => { $ crate :: ATOM_JSWORD__52_65_74_75_72_6E_54_79_70_65 } ;
+("SVGElement") => { $ crate :: ATOM_JSWORD__53_56_47_45_6C_65_6D_65_6E_74 } ;
+("ResizeObserverEntry") => { $ crate :: ATOM_JSWORD__52_65_73_69_7A_65_4F_62_73_65_72_76_65_72_45_6E_74_72_79 } ;
+("with") => { $ crate :: ATOM_JSWORD__77_69_74_68 } ;
+("WebAssembly") => { $ crate :: ATOM_JSWORD__57_65_62_41_73_73_65_6D_62_6C_79 } ;
+("XPathResult") => { $ crate :: ATOM_JSWORD__58_50_61_74_68_52_65_73_75_6C_74 } ;
+("HTMLFrameElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_46_72_61_6D_65_45_6C_65_6D_65_6E_74 } ;
+("MIDIInput") => { $ crate :: ATOM_JSWORD__4D_49_44_49_49_6E_70_75_74 } ;
+("HTMLBodyElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_42_6F_64_79_45_6C_65_6D_65_6E_74 } ;
+("important") => { $ crate :: ATOM_JSWORD__69_6D_70_6F_72_74_61_6E_74 } ;
+("WebGLQuery") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_51_75_65_72_79 } ;
+("") => { $ crate :: ATOM_JSWORD_ } ;
+("override") => { $ crate :: ATOM_JSWORD__6F_76_65_72_72_69_64_65 } ;
+("MIDIMessageEvent") => { $ crate :: ATOM_JSWORD__4D_49_44_49_4D_65_73_73_61_67_65_45_76_65_6E_74 } ;
+("WeakMap") => { $ crate :: ATOM_JSWORD__57_65_61_6B_4D_61_70 } ;
+("SVGDescElement") => { $ crate :: ATOM_JSWORD__53_56_47_44_65_73_63_45_6C_65_6D_65_6E_74 } ;
+("ArrayBuffer") => { $ crate :: ATOM_JSWORD__41_72_72_61_79_42_75_66_66_65_72 } ;
+("RegExp") => { $ crate :: ATOM_JSWORD__52_65_67_45_78_70 } ;
+("FontFaceSetLoadEvent") => { $ crate :: ATOM_JSWORD__46_6F_6E_74_46_61_63_65_53_65_74_4C_6F_61_64_45_76_65_6E_74 } ;
+("XMLSerializer") => { $ crate :: ATOM_JSWORD__58_4D_4C_53_65_72_69_61_6C_69_7A_65_72 } ;
+("RTCSctpTransport") => { $ crate :: ATOM_JSWORD__52_54_43_53_63_74_70_54_72_61_6E_73_70_6F_72_74 } ;
+("IDBObjectStore") => { $ crate :: ATOM_JSWORD__49_44_42_4F_62_6A_65_63_74_53_74_6F_72_65 } ;
+("WebSocket") => { $ crate :: ATOM_JSWORD__57_65_62_53_6F_63_6B_65_74 } ;
+("Pick") => { $ crate :: ATOM_JSWORD__50_69_63_6B } ;
+("null") => { $ crate :: ATOM_JSWORD__6E_75_6C_6C } ;
+("RTCRtpReceiver") => { $ crate :: ATOM_JSWORD__52_54_43_52_74_70_52_65_63_65_69_76_65_72 } ;
+("XMLDocument") => { $ crate :: ATOM_JSWORD__58_4D_4C_44_6F_63_75_6D_65_6E_74 } ;
+("async") => { $ crate :: ATOM_JSWORD__61_73_79_6E_63 } ;
+("ShadowRoot") => { $ crate :: ATOM_JSWORD__53_68_61_64_6F_77_52_6F_6F_74 } ;
+("SVGFEFuncAElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_46_75_6E_63_41_45_6C_65_6D_65_6E_74 } ;
+("HTMLContentElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_43_6F_6E_74_65_6E_74_45_6C_65_6D_65_6E_74 } ;
+("DOMRect") => { $ crate :: ATOM_JSWORD__44_4F_4D_52_65_63_74 } ;
+("SVGUnitTypes") => { $ crate :: ATOM_JSWORD__53_56_47_55_6E_69_74_54_79_70_65_73 } ;
+("SVGAnimatedRect") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_52_65_63_74 } ;
+("Number") => { $ crate :: ATOM_JSWORD__4E_75_6D_62_65_72 } ;
+("SVGFEFloodElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_46_6C_6F_6F_64_45_6C_65_6D_65_6E_74 } ;
+("export") => { $ crate :: ATOM_JSWORD__65_78_70_6F_72_74 } ;
+("WaveShaperNode") => { $ crate :: ATOM_JSWORD__57_61_76_65_53_68_61_70_65_72_4E_6F_64_65 } ;
+("DOMRectReadOnly") => { $ crate :: ATOM_JSWORD__44_4F_4D_52_65_63_74_52_65_61_64_4F_6E_6C_79 } ;
+("DOMException") => { $ crate :: ATOM_JSWORD__44_4F_4D_45_78_63_65_70_74_69_6F_6E } ;
+("Extract") => { $ crate :: ATOM_JSWORD__45_78_74_72_61_63_74 } ;
+("Float64Array") => { $ crate :: ATOM_JSWORD__46_6C_6F_61_74_36_34_41_72_72_61_79 } ;
+("SVGComponentTransferFunctionElement") => { $ crate :: ATOM_JSWORD__53_56_47_43_6F_6D_70_6F_6E_65_6E_74_54_72_61_6E_73_66_65_72_46_75_6E_63_74_69_6F_6E_45_6C_65_6D_65_6E_74 } ;
+("DOMError") => { $ crate :: ATOM_JSWORD__44_4F_4D_45_72_72_6F_72 } ;
+("SVGMarkerElement") => { $ crate :: ATOM_JSWORD__53_56_47_4D_61_72_6B_65_72_45_6C_65_6D_65_6E_74 } ;
+("IDBVersionChangeEvent") => { $ crate :: ATOM_JSWORD__49_44_42_56_65_72_73_69_6F_6E_43_68_61_6E_67_65_45_76_65_6E_74 } ;
+("ReadableStream") => { $ crate :: ATOM_JSWORD__52_65_61_64_61_62_6C_65_53_74_72_65_61_6D } ;
+("SVGPathElement") => { $ crate :: ATOM_JSWORD__53_56_47_50_61_74_68_45_6C_65_6D_65_6E_74 } ;
+("InputEvent") => { $ crate :: ATOM_JSWORD__49_6E_70_75_74_45_76_65_6E_74 } ;
+("StaticRange") => { $ crate :: ATOM_JSWORD__53_74_61_74_69_63_52_61_6E_67_65 } ;
+("HTMLTitleElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_69_74_6C_65_45_6C_65_6D_65_6E_74 } ;
+("var") => { $ crate :: ATOM_JSWORD__76_61_72 } ;
+("HTMLDocument") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_44_6F_63_75_6D_65_6E_74 } ;
+("React") => { $ crate :: ATOM_JSWORD__52_65_61_63_74 } ;
+("HTMLFormControlsCollection") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_46_6F_72_6D_43_6F_6E_74_72_6F_6C_73_43_6F_6C_6C_65_63_74_69_6F_6E } ;
+("XPathEvaluator") => { $ crate :: ATOM_JSWORD__58_50_61_74_68_45_76_61_6C_75_61_74_6F_72 } ;
+("CSS") => { $ crate :: ATOM_JSWORD__43_53_53 } ;
+("case") => { $ crate :: ATOM_JSWORD__63_61_73_65 } ;
+("String") => { $ crate :: ATOM_JSWORD__53_74_72_69_6E_67 } ;
+("Request") => { $ crate :: ATOM_JSWORD__52_65_71_75_65_73_74 } ;
+("CryptoKey") => { $ crate :: ATOM_JSWORD__43_72_79_70_74_6F_4B_65_79 } ;
+("SVGAnimatedString") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_53_74_72_69_6E_67 } ;
+("HTMLDataListElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_44_61_74_61_4C_69_73_74_45_6C_65_6D_65_6E_74 } ;
+("HTMLLinkElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4C_69_6E_6B_45_6C_65_6D_65_6E_74 } ;
+("MessagePort") => { $ crate :: ATOM_JSWORD__4D_65_73_73_61_67_65_50_6F_72_74 } ;
+("HTMLScriptElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_53_63_72_69_70_74_45_6C_65_6D_65_6E_74 } ;
+("SVGForeignObjectElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_6F_72_65_69_67_6E_4F_62_6A_65_63_74_45_6C_65_6D_65_6E_74 } ;
+("Attr") => { $ crate :: ATOM_JSWORD__41_74_74_72 } ;
+("CanvasCaptureMediaStreamTrack") => { $ crate :: ATOM_JSWORD__43_61_6E_76_61_73_43_61_70_74_75_72_65_4D_65_64_69_61_53_74_72_65_61_6D_54_72_61_63_6B } ;
+("PromiseRejectionEvent") => { $ crate :: ATOM_JSWORD__50_72_6F_6D_69_73_65_52_65_6A_65_63_74_69_6F_6E_45_76_65_6E_74 } ;
+("public") => { $ crate :: ATOM_JSWORD__70_75_62_6C_69_63 } ;
+("HTMLSlotElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_53_6C_6F_74_45_6C_65_6D_65_6E_74 } ;
+("SVGMetadataElement") => { $ crate :: ATOM_JSWORD__53_56_47_4D_65_74_61_64_61_74_61_45_6C_65_6D_65_6E_74 } ;
+("Node") => { $ crate :: ATOM_JSWORD__4E_6F_64_65 } ;
+("Uint32Array") => { $ crate :: ATOM_JSWORD__55_69_6E_74_33_32_41_72_72_61_79 } ;
+("Array") => { $ crate :: ATOM_JSWORD__41_72_72_61_79 } ;
+("HTMLInputElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_49_6E_70_75_74_45_6C_65_6D_65_6E_74 } ;
+("SVGAnimatedAngle") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_41_6E_67_6C_65 } ;
+("concat") => { $ crate :: ATOM_JSWORD__63_6F_6E_63_61_74 } ;
+("DelayNode") => { $ crate :: ATOM_JSWORD__44_65_6C_61_79_4E_6F_64_65 } ;
+("AnimationPlaybackEvent") => { $ crate :: ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_50_6C_61_79_62_61_63_6B_45_76_65_6E_74 } ;
+("MimeTypeArray") => { $ crate :: ATOM_JSWORD__4D_69_6D_65_54_79_70_65_41_72_72_61_79 } ;
+("HTMLAnchorElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_41_6E_63_68_6F_72_45_6C_65_6D_65_6E_74 } ;
+("HTMLPreElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_50_72_65_45_6C_65_6D_65_6E_74 } ;
+("CSSKeyframeRule") => { $ crate :: ATOM_JSWORD__43_53_53_4B_65_79_66_72_61_6D_65_52_75_6C_65 } ;
+("MediaElementAudioSourceNode") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_45_6C_65_6D_65_6E_74_41_75_64_69_6F_53_6F_75_72_63_65_4E_6F_64_65 } ;
+("NetworkInformation") => { $ crate :: ATOM_JSWORD__4E_65_74_77_6F_72_6B_49_6E_66_6F_72_6D_61_74_69_6F_6E } ;
+("Element") => { $ crate :: ATOM_JSWORD__45_6C_65_6D_65_6E_74 } ;
+("SVGSetElement") => { $ crate :: ATOM_JSWORD__53_56_47_53_65_74_45_6C_65_6D_65_6E_74 } ;
+("TaskAttributionTiming") => { $ crate :: ATOM_JSWORD__54_61_73_6B_41_74_74_72_69_62_75_74_69_6F_6E_54_69_6D_69_6E_67 } ;
+("SVGSVGElement") => { $ crate :: ATOM_JSWORD__53_56_47_53_56_47_45_6C_65_6D_65_6E_74 } ;
+("DOMPoint") => { $ crate :: ATOM_JSWORD__44_4F_4D_50_6F_69_6E_74 } ;
+("DocumentFragment") => { $ crate :: ATOM_JSWORD__44_6F_63_75_6D_65_6E_74_46_72_61_67_6D_65_6E_74 } ;
+("MediaSource") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_53_6F_75_72_63_65 } ;
+("SVGRadialGradientElement") => { $ crate :: ATOM_JSWORD__53_56_47_52_61_64_69_61_6C_47_72_61_64_69_65_6E_74_45_6C_65_6D_65_6E_74 } ;
+("SVGClipPathElement") => { $ crate :: ATOM_JSWORD__53_56_47_43_6C_69_70_50_61_74_68_45_6C_65_6D_65_6E_74 } ;
+("BaseAudioContext") => { $ crate :: ATOM_JSWORD__42_61_73_65_41_75_64_69_6F_43_6F_6E_74_65_78_74 } ;
+("SVGFESpecularLightingElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_53_70_65_63_75_6C_61_72_4C_69_67_68_74_69_6E_67_45_6C_65_6D_65_6E_74 } ;
+("SVGSymbolElement") => { $ crate :: ATOM_JSWORD__53_56_47_53_79_6D_62_6F_6C_45_6C_65_6D_65_6E_74 } ;
+("AudioBufferSourceNode") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_42_75_66_66_65_72_53_6F_75_72_63_65_4E_6F_64_65 } ;
+("BroadcastChannel") => { $ crate :: ATOM_JSWORD__42_72_6F_61_64_63_61_73_74_43_68_61_6E_6E_65_6C } ;
+("HTMLTrackElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_72_61_63_6B_45_6C_65_6D_65_6E_74 } ;
+("HTMLBaseElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_42_61_73_65_45_6C_65_6D_65_6E_74 } ;
+("HTMLOutputElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4F_75_74_70_75_74_45_6C_65_6D_65_6E_74 } ;
+("length") => { $ crate :: ATOM_JSWORD__6C_65_6E_67_74_68 } ;
+("private") => { $ crate :: ATOM_JSWORD__70_72_69_76_61_74_65 } ;
+("HTMLLIElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4C_49_45_6C_65_6D_65_6E_74 } ;
+("HTMLAudioElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_41_75_64_69_6F_45_6C_65_6D_65_6E_74 } ;
+("SVGAnimatedTransformList") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_54_72_61_6E_73_66_6F_72_6D_4C_69_73_74 } ;
+("PresentationRequest") => { $ crate :: ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_52_65_71_75_65_73_74 } ;
+("WebGLRenderbuffer") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_52_65_6E_64_65_72_62_75_66_66_65_72 } ;
+("object") => { $ crate :: ATOM_JSWORD__6F_62_6A_65_63_74 } ;
+("void") => { $ crate :: ATOM_JSWORD__76_6F_69_64 } ;
+("SVGPolylineElement") => { $ crate :: ATOM_JSWORD__53_56_47_50_6F_6C_79_6C_69_6E_65_45_6C_65_6D_65_6E_74 } ;
+("NodeIterator") => { $ crate :: ATOM_JSWORD__4E_6F_64_65_49_74_65_72_61_74_6F_72 } ;
+("TextTrackCue") => { $ crate :: ATOM_JSWORD__54_65_78_74_54_72_61_63_6B_43_75_65 } ;
+("symbol") => { $ crate :: ATOM_JSWORD__73_79_6D_62_6F_6C } ;
+("SVGPatternElement") => { $ crate :: ATOM_JSWORD__53_56_47_50_61_74_74_65_72_6E_45_6C_65_6D_65_6E_74 } ;
+("RTCIceTransport") => { $ crate :: ATOM_JSWORD__52_54_43_49_63_65_54_72_61_6E_73_70_6F_72_74 } ;
+("MediaError") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_45_72_72_6F_72 } ;
+("ChannelMergerNode") => { $ crate :: ATOM_JSWORD__43_68_61_6E_6E_65_6C_4D_65_72_67_65_72_4E_6F_64_65 } ;
+("GainNode") => { $ crate :: ATOM_JSWORD__47_61_69_6E_4E_6F_64_65 } ;
+("TextTrackCueList") => { $ crate :: ATOM_JSWORD__54_65_78_74_54_72_61_63_6B_43_75_65_4C_69_73_74 } ;
+("HTMLTemplateElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_65_6D_70_6C_61_74_65_45_6C_65_6D_65_6E_74 } ;
+("continue") => { $ crate :: ATOM_JSWORD__63_6F_6E_74_69_6E_75_65 } ;
+("EvalError") => { $ crate :: ATOM_JSWORD__45_76_61_6C_45_72_72_6F_72 } ;
+("IDBIndex") => { $ crate :: ATOM_JSWORD__49_44_42_49_6E_64_65_78 } ;
+("IDBRequest") => { $ crate :: ATOM_JSWORD__49_44_42_52_65_71_75_65_73_74 } ;
+("MediaKeySession") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_4B_65_79_53_65_73_73_69_6F_6E } ;
+("Symbol") => { $ crate :: ATOM_JSWORD__53_79_6D_62_6F_6C } ;
+("PerformanceObserverEntryList") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4F_62_73_65_72_76_65_72_45_6E_74_72_79_4C_69_73_74 } ;
+("namespace") => { $ crate :: ATOM_JSWORD__6E_61_6D_65_73_70_61_63_65 } ;
+("Animation") => { $ crate :: ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E } ;
+("FormData") => { $ crate :: ATOM_JSWORD__46_6F_72_6D_44_61_74_61 } ;
+("AudioParam") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_50_61_72_61_6D } ;
+("implements") => { $ crate :: ATOM_JSWORD__69_6D_70_6C_65_6D_65_6E_74_73 } ;
+("global") => { $ crate :: ATOM_JSWORD__67_6C_6F_62_61_6C } ;
+("AnimationEvent") => { $ crate :: ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_45_76_65_6E_74 } ;
+("WebGLProgram") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_50_72_6F_67_72_61_6D } ;
+("CSSStyleSheet") => { $ crate :: ATOM_JSWORD__43_53_53_53_74_79_6C_65_53_68_65_65_74 } ;
+("History") => { $ crate :: ATOM_JSWORD__48_69_73_74_6F_72_79 } ;
+("CSSRuleList") => { $ crate :: ATOM_JSWORD__43_53_53_52_75_6C_65_4C_69_73_74 } ;
+("IDBOpenDBRequest") => { $ crate :: ATOM_JSWORD__49_44_42_4F_70_65_6E_44_42_52_65_71_75_65_73_74 } ;
+("WebGLSampler") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_53_61_6D_70_6C_65_72 } ;
+("PresentationConnectionList") => { $ crate :: ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_43_6F_6E_6E_65_63_74_69_6F_6E_4C_69_73_74 } ;
+("HTMLFrameSetElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_46_72_61_6D_65_53_65_74_45_6C_65_6D_65_6E_74 } ;
+("ConstantSourceNode") => { $ crate :: ATOM_JSWORD__43_6F_6E_73_74_61_6E_74_53_6F_75_72_63_65_4E_6F_64_65 } ;
+("MouseEvent") => { $ crate :: ATOM_JSWORD__4D_6F_75_73_65_45_76_65_6E_74 } ;
+("_extends") => { $ crate :: ATOM_JSWORD__5F_65_78_74_65_6E_64_73 } ;
+("SVGGeometryElement") => { $ crate :: ATOM_JSWORD__53_56_47_47_65_6F_6D_65_74_72_79_45_6C_65_6D_65_6E_74 } ;
+("SVGLineElement") => { $ crate :: ATOM_JSWORD__53_56_47_4C_69_6E_65_45_6C_65_6D_65_6E_74 } ;
+("SVGRect") => { $ crate :: ATOM_JSWORD__53_56_47_52_65_63_74 } ;
+("Int16Array") => { $ crate :: ATOM_JSWORD__49_6E_74_31_36_41_72_72_61_79 } ;
+("this") => { $ crate :: ATOM_JSWORD__74_68_69_73 } ;
+("ApplicationCacheErrorEvent") => { $ crate :: ATOM_JSWORD__41_70_70_6C_69_63_61_74_69_6F_6E_43_61_63_68_65_45_72_72_6F_72_45_76_65_6E_74 } ;
+("SVGLength") => { $ crate :: ATOM_JSWORD__53_56_47_4C_65_6E_67_74_68 } ;
+("asserts") => { $ crate :: ATOM_JSWORD__61_73_73_65_72_74_73 } ;
+("or") => { $ crate :: ATOM_JSWORD__6F_72 } ;
+("require") => { $ crate :: ATOM_JSWORD__72_65_71_75_69_72_65 } ;
+("WheelEvent") => { $ crate :: ATOM_JSWORD__57_68_65_65_6C_45_76_65_6E_74 } ;
+("NodeList") => { $ crate :: ATOM_JSWORD__4E_6F_64_65_4C_69_73_74 } ;
+("DOMMatrixReadOnly") => { $ crate :: ATOM_JSWORD__44_4F_4D_4D_61_74_72_69_78_52_65_61_64_4F_6E_6C_79 } ;
+("Uint16Array") => { $ crate :: ATOM_JSWORD__55_69_6E_74_31_36_41_72_72_61_79 } ;
+("HTMLTextAreaElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_65_78_74_41_72_65_61_45_6C_65_6D_65_6E_74 } ;
+("HTMLProgressElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_50_72_6F_67_72_65_73_73_45_6C_65_6D_65_6E_74 } ;
+("Cache") => { $ crate :: ATOM_JSWORD__43_61_63_68_65 } ;
+("PerformanceNavigationTiming") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_4E_61_76_69_67_61_74_69_6F_6E_54_69_6D_69_6E_67 } ;
+("CSSImportRule") => { $ crate :: ATOM_JSWORD__43_53_53_49_6D_70_6F_72_74_52_75_6C_65 } ;
+("ByteLengthQueuingStrategy") => { $ crate :: ATOM_JSWORD__42_79_74_65_4C_65_6E_67_74_68_51_75_65_75_69_6E_67_53_74_72_61_74_65_67_79 } ;
+("Float32Array") => { $ crate :: ATOM_JSWORD__46_6C_6F_61_74_33_32_41_72_72_61_79 } ;
+("Promise") => { $ crate :: ATOM_JSWORD__50_72_6F_6D_69_73_65 } ;
+("CompositionEvent") => { $ crate :: ATOM_JSWORD__43_6F_6D_70_6F_73_69_74_69_6F_6E_45_76_65_6E_74 } ;
+("Proxy") => { $ crate :: ATOM_JSWORD__50_72_6F_78_79 } ;
+("HTMLHeadElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_48_65_61_64_45_6C_65_6D_65_6E_74 } ;
+("HTMLOListElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4F_4C_69_73_74_45_6C_65_6D_65_6E_74 } ;
+("SVGNumberList") => { $ crate :: ATOM_JSWORD__53_56_47_4E_75_6D_62_65_72_4C_69_73_74 } ;
+("as") => { $ crate :: ATOM_JSWORD__61_73 } ;
+("SVGAnimatedLengthList") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_4C_65_6E_67_74_68_4C_69_73_74 } ;
+("Bottom line") => { $ crate :: ATOM_JSWORD__42_6F_74_74_6F_6D_20_6C_69_6E_65 } ;
+("if") => { $ crate :: ATOM_JSWORD__69_66 } ;
+("Text") => { $ crate :: ATOM_JSWORD__54_65_78_74 } ;
+("CSSRule") => { $ crate :: ATOM_JSWORD__43_53_53_52_75_6C_65 } ;
+("ImageBitmapRenderingContext") => { $ crate :: ATOM_JSWORD__49_6D_61_67_65_42_69_74_6D_61_70_52_65_6E_64_65_72_69_6E_67_43_6F_6E_74_65_78_74 } ;
+("abstract") => { $ crate :: ATOM_JSWORD__61_62_73_74_72_61_63_74 } ;
+("SecurityPolicyViolationEvent") => { $ crate :: ATOM_JSWORD__53_65_63_75_72_69_74_79_50_6F_6C_69_63_79_56_69_6F_6C_61_74_69_6F_6E_45_76_65_6E_74 } ;
+("Credential") => { $ crate :: ATOM_JSWORD__43_72_65_64_65_6E_74_69_61_6C } ;
+("AnimationTimeline") => { $ crate :: ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_54_69_6D_65_6C_69_6E_65 } ;
+("HTMLOptionElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4F_70_74_69_6F_6E_45_6C_65_6D_65_6E_74 } ;
+("HTMLIFrameElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_49_46_72_61_6D_65_45_6C_65_6D_65_6E_74 } ;
+("AnimationEffectTimingReadOnly") => { $ crate :: ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_45_66_66_65_63_74_54_69_6D_69_6E_67_52_65_61_64_4F_6E_6C_79 } ;
+("finally") => { $ crate :: ATOM_JSWORD__66_69_6E_61_6C_6C_79 } ;
+("WebGLRenderingContext") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_52_65_6E_64_65_72_69_6E_67_43_6F_6E_74_65_78_74 } ;
+("WeakSet") => { $ crate :: ATOM_JSWORD__57_65_61_6B_53_65_74 } ;
+("Exclude") => { $ crate :: ATOM_JSWORD__45_78_63_6C_75_64_65 } ;
+("SVGAnimateElement") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_45_6C_65_6D_65_6E_74 } ;
+("RTCDtlsTransport") => { $ crate :: ATOM_JSWORD__52_54_43_44_74_6C_73_54_72_61_6E_73_70_6F_72_74 } ;
+("SVGLengthList") => { $ crate :: ATOM_JSWORD__53_56_47_4C_65_6E_67_74_68_4C_69_73_74 } ;
+("WebGLTransformFeedback") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_54_72_61_6E_73_66_6F_72_6D_46_65_65_64_62_61_63_6B } ;
+("Document") => { $ crate :: ATOM_JSWORD__44_6F_63_75_6D_65_6E_74 } ;
+("class") => { $ crate :: ATOM_JSWORD__63_6C_61_73_73 } ;
+("PresentationReceiver") => { $ crate :: ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_52_65_63_65_69_76_65_72 } ;
+("Headers") => { $ crate :: ATOM_JSWORD__48_65_61_64_65_72_73 } ;
+("ImageData") => { $ crate :: ATOM_JSWORD__49_6D_61_67_65_44_61_74_61 } ;
+("Math") => { $ crate :: ATOM_JSWORD__4D_61_74_68 } ;
+("HTMLButtonElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_42_75_74_74_6F_6E_45_6C_65_6D_65_6E_74 } ;
+("AnimationEffectTiming") => { $ crate :: ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_45_66_66_65_63_74_54_69_6D_69_6E_67 } ;
+("MediaKeyMessageEvent") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_4B_65_79_4D_65_73_73_61_67_65_45_76_65_6E_74 } ;
+("SVGFEDiffuseLightingElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_44_69_66_66_75_73_65_4C_69_67_68_74_69_6E_67_45_6C_65_6D_65_6E_74 } ;
+("of") => { $ crate :: ATOM_JSWORD__6F_66 } ;
+("MIDIPort") => { $ crate :: ATOM_JSWORD__4D_49_44_49_50_6F_72_74 } ;
+("instanceof") => { $ crate :: ATOM_JSWORD__69_6E_73_74_61_6E_63_65_6F_66 } ;
+("SVGGradientElement") => { $ crate :: ATOM_JSWORD__53_56_47_47_72_61_64_69_65_6E_74_45_6C_65_6D_65_6E_74 } ;
+("MediaRecorder") => { $ crate :: ATOM_JSWORD__4D_65_64_69_61_52_65_63_6F_72_64_65_72 } ;
+("IDBFactory") => { $ crate :: ATOM_JSWORD__49_44_42_46_61_63_74_6F_72_79 } ;
+("BudgetService") => { $ crate :: ATOM_JSWORD__42_75_64_67_65_74_53_65_72_76_69_63_65 } ;
+("FileList") => { $ crate :: ATOM_JSWORD__46_69_6C_65_4C_69_73_74 } ;
+("FileReader") => { $ crate :: ATOM_JSWORD__46_69_6C_65_52_65_61_64_65_72 } ;
+("BatteryManager") => { $ crate :: ATOM_JSWORD__42_61_74_74_65_72_79_4D_61_6E_61_67_65_72 } ;
+("IIRFilterNode") => { $ crate :: ATOM_JSWORD__49_49_52_46_69_6C_74_65_72_4E_6F_64_65 } ;
+("OffscreenCanvas") => { $ crate :: ATOM_JSWORD__4F_66_66_73_63_72_65_65_6E_43_61_6E_76_61_73 } ;
+("string") => { $ crate :: ATOM_JSWORD__73_74_72_69_6E_67 } ;
+("StereoPannerNode") => { $ crate :: ATOM_JSWORD__53_74_65_72_65_6F_50_61_6E_6E_65_72_4E_6F_64_65 } ;
+("DocumentType") => { $ crate :: ATOM_JSWORD__44_6F_63_75_6D_65_6E_74_54_79_70_65 } ;
+("url") => { $ crate :: ATOM_JSWORD__75_72_6C } ;
+("AudioWorkletGlobalScope") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_57_6F_72_6B_6C_65_74_47_6C_6F_62_61_6C_53_63_6F_70_65 } ;
+("Audio") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F } ;
+("HTMLBRElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_42_52_45_6C_65_6D_65_6E_74 } ;
+("SVGFEFuncGElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_46_75_6E_63_47_45_6C_65_6D_65_6E_74 } ;
+("default") => { $ crate :: ATOM_JSWORD__64_65_66_61_75_6C_74 } ;
+("infer") => { $ crate :: ATOM_JSWORD__69_6E_66_65_72 } ;
+("ServiceWorkerContainer") => { $ crate :: ATOM_JSWORD__53_65_72_76_69_63_65_57_6F_72_6B_65_72_43_6F_6E_74_61_69_6E_65_72 } ;
+("Reflect") => { $ crate :: ATOM_JSWORD__52_65_66_6C_65_63_74 } ;
+("HTMLMapElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4D_61_70_45_6C_65_6D_65_6E_74 } ;
+("BigUint64Array") => { $ crate :: ATOM_JSWORD__42_69_67_55_69_6E_74_36_34_41_72_72_61_79 } ;
+("PaymentRequest") => { $ crate :: ATOM_JSWORD__50_61_79_6D_65_6E_74_52_65_71_75_65_73_74 } ;
+("HTMLParamElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_50_61_72_61_6D_45_6C_65_6D_65_6E_74 } ;
+("SVGGraphicsElement") => { $ crate :: ATOM_JSWORD__53_56_47_47_72_61_70_68_69_63_73_45_6C_65_6D_65_6E_74 } ;
+("switch") => { $ crate :: ATOM_JSWORD__73_77_69_74_63_68 } ;
+("SVGFilterElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_69_6C_74_65_72_45_6C_65_6D_65_6E_74 } ;
+("RangeError") => { $ crate :: ATOM_JSWORD__52_61_6E_67_65_45_72_72_6F_72 } ;
+("SVGStringList") => { $ crate :: ATOM_JSWORD__53_56_47_53_74_72_69_6E_67_4C_69_73_74 } ;
+("super") => { $ crate :: ATOM_JSWORD__73_75_70_65_72 } ;
+("eval") => { $ crate :: ATOM_JSWORD__65_76_61_6C } ;
+("import") => { $ crate :: ATOM_JSWORD__69_6D_70_6F_72_74 } ;
+("SVGNumber") => { $ crate :: ATOM_JSWORD__53_56_47_4E_75_6D_62_65_72 } ;
+("Int8Array") => { $ crate :: ATOM_JSWORD__49_6E_74_38_41_72_72_61_79 } ;
+("SVGFETurbulenceElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_54_75_72_62_75_6C_65_6E_63_65_45_6C_65_6D_65_6E_74 } ;
+("RTCIceGatherer") => { $ crate :: ATOM_JSWORD__52_54_43_49_63_65_47_61_74_68_65_72_65_72 } ;
+("intrinsic") => { $ crate :: ATOM_JSWORD__69_6E_74_72_69_6E_73_69_63 } ;
+("Performance") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65 } ;
+("displayName") => { $ crate :: ATOM_JSWORD__64_69_73_70_6C_61_79_4E_61_6D_65 } ;
+("BeforeUnloadEvent") => { $ crate :: ATOM_JSWORD__42_65_66_6F_72_65_55_6E_6C_6F_61_64_45_76_65_6E_74 } ;
+("ScreenOrientation") => { $ crate :: ATOM_JSWORD__53_63_72_65_65_6E_4F_72_69_65_6E_74_61_74_69_6F_6E } ;
+("ImageBitmap") => { $ crate :: ATOM_JSWORD__49_6D_61_67_65_42_69_74_6D_61_70 } ;
+("AudioProcessingEvent") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_50_72_6F_63_65_73_73_69_6E_67_45_76_65_6E_74 } ;
+("HTMLLegendElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4C_65_67_65_6E_64_45_6C_65_6D_65_6E_74 } ;
+("SVGFEPointLightElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_50_6F_69_6E_74_4C_69_67_68_74_45_6C_65_6D_65_6E_74 } ;
+("HTMLMarqueeElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4D_61_72_71_75_65_65_45_6C_65_6D_65_6E_74 } ;
+("HTMLDetailsElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_44_65_74_61_69_6C_73_45_6C_65_6D_65_6E_74 } ;
+("SpeechSynthesisEvent") => { $ crate :: ATOM_JSWORD__53_70_65_65_63_68_53_79_6E_74_68_65_73_69_73_45_76_65_6E_74 } ;
+("XMLHttpRequestEventTarget") => { $ crate :: ATOM_JSWORD__58_4D_4C_48_74_74_70_52_65_71_75_65_73_74_45_76_65_6E_74_54_61_72_67_65_74 } ;
+("_defineProperty") => { $ crate :: ATOM_JSWORD__5F_64_65_66_69_6E_65_50_72_6F_70_65_72_74_79 } ;
+("CustomEvent") => { $ crate :: ATOM_JSWORD__43_75_73_74_6F_6D_45_76_65_6E_74 } ;
+("BiquadFilterNode") => { $ crate :: ATOM_JSWORD__42_69_71_75_61_64_46_69_6C_74_65_72_4E_6F_64_65 } ;
+("Notification") => { $ crate :: ATOM_JSWORD__4E_6F_74_69_66_69_63_61_74_69_6F_6E } ;
+("in") => { $ crate :: ATOM_JSWORD__69_6E } ;
+("SVGFEFuncRElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_46_75_6E_63_52_45_6C_65_6D_65_6E_74 } ;
+("SVGStyleElement") => { $ crate :: ATOM_JSWORD__53_56_47_53_74_79_6C_65_45_6C_65_6D_65_6E_74 } ;
+("Storage") => { $ crate :: ATOM_JSWORD__53_74_6F_72_61_67_65 } ;
+("HTMLMediaElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_4D_65_64_69_61_45_6C_65_6D_65_6E_74 } ;
+("key") => { $ crate :: ATOM_JSWORD__6B_65_79 } ;
+("SVGFEGaussianBlurElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_47_61_75_73_73_69_61_6E_42_6C_75_72_45_6C_65_6D_65_6E_74 } ;
+("KeyboardEvent") => { $ crate :: ATOM_JSWORD__4B_65_79_62_6F_61_72_64_45_76_65_6E_74 } ;
+("ProgressEvent") => { $ crate :: ATOM_JSWORD__50_72_6F_67_72_65_73_73_45_76_65_6E_74 } ;
+("HTMLCanvasElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_43_61_6E_76_61_73_45_6C_65_6D_65_6E_74 } ;
+("RTCPeerConnectionIceEvent") => { $ crate :: ATOM_JSWORD__52_54_43_50_65_65_72_43_6F_6E_6E_65_63_74_69_6F_6E_49_63_65_45_76_65_6E_74 } ;
+("SVGRectElement") => { $ crate :: ATOM_JSWORD__53_56_47_52_65_63_74_45_6C_65_6D_65_6E_74 } ;
+("HTMLAreaElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_41_72_65_61_45_6C_65_6D_65_6E_74 } ;
+("return") => { $ crate :: ATOM_JSWORD__72_65_74_75_72_6E } ;
+("TextTrack") => { $ crate :: ATOM_JSWORD__54_65_78_74_54_72_61_63_6B } ;
+("yield") => { $ crate :: ATOM_JSWORD__79_69_65_6C_64 } ;
+("HTMLUListElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_55_4C_69_73_74_45_6C_65_6D_65_6E_74 } ;
+("TypeError") => { $ crate :: ATOM_JSWORD__54_79_70_65_45_72_72_6F_72 } ;
+("HashChangeEvent") => { $ crate :: ATOM_JSWORD__48_61_73_68_43_68_61_6E_67_65_45_76_65_6E_74 } ;
+("MutationRecord") => { $ crate :: ATOM_JSWORD__4D_75_74_61_74_69_6F_6E_52_65_63_6F_72_64 } ;
+("CSSGroupingRule") => { $ crate :: ATOM_JSWORD__43_53_53_47_72_6F_75_70_69_6E_67_52_75_6C_65 } ;
+("PerformanceEntry") => { $ crate :: ATOM_JSWORD__50_65_72_66_6F_72_6D_61_6E_63_65_45_6E_74_72_79 } ;
+("DataTransfer") => { $ crate :: ATOM_JSWORD__44_61_74_61_54_72_61_6E_73_66_65_72 } ;
+("SVGFEComponentTransferElement") => { $ crate :: ATOM_JSWORD__53_56_47_46_45_43_6F_6D_70_6F_6E_65_6E_74_54_72_61_6E_73_66_65_72_45_6C_65_6D_65_6E_74 } ;
+("WritableStream") => { $ crate :: ATOM_JSWORD__57_72_69_74_61_62_6C_65_53_74_72_65_61_6D } ;
+("toString") => { $ crate :: ATOM_JSWORD__74_6F_53_74_72_69_6E_67 } ;
+("CloseEvent") => { $ crate :: ATOM_JSWORD__43_6C_6F_73_65_45_76_65_6E_74 } ;
+("SVGMPathElement") => { $ crate :: ATOM_JSWORD__53_56_47_4D_50_61_74_68_45_6C_65_6D_65_6E_74 } ;
+("SVGAnimatedNumber") => { $ crate :: ATOM_JSWORD__53_56_47_41_6E_69_6D_61_74_65_64_4E_75_6D_62_65_72 } ;
+("catch") => { $ crate :: ATOM_JSWORD__63_61_74_63_68 } ;
+("static") => { $ crate :: ATOM_JSWORD__73_74_61_74_69_63 } ;
+("EventSource") => { $ crate :: ATOM_JSWORD__45_76_65_6E_74_53_6F_75_72_63_65 } ;
+("DOMMatrix") => { $ crate :: ATOM_JSWORD__44_4F_4D_4D_61_74_72_69_78 } ;
+("PaymentAddress") => { $ crate :: ATOM_JSWORD__50_61_79_6D_65_6E_74_41_64_64_72_65_73_73 } ;
+("IDBKeyRange") => { $ crate :: ATOM_JSWORD__49_44_42_4B_65_79_52_61_6E_67_65 } ;
+("TextDecoder") => { $ crate :: ATOM_JSWORD__54_65_78_74_44_65_63_6F_64_65_72 } ;
+("BarProp") => { $ crate :: ATOM_JSWORD__42_61_72_50_72_6F_70 } ;
+("boolean") => { $ crate :: ATOM_JSWORD__62_6F_6F_6C_65_61_6E } ;
+("RTCIceCandidate") => { $ crate :: ATOM_JSWORD__52_54_43_49_63_65_43_61_6E_64_69_64_61_74_65 } ;
+("HTMLTableElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_45_6C_65_6D_65_6E_74 } ;
+("TrackEvent") => { $ crate :: ATOM_JSWORD__54_72_61_63_6B_45_76_65_6E_74 } ;
+("AudioBuffer") => { $ crate :: ATOM_JSWORD__41_75_64_69_6F_42_75_66_66_65_72 } ;
+("new") => { $ crate :: ATOM_JSWORD__6E_65_77 } ;
+("SharedWorker") => { $ crate :: ATOM_JSWORD__53_68_61_72_65_64_57_6F_72_6B_65_72 } ;
+("IdleDeadline") => { $ crate :: ATOM_JSWORD__49_64_6C_65_44_65_61_64_6C_69_6E_65 } ;
+("StorageManager") => { $ crate :: ATOM_JSWORD__53_74_6F_72_61_67_65_4D_61_6E_61_67_65_72 } ;
+("WebGLContextEvent") => { $ crate :: ATOM_JSWORD__57_65_62_47_4C_43_6F_6E_74_65_78_74_45_76_65_6E_74 } ;
+("PointerEvent") => { $ crate :: ATOM_JSWORD__50_6F_69_6E_74_65_72_45_76_65_6E_74 } ;
+("Location") => { $ crate :: ATOM_JSWORD__4C_6F_63_61_74_69_6F_6E } ;
+("HTMLCollection") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_43_6F_6C_6C_65_63_74_69_6F_6E } ;
+("HTMLFieldSetElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_46_69_65_6C_64_53_65_74_45_6C_65_6D_65_6E_74 } ;
+("await") => { $ crate :: ATOM_JSWORD__61_77_61_69_74 } ;
+("SyntaxError") => { $ crate :: ATOM_JSWORD__53_79_6E_74_61_78_45_72_72_6F_72 } ;
+("apply") => { $ crate :: ATOM_JSWORD__61_70_70_6C_79 } ;
+("TextMetrics") => { $ crate :: ATOM_JSWORD__54_65_78_74_4D_65_74_72_69_63_73 } ;
+("Function") => { $ crate :: ATOM_JSWORD__46_75_6E_63_74_69_6F_6E } ;
+("RadioNodeList") => { $ crate :: ATOM_JSWORD__52_61_64_69_6F_4E_6F_64_65_4C_69_73_74 } ;
+("Gamepad") => { $ crate :: ATOM_JSWORD__47_61_6D_65_70_61_64 } ;
+("DeviceMotionEvent") => { $ crate :: ATOM_JSWORD__44_65_76_69_63_65_4D_6F_74_69_6F_6E_45_76_65_6E_74 } ;
+("CanvasGradient") => { $ crate :: ATOM_JSWORD__43_61_6E_76_61_73_47_72_61_64_69_65_6E_74 } ;
+("MIDIConnectionEvent") => { $ crate :: ATOM_JSWORD__4D_49_44_49_43_6F_6E_6E_65_63_74_69_6F_6E_45_76_65_6E_74 } ;
+("NavigationPreloadManager") => { $ crate :: ATOM_JSWORD__4E_61_76_69_67_61_74_69_6F_6E_50_72_65_6C_6F_61_64_4D_61_6E_61_67_65_72 } ;
+("AnimationEffectReadOnly") => { $ crate :: ATOM_JSWORD__41_6E_69_6D_61_74_69_6F_6E_45_66_66_65_63_74_52_65_61_64_4F_6E_6C_79 } ;
+("iterator") => { $ crate :: ATOM_JSWORD__69_74_65_72_61_74_6F_72 } ;
+("PresentationAvailability") => { $ crate :: ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_41_76_61_69_6C_61_62_69_6C_69_74_79 } ;
+("MIDIOutput") => { $ crate :: ATOM_JSWORD__4D_49_44_49_4F_75_74_70_75_74 } ;
+("MIDIOutputMap") => { $ crate :: ATOM_JSWORD__4D_49_44_49_4F_75_74_70_75_74_4D_61_70 } ;
+("and") => { $ crate :: ATOM_JSWORD__61_6E_64 } ;
+("HTMLShadowElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_53_68_61_64_6F_77_45_6C_65_6D_65_6E_74 } ;
+("URLSearchParams") => { $ crate :: ATOM_JSWORD__55_52_4C_53_65_61_72_63_68_50_61_72_61_6D_73 } ;
+("PaymentResponse") => { $ crate :: ATOM_JSWORD__50_61_79_6D_65_6E_74_52_65_73_70_6F_6E_73_65 } ;
+("RTCPeerConnection") => { $ crate :: ATOM_JSWORD__52_54_43_50_65_65_72_43_6F_6E_6E_65_63_74_69_6F_6E } ;
+("MutationEvent") => { $ crate :: ATOM_JSWORD__4D_75_74_61_74_69_6F_6E_45_76_65_6E_74 } ;
+("SVGMaskElement") => { $ crate :: ATOM_JSWORD__53_56_47_4D_61_73_6B_45_6C_65_6D_65_6E_74 } ;
+("SVGSwitchElement") => { $ crate :: ATOM_JSWORD__53_56_47_53_77_69_74_63_68_45_6C_65_6D_65_6E_74 } ;
+("debugger") => { $ crate :: ATOM_JSWORD__64_65_62_75_67_67_65_72 } ;
+("TouchEvent") => { $ crate :: ATOM_JSWORD__54_6F_75_63_68_45_76_65_6E_74 } ;
+("HTMLTableRowElement") => { $ crate :: ATOM_JSWORD__48_54_4D_4C_54_61_62_6C_65_52_6F_77_45_6C_65_6D_65_6E_74 } ;
+("else") => { $ crate :: ATOM_JSWORD__65_6C_73_65 } ;
+("PresentationConnectionCloseEvent") => { $ crate :: ATOM_JSWORD__50_72_65_73_65_6E_74_61_74_69_6F_6E_43_6F_6E_6E_65_63_74_69_6F_6E_43_6C_6F_73_65_45_76_65_6E_74 } ;
+("Window") => { $ crate :: ATOM_JSWORD__57_69_6E_64_6F_77 } ;
+}
\ No newline at end of file